@@ -31,3 +31,19 @@ public class ASwiftStack {
     }
 }
 
+/// `import_opaque_swift_class.rs` calls `count` through this protocol.
+protocol SwiftCountable {
+    func count() -> UInt32
+}
+
+public class ASwiftCounter: SwiftCountable {
+    private let start: UInt32
+
+    init(start: UInt32) {
+        self.start = start
+    }
+
+    func count() -> UInt32 {
+        start
+    }
+}
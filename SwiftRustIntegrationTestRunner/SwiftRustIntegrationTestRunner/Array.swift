@@ -0,0 +1,14 @@
+//
+//  Array.swift
+//  SwiftRustIntegrationTestRunner
+//
+
+import Foundation
+
+public func runArrayTest() {
+    run_array_test()
+}
+
+func swiftReflectI32Array(arg: (Int32, Int32, Int32)) -> (Int32, Int32, Int32) {
+    arg
+}
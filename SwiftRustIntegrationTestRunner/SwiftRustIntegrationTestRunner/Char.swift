@@ -0,0 +1,12 @@
+//
+//  Char.swift
+//  SwiftRustIntegrationTestRunner
+//
+
+public func runCharTest() {
+    run_char_test()
+}
+
+func swiftReflectChar(arg: Unicode.Scalar) -> Unicode.Scalar {
+    arg
+}
@@ -0,0 +1,14 @@
+//
+//  Duration.swift
+//  SwiftRustIntegrationTestRunner
+//
+
+import Foundation
+
+public func runDurationTest() {
+    run_duration_test()
+}
+
+func swiftReflectDuration(arg: TimeInterval) -> TimeInterval {
+    arg
+}
@@ -0,0 +1,18 @@
+//
+//  Int128.swift
+//  SwiftRustIntegrationTestRunner
+//
+
+import Foundation
+
+public func runInt128Test() {
+    run_int128_test()
+}
+
+func swiftReflectU128(arg: RustU128) -> RustU128 {
+    arg
+}
+
+func swiftReflectI128(arg: RustI128) -> RustI128 {
+    arg
+}
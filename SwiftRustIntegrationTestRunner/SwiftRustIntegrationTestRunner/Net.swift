@@ -0,0 +1,18 @@
+//
+//  Net.swift
+//  SwiftRustIntegrationTestRunner
+//
+
+import Foundation
+
+public func runNetTest() {
+    run_net_test()
+}
+
+func swiftReflectIpAddr(arg: RustIpAddr) -> RustIpAddr {
+    arg
+}
+
+func swiftReflectSocketAddr(arg: RustSocketAddr) -> RustSocketAddr {
+    arg
+}
@@ -0,0 +1,17 @@
+//
+//  OpaqueTypeAttributes.swift
+//  SwiftRustIntegrationTestRunner
+//
+
+import Foundation
+
+/// Implemented by opaque Rust types that use `#[swift_bridge(conforms_to = ConformsToShape)]`.
+/// See crates/swift-integration-tests/src/opaque_type_attributes/conforms_to.rs
+protocol ConformsToShape {
+    func area() -> Double
+}
+
+/// See crates/swift-integration-tests/src/opaque_type_attributes/conforms_to.rs
+protocol ConformsToNamed {
+    func name() -> RustString
+}
@@ -0,0 +1,14 @@
+//
+//  PathBuf.swift
+//  SwiftRustIntegrationTestRunner
+//
+
+import Foundation
+
+public func runPathBufTest() {
+    run_path_buf_test()
+}
+
+func swiftReflectPathBuf(arg: URL) -> URL {
+    arg
+}
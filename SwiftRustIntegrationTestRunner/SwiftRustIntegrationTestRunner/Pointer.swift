@@ -21,3 +21,8 @@ func swift_echo_const_u8(ptr: UnsafePointer<UInt8>) -> UnsafePointer<UInt8> {
 func swift_echo_mut_u8(ptr: UnsafeMutablePointer<UInt8>) -> UnsafeMutablePointer<UInt8> {
     ptr
 }
+
+func swift_divmod(a: UInt32, b: UInt32, quotient: inout UInt32, remainder: inout UInt32) {
+    quotient = a / b
+    remainder = a % b
+}
@@ -0,0 +1,12 @@
+//
+//  Result.swift
+//  SwiftRustIntegrationTestRunner
+//
+
+import Foundation
+
+extension ResultTestOpaqueRustError: Error {}
+
+extension ResultTestSharedError: Error {}
+
+extension AsyncResultError: Error {}
@@ -0,0 +1,14 @@
+//
+//  Tuple.swift
+//  SwiftRustIntegrationTestRunner
+//
+
+import Foundation
+
+public func runTupleTest() {
+    run_tuple_test()
+}
+
+func swiftSwapTuple(arg: (UInt16, Double)) -> (Double, UInt16) {
+    (arg.1, arg.0)
+}
@@ -0,0 +1,32 @@
+//
+//  ArrayTests.swift
+//  SwiftRustIntegrationTestRunnerTests
+//
+
+import XCTest
+@testable import SwiftRustIntegrationTestRunner
+
+class ArrayTests: XCTestCase {
+    /// Verify that Rust can call a Swift function that takes and returns a fixed size array.
+    func testRustTests() throws {
+        runArrayTest()
+    }
+
+    /// Verify that we can pass a fixed size array to Rust and get one back.
+    func testReflectU8Array() throws {
+        let array = rust_reflect_u8_array((1, 2, 3, 4))
+
+        XCTAssertEqual(array.0, 1)
+        XCTAssertEqual(array.1, 2)
+        XCTAssertEqual(array.2, 3)
+        XCTAssertEqual(array.3, 4)
+    }
+
+    /// Verify that Rust receives the array's elements in the right order.
+    func testReverseF32Array() throws {
+        let array = rust_reverse_f32_array((1.5, 2.5))
+
+        XCTAssertEqual(array.0, 2.5)
+        XCTAssertEqual(array.1, 1.5)
+    }
+}
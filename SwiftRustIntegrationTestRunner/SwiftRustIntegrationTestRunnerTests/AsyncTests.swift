@@ -34,8 +34,63 @@ class AsyncTests: XCTestCase {
         XCTAssertEqual(num, 567)
     }
 
+    /// Verify that we can call async Rust methods that return an opaque Rust type.
+    func testSwiftCallsRustAsyncMethodReturnsOpaqueType() async throws {
+        let test = TestRustAsyncSelf()
+
+        let loaded: AsyncRustLoadedValue = await test.load_value(890)
+        XCTAssertEqual(loaded.value(), 890)
+    }
+
+    /// Verify that we can iterate over an async Rust iterator using `for await`.
+    func testSwiftIteratesRustAsyncIterator() async throws {
+        var values: [UInt32] = []
+        for await value in AsyncRustCounter(3) {
+            values.append(value)
+        }
+
+        XCTAssertEqual(values, [1, 2, 3])
+    }
+
     
     func testSwiftCallsRustAsyncFnRetStruct() async throws {
         let _: AsyncRustFnReturnStruct = await rust_async_return_struct()
     }
+    
+    /// Verify that we can call async Rust functions that return a Result.
+    func testSwiftCallsRustAsyncFnReturnsResult() async throws {
+        let num = try await rust_async_func_returns_result_u8(true)
+        XCTAssertEqual(num, 123)
+        
+        do {
+            let _ = try await rust_async_func_returns_result_u8(false)
+            XCTFail("The function should have thrown")
+        } catch let error as AsyncResultError {
+            XCTAssertEqual(error.code, 456)
+        }
+        
+        try await rust_async_func_returns_result_null(true)
+        
+        do {
+            try await rust_async_func_returns_result_null(false)
+            XCTFail("The function should have thrown")
+        } catch let error as AsyncResultError {
+            XCTAssertEqual(error.code, 789)
+        }
+    }
+    
+    /// Verify that cancelling the Swift task that is awaiting an async Rust function that returns
+    /// a Result throws a `CancellationError`.
+    func testSwiftCancelsRustAsyncFnReturnsResult() async throws {
+        let task = Task {
+            try await rust_async_func_never_completes()
+        }
+        task.cancel()
+        
+        do {
+            let _ = try await task.value
+            XCTFail("The task should have been cancelled")
+        } catch is CancellationError {
+        }
+    }
 }
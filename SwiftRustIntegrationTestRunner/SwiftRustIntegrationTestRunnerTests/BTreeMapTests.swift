@@ -0,0 +1,25 @@
+//
+//  BTreeMapTests.swift
+//  SwiftRustIntegrationTestRunnerTests
+//
+
+import XCTest
+@testable import SwiftRustIntegrationTestRunner
+
+class BTreeMapTests: XCTestCase {
+    /// Verify that a BTreeMap is returned to Swift in its sorted order.
+    func testReturnBTreeMapInSortedOrder() throws {
+        let entries = rust_return_btreemap_in_sorted_order()
+
+        XCTAssertEqual(entries.map { $0.0 }, [1, 2, 3])
+        XCTAssertEqual(entries.map { $0.1 }, ["one", "two", "three"])
+    }
+
+    /// Verify that an array of tuples gets sorted when it is bridged to a BTreeMap.
+    func testReflectBTreeMap() throws {
+        let entries = rust_reflect_btreemap_string_i32([("b", 2), ("c", 3), ("a", 1)])
+
+        XCTAssertEqual(entries.map { $0.0 }, ["a", "b", "c"])
+        XCTAssertEqual(entries.map { $0.1 }, [1, 2, 3])
+    }
+}
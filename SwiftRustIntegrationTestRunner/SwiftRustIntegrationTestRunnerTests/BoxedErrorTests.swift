@@ -0,0 +1,28 @@
+//
+//  BoxedErrorTests.swift
+//  SwiftRustIntegrationTestRunnerTests
+//
+
+import XCTest
+@testable import SwiftRustIntegrationTestRunner
+
+/// Tests for Box<dyn Error>, which Swift sees as a RustError.
+/// See crates/swift-integration-tests/src/boxed_error.rs
+class BoxedErrorTests: XCTestCase {
+    /// Verify that a returned Box<dyn Error> conforms to Swift's Error protocol and exposes the
+    /// Rust error's Display representation as its description.
+    func testReturnBoxedError() throws {
+        let error: Error = rust_func_returns_boxed_error()
+
+        XCTAssertEqual((error as! RustError).description, "something went wrong")
+        XCTAssertEqual("\(error)", "something went wrong")
+    }
+
+    /// Verify that an Option<Box<dyn Error>> becomes an optional RustError.
+    func testReturnOptionBoxedError() throws {
+        XCTAssertNil(rust_func_returns_option_boxed_error(false))
+
+        let error = rust_func_returns_option_boxed_error(true)
+        XCTAssertEqual(error?.description, "something went wrong")
+    }
+}
@@ -0,0 +1,35 @@
+//
+//  CharTests.swift
+//  SwiftRustIntegrationTestRunnerTests
+//
+
+import XCTest
+@testable import SwiftRustIntegrationTestRunner
+
+class CharTests: XCTestCase {
+    /// Verify that Rust can pass a char to Swift and get it back.
+    func testRustTests() throws {
+        runCharTest()
+    }
+
+    /// Verify that we can pass a Unicode.Scalar to Rust and get it back.
+    func testReflectChar() throws {
+        XCTAssertEqual(rust_reflect_char("a"), "a")
+        XCTAssertEqual(rust_reflect_char("é"), "é")
+        XCTAssertEqual(rust_reflect_char("🦀"), "🦀")
+    }
+
+    /// Verify that the scalars on either side of the surrogate range and the largest scalar
+    /// survive the round trip.
+    ///
+    /// Surrogate code points are not valid Rust `char`s, but they can't be represented by a
+    /// `Unicode.Scalar` either, so they can never be passed to Rust.
+    func testReflectCharBoundaries() throws {
+        XCTAssertNil(Unicode.Scalar(UInt32(0xD800)))
+
+        for value: UInt32 in [0xD7FF, 0xE000, 0x10FFFF] {
+            let scalar = Unicode.Scalar(value)!
+            XCTAssertEqual(rust_reflect_char(scalar), scalar)
+        }
+    }
+}
@@ -0,0 +1,64 @@
+//
+//  ClosureTests.swift
+//  SwiftRustIntegrationTestRunnerTests
+//
+
+import XCTest
+@testable import SwiftRustIntegrationTestRunner
+
+class ClosureTests: XCTestCase {
+    /// Verify that Rust can call a Swift closure that takes no arguments.
+    func testRustCallsSwiftClosureNoArgs() throws {
+        var called = false
+
+        rust_fn_calls_closure_no_args {
+            called = true
+        }
+
+        XCTAssertTrue(called)
+    }
+
+    /// Verify that Rust can call a Swift closure that takes one argument.
+    func testRustCallsSwiftClosureOneArg() throws {
+        var received: UInt32 = 0
+
+        rust_fn_calls_closure_one_arg { val in
+            received = val
+        }
+
+        XCTAssertEqual(received, 123)
+    }
+
+    /// Verify that Rust can call an `FnMut` Swift closure multiple times.
+    func testRustCallsSwiftFnMutClosureMultipleArgs() throws {
+        var calls: [(UInt8, Bool)] = []
+
+        rust_fn_calls_fn_mut_closure_multiple_args { num, flag in
+            calls.append((num, flag))
+        }
+
+        XCTAssertEqual(calls.count, 2)
+        XCTAssertEqual(calls[0].0, 1)
+        XCTAssertTrue(calls[0].1)
+        XCTAssertEqual(calls[1].0, 2)
+        XCTAssertFalse(calls[1].1)
+    }
+
+    /// Verify that Rust can borrow a Swift closure and use the values that it returns.
+    func testRustCallsBorrowedSwiftClosureReturningPrimitive() throws {
+        let sum = rust_fn_sums_borrowed_closure_results { val in
+            val * 10
+        }
+
+        XCTAssertEqual(sum, 60)
+    }
+
+    /// Verify that Rust can use the String that a Swift closure returns.
+    func testRustCallsSwiftClosureReturningString() throws {
+        let joined = rust_fn_calls_closure_returning_string { val in
+            "swift\(val)"
+        }
+
+        XCTAssertEqual(joined.toString(), "swift1 swift2")
+    }
+}
@@ -25,4 +25,10 @@ class ConditionalCompilationTests: XCTestCase {
     func testConditionalCompilation() throws {
         XCTAssertEqual(conditionally_exposed_fn(), 123)
     }
+
+    /// Call a function that is only exposed when the "this_is_enabled" feature is enabled, from a
+    /// bridge module that also holds a function whose feature is not enabled.
+    func testConditionalCompilationOfFunction() throws {
+        XCTAssertEqual(conditionally_exposed_fn_in_ungated_module(), 45)
+    }
 }
@@ -0,0 +1,35 @@
+//
+//  DurationTests.swift
+//  SwiftRustIntegrationTestRunnerTests
+//
+
+import XCTest
+@testable import SwiftRustIntegrationTestRunner
+
+class DurationTests: XCTestCase {
+    /// Verify that Rust can pass a Duration to Swift and get it back.
+    func testRustTests() throws {
+        runDurationTest()
+    }
+
+    /// Verify that we can pass a TimeInterval to Rust and get it back.
+    func testReflectDuration() throws {
+        XCTAssertEqual(rust_reflect_duration(1.5), 1.5)
+        XCTAssertEqual(rust_reflect_duration(0), 0)
+    }
+
+    /// Verify that sub-nanosecond precision gets rounded to the nearest nanosecond.
+    func testRoundsToNearestNanosecond() throws {
+        XCTAssertEqual(rust_reflect_duration(0.0000000016), 0.000000002)
+    }
+
+    /// Verify that negative intervals become a zero duration.
+    func testNegativeIntervalIsZero() throws {
+        XCTAssertEqual(rust_reflect_duration(-5), 0)
+    }
+
+    /// Verify that Rust's seconds and nanoseconds get combined into a TimeInterval.
+    func testDurationFromParts() throws {
+        XCTAssertEqual(rust_duration_from_parts(2, 500_000_000), 2.5)
+    }
+}
@@ -0,0 +1,21 @@
+//
+//  FunctionAttributeConstantTests.swift
+//  SwiftRustIntegrationTestRunnerTests
+//
+
+import XCTest
+@testable import SwiftRustIntegrationTestRunner
+
+/// Tests the #[swift_bridge(constant)] attribute.
+class FunctionAttributeConstantTests: XCTestCase {
+
+    /// Verify that we can read primitive and string constants from the Swift class.
+    /// See crates/swift-integration-tests/src/function_attributes/constant.rs
+    func testConstants() throws {
+        XCTAssertEqual(ConstantsHolder.MAX, 123)
+        XCTAssertEqual(ConstantsHolder.ratio, 0.5)
+        XCTAssertEqual(ConstantsHolder.enabled, true)
+        XCTAssertEqual(ConstantsHolder.name, "constants holder")
+        XCTAssertEqual(ConstantsHolder.greeting, "hello")
+    }
+}
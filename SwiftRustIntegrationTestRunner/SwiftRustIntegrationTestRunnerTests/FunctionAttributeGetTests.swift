@@ -0,0 +1,34 @@
+//
+//  FunctionAttributeGetTests.swift
+//  SwiftRustIntegrationTestRunnerTests
+//
+
+import XCTest
+@testable import SwiftRustIntegrationTestRunner
+
+/// Tests the #[swift_bridge(get)], #[swift_bridge(get(clone))] and #[swift_bridge(get(computed))]
+/// attributes.
+class FunctionAttributeGetTests: XCTestCase {
+
+    /// Verify that we can read borrowed, copied and cloned fields.
+    /// See crates/swift-integration-tests/src/function_attributes/get.rs
+    func testGetters() throws {
+        let holder = GetterHolder()
+
+        XCTAssertEqual(holder.number(), 123)
+        XCTAssertEqual(holder.borrowed_text().toString(), "borrowed")
+        XCTAssertEqual(holder.cloned_text().toString(), "cloned")
+
+        let vec = holder.cloned_vec()
+        XCTAssertEqual(vec.len(), 3)
+        XCTAssertEqual(vec.get(index: 2), 3)
+    }
+
+    /// Verify that a computed property calls its Rust method.
+    /// See crates/swift-integration-tests/src/function_attributes/get.rs
+    func testComputedProperty() throws {
+        let holder = GetterHolder()
+
+        XCTAssertFalse(holder.isEmpty)
+    }
+}
@@ -0,0 +1,33 @@
+//
+//  FunctionAttributeIteratorTests.swift
+//  SwiftRustIntegrationTestRunnerTests
+//
+
+import XCTest
+@testable import SwiftRustIntegrationTestRunner
+
+/// Tests the #[swift_bridge(Iterator)] attribute.
+class FunctionAttributeIteratorTests: XCTestCase {
+
+    /// Verify that we can iterate over a Rust iterator of primitives.
+    func testIteratorOfPrimitives() throws {
+        var values: [UInt32] = []
+        for value in IteratorOfPrimitives(3) {
+            values.append(value)
+        }
+
+        XCTAssertEqual(values, [0, 1, 2])
+    }
+
+    /// Verify that we can iterate over a Rust iterator of opaque Rust types.
+    func testIteratorOfOpaqueRustTypes() throws {
+        let values = IteratorOfOpaqueRustTypes(3).map { item in item.value() }
+
+        XCTAssertEqual(values, [0, 1, 2])
+    }
+
+    /// Verify that an empty iterator never yields a value.
+    func testEmptyIterator() throws {
+        XCTAssertNil(IteratorOfPrimitives(0).next())
+    }
+}
@@ -0,0 +1,37 @@
+//
+//  FunctionAttributeOperatorTests.swift
+//  SwiftRustIntegrationTestRunnerTests
+//
+
+import XCTest
+@testable import SwiftRustIntegrationTestRunner
+
+/// Tests the #[swift_bridge(operator = "...")] attribute.
+class FunctionAttributeOperatorTests: XCTestCase {
+
+    /// Verify that we can use Rust methods as Swift operators.
+    /// See crates/swift-integration-tests/src/function_attributes/operator.rs
+    func testOperators() throws {
+        let a = OperatorVector(1, 2)
+        let b = OperatorVector(3, 5)
+
+        let sum = a + b
+        XCTAssertEqual(sum.x(), 4)
+        XCTAssertEqual(sum.y(), 7)
+
+        let difference = b - a
+        XCTAssertEqual(difference.x(), 2)
+        XCTAssertEqual(difference.y(), 3)
+
+        let product = a * 2
+        XCTAssertEqual(product.x(), 2)
+        XCTAssertEqual(product.y(), 4)
+
+        let quotient = b / 2
+        XCTAssertEqual(quotient.x(), 1.5)
+        XCTAssertEqual(quotient.y(), 2.5)
+
+        XCTAssertTrue(a == OperatorVector(1, 2))
+        XCTAssertFalse(a == b)
+    }
+}
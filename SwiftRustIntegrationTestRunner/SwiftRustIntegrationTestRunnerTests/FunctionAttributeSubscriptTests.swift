@@ -0,0 +1,21 @@
+//
+//  FunctionAttributeSubscriptTests.swift
+//  SwiftRustIntegrationTestRunnerTests
+//
+
+import XCTest
+@testable import SwiftRustIntegrationTestRunner
+
+/// Tests the #[swift_bridge(subscript)] attribute.
+class FunctionAttributeSubscriptTests: XCTestCase {
+
+    /// Verify that we can use Rust methods as Swift subscripts.
+    /// See crates/swift-integration-tests/src/function_attributes/subscript.rs
+    func testSubscripts() throws {
+        let grid = SubscriptGrid(3)
+
+        XCTAssertEqual(grid[0], 0)
+        XCTAssertEqual(grid[4], 40)
+        XCTAssertEqual(grid[1, 2], 50)
+    }
+}
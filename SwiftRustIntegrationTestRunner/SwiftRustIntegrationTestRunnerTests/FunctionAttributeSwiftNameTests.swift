@@ -0,0 +1,21 @@
+//
+//  FunctionAttributeSwiftNameTests.swift
+//  SwiftRustIntegrationTestRunnerTests
+//
+
+import XCTest
+@testable import SwiftRustIntegrationTestRunner
+
+/// Tests the #[swift_bridge(swift_name = "...")] function attribute.
+class FunctionAttributeSwiftNameTests: XCTestCase {
+
+    /// Verify that we can call a Rust function using its `swift_name`.
+    func testRustFnWithSwiftName() throws {
+        XCTAssertEqual(rustFnWithSwiftName(), 10)
+    }
+
+    /// Verify that we can call a Rust method using its `swift_name`.
+    func testRustMethodWithSwiftName() throws {
+        XCTAssertEqual(SwiftNameOpaqueType().methodWithSwiftName(5), 6)
+    }
+}
@@ -0,0 +1,21 @@
+//
+//  FunctionAttributeSwiftVisibilityTests.swift
+//  SwiftRustIntegrationTestRunnerTests
+//
+
+import XCTest
+@testable import SwiftRustIntegrationTestRunner
+
+/// Tests the #[swift_bridge(swift_visibility = "...")] attribute.
+class FunctionAttributeSwiftVisibilityTests: XCTestCase {
+
+    /// Verify that we can call an `internal` Rust function from within the module.
+    func testRustFnWithInternalVisibility() throws {
+        XCTAssertEqual(rustFnWithInternalVisibility(), 10)
+    }
+
+    /// Verify that we can use an `internal` class and call its `internal` method.
+    func testInternalClassAndMethod() throws {
+        XCTAssertEqual(SwiftVisibilityOpaqueType().methodWithInternalVisibility(5), 6)
+    }
+}
@@ -0,0 +1,18 @@
+//
+//  GenericOpaqueRustTypeTests.swift
+//  SwiftRustIntegrationTestRunnerTests
+//
+
+import XCTest
+@testable import SwiftRustIntegrationTestRunner
+
+class GenericOpaqueRustTypeTests: XCTestCase {
+    /// Verify that every instantiation of a generic opaque Rust type gets its own Swift class.
+    func testGenericOpaqueRustTypeInstantiations() throws {
+        let u32Wrapper: GenericWrapperUInt32 = new_generic_wrapper_u32(123)
+        let boolWrapper: GenericWrapperBool = new_generic_wrapper_bool(true)
+
+        XCTAssertEqual(u32Wrapper.get(), 123)
+        XCTAssertEqual(boolWrapper.get(), true)
+    }
+}
@@ -0,0 +1,39 @@
+//
+//  Int128Tests.swift
+//  SwiftRustIntegrationTestRunnerTests
+//
+
+import XCTest
+@testable import SwiftRustIntegrationTestRunner
+
+class Int128Tests: XCTestCase {
+    /// Verify that Rust can pass u128 and i128 values to Swift and get them back.
+    func testRustTests() throws {
+        runInt128Test()
+    }
+
+    /// Verify that we can pass a RustU128 to Rust and get it back.
+    func testReflectU128() throws {
+        let value = RustU128(high: 0x0123_4567_89AB_CDEF, low: 0xFEDC_BA98_7654_3210)
+        XCTAssertEqual(rust_reflect_u128(value), value)
+        XCTAssertEqual(rust_reflect_u128(RustU128(5)), RustU128(5))
+    }
+
+    /// Verify that we can pass a RustI128 to Rust and get it back.
+    func testReflectI128() throws {
+        XCTAssertEqual(rust_reflect_i128(RustI128(-5)), RustI128(-5))
+        XCTAssertEqual(rust_reflect_i128(RustI128(-5)), RustI128(high: -1, low: UInt64.max - 4))
+    }
+
+    /// Verify that the high and low words are not swapped when crossing the boundary.
+    func testWordOrder() throws {
+        XCTAssertEqual(rust_u128_max(), RustU128(high: UInt64.max, low: UInt64.max))
+        XCTAssertEqual(rust_i128_min(), RustI128(high: Int64.min, low: 0))
+    }
+
+    /// Verify that values compare by their high word first.
+    func testComparable() throws {
+        XCTAssertLessThan(RustU128(high: 0, low: UInt64.max), RustU128(high: 1, low: 0))
+        XCTAssertLessThan(RustI128(-1), RustI128(0))
+    }
+}
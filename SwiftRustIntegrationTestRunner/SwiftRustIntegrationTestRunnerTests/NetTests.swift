@@ -0,0 +1,41 @@
+//
+//  NetTests.swift
+//  SwiftRustIntegrationTestRunnerTests
+//
+
+import XCTest
+@testable import SwiftRustIntegrationTestRunner
+
+class NetTests: XCTestCase {
+    /// Verify that Rust can pass IpAddr and SocketAddr values to Swift and get them back.
+    func testRustTests() throws {
+        runNetTest()
+    }
+
+    /// Verify that we can pass IPv4 and IPv6 addresses to Rust and get them back.
+    func testReflectIpAddr() throws {
+        let v4 = RustIpAddr.v4([10, 0, 0, 1])
+        XCTAssertEqual(rust_reflect_ip_addr(v4), v4)
+
+        let v6 = RustIpAddr.v6([0x20, 0x01, 0x0d, 0xb8] + Array(repeating: 0, count: 11) + [1])
+        XCTAssertEqual(rust_reflect_ip_addr(v6), v6)
+    }
+
+    /// Verify that the octets are in network byte order.
+    func testOctetsAreInNetworkByteOrder() throws {
+        XCTAssertEqual(rust_localhost_v4(), .v4([127, 0, 0, 1]))
+    }
+
+    /// Verify that we can pass a SocketAddr to Rust and get it back.
+    func testReflectSocketAddr() throws {
+        let addr = RustSocketAddr(ip: .v4([192, 168, 1, 20]), port: 443)
+        XCTAssertEqual(rust_reflect_socket_addr(addr), addr)
+    }
+
+    /// Verify that an IPv6 socket address keeps its address and port.
+    func testV6SocketAddr() throws {
+        let addr = rust_localhost_v6_socket_addr(8080)
+        XCTAssertEqual(addr.ip, .v6(Array(repeating: 0, count: 15) + [1]))
+        XCTAssertEqual(addr.port, 8080)
+    }
+}
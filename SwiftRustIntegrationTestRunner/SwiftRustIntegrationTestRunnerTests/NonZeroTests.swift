@@ -0,0 +1,21 @@
+//
+//  NonZeroTests.swift
+//  SwiftRustIntegrationTestRunnerTests
+//
+
+import XCTest
+@testable import SwiftRustIntegrationTestRunner
+
+class NonZeroTests: XCTestCase {
+    /// Verify that we can pass NonZero integers to Rust and get them back.
+    func testReflectNonZero() throws {
+        XCTAssertEqual(rust_reflect_non_zero_u32(5), 5)
+        XCTAssertEqual(rust_reflect_non_zero_i64(-5), -5)
+    }
+
+    /// Verify that an Option<NonZeroU32> becomes an Optional<UInt32>.
+    func testReflectOptionNonZero() throws {
+        XCTAssertEqual(rust_reflect_option_non_zero_u32(5), 5)
+        XCTAssertNil(rust_reflect_option_non_zero_u32(nil))
+    }
+}
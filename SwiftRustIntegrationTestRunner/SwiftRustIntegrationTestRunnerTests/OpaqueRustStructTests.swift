@@ -33,6 +33,15 @@ class OpaqueRustStructTests: XCTestCase {
         stack.pop()
         XCTAssertEqual(stack.len(), 1)
     }
+
+    /// Verify that an associated function that returns `Self` becomes a static method that
+    /// returns an owned class instance.
+    func testAssociatedFunctionReturningSelf() throws {
+        let stack = ARustStack.with_val(5)
+
+        XCTAssertEqual(stack.len(), 1)
+        XCTAssertEqual(stack.as_slice()[0], 5)
+    }
     
     /// Verify that when we de-alocate a class instance that is wrapping a type that was returned to us from
     /// Rust by reference we do not free the Rust type's memory (like we do with owned values).
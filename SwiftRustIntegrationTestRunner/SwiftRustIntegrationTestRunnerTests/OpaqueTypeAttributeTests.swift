@@ -32,6 +32,137 @@ class OpaqueTypeAttributeTests: XCTestCase {
         
         XCTAssert(AlreadyDeclaredTypeTest.an_associated_function())
     }
+    
+    /// Verify that an opaque Rust type's `Comparable` conformance uses its `Ord` implementation.
+    /// See crates/swift-integration-tests/src/opaque_type_attributes/comparable.rs
+    func testExternRustComparable() throws {
+        let v1_2 = new_comparable_version(1, 2)
+        let v1_10 = new_comparable_version(1, 10)
+        let v2_0 = new_comparable_version(2, 0)
+        
+        XCTAssert(v1_2 < v1_10)
+        XCTAssert(v2_0 > v1_10)
+        XCTAssertEqual(v1_2, new_comparable_version(1, 2))
+        XCTAssertNotEqual(v1_2, v2_0)
+        XCTAssertEqual([v2_0, v1_2, v1_10].max(), v2_0)
+    }
+    
+    /// Verify that a `Copy` opaque Rust type gets passed to and from Rust by value.
+    /// See crates/swift-integration-tests/src/opaque_type_attributes/copy.rs
+    func testExternRustCopy() throws {
+        let origin = CopyPoint(0.0, 0.0)
+        let point = origin.translated(3.0, 4.0)
+        
+        XCTAssertEqual(origin.x(), 0.0)
+        XCTAssertEqual(point.x(), 3.0)
+        XCTAssertEqual(point.y(), 4.0)
+        XCTAssertEqual(copy_point_distance(origin, point), 5.0)
+    }
+    
+    /// Verify that an opaque Rust type's `description` uses its `Display` implementation.
+    /// See crates/swift-integration-tests/src/opaque_type_attributes/display.rs
+    func testExternRustDisplay() throws {
+        let money = new_display_money(1050)
+        
+        XCTAssertEqual(money.description, "$10.50")
+        XCTAssertEqual("\(money)", "$10.50")
+    }
+    
+    /// Verify that an opaque Rust type can be created from a string literal.
+    /// See crates/swift-integration-tests/src/opaque_type_attributes/expressible_by_string_literal.rs
+    func testExternRustExpressibleByStringLiteral() throws {
+        let username: LiteralUsername = "ferris"
+        
+        XCTAssertEqual(username.name().toString(), "ferris")
+    }
+    
+    /// Verify that we can share a `Sendable` opaque Rust type between Swift tasks.
+    /// See crates/swift-integration-tests/src/opaque_type_attributes/sendable.rs
+    func testExternRustSendable() async throws {
+        let counter = new_sendable_counter()
+        
+        await withTaskGroup(of: Void.self) { group in
+            for _ in 0..<10 {
+                group.addTask {
+                    _ = counter.increment()
+                }
+            }
+        }
+        
+        XCTAssertEqual(counter.increment(), 11)
+    }
+    
+    /// Verify that copying an `Arc`-backed type shares the same Rust value instead of
+    /// copying it.
+    /// See crates/swift-integration-tests/src/opaque_type_attributes/arc.rs
+    func testExternRustArcCopy() throws {
+        let counter: ArcCounter = new_arc_counter()
+        XCTAssertEqual(arc_counter_strong_count(counter), 1)
+        
+        do {
+            let copy: ArcCounter = counter.copy()
+            XCTAssertEqual(arc_counter_strong_count(counter), 2)
+            
+            XCTAssertEqual(copy.increment(), 1)
+            XCTAssertEqual(counter.increment(), 2)
+        }
+        
+        XCTAssertEqual(arc_counter_value(counter.copy()), 2)
+        XCTAssertEqual(arc_counter_strong_count(counter), 1)
+    }
+    
+    /// Verify that we can call the methods of a pinned type without the Rust value moving.
+    /// See crates/swift-integration-tests/src/opaque_type_attributes/pin.rs
+    func testExternRustPin() throws {
+        let counter = PinnedCounter()
+        XCTAssertTrue(counter.has_not_moved())
+        
+        counter.increment()
+        counter.increment()
+        
+        XCTAssertEqual(counter.count(), 2)
+        XCTAssertTrue(counter.has_not_moved())
+    }
+    
+    /// Verify that we can call the methods of a mutex guarded type, and that they throw once the
+    /// mutex is poisoned.
+    /// See crates/swift-integration-tests/src/opaque_type_attributes/mutex.rs
+    func testExternRustMutex() throws {
+        let counter = MutexCounter()
+        
+        try counter.increment()
+        try counter.increment()
+        XCTAssertEqual(try counter.count(), 2)
+        
+        poison_mutex_counter(counter)
+        XCTAssertThrowsError(try counter.count())
+    }
+    
+    /// Verify that calling a trait object's methods dispatches to the underlying Rust type.
+    /// See crates/swift-integration-tests/src/opaque_type_attributes/trait_object.rs
+    func testExternRustTraitObject() throws {
+        let square: TraitObjectShape = new_trait_object_square(3)
+        let triangle: TraitObjectShape = new_trait_object_triangle(4, 5)
+        
+        XCTAssertEqual(square.area(), 9)
+        XCTAssertEqual(square.sides(), 4)
+        
+        XCTAssertEqual(triangle.area(), 10)
+        XCTAssertEqual(triangle.sides(), 3)
+    }
+
+    /// Verify that opaque Rust types conform to the Swift protocols listed in their
+    /// `conforms_to` attribute.
+    /// See crates/swift-integration-tests/src/opaque_type_attributes/conforms_to.rs
+    func testExternRustConformsTo() throws {
+        let shapes: [ConformsToShape] = [new_conforms_to_circle(1), new_conforms_to_square(3)]
+
+        XCTAssertEqual(shapes[0].area(), Double.pi)
+        XCTAssertEqual(shapes[1].area(), 9)
+
+        let named: ConformsToNamed = new_conforms_to_square(1)
+        XCTAssertEqual(named.name().toString(), "square")
+    }
 
     func testPerformanceExample() throws {
         // This is an example of a performance test case.
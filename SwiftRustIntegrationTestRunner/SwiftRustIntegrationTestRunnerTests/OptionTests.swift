@@ -93,6 +93,14 @@ class OptionTests: XCTestCase {
         let none: RustStr? = nil
         XCTAssertNil(rust_reflect_option_str(none))
     }
+
+    /// Verify that an empty string does not get confused with `None`.
+    func testSwiftCallRustOptionStrEmptyIsNotNone() throws {
+        let empty: String? = ""
+        let reflected = rust_reflect_option_str(empty)
+        XCTAssertNotNil(reflected)
+        XCTAssertEqual(reflected!.toString(), "")
+    }
     
     func testSwiftCallRustWithOptionOpaqueRustType() throws {
         let val = OptTestOpaqueRustType(123)
@@ -101,6 +109,31 @@ class OptionTests: XCTestCase {
         
         XCTAssertNil(rust_reflect_option_opaque_rust_type(nil))
     }
+
+    /// Verify that an `Option<&OpaqueRustType>` becomes an optional class reference.
+    func testSwiftCallRustReturnOptionRefOpaqueRustType() throws {
+        let even = OptTestOpaqueRustType(124)
+        XCTAssertEqual(even.self_if_field_is_even()!.field(), 124)
+
+        let odd = OptTestOpaqueRustType(123)
+        XCTAssertNil(odd.self_if_field_is_even())
+    }
+
+    /// Verify that we can pass `Option<&OpaqueRustType>` and `Option<OpaqueRustType>` arguments
+    /// to Rust methods.
+    func testSwiftCallRustMethodWithOptionOpaqueRustTypeArg() throws {
+        let val = OptTestOpaqueRustType(10)
+        let other = OptTestOpaqueRustType(5)
+
+        XCTAssertEqual(val.field_plus(other), 15)
+        XCTAssertEqual(val.field_plus(nil), 10)
+        XCTAssertEqual(other.field(), 5)
+
+        val.take_field(nil)
+        XCTAssertEqual(val.field(), 10)
+        val.take_field(OptTestOpaqueRustType(7))
+        XCTAssertEqual(val.field(), 7)
+    }
     
     func testStructWithOptionFieldsSome() throws {
         let val = StructWithOptionFields(
@@ -0,0 +1,28 @@
+//
+//  PathBufTests.swift
+//  SwiftRustIntegrationTestRunnerTests
+//
+
+import XCTest
+@testable import SwiftRustIntegrationTestRunner
+
+class PathBufTests: XCTestCase {
+    /// Verify that Rust can pass a PathBuf to Swift and get it back.
+    func testRustTests() throws {
+        runPathBufTest()
+    }
+
+    /// Verify that we can pass a file URL to Rust and get it back.
+    func testReflectPathBuf() throws {
+        let url = URL(fileURLWithPath: "/tmp/some file.txt")
+
+        XCTAssertEqual(rust_reflect_path_buf(url), url)
+    }
+
+    /// Verify that Rust can operate on the path that we pass it.
+    func testJoinPath() throws {
+        let url = rust_join_path(URL(fileURLWithPath: "/tmp"), "file.txt")
+
+        XCTAssertEqual(url.path, "/tmp/file.txt")
+    }
+}
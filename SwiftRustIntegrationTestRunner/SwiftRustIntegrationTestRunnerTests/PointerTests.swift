@@ -37,4 +37,31 @@ class PointerTests: XCTestCase {
     func testRustCallSwiftUInt8() throws {
         rust_run_u8_pointer_tests()
     }
+
+    /// Verify that we can call a Rust `unsafe fn`.
+    func testCallUnsafeRustFunction() throws {
+        let bytes: [UInt8] = [1, 2, 3, 250]
+
+        let sum = bytes.withUnsafeBufferPointer { buffer in
+            rust_sum_u8_buffer(buffer.baseAddress!, UInt(buffer.count))
+        }
+
+        XCTAssertEqual(sum, 256)
+    }
+
+    /// Verify that Rust can write to the `inout` primitives that we pass it.
+    func testRustWritesToInoutParameters() throws {
+        var quotient: UInt32 = 0
+        var remainder: UInt32 = 0
+
+        rust_divmod(17, 5, &quotient, &remainder)
+
+        XCTAssertEqual(quotient, 3)
+        XCTAssertEqual(remainder, 2)
+    }
+
+    /// Verify that Swift can write to the `&mut` primitives that Rust passes it.
+    func testSwiftWritesToRustMutableReferences() throws {
+        rust_run_out_param_tests()
+    }
 }
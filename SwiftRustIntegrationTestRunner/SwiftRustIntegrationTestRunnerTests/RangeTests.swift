@@ -0,0 +1,29 @@
+//
+//  RangeTests.swift
+//  SwiftRustIntegrationTestRunnerTests
+//
+
+import XCTest
+@testable import SwiftRustIntegrationTestRunner
+
+class RangeTests: XCTestCase {
+    /// Verify that we can pass a Range to Rust and get it back.
+    func testReflectRange() throws {
+        XCTAssertEqual(rust_reflect_range_usize(3..<10), 3..<10)
+    }
+
+    /// Verify that we can pass an empty Range to Rust and get it back.
+    func testReflectEmptyRange() throws {
+        XCTAssertEqual(rust_reflect_range_usize(5..<5), 5..<5)
+    }
+
+    /// Verify that a ClosedRange can be bridged to and from a RangeInclusive.
+    func testReflectClosedRange() throws {
+        XCTAssertEqual(rust_reflect_range_inclusive_i32(-5...5), -5...5)
+    }
+
+    /// Verify that Rust receives the bounds of the range.
+    func testRangeBounds() throws {
+        XCTAssertEqual(rust_range_len(10..<15), 5)
+    }
+}
@@ -0,0 +1,49 @@
+//
+//  ResultTests.swift
+//  SwiftRustIntegrationTestRunnerTests
+//
+
+import XCTest
+@testable import SwiftRustIntegrationTestRunner
+
+class ResultTests: XCTestCase {
+    /// Verify that we can call a Rust function that returns a Result<OpaqueRust, OpaqueRust>.
+    func testSwiftCallRustResultOpaqueRust() throws {
+        XCTAssertEqual(try rust_func_returns_result_opaque_rust(true).val(), 123)
+
+        XCTAssertThrowsError(try rust_func_returns_result_opaque_rust(false)) { error in
+            XCTAssertEqual((error as! ResultTestOpaqueRustError).code(), 456)
+        }
+    }
+
+    /// Verify that we can call a Rust function that returns a Result<SharedStruct, SharedStruct>.
+    func testSwiftCallRustResultSharedStruct() throws {
+        XCTAssertEqual(try rust_func_returns_result_shared_struct(true).field, 123)
+
+        XCTAssertThrowsError(try rust_func_returns_result_shared_struct(false)) { error in
+            XCTAssertEqual((error as! ResultTestSharedError).code, 456)
+        }
+    }
+
+    /// Verify that we can call a Rust function that returns a Result<u32, OpaqueRust>.
+    func testSwiftCallRustResultPrimitive() throws {
+        XCTAssertEqual(try rust_func_returns_result_u32(true), 123)
+        XCTAssertThrowsError(try rust_func_returns_result_u32(false))
+    }
+
+    /// Verify that we can call a Rust function that returns a Result<(), OpaqueRust>.
+    func testSwiftCallRustResultNull() throws {
+        XCTAssertNoThrow(try rust_func_returns_result_null(true))
+        XCTAssertThrowsError(try rust_func_returns_result_null(false))
+    }
+
+    /// Verify that we can call a Rust function that returns a Result<(), SharedStruct> and that
+    /// the error that gets thrown is the Rust error.
+    func testSwiftCallRustResultNullSharedError() throws {
+        XCTAssertNoThrow(try rust_func_returns_result_null_shared_error(true))
+
+        XCTAssertThrowsError(try rust_func_returns_result_null_shared_error(false)) { error in
+            XCTAssertEqual((error as! ResultTestSharedError).code, 789)
+        }
+    }
+}
@@ -0,0 +1,35 @@
+//
+//  SetTests.swift
+//  SwiftRustIntegrationTestRunnerTests
+//
+
+import XCTest
+@testable import SwiftRustIntegrationTestRunner
+
+class SetTests: XCTestCase {
+    /// Verify that we can pass a Set of primitives to Rust and get it back.
+    func testReflectHashSetOfPrimitives() throws {
+        let set: Set<UInt64> = [1, 2, 3]
+
+        XCTAssertEqual(rust_reflect_hashset_u64(set), set)
+    }
+
+    /// Verify that we can pass a Set of Strings to Rust and get it back.
+    func testReflectHashSetOfStrings() throws {
+        let set: Set<String> = ["hello", "world"]
+
+        XCTAssertEqual(rust_reflect_hashset_string(set), set)
+    }
+
+    /// Verify that a Set can be bridged to and from a BTreeSet.
+    func testReflectBTreeSet() throws {
+        let set: Set<Int32> = [-5, 0, 5]
+
+        XCTAssertEqual(rust_reflect_btreeset_i32(set), set)
+    }
+
+    /// Verify that we can receive an empty Set from Rust.
+    func testReturnEmptyHashSet() throws {
+        XCTAssertEqual(rust_return_empty_hashset(), Set())
+    }
+}
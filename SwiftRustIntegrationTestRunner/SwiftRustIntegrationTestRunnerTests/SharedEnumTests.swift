@@ -31,4 +31,83 @@ class SharedEnumTests: XCTestCase {
             fatalError()
         }
     }
+    
+    func testEnumWithData() {
+        let reflected = reflect_enum_with_data(EnumWithData.Named(a: 123, b: 1.5))
+        switch reflected {
+        case let .Named(a, b):
+            XCTAssertEqual(a, 123)
+            XCTAssertEqual(b, 1.5)
+        default:
+            fatalError()
+        }
+        
+        let reflectedUnnamed = reflect_enum_with_data(EnumWithData.Unnamed(456, true))
+        switch reflectedUnnamed {
+        case let .Unnamed(num, flag):
+            XCTAssertEqual(num, 456)
+            XCTAssertTrue(flag)
+        default:
+            fatalError()
+        }
+        
+        switch reflect_enum_with_data(EnumWithData.NoData) {
+        case .NoData:
+            break;
+        default:
+            fatalError()
+        }
+    }
+
+    /// Verify that the `#[swift_bridge(Hashable)]` attribute lets us use enums as dictionary keys.
+    func testHashableEnum() {
+        let noData: [EnumWithNoData: String] = [.Variant1: "one", .Variant2: "two"]
+        XCTAssertEqual(noData[reflect_enum_with_no_data(.Variant2)], "two")
+
+        let withData: Set<EnumWithData> = [.Unnamed(1, true), .Unnamed(1, true), .NoData]
+        XCTAssertEqual(withData.count, 2)
+        XCTAssert(withData.contains(reflect_enum_with_data(.NoData)))
+    }
+
+    /// Verify that explicit discriminants become the Swift enum's raw values.
+    func testEnumWithDiscriminants() {
+        XCTAssertEqual(EnumWithDiscriminants.Ok.rawValue, 0)
+        XCTAssertEqual(EnumWithDiscriminants.NotFound.rawValue, 404)
+        XCTAssertEqual(EnumWithDiscriminants.Gone.rawValue, 405)
+        XCTAssertEqual(EnumWithDiscriminants.Negative.rawValue, -1)
+
+        XCTAssertEqual(reflect_enum_with_discriminants(.Gone), .Gone)
+        XCTAssertEqual(EnumWithDiscriminants(rawValue: 404), .NotFound)
+    }
+
+    /// Verify that a transparent enum is passed to and from Rust as its raw value.
+    func testTransparentEnum() {
+        XCTAssertEqual(reflect_transparent_permission(.Write), .Write)
+        XCTAssertEqual(transparent_permission_as_i32(.Execute), 4)
+        XCTAssertEqual(TransparentPermission.Read.rawValue, 1)
+    }
+
+    /// Verify that the `#[swift_bridge(Comparable)]` attribute orders variants by their
+    /// discriminants instead of by the order that they were declared in.
+    func testComparableEnum() {
+        XCTAssert(ComparablePriority.Low < ComparablePriority.Medium)
+        XCTAssert(ComparablePriority.High < ComparablePriority.Low)
+        XCTAssertEqual(
+            [ComparablePriority.Medium, .Low, .High].sorted(),
+            [.High, .Low, .Medium]
+        )
+    }
+
+    /// Verify that the `#[swift_bridge(LocalizedError)]` attribute describes each variant using
+    /// its associated `String` or its name.
+    func testLocalizedErrorEnum() {
+        let notFound = reflect_download_error(.NotFound)
+        XCTAssertEqual(notFound.errorDescription, "NotFound")
+
+        let network = reflect_download_error(.Network("timed out".intoRustString()))
+        XCTAssertEqual(network.errorDescription, "timed out")
+
+        let error: Error = network
+        XCTAssertEqual(error.localizedDescription, "timed out")
+    }
 }
@@ -29,5 +29,53 @@ class SharedStructAttributeTests: XCTestCase {
             123
         )
     }
-}
+    
+    /// Verify that we can compare structs that use the `Equatable` attribute.
+    /// See crates/swift-integration-tests/src/struct_attributes/equatable.rs
+    func testSharedStructEquatable() throws {
+        let val = EquatableStruct(field: 1, optional: 2, inner: EquatableInnerStruct(field: 3))
+        
+        XCTAssertEqual(reflect_equatable_struct(val), val)
+        XCTAssertNotEqual(
+            val,
+            EquatableStruct(field: 1, optional: nil, inner: EquatableInnerStruct(field: 3))
+        )
+    }
+    
+    /// Verify that we can encode and decode structs that use the `Codable` attribute.
+    /// See crates/swift-integration-tests/src/struct_attributes/codable.rs
+    func testSharedStructCodable() throws {
+        let val = reflect_codable_struct(
+            CodableStruct(field: 1, optional: 2, inner: CodableInnerStruct(field: 3))
+        )
+        
+        let data = try JSONEncoder().encode(val)
+        let decoded = try JSONDecoder().decode(CodableStruct.self, from: data)
+        
+        XCTAssertEqual(decoded.field, 1)
+        XCTAssertEqual(decoded.optional, 2)
+        XCTAssertEqual(decoded.inner.field, 3)
+    }
 
+    /// Verify that structs that use the `Identifiable` attribute conform to `Identifiable`.
+    /// See crates/swift-integration-tests/src/struct_attributes/identifiable.rs
+    func testSharedStructIdentifiable() throws {
+        func getId<T: Identifiable>(_ val: T) -> T.ID {
+            val.id
+        }
+
+        let val = reflect_identifiable_struct(IdentifiableStruct(id: 5, field: 6))
+        XCTAssertEqual(getId(val), 5)
+
+        XCTAssertEqual(getId(IdentifiableStructCustomField(uuid: 7, field: 8)), 7)
+    }
+
+    /// Verify that structs that use the `rename_all = "camelCase"` attribute have camelCase fields.
+    /// See crates/swift-integration-tests/src/struct_attributes/rename_all.rs
+    func testSharedStructRenameAllCamelCase() throws {
+        let val = reflect_rename_all_struct(RenameAllStruct(userId: 5, isActive: true))
+
+        XCTAssertEqual(val.userId, 6)
+        XCTAssertEqual(val.isActive, false)
+    }
+}
@@ -42,4 +42,25 @@ class SharedStructTests: XCTestCase {
         XCTAssertEqual(val._0, reflected._0)
         XCTAssertEqual(val._1, reflected._1)
     }
+    
+    /// Verify that we can create a tuple struct using its positional initializer.
+    func testTupleStructPositionalInitializer() {
+        let val = StructReprStructTupleStruct(11, 22)
+        
+        XCTAssertEqual(val._0, 11)
+        XCTAssertEqual(val._1, 22)
+    }
+
+    /// Verify that a `swift_repr = "class"` struct has reference semantics in Swift and that
+    /// Rust hands back a new snapshot of it.
+    func testStructReprClass() {
+        let val = StructReprClass(named_field: 12)
+        let alias = val
+        alias.named_field = 34
+        XCTAssertEqual(val.named_field, 34)
+
+        let reflected = swift_calls_rust_struct_repr_class(val)
+        XCTAssertEqual(reflected.named_field, 34)
+        XCTAssertFalse(reflected === val)
+    }
 }
@@ -0,0 +1,44 @@
+//
+//  SliceTests.swift
+//  SwiftRustIntegrationTestRunnerTests
+//
+
+import XCTest
+@testable import SwiftRustIntegrationTestRunner
+
+class SliceTests: XCTestCase {
+    /// Verify that Rust can write into a buffer that Swift owns.
+    func testRustWritesIntoMutableSlice() throws {
+        var bytes: [UInt8] = [0, 0, 0, 0]
+
+        bytes.withUnsafeMutableBufferPointer { buffer in
+            rust_fill_mut_slice(buffer, 7)
+        }
+
+        XCTAssertEqual(bytes, [7, 7, 7, 7])
+        XCTAssertEqual(bytes.withUnsafeBufferPointer { rust_sum_slice($0) }, 28)
+    }
+
+    /// Verify that we can pass an empty buffer, whose base address is nil, to Rust.
+    func testEmptyMutableSlice() throws {
+        var bytes: [UInt8] = []
+
+        bytes.withUnsafeMutableBufferPointer { buffer in
+            rust_fill_mut_slice(buffer, 7)
+        }
+
+        XCTAssertEqual(bytes, [])
+    }
+
+    /// Verify that we can pass a Swift array of Strings to Rust as a `&[&str]`.
+    func testStrSliceArgument() throws {
+        XCTAssertEqual(rust_join_str_slice(["a", "", "bc"], "-").toString(), "a--bc")
+        XCTAssertEqual(rust_join_str_slice([], "-").toString(), "")
+    }
+
+    /// Verify that we can pass a Data to a Rust function that takes a `&[u8]`.
+    func testDataAsSliceArgument() throws {
+        XCTAssertEqual(rust_sum_data(Data([1, 2, 3])), 6)
+        XCTAssertEqual(rust_sum_data(Data()), 0)
+    }
+}
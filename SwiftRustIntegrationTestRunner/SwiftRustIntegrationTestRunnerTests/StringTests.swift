@@ -46,6 +46,29 @@ class StringTests: XCTestCase {
             "hi"
         )
     }
+    
+    /// Verify that we can pass both Swift `String`s and `RustString`s to a Rust function that
+    /// takes an owned `String`.
+    func testPassOwnedStringToRust() throws {
+        XCTAssertEqual(rust_reflect_string("hello").toString(), "hello")
+        XCTAssertEqual(rust_reflect_string("").toString(), "")
+
+        let rustString: RustString = create_string("world")
+        XCTAssertEqual(rust_reflect_string(rustString).toString(), "world")
+    }
+
+    /// Verify that we can return both borrowed and owned `Cow<str>`s from Rust.
+    func testRustCowStr() throws {
+        XCTAssertEqual(rust_cow_str(false), "borrowed")
+        XCTAssertEqual(rust_cow_str(true), "owned")
+        
+        XCTAssertEqual(CowStrHolder("hello").name(), "hello")
+    }
+
+    /// Verify that we can return a `Box<str>` from Rust.
+    func testRustBoxedStr() throws {
+        XCTAssertEqual(rust_boxed_str(), "boxed")
+    }
 }
 
 
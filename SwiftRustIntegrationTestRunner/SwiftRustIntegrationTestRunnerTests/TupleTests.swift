@@ -0,0 +1,39 @@
+//
+//  TupleTests.swift
+//  SwiftRustIntegrationTestRunnerTests
+//
+
+import XCTest
+@testable import SwiftRustIntegrationTestRunner
+
+class TupleTests: XCTestCase {
+    /// Verify that Rust can call a Swift function that takes and returns a tuple.
+    func testRustTests() throws {
+        runTupleTest()
+    }
+
+    /// Verify that we can pass a tuple of primitives to Rust and get one back.
+    func testReflectTuplePrimitives() throws {
+        let tuple = rust_reflect_tuple_primitives((1, -2, true))
+
+        XCTAssertEqual(tuple.0, 1)
+        XCTAssertEqual(tuple.1, -2)
+        XCTAssertEqual(tuple.2, true)
+    }
+
+    /// Verify that a tuple can hold a String.
+    func testTupleWithString() throws {
+        let tuple = rust_tuple_with_string((5, "hello"))
+
+        XCTAssertEqual(tuple.0, 6)
+        XCTAssertEqual(tuple.1.toString(), "HELLO")
+    }
+
+    /// Verify that a tuple can hold a shared struct.
+    func testSwapTupleWithStruct() throws {
+        let tuple = rust_swap_tuple_with_struct((TupleTestStruct(field: 3), 4))
+
+        XCTAssertEqual(tuple.0, 4)
+        XCTAssertEqual(tuple.1.field, 3)
+    }
+}
@@ -79,6 +79,122 @@ class VecTests: XCTestCase {
         XCTAssertEqual(reflected.len(), 1)
         XCTAssertEqual(reflected.get(index: 0)!.text().toString(), "hello world")
     }
+
+    /// Verify that an empty Vec<T> of opaque Rust types can cross the boundary.
+    func testReflectEmptyVecOfOpaqueRustType() throws {
+        let reflected = rust_reflect_vec_opaque_rust_type(RustVec())
+        XCTAssertEqual(reflected.len(), 0)
+        XCTAssertNil(reflected.pop())
+    }
+
+    /// Verify that dropping a RustVec of opaque Rust types frees the elements that are still
+    /// inside of it, but not the elements that were popped off.
+    func testDroppingVecOfOpaqueRustTypeFreesElements() throws {
+        let startCount = drop_counter_inside_vec_t_drop_count()
+
+        var popped: DropCounterInsideVecT? = nil
+        do {
+            let vec: RustVec<DropCounterInsideVecT> = RustVec()
+            vec.push(value: DropCounterInsideVecT())
+            vec.push(value: DropCounterInsideVecT())
+            vec.push(value: DropCounterInsideVecT())
+
+            popped = vec.pop()
+        }
+        XCTAssertNotNil(popped)
+        XCTAssertEqual(drop_counter_inside_vec_t_drop_count(), startCount + 2)
+
+        popped = nil
+        XCTAssertEqual(drop_counter_inside_vec_t_drop_count(), startCount + 3)
+    }
+
+    /// Verify that a returned Vec of shared structs becomes a Swift Array.
+    func testReturnVecOfSharedStruct() throws {
+        let array: [SharedStructInsideVecT] = rust_return_vec_of_shared_struct(3)
+
+        XCTAssertEqual(array.count, 3)
+        for (index, elem) in array.enumerated() {
+            XCTAssertEqual(elem.number, UInt32(index))
+            XCTAssertEqual(elem.text.toString(), "number \(index)")
+        }
+    }
+
+    /// Verify that an empty Vec of shared structs becomes an empty Swift Array.
+    func testReturnEmptyVecOfSharedStruct() throws {
+        XCTAssertEqual(rust_return_vec_of_shared_struct(0).count, 0)
+    }
+
+    /// Verify that a returned Vec<Vec<T>> becomes a nested Swift Array, including empty rows.
+    func testReturnJaggedVec() throws {
+        let rows: [[Double]] = rust_return_jagged_vec(3)
+
+        XCTAssertEqual(rows, [[], [0], [0, 1]])
+    }
+
+    /// Verify that an empty Vec<Vec<T>> becomes an empty Swift Array.
+    func testReturnEmptyJaggedVec() throws {
+        XCTAssertEqual(rust_return_jagged_vec(0), [])
+    }
+
+    /// Verify that a returned Option<Vec<T>> becomes an optional Swift Array, and that `None` is
+    /// not confused with an empty Vec.
+    func testReturnOptionVec() throws {
+        XCTAssertEqual(rust_return_option_vec(3), [0, 1, 2])
+        XCTAssertEqual(rust_return_option_vec(0), [])
+        XCTAssertNil(rust_return_option_vec(nil))
+    }
+
+    /// Verify that a returned Vec<Option<T>> becomes a Swift Array of optionals.
+    func testReturnVecOfOption() throws {
+        XCTAssertEqual(rust_return_vec_of_option(4), [0, nil, 2, nil])
+        XCTAssertEqual(rust_return_vec_of_option(0), [])
+    }
+
+    /// Verify that a returned VecDeque<T> becomes a Swift Array in front-to-back order, even if
+    /// the deque's ring buffer wrapped around.
+    func testReturnVecDeque() throws {
+        XCTAssertEqual(rust_return_wrapped_vec_deque(), [0, 1, 2, 3, 4])
+        XCTAssertEqual(rust_return_empty_vec_deque(), [])
+    }
+
+    /// Verify that a Swift Array can be passed to a Rust function that takes a Vec of primitives.
+    func testPassArrayAsVecOfPrimitives() throws {
+        XCTAssertEqual(rust_sum_vec([1, 2, 3]), 6)
+        XCTAssertEqual(rust_sum_vec([]), 0)
+        XCTAssertEqual(rust_count_true([true, false, true]), 2)
+    }
+
+    /// Verify that a Vec<u8> that is returned with `return_as = "Data"` becomes Swift `Data`.
+    func testReturnVecU8AsData() throws {
+        XCTAssertEqual(rust_return_vec_u8_as_data(3), Data([0, 1, 2]))
+        XCTAssertEqual(rust_return_vec_u8_as_data(0), Data())
+    }
+
+    /// Verify that a Vec<f32> that is returned with `return_as = "Array"` becomes a Swift `[Float]`.
+    func testReturnVecF32AsArray() throws {
+        let array: [Float] = rust_return_vec_f32_as_array(3)
+        XCTAssertEqual(array, [0.0, 0.5, 1.0])
+        XCTAssertEqual(rust_return_vec_f32_as_array(0), [])
+    }
+
+    /// Verify that pushing to a `&mut Vec<T>` handle from Swift mutates the Vec that Rust owns,
+    /// and that Rust's pushes are visible through the handle.
+    func testMutateRustOwnedVecThroughRefMut() throws {
+        let owner = RustVecOwner()
+
+        let numbers = owner.numbers_mut()
+        numbers.push(value: 1)
+        numbers.push(value: 2)
+        XCTAssertEqual(owner.numbers_sum(), 3)
+
+        let items: [UInt32] = [3, 4]
+        items.withUnsafeBufferPointer { items in
+            rust_push_all(numbers, items)
+        }
+        XCTAssertEqual(numbers.len(), 4)
+        XCTAssertEqual(numbers.pop(), 4)
+        XCTAssertEqual(owner.numbers_sum(), 6)
+    }
     
     /// Verify that we can construct a RustVec of every primitive type.
     /// We tested all of the methods on  two different primitives above to be sure that our
@@ -9,6 +9,7 @@ fn main() {
         let generated = swift_bridge_build::parse_bridges(vec![
             //
             manifest_dir().join("src/std_bridge/string.rs"),
+            manifest_dir().join("src/std_bridge/error.rs"),
         ]);
         let generated_swift = generated.concat_swift();
         let generated_c = generated.concat_c();
@@ -33,11 +34,36 @@ fn core_swift() -> String {
     let mut core_swift = "".to_string();
 
     core_swift += include_str!("src/std_bridge/string.swift");
+    // Swift only hands Rust `malloc`ed string buffers when Rust can take ownership of them.
+    // See `FfiString` in src/std_bridge/string.rs
+    core_swift += &format!(
+        "let __swift_bridge__uncheckedZeroCopyStrings = {}\n",
+        std::env::var("CARGO_FEATURE_UNCHECKED_ZERO_COPY_STRINGS").is_ok()
+    );
     core_swift += include_str!("src/std_bridge/rust_vec.swift");
+    core_swift += include_str!("src/std_bridge/hashmap.swift");
+    core_swift += include_str!("src/std_bridge/set.swift");
+    core_swift += include_str!("src/std_bridge/vec.swift");
+    core_swift += include_str!("src/std_bridge/boxed_fn.swift");
+    core_swift += include_str!("src/std_bridge/duration.swift");
+    core_swift += include_str!("src/std_bridge/date_time.swift");
+    core_swift += include_str!("src/std_bridge/int128.swift");
+    core_swift += include_str!("src/std_bridge/net.swift");
+    core_swift += include_str!("src/std_bridge/error.swift");
+    core_swift += include_str!("src/std_bridge/async_support.swift");
 
     for path in vec![
         "src/std_bridge/string.swift",
         "src/std_bridge/rust_vec.swift",
+        "src/std_bridge/hashmap.swift",
+        "src/std_bridge/set.swift",
+        "src/std_bridge/vec.swift",
+        "src/std_bridge/boxed_fn.swift",
+        "src/std_bridge/duration.swift",
+        "src/std_bridge/int128.swift",
+        "src/std_bridge/net.swift",
+        "src/std_bridge/error.swift",
+        "src/std_bridge/async_support.swift",
     ] {
         println!(
             "cargo:rerun-if-changed={}",
@@ -70,9 +96,26 @@ fn core_c_header() -> String {
     let mut header = r#"#include <stdint.h>
 #include <stdbool.h> 
 typedef struct RustStr { uint8_t* const start; uintptr_t len; } RustStr;
+typedef struct __private__FfiString { uint8_t* ptr; uintptr_t len; uintptr_t cap; void* rust_string; } __private__FfiString;
+typedef struct __private__FfiCowStr { uint8_t* start; uintptr_t len; uintptr_t cap; bool is_owned; } __private__FfiCowStr;
+typedef struct __private__FfiBoxedStr { uint8_t* start; uintptr_t len; } __private__FfiBoxedStr;
 typedef struct __private__FfiSlice { void* const start; uintptr_t len; } __private__FfiSlice;
 typedef struct __private__PointerToSwiftType { void* ptr; } __private__RustHandleToSwiftType;
+typedef struct __private__FfiHashMap { void* const keys; void* const values; uintptr_t len; void* owner; } __private__FfiHashMap;
+typedef struct __private__FfiSet { void* const elems; uintptr_t len; void* owner; } __private__FfiSet;
+typedef struct __private__FfiVec { void* const elems; uintptr_t len; void* owner; } __private__FfiVec;
+typedef struct __private__FfiDuration { uint64_t secs; uint64_t nanos; } __private__FfiDuration;
+typedef struct __private__FfiDateTime { int64_t secs; uint32_t nanos; } __private__FfiDateTime;
+typedef struct __private__FfiU128 { uint64_t high; uint64_t low; } __private__FfiU128;
+typedef struct __private__FfiI128 { int64_t high; uint64_t low; } __private__FfiI128;
+typedef struct __private__FfiIpAddr { bool is_v6; uint8_t octets[16]; } __private__FfiIpAddr;
+typedef struct __private__FfiSocketAddr { struct __private__FfiIpAddr ip; uint16_t port; } __private__FfiSocketAddr;
 void* __swift_bridge__null_pointer(void);
+void __swift_bridge__$FfiHashMap$_free(struct __private__FfiHashMap map);
+void __swift_bridge__$FfiSet$_free(struct __private__FfiSet set);
+void __swift_bridge__$FfiVec$_free(struct __private__FfiVec vec);
+void __swift_bridge__$FfiCowStr$_free(struct __private__FfiCowStr cow);
+void __swift_bridge__$FfiBoxedStr$_free(struct __private__FfiBoxedStr boxed);
 
 typedef struct __private__OptionU8 { uint8_t val; bool is_some; } __private__OptionU8;
 typedef struct __private__OptionI8 { int8_t val; bool is_some; } __private__OptionI8;
@@ -108,6 +151,22 @@ typedef struct __private__OptionBool { bool val; bool is_some; } __private__Opti
         header += &vec_of_primitive_headers(rust_ty, c_ty);
     }
 
+    for (rust_ty, c_ty) in vec![
+        ("u8", "uint8_t"),
+        ("u16", "uint16_t"),
+        ("u32", "uint32_t"),
+        ("u64", "uint64_t"),
+        ("usize", "uintptr_t"),
+        //
+        ("i8", "int8_t"),
+        ("i16", "int16_t"),
+        ("i32", "int32_t"),
+        ("i64", "int64_t"),
+        ("isize", "intptr_t"),
+    ] {
+        header += &range_of_integer_header(rust_ty, c_ty);
+    }
+
     header
 }
 
@@ -125,6 +184,7 @@ fn vec_of_primitive_headers(rust_ty: &str, c_ty: &str) -> String {
     format!(
         r#"
 void* __swift_bridge__$Vec_{rust_ty}$new();
+void* __swift_bridge__$Vec_{rust_ty}$from_slice({c_ty} const * elems, uintptr_t len);
 void __swift_bridge__$Vec_{rust_ty}$_free(void* const vec);
 uintptr_t __swift_bridge__$Vec_{rust_ty}$len(void* const vec);
 void __swift_bridge__$Vec_{rust_ty}$push(void* const vec, {c_ty} val);
@@ -139,6 +199,21 @@ void __swift_bridge__$Vec_{rust_ty}$push(void* const vec, {c_ty} val);
     )
 }
 
+/// The FFI representation of a Range<T> or RangeInclusive<T> where T is an integer such as u8
+fn range_of_integer_header(rust_ty: &str, c_ty: &str) -> String {
+    let mut chars = rust_ty.chars();
+
+    // u8 -> U8, usize -> Usize, etc...
+    let capitalized_first_letter =
+        chars.next().unwrap().to_string().to_uppercase() + chars.as_str();
+
+    format!(
+        "typedef struct __private__FfiRange{ty} {{ {c_ty} start; {c_ty} end; bool inclusive; }} __private__FfiRange{ty};\n",
+        ty = capitalized_first_letter,
+        c_ty = c_ty
+    )
+}
+
 fn conform_to_vectorizable(swift_ty: &str, rust_ty: &str) -> String {
     format!(
         r#"
@@ -5,10 +5,18 @@
 
 mod package;
 pub use package::*;
-use std::path::Path;
-use swift_bridge_ir::{CodegenConfig, SwiftBridgeModule};
+
+mod swift_package_manifest;
+mod xcframework;
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+use swift_bridge_ir::{
+    CodegenConfig, SwiftBridgeModule, SwiftBridgeModuleAttr, SwiftBridgeModuleAttrs,
+};
+pub use swift_package_manifest::*;
 use syn::__private::ToTokens;
 use syn::{File, Item};
+pub use xcframework::*;
 
 /// Parse rust sources files for `#\[swift_bridge::bridge\]` headers and generate the corresponding
 /// Swift files.
@@ -21,7 +29,7 @@ pub fn parse_bridges(
         let rust_file: &Path = rust_file.as_ref();
 
         let file = std::fs::read_to_string(rust_file).unwrap();
-        let gen = match parse_file(&file) {
+        let mut gen = match parse_file(&file) {
             Ok(generated) => generated,
             Err(e) => {
                 // TODO: Return an error...
@@ -34,6 +42,7 @@ Error while parsing {:?}
                 )
             }
         };
+        gen.source_file = rust_file.to_path_buf();
 
         generated_code.generated.push(gen);
     }
@@ -84,6 +93,43 @@ impl GeneratedCode {
         .unwrap();
     }
 
+    /// Write the generated Swift for each bridge module to its own `{file_stem}_{module_name}.swift`
+    /// file and all of the generated C headers to a single header file.
+    ///
+    /// Splitting the Swift into one file per bridge module keeps Xcode from having to re-index
+    /// all of the generated Swift whenever one bridge module changes.
+    ///
+    /// A `mod ffi { ... }` in `src/users.rs` gets written to `users_ffi.swift`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if two bridge modules would be written to the same Swift file, such as two `mod ffi`
+    /// modules in `src/a/users.rs` and `src/b/users.rs`.
+    pub fn write_per_module(&self, swift_bridge_out_dir: impl AsRef<Path>, package_name: &str) {
+        let out = swift_bridge_out_dir.as_ref().join(package_name);
+        let _ = std::fs::create_dir_all(&out);
+
+        std::fs::write(out.join(format!("{}.h", package_name)), self.concat_c()).unwrap();
+        for (swift_file_name, swift) in self.swift_per_file_name() {
+            std::fs::write(out.join(swift_file_name), swift).unwrap();
+        }
+    }
+
+    /// Write all of the generated Objective-C headers to a single `{package_name}-ObjC.h` file.
+    ///
+    /// This header has the same declarations as the C header, but is annotated with
+    /// Objective-C nullability specifiers.
+    pub fn write_objc_header(&self, swift_bridge_out_dir: impl AsRef<Path>, package_name: &str) {
+        let out = swift_bridge_out_dir.as_ref().join(package_name);
+        let _ = std::fs::create_dir_all(&out);
+
+        std::fs::write(
+            out.join(format!("{}-ObjC.h", package_name)),
+            self.concat_objc(),
+        )
+        .unwrap();
+    }
+
     /// Concatenate all of the generated Swift code into one file.
     pub fn concat_swift(&self) -> String {
         let mut swift = "".to_string();
@@ -95,6 +141,57 @@ impl GeneratedCode {
         swift
     }
 
+    /// The generated Swift code for each bridge module, keyed by the Rust file that the bridge
+    /// module is in and the bridge module's name.
+    ///
+    /// `mod foo { ... }` in `src/lib.rs` -> ("src/lib.rs", "foo")
+    pub fn swift_per_module(&self) -> BTreeMap<(PathBuf, String), String> {
+        let mut swift_per_module = BTreeMap::new();
+
+        for gen in &self.generated {
+            for (module_name, swift) in &gen.swift_per_module {
+                swift_per_module.insert(
+                    (gen.source_file.clone(), module_name.clone()),
+                    swift.clone(),
+                );
+            }
+        }
+
+        swift_per_module
+    }
+
+    /// The generated Swift code for each bridge module, keyed by the name of the Swift file that
+    /// [`GeneratedCode::write_per_module`] writes it to.
+    fn swift_per_file_name(&self) -> BTreeMap<String, String> {
+        let mut swift_per_file_name = BTreeMap::new();
+        let mut source_per_file_name: BTreeMap<String, (PathBuf, String)> = BTreeMap::new();
+
+        for ((source_file, module_name), swift) in self.swift_per_module() {
+            let file_stem = source_file
+                .file_stem()
+                .map(|stem| stem.to_string_lossy().to_string())
+                .unwrap_or_default();
+            let swift_file_name = format!("{}_{}.swift", file_stem, module_name);
+
+            if let Some((other_file, other_module)) = source_per_file_name.insert(
+                swift_file_name.clone(),
+                (source_file.clone(), module_name.clone()),
+            ) {
+                panic!(
+                    r#"
+Bridge modules `{}` in {:?} and `{}` in {:?} would both be written to {}.
+Rename one of the modules or one of the files.
+"#,
+                    other_module, other_file, module_name, source_file, swift_file_name
+                )
+            }
+
+            swift_per_file_name.insert(swift_file_name, swift);
+        }
+
+        swift_per_file_name
+    }
+
     /// Concatenate all of the generated C code into one file.
     pub fn concat_c(&self) -> String {
         let mut c_header = "".to_string();
@@ -105,6 +202,17 @@ impl GeneratedCode {
 
         c_header
     }
+
+    /// Concatenate all of the generated Objective-C headers into one file.
+    pub fn concat_objc(&self) -> String {
+        let mut objc_header = "".to_string();
+
+        for gen in &self.generated {
+            objc_header += &gen.objc_header;
+        }
+
+        objc_header
+    }
 }
 
 fn parse_file(file: &str) -> syn::Result<GeneratedFromSwiftBridgeModule> {
@@ -112,19 +220,40 @@ fn parse_file(file: &str) -> syn::Result<GeneratedFromSwiftBridgeModule> {
 
     let mut generated = GeneratedFromSwiftBridgeModule {
         c_header: "".to_string(),
+        objc_header: "".to_string(),
         swift: "".to_string(),
+        swift_per_module: vec![],
+        source_file: PathBuf::new(),
     };
 
     for item in file.items {
         match item {
             Item::Mod(module) => {
+                let module_name = module.ident.clone();
+
                 // TODO: Move this check into the `impl Parse for SwiftBridgeModule`.. Modify our
                 //  tests in swift-bridge-ir to annotate modules with `#[swift_bridge::bridge]`
-                if module.attrs.iter().any(|a| {
+                let bridge_attr = module.attrs.iter().find(|a| {
                     let attrib = a.path.to_token_stream().to_string();
                     attrib == "swift_bridge :: bridge" || attrib == "swift_bridge_macro :: bridge"
-                }) {
-                    let module: SwiftBridgeModule = syn::parse2(module.to_token_stream())?;
+                });
+                if let Some(bridge_attr) = bridge_attr {
+                    let bridge_attr_args: SwiftBridgeModuleAttrs = if bridge_attr.tokens.is_empty()
+                    {
+                        SwiftBridgeModuleAttrs { attributes: vec![] }
+                    } else {
+                        bridge_attr.parse_args()?
+                    };
+
+                    let mut module: SwiftBridgeModule = syn::parse2(module.to_token_stream())?;
+
+                    // The Swift code and C headers need to link against the same symbols that the
+                    // `#[swift_bridge::bridge]` macro exports.
+                    for arg in bridge_attr_args.attributes {
+                        if let SwiftBridgeModuleAttr::SymbolPrefix(prefix) = arg {
+                            module.set_symbol_prefix(prefix.value());
+                        }
+                    }
 
                     let config = CodegenConfig {
                         crate_feature_lookup: Box::new(|feature_name| {
@@ -134,15 +263,25 @@ fn parse_file(file: &str) -> syn::Result<GeneratedFromSwiftBridgeModule> {
                             let env_var_name = format!("CARGO_FEATURE_{}", normalized_feature_name);
                             std::env::var(env_var_name).is_ok()
                         }),
+                        generate_objc_header: true,
+                        cpp_header: None,
+                        library_evolution: false,
                     };
                     let swift_and_c = module.generate_swift_code_and_c_header(config);
 
                     generated.c_header += &swift_and_c.c_header;
                     generated.c_header += "\n\n";
 
-                    let swift = &swift_and_c.swift;
+                    if let Some(objc_header) = &swift_and_c.objc_header {
+                        generated.objc_header += objc_header;
+                        generated.objc_header += "\n\n";
+                    }
+
+                    let swift = format!("{}\n\n", swift_and_c.swift);
                     generated.swift += &swift;
-                    generated.swift += "\n\n";
+                    generated
+                        .swift_per_module
+                        .push((module_name.to_string(), swift));
                 }
             }
             _ => {}
@@ -155,5 +294,103 @@ fn parse_file(file: &str) -> syn::Result<GeneratedFromSwiftBridgeModule> {
 #[derive(Debug)]
 struct GeneratedFromSwiftBridgeModule {
     c_header: String,
+    objc_header: String,
     swift: String,
+    /// The Swift code for each bridge module in the file, along with the module's name.
+    swift_per_module: Vec<(String, String)>,
+    /// The Rust file that the bridge modules were parsed from.
+    source_file: PathBuf,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Verify that each bridge module's Swift code gets its own entry, keyed by the module's file
+    /// and name, while the concatenated Swift still contains all of the modules.
+    #[test]
+    fn swift_per_module() {
+        let file = r#"
+#[swift_bridge::bridge]
+mod first {
+    extern "Rust" {
+        fn first_function();
+    }
+}
+
+#[swift_bridge::bridge]
+mod second {
+    extern "Rust" {
+        fn second_function();
+    }
+}
+"#;
+
+        let mut gen = parse_file(file).unwrap();
+        gen.source_file = PathBuf::from("src/lib.rs");
+        let generated = GeneratedCode {
+            generated: vec![gen],
+        };
+        let swift_per_module = generated.swift_per_module();
+
+        let first = &swift_per_module[&(PathBuf::from("src/lib.rs"), "first".to_string())];
+        let second = &swift_per_module[&(PathBuf::from("src/lib.rs"), "second".to_string())];
+
+        assert_eq!(swift_per_module.len(), 2);
+        assert!(first.contains("func first_function"));
+        assert!(!first.contains("func second_function"));
+        assert!(second.contains("func second_function"));
+        assert!(!second.contains("func first_function"));
+
+        assert_eq!(generated.concat_swift(), format!("{}{}", first, second));
+    }
+
+    /// Verify that bridge modules that have the same name but live in different files get
+    /// written to different Swift files.
+    #[test]
+    fn swift_per_file_name_for_modules_with_the_same_name() {
+        let generated = GeneratedCode {
+            generated: vec![
+                parse_ffi_module_in("src/users.rs", "fn user_function();"),
+                parse_ffi_module_in("src/posts.rs", "fn post_function();"),
+            ],
+        };
+        let swift_per_file_name = generated.swift_per_file_name();
+
+        assert_eq!(swift_per_file_name.len(), 2);
+        assert!(swift_per_file_name["users_ffi.swift"].contains("func user_function"));
+        assert!(swift_per_file_name["posts_ffi.swift"].contains("func post_function"));
+    }
+
+    /// Verify that we panic instead of silently merging two bridge modules into the same Swift
+    /// file.
+    #[test]
+    #[should_panic(expected = "would both be written to users_ffi.swift")]
+    fn swift_per_file_name_collision() {
+        let generated = GeneratedCode {
+            generated: vec![
+                parse_ffi_module_in("src/a/users.rs", "fn a_function();"),
+                parse_ffi_module_in("src/b/users.rs", "fn b_function();"),
+            ],
+        };
+        generated.swift_per_file_name();
+    }
+
+    fn parse_ffi_module_in(source_file: &str, rust_fn: &str) -> GeneratedFromSwiftBridgeModule {
+        let file = format!(
+            r#"
+#[swift_bridge::bridge]
+mod ffi {{
+    extern "Rust" {{
+        {}
+    }}
+}}
+"#,
+            rust_fn
+        );
+
+        let mut gen = parse_file(&file).unwrap();
+        gen.source_file = PathBuf::from(source_file);
+        gen
+    }
 }
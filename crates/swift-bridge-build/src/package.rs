@@ -80,6 +80,41 @@ impl ApplePlatform {
         }
     }
 
+    /// The platform's name in a `Package.swift`'s `platforms` list.
+    ///
+    /// Returns None for platforms that a `Package.swift` can't declare, such as the simulators,
+    /// which share their device's minimum version.
+    pub fn swift_package_platform(&self) -> Option<&str> {
+        match self {
+            ApplePlatform::IOS => Some("iOS"),
+            ApplePlatform::MacOS => Some("macOS"),
+            ApplePlatform::MacCatalyst => Some("macCatalyst"),
+            ApplePlatform::TvOS => Some("tvOS"),
+            ApplePlatform::WatchOS => Some("watchOS"),
+            ApplePlatform::Simulator
+            | ApplePlatform::WatchOSSimulator
+            | ApplePlatform::CarPlayOS
+            | ApplePlatform::CarPlayOSSimulator => None,
+        }
+    }
+
+    /// The platform and platform variant that an xcframework's `Info.plist` lists for a library
+    /// built for the specified platform. e.g. `("ios", Some("simulator"))`
+    ///
+    /// Returns None for platforms that xcframeworks don't support.
+    pub fn xcframework_platform(&self) -> Option<(&'static str, Option<&'static str>)> {
+        match self {
+            ApplePlatform::IOS => Some(("ios", None)),
+            ApplePlatform::Simulator => Some(("ios", Some("simulator"))),
+            ApplePlatform::MacOS => Some(("macos", None)),
+            ApplePlatform::MacCatalyst => Some(("ios", Some("maccatalyst"))),
+            ApplePlatform::TvOS => Some(("tvos", None)),
+            ApplePlatform::WatchOS => Some(("watchos", None)),
+            ApplePlatform::WatchOSSimulator => Some(("watchos", Some("simulator"))),
+            ApplePlatform::CarPlayOS | ApplePlatform::CarPlayOSSimulator => None,
+        }
+    }
+
     /// Array containing all `ApplePlatform` variants
     pub const ALL: &'static [Self] = &[
         ApplePlatform::IOS,
@@ -0,0 +1,296 @@
+//! Generate a `Package.swift` and a `module.modulemap` that point at a Rust library's build output.
+
+use crate::ApplePlatform;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Config for generating a Swift Package manifest from the output of a `cargo build`.
+///
+/// Unlike [`create_package`](crate::create_package) this does not bundle the static library into
+/// an xcframework. The module map links against the static library where cargo left it, so
+/// this is meant for local development.
+pub struct SwiftPackageManifestConfig {
+    /// The name that was passed to `write_all_concatenated`. Usually `env!("CARGO_PKG_NAME")`.
+    pub crate_name: String,
+    /// The name of the Swift Package and of the library product that it exposes.
+    pub product_name: String,
+    /// The directory containing the generated bridges
+    ///
+    /// Relative paths are resolved against the current directory.
+    pub bridge_dir: PathBuf,
+    /// The directory containing the static library. e.g. `target/aarch64-apple-ios/debug`
+    ///
+    /// Relative paths are resolved against the current directory.
+    pub lib_dir: PathBuf,
+    /// The directory where the package will be saved
+    pub out_dir: PathBuf,
+    /// Minimum version per platform. e.g. `(ApplePlatform::IOS, "13.0".to_string())`
+    ///
+    /// Simulator platforms share their device's minimum version, so they get skipped.
+    pub platforms: Vec<(ApplePlatform, String)>,
+}
+
+impl SwiftPackageManifestConfig {
+    /// The name of the Clang module that exposes the generated C headers to Swift.
+    ///
+    /// MyProduct -> MyProductFFI
+    fn ffi_module_name(&self) -> String {
+        format!("{}FFI", self.product_name)
+    }
+
+    /// The library name that the linker looks for. Cargo replaces dashes with underscores when
+    /// naming the static library, so `my-rust-lib` gets built into `libmy_rust_lib.a`.
+    fn link_name(&self) -> String {
+        self.crate_name.replace('-', "_")
+    }
+}
+
+/// Writes a `Package.swift` and a `module.modulemap` for the Rust library to the output directory,
+/// along with the generated Swift files.
+///
+/// ```text
+/// out_dir
+/// ├── Package.swift
+/// └── Sources
+///     ├── MyProduct
+///     │   ├── SwiftBridgeCore.swift
+///     │   └── my-rust-lib.swift
+///     └── MyProductFFI
+///         └── module.modulemap
+/// ```
+pub fn write_swift_package_manifest(config: &SwiftPackageManifestConfig) {
+    let output_dir: &Path = config.out_dir.as_ref();
+
+    let ffi_module_dir = output_dir.join("Sources").join(config.ffi_module_name());
+    fs::create_dir_all(&ffi_module_dir).expect("Couldn't create directory for module map");
+    fs::write(
+        ffi_module_dir.join("module.modulemap"),
+        generate_module_map(config),
+    )
+    .expect("Couldn't write module.modulemap file");
+
+    // The Swift files need to be inside of the package, and they need to import the headers that
+    // our module map exposes.
+    let sources_dir = output_dir.join("Sources").join(&config.product_name);
+    fs::create_dir_all(&sources_dir).expect("Couldn't create directory for source files");
+
+    let bridge_dir: &Path = config.bridge_dir.as_ref();
+    let swift_file_name = format!("{}.swift", config.crate_name);
+    for (from, file_name) in [
+        (
+            bridge_dir.join("SwiftBridgeCore.swift"),
+            "SwiftBridgeCore.swift",
+        ),
+        (
+            bridge_dir.join(&config.crate_name).join(&swift_file_name),
+            swift_file_name.as_str(),
+        ),
+    ] {
+        let swift = fs::read_to_string(&from)
+            .unwrap_or_else(|_| panic!("Couldn't read generated Swift file {:?}", from));
+        fs::write(
+            sources_dir.join(file_name),
+            format!("import {}\n{}", config.ffi_module_name(), swift),
+        )
+        .unwrap_or_else(|_| panic!("Couldn't write {} to the package", file_name));
+    }
+
+    fs::write(
+        output_dir.join("Package.swift"),
+        generate_package_swift(config),
+    )
+    .expect("Couldn't write Package.swift file");
+}
+
+/// Generate the contents of the `Package.swift` file.
+pub fn generate_package_swift(config: &SwiftPackageManifestConfig) -> String {
+    let product_name = &config.product_name;
+    let ffi_module_name = config.ffi_module_name();
+
+    let platforms: Vec<String> = config
+        .platforms
+        .iter()
+        .filter_map(|(platform, version)| {
+            platform
+                .swift_package_platform()
+                .map(|platform| format!("\t\t.{}(\"{}\"),\n", platform, version))
+        })
+        .collect();
+    let platforms = if platforms.is_empty() {
+        "".to_string()
+    } else {
+        format!("\tplatforms: [\n{}\t],\n", platforms.join(""))
+    };
+
+    format!(
+        r#"// swift-tools-version:5.5.0
+import PackageDescription
+let package = Package(
+	name: "{product_name}",
+{platforms}	products: [
+		.library(
+			name: "{product_name}",
+			targets: ["{product_name}"]),
+	],
+	dependencies: [],
+	targets: [
+		.systemLibrary(
+			name: "{ffi_module_name}",
+			path: "Sources/{ffi_module_name}"
+		),
+		.target(
+			name: "{product_name}",
+			dependencies: ["{ffi_module_name}"],
+			linkerSettings: [.unsafeFlags(["-L{lib_dir}"])])
+	]
+)
+"#,
+        product_name = product_name,
+        platforms = platforms,
+        ffi_module_name = ffi_module_name,
+        lib_dir = absolute_path(&config.lib_dir).display(),
+    )
+}
+
+/// Generate the contents of the `module.modulemap` file.
+pub fn generate_module_map(config: &SwiftPackageManifestConfig) -> String {
+    let bridge_dir = absolute_path(&config.bridge_dir);
+    let core_header = bridge_dir.join("SwiftBridgeCore.h");
+    let crate_header = bridge_dir
+        .join(&config.crate_name)
+        .join(format!("{}.h", config.crate_name));
+
+    format!(
+        r#"module {ffi_module_name} {{
+    header "{core_header}"
+    header "{crate_header}"
+    link "{link_name}"
+    export *
+}}
+"#,
+        ffi_module_name = config.ffi_module_name(),
+        core_header = core_header.display(),
+        crate_header = crate_header.display(),
+        link_name = config.link_name(),
+    )
+}
+
+/// The module map and the manifest get read by Swift from within the package, so the paths that
+/// they point to can't be relative to the directory that we were run from.
+///
+/// Paths that don't exist yet, such as a library directory that hasn't been built into, get joined
+/// onto the current directory instead of getting canonicalized.
+fn absolute_path(path: &Path) -> PathBuf {
+    fs::canonicalize(path).unwrap_or_else(|_| {
+        std::env::current_dir()
+            .expect("Couldn't get the current directory")
+            .join(path)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn config() -> SwiftPackageManifestConfig {
+        SwiftPackageManifestConfig {
+            crate_name: "my-rust-lib".to_string(),
+            product_name: "MyRustLib".to_string(),
+            bridge_dir: PathBuf::from("/project/generated"),
+            lib_dir: PathBuf::from("/project/target/debug"),
+            out_dir: PathBuf::from("/project/MyRustLib"),
+            platforms: vec![
+                (ApplePlatform::IOS, "13.0".to_string()),
+                (ApplePlatform::Simulator, "13.0".to_string()),
+                (ApplePlatform::MacOS, "10.15".to_string()),
+            ],
+        }
+    }
+
+    /// Verify that the manifest declares the product, the module map's system library target and
+    /// the Swift target that depends on it.
+    #[test]
+    fn package_swift_targets() {
+        let package_swift = generate_package_swift(&config());
+
+        assert!(package_swift.contains(r#"name: "MyRustLib","#));
+        assert!(package_swift.contains(
+            r#"		.library(
+			name: "MyRustLib",
+			targets: ["MyRustLib"]),"#
+        ));
+        assert!(package_swift.contains(
+            r#"	targets: [
+		.systemLibrary(
+			name: "MyRustLibFFI",
+			path: "Sources/MyRustLibFFI"
+		),
+		.target(
+			name: "MyRustLib",
+			dependencies: ["MyRustLibFFI"],
+			linkerSettings: [.unsafeFlags(["-L/project/target/debug"])])
+	]"#
+        ));
+    }
+
+    /// Verify that we emit the minimum platform versions, skipping simulators.
+    #[test]
+    fn package_swift_platforms() {
+        let package_swift = generate_package_swift(&config());
+
+        assert!(package_swift.contains(
+            r#"	platforms: [
+		.iOS("13.0"),
+		.macOS("10.15"),
+	],"#
+        ));
+    }
+
+    /// Verify that we do not emit a `platforms` list if no platforms were provided.
+    #[test]
+    fn package_swift_without_platforms() {
+        let mut config = config();
+        config.platforms = vec![];
+
+        assert!(!generate_package_swift(&config).contains("platforms"));
+    }
+
+    /// Verify that the module map includes the generated headers and links the static library.
+    #[test]
+    fn module_map_includes_headers() {
+        let module_map = generate_module_map(&config());
+
+        assert_eq!(
+            module_map,
+            r#"module MyRustLibFFI {
+    header "/project/generated/SwiftBridgeCore.h"
+    header "/project/generated/my-rust-lib/my-rust-lib.h"
+    link "my_rust_lib"
+    export *
+}
+"#
+        );
+    }
+
+    /// Verify that relative bridge and library directories get written as absolute paths.
+    #[test]
+    fn relative_dirs_become_absolute() {
+        let mut config = config();
+        config.bridge_dir = PathBuf::from("generated");
+        config.lib_dir = PathBuf::from("target/aarch64-apple-ios/debug");
+
+        let current_dir = std::env::current_dir().unwrap();
+
+        assert!(generate_module_map(&config).contains(&format!(
+            r#"header "{}""#,
+            current_dir
+                .join("generated")
+                .join("SwiftBridgeCore.h")
+                .display()
+        )));
+        assert!(generate_package_swift(&config).contains(&format!(
+            r#"-L{}""#,
+            current_dir.join("target/aarch64-apple-ios/debug").display()
+        )));
+    }
+}
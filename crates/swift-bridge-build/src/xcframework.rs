@@ -0,0 +1,320 @@
+//! Lay out an `.xcframework` from per-target static libraries and the generated headers.
+
+use crate::ApplePlatform;
+use std::collections::{BTreeMap, HashMap};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+/// Config for writing an xcframework from the output of `cargo build`.
+///
+/// Unlike [`create_package`](crate::create_package) this writes the xcframework's directory
+/// structure and `Info.plist` itself instead of calling `xcodebuild -create-xcframework`.
+pub struct XcframeworkConfig {
+    /// The name of the xcframework, without the `.xcframework` extension.
+    pub name: String,
+    /// The directory containing the headers and the `module.modulemap` that every library in the
+    /// xcframework exposes.
+    pub headers_dir: PathBuf,
+    /// Static library per Rust target.
+    /// e.g. `("aarch64-apple-ios-sim", "target/aarch64-apple-ios-sim/debug/libmy_rust_lib.a")`
+    ///
+    /// Targets that share a platform, such as `aarch64-apple-ios-sim` and `x86_64-apple-ios`,
+    /// get combined into one universal library using `lipo`.
+    pub libraries: HashMap<String, PathBuf>,
+    /// The directory where the xcframework will be saved
+    pub out_dir: PathBuf,
+}
+
+/// The libraries for one platform, such as the iOS simulator, which an xcframework keeps in a
+/// directory named after the slice's library identifier.
+struct XcframeworkSlice {
+    platform: &'static str,
+    platform_variant: Option<&'static str>,
+    architectures: Vec<&'static str>,
+    libraries: Vec<PathBuf>,
+}
+
+impl XcframeworkSlice {
+    /// ios-arm64_x86_64-simulator
+    fn library_identifier(&self) -> String {
+        let mut identifier = format!("{}-{}", self.platform, self.architectures.join("_"));
+        if let Some(variant) = self.platform_variant {
+            identifier += "-";
+            identifier += variant;
+        }
+        identifier
+    }
+
+    /// libmy_rust_lib.a
+    fn library_path(&self) -> &str {
+        self.libraries[0].file_name().unwrap().to_str().unwrap()
+    }
+}
+
+/// The platform and architecture of a Rust target.
+///
+/// aarch64-apple-ios-sim -> (ApplePlatform::Simulator, "arm64")
+fn apple_target(target: &str) -> Option<(ApplePlatform, &'static str)> {
+    let target = match target {
+        "aarch64-apple-ios" => (ApplePlatform::IOS, "arm64"),
+        "aarch64-apple-ios-sim" => (ApplePlatform::Simulator, "arm64"),
+        "x86_64-apple-ios" => (ApplePlatform::Simulator, "x86_64"),
+        "aarch64-apple-darwin" => (ApplePlatform::MacOS, "arm64"),
+        "x86_64-apple-darwin" => (ApplePlatform::MacOS, "x86_64"),
+        "aarch64-apple-ios-macabi" => (ApplePlatform::MacCatalyst, "arm64"),
+        "x86_64-apple-ios-macabi" => (ApplePlatform::MacCatalyst, "x86_64"),
+        "aarch64-apple-tvos" => (ApplePlatform::TvOS, "arm64"),
+        "arm64_32-apple-watchos" => (ApplePlatform::WatchOS, "arm64_32"),
+        "aarch64-apple-watchos-sim" => (ApplePlatform::WatchOSSimulator, "arm64"),
+        "x86_64-apple-watchos-sim" => (ApplePlatform::WatchOSSimulator, "x86_64"),
+        _ => return None,
+    };
+    Some(target)
+}
+
+/// Group the libraries by platform, sorted by library identifier so that the output does not
+/// depend on the `HashMap`'s iteration order.
+fn slices(config: &XcframeworkConfig) -> Vec<XcframeworkSlice> {
+    let mut slices: BTreeMap<(&str, Option<&str>), XcframeworkSlice> = BTreeMap::new();
+
+    let mut targets: Vec<(&String, &PathBuf)> = config.libraries.iter().collect();
+    targets.sort();
+
+    for (target, library) in targets {
+        let (platform, arch) = apple_target(target)
+            .unwrap_or_else(|| panic!("Unsupported target for an xcframework: {}", target));
+        let (platform, platform_variant) = platform.xcframework_platform().unwrap();
+
+        let slice = slices
+            .entry((platform, platform_variant))
+            .or_insert_with(|| XcframeworkSlice {
+                platform,
+                platform_variant,
+                architectures: vec![],
+                libraries: vec![],
+            });
+        slice.architectures.push(arch);
+        slice.libraries.push(library.clone());
+    }
+
+    let mut slices: Vec<XcframeworkSlice> = slices.into_values().collect();
+    for slice in slices.iter_mut() {
+        slice.architectures.sort();
+    }
+    slices.sort_by_key(|slice| slice.library_identifier());
+    slices
+}
+
+/// Writes the xcframework to `out_dir/{name}.xcframework`, replacing any previous one.
+///
+/// ```text
+/// MyRustLib.xcframework
+/// ├── Info.plist
+/// ├── ios-arm64
+/// │   ├── Headers
+/// │   │   ├── SwiftBridgeCore.h
+/// │   │   ├── module.modulemap
+/// │   │   └── my-rust-lib.h
+/// │   └── libmy_rust_lib.a
+/// └── ios-arm64_x86_64-simulator
+///     ├── Headers
+///     │   └── ...
+///     └── libmy_rust_lib.a
+/// ```
+pub fn create_xcframework(config: &XcframeworkConfig) {
+    let output_dir: &Path = config.out_dir.as_ref();
+    let xcframework_dir = output_dir.join(format!("{}.xcframework", config.name));
+    if xcframework_dir.exists() {
+        fs::remove_dir_all(&xcframework_dir).expect("Couldn't delete previous xcframework");
+    }
+    fs::create_dir_all(&xcframework_dir).expect("Couldn't create directory for xcframework");
+
+    for slice in slices(config) {
+        let slice_dir = xcframework_dir.join(slice.library_identifier());
+
+        let headers_dir = slice_dir.join("Headers");
+        fs::create_dir_all(&headers_dir).expect("Couldn't create headers directory");
+        for file in fs::read_dir(&config.headers_dir).expect("Couldn't read headers directory") {
+            let file = file.unwrap().path();
+            if file.is_file() {
+                fs::copy(&file, headers_dir.join(file.file_name().unwrap()))
+                    .unwrap_or_else(|_| panic!("Couldn't copy header {:?}", file));
+            }
+        }
+
+        let library_path = slice_dir.join(slice.library_path());
+        if slice.libraries.len() == 1 {
+            fs::copy(&slice.libraries[0], &library_path).unwrap_or_else(|_| {
+                panic!("Couldn't copy library {:?}", slice.library_identifier())
+            });
+        } else {
+            let output = Command::new("lipo")
+                .arg("-create")
+                .args(&slice.libraries)
+                .arg("-output")
+                .arg(&library_path)
+                .output()
+                .expect("Failed to execute lipo");
+            if !output.status.success() {
+                let stderr = std::str::from_utf8(&output.stderr).unwrap();
+                panic!("{}", stderr);
+            }
+        }
+    }
+
+    fs::write(
+        xcframework_dir.join("Info.plist"),
+        generate_xcframework_info_plist(config),
+    )
+    .expect("Couldn't write Info.plist file");
+}
+
+/// Generate the contents of the xcframework's `Info.plist` file.
+pub fn generate_xcframework_info_plist(config: &XcframeworkConfig) -> String {
+    let available_libraries: String = slices(config)
+        .iter()
+        .map(|slice| {
+            let architectures: String = slice
+                .architectures
+                .iter()
+                .map(|arch| format!("\t\t\t\t<string>{}</string>\n", arch))
+                .collect();
+            let platform_variant = match slice.platform_variant {
+                Some(variant) => format!(
+                    "\t\t\t<key>SupportedPlatformVariant</key>\n\t\t\t<string>{}</string>\n",
+                    variant
+                ),
+                None => "".to_string(),
+            };
+
+            format!(
+                r#"		<dict>
+			<key>HeadersPath</key>
+			<string>Headers</string>
+			<key>LibraryIdentifier</key>
+			<string>{library_identifier}</string>
+			<key>LibraryPath</key>
+			<string>{library_path}</string>
+			<key>SupportedArchitectures</key>
+			<array>
+{architectures}			</array>
+			<key>SupportedPlatform</key>
+			<string>{platform}</string>
+{platform_variant}		</dict>
+"#,
+                library_identifier = slice.library_identifier(),
+                library_path = slice.library_path(),
+                architectures = architectures,
+                platform = slice.platform,
+                platform_variant = platform_variant,
+            )
+        })
+        .collect();
+
+    format!(
+        r#"<?xml version="1.0" encoding="UTF-8"?>
+<!DOCTYPE plist PUBLIC "-//Apple//DTD PLIST 1.0//EN" "http://www.apple.com/DTDs/PropertyList-1.0.dtd">
+<plist version="1.0">
+<dict>
+	<key>AvailableLibraries</key>
+	<array>
+{available_libraries}	</array>
+	<key>CFBundlePackageType</key>
+	<string>XFWK</string>
+	<key>XCFrameworkFormatVersion</key>
+	<string>1.0</string>
+</dict>
+</plist>
+"#,
+        available_libraries = available_libraries,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn config() -> XcframeworkConfig {
+        XcframeworkConfig {
+            name: "MyRustLib".to_string(),
+            headers_dir: PathBuf::from("/project/include"),
+            libraries: HashMap::from([
+                (
+                    "aarch64-apple-ios".to_string(),
+                    PathBuf::from("/project/target/aarch64-apple-ios/debug/libmy_rust_lib.a"),
+                ),
+                (
+                    "aarch64-apple-ios-sim".to_string(),
+                    PathBuf::from("/project/target/aarch64-apple-ios-sim/debug/libmy_rust_lib.a"),
+                ),
+                (
+                    "x86_64-apple-ios".to_string(),
+                    PathBuf::from("/project/target/x86_64-apple-ios/debug/libmy_rust_lib.a"),
+                ),
+                (
+                    "aarch64-apple-darwin".to_string(),
+                    PathBuf::from("/project/target/aarch64-apple-darwin/debug/libmy_rust_lib.a"),
+                ),
+            ]),
+            out_dir: PathBuf::from("/project/out"),
+        }
+    }
+
+    /// Verify that the Info.plist lists a library per platform, combining the simulator targets
+    /// into a single library.
+    #[test]
+    fn info_plist_library_identifiers() {
+        let info_plist = generate_xcframework_info_plist(&config());
+
+        let identifiers: Vec<&str> = info_plist
+            .split("<key>LibraryIdentifier</key>\n\t\t\t<string>")
+            .skip(1)
+            .map(|rest| rest.split("</string>").next().unwrap())
+            .collect();
+        assert_eq!(
+            identifiers,
+            vec!["ios-arm64", "ios-arm64_x86_64-simulator", "macos-arm64"]
+        );
+    }
+
+    /// Verify that we emit the full entry for a simulator library.
+    #[test]
+    fn info_plist_simulator_library() {
+        let info_plist = generate_xcframework_info_plist(&config());
+
+        assert!(info_plist.contains(
+            r#"		<dict>
+			<key>HeadersPath</key>
+			<string>Headers</string>
+			<key>LibraryIdentifier</key>
+			<string>ios-arm64_x86_64-simulator</string>
+			<key>LibraryPath</key>
+			<string>libmy_rust_lib.a</string>
+			<key>SupportedArchitectures</key>
+			<array>
+				<string>arm64</string>
+				<string>x86_64</string>
+			</array>
+			<key>SupportedPlatform</key>
+			<string>ios</string>
+			<key>SupportedPlatformVariant</key>
+			<string>simulator</string>
+		</dict>
+"#
+        ));
+    }
+
+    /// Verify that device libraries don't have a platform variant.
+    #[test]
+    fn info_plist_device_library() {
+        let info_plist = generate_xcframework_info_plist(&config());
+
+        assert!(info_plist.contains(
+            r#"			<key>SupportedPlatform</key>
+			<string>ios</string>
+		</dict>
+"#
+        ));
+    }
+}
@@ -1,6 +1,6 @@
 use proc_macro2::Ident;
 use syn::parse::{Parse, ParseStream};
-use syn::{Path, Token};
+use syn::{LitStr, Path, Token};
 
 /// The `...` in
 /// `#\[swift_bridge::bridge(...)\]`
@@ -15,6 +15,9 @@ pub enum SwiftBridgeModuleAttr {
     /// as `RustString`.
     /// `#\[swift_bridge::bridge(swift_bridge_path = swift_bridge)\]`
     SwiftBridgePath(Path),
+    /// Sets the prefix of the generated symbols, which defaults to `__swift_bridge__`.
+    /// `#\[swift_bridge::bridge(symbol_prefix = "__my_crate__")\]`
+    SymbolPrefix(LitStr),
 }
 
 impl Parse for SwiftBridgeModuleAttrs {
@@ -38,6 +41,7 @@ impl Parse for SwiftBridgeModuleAttr {
 
         let attr = match key.to_string().as_str() {
             "swift_bridge_path" => SwiftBridgeModuleAttr::SwiftBridgePath(input.parse()?),
+            "symbol_prefix" => SwiftBridgeModuleAttr::SymbolPrefix(input.parse()?),
             _ => {
                 return Err(syn::Error::new(input.span(), "Unknown attribute."));
             }
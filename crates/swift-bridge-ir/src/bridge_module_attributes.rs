@@ -1,15 +1,21 @@
-use proc_macro2::Ident;
+use proc_macro2::{Ident, TokenStream};
+use quote::{quote, ToTokens};
 use syn::parse::{Parse, ParseStream};
 use syn::LitStr;
 use syn::Token;
 
-/// A `cfg` attribute on a bridge module.
+/// A `cfg` attribute on a bridge module or on one of its functions.
 ///
 /// ```no_run
 /// #[swift_bridge::bridge]
 /// // This is a cfg attribute.
 /// #[cfg(feature = "some-feature")]
 /// mod ffi {
+///     extern "Rust" {
+///         // So is this.
+///         #[cfg(feature = "another-feature")]
+///         fn some_function();
+///     }
 /// }
 /// ```
 pub enum CfgAttr {
@@ -34,3 +40,15 @@ impl Parse for CfgAttr {
         }
     }
 }
+
+impl ToTokens for CfgAttr {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        match self {
+            CfgAttr::Feature(feature_name) => {
+                tokens.extend(quote! {
+                    #[cfg(feature = #feature_name)]
+                });
+            }
+        }
+    }
+}
@@ -5,16 +5,41 @@ use std::str::FromStr;
 use proc_macro2::{Ident, Span, TokenStream};
 use quote::ToTokens;
 use quote::{quote, quote_spanned};
-use syn::{FnArg, Pat, PatType, Path, ReturnType, Type};
+use syn::{
+    FnArg, GenericArgument, Lit, Pat, PatType, Path, PathArguments, ReturnType, Type,
+    TypeParamBound, TypePath,
+};
 
 use crate::parse::{HostLang, TypeDeclarations};
 use crate::SWIFT_BRIDGE_PREFIX;
 
+pub(crate) use self::bridged_array::{array_has_unsupported_elem_type, BridgedArray};
+pub(crate) use self::bridged_boxed_fn::BridgedBoxedFn;
+use self::bridged_hashmap::BridgedHashMap;
+pub(crate) use self::bridged_non_zero::BridgedNonZero;
 use self::bridged_option::BridgedOption;
+pub(crate) use self::bridged_range::unsupported_range_elem_type;
+use self::bridged_range::BridgedRange;
+pub(crate) use self::bridged_result::BridgedResult;
+use self::bridged_set::BridgedSet;
+pub(crate) use self::bridged_tuple::BridgedTuple;
+pub(crate) use self::opaque_generic_args::OpaqueGenericArgs;
 pub(crate) use self::shared_enum::{EnumVariant, SharedEnum};
-pub(crate) use self::shared_struct::{SharedStruct, StructFields, StructSwiftRepr};
-
+pub(crate) use self::shared_struct::{
+    snake_case_to_camel_case, NormalizedStructField, SharedStruct, StructFields, StructRenameAll,
+    StructSwiftRepr,
+};
+
+mod bridged_array;
+mod bridged_boxed_fn;
+mod bridged_hashmap;
+mod bridged_non_zero;
 mod bridged_option;
+mod bridged_range;
+mod bridged_result;
+mod bridged_set;
+mod bridged_tuple;
+mod opaque_generic_args;
 mod shared_enum;
 mod shared_struct;
 
@@ -48,9 +73,15 @@ pub(crate) enum StdLibType {
     I64,
     Usize,
     Isize,
+    /// `u128`, which Swift sees as a `RustU128` since it has no portable 128 bit integer.
+    U128,
+    /// `i128`, which Swift sees as a `RustI128`.
+    I128,
     F32,
     F64,
     Bool,
+    /// `char`
+    Char,
     /// `*const T` or `*mut T`
     Pointer(BuiltInPointer),
     /// `&[T]` or `&mut [T]`
@@ -59,7 +90,49 @@ pub(crate) enum StdLibType {
     Str,
     String,
     Vec(BuiltInVec),
+    /// `&Vec<T>` or `&mut Vec<T>`, which Swift sees as a `RustVec<T>` that it does not own.
+    RefVec(BuiltInRefVec),
+    /// `&mut u32` or a mutable reference to another number or `bool`, which Swift sees as an
+    /// `inout` parameter.
+    RefMutPrimitive(Box<BridgedType>),
+    /// `VecDeque<T>`, which gets moved into a Swift Array in front-to-back order.
+    VecDeque(BuiltInVec),
     Option(BridgedOption),
+    /// `HashMap<K, V>` or `BTreeMap<K, V>`
+    HashMap(BridgedHashMap),
+    /// `HashSet<T>` or `BTreeSet<T>`
+    Set(BridgedSet),
+    /// `Result<T, E>`
+    Result(BridgedResult),
+    /// `Box<dyn Fn(A, B) -> R>`, `Box<dyn FnMut(A, B) -> R>` or `&dyn Fn(A, B) -> R`
+    BoxedFn(BridgedBoxedFn),
+    /// `[T; N]`
+    Array(BridgedArray),
+    /// `Range<T>` or `RangeInclusive<T>`
+    Range(BridgedRange),
+    /// `(A, B)`
+    Tuple(BridgedTuple),
+    /// `std::time::Duration`
+    Duration,
+    /// `chrono::DateTime<Utc>`, which Swift sees as a `Date`.
+    ///
+    /// Only recognized when the `chrono` feature is enabled.
+    #[cfg_attr(not(feature = "chrono"), allow(dead_code))]
+    DateTime,
+    /// `std::net::IpAddr`, which Swift sees as a `RustIpAddr`.
+    IpAddr,
+    /// `std::net::SocketAddr`, which Swift sees as a `RustSocketAddr`.
+    SocketAddr,
+    /// `std::path::PathBuf`
+    PathBuf,
+    /// `Cow<'a, str>`
+    CowStr,
+    /// `Box<str>`, which Swift sees as a `String`.
+    BoxedStr,
+    /// `Box<dyn std::error::Error>`, which Swift sees as a `RustError`.
+    BoxedError,
+    /// `NonZeroU32` and friends, which Swift sees as the underlying integer.
+    NonZero(BridgedNonZero),
 }
 
 /// TODO: Add this to `OpaqueForeignType`
@@ -123,16 +196,155 @@ impl PartialEq for Pointee {
     }
 }
 
-/// &[T]
+/// &[T] or &mut [T]
 #[derive(Debug, PartialEq, Clone)]
 pub(crate) struct BuiltInRefSlice {
     pub ty: Box<BridgedType>,
+    /// `&mut [T]`
+    pub mutable: bool,
+}
+
+impl BuiltInRefSlice {
+    /// `&[&str]`, which Swift passes to Rust as a borrowed array of `RustStr`s.
+    pub fn is_str_slice(&self) -> bool {
+        !self.mutable && *self.ty == BridgedType::StdLib(StdLibType::Str)
+    }
+
+    /// `&[u8]`, which Swift can pass to Rust as the bytes of a `Data`.
+    pub fn is_u8_slice(&self) -> bool {
+        !self.mutable && *self.ty == BridgedType::StdLib(StdLibType::U8)
+    }
+}
+
+/// &Vec<T> or &mut Vec<T>
+#[derive(Debug, PartialEq, Clone)]
+pub(crate) struct BuiltInRefVec {
+    pub vec: BuiltInVec,
+    /// `&mut Vec<T>`
+    pub mutable: bool,
 }
 
 /// Vec<T>
 #[derive(Debug, PartialEq, Clone)]
 pub(crate) struct BuiltInVec {
     pub ty: Box<BridgedType>,
+    /// Whether or not this Vec is the element of another Vec or the value of an Option, such as
+    /// the `Vec<u8>` in a `Vec<Vec<u8>>` or in an `Option<Vec<u8>>`.
+    ///
+    /// A `VecDeque<T>` is always `nested`, since it always gets moved into a Swift Array.
+    pub nested: bool,
+    /// Whether or not this `Vec<u8>` is the return value of a function with the
+    /// `#[swift_bridge(return_as = "Data")]` attribute, in which case Swift receives a `Data`
+    /// that takes ownership of the Vec's buffer.
+    pub as_data: bool,
+    /// Whether or not this Vec of numbers is the return value of a function with the
+    /// `#[swift_bridge(return_as = "Array")]` attribute, in which case Swift receives an Array,
+    /// such as a `[Float]`, instead of a `RustVec<T>`.
+    pub as_array: bool,
+}
+
+impl BuiltInVec {
+    /// If the Vec holds shared structs, return the struct.
+    pub fn shared_struct_elem(&self) -> Option<&SharedStruct> {
+        match self.ty.deref() {
+            BridgedType::Foreign(CustomBridgedType::Shared(SharedType::Struct(shared_struct))) => {
+                Some(shared_struct)
+            }
+            _ => None,
+        }
+    }
+
+    /// Whether or not the Vec's elements get moved into a Swift Array using a
+    /// `swift_bridge::vec::FfiVec`, instead of exposing the Vec as a `RustVec<T>`.
+    ///
+    /// Shared structs aren't `Vectorizable`, so a `Vec<SharedStruct>` becomes a `[SharedStruct]`.
+    /// A `Vec<Vec<T>>` becomes a `[[T]]`, where every inner Vec is its own `FfiVec` that carries
+    /// its own length. A `Vec<Option<T>>` becomes a `[T?]` and an `Option<Vec<T>>` becomes a
+    /// `[T]?`.
+    pub fn moves_into_swift_array(&self) -> bool {
+        self.nested
+            || self.as_data
+            || self.as_array
+            || self.shared_struct_elem().is_some()
+            || matches!(
+                self.ty.deref(),
+                BridgedType::StdLib(StdLibType::Vec(_) | StdLibType::Option(_))
+            )
+    }
+
+    /// Whether or not Swift can pass an Array to a Rust function that takes this Vec, using the
+    /// `#[swift_bridge(as = "Array")]` argument attribute.
+    ///
+    /// Only Vecs of primitives, such as a `Vec<i32>` that Swift passes as an `[Int32]`, are
+    /// supported for now.
+    pub fn can_be_passed_as_swift_array(&self) -> bool {
+        let has_primitive_elems = self.has_numeric_elems()
+            || matches!(self.ty.deref(), BridgedType::StdLib(StdLibType::Bool));
+
+        has_primitive_elems && !self.moves_into_swift_array()
+    }
+
+    /// The Swift Array that gets passed to a Rust function that takes this Vec as an argument
+    /// with the `#[swift_bridge(as = "Array")]` attribute.
+    ///
+    /// Vec<i32> -> [Int32]
+    pub fn to_swift_array_type(&self, type_pos: TypePosition) -> String {
+        format!("[{}]", self.ty.to_swift_type(type_pos))
+    }
+
+    /// Copy a Swift Array's elements into a new Rust Vec that gets passed to a Rust function.
+    ///
+    /// Rust copies the Array's elements into a new Vec, so an empty Array's null `baseAddress`
+    /// becomes an empty Vec.
+    pub fn convert_swift_array_to_ffi_value(&self, value: &str) -> String {
+        format!(
+            "{value}.withUnsafeBufferPointer {{ buf in __swift_bridge__$Vec_{ty}$from_slice(buf.baseAddress, UInt(buf.count)) }}",
+            value = value,
+            ty = self.ty.to_rust()
+        )
+    }
+
+    /// Whether or not the Vec holds numbers that have the same layout in Rust and Swift, such as
+    /// `f32` and `Float`, so that Swift can copy the whole buffer into an Array at once instead of
+    /// converting one element at a time.
+    pub fn has_numeric_elems(&self) -> bool {
+        matches!(
+            self.ty.deref(),
+            BridgedType::StdLib(
+                StdLibType::U8
+                    | StdLibType::I8
+                    | StdLibType::U16
+                    | StdLibType::I16
+                    | StdLibType::U32
+                    | StdLibType::I32
+                    | StdLibType::U64
+                    | StdLibType::I64
+                    | StdLibType::Usize
+                    | StdLibType::Isize
+                    | StdLibType::F32
+                    | StdLibType::F64
+            )
+        )
+    }
+
+    /// The `Vec<T>` that a `VecDeque<T>` crosses the FFI boundary as.
+    fn as_vec_type(&self) -> BridgedType {
+        BridgedType::StdLib(StdLibType::Vec(self.clone()))
+    }
+
+    /// The Swift type of an element of the `__private__FfiVec` that we move into a Swift Array.
+    fn ffi_elem_swift_type(&self) -> String {
+        match self.ty.deref() {
+            BridgedType::Foreign(CustomBridgedType::Shared(SharedType::Struct(shared_struct))) => {
+                shared_struct.ffi_name_string()
+            }
+            BridgedType::StdLib(StdLibType::Option(opt)) => opt.to_swift_ffi_type(),
+            BridgedType::StdLib(StdLibType::String) => "UnsafeMutableRawPointer".to_string(),
+            _ => self
+                .ty
+                .to_swift_type(TypePosition::FnReturn(HostLang::Swift)),
+        }
+    }
 }
 
 #[derive(Debug, PartialEq, Clone)]
@@ -160,6 +372,302 @@ impl BridgedType {
     pub fn is_null(&self) -> bool {
         matches!(self, BridgedType::StdLib(StdLibType::Null))
     }
+
+    /// Whether or not this type can be written through a `&mut` reference that Swift sees as an
+    /// `inout` parameter. These are the types that have the same representation in Rust, C and
+    /// Swift.
+    pub fn can_be_inout(&self) -> bool {
+        matches!(
+            self,
+            BridgedType::StdLib(
+                StdLibType::U8
+                    | StdLibType::I8
+                    | StdLibType::U16
+                    | StdLibType::I16
+                    | StdLibType::U32
+                    | StdLibType::I32
+                    | StdLibType::U64
+                    | StdLibType::I64
+                    | StdLibType::Usize
+                    | StdLibType::Isize
+                    | StdLibType::F32
+                    | StdLibType::F64
+                    | StdLibType::Bool
+            )
+        )
+    }
+
+    /// Whether or not the Swift representation of this type conforms to `Equatable`.
+    pub fn is_swift_equatable(&self) -> bool {
+        match self {
+            BridgedType::StdLib(stdlib_type) => match stdlib_type {
+                StdLibType::U8
+                | StdLibType::I8
+                | StdLibType::U16
+                | StdLibType::I16
+                | StdLibType::U32
+                | StdLibType::I32
+                | StdLibType::U64
+                | StdLibType::I64
+                | StdLibType::Usize
+                | StdLibType::Isize
+                | StdLibType::F32
+                | StdLibType::F64
+                | StdLibType::Bool
+                | StdLibType::Char
+                | StdLibType::Pointer(_)
+                | StdLibType::Range(_)
+                | StdLibType::U128
+                | StdLibType::I128
+                | StdLibType::NonZero(_)
+                | StdLibType::Duration
+                | StdLibType::DateTime
+                | StdLibType::IpAddr
+                | StdLibType::SocketAddr => true,
+                StdLibType::Option(opt) => opt.ty.is_swift_equatable(),
+                StdLibType::Null
+                | StdLibType::RefSlice(_)
+                | StdLibType::Str
+                | StdLibType::String
+                | StdLibType::PathBuf
+                | StdLibType::CowStr
+                | StdLibType::BoxedStr
+                | StdLibType::BoxedError
+                | StdLibType::Vec(_)
+                | StdLibType::RefVec(_)
+                | StdLibType::RefMutPrimitive(_)
+                | StdLibType::VecDeque(_)
+                | StdLibType::HashMap(_)
+                | StdLibType::Set(_)
+                | StdLibType::Result(_)
+                | StdLibType::BoxedFn(_)
+                | StdLibType::Array(_)
+                | StdLibType::Tuple(_) => false,
+            },
+            BridgedType::Foreign(CustomBridgedType::Shared(SharedType::Struct(shared_struct))) => {
+                shared_struct.equatable
+            }
+            // Swift automatically synthesizes `Equatable` for enums without associated values.
+            BridgedType::Foreign(CustomBridgedType::Shared(SharedType::Enum(shared_enum))) => {
+                !shared_enum.has_one_or_more_variants_with_data()
+            }
+            BridgedType::Foreign(CustomBridgedType::Opaque(_)) => false,
+        }
+    }
+
+    /// Whether or not the Swift representation of this type conforms to `Hashable`.
+    pub fn is_swift_hashable(&self) -> bool {
+        match self {
+            BridgedType::StdLib(stdlib_type) => match stdlib_type {
+                StdLibType::U8
+                | StdLibType::I8
+                | StdLibType::U16
+                | StdLibType::I16
+                | StdLibType::U32
+                | StdLibType::I32
+                | StdLibType::U64
+                | StdLibType::I64
+                | StdLibType::Usize
+                | StdLibType::Isize
+                | StdLibType::F32
+                | StdLibType::F64
+                | StdLibType::Bool
+                | StdLibType::Char
+                | StdLibType::Range(_)
+                | StdLibType::U128
+                | StdLibType::I128
+                | StdLibType::NonZero(_)
+                | StdLibType::Duration
+                | StdLibType::DateTime
+                | StdLibType::IpAddr
+                | StdLibType::SocketAddr => true,
+                StdLibType::Option(opt) => opt.ty.is_swift_hashable(),
+                StdLibType::Null
+                | StdLibType::Pointer(_)
+                | StdLibType::RefSlice(_)
+                | StdLibType::Str
+                | StdLibType::String
+                | StdLibType::PathBuf
+                | StdLibType::CowStr
+                | StdLibType::BoxedStr
+                | StdLibType::BoxedError
+                | StdLibType::Vec(_)
+                | StdLibType::RefVec(_)
+                | StdLibType::RefMutPrimitive(_)
+                | StdLibType::VecDeque(_)
+                | StdLibType::HashMap(_)
+                | StdLibType::Set(_)
+                | StdLibType::Result(_)
+                | StdLibType::BoxedFn(_)
+                | StdLibType::Array(_)
+                | StdLibType::Tuple(_) => false,
+            },
+            BridgedType::Foreign(CustomBridgedType::Shared(SharedType::Struct(_))) => false,
+            // Swift automatically synthesizes `Hashable` for enums without associated values.
+            BridgedType::Foreign(CustomBridgedType::Shared(SharedType::Enum(shared_enum))) => {
+                shared_enum.hashable || !shared_enum.has_one_or_more_variants_with_data()
+            }
+            BridgedType::Foreign(CustomBridgedType::Opaque(_)) => false,
+        }
+    }
+
+    /// Whether or not the Swift representation of this type conforms to `Codable`.
+    pub fn is_swift_codable(&self) -> bool {
+        match self {
+            BridgedType::StdLib(stdlib_type) => match stdlib_type {
+                StdLibType::U8
+                | StdLibType::I8
+                | StdLibType::U16
+                | StdLibType::I16
+                | StdLibType::U32
+                | StdLibType::I32
+                | StdLibType::U64
+                | StdLibType::I64
+                | StdLibType::Usize
+                | StdLibType::Isize
+                | StdLibType::F32
+                | StdLibType::F64
+                | StdLibType::Bool
+                | StdLibType::Range(_)
+                | StdLibType::U128
+                | StdLibType::I128
+                | StdLibType::NonZero(_)
+                | StdLibType::Duration
+                | StdLibType::DateTime
+                | StdLibType::IpAddr
+                | StdLibType::SocketAddr => true,
+                StdLibType::Option(opt) => opt.ty.is_swift_codable(),
+                // `RustString`, `RustStr` and `RustVec` are handles to Rust memory, so they can't
+                // be encoded.
+                StdLibType::Null
+                | StdLibType::Char
+                | StdLibType::Pointer(_)
+                | StdLibType::RefSlice(_)
+                | StdLibType::Str
+                | StdLibType::String
+                | StdLibType::PathBuf
+                | StdLibType::CowStr
+                | StdLibType::BoxedStr
+                | StdLibType::BoxedError
+                | StdLibType::Vec(_)
+                | StdLibType::RefVec(_)
+                | StdLibType::RefMutPrimitive(_)
+                | StdLibType::VecDeque(_)
+                | StdLibType::HashMap(_)
+                | StdLibType::Set(_)
+                | StdLibType::Result(_)
+                | StdLibType::BoxedFn(_)
+                | StdLibType::Array(_)
+                | StdLibType::Tuple(_) => false,
+            },
+            BridgedType::Foreign(CustomBridgedType::Shared(SharedType::Struct(shared_struct))) => {
+                shared_struct.codable
+            }
+            BridgedType::Foreign(CustomBridgedType::Shared(SharedType::Enum(_))) => false,
+            BridgedType::Foreign(CustomBridgedType::Opaque(_)) => false,
+        }
+    }
+
+    /// Whether or not an argument of this type can be given a default value using
+    /// `#[swift_bridge(default = ...)]`.
+    ///
+    /// For now we support integers, floats, bools and strings.
+    pub fn supports_swift_default_value(&self) -> bool {
+        matches!(
+            self,
+            BridgedType::StdLib(
+                StdLibType::U8
+                    | StdLibType::I8
+                    | StdLibType::U16
+                    | StdLibType::I16
+                    | StdLibType::U32
+                    | StdLibType::I32
+                    | StdLibType::U64
+                    | StdLibType::I64
+                    | StdLibType::Usize
+                    | StdLibType::Isize
+                    | StdLibType::F32
+                    | StdLibType::F64
+                    | StdLibType::Bool
+                    | StdLibType::Str
+                    | StdLibType::String
+            )
+        )
+    }
+
+    /// Whether or not Swift can pass this type to Rust, either as an argument to an `extern "Rust"`
+    /// function or as the return value of an `extern "Swift"` function.
+    ///
+    /// Some types, such as `Result<T, E>`, can only be passed from Rust to Swift for now.
+    pub fn can_be_passed_from_swift_to_rust(&self) -> bool {
+        match self {
+            BridgedType::StdLib(
+                StdLibType::Result(_)
+                | StdLibType::CowStr
+                | StdLibType::BoxedStr
+                | StdLibType::VecDeque(_),
+            ) => false,
+            // Swift Arrays don't get moved back into Rust Vecs yet.
+            BridgedType::StdLib(StdLibType::Vec(vec)) => !vec.moves_into_swift_array(),
+            _ => true,
+        }
+    }
+
+    /// Whether or not we can generate code for an `Option` that holds this type.
+    ///
+    /// Some types, such as `char`, can't be wrapped in an `Option` yet.
+    pub fn can_be_wrapped_in_option(&self) -> bool {
+        !matches!(
+            self,
+            BridgedType::StdLib(
+                StdLibType::Char
+                    | StdLibType::CowStr
+                    | StdLibType::BoxedStr
+                    | StdLibType::VecDeque(_)
+            )
+        )
+    }
+
+    /// Whether or not a function with the `#[swift_bridge(constant)]` attribute can return this
+    /// type.
+    ///
+    /// Constants support the same integers, floats, bools and strings as default argument values.
+    pub fn supports_swift_constant(&self) -> bool {
+        self.supports_swift_default_value()
+    }
+
+    /// Whether or not the given literal can be used as a default value for an argument of this
+    /// type.
+    ///
+    /// `5` -> u8, `5` -> f32, `5.0` -> f32, `true` -> bool, `"hello"` -> &str
+    pub fn can_be_initialized_with_literal(&self, lit: &Lit) -> bool {
+        let stdlib_type = match self {
+            BridgedType::StdLib(stdlib_type) => stdlib_type,
+            _ => return false,
+        };
+
+        match lit {
+            Lit::Int(_) => matches!(
+                stdlib_type,
+                StdLibType::U8
+                    | StdLibType::I8
+                    | StdLibType::U16
+                    | StdLibType::I16
+                    | StdLibType::U32
+                    | StdLibType::I32
+                    | StdLibType::U64
+                    | StdLibType::I64
+                    | StdLibType::Usize
+                    | StdLibType::Isize
+                    | StdLibType::F32
+                    | StdLibType::F64
+            ),
+            Lit::Float(_) => matches!(stdlib_type, StdLibType::F32 | StdLibType::F64),
+            Lit::Bool(_) => matches!(stdlib_type, StdLibType::Bool),
+            Lit::Str(_) => matches!(stdlib_type, StdLibType::Str | StdLibType::String),
+            _ => false,
+        }
+    }
 }
 
 #[cfg(test)]
@@ -184,11 +692,73 @@ pub(crate) struct OpaqueForeignType {
     pub host_lang: HostLang,
     pub reference: bool,
     pub mutable: bool,
+    pub generic_args: OpaqueGenericArgs,
+    /// `Box<dyn Trait>`
+    pub trait_object: bool,
+    /// `Arc<Type>`
+    pub arc: bool,
+    /// `Pin<Box<Type>>`
+    pub pin: bool,
+    /// `Mutex<Type>`
+    pub mutex: bool,
+    /// `#[swift_bridge(Copy(N))]`, where `N` is the size of the type in bytes
+    pub copy: Option<usize>,
+    /// `#[swift_bridge(swift_name = "...")]`
+    pub swift_name: Option<String>,
 }
 
 impl OpaqueForeignType {
-    fn swift_name(&self) -> String {
-        format!("{}", self.ty)
+    /// MyWrapper<u32> -> MyWrapperUInt32
+    /// #[swift_bridge(swift_name = "ImageBuffer")] type ImgBuf; -> ImageBuffer
+    pub fn swift_name(&self) -> String {
+        if let Some(swift_name) = &self.swift_name {
+            return swift_name.clone();
+        }
+
+        format!("{}{}", self.ty, self.generic_args.swift_name_suffix())
+    }
+
+    /// The Rust type, for use in a type position within the generated module.
+    ///
+    /// MyType -> super::MyType
+    /// MyWrapper<u32> -> super::MyWrapper<u32>
+    /// Box<dyn MyTrait> -> Box<dyn super::MyTrait>
+    /// Arc<MyType> -> std::sync::Arc<super::MyType>
+    /// Pin<Box<MyType>> -> std::pin::Pin<Box<super::MyType>>
+    /// Mutex<MyType> -> std::sync::Mutex<super::MyType>
+    pub fn rust_ty_tokens(&self) -> TokenStream {
+        let ty = &self.ty;
+        let generic_args = self.generic_args.to_rust_type_tokens();
+
+        if self.host_lang.is_swift() {
+            quote! { #ty }
+        } else if self.trait_object {
+            quote! { Box<dyn super::#ty> }
+        } else if self.arc {
+            quote! { std::sync::Arc<super::#ty> }
+        } else if self.pin {
+            quote! { std::pin::Pin<Box<super::#ty>> }
+        } else if self.mutex {
+            quote! { std::sync::Mutex<super::#ty> }
+        } else {
+            quote! { super::#ty #generic_args }
+        }
+    }
+
+    /// The FFI representation of a `#[swift_bridge(Copy(N))]` type, which holds the type's bytes
+    /// so that it can be passed by value.
+    ///
+    /// MyType -> __swift_bridge__MyType
+    pub fn copy_ffi_repr_tokens(&self) -> Ident {
+        Ident::new(
+            &format!("{}{}", SWIFT_BRIDGE_PREFIX, self.ty),
+            self.ty.span(),
+        )
+    }
+
+    /// MyType -> __swift_bridge__$MyType
+    pub fn copy_ffi_repr_string(&self) -> String {
+        format!("{}${}", SWIFT_BRIDGE_PREFIX, self.ty)
     }
 }
 
@@ -199,6 +769,13 @@ impl Debug for OpaqueForeignType {
             .field("host_lang", &self.host_lang)
             .field("reference", &self.reference)
             .field("mutable", &self.mutable)
+            .field("generic_args", &self.generic_args)
+            .field("trait_object", &self.trait_object)
+            .field("arc", &self.arc)
+            .field("pin", &self.pin)
+            .field("mutex", &self.mutex)
+            .field("copy", &self.copy)
+            .field("swift_name", &self.swift_name)
             .finish()
     }
 }
@@ -209,6 +786,13 @@ impl PartialEq for OpaqueForeignType {
             && self.host_lang == other.host_lang
             && self.reference == other.reference
             && self.mutable == other.mutable
+            && self.generic_args == other.generic_args
+            && self.trait_object == other.trait_object
+            && self.arc == other.arc
+            && self.pin == other.pin
+            && self.mutex == other.mutex
+            && self.copy == other.copy
+            && self.swift_name == other.swift_name
     }
 }
 
@@ -225,6 +809,83 @@ pub(crate) fn pat_type_pat_is_self(pat_type: &PatType) -> bool {
     }
 }
 
+/// Whether or not the path is a `Cow<'a, str>`, `Cow<str>` or `std::borrow::Cow<'a, str>`.
+fn is_cow_str(path: &TypePath) -> bool {
+    let last = match path.path.segments.last() {
+        Some(last) if last.ident == "Cow" => last,
+        _ => return false,
+    };
+
+    let args = match &last.arguments {
+        PathArguments::AngleBracketed(args) => &args.args,
+        _ => return false,
+    };
+
+    let mut types = args.iter().filter_map(|arg| match arg {
+        GenericArgument::Type(ty) => Some(ty),
+        _ => None,
+    });
+
+    match (types.next(), types.next()) {
+        (Some(Type::Path(ty)), None) => ty.path.is_ident("str"),
+        _ => false,
+    }
+}
+
+/// `Box<str>`
+fn is_boxed_str(path: &TypePath) -> bool {
+    let last = match path.path.segments.last() {
+        Some(last) if last.ident == "Box" => last,
+        _ => return false,
+    };
+
+    let args = match &last.arguments {
+        PathArguments::AngleBracketed(args) => &args.args,
+        _ => return false,
+    };
+
+    match args.first() {
+        Some(GenericArgument::Type(Type::Path(ty))) if args.len() == 1 => ty.path.is_ident("str"),
+        _ => false,
+    }
+}
+
+/// `Box<dyn Error>` or `Box<dyn std::error::Error>`, optionally with `Send` and `Sync` bounds.
+fn is_boxed_error(path: &TypePath) -> bool {
+    let last = match path.path.segments.last() {
+        Some(last) if last.ident == "Box" => last,
+        _ => return false,
+    };
+
+    let args = match &last.arguments {
+        PathArguments::AngleBracketed(args) => &args.args,
+        _ => return false,
+    };
+
+    let trait_object = match args.first() {
+        Some(GenericArgument::Type(Type::TraitObject(trait_object))) if args.len() == 1 => {
+            trait_object
+        }
+        _ => return false,
+    };
+
+    let mut is_error = false;
+    for bound in trait_object.bounds.iter() {
+        let bound = match bound {
+            TypeParamBound::Trait(bound) => bound.path.to_token_stream().to_string(),
+            TypeParamBound::Lifetime(_) => continue,
+        };
+
+        match bound.as_str() {
+            "Error" | "std :: error :: Error" => is_error = true,
+            "Send" | "Sync" => {}
+            _ => return false,
+        }
+    }
+
+    is_error
+}
+
 /// foo: u8 -> Some("foo")
 pub(crate) fn fn_arg_name(fn_arg: &FnArg) -> Option<&Ident> {
     match fn_arg {
@@ -250,6 +911,22 @@ impl BridgedType {
             Type::Path(path) => {
                 if let Some(ty) = types.get_with_type_path(path) {
                     Some(ty.to_bridged_type(false, false))
+                } else if let Some(map) = BridgedHashMap::new_with_type_path(path, types) {
+                    Some(BridgedType::StdLib(StdLibType::HashMap(map)))
+                } else if let Some(set) = BridgedSet::new_with_type_path(path, types) {
+                    Some(BridgedType::StdLib(StdLibType::Set(set)))
+                } else if let Some(range) = BridgedRange::new_with_type_path(path, types) {
+                    Some(BridgedType::StdLib(StdLibType::Range(range)))
+                } else if let Some(result) = BridgedResult::new_with_type_path(path, types) {
+                    Some(BridgedType::StdLib(StdLibType::Result(result)))
+                } else if let Some(boxed_fn) = BridgedBoxedFn::new_with_type_path(path, types) {
+                    Some(BridgedType::StdLib(StdLibType::BoxedFn(boxed_fn)))
+                } else if is_cow_str(path) {
+                    Some(BridgedType::StdLib(StdLibType::CowStr))
+                } else if is_boxed_str(path) {
+                    Some(BridgedType::StdLib(StdLibType::BoxedStr))
+                } else if is_boxed_error(path) {
+                    Some(BridgedType::StdLib(StdLibType::BoxedError))
                 } else {
                     Self::new_with_str(
                         path.path.segments.to_token_stream().to_string().as_str(),
@@ -257,6 +934,10 @@ impl BridgedType {
                     )
                 }
             }
+            Type::Array(array) => BridgedArray::new_with_type_array(array, types)
+                .map(|array| BridgedType::StdLib(StdLibType::Array(array))),
+            Type::Tuple(tuple) => BridgedTuple::new_with_type_tuple(tuple, types)
+                .map(|tuple| BridgedType::StdLib(StdLibType::Tuple(tuple))),
             Type::Ptr(ptr) => {
                 let kind = if ptr.const_token.is_some() {
                     PointerKind::Const
@@ -287,12 +968,36 @@ impl BridgedType {
                             return Some(BridgedType::StdLib(StdLibType::Str));
                         }
 
-                        None
+                        // `&Vec<T>` and `&mut Vec<T>` are handed to Swift as a `RustVec<T>` handle,
+                        // so the Vec's elements need to be `Vectorizable`.
+                        match Self::new_with_str(&path, types)? {
+                            BridgedType::StdLib(StdLibType::Vec(vec))
+                                if !vec.moves_into_swift_array() =>
+                            {
+                                Some(BridgedType::StdLib(StdLibType::RefVec(BuiltInRefVec {
+                                    vec,
+                                    mutable: ty_ref.mutability.is_some(),
+                                })))
+                            }
+                            primitive
+                                if ty_ref.mutability.is_some() && primitive.can_be_inout() =>
+                            {
+                                Some(BridgedType::StdLib(StdLibType::RefMutPrimitive(Box::new(
+                                    primitive,
+                                ))))
+                            }
+                            _ => None,
+                        }
                     }
                 }
                 Type::Slice(slice) => Self::new_with_type(&slice.elem, types).map(|ty| {
-                    BridgedType::StdLib(StdLibType::RefSlice(BuiltInRefSlice { ty: Box::new(ty) }))
+                    BridgedType::StdLib(StdLibType::RefSlice(BuiltInRefSlice {
+                        ty: Box::new(ty),
+                        mutable: ty_ref.mutability.is_some(),
+                    }))
                 }),
+                Type::TraitObject(_) => BridgedBoxedFn::new_with_type_reference(ty_ref, types)
+                    .map(|boxed_fn| BridgedType::StdLib(StdLibType::BoxedFn(boxed_fn))),
                 _ => None,
             },
             _ => None,
@@ -315,25 +1020,60 @@ impl BridgedType {
 
     pub fn new_with_str(string: &str, types: &TypeDeclarations) -> Option<BridgedType> {
         if string.starts_with("Vec < ") {
-            let inner = string.trim_start_matches("Vec < ");
-            let inner = inner.trim_end_matches(" >");
+            // Only strip one `Vec <` and `>`, since the inner type might be a Vec.
+            // i.e. `Vec<Vec<u8>>`
+            let inner = string.strip_prefix("Vec < ")?.strip_suffix(" >")?;
 
             let inner = if let Some(declared_ty) = types.get(inner) {
                 declared_ty.to_bridged_type(false, false)
             } else {
                 let inner: Type = syn::parse2(TokenStream::from_str(inner).unwrap()).unwrap();
-                BridgedType::new_with_type(&inner, types)?
+                match BridgedType::new_with_type(&inner, types)? {
+                    BridgedType::StdLib(StdLibType::Vec(inner)) => {
+                        BridgedType::StdLib(StdLibType::Vec(BuiltInVec {
+                            nested: true,
+                            ..inner
+                        }))
+                    }
+                    inner => inner,
+                }
             };
 
             return Some(BridgedType::StdLib(StdLibType::Vec(BuiltInVec {
                 ty: Box::new(inner),
+                nested: false,
+                as_data: false,
+                as_array: false,
+            })));
+        } else if let Some(inner) = string
+            .strip_prefix("VecDeque < ")
+            .or_else(|| string.strip_prefix("std :: collections :: VecDeque < "))
+        {
+            let inner = inner.strip_suffix(" >")?;
+            let inner: Type = syn::parse2(TokenStream::from_str(inner).unwrap()).unwrap();
+            let inner = BridgedType::new_with_type(&inner, types)?;
+
+            return Some(BridgedType::StdLib(StdLibType::VecDeque(BuiltInVec {
+                ty: Box::new(inner),
+                nested: true,
+                as_data: false,
+                as_array: false,
             })));
         } else if string.starts_with("Option < ") {
             let inner = string.trim_start_matches("Option < ");
-            let inner = inner.trim_end_matches(" >");
+            // Only strip one `>`, since the inner type might be generic. i.e. `Option<Foo<u32>>`
+            let inner = inner.strip_suffix(" >")?;
 
             let inner: Type = syn::parse2(TokenStream::from_str(inner).unwrap()).unwrap();
-            let inner = BridgedType::new_with_type(&inner, types)?;
+            let inner = match BridgedType::new_with_type(&inner, types)? {
+                BridgedType::StdLib(StdLibType::Vec(inner)) => {
+                    BridgedType::StdLib(StdLibType::Vec(BuiltInVec {
+                        nested: true,
+                        ..inner
+                    }))
+                }
+                inner => inner,
+            };
 
             return Some(BridgedType::StdLib(StdLibType::Option(BridgedOption {
                 ty: Box::new(inner),
@@ -351,12 +1091,26 @@ impl BridgedType {
             "i64" => BridgedType::StdLib(StdLibType::I64),
             "usize" => BridgedType::StdLib(StdLibType::Usize),
             "isize" => BridgedType::StdLib(StdLibType::Isize),
+            "u128" => BridgedType::StdLib(StdLibType::U128),
+            "i128" => BridgedType::StdLib(StdLibType::I128),
             "f32" => BridgedType::StdLib(StdLibType::F32),
             "f64" => BridgedType::StdLib(StdLibType::F64),
             "String" => BridgedType::StdLib(StdLibType::String),
             "bool" => BridgedType::StdLib(StdLibType::Bool),
+            "char" => BridgedType::StdLib(StdLibType::Char),
+            "Duration" | "std :: time :: Duration" => BridgedType::StdLib(StdLibType::Duration),
+            #[cfg(feature = "chrono")]
+            "DateTime < Utc >" | "chrono :: DateTime < chrono :: Utc >" => {
+                BridgedType::StdLib(StdLibType::DateTime)
+            }
+            "IpAddr" | "std :: net :: IpAddr" => BridgedType::StdLib(StdLibType::IpAddr),
+            "SocketAddr" | "std :: net :: SocketAddr" => {
+                BridgedType::StdLib(StdLibType::SocketAddr)
+            }
+            "PathBuf" | "std :: path :: PathBuf" => BridgedType::StdLib(StdLibType::PathBuf),
             _ => {
-                return None;
+                return BridgedNonZero::new_with_str(string)
+                    .map(|non_zero| BridgedType::StdLib(StdLibType::NonZero(non_zero)));
             }
         };
         return Some(ty);
@@ -385,6 +1139,7 @@ impl BridgedType {
                     StdLibType::F32 => quote! { f32 },
                     StdLibType::F64 => quote! { f64 },
                     StdLibType::Bool => quote! { bool },
+                    StdLibType::Char => quote! { char },
                     StdLibType::Pointer(ptr) => {
                         let ptr_kind = &ptr.kind;
 
@@ -401,7 +1156,11 @@ impl BridgedType {
                     }
                     StdLibType::RefSlice(ref_slice) => {
                         let ty = ref_slice.ty.to_rust();
-                        quote! { &[#ty]}
+                        if ref_slice.mutable {
+                            quote! { &mut [#ty]}
+                        } else {
+                            quote! { &[#ty]}
+                        }
                     }
                     StdLibType::Str => quote! { &str },
                     StdLibType::String => quote! { String },
@@ -409,10 +1168,55 @@ impl BridgedType {
                         let ty = v.ty.to_rust();
                         quote! { Vec<#ty> }
                     }
+                    StdLibType::RefVec(ref_vec) => {
+                        let ty = ref_vec.vec.ty.to_rust();
+                        if ref_vec.mutable {
+                            quote! { &mut Vec<#ty> }
+                        } else {
+                            quote! { &Vec<#ty> }
+                        }
+                    }
+                    StdLibType::RefMutPrimitive(primitive) => {
+                        let ty = primitive.to_rust();
+                        quote! { &mut #ty }
+                    }
+                    StdLibType::VecDeque(v) => {
+                        let ty = v.ty.to_rust();
+                        quote! { std::collections::VecDeque<#ty> }
+                    }
                     StdLibType::Option(opt) => {
                         let ty = opt.ty.to_rust();
-                        quote! { Option<#ty> }
+                        match opt.ty.deref() {
+                            BridgedType::Foreign(CustomBridgedType::Opaque(opaque))
+                                if opaque.reference =>
+                            {
+                                if opaque.mutable {
+                                    quote! { Option<&mut #ty> }
+                                } else {
+                                    quote! { Option<&#ty> }
+                                }
+                            }
+                            _ => quote! { Option<#ty> },
+                        }
                     }
+                    StdLibType::HashMap(map) => map.to_rust(),
+                    StdLibType::Set(set) => set.to_rust(),
+                    StdLibType::Result(result) => result.to_rust(),
+                    StdLibType::BoxedFn(boxed_fn) => boxed_fn.to_rust(),
+                    StdLibType::Array(array) => array.to_rust(),
+                    StdLibType::Tuple(tuple) => tuple.to_rust(),
+                    StdLibType::Range(range) => range.to_rust(),
+                    StdLibType::Duration => quote! { std::time::Duration },
+                    StdLibType::DateTime => quote! { chrono::DateTime<chrono::Utc> },
+                    StdLibType::IpAddr => quote! { std::net::IpAddr },
+                    StdLibType::SocketAddr => quote! { std::net::SocketAddr },
+                    StdLibType::U128 => quote! { u128 },
+                    StdLibType::I128 => quote! { i128 },
+                    StdLibType::PathBuf => quote! { std::path::PathBuf },
+                    StdLibType::CowStr => quote! { std::borrow::Cow<'static, str> },
+                    StdLibType::BoxedStr => quote! { Box<str> },
+                    StdLibType::BoxedError => quote! { Box<dyn std::error::Error> },
+                    StdLibType::NonZero(non_zero) => non_zero.to_rust(),
                 }
             }
             BridgedType::Foreign(CustomBridgedType::Shared(SharedType::Struct(shared_struct))) => {
@@ -425,19 +1229,7 @@ impl BridgedType {
                 //
                 todo!("Shared enum to Rust type name")
             }
-            BridgedType::Foreign(CustomBridgedType::Opaque(opaque)) => {
-                let ty_name = &opaque.ty;
-
-                if opaque.host_lang.is_rust() {
-                    quote! {
-                        super:: #ty_name
-                    }
-                } else {
-                    quote! {
-                        #ty_name
-                    }
-                }
-            }
+            BridgedType::Foreign(CustomBridgedType::Opaque(opaque)) => opaque.rust_ty_tokens(),
         }
     }
 
@@ -462,6 +1254,7 @@ impl BridgedType {
                 StdLibType::Usize => quote! { usize },
                 StdLibType::Isize => quote! { isize },
                 StdLibType::Bool => quote! { bool },
+                StdLibType::Char => quote! { u32 },
                 StdLibType::Pointer(ptr) => {
                     let kind = ptr.kind.to_token_stream();
 
@@ -487,10 +1280,29 @@ impl BridgedType {
                 StdLibType::String => {
                     quote! { *mut #swift_bridge_path::string::RustString }
                 }
+                StdLibType::Vec(vec) if vec.moves_into_swift_array() => {
+                    let elem = vec.ty.to_ffi_compatible_rust_type(swift_bridge_path);
+                    quote! { #swift_bridge_path::vec::FfiVec<#elem> }
+                }
                 StdLibType::Vec(ty) => {
                     let ty = ty.ty.to_rust();
                     quote! { *mut Vec<#ty> }
                 }
+                StdLibType::RefVec(ref_vec) => {
+                    let ty = ref_vec.vec.ty.to_rust();
+                    if ref_vec.mutable {
+                        quote! { *mut Vec<#ty> }
+                    } else {
+                        quote! { *const Vec<#ty> }
+                    }
+                }
+                StdLibType::RefMutPrimitive(primitive) => {
+                    let ty = primitive.to_rust();
+                    quote! { *mut #ty }
+                }
+                StdLibType::VecDeque(deque) => deque
+                    .as_vec_type()
+                    .to_ffi_compatible_rust_type(swift_bridge_path),
                 StdLibType::Option(opt) => match opt.ty.deref() {
                     BridgedType::StdLib(stdlib_ty) => match stdlib_ty {
                         StdLibType::Null => {
@@ -535,6 +1347,9 @@ impl BridgedType {
                         StdLibType::Bool => {
                             quote! { #swift_bridge_path::option::OptionBool }
                         }
+                        StdLibType::Char => {
+                            todo!("Option<char> is not yet supported")
+                        }
                         StdLibType::Pointer(_) => {
                             todo!("Option<*const T> and Option<*mut T> are not yet supported")
                         }
@@ -545,12 +1360,69 @@ impl BridgedType {
                             quote! { #swift_bridge_path::string::RustStr }
                         }
                         StdLibType::String => opt.ty.to_ffi_compatible_rust_type(swift_bridge_path),
-                        StdLibType::Vec(_) => {
-                            todo!("Option<Vec<T>> is not yet supported")
+                        StdLibType::Vec(_) => opt.ty.to_ffi_compatible_rust_type(swift_bridge_path),
+                        StdLibType::RefVec(_) => {
+                            todo!("Option<&Vec<T>> and Option<&mut Vec<T>> are not yet supported")
+                        }
+                        StdLibType::RefMutPrimitive(_) => {
+                            todo!("Option<&mut T> is not yet supported")
+                        }
+                        StdLibType::VecDeque(_) => {
+                            todo!("Option<VecDeque<T>> is not yet supported")
                         }
                         StdLibType::Option(_) => {
                             todo!("Option<Option<T>> is not yet supported")
                         }
+                        StdLibType::HashMap(_) => {
+                            todo!("Option<HashMap<K, V>> is not yet supported")
+                        }
+                        StdLibType::Set(_) => {
+                            todo!(
+                                "Option<HashSet<T>> and Option<BTreeSet<T>> are not yet supported"
+                            )
+                        }
+                        StdLibType::Result(_) => {
+                            todo!("Option<Result<T, E>> is not yet supported")
+                        }
+                        StdLibType::BoxedFn(_) => {
+                            todo!("Option<Box<dyn Fn>> is not yet supported")
+                        }
+                        StdLibType::Array(_) => {
+                            todo!("Option<[T; N]> is not yet supported")
+                        }
+                        StdLibType::Tuple(_) => {
+                            todo!("Option<(A, B)> is not yet supported")
+                        }
+                        StdLibType::Range(_) => {
+                            todo!("Option<Range<T>> is not yet supported")
+                        }
+                        StdLibType::Duration => {
+                            todo!("Option<Duration> is not yet supported")
+                        }
+                        StdLibType::DateTime => {
+                            todo!("Option<DateTime<Utc>> is not yet supported")
+                        }
+                        StdLibType::IpAddr | StdLibType::SocketAddr => {
+                            todo!("Option<IpAddr> and Option<SocketAddr> are not yet supported")
+                        }
+                        StdLibType::U128 | StdLibType::I128 => {
+                            todo!("Option<u128> and Option<i128> are not yet supported")
+                        }
+                        StdLibType::PathBuf => {
+                            todo!("Option<PathBuf> is not yet supported")
+                        }
+                        StdLibType::CowStr => {
+                            todo!("Option<Cow<str>> is not yet supported")
+                        }
+                        StdLibType::BoxedStr => {
+                            todo!("Option<Box<str>> is not yet supported")
+                        }
+                        StdLibType::NonZero(non_zero) => {
+                            non_zero.int.to_ffi_compatible_rust_type(swift_bridge_path)
+                        }
+                        StdLibType::BoxedError => {
+                            opt.ty.to_ffi_compatible_rust_type(swift_bridge_path)
+                        }
                     },
                     BridgedType::Foreign(CustomBridgedType::Shared(SharedType::Struct(
                         shared_struct,
@@ -565,11 +1437,39 @@ impl BridgedType {
                         quote! { #name }
                     }
                     BridgedType::Foreign(CustomBridgedType::Opaque(opaque)) => {
-                        let type_name = &opaque.ty;
+                        let type_name = opaque.rust_ty_tokens();
 
-                        quote! { *mut super::#type_name }
+                        if opaque.reference && !opaque.mutable {
+                            quote! { *const #type_name }
+                        } else {
+                            quote! { *mut #type_name }
+                        }
                     }
                 },
+                StdLibType::HashMap(map) => map.to_ffi_compatible_rust_type(swift_bridge_path),
+                StdLibType::Set(set) => set.to_ffi_compatible_rust_type(swift_bridge_path),
+                StdLibType::Result(result) => result.to_ffi_compatible_rust_type(),
+                StdLibType::BoxedFn(boxed_fn) => boxed_fn.to_ffi_compatible_rust_type(),
+                StdLibType::Array(array) => array.to_ffi_compatible_rust_type(),
+                StdLibType::Tuple(tuple) => tuple.to_ffi_compatible_rust_type(),
+                StdLibType::Range(range) => range.to_ffi_compatible_rust_type(swift_bridge_path),
+                StdLibType::Duration => quote! { #swift_bridge_path::duration::FfiDuration },
+                StdLibType::DateTime => quote! { #swift_bridge_path::date_time::FfiDateTime },
+                StdLibType::IpAddr => quote! { #swift_bridge_path::net::FfiIpAddr },
+                StdLibType::SocketAddr => quote! { #swift_bridge_path::net::FfiSocketAddr },
+                StdLibType::U128 => quote! { #swift_bridge_path::int128::FfiU128 },
+                StdLibType::I128 => quote! { #swift_bridge_path::int128::FfiI128 },
+                StdLibType::PathBuf => {
+                    quote! { *mut #swift_bridge_path::string::RustString }
+                }
+                StdLibType::CowStr => quote! { #swift_bridge_path::string::FfiCowStr },
+                StdLibType::BoxedStr => quote! { #swift_bridge_path::string::FfiBoxedStr },
+                StdLibType::BoxedError => {
+                    quote! { *mut #swift_bridge_path::error::RustError }
+                }
+                StdLibType::NonZero(non_zero) => {
+                    non_zero.int.to_ffi_compatible_rust_type(swift_bridge_path)
+                }
             },
             BridgedType::Foreign(CustomBridgedType::Shared(SharedType::Struct(shared_struct))) => {
                 let ty_name = &shared_struct.name;
@@ -592,9 +1492,12 @@ impl BridgedType {
                 quote! { #ffi_ty_name }
             }
             BridgedType::Foreign(CustomBridgedType::Opaque(opaque)) => {
-                let ty_name = &opaque.ty;
+                let ty_name = opaque.rust_ty_tokens();
 
-                if opaque.host_lang.is_rust() {
+                if opaque.copy.is_some() {
+                    let ffi_repr = opaque.copy_ffi_repr_tokens();
+                    quote! { #ffi_repr }
+                } else if opaque.host_lang.is_rust() {
                     if opaque.reference {
                         let ptr = if opaque.mutable {
                             quote! { *mut }
@@ -602,9 +1505,9 @@ impl BridgedType {
                             quote! { *const }
                         };
 
-                        quote_spanned! {ty_name.span()=> #ptr super::#ty_name }
+                        quote_spanned! {opaque.ty.span()=> #ptr #ty_name }
                     } else {
-                        quote! { *mut super::#ty_name }
+                        quote! { *mut #ty_name }
                     }
                 } else {
                     quote! { #ty_name }
@@ -634,6 +1537,20 @@ impl BridgedType {
                 StdLibType::Usize => "UInt".to_string(),
                 StdLibType::Isize => "Int".to_string(),
                 StdLibType::Bool => "Bool".to_string(),
+                StdLibType::Char => match type_pos {
+                    TypePosition::FnArg(func_host_lang)
+                    | TypePosition::FnReturn(func_host_lang) => {
+                        if func_host_lang.is_swift() {
+                            "UInt32".to_string()
+                        } else {
+                            "Unicode.Scalar".to_string()
+                        }
+                    }
+                    TypePosition::SharedStructField => {
+                        todo!("char fields in shared structs are not yet supported")
+                    }
+                    TypePosition::SwiftCallsRustAsyncOnCompleteReturnTy => "UInt32".to_string(),
+                },
                 StdLibType::Pointer(ptr) => {
                     let maybe_mutable = match ptr.kind {
                         PointerKind::Const => "",
@@ -655,10 +1572,18 @@ impl BridgedType {
                 }
                 StdLibType::RefSlice(slice) => {
                     match type_pos {
+                        TypePosition::FnArg(HostLang::Rust) if slice.is_str_slice() => {
+                            "[String]".to_string()
+                        }
                         TypePosition::FnArg(func_host_lang)
                         | TypePosition::FnReturn(func_host_lang) => {
                             if func_host_lang.is_swift() {
                                 "__private__FfiSlice".to_string()
+                            } else if slice.mutable {
+                                format!(
+                                    "UnsafeMutableBufferPointer<{}>",
+                                    slice.ty.to_swift_type(type_pos)
+                                )
                             } else {
                                 format!("UnsafeBufferPointer<{}>", slice.ty.to_swift_type(type_pos))
                             }
@@ -695,13 +1620,53 @@ impl BridgedType {
                         unimplemented!()
                     }
                 },
+                StdLibType::Vec(vec) if vec.as_data => "Data".to_string(),
+                StdLibType::Vec(vec) if vec.moves_into_swift_array() => match type_pos {
+                    TypePosition::FnArg(func_host_lang)
+                    | TypePosition::FnReturn(func_host_lang) => {
+                        if func_host_lang.is_swift() {
+                            "__private__FfiVec".to_string()
+                        } else {
+                            format!("[{}]", vec.ty.to_swift_type(type_pos))
+                        }
+                    }
+                    TypePosition::SharedStructField => {
+                        todo!("Vec<SharedStruct> and Vec<Vec<T>> fields in shared structs are not yet supported")
+                    }
+                    TypePosition::SwiftCallsRustAsyncOnCompleteReturnTy => {
+                        "__private__FfiVec".to_string()
+                    }
+                },
                 StdLibType::Vec(ty) => {
                     format!("RustVec<{}>", ty.ty.to_swift_type(type_pos))
                 }
+                StdLibType::RefVec(ref_vec) => match type_pos {
+                    // Swift receives a pointer to the Rust-owned Vec, and then wraps it in a
+                    // borrowed `RustVec`.
+                    TypePosition::FnArg(func_host_lang) if func_host_lang.is_swift() => {
+                        "UnsafeMutableRawPointer".to_string()
+                    }
+                    _ => format!("RustVec<{}>", ref_vec.vec.ty.to_swift_type(type_pos)),
+                },
+                StdLibType::RefMutPrimitive(primitive) => match type_pos {
+                    // Swift receives a pointer to the Rust value, which it then passes along as
+                    // an `inout` argument using the pointer's `pointee`.
+                    TypePosition::FnArg(func_host_lang) if func_host_lang.is_swift() => {
+                        format!(
+                            "UnsafeMutablePointer<{}>",
+                            primitive.to_swift_type(type_pos)
+                        )
+                    }
+                    _ => format!("inout {}", primitive.to_swift_type(type_pos)),
+                },
+                StdLibType::VecDeque(deque) => deque.as_vec_type().to_swift_type(type_pos),
                 StdLibType::Option(opt) => match type_pos {
                     TypePosition::FnArg(func_host_lang)
                     | TypePosition::FnReturn(func_host_lang) => {
-                        if func_host_lang.is_swift() {
+                        if func_host_lang.is_swift() && opt.is_opaque_rust_type() {
+                            // `None` crosses the FFI boundary as a null pointer.
+                            opt.to_swift_ffi_type()
+                        } else if func_host_lang.is_swift() {
                             opt.ty.to_swift_type(type_pos)
                         } else {
                             format!("Optional<{}>", opt.ty.to_swift_type(type_pos))
@@ -710,10 +1675,186 @@ impl BridgedType {
                     TypePosition::SharedStructField => {
                         format!("Optional<{}>", opt.ty.to_swift_type(type_pos))
                     }
+                    TypePosition::SwiftCallsRustAsyncOnCompleteReturnTy => opt.to_swift_ffi_type(),
+                },
+                StdLibType::HashMap(map) => match type_pos {
+                    TypePosition::FnArg(func_host_lang)
+                    | TypePosition::FnReturn(func_host_lang) => {
+                        if func_host_lang.is_swift() {
+                            "__private__FfiHashMap".to_string()
+                        } else {
+                            map.to_swift_type()
+                        }
+                    }
+                    TypePosition::SharedStructField => {
+                        todo!("HashMap<K, V> fields in shared structs are not yet supported")
+                    }
+                    TypePosition::SwiftCallsRustAsyncOnCompleteReturnTy => {
+                        "__private__FfiHashMap".to_string()
+                    }
+                },
+                StdLibType::Set(set) => match type_pos {
+                    TypePosition::FnArg(func_host_lang)
+                    | TypePosition::FnReturn(func_host_lang) => {
+                        if func_host_lang.is_swift() {
+                            "__private__FfiSet".to_string()
+                        } else {
+                            set.to_swift_type()
+                        }
+                    }
+                    TypePosition::SharedStructField => {
+                        todo!("Set fields in shared structs are not yet supported")
+                    }
+                    TypePosition::SwiftCallsRustAsyncOnCompleteReturnTy => {
+                        "__private__FfiSet".to_string()
+                    }
+                },
+                StdLibType::Result(result) => result.to_swift_type(type_pos),
+                StdLibType::BoxedFn(boxed_fn) => boxed_fn.to_swift_type(type_pos),
+                StdLibType::Array(array) => array.to_swift_type(type_pos),
+                StdLibType::Tuple(tuple) => tuple.to_swift_type(type_pos),
+                StdLibType::Range(range) => range.to_swift_type(type_pos),
+                StdLibType::U128 | StdLibType::I128 => {
+                    let (ffi_name, swift_name) = match self {
+                        BridgedType::StdLib(StdLibType::U128) => ("__private__FfiU128", "RustU128"),
+                        _ => ("__private__FfiI128", "RustI128"),
+                    };
+
+                    match type_pos {
+                        TypePosition::FnArg(func_host_lang)
+                        | TypePosition::FnReturn(func_host_lang) => {
+                            if func_host_lang.is_swift() {
+                                ffi_name.to_string()
+                            } else {
+                                swift_name.to_string()
+                            }
+                        }
+                        TypePosition::SharedStructField => {
+                            todo!("128 bit integer fields in shared structs are not yet supported")
+                        }
+                        TypePosition::SwiftCallsRustAsyncOnCompleteReturnTy => ffi_name.to_string(),
+                    }
+                }
+                StdLibType::IpAddr | StdLibType::SocketAddr => {
+                    let (ffi_name, swift_name) = match self {
+                        BridgedType::StdLib(StdLibType::IpAddr) => {
+                            ("__private__FfiIpAddr", "RustIpAddr")
+                        }
+                        _ => ("__private__FfiSocketAddr", "RustSocketAddr"),
+                    };
+
+                    match type_pos {
+                        TypePosition::FnArg(func_host_lang)
+                        | TypePosition::FnReturn(func_host_lang) => {
+                            if func_host_lang.is_swift() {
+                                ffi_name.to_string()
+                            } else {
+                                swift_name.to_string()
+                            }
+                        }
+                        TypePosition::SharedStructField => {
+                            todo!("IpAddr and SocketAddr fields in shared structs are not yet supported")
+                        }
+                        TypePosition::SwiftCallsRustAsyncOnCompleteReturnTy => ffi_name.to_string(),
+                    }
+                }
+                StdLibType::Duration => match type_pos {
+                    TypePosition::FnArg(func_host_lang)
+                    | TypePosition::FnReturn(func_host_lang) => {
+                        if func_host_lang.is_swift() {
+                            "__private__FfiDuration".to_string()
+                        } else {
+                            "TimeInterval".to_string()
+                        }
+                    }
+                    TypePosition::SharedStructField => {
+                        todo!("Duration fields in shared structs are not yet supported")
+                    }
+                    TypePosition::SwiftCallsRustAsyncOnCompleteReturnTy => {
+                        "__private__FfiDuration".to_string()
+                    }
+                },
+                StdLibType::DateTime => match type_pos {
+                    TypePosition::FnArg(func_host_lang)
+                    | TypePosition::FnReturn(func_host_lang) => {
+                        if func_host_lang.is_swift() {
+                            "__private__FfiDateTime".to_string()
+                        } else {
+                            "Date".to_string()
+                        }
+                    }
+                    TypePosition::SharedStructField => {
+                        todo!("DateTime<Utc> fields in shared structs are not yet supported")
+                    }
+                    TypePosition::SwiftCallsRustAsyncOnCompleteReturnTy => {
+                        "__private__FfiDateTime".to_string()
+                    }
+                },
+                StdLibType::PathBuf => match type_pos {
+                    TypePosition::FnArg(func_host_lang)
+                    | TypePosition::FnReturn(func_host_lang) => {
+                        if func_host_lang.is_swift() {
+                            "UnsafeMutableRawPointer".to_string()
+                        } else {
+                            "URL".to_string()
+                        }
+                    }
+                    TypePosition::SharedStructField => {
+                        todo!("PathBuf fields in shared structs are not yet supported")
+                    }
                     TypePosition::SwiftCallsRustAsyncOnCompleteReturnTy => {
                         unimplemented!()
                     }
                 },
+                StdLibType::CowStr => match type_pos {
+                    TypePosition::FnArg(func_host_lang)
+                    | TypePosition::FnReturn(func_host_lang) => {
+                        if func_host_lang.is_swift() {
+                            "__private__FfiCowStr".to_string()
+                        } else {
+                            "String".to_string()
+                        }
+                    }
+                    TypePosition::SharedStructField => {
+                        todo!("Cow<str> fields in shared structs are not yet supported")
+                    }
+                    TypePosition::SwiftCallsRustAsyncOnCompleteReturnTy => {
+                        "__private__FfiCowStr".to_string()
+                    }
+                },
+                StdLibType::BoxedStr => match type_pos {
+                    TypePosition::FnArg(func_host_lang)
+                    | TypePosition::FnReturn(func_host_lang) => {
+                        if func_host_lang.is_swift() {
+                            "__private__FfiBoxedStr".to_string()
+                        } else {
+                            "String".to_string()
+                        }
+                    }
+                    TypePosition::SharedStructField => {
+                        todo!("Box<str> fields in shared structs are not yet supported")
+                    }
+                    TypePosition::SwiftCallsRustAsyncOnCompleteReturnTy => {
+                        "__private__FfiBoxedStr".to_string()
+                    }
+                },
+                StdLibType::BoxedError => match type_pos {
+                    TypePosition::FnArg(func_host_lang)
+                    | TypePosition::FnReturn(func_host_lang) => {
+                        if func_host_lang.is_swift() {
+                            "UnsafeMutableRawPointer".to_string()
+                        } else {
+                            "RustError".to_string()
+                        }
+                    }
+                    TypePosition::SharedStructField => {
+                        todo!("Box<dyn Error> fields in shared structs are not yet supported")
+                    }
+                    TypePosition::SwiftCallsRustAsyncOnCompleteReturnTy => {
+                        "UnsafeMutableRawPointer".to_string()
+                    }
+                },
+                StdLibType::NonZero(non_zero) => non_zero.int.to_swift_type(type_pos),
             },
             BridgedType::Foreign(CustomBridgedType::Shared(SharedType::Struct(shared_struct))) => {
                 match type_pos {
@@ -748,12 +1889,27 @@ impl BridgedType {
                 }
             }
             BridgedType::Foreign(CustomBridgedType::Opaque(opaque)) => {
-                if opaque.host_lang.is_rust() {
+                if opaque.copy.is_some() {
+                    match type_pos {
+                        TypePosition::FnArg(func_host_lang)
+                        | TypePosition::FnReturn(func_host_lang) => {
+                            if func_host_lang.is_rust() {
+                                opaque.swift_name()
+                            } else {
+                                opaque.copy_ffi_repr_string()
+                            }
+                        }
+                        TypePosition::SharedStructField => opaque.swift_name(),
+                        TypePosition::SwiftCallsRustAsyncOnCompleteReturnTy => {
+                            unimplemented!()
+                        }
+                    }
+                } else if opaque.host_lang.is_rust() {
                     match type_pos {
                         TypePosition::FnArg(func_host_lang)
                         | TypePosition::FnReturn(func_host_lang) => {
                             if func_host_lang.is_rust() {
-                                let mut class_name = opaque.ty.to_string();
+                                let mut class_name = opaque.swift_name();
 
                                 if opaque.reference {
                                     class_name += "Ref";
@@ -772,8 +1928,9 @@ impl BridgedType {
                             //
                             unimplemented!()
                         }
+                        // C imports the callback's `void*` parameter as an optional pointer.
                         TypePosition::SwiftCallsRustAsyncOnCompleteReturnTy => {
-                            unimplemented!()
+                            "UnsafeMutableRawPointer?".to_string()
                         }
                     }
                 } else {
@@ -799,6 +1956,26 @@ impl BridgedType {
         }
     }
 
+    /// The type's declaration in an Objective-C header.
+    ///
+    /// The Objective-C header assumes that pointers are non-null, so we annotate the pointers
+    /// that can be null, such as an `Option<String>` or a raw pointer, as `_Nullable`.
+    pub fn to_objc(&self) -> String {
+        let c = self.to_c();
+
+        let is_nullable = match self {
+            BridgedType::StdLib(StdLibType::Option(_)) => c.ends_with('*'),
+            BridgedType::StdLib(StdLibType::Pointer(_)) => true,
+            _ => false,
+        };
+
+        if is_nullable {
+            format!("{} _Nullable", c)
+        } else {
+            c
+        }
+    }
+
     pub fn to_c(&self) -> String {
         match self {
             BridgedType::StdLib(stdlib_type) => match stdlib_type {
@@ -815,6 +1992,7 @@ impl BridgedType {
                 StdLibType::Usize => "uintptr_t".to_string(),
                 StdLibType::Isize => "intptr_t".to_string(),
                 StdLibType::Bool => "bool".to_string(),
+                StdLibType::Char => "uint32_t".to_string(),
                 StdLibType::Pointer(ptr) => {
                     let maybe_const = match ptr.kind {
                         PointerKind::Const => " const ",
@@ -832,17 +2010,46 @@ impl BridgedType {
                 StdLibType::Str => "struct RustStr".to_string(),
                 StdLibType::Null => "void".to_string(),
                 StdLibType::String => "void*".to_string(),
-                StdLibType::Vec(_) => "void*".to_string(),
+                StdLibType::Vec(vec) if vec.moves_into_swift_array() => {
+                    "struct __private__FfiVec".to_string()
+                }
+                StdLibType::Vec(_) | StdLibType::RefVec(_) => "void*".to_string(),
+                StdLibType::RefMutPrimitive(primitive) => format!("{}*", primitive.to_c()),
+                StdLibType::VecDeque(deque) => deque.as_vec_type().to_c(),
                 StdLibType::Option(opt) => opt.to_c(),
+                StdLibType::HashMap(_) => "struct __private__FfiHashMap".to_string(),
+                StdLibType::Set(_) => "struct __private__FfiSet".to_string(),
+                StdLibType::Result(result) => result.to_c(),
+                StdLibType::BoxedFn(boxed_fn) => boxed_fn.to_c(),
+                StdLibType::Array(array) => array.to_c(),
+                StdLibType::Tuple(tuple) => tuple.to_c(),
+                StdLibType::Range(range) => range.to_c(),
+                StdLibType::Duration => "struct __private__FfiDuration".to_string(),
+                StdLibType::DateTime => "struct __private__FfiDateTime".to_string(),
+                StdLibType::IpAddr => "struct __private__FfiIpAddr".to_string(),
+                StdLibType::SocketAddr => "struct __private__FfiSocketAddr".to_string(),
+                StdLibType::U128 => "struct __private__FfiU128".to_string(),
+                StdLibType::I128 => "struct __private__FfiI128".to_string(),
+                StdLibType::PathBuf => "void*".to_string(),
+                StdLibType::CowStr => "struct __private__FfiCowStr".to_string(),
+                StdLibType::BoxedStr => "struct __private__FfiBoxedStr".to_string(),
+                StdLibType::BoxedError => "void*".to_string(),
+                StdLibType::NonZero(non_zero) => non_zero.int.to_c(),
             },
             BridgedType::Foreign(CustomBridgedType::Shared(SharedType::Struct(shared_struct))) => {
                 format!("struct {}", shared_struct.ffi_name_string())
             }
             BridgedType::Foreign(CustomBridgedType::Shared(SharedType::Enum(shared_enum))) => {
-                format!("struct {}", shared_enum.ffi_name_string())
+                if shared_enum.transparent {
+                    shared_enum.ffi_name_string()
+                } else {
+                    format!("struct {}", shared_enum.ffi_name_string())
+                }
             }
             BridgedType::Foreign(CustomBridgedType::Opaque(opaque)) => {
-                if opaque.host_lang.is_rust() {
+                if opaque.copy.is_some() {
+                    format!("struct {}", opaque.copy_ffi_repr_string())
+                } else if opaque.host_lang.is_rust() {
                     "void*".to_string()
                 } else {
                     "struct __private__PointerToSwiftType".to_string()
@@ -923,14 +2130,23 @@ impl BridgedType {
                 | StdLibType::Bool => {
                     quote! { #expression }
                 }
+                StdLibType::Char => {
+                    quote! { u32::from( #expression ) }
+                }
                 StdLibType::Pointer(_) => {
                     quote! {
                         #expression
                     }
                 }
-                StdLibType::RefSlice(_) => {
-                    quote! {
-                        #swift_bridge_path::FfiSlice::from_slice( #expression )
+                StdLibType::RefSlice(slice) => {
+                    if slice.mutable {
+                        quote! {
+                            #swift_bridge_path::FfiSlice::from_mut_slice( #expression )
+                        }
+                    } else {
+                        quote! {
+                            #swift_bridge_path::FfiSlice::from_slice( #expression )
+                        }
                     }
                 }
                 StdLibType::Str => {
@@ -943,12 +2159,120 @@ impl BridgedType {
                         #swift_bridge_path::string::RustString( #expression ).box_into_raw()
                     }
                 }
+                StdLibType::Vec(vec) if vec.moves_into_swift_array() => {
+                    let elem = vec.ty.convert_rust_value_to_ffi_compatible_value(
+                        &quote! { elem },
+                        swift_bridge_path,
+                    );
+
+                    quote! {
+                        #swift_bridge_path::vec::FfiVec::from_elems(
+                            #expression.into_iter().map(|elem| #elem)
+                        )
+                    }
+                }
                 StdLibType::Vec(_) => {
                     quote! { Box::into_raw(Box::new( #expression )) }
                 }
+                StdLibType::RefVec(_) | StdLibType::RefMutPrimitive(_) => {
+                    let ty = self.to_ffi_compatible_rust_type(swift_bridge_path);
+                    quote! { #expression as #ty }
+                }
+                // A `VecDeque` iterates from front to back, so it gets moved into the Swift
+                // Array the same way that a `Vec` does.
+                StdLibType::VecDeque(deque) => deque
+                    .as_vec_type()
+                    .convert_rust_value_to_ffi_compatible_value(expression, swift_bridge_path),
                 StdLibType::Option(opt) => {
                     opt.convert_rust_value_to_ffi_value(expression, swift_bridge_path)
                 }
+                StdLibType::HashMap(map) => {
+                    map.convert_rust_value_to_ffi_value(expression, swift_bridge_path)
+                }
+                StdLibType::Set(set) => {
+                    set.convert_rust_value_to_ffi_value(expression, swift_bridge_path)
+                }
+                StdLibType::Result(result) => {
+                    result.convert_rust_value_to_ffi_value(expression, swift_bridge_path)
+                }
+                StdLibType::BoxedFn(_) => {
+                    todo!("Returning Box<dyn Fn> from Rust is not yet supported")
+                }
+                StdLibType::Array(array) => array.convert_rust_value_to_ffi_value(expression),
+                StdLibType::Tuple(tuple) => {
+                    tuple.convert_rust_value_to_ffi_value(expression, swift_bridge_path)
+                }
+                StdLibType::Range(range) => {
+                    range.convert_rust_value_to_ffi_value(expression, swift_bridge_path)
+                }
+                StdLibType::Duration => {
+                    quote! {
+                        #swift_bridge_path::duration::FfiDuration::from_duration( #expression )
+                    }
+                }
+                // `timestamp()` rounds towards negative infinity, so the nanoseconds are never
+                // negative, even for dates before 1970.
+                StdLibType::DateTime => {
+                    quote! {
+                        {
+                            let date_time = #expression;
+                            #swift_bridge_path::date_time::FfiDateTime {
+                                secs: date_time.timestamp(),
+                                nanos: date_time.timestamp_subsec_nanos(),
+                            }
+                        }
+                    }
+                }
+                StdLibType::IpAddr => {
+                    quote! {
+                        #swift_bridge_path::net::FfiIpAddr::from_ip_addr( #expression )
+                    }
+                }
+                StdLibType::SocketAddr => {
+                    quote! {
+                        #swift_bridge_path::net::FfiSocketAddr::from_socket_addr( #expression )
+                    }
+                }
+                StdLibType::U128 => {
+                    quote! {
+                        #swift_bridge_path::int128::FfiU128::from_u128( #expression )
+                    }
+                }
+                StdLibType::I128 => {
+                    quote! {
+                        #swift_bridge_path::int128::FfiI128::from_i128( #expression )
+                    }
+                }
+                // Paths that aren't valid UTF-8 get converted lossily, since Swift strings are
+                // always valid Unicode.
+                StdLibType::PathBuf => {
+                    quote! {
+                        #swift_bridge_path::string::RustString(
+                            #expression
+                                .into_os_string()
+                                .into_string()
+                                .unwrap_or_else(|path| path.to_string_lossy().into_owned())
+                        ).box_into_raw()
+                    }
+                }
+                StdLibType::CowStr => {
+                    quote! {
+                        #swift_bridge_path::string::FfiCowStr::from_cow( #expression )
+                    }
+                }
+                StdLibType::BoxedStr => {
+                    quote! {
+                        #swift_bridge_path::string::FfiBoxedStr::from_boxed_str( #expression )
+                    }
+                }
+                StdLibType::BoxedError => {
+                    quote! {
+                        Box::into_raw(Box::new(#swift_bridge_path::error::RustError( #expression )))
+                    }
+                }
+                StdLibType::NonZero(non_zero) => {
+                    non_zero.convert_rust_value_to_ffi_value(expression)
+                }
             },
             BridgedType::Foreign(CustomBridgedType::Shared(SharedType::Struct(_shared_struct))) => {
                 quote! {
@@ -961,9 +2285,20 @@ impl BridgedType {
                 }
             }
             BridgedType::Foreign(CustomBridgedType::Opaque(opaque)) => {
-                let ty_name = &opaque.ty;
+                let ty_name = opaque.rust_ty_tokens();
 
-                if opaque.host_lang.is_rust() {
+                if opaque.copy.is_some() {
+                    let ffi_repr = opaque.copy_ffi_repr_tokens();
+                    let value = if opaque.reference {
+                        quote! { *#expression }
+                    } else {
+                        quote! { #expression }
+                    };
+
+                    quote! {
+                        #ffi_repr::from_rust_repr(#value)
+                    }
+                } else if opaque.host_lang.is_rust() {
                     if opaque.reference {
                         let ptr = if opaque.mutable {
                             quote! { *mut }
@@ -972,11 +2307,11 @@ impl BridgedType {
                         };
 
                         quote! {
-                            #expression as #ptr super::#ty_name
+                            #expression as #ptr #ty_name
                         }
                     } else {
                         quote! {
-                            Box::into_raw(Box::new(#expression)) as *mut super::#ty_name
+                            Box::into_raw(Box::new(#expression)) as *mut #ty_name
                         }
                     }
                 } else {
@@ -1013,11 +2348,25 @@ impl BridgedType {
                 | StdLibType::Bool => {
                     quote_spanned! {span=> #value }
                 }
+                // Swift's `Unicode.Scalar` can't hold a surrogate code point, so this only fails
+                // if the value was corrupted on its way across the FFI boundary.
+                StdLibType::Char => {
+                    quote_spanned! {span=>
+                        char::from_u32(#value).expect("Invalid Unicode scalar value")
+                    }
+                }
                 StdLibType::Pointer(_) => {
                     quote_spanned! {span=> #value }
                 }
-                StdLibType::RefSlice(_reference) => {
-                    quote_spanned! {span=> #value.as_slice() }
+                StdLibType::RefSlice(slice) if slice.is_str_slice() => {
+                    quote_spanned! {span=> & #value.to_str_vec() }
+                }
+                StdLibType::RefSlice(slice) => {
+                    if slice.mutable {
+                        quote_spanned! {span=> #value.as_mut_slice() }
+                    } else {
+                        quote_spanned! {span=> #value.as_slice() }
+                    }
                 }
                 StdLibType::Str => {
                     quote_spanned! {span=> #value.to_str() }
@@ -1027,14 +2376,102 @@ impl BridgedType {
                         unsafe { Box::from_raw(#value).0 }
                     }
                 }
+                StdLibType::Vec(vec) if vec.moves_into_swift_array() => {
+                    todo!("Passing a Swift Array to Rust as a Vec<T> is not yet supported")
+                }
                 StdLibType::Vec(_) => {
                     quote_spanned! {span=>
                         unsafe { * Box::from_raw(#value) }
                     }
                 }
+                StdLibType::RefVec(ref_vec) => {
+                    if ref_vec.mutable {
+                        quote_spanned! {span=>
+                            unsafe { &mut * #value }
+                        }
+                    } else {
+                        quote_spanned! {span=>
+                            unsafe { & * #value }
+                        }
+                    }
+                }
+                StdLibType::RefMutPrimitive(_) => {
+                    quote_spanned! {span=>
+                        unsafe { &mut * #value }
+                    }
+                }
+                StdLibType::VecDeque(_) => {
+                    todo!("Passing a Swift Array to Rust as a VecDeque<T> is not yet supported")
+                }
                 StdLibType::Option(bridged_option) => {
                     bridged_option.convert_ffi_value_to_rust_value(value)
                 }
+                StdLibType::HashMap(map) => map.convert_ffi_value_to_rust_value(value, span),
+                StdLibType::Set(set) => set.convert_ffi_value_to_rust_value(value, span),
+                StdLibType::Result(_) => {
+                    todo!("Result<T, E> arguments are not yet supported")
+                }
+                StdLibType::BoxedFn(boxed_fn) => boxed_fn.convert_ffi_value_to_rust_value(value),
+                StdLibType::Array(array) => array.convert_ffi_value_to_rust_value(value),
+                StdLibType::Tuple(tuple) => tuple.convert_ffi_value_to_rust_value(value, span),
+                StdLibType::Range(range) => range.convert_ffi_value_to_rust_value(value, span),
+                StdLibType::Duration => {
+                    quote_spanned! {span=>
+                        #value.into_duration()
+                    }
+                }
+                StdLibType::DateTime => {
+                    quote_spanned! {span=>
+                        {
+                            let date_time = #value;
+                            chrono::TimeZone::timestamp_opt(
+                                &chrono::Utc,
+                                date_time.secs,
+                                date_time.nanos,
+                            )
+                            .unwrap()
+                        }
+                    }
+                }
+                StdLibType::IpAddr => {
+                    quote_spanned! {span=>
+                        #value.into_ip_addr()
+                    }
+                }
+                StdLibType::SocketAddr => {
+                    quote_spanned! {span=>
+                        #value.into_socket_addr()
+                    }
+                }
+                StdLibType::U128 => {
+                    quote_spanned! {span=>
+                        #value.into_u128()
+                    }
+                }
+                StdLibType::I128 => {
+                    quote_spanned! {span=>
+                        #value.into_i128()
+                    }
+                }
+                StdLibType::PathBuf => {
+                    quote_spanned! {span=>
+                        std::path::PathBuf::from(unsafe { Box::from_raw(#value).0 })
+                    }
+                }
+                StdLibType::CowStr => {
+                    todo!("Passing Cow<str> from Swift to Rust is not yet supported")
+                }
+                StdLibType::BoxedStr => {
+                    todo!("Passing Box<str> from Swift to Rust is not yet supported")
+                }
+                StdLibType::BoxedError => {
+                    quote_spanned! {span=>
+                        unsafe { Box::from_raw(#value).0 }
+                    }
+                }
+                StdLibType::NonZero(non_zero) => {
+                    non_zero.convert_ffi_value_to_rust_value(value, span)
+                }
             },
             BridgedType::Foreign(CustomBridgedType::Shared(SharedType::Struct(_shared_struct))) => {
                 quote_spanned! {span=>
@@ -1047,7 +2484,23 @@ impl BridgedType {
                 }
             }
             BridgedType::Foreign(CustomBridgedType::Opaque(opaque)) => {
-                if opaque.host_lang.is_rust() {
+                if opaque.copy.is_some() {
+                    if opaque.reference {
+                        let maybe_mut = if opaque.mutable {
+                            quote! { mut }
+                        } else {
+                            quote! {}
+                        };
+
+                        quote_spanned! {span=>
+                            & #maybe_mut #value.into_rust_repr()
+                        }
+                    } else {
+                        quote_spanned! {span=>
+                            #value.into_rust_repr()
+                        }
+                    }
+                } else if opaque.host_lang.is_rust() {
                     if opaque.reference {
                         let maybe_mut = if opaque.mutable {
                             quote! { mut }
@@ -1107,6 +2560,7 @@ impl BridgedType {
                 | StdLibType::F32
                 | StdLibType::F64
                 | StdLibType::Bool => value.to_string(),
+                StdLibType::Char => format!("Unicode.Scalar({})!", value),
                 StdLibType::Pointer(ptr) => match &ptr.pointee {
                     Pointee::BuiltIn(_) => value.to_string(),
                     Pointee::Void(_ty) => match ptr.kind {
@@ -1132,9 +2586,16 @@ impl BridgedType {
                     },
                 },
                 StdLibType::RefSlice(ty) => {
+                    let buffer_pointer = if ty.mutable {
+                        "UnsafeMutableBufferPointer"
+                    } else {
+                        "UnsafeBufferPointer"
+                    };
+
                     format!(
-                           "let slice = {value}; return UnsafeBufferPointer(start: slice.start.assumingMemoryBound(to: {ty}.self), count: Int(slice.len));",
+                           "let slice = {value}; return {buffer_pointer}(start: slice.start.assumingMemoryBound(to: {ty}.self), count: Int(slice.len));",
                            value = value,
+                           buffer_pointer = buffer_pointer,
                            ty = ty.ty.to_swift_type(type_pos)
                        )
                 }
@@ -1142,19 +2603,79 @@ impl BridgedType {
                 StdLibType::String => {
                     format!("RustString(ptr: {})", value)
                 }
+                StdLibType::Vec(vec) if vec.as_data => {
+                    format!("{value}.toData()", value = value)
+                }
+                StdLibType::Vec(vec) if vec.moves_into_swift_array() && vec.has_numeric_elems() => {
+                    format!(
+                        "{value}.toArray(of: {elem}.self)",
+                        value = value,
+                        elem = vec.ffi_elem_swift_type(),
+                    )
+                }
+                StdLibType::Vec(vec) if vec.moves_into_swift_array() => {
+                    format!(
+                        "{value}.toArray({{ (elem: {ffi_elem}) in {elem} }})",
+                        value = value,
+                        ffi_elem = vec.ffi_elem_swift_type(),
+                        elem = vec.ty.convert_ffi_value_to_swift_value("elem", type_pos),
+                    )
+                }
                 StdLibType::Vec(_ty) => {
                     format!("RustVec(ptr: {})", value)
                 }
+                // The Vec is still owned by Rust, so Swift must not free it.
+                StdLibType::RefVec(_) => {
+                    format!("RustVec(borrowedPtr: {})", value)
+                }
+                // Swift passes the pointed to value along as an `inout` argument.
+                StdLibType::RefMutPrimitive(_) => {
+                    format!("&{}.pointee", value)
+                }
+                StdLibType::VecDeque(deque) => deque
+                    .as_vec_type()
+                    .convert_ffi_value_to_swift_value(value, type_pos),
                 StdLibType::Option(opt) => opt.convert_ffi_expression_to_swift(value),
+                StdLibType::HashMap(map) => map.convert_ffi_expression_to_swift(value),
+                StdLibType::Set(set) => set.convert_ffi_expression_to_swift(value),
+                StdLibType::Result(result) => result.convert_ffi_expression_to_swift(value),
+                StdLibType::BoxedFn(_) => {
+                    todo!("Returning Box<dyn Fn> from Rust is not yet supported")
+                }
+                StdLibType::Array(array) => array.convert_ffi_expression_to_swift(value),
+                StdLibType::Tuple(tuple) => tuple.convert_ffi_expression_to_swift(value, type_pos),
+                StdLibType::Range(range) => range.convert_ffi_expression_to_swift(value),
+                StdLibType::Duration => format!("{}.intoTimeInterval()", value),
+                StdLibType::DateTime => format!("{}.intoDate()", value),
+                StdLibType::U128
+                | StdLibType::I128
+                | StdLibType::IpAddr
+                | StdLibType::SocketAddr => format!("{}.intoSwiftRepr()", value),
+                StdLibType::PathBuf => {
+                    format!(
+                        "URL(fileURLWithPath: RustString(ptr: {}).toString())",
+                        value
+                    )
+                }
+                StdLibType::CowStr | StdLibType::BoxedStr => format!("{}.intoString()", value),
+                StdLibType::BoxedError => format!("RustError(ptr: {})", value),
+                StdLibType::NonZero(_) => value.to_string(),
             },
             BridgedType::Foreign(CustomBridgedType::Shared(SharedType::Struct(_shared_struct))) => {
                 format!("{}.intoSwiftRepr()", value)
             }
-            BridgedType::Foreign(CustomBridgedType::Shared(SharedType::Enum(_shared_enum))) => {
+            BridgedType::Foreign(CustomBridgedType::Shared(SharedType::Enum(shared_enum))) => {
+                if shared_enum.transparent {
+                    format!("{}(rawValue: {})!", shared_enum.swift_name_string(), value)
+                } else {
+                    format!("{}.intoSwiftRepr()", value)
+                }
+            }
+            BridgedType::Foreign(CustomBridgedType::Opaque(opaque)) if opaque.copy.is_some() => {
                 format!("{}.intoSwiftRepr()", value)
             }
             BridgedType::Foreign(CustomBridgedType::Opaque(opaque)) => {
-                let mut ty_name = opaque.ty.to_string();
+                let mut ty_name = opaque.swift_name();
 
                 if opaque.reference {
                     ty_name += "Ref";
@@ -1164,7 +2685,15 @@ impl BridgedType {
                 }
 
                 if opaque.host_lang.is_rust() {
-                    format!("{ty_name}(ptr: {value})", ty_name = ty_name, value = value,)
+                    if matches!(
+                        type_pos,
+                        TypePosition::SwiftCallsRustAsyncOnCompleteReturnTy
+                    ) {
+                        // Rust boxed the future's output, so the class takes ownership of it.
+                        format!("{ty_name}(ptr: {value}!)", ty_name = ty_name, value = value,)
+                    } else {
+                        format!("{ty_name}(ptr: {value})", ty_name = ty_name, value = value,)
+                    }
                 } else {
                     format!(
                         "Unmanaged<{ty_name}>.fromOpaque({value}.ptr).takeRetainedValue()",
@@ -1204,6 +2733,13 @@ impl BridgedType {
                 | StdLibType::F32
                 | StdLibType::F64
                 | StdLibType::Bool => value.to_string(),
+                StdLibType::Char => format!("{}.value", value),
+                StdLibType::RefSlice(slice)
+                    if slice.is_str_slice()
+                        && matches!(type_pos, TypePosition::FnArg(HostLang::Rust)) =>
+                {
+                    format!("{}AsRustStrSlice", value)
+                }
                 StdLibType::RefSlice(_) => {
                     format!("{}.toFfiSlice()", value)
                 }
@@ -1248,15 +2784,69 @@ impl BridgedType {
                         value = value
                     )
                 }
+                StdLibType::Vec(vec) if vec.moves_into_swift_array() => {
+                    todo!("Passing a Swift Array to Rust as a Vec<T> is not yet supported")
+                }
                 StdLibType::Vec(_) => {
                     format!(
                         "{{ let val = {value}; val.isOwned = false; return val.ptr }}()",
                         value = value
                     )
                 }
+                StdLibType::RefVec(_) => {
+                    format!("{value}.ptr", value = value)
+                }
+                StdLibType::RefMutPrimitive(_) => {
+                    format!("&{value}", value = value)
+                }
+                StdLibType::VecDeque(_) => {
+                    todo!("Passing a Swift Array to Rust as a VecDeque<T> is not yet supported")
+                }
                 StdLibType::Option(option) => {
                     option.convert_swift_expression_to_ffi_compatible(value, type_pos)
                 }
+                StdLibType::HashMap(map) => map.convert_swift_expression_to_ffi_compatible(value),
+                StdLibType::Set(set) => set.convert_swift_expression_to_ffi_compatible(value),
+                StdLibType::Result(_) => {
+                    todo!("Result<T, E> arguments are not yet supported")
+                }
+                StdLibType::BoxedFn(boxed_fn) => {
+                    boxed_fn.convert_swift_expression_to_ffi_compatible(value)
+                }
+                StdLibType::Array(array) => array.convert_swift_expression_to_ffi_compatible(value),
+                StdLibType::Tuple(tuple) => {
+                    tuple.convert_swift_expression_to_ffi_compatible(value, type_pos)
+                }
+                StdLibType::Range(range) => range.convert_swift_expression_to_ffi_compatible(value),
+                StdLibType::Duration => {
+                    format!("__private__FfiDuration.fromTimeInterval({})", value)
+                }
+                StdLibType::DateTime => {
+                    format!("__private__FfiDateTime.fromDate({})", value)
+                }
+                StdLibType::U128
+                | StdLibType::I128
+                | StdLibType::IpAddr
+                | StdLibType::SocketAddr => format!("{}.intoFfiRepr()", value),
+                StdLibType::PathBuf => {
+                    format!(
+                        "{{ let rustString = {value}.path.intoRustString(); rustString.isOwned = false; return rustString.ptr }}()",
+                        value = value
+                    )
+                }
+                StdLibType::CowStr => {
+                    todo!("Passing Cow<str> from Swift to Rust is not yet supported")
+                }
+                StdLibType::BoxedStr => {
+                    todo!("Passing Box<str> from Swift to Rust is not yet supported")
+                }
+                StdLibType::BoxedError => {
+                    format!(
+                        "{{ let val = {value}; val.isOwned = false; return val.ptr }}()",
+                        value = value
+                    )
+                }
+                StdLibType::NonZero(_) => value.to_string(),
             },
             BridgedType::Foreign(CustomBridgedType::Shared(SharedType::Struct(_shared_struct))) => {
                 format!("{}.intoFfiRepr()", value)
@@ -1264,8 +2854,11 @@ impl BridgedType {
             BridgedType::Foreign(CustomBridgedType::Shared(SharedType::Enum(_shared_enum))) => {
                 format!("{}.intoFfiRepr()", value)
             }
+            BridgedType::Foreign(CustomBridgedType::Opaque(opaque)) if opaque.copy.is_some() => {
+                format!("{}.intoFfiRepr()", value)
+            }
             BridgedType::Foreign(CustomBridgedType::Opaque(opaque)) => {
-                let ty_name = &opaque.ty;
+                let ty_name = opaque.swift_name();
 
                 if opaque.host_lang.is_rust() {
                     if opaque.reference {
@@ -1336,14 +2929,23 @@ impl BridgedType {
                 | StdLibType::U64
                 | StdLibType::I64
                 | StdLibType::Usize
-                | StdLibType::Isize => Some("stdint.h"),
+                | StdLibType::Isize
+                | StdLibType::Char => Some("stdint.h"),
+                StdLibType::NonZero(non_zero) => non_zero.int.c_include(),
+                StdLibType::Option(opt)
+                    if matches!(opt.ty.deref(), BridgedType::StdLib(StdLibType::NonZero(_))) =>
+                {
+                    opt.ty.c_include()
+                }
                 StdLibType::Bool => Some("stdbool.h"),
                 StdLibType::Pointer(ptr) => match &ptr.pointee {
                     Pointee::BuiltIn(ty) => ty.c_include(),
                     Pointee::Void(_) => None,
                 },
                 StdLibType::RefSlice(slice) => slice.ty.c_include(),
-                StdLibType::Vec(_vec) => Some("stdint.h"),
+                StdLibType::Vec(_vec) | StdLibType::VecDeque(_vec) => Some("stdint.h"),
+                StdLibType::Result(result) => result.ok_ty.c_include(),
+                StdLibType::Array(array) => array.c_include(),
                 _ => None,
             },
             BridgedType::Foreign(CustomBridgedType::Shared(SharedType::Struct(_shared_struct))) => {
@@ -1388,6 +2990,9 @@ impl BridgedType {
                     rust: quote! { bool },
                     swift: "bool".into(),
                 },
+                StdLibType::Char => {
+                    todo!("Support Option<char>")
+                }
                 StdLibType::Pointer(_) => {
                     todo!("Support Option<*const T> and Option<*mut T>")
                 }
@@ -1416,12 +3021,75 @@ impl BridgedType {
                         swift: "TODO_SWIFT_OPTIONAL_STRING_SUPPORT".to_string(),
                     }
                 }
-                StdLibType::Vec(_) => {
-                    todo!("Support Option<Vec<T>>")
+                // A `None` is an `FfiVec` with a null buffer. An empty Vec always has a non-null
+                // buffer, so the two can be told apart.
+                StdLibType::Vec(_) => UnusedOptionNoneValue {
+                    rust: quote! { #swift_bridge_path::vec::FfiVec::none() },
+                    swift: "__private__FfiVec(elems: nil, len: 0, owner: nil)".to_string(),
+                },
+                StdLibType::VecDeque(_) => {
+                    todo!("Support Option<VecDeque<T>>")
+                }
+                StdLibType::RefVec(_) => {
+                    todo!("Support Option<&Vec<T>> and Option<&mut Vec<T>>")
+                }
+                StdLibType::RefMutPrimitive(_) => {
+                    todo!("Support Option<&mut T>")
                 }
                 StdLibType::Option(_) => {
                     todo!("Support nested Option<Option<T>>")
                 }
+                StdLibType::HashMap(_) => {
+                    todo!("Support Option<HashMap<K, V>>")
+                }
+                StdLibType::Set(_) => {
+                    todo!("Support Option<HashSet<T>> and Option<BTreeSet<T>>")
+                }
+                StdLibType::Result(_) => {
+                    todo!("Support Option<Result<T, E>>")
+                }
+                StdLibType::BoxedFn(_) => {
+                    todo!("Support Option<Box<dyn Fn>>")
+                }
+                StdLibType::Array(_) => {
+                    todo!("Support Option<[T; N]>")
+                }
+                StdLibType::Tuple(_) => {
+                    todo!("Support Option<(A, B)>")
+                }
+                StdLibType::Range(_) => {
+                    todo!("Support Option<Range<T>>")
+                }
+                StdLibType::Duration => {
+                    todo!("Support Option<Duration>")
+                }
+                StdLibType::DateTime => {
+                    todo!("Support Option<DateTime<Utc>>")
+                }
+                StdLibType::IpAddr | StdLibType::SocketAddr => {
+                    todo!("Support Option<IpAddr> and Option<SocketAddr>")
+                }
+                StdLibType::U128 | StdLibType::I128 => {
+                    todo!("Support Option<u128> and Option<i128>")
+                }
+                StdLibType::PathBuf => {
+                    todo!("Support Option<PathBuf>")
+                }
+                StdLibType::CowStr => {
+                    todo!("Support Option<Cow<str>>")
+                }
+                StdLibType::BoxedStr => {
+                    todo!("Support Option<Box<str>>")
+                }
+                // Zero is never a valid value, so it doubles as the `None` niche.
+                StdLibType::NonZero(_) => UnusedOptionNoneValue {
+                    rust: quote! { 0 },
+                    swift: "0".into(),
+                },
+                StdLibType::BoxedError => UnusedOptionNoneValue {
+                    rust: quote! { std::ptr::null_mut() },
+                    swift: "nil".to_string(),
+                },
             },
             BridgedType::Foreign(CustomBridgedType::Shared(SharedType::Struct(shared_struct))) => {
                 let option_name = shared_struct.ffi_option_name_tokens();
@@ -1438,13 +3106,22 @@ impl BridgedType {
                 }
             }
             BridgedType::Foreign(CustomBridgedType::Opaque(opaque)) => {
-                let ty_name = &opaque.ty;
+                let ty_name = opaque.rust_ty_tokens();
 
                 if opaque.reference {
-                    todo!("Support returning Option<&T> where T is an opaque type")
+                    let null = if opaque.mutable {
+                        quote! { std::ptr::null_mut::<#ty_name>() }
+                    } else {
+                        quote! { std::ptr::null::<#ty_name>() }
+                    };
+
+                    UnusedOptionNoneValue {
+                        rust: null,
+                        swift: "TODO..Support Swift Option<&T>::None value".into(),
+                    }
                 } else {
                     UnusedOptionNoneValue {
-                        rust: quote! { std::ptr::null::<#ty_name>() as *mut super::#ty_name },
+                        rust: quote! { std::ptr::null::<#ty_name>() as *mut #ty_name },
                         swift: "TODO..Support Swift Option<T>::None value".into(),
                     }
                 }
@@ -1458,8 +3135,14 @@ impl BridgedType {
         match self {
             BridgedType::StdLib(stdlib_type) => match stdlib_type {
                 StdLibType::String => true,
-                StdLibType::Vec(inner) => inner.ty.contains_owned_string_recursive(),
+                StdLibType::Vec(inner) | StdLibType::VecDeque(inner) => {
+                    inner.ty.contains_owned_string_recursive()
+                }
                 StdLibType::Option(inner) => inner.ty.contains_owned_string_recursive(),
+                StdLibType::Tuple(tuple) => tuple
+                    .elems
+                    .iter()
+                    .any(|elem| elem.contains_owned_string_recursive()),
                 _ => false,
             },
             BridgedType::Foreign(CustomBridgedType::Shared(SharedType::Struct(_shared_struct))) => {
@@ -1480,7 +3163,9 @@ impl BridgedType {
         match self {
             BridgedType::StdLib(stdlib_type) => match stdlib_type {
                 StdLibType::Str => true,
-                StdLibType::Vec(inner) => inner.ty.contains_ref_string_recursive(),
+                StdLibType::Vec(inner) | StdLibType::VecDeque(inner) => {
+                    inner.ty.contains_ref_string_recursive()
+                }
                 StdLibType::Option(inner) => inner.ty.contains_ref_string_recursive(),
                 _ => false,
             },
@@ -1496,27 +3181,31 @@ impl BridgedType {
         }
     }
 
-    /// Convert a rust expression into this type using
+    /// Convert a rust expression into this type using `.into()`.
+    ///
+    /// We always annotate the type that we're converting into, so that the `.into()` call gets
+    /// type checked against the bridged type instead of relying on type inference, which can
+    /// fail when the value is later converted to its FFI representation.
     pub fn rust_expression_into(&self, expression: &TokenStream) -> TokenStream {
-        match self {
+        let ty = match self {
             BridgedType::Foreign(CustomBridgedType::Shared(SharedType::Struct(shared_struct))) => {
                 let struct_name = &shared_struct.name;
 
-                let maybe_super = if shared_struct.already_declared {
-                    quote! { super:: }
+                if shared_struct.already_declared {
+                    quote! { super:: #struct_name }
                 } else {
-                    quote! {}
-                };
-
-                quote! {
-                    { let val: #maybe_super #struct_name = #expression.into(); val }
+                    quote! { #struct_name }
                 }
             }
-            // TODO: Instead of this catchall.. explicitly match on all variants and use
-            //  a similar approach to how we handle shared structs
-            _ => {
-                quote! { #expression.into() }
+            BridgedType::Foreign(CustomBridgedType::Shared(SharedType::Enum(shared_enum))) => {
+                let enum_name = &shared_enum.name;
+                quote! { #enum_name }
             }
+            _ => self.to_rust(),
+        };
+
+        quote! {
+            { let val: #ty = #expression.into(); val }
         }
     }
 }
@@ -1532,6 +3221,9 @@ mod tests {
     use quote::quote;
     use syn::parse_quote;
 
+    use super::bridged_hashmap::MapKind;
+    use super::bridged_range::RangeKind;
+    use super::bridged_set::SetKind;
     use super::*;
 
     /// Verify that we can parse std lib types.
@@ -1552,10 +3244,67 @@ mod tests {
             (quote! {f64}, StdLibType::F64),
             (quote! {&str}, StdLibType::Str),
             (quote! {String}, StdLibType::String),
+            (quote! {Cow<'a, str>}, StdLibType::CowStr),
+            (quote! {Cow<str>}, StdLibType::CowStr),
+            (quote! {std::borrow::Cow<'static, str>}, StdLibType::CowStr),
+            (quote! {Box<str>}, StdLibType::BoxedStr),
+            (quote! {Box<dyn Error>}, StdLibType::BoxedError),
+            (quote! {Box<dyn std::error::Error>}, StdLibType::BoxedError),
+            (
+                quote! {Box<dyn Error + Send + Sync + 'static>},
+                StdLibType::BoxedError,
+            ),
+            (
+                quote! { NonZeroU32},
+                StdLibType::NonZero(BridgedNonZero {
+                    int: Box::new(BridgedType::StdLib(StdLibType::U32)),
+                }),
+            ),
+            (
+                quote! { std::num::NonZeroIsize},
+                StdLibType::NonZero(BridgedNonZero {
+                    int: Box::new(BridgedType::StdLib(StdLibType::Isize)),
+                }),
+            ),
             (
                 quote! { Vec<u32>},
                 StdLibType::Vec(BuiltInVec {
                     ty: Box::new(BridgedType::StdLib(StdLibType::U32)),
+                    nested: false,
+                    as_data: false,
+                    as_array: false,
+                }),
+            ),
+            (
+                quote! { Vec<Vec<u32> >},
+                StdLibType::Vec(BuiltInVec {
+                    ty: Box::new(BridgedType::StdLib(StdLibType::Vec(BuiltInVec {
+                        ty: Box::new(BridgedType::StdLib(StdLibType::U32)),
+                        nested: true,
+                        as_data: false,
+                        as_array: false,
+                    }))),
+                    nested: false,
+                    as_data: false,
+                    as_array: false,
+                }),
+            ),
+            (
+                quote! { VecDeque<u32>},
+                StdLibType::VecDeque(BuiltInVec {
+                    ty: Box::new(BridgedType::StdLib(StdLibType::U32)),
+                    nested: true,
+                    as_data: false,
+                    as_array: false,
+                }),
+            ),
+            (
+                quote! { std::collections::VecDeque<u32>},
+                StdLibType::VecDeque(BuiltInVec {
+                    ty: Box::new(BridgedType::StdLib(StdLibType::U32)),
+                    nested: true,
+                    as_data: false,
+                    as_array: false,
                 }),
             ),
             (
@@ -1564,6 +3313,50 @@ mod tests {
                     ty: Box::new(BridgedType::StdLib(StdLibType::U32)),
                 }),
             ),
+            (
+                quote! { HashMap<String, u32>},
+                StdLibType::HashMap(BridgedHashMap {
+                    kind: MapKind::HashMap,
+                    key: Box::new(BridgedType::StdLib(StdLibType::String)),
+                    value: Box::new(BridgedType::StdLib(StdLibType::U32)),
+                }),
+            ),
+            (
+                quote! { BTreeMap<String, u32>},
+                StdLibType::HashMap(BridgedHashMap {
+                    kind: MapKind::BTreeMap,
+                    key: Box::new(BridgedType::StdLib(StdLibType::String)),
+                    value: Box::new(BridgedType::StdLib(StdLibType::U32)),
+                }),
+            ),
+            (
+                quote! { HashSet<u64>},
+                StdLibType::Set(BridgedSet {
+                    kind: SetKind::HashSet,
+                    elem: Box::new(BridgedType::StdLib(StdLibType::U64)),
+                }),
+            ),
+            (
+                quote! { std::collections::BTreeSet<String>},
+                StdLibType::Set(BridgedSet {
+                    kind: SetKind::BTreeSet,
+                    elem: Box::new(BridgedType::StdLib(StdLibType::String)),
+                }),
+            ),
+            (
+                quote! { Range<usize>},
+                StdLibType::Range(BridgedRange {
+                    kind: RangeKind::Range,
+                    elem: Box::new(BridgedType::StdLib(StdLibType::Usize)),
+                }),
+            ),
+            (
+                quote! { std::ops::RangeInclusive<i32>},
+                StdLibType::Range(BridgedRange {
+                    kind: RangeKind::RangeInclusive,
+                    elem: Box::new(BridgedType::StdLib(StdLibType::I32)),
+                }),
+            ),
             (
                 quote! {*const u8},
                 StdLibType::Pointer(BuiltInPointer {
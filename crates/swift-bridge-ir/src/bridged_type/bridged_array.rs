@@ -0,0 +1,195 @@
+use crate::bridged_type::{BridgedType, StdLibType, TypePosition};
+use crate::parse::{HostLang, TypeDeclarations};
+use crate::SWIFT_BRIDGE_PREFIX;
+use proc_macro2::{Ident, Literal, Span, TokenStream};
+use quote::quote;
+use syn::{Expr, ExprLit, Lit, TypeArray};
+
+/// `[T; N]`
+///
+/// C can't pass arrays by value, so a fixed size array crosses the FFI boundary wrapped in a
+/// `#[repr(C)]` struct.
+///
+/// ```no_run,ignore
+/// #[repr(C)]
+/// pub struct __swift_bridge__ArrayUInt8Len4 {
+///     elements: [u8; 4],
+/// }
+/// ```
+///
+/// Swift imports the C array field as a tuple, so on the Swift side a `[u8; 4]` becomes a
+/// `(UInt8, UInt8, UInt8, UInt8)`.
+#[derive(Debug, PartialEq, Clone)]
+pub(crate) struct BridgedArray {
+    pub elem: Box<BridgedType>,
+    pub len: usize,
+}
+
+impl BridgedArray {
+    /// `[T; N]`
+    ///
+    /// Returns None if the length isn't an integer literal or if we do not support the element
+    /// type.
+    pub(super) fn new_with_type_array(array: &TypeArray, types: &TypeDeclarations) -> Option<Self> {
+        let len = match &array.len {
+            Expr::Lit(ExprLit {
+                lit: Lit::Int(len), ..
+            }) => len.base10_parse::<usize>().ok()?,
+            _ => return None,
+        };
+
+        let elem = BridgedType::new_with_type(&array.elem, types)?;
+        if !Self::supports_elem_type(&elem) {
+            return None;
+        }
+
+        Some(BridgedArray {
+            elem: Box::new(elem),
+            len,
+        })
+    }
+
+    /// For now the array's elements can only be primitives, since those are `Copy` and have the
+    /// same layout in Rust, C and Swift.
+    pub(crate) fn supports_elem_type(ty: &BridgedType) -> bool {
+        matches!(
+            ty,
+            BridgedType::StdLib(
+                StdLibType::U8
+                    | StdLibType::I8
+                    | StdLibType::U16
+                    | StdLibType::I16
+                    | StdLibType::U32
+                    | StdLibType::I32
+                    | StdLibType::U64
+                    | StdLibType::I64
+                    | StdLibType::Usize
+                    | StdLibType::Isize
+                    | StdLibType::F32
+                    | StdLibType::F64
+                    | StdLibType::Bool
+            )
+        )
+    }
+
+    /// [u8; 4]
+    pub(super) fn to_rust(&self) -> TokenStream {
+        let elem = self.elem.to_rust();
+        let len = Literal::usize_unsuffixed(self.len);
+
+        quote! { [#elem; #len] }
+    }
+
+    /// ArrayUInt8Len4
+    fn name_without_prefix(&self) -> String {
+        format!(
+            "Array{}Len{}",
+            self.elem
+                .to_swift_type(TypePosition::FnReturn(HostLang::Rust)),
+            self.len
+        )
+    }
+
+    /// __swift_bridge__$ArrayUInt8Len4
+    pub fn ffi_name_string(&self) -> String {
+        format!("{}${}", SWIFT_BRIDGE_PREFIX, self.name_without_prefix())
+    }
+
+    /// __swift_bridge__ArrayUInt8Len4
+    pub fn ffi_name_tokens(&self) -> TokenStream {
+        let name = Ident::new(
+            &format!("{}{}", SWIFT_BRIDGE_PREFIX, self.name_without_prefix()),
+            Span::call_site(),
+        );
+        quote! { #name }
+    }
+
+    /// The `#[repr(C)]` struct that wraps the array while it crosses the FFI boundary.
+    pub(crate) fn generate_ffi_repr_tokens(&self) -> TokenStream {
+        let ffi_name = self.ffi_name_tokens();
+        let array = self.to_rust();
+
+        quote! {
+            #[repr(C)]
+            #[doc(hidden)]
+            pub struct #ffi_name {
+                elements: #array,
+            }
+        }
+    }
+
+    /// The C declaration of the struct that wraps the array.
+    pub(crate) fn generate_c_declaration(&self) -> String {
+        format!(
+            "typedef struct {ffi_name} {{ {elem} elements[{len}]; }} {ffi_name};",
+            ffi_name = self.ffi_name_string(),
+            elem = self.elem.to_c(),
+            len = self.len
+        )
+    }
+
+    pub(super) fn to_ffi_compatible_rust_type(&self) -> TokenStream {
+        self.ffi_name_tokens()
+    }
+
+    /// (UInt8, UInt8, UInt8, UInt8)
+    ///
+    /// The `@_cdecl` functions that Rust calls use the C struct instead.
+    pub(super) fn to_swift_type(&self, type_pos: TypePosition) -> String {
+        match type_pos {
+            TypePosition::FnArg(func_host_lang) | TypePosition::FnReturn(func_host_lang)
+                if func_host_lang.is_swift() =>
+            {
+                self.ffi_name_string()
+            }
+            TypePosition::SharedStructField => {
+                todo!("Fixed size array fields in shared structs are not yet supported")
+            }
+            _ => {
+                let elem = self.elem.to_swift_type(type_pos);
+                let elems = vec![elem; self.len];
+
+                format!("({})", elems.join(", "))
+            }
+        }
+    }
+
+    pub(super) fn to_c(&self) -> String {
+        format!("struct {}", self.ffi_name_string())
+    }
+
+    pub(super) fn convert_rust_value_to_ffi_value(&self, expression: &TokenStream) -> TokenStream {
+        let ffi_name = self.ffi_name_tokens();
+
+        quote! {
+            #ffi_name { elements: #expression }
+        }
+    }
+
+    pub(super) fn convert_ffi_value_to_rust_value(&self, value: &TokenStream) -> TokenStream {
+        quote! {
+            #value.elements
+        }
+    }
+
+    pub(super) fn convert_ffi_expression_to_swift(&self, expression: &str) -> String {
+        format!("{}.elements", expression)
+    }
+
+    pub(super) fn convert_swift_expression_to_ffi_compatible(&self, expression: &str) -> String {
+        format!("{}(elements: {})", self.ffi_name_string(), expression)
+    }
+
+    pub(super) fn c_include(&self) -> Option<&'static str> {
+        self.elem.c_include()
+    }
+}
+
+/// Used by the parser to tell apart an array with an unsupported element type from a type that
+/// was never declared.
+pub(crate) fn array_has_unsupported_elem_type(array: &TypeArray, types: &TypeDeclarations) -> bool {
+    match BridgedType::new_with_type(&array.elem, types) {
+        Some(elem) => !BridgedArray::supports_elem_type(&elem),
+        None => true,
+    }
+}
@@ -0,0 +1,384 @@
+use crate::bridged_type::{BridgedType, StdLibType, TypePosition};
+use crate::parse::{HostLang, TypeDeclarations};
+use crate::SWIFT_BRIDGE_PREFIX;
+use proc_macro2::{Ident, Span, TokenStream};
+use quote::quote;
+use syn::{
+    GenericArgument, Path, PathArguments, Type, TypeParamBound, TypePath, TypeReference,
+    TypeTraitObject,
+};
+
+/// `Box<dyn Fn(A, B) -> R>`, `Box<dyn FnMut(A, B) -> R>` or `&dyn Fn(A, B) -> R`
+///
+/// A Swift closure crosses the FFI boundary as a `#[repr(C)]` struct that holds a pointer to the
+/// retained Swift closure along with C function pointers for calling and releasing it.
+///
+/// ```no_run,ignore
+/// #[repr(C)]
+/// pub struct __swift_bridge__BoxedFnUInt32 {
+///     ctx: *mut std::ffi::c_void,
+///     call: extern "C" fn(*mut std::ffi::c_void, u32),
+///     free: extern "C" fn(*mut std::ffi::c_void),
+/// }
+/// ```
+///
+/// When the Rust `Box<dyn Fn>` is dropped we call `free`, which releases the Swift closure.
+/// A `&dyn Fn` uses the same struct, which gets dropped once the Rust function returns.
+#[derive(Debug, PartialEq, Clone)]
+pub(crate) struct BridgedBoxedFn {
+    pub kind: BoxedFnKind,
+    pub params: Vec<BridgedType>,
+    /// The closure's return type, which is `StdLibType::Null` for closures that return `()`.
+    pub ret: Box<BridgedType>,
+    /// Whether or not this is a `&dyn Fn` that Rust borrows for the duration of the call.
+    pub borrowed: bool,
+}
+
+#[derive(Debug, PartialEq, Copy, Clone)]
+pub(crate) enum BoxedFnKind {
+    Fn,
+    FnMut,
+}
+
+impl BridgedBoxedFn {
+    /// `Box<dyn Fn(A, B) -> R>` or `Box<dyn FnMut(A, B) -> R>`
+    ///
+    /// Returns None if the path is not a boxed closure or if we do not support its parameter or
+    /// return types.
+    pub(super) fn new_with_type_path(path: &TypePath, types: &TypeDeclarations) -> Option<Self> {
+        let last = path.path.segments.last()?;
+        if last.ident != "Box" {
+            return None;
+        }
+
+        let trait_object = match &last.arguments {
+            PathArguments::AngleBracketed(args) => match args.args.first()? {
+                GenericArgument::Type(Type::TraitObject(trait_object)) => trait_object,
+                _ => return None,
+            },
+            _ => return None,
+        };
+
+        Self::new_with_trait_object(trait_object, false, types)
+    }
+
+    /// `&dyn Fn(A, B) -> R`
+    ///
+    /// Returns None if the reference is not to an `Fn` trait object or if we do not support its
+    /// parameter or return types.
+    pub(super) fn new_with_type_reference(
+        reference: &TypeReference,
+        types: &TypeDeclarations,
+    ) -> Option<Self> {
+        if reference.mutability.is_some() {
+            return None;
+        }
+
+        let trait_object = match reference.elem.as_ref() {
+            Type::TraitObject(trait_object) => trait_object,
+            _ => return None,
+        };
+
+        let borrowed = Self::new_with_trait_object(trait_object, true, types)?;
+        if borrowed.kind != BoxedFnKind::Fn {
+            return None;
+        }
+
+        Some(borrowed)
+    }
+
+    fn new_with_trait_object(
+        trait_object: &TypeTraitObject,
+        borrowed: bool,
+        types: &TypeDeclarations,
+    ) -> Option<Self> {
+        // We only support a single `Fn` or `FnMut` bound, so `Box<dyn Fn() + Send>` is not yet
+        // supported.
+        if trait_object.bounds.len() != 1 {
+            return None;
+        }
+        let fn_trait = match trait_object.bounds.first()? {
+            TypeParamBound::Trait(fn_trait) => fn_trait.path.segments.last()?,
+            _ => return None,
+        };
+
+        let kind = if fn_trait.ident == "Fn" {
+            BoxedFnKind::Fn
+        } else if fn_trait.ident == "FnMut" {
+            BoxedFnKind::FnMut
+        } else {
+            return None;
+        };
+
+        let signature = match &fn_trait.arguments {
+            PathArguments::Parenthesized(signature) => signature,
+            _ => return None,
+        };
+
+        let ret = BridgedType::new_with_return_type(&signature.output, types)?;
+        if !ret.is_null() && !Self::supports_return_type(&ret) {
+            return None;
+        }
+
+        let mut params = vec![];
+        for input in signature.inputs.iter() {
+            let param = BridgedType::new_with_type(input, types)?;
+            if !Self::supports_param_type(&param) {
+                return None;
+            }
+
+            params.push(param);
+        }
+
+        Some(BridgedBoxedFn {
+            kind,
+            params,
+            ret: Box::new(ret),
+            borrowed,
+        })
+    }
+
+    /// For now the closure's parameters can only be primitives.
+    fn supports_param_type(ty: &BridgedType) -> bool {
+        matches!(
+            ty,
+            BridgedType::StdLib(
+                StdLibType::U8
+                    | StdLibType::I8
+                    | StdLibType::U16
+                    | StdLibType::I16
+                    | StdLibType::U32
+                    | StdLibType::I32
+                    | StdLibType::U64
+                    | StdLibType::I64
+                    | StdLibType::Usize
+                    | StdLibType::Isize
+                    | StdLibType::F32
+                    | StdLibType::F64
+                    | StdLibType::Bool
+            )
+        )
+    }
+
+    /// For now the closure can only return primitives or a `String`.
+    fn supports_return_type(ty: &BridgedType) -> bool {
+        Self::supports_param_type(ty) || matches!(ty, BridgedType::StdLib(StdLibType::String))
+    }
+
+    /// Box<dyn Fn(A, B) -> R> or &dyn Fn(A, B) -> R
+    pub(super) fn to_rust(&self) -> TokenStream {
+        let params = self.params.iter().map(|param| param.to_rust());
+        let ret = self.rust_return_tokens();
+
+        if self.borrowed {
+            return quote! { &dyn Fn(#(#params),*) #ret };
+        }
+
+        match self.kind {
+            BoxedFnKind::Fn => quote! { Box<dyn Fn(#(#params),*) #ret> },
+            BoxedFnKind::FnMut => quote! { Box<dyn FnMut(#(#params),*) #ret> },
+        }
+    }
+
+    /// `-> R`, or nothing if the closure returns `()`.
+    fn rust_return_tokens(&self) -> TokenStream {
+        if self.ret.is_null() {
+            quote! {}
+        } else {
+            let ret = self.ret.to_rust();
+            quote! { -> #ret }
+        }
+    }
+
+    /// BoxedFnUInt32AndBool or BoxedFnUInt32ReturningString
+    fn name_without_prefix(&self) -> String {
+        let params: Vec<String> = self
+            .params
+            .iter()
+            .map(|param| param.to_swift_type(TypePosition::FnArg(HostLang::Rust)))
+            .collect();
+
+        if self.ret.is_null() {
+            format!("BoxedFn{}", params.join("And"))
+        } else {
+            format!(
+                "BoxedFn{}Returning{}",
+                params.join("And"),
+                self.swift_return_type()
+            )
+        }
+    }
+
+    /// __swift_bridge__$BoxedFnUInt32AndBool
+    pub fn ffi_name_string(&self) -> String {
+        format!("{}${}", SWIFT_BRIDGE_PREFIX, self.name_without_prefix())
+    }
+
+    /// __swift_bridge__BoxedFnUInt32AndBool
+    pub fn ffi_name_tokens(&self) -> TokenStream {
+        let name = Ident::new(
+            &format!("{}{}", SWIFT_BRIDGE_PREFIX, self.name_without_prefix()),
+            Span::call_site(),
+        );
+        quote! { #name }
+    }
+
+    /// The `#[repr(C)]` struct that Swift passes to Rust, along with the code to turn it into a
+    /// `Box<dyn Fn>` that releases the Swift closure when it is dropped.
+    pub(crate) fn generate_ffi_repr_tokens(&self, swift_bridge_path: &Path) -> TokenStream {
+        let ffi_name = self.ffi_name_tokens();
+        let ret = self.rust_return_tokens();
+
+        let param_names: Vec<Ident> = (0..self.params.len())
+            .map(|idx| Ident::new(&format!("arg{}", idx), Span::call_site()))
+            .collect();
+        let param_types: Vec<TokenStream> = self.params.iter().map(|p| p.to_rust()).collect();
+
+        let call = quote! { (self.call)(self.ctx #(, #param_names)*) };
+        let (ffi_ret, call) = if self.ret.is_null() {
+            (quote! {}, call)
+        } else {
+            let ffi_ret = self.ret.to_ffi_compatible_rust_type(swift_bridge_path);
+            (
+                quote! { -> #ffi_ret },
+                self.ret
+                    .convert_ffi_value_to_rust_value(&call, Span::call_site()),
+            )
+        };
+
+        // `into_rust_repr` returns an `impl Fn` so that the same struct can be boxed into either a
+        // `Box<dyn Fn>` or a `Box<dyn FnMut>`.
+        quote! {
+            #[repr(C)]
+            #[doc(hidden)]
+            pub struct #ffi_name {
+                ctx: *mut std::ffi::c_void,
+                call: extern "C" fn(*mut std::ffi::c_void #(, #param_types)*) #ffi_ret,
+                free: extern "C" fn(*mut std::ffi::c_void),
+            }
+
+            impl #ffi_name {
+                #[doc(hidden)]
+                #[inline(always)]
+                pub fn into_rust_repr(self) -> impl Fn(#(#param_types),*) #ret {
+                    move |#(#param_names: #param_types),*| self.invoke(#(#param_names),*)
+                }
+
+                fn invoke(&self #(, #param_names: #param_types)*) #ret {
+                    #call
+                }
+            }
+
+            impl Drop for #ffi_name {
+                fn drop(&mut self) {
+                    (self.free)(self.ctx)
+                }
+            }
+        }
+    }
+
+    /// The C declaration of the struct that holds the Swift closure.
+    pub(crate) fn generate_c_declaration(&self) -> String {
+        let mut call_params = vec!["void*".to_string()];
+        for param in self.params.iter() {
+            call_params.push(param.to_c());
+        }
+
+        format!(
+            "typedef struct {ffi_name} {{ void* ctx; {ret} (*call)({call_params}); void (*free)(void*); }} {ffi_name};",
+            ffi_name = self.ffi_name_string(),
+            ret = self.ret.to_c(),
+            call_params = call_params.join(", ")
+        )
+    }
+
+    pub(super) fn to_ffi_compatible_rust_type(&self) -> TokenStream {
+        self.ffi_name_tokens()
+    }
+
+    /// @escaping (UInt32, Bool) -> ()
+    ///
+    /// A `&dyn Fn` is also `@escaping`, since we retain the closure until Rust is done with it.
+    pub(super) fn to_swift_type(&self, type_pos: TypePosition) -> String {
+        match type_pos {
+            TypePosition::FnArg(func_host_lang) if func_host_lang.is_rust() => {
+                format!("@escaping {}", self.swift_closure_type())
+            }
+            _ => {
+                todo!("Boxed closures are only supported as arguments to extern \"Rust\" functions")
+            }
+        }
+    }
+
+    /// (UInt32, Bool) -> ()
+    fn swift_closure_type(&self) -> String {
+        let params: Vec<String> = self
+            .params
+            .iter()
+            .map(|param| param.to_swift_type(TypePosition::FnArg(HostLang::Rust)))
+            .collect();
+
+        format!("({}) -> {}", params.join(", "), self.swift_return_type())
+    }
+
+    /// The type that the Swift closure returns, such as `()`, `UInt32` or `String`.
+    fn swift_return_type(&self) -> String {
+        match self.ret.as_ref() {
+            BridgedType::StdLib(StdLibType::String) => "String".to_string(),
+            ret => ret.to_swift_type(TypePosition::FnReturn(HostLang::Rust)),
+        }
+    }
+
+    pub(super) fn to_c(&self) -> String {
+        format!("struct {}", self.ffi_name_string())
+    }
+
+    pub(super) fn convert_ffi_value_to_rust_value(&self, value: &TokenStream) -> TokenStream {
+        if self.borrowed {
+            quote! {
+                &#value.into_rust_repr()
+            }
+        } else {
+            quote! {
+                Box::new(#value.into_rust_repr())
+            }
+        }
+    }
+
+    /// Retain the Swift closure and create the C function pointers that call and release it.
+    ///
+    /// The function pointers can't capture any context, so they get the closure back out of the
+    /// `ctx` pointer.
+    pub(super) fn convert_swift_expression_to_ffi_compatible(&self, expression: &str) -> String {
+        let closure_box = format!("__private__SwiftClosureBox<{}>", self.swift_closure_type());
+
+        let params: Vec<String> = (0..self.params.len())
+            .map(|idx| format!("arg{}", idx))
+            .collect();
+        let call_params = std::iter::once("ctx".to_string())
+            .chain(params.iter().cloned())
+            .collect::<Vec<_>>()
+            .join(", ");
+
+        // The value that the closure returns gets converted into its FFI representation before it
+        // is handed back to Rust.
+        let call_closure = format!(
+            "Unmanaged<{closure_box}>.fromOpaque(ctx!).takeUnretainedValue().closure({params})",
+            closure_box = closure_box,
+            params = params.join(", ")
+        );
+        let call_closure = self.ret.convert_swift_expression_to_ffi_compatible(
+            &call_closure,
+            TypePosition::FnReturn(HostLang::Swift),
+        );
+
+        format!(
+            "{ffi_name}(ctx: Unmanaged.passRetained({closure_box}({expression})).toOpaque(), call: {{ {call_params} in {call_closure} }}, free: {{ ctx in Unmanaged<{closure_box}>.fromOpaque(ctx!).release() }})",
+            ffi_name = self.ffi_name_string(),
+            closure_box = closure_box,
+            expression = expression,
+            call_params = call_params,
+            call_closure = call_closure
+        )
+    }
+}
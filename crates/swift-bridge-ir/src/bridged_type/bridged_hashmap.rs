@@ -0,0 +1,238 @@
+use crate::bridged_type::{BridgedType, StdLibType, TypePosition};
+use crate::parse::{HostLang, TypeDeclarations};
+use proc_macro2::{Span, TokenStream};
+use quote::quote;
+use syn::{GenericArgument, Path, PathArguments, TypePath};
+
+/// HashMap<K, V> or BTreeMap<K, V>
+///
+/// Crosses the FFI boundary as a `swift_bridge::hashmap::FfiHashMap`, which holds two parallel
+/// buffers of FFI compatible keys and values.
+///
+/// On the Swift side a `HashMap` is exposed as a `Dictionary<K, V>`. A Swift `Dictionary` does
+/// not preserve the order of its entries, so a `BTreeMap` is exposed as an array of `(K, V)`
+/// tuples that holds the entries in the same sorted order that Rust iterates them in.
+#[derive(Debug, PartialEq, Clone)]
+pub(crate) struct BridgedHashMap {
+    pub kind: MapKind,
+    pub key: Box<BridgedType>,
+    pub value: Box<BridgedType>,
+}
+
+#[derive(Debug, PartialEq, Copy, Clone)]
+pub(crate) enum MapKind {
+    /// `HashMap<K, V>`
+    HashMap,
+    /// `BTreeMap<K, V>`
+    BTreeMap,
+}
+
+impl BridgedHashMap {
+    /// `HashMap<K, V>`, `BTreeMap<K, V>` or their `std::collections::` paths.
+    ///
+    /// Returns None if the path is not a map or if we do not support its key or value type.
+    pub(super) fn new_with_type_path(path: &TypePath, types: &TypeDeclarations) -> Option<Self> {
+        let last = path.path.segments.last()?;
+        let kind = if last.ident == "HashMap" {
+            MapKind::HashMap
+        } else if last.ident == "BTreeMap" {
+            MapKind::BTreeMap
+        } else {
+            return None;
+        };
+
+        let args = match &last.arguments {
+            PathArguments::AngleBracketed(args) => &args.args,
+            _ => return None,
+        };
+        let mut generics = args.iter().filter_map(|arg| match arg {
+            GenericArgument::Type(ty) => Some(ty),
+            _ => None,
+        });
+
+        let key = BridgedType::new_with_type(generics.next()?, types)?;
+        let value = BridgedType::new_with_type(generics.next()?, types)?;
+
+        if !Self::supports_entry_type(&key) || !Self::supports_entry_type(&value) {
+            return None;
+        }
+
+        Some(BridgedHashMap {
+            kind,
+            key: Box::new(key),
+            value: Box::new(value),
+        })
+    }
+
+    /// Whether or not we know how to bridge the given type as a key or a value of a HashMap.
+    ///
+    /// For now we support primitives and `String`.
+    pub(super) fn supports_entry_type(ty: &BridgedType) -> bool {
+        matches!(
+            ty,
+            BridgedType::StdLib(
+                StdLibType::U8
+                    | StdLibType::I8
+                    | StdLibType::U16
+                    | StdLibType::I16
+                    | StdLibType::U32
+                    | StdLibType::I32
+                    | StdLibType::U64
+                    | StdLibType::I64
+                    | StdLibType::Usize
+                    | StdLibType::Isize
+                    | StdLibType::F32
+                    | StdLibType::F64
+                    | StdLibType::Bool
+                    | StdLibType::String
+            )
+        )
+    }
+
+    /// std::collections::HashMap<K, V>
+    pub(super) fn to_rust(&self) -> TokenStream {
+        let key = self.key.to_rust();
+        let value = self.value.to_rust();
+
+        match self.kind {
+            MapKind::HashMap => quote! { std::collections::HashMap<#key, #value> },
+            MapKind::BTreeMap => quote! { std::collections::BTreeMap<#key, #value> },
+        }
+    }
+
+    /// swift_bridge::hashmap::FfiHashMap<K, V>
+    pub(super) fn to_ffi_compatible_rust_type(&self, swift_bridge_path: &Path) -> TokenStream {
+        let key = self.key.to_ffi_compatible_rust_type(swift_bridge_path);
+        let value = self.value.to_ffi_compatible_rust_type(swift_bridge_path);
+
+        quote! { #swift_bridge_path::hashmap::FfiHashMap<#key, #value> }
+    }
+
+    /// Dictionary<K, V> or [(K, V)]
+    pub(super) fn to_swift_type(&self) -> String {
+        let key = swift_entry_type(&self.key);
+        let value = swift_entry_type(&self.value);
+
+        match self.kind {
+            MapKind::HashMap => format!("Dictionary<{}, {}>", key, value),
+            MapKind::BTreeMap => format!("[({}, {})]", key, value),
+        }
+    }
+
+    pub(super) fn convert_rust_value_to_ffi_value(
+        &self,
+        expression: &TokenStream,
+        swift_bridge_path: &Path,
+    ) -> TokenStream {
+        let key = self
+            .key
+            .convert_rust_value_to_ffi_compatible_value(&quote! { key }, swift_bridge_path);
+        let value = self
+            .value
+            .convert_rust_value_to_ffi_compatible_value(&quote! { value }, swift_bridge_path);
+
+        quote! {
+            #swift_bridge_path::hashmap::FfiHashMap::from_entries(
+                #expression.into_iter().map(|(key, value)| (#key, #value))
+            )
+        }
+    }
+
+    pub(super) fn convert_ffi_value_to_rust_value(
+        &self,
+        value: &TokenStream,
+        span: Span,
+    ) -> TokenStream {
+        let converted_key = self
+            .key
+            .convert_ffi_value_to_rust_value(&quote! { key }, span);
+        let converted_value = self
+            .value
+            .convert_ffi_value_to_rust_value(&quote! { value }, span);
+
+        let map = match self.kind {
+            MapKind::HashMap => quote! { std::collections::HashMap },
+            MapKind::BTreeMap => quote! { std::collections::BTreeMap },
+        };
+
+        quote! {
+            #value
+                .into_entries()
+                .map(|(key, value)| (#converted_key, #converted_value))
+                .collect::<#map<_, _>>()
+        }
+    }
+
+    /// Move the entries of a Rust HashMap into a Swift Dictionary, or the entries of a Rust
+    /// BTreeMap into a Swift array of tuples in the same order.
+    pub(super) fn convert_ffi_expression_to_swift(&self, expression: &str) -> String {
+        let into_swift = match self.kind {
+            MapKind::HashMap => "toDictionary",
+            MapKind::BTreeMap => "toEntries",
+        };
+
+        format!(
+            "{expression}.{into_swift}({{ (key: {ffi_key}) in {key} }}, {{ (value: {ffi_value}) in {value} }})",
+            expression = expression,
+            into_swift = into_swift,
+            ffi_key = swift_ffi_entry_type(&self.key),
+            key = convert_ffi_entry_to_swift(&self.key, "key"),
+            ffi_value = swift_ffi_entry_type(&self.value),
+            value = convert_ffi_entry_to_swift(&self.value, "value"),
+        )
+    }
+
+    /// A Swift Dictionary or array of tuples argument gets lent to Rust using `toFfiHashMap`,
+    /// which gives us a `{arg}AsFfiHashMap` that is valid for the duration of the call.
+    /// See `generate_swift.rs`.
+    pub(super) fn convert_swift_expression_to_ffi_compatible(&self, expression: &str) -> String {
+        format!("{expression}AsFfiHashMap", expression = expression)
+    }
+
+    /// The closures that convert the keys and values of a Swift Dictionary or array of tuples
+    /// into their FFI representation.
+    ///
+    /// `{ (key: String) -> UnsafeMutableRawPointer in ... }, { (value: UInt8) -> UInt8 in value }`
+    pub(crate) fn swift_entries_to_ffi_closures(&self) -> String {
+        format!(
+            "{{ (key: {key_ty}) -> {ffi_key_ty} in {key} }}, {{ (value: {value_ty}) -> {ffi_value_ty} in {value} }}",
+            key_ty = swift_entry_type(&self.key),
+            ffi_key_ty = swift_ffi_entry_type(&self.key),
+            key = convert_swift_entry_to_ffi(&self.key, "key"),
+            value_ty = swift_entry_type(&self.value),
+            ffi_value_ty = swift_ffi_entry_type(&self.value),
+            value = convert_swift_entry_to_ffi(&self.value, "value"),
+        )
+    }
+}
+
+/// The type of a key or value inside of the Swift Dictionary or array of tuples.
+pub(super) fn swift_entry_type(ty: &BridgedType) -> String {
+    match ty {
+        BridgedType::StdLib(StdLibType::String) => "String".to_string(),
+        _ => ty.to_swift_type(TypePosition::FnReturn(HostLang::Rust)),
+    }
+}
+
+/// The type of a key or value inside of the FFI buffers.
+pub(super) fn swift_ffi_entry_type(ty: &BridgedType) -> String {
+    match ty {
+        BridgedType::StdLib(StdLibType::String) => "UnsafeMutableRawPointer".to_string(),
+        _ => ty.to_swift_type(TypePosition::FnReturn(HostLang::Rust)),
+    }
+}
+
+pub(super) fn convert_ffi_entry_to_swift(ty: &BridgedType, expression: &str) -> String {
+    match ty {
+        BridgedType::StdLib(StdLibType::String) => {
+            format!("RustString(ptr: {}).toString()", expression)
+        }
+        _ => {
+            ty.convert_ffi_value_to_swift_value(expression, TypePosition::FnReturn(HostLang::Rust))
+        }
+    }
+}
+
+pub(super) fn convert_swift_entry_to_ffi(ty: &BridgedType, expression: &str) -> String {
+    ty.convert_swift_expression_to_ffi_compatible(expression, TypePosition::FnArg(HostLang::Rust))
+}
@@ -0,0 +1,110 @@
+use crate::bridged_type::{BridgedType, StdLibType};
+use proc_macro2::{Ident, Span, TokenStream};
+use quote::{quote, quote_spanned};
+
+/// `NonZeroU32` and the other `std::num::NonZero*` integers.
+///
+/// Crosses the FFI boundary as the underlying integer, which is also what Swift sees.
+/// Converting the integer back into Rust asserts that it is not zero.
+///
+/// An `Option<NonZeroU32>` uses the same niche that Rust does, so it crosses the FFI boundary
+/// as a plain `u32` where `0` means `None`.
+#[derive(Debug, PartialEq, Clone)]
+pub(crate) struct BridgedNonZero {
+    /// The underlying integer, such as the `u32` in `NonZeroU32`.
+    pub int: Box<BridgedType>,
+}
+
+impl BridgedNonZero {
+    /// `NonZeroU32`, `std::num::NonZeroU32` or `core::num::NonZeroU32`.
+    pub(super) fn new_with_str(string: &str) -> Option<Self> {
+        let name = string
+            .strip_prefix("std :: num :: ")
+            .or_else(|| string.strip_prefix("core :: num :: "))
+            .unwrap_or(string);
+
+        let int = match name.strip_prefix("NonZero")? {
+            "U8" => StdLibType::U8,
+            "I8" => StdLibType::I8,
+            "U16" => StdLibType::U16,
+            "I16" => StdLibType::I16,
+            "U32" => StdLibType::U32,
+            "I32" => StdLibType::I32,
+            "U64" => StdLibType::U64,
+            "I64" => StdLibType::I64,
+            "Usize" => StdLibType::Usize,
+            "Isize" => StdLibType::Isize,
+            _ => return None,
+        };
+
+        Some(BridgedNonZero {
+            int: Box::new(BridgedType::StdLib(int)),
+        })
+    }
+
+    /// std::num::NonZeroU32
+    pub(super) fn to_rust(&self) -> TokenStream {
+        let ident = self.non_zero_ident();
+        quote! { std::num::#ident }
+    }
+
+    /// NonZeroU32
+    fn non_zero_ident(&self) -> Ident {
+        let int = self.int.to_rust().to_string();
+        let mut chars = int.chars();
+
+        // u32 -> U32, usize -> Usize, etc...
+        let capitalized = chars.next().unwrap().to_uppercase().to_string() + chars.as_str();
+
+        Ident::new(&format!("NonZero{}", capitalized), Span::call_site())
+    }
+
+    pub(super) fn convert_rust_value_to_ffi_value(&self, expression: &TokenStream) -> TokenStream {
+        quote! { #expression.get() }
+    }
+
+    pub(super) fn convert_ffi_value_to_rust_value(
+        &self,
+        value: &TokenStream,
+        span: Span,
+    ) -> TokenStream {
+        let ident = self.non_zero_ident();
+
+        quote_spanned! {span=>
+            std::num::#ident::new(#value).expect("Expected a non-zero integer")
+        }
+    }
+
+    /// Option<NonZeroU32> -> u32, where `None` becomes `0`.
+    pub(super) fn convert_rust_option_to_ffi_value(&self, expression: &TokenStream) -> TokenStream {
+        quote! {
+            if let Some(val) = #expression {
+                val.get()
+            } else {
+                0
+            }
+        }
+    }
+
+    /// u32 -> Option<NonZeroU32>, where `0` becomes `None`.
+    pub(super) fn convert_ffi_value_to_rust_option(&self, value: &TokenStream) -> TokenStream {
+        let ident = self.non_zero_ident();
+        quote! { std::num::#ident::new(#value) }
+    }
+
+    /// `{ let val = expression; if val != 0 { return val } else { return nil } }()`
+    pub(super) fn convert_ffi_expression_to_swift_option(&self, expression: &str) -> String {
+        format!(
+            "{{ let val = {expression}; if val != 0 {{ return val }} else {{ return nil }} }}()",
+            expression = expression
+        )
+    }
+
+    /// `{ if let val = expression { return val } else { return 0 } }()`
+    pub(super) fn convert_swift_option_to_ffi_expression(&self, expression: &str) -> String {
+        format!(
+            "{{ if let val = {expression} {{ return val }} else {{ return 0 }} }}()",
+            expression = expression
+        )
+    }
+}
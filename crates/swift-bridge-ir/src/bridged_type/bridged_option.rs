@@ -1,4 +1,5 @@
 use crate::bridged_type::{BridgedType, CustomBridgedType, SharedType, StdLibType, TypePosition};
+use crate::parse::HostLang;
 use proc_macro2::TokenStream;
 use quote::quote;
 use std::ops::Deref;
@@ -98,11 +99,88 @@ impl BridgedOption {
                     }
                 }
                 StdLibType::Vec(_) => {
-                    todo!("Support Option<Vec<T>>")
+                    let val = self.ty.convert_rust_value_to_ffi_compatible_value(
+                        &quote! { val },
+                        swift_bridge_path,
+                    );
+
+                    quote! {
+                        if let Some(val) = #expression {
+                            #val
+                        } else {
+                            #unused_none_value
+                        }
+                    }
                 }
                 StdLibType::Option(_) => {
                     todo!("Support Option<Option<T>>")
                 }
+                StdLibType::VecDeque(_) => {
+                    todo!("Support Option<VecDeque<T>>")
+                }
+                StdLibType::RefVec(_) => {
+                    todo!("Support Option<&Vec<T>> and Option<&mut Vec<T>>")
+                }
+                StdLibType::RefMutPrimitive(_) => {
+                    todo!("Support Option<&mut T>")
+                }
+                StdLibType::HashMap(_) => {
+                    todo!("Support Option<HashMap<K, V>>")
+                }
+                StdLibType::Set(_) => {
+                    todo!("Support Option<HashSet<T>> and Option<BTreeSet<T>>")
+                }
+                StdLibType::Result(_) => {
+                    todo!("Support Option<Result<T, E>>")
+                }
+                StdLibType::BoxedFn(_) => {
+                    todo!("Support Option<Box<dyn Fn>>")
+                }
+                StdLibType::Array(_) => {
+                    todo!("Support Option<[T; N]>")
+                }
+                StdLibType::Tuple(_) => {
+                    todo!("Support Option<(A, B)>")
+                }
+                StdLibType::Range(_) => {
+                    todo!("Support Option<Range<T>>")
+                }
+                StdLibType::Char => {
+                    todo!("Support Option<char>")
+                }
+                StdLibType::Duration => {
+                    todo!("Support Option<Duration>")
+                }
+                StdLibType::DateTime => {
+                    todo!("Support Option<DateTime<Utc>>")
+                }
+                StdLibType::IpAddr | StdLibType::SocketAddr => {
+                    todo!("Support Option<IpAddr> and Option<SocketAddr>")
+                }
+                StdLibType::U128 | StdLibType::I128 => {
+                    todo!("Support Option<u128> and Option<i128>")
+                }
+                StdLibType::PathBuf => {
+                    todo!("Support Option<PathBuf>")
+                }
+                StdLibType::CowStr => {
+                    todo!("Support Option<Cow<str>>")
+                }
+                StdLibType::BoxedStr => {
+                    todo!("Support Option<Box<str>>")
+                }
+                StdLibType::NonZero(non_zero) => {
+                    non_zero.convert_rust_option_to_ffi_value(expression)
+                }
+                StdLibType::BoxedError => {
+                    quote! {
+                        if let Some(val) = #expression {
+                            Box::into_raw(Box::new(#swift_bridge_path::error::RustError(val)))
+                        } else {
+                            #unused_none_value
+                        }
+                    }
+                }
             },
             BridgedType::Foreign(CustomBridgedType::Shared(SharedType::Struct(shared_struct))) => {
                 let option_name = shared_struct.ffi_option_name_tokens();
@@ -116,12 +194,30 @@ impl BridgedOption {
                     #option_name::from_rust_repr(#expression)
                 }
             }
-            BridgedType::Foreign(CustomBridgedType::Opaque(_opaque_type)) => {
-                quote! {
-                    if let Some(val) = #expression {
-                        Box::into_raw(Box::new(val))
+            BridgedType::Foreign(CustomBridgedType::Opaque(opaque)) => {
+                let ty_name = opaque.rust_ty_tokens();
+
+                if opaque.reference {
+                    let ptr = if opaque.mutable {
+                        quote! { *mut }
                     } else {
-                        std::ptr::null_mut()
+                        quote! { *const }
+                    };
+
+                    quote! {
+                        if let Some(val) = #expression {
+                            val as #ptr #ty_name
+                        } else {
+                            #unused_none_value
+                        }
+                    }
+                } else {
+                    quote! {
+                        if let Some(val) = #expression {
+                            Box::into_raw(Box::new(val))
+                        } else {
+                            std::ptr::null_mut()
+                        }
                     }
                 }
             }
@@ -157,7 +253,10 @@ impl BridgedOption {
                 }
                 StdLibType::Str => {
                     quote! {
-                        if #value.start.is_null() { None } else { Some(#value.to_str()) }
+                        {
+                            let val = #value;
+                            if val.start.is_null() { None } else { Some(val.to_str()) }
+                        }
                     }
                 }
                 StdLibType::String => {
@@ -175,6 +274,70 @@ impl BridgedOption {
                 StdLibType::Option(_) => {
                     todo!("Option<Option<T>> is not yet supported")
                 }
+                StdLibType::VecDeque(_) => {
+                    todo!("Option<VecDeque<T>> is not yet supported")
+                }
+                StdLibType::RefVec(_) => {
+                    todo!("Option<&Vec<T>> and Option<&mut Vec<T>> are not yet supported")
+                }
+                StdLibType::RefMutPrimitive(_) => {
+                    todo!("Option<&mut T> is not yet supported")
+                }
+                StdLibType::HashMap(_) => {
+                    todo!("Option<HashMap<K, V>> is not yet supported")
+                }
+                StdLibType::Set(_) => {
+                    todo!("Option<HashSet<T>> and Option<BTreeSet<T>> are not yet supported")
+                }
+                StdLibType::Result(_) => {
+                    todo!("Option<Result<T, E>> is not yet supported")
+                }
+                StdLibType::BoxedFn(_) => {
+                    todo!("Option<Box<dyn Fn>> is not yet supported")
+                }
+                StdLibType::Array(_) => {
+                    todo!("Option<[T; N]> is not yet supported")
+                }
+                StdLibType::Tuple(_) => {
+                    todo!("Option<(A, B)> is not yet supported")
+                }
+                StdLibType::Range(_) => {
+                    todo!("Option<Range<T>> is not yet supported")
+                }
+                StdLibType::Char => {
+                    todo!("Option<char> is not yet supported")
+                }
+                StdLibType::Duration => {
+                    todo!("Option<Duration> is not yet supported")
+                }
+                StdLibType::DateTime => {
+                    todo!("Option<DateTime<Utc>> is not yet supported")
+                }
+                StdLibType::IpAddr | StdLibType::SocketAddr => {
+                    todo!("Option<IpAddr> and Option<SocketAddr> are not yet supported")
+                }
+                StdLibType::U128 | StdLibType::I128 => {
+                    todo!("Option<u128> and Option<i128> are not yet supported")
+                }
+                StdLibType::PathBuf => {
+                    todo!("Option<PathBuf> is not yet supported")
+                }
+                StdLibType::CowStr => {
+                    todo!("Option<Cow<str>> is not yet supported")
+                }
+                StdLibType::BoxedStr => {
+                    todo!("Option<Box<str>> is not yet supported")
+                }
+                StdLibType::NonZero(non_zero) => non_zero.convert_ffi_value_to_rust_option(value),
+                StdLibType::BoxedError => {
+                    quote! {
+                        if #value.is_null() {
+                            None
+                        } else {
+                            Some(unsafe { Box::from_raw(#value).0 })
+                        }
+                    }
+                }
             },
             BridgedType::Foreign(CustomBridgedType::Shared(SharedType::Struct(_shared_struct))) => {
                 quote! {
@@ -186,12 +349,21 @@ impl BridgedOption {
                     #value.into_rust_repr()
                 }
             }
-            BridgedType::Foreign(CustomBridgedType::Opaque(_opaque)) => {
+            BridgedType::Foreign(CustomBridgedType::Opaque(opaque)) => {
+                // A borrowed value is still owned by Swift, so we must not free it.
+                let val = if opaque.reference && opaque.mutable {
+                    quote! { &mut * #value }
+                } else if opaque.reference {
+                    quote! { & * #value }
+                } else {
+                    quote! { * Box::from_raw(#value) }
+                };
+
                 quote! {
                     if #value.is_null() {
                         None
                     } else {
-                        Some(unsafe { * Box::from_raw(#value) } )
+                        Some(unsafe { #val } )
                     }
                 }
             }
@@ -235,11 +407,80 @@ impl BridgedOption {
                     format!("{{ let val = {expression}; if val != nil {{ return RustString(ptr: val!) }} else {{ return nil }} }}()", expression = expression,)
                 }
                 StdLibType::Vec(_) => {
-                    todo!("Support Option<Vec<T>>")
+                    format!(
+                        "{{ let val = {expression}; if val.elems != nil {{ return {val} }} else {{ return nil }} }}()",
+                        expression = expression,
+                        val = self
+                            .ty
+                            .convert_ffi_value_to_swift_value("val", TypePosition::FnReturn(HostLang::Rust)),
+                    )
                 }
                 StdLibType::Option(_) => {
                     todo!("Support Option<Option<T>>")
                 }
+                StdLibType::VecDeque(_) => {
+                    todo!("Support Option<VecDeque<T>>")
+                }
+                StdLibType::RefVec(_) => {
+                    todo!("Support Option<&Vec<T>> and Option<&mut Vec<T>>")
+                }
+                StdLibType::RefMutPrimitive(_) => {
+                    todo!("Support Option<&mut T>")
+                }
+                StdLibType::HashMap(_) => {
+                    todo!("Support Option<HashMap<K, V>>")
+                }
+                StdLibType::Set(_) => {
+                    todo!("Support Option<HashSet<T>> and Option<BTreeSet<T>>")
+                }
+                StdLibType::Result(_) => {
+                    todo!("Support Option<Result<T, E>>")
+                }
+                StdLibType::BoxedFn(_) => {
+                    todo!("Support Option<Box<dyn Fn>>")
+                }
+                StdLibType::Array(_) => {
+                    todo!("Support Option<[T; N]>")
+                }
+                StdLibType::Tuple(_) => {
+                    todo!("Support Option<(A, B)>")
+                }
+                StdLibType::Range(_) => {
+                    todo!("Support Option<Range<T>>")
+                }
+                StdLibType::Char => {
+                    todo!("Support Option<char>")
+                }
+                StdLibType::Duration => {
+                    todo!("Support Option<Duration>")
+                }
+                StdLibType::DateTime => {
+                    todo!("Support Option<DateTime<Utc>>")
+                }
+                StdLibType::IpAddr | StdLibType::SocketAddr => {
+                    todo!("Support Option<IpAddr> and Option<SocketAddr>")
+                }
+                StdLibType::U128 | StdLibType::I128 => {
+                    todo!("Support Option<u128> and Option<i128>")
+                }
+                StdLibType::PathBuf => {
+                    todo!("Support Option<PathBuf>")
+                }
+                StdLibType::CowStr => {
+                    todo!("Support Option<Cow<str>>")
+                }
+                StdLibType::BoxedStr => {
+                    todo!("Support Option<Box<str>>")
+                }
+                StdLibType::NonZero(non_zero) => {
+                    non_zero.convert_ffi_expression_to_swift_option(expression)
+                }
+                StdLibType::BoxedError => {
+                    format!(
+                        "{{ if let val = {expression} {{ return RustError(ptr: val) }} else {{ return nil }} }}()",
+                        expression = expression
+                    )
+                }
             },
             BridgedType::Foreign(CustomBridgedType::Shared(SharedType::Struct(_shared_struct))) => {
                 format!("{expression}.intoSwiftRepr()", expression = expression)
@@ -247,12 +488,21 @@ impl BridgedOption {
             BridgedType::Foreign(CustomBridgedType::Shared(SharedType::Enum(_shared_enum))) => {
                 format!("{expression}.intoSwiftRepr()", expression = expression)
             }
+            // A `None` is a null pointer, so we return `nil` instead of creating a class instance
+            // that points to nothing.
             BridgedType::Foreign(CustomBridgedType::Opaque(opaque)) => {
-                let type_name = opaque.swift_name();
+                let mut type_name = opaque.swift_name();
+                if opaque.reference {
+                    type_name += "Ref";
+                }
+                if opaque.mutable {
+                    type_name += "Mut";
+                }
+
                 format!(
-                    "{{ let val = {expression}; if val != nil {{ return {type_name}(ptr: val!) }} else {{ return nil }} }}()",
-                        expression = expression,
-                        type_name = type_name
+                    "{{ if let val = {expression} {{ return {type_name}(ptr: val) }} else {{ return nil }} }}()",
+                    expression = expression,
+                    type_name = type_name
                 )
             }
         }
@@ -296,9 +546,19 @@ impl BridgedOption {
                 StdLibType::RefSlice(_) => {
                     todo!("Option<&[T]> is not yet supported")
                 }
-                StdLibType::Str => {
-                    format!("{expression}AsRustStr", expression = expression)
-                }
+                StdLibType::Str => match type_pos {
+                    TypePosition::FnArg(func_host_lang) if func_host_lang.is_rust() => {
+                        format!("{expression}AsRustStr", expression = expression)
+                    }
+                    // A `nil` gets passed to Rust as a `RustStr` with a null pointer. An empty
+                    // string always has a non-null pointer, so Rust can tell the two apart.
+                    _ => {
+                        format!(
+                            "{{ if let val = {expression} {{ return val }} else {{ return RustStr(start: nil, len: 0) }} }}()",
+                            expression = expression
+                        )
+                    }
+                },
                 StdLibType::String => match type_pos {
                     TypePosition::FnArg(_func_host_lang) => {
                         format!(
@@ -322,6 +582,66 @@ impl BridgedOption {
                 StdLibType::Option(_) => {
                     todo!("Option<Option<T> is not yet supported")
                 }
+                StdLibType::VecDeque(_) => {
+                    todo!("Option<VecDeque<T>> is not yet supported")
+                }
+                StdLibType::RefVec(_) => {
+                    todo!("Option<&Vec<T>> and Option<&mut Vec<T>> are not yet supported")
+                }
+                StdLibType::RefMutPrimitive(_) => {
+                    todo!("Option<&mut T> is not yet supported")
+                }
+                StdLibType::HashMap(_) => {
+                    todo!("Option<HashMap<K, V>> is not yet supported")
+                }
+                StdLibType::Set(_) => {
+                    todo!("Option<HashSet<T>> and Option<BTreeSet<T>> are not yet supported")
+                }
+                StdLibType::Result(_) => {
+                    todo!("Option<Result<T, E>> is not yet supported")
+                }
+                StdLibType::BoxedFn(_) => {
+                    todo!("Option<Box<dyn Fn>> is not yet supported")
+                }
+                StdLibType::Array(_) => {
+                    todo!("Option<[T; N]> is not yet supported")
+                }
+                StdLibType::Tuple(_) => {
+                    todo!("Option<(A, B)> is not yet supported")
+                }
+                StdLibType::Range(_) => {
+                    todo!("Option<Range<T>> is not yet supported")
+                }
+                StdLibType::Char => {
+                    todo!("Option<char> is not yet supported")
+                }
+                StdLibType::Duration => {
+                    todo!("Option<Duration> is not yet supported")
+                }
+                StdLibType::DateTime => {
+                    todo!("Option<DateTime<Utc>> is not yet supported")
+                }
+                StdLibType::IpAddr | StdLibType::SocketAddr => {
+                    todo!("Option<IpAddr> and Option<SocketAddr> are not yet supported")
+                }
+                StdLibType::U128 | StdLibType::I128 => {
+                    todo!("Option<u128> and Option<i128> are not yet supported")
+                }
+                StdLibType::PathBuf => {
+                    todo!("Option<PathBuf> is not yet supported")
+                }
+                StdLibType::CowStr => {
+                    todo!("Option<Cow<str>> is not yet supported")
+                }
+                StdLibType::BoxedStr => {
+                    todo!("Option<Box<str>> is not yet supported")
+                }
+                StdLibType::NonZero(non_zero) => {
+                    non_zero.convert_swift_option_to_ffi_expression(expression)
+                }
+                StdLibType::BoxedError => {
+                    format!("{{ if let val = {expression} {{ val.isOwned = false; return val.ptr }} else {{ return nil }} }}()", expression = expression)
+                }
             },
             BridgedType::Foreign(CustomBridgedType::Shared(SharedType::Struct(shared_struct))) => {
                 let ffi_name = shared_struct.ffi_option_name_string();
@@ -339,14 +659,49 @@ impl BridgedOption {
                     expression = expression
                 )
             }
-            BridgedType::Foreign(CustomBridgedType::Opaque(_opaque)) => {
-                format!("{{ if let val = {expression} {{ val.isOwned = false; return val.ptr }} else {{ return nil }} }}()", expression = expression,)
+            // Swift gives up ownership of an owned value, while a borrowed value is only lent out
+            // for the duration of the call.
+            BridgedType::Foreign(CustomBridgedType::Opaque(opaque)) => {
+                if opaque.reference {
+                    format!("{{ if let val = {expression} {{ return val.ptr }} else {{ return nil }} }}()", expression = expression,)
+                } else {
+                    format!("{{ if let val = {expression} {{ val.isOwned = false; return val.ptr }} else {{ return nil }} }}()", expression = expression,)
+                }
             }
         }
     }
 }
 
 impl BridgedOption {
+    /// `Option<SomeRustType>`, `Option<&SomeRustType>` or `Option<&mut SomeRustType>`, which cross
+    /// the FFI boundary as a pointer that is null for `None`.
+    pub fn is_opaque_rust_type(&self) -> bool {
+        matches!(
+            self.ty.deref(),
+            BridgedType::Foreign(CustomBridgedType::Opaque(opaque))
+                if opaque.host_lang.is_rust() && opaque.copy.is_none()
+        )
+    }
+
+    /// The name of the Option's C type as imported into Swift.
+    ///
+    /// `struct __private__OptionU8` -> `__private__OptionU8`, `void*` -> `UnsafeMutableRawPointer?`,
+    /// `Option<NonZeroU32>` -> `UInt32`
+    pub fn to_swift_ffi_type(&self) -> String {
+        if let BridgedType::StdLib(StdLibType::NonZero(non_zero)) = self.ty.deref() {
+            return non_zero
+                .int
+                .to_swift_type(TypePosition::FnReturn(HostLang::Rust));
+        }
+
+        let c_ty = self.to_c();
+
+        match c_ty.strip_prefix("struct ") {
+            Some(struct_name) => struct_name.to_string(),
+            None => "UnsafeMutableRawPointer?".to_string(),
+        }
+    }
+
     pub fn to_c(&self) -> String {
         match self.ty.deref() {
             BridgedType::StdLib(stdlib_type) => match stdlib_type {
@@ -374,12 +729,66 @@ impl BridgedOption {
                 }
                 StdLibType::Str => "struct RustStr".to_string(),
                 StdLibType::String => "void*".to_string(),
-                StdLibType::Vec(_) => {
-                    todo!("Option<Vec<T>> is not yet supported")
-                }
+                StdLibType::Vec(_) => "struct __private__FfiVec".to_string(),
                 StdLibType::Option(_) => {
                     todo!("Option<Option<T>> is not yet supported")
                 }
+                StdLibType::VecDeque(_) => {
+                    todo!("Option<VecDeque<T>> is not yet supported")
+                }
+                StdLibType::RefVec(_) => {
+                    todo!("Option<&Vec<T>> and Option<&mut Vec<T>> are not yet supported")
+                }
+                StdLibType::RefMutPrimitive(_) => {
+                    todo!("Option<&mut T> is not yet supported")
+                }
+                StdLibType::HashMap(_) => {
+                    todo!("Option<HashMap<K, V>> is not yet supported")
+                }
+                StdLibType::Set(_) => {
+                    todo!("Option<HashSet<T>> and Option<BTreeSet<T>> are not yet supported")
+                }
+                StdLibType::Result(_) => {
+                    todo!("Option<Result<T, E>> is not yet supported")
+                }
+                StdLibType::BoxedFn(_) => {
+                    todo!("Option<Box<dyn Fn>> is not yet supported")
+                }
+                StdLibType::Array(_) => {
+                    todo!("Option<[T; N]> is not yet supported")
+                }
+                StdLibType::Tuple(_) => {
+                    todo!("Option<(A, B)> is not yet supported")
+                }
+                StdLibType::Range(_) => {
+                    todo!("Option<Range<T>> is not yet supported")
+                }
+                StdLibType::Char => {
+                    todo!("Option<char> is not yet supported")
+                }
+                StdLibType::Duration => {
+                    todo!("Option<Duration> is not yet supported")
+                }
+                StdLibType::DateTime => {
+                    todo!("Option<DateTime<Utc>> is not yet supported")
+                }
+                StdLibType::IpAddr | StdLibType::SocketAddr => {
+                    todo!("Option<IpAddr> and Option<SocketAddr> are not yet supported")
+                }
+                StdLibType::U128 | StdLibType::I128 => {
+                    todo!("Option<u128> and Option<i128> are not yet supported")
+                }
+                StdLibType::PathBuf => {
+                    todo!("Option<PathBuf> is not yet supported")
+                }
+                StdLibType::CowStr => {
+                    todo!("Option<Cow<str>> is not yet supported")
+                }
+                StdLibType::BoxedStr => {
+                    todo!("Option<Box<str>> is not yet supported")
+                }
+                StdLibType::NonZero(non_zero) => non_zero.int.to_c(),
+                StdLibType::BoxedError => "void*".to_string(),
             },
             BridgedType::Foreign(CustomBridgedType::Shared(SharedType::Struct(shared_struct))) => {
                 format!("struct {}", shared_struct.ffi_option_name_string())
@@ -0,0 +1,220 @@
+use crate::bridged_type::{BridgedType, StdLibType, TypePosition};
+use crate::parse::{HostLang, TypeDeclarations};
+use proc_macro2::{Span, TokenStream};
+use quote::{quote, quote_spanned};
+use syn::{GenericArgument, Path, PathArguments, Type, TypePath};
+
+/// `Range<T>` or `RangeInclusive<T>`, where `T` is an integer.
+///
+/// Crosses the FFI boundary as a `swift_bridge::range::FfiRange<T>`, which holds the start and
+/// end of the range along with whether or not the end is inclusive.
+///
+/// On the Swift side a `Range<T>` is exposed as a `Range<T>` and a `RangeInclusive<T>` is
+/// exposed as a `ClosedRange<T>`.
+#[derive(Debug, PartialEq, Clone)]
+pub(crate) struct BridgedRange {
+    pub kind: RangeKind,
+    pub elem: Box<BridgedType>,
+}
+
+#[derive(Debug, PartialEq, Copy, Clone)]
+pub(crate) enum RangeKind {
+    /// `Range<T>`
+    Range,
+    /// `RangeInclusive<T>`
+    RangeInclusive,
+}
+
+impl BridgedRange {
+    /// `Range<T>`, `RangeInclusive<T>` or their `std::ops::` paths.
+    ///
+    /// Returns None if the path is not a range or if its element type is not an integer.
+    pub(super) fn new_with_type_path(path: &TypePath, types: &TypeDeclarations) -> Option<Self> {
+        let (kind, elem) = range_kind_and_elem_type(path)?;
+
+        let elem = BridgedType::new_with_type(elem, types)?;
+        if !Self::supports_elem_type(&elem) {
+            return None;
+        }
+
+        Some(BridgedRange {
+            kind,
+            elem: Box::new(elem),
+        })
+    }
+
+    /// For now the range's elements can only be integers.
+    fn supports_elem_type(ty: &BridgedType) -> bool {
+        matches!(
+            ty,
+            BridgedType::StdLib(
+                StdLibType::U8
+                    | StdLibType::I8
+                    | StdLibType::U16
+                    | StdLibType::I16
+                    | StdLibType::U32
+                    | StdLibType::I32
+                    | StdLibType::U64
+                    | StdLibType::I64
+                    | StdLibType::Usize
+                    | StdLibType::Isize
+            )
+        )
+    }
+
+    /// std::ops::Range<T>
+    pub(super) fn to_rust(&self) -> TokenStream {
+        let elem = self.elem.to_rust();
+
+        match self.kind {
+            RangeKind::Range => quote! { std::ops::Range<#elem> },
+            RangeKind::RangeInclusive => quote! { std::ops::RangeInclusive<#elem> },
+        }
+    }
+
+    /// swift_bridge::range::FfiRange<T>
+    pub(super) fn to_ffi_compatible_rust_type(&self, swift_bridge_path: &Path) -> TokenStream {
+        let elem = self.elem.to_rust();
+
+        quote! { #swift_bridge_path::range::FfiRange<#elem> }
+    }
+
+    /// Range<UInt> or ClosedRange<UInt>
+    ///
+    /// The `@_cdecl` functions that Rust calls use the C struct instead.
+    pub(super) fn to_swift_type(&self, type_pos: TypePosition) -> String {
+        match type_pos {
+            TypePosition::FnArg(func_host_lang) | TypePosition::FnReturn(func_host_lang)
+                if func_host_lang.is_swift() =>
+            {
+                self.ffi_name_string()
+            }
+            TypePosition::SharedStructField => {
+                todo!("Range fields in shared structs are not yet supported")
+            }
+            TypePosition::SwiftCallsRustAsyncOnCompleteReturnTy => self.ffi_name_string(),
+            _ => self.swift_range_type(),
+        }
+    }
+
+    /// struct __private__FfiRangeUsize
+    pub(super) fn to_c(&self) -> String {
+        format!("struct {}", self.ffi_name_string())
+    }
+
+    /// __private__FfiRangeUsize
+    fn ffi_name_string(&self) -> String {
+        let elem = self.elem.to_rust().to_string();
+        let mut chars = elem.chars();
+
+        // u8 -> U8, usize -> Usize, etc...
+        let capitalized = chars.next().unwrap().to_uppercase().to_string() + chars.as_str();
+
+        format!("__private__FfiRange{}", capitalized)
+    }
+
+    /// Range<UInt> or ClosedRange<UInt>
+    fn swift_range_type(&self) -> String {
+        let elem = self
+            .elem
+            .to_swift_type(TypePosition::FnReturn(HostLang::Rust));
+
+        match self.kind {
+            RangeKind::Range => format!("Range<{}>", elem),
+            RangeKind::RangeInclusive => format!("ClosedRange<{}>", elem),
+        }
+    }
+
+    pub(super) fn convert_rust_value_to_ffi_value(
+        &self,
+        expression: &TokenStream,
+        swift_bridge_path: &Path,
+    ) -> TokenStream {
+        match self.kind {
+            RangeKind::Range => quote! {
+                #swift_bridge_path::range::FfiRange::from_range( #expression )
+            },
+            RangeKind::RangeInclusive => quote! {
+                #swift_bridge_path::range::FfiRange::from_range_inclusive( #expression )
+            },
+        }
+    }
+
+    pub(super) fn convert_ffi_value_to_rust_value(
+        &self,
+        value: &TokenStream,
+        span: Span,
+    ) -> TokenStream {
+        match self.kind {
+            RangeKind::Range => quote_spanned! {span=> #value.into_range() },
+            RangeKind::RangeInclusive => quote_spanned! {span=> #value.into_range_inclusive() },
+        }
+    }
+
+    /// `{ () -> Range<UInt> in let val = expression; return val.start..<val.end }()`
+    pub(super) fn convert_ffi_expression_to_swift(&self, expression: &str) -> String {
+        let operator = match self.kind {
+            RangeKind::Range => "..<",
+            RangeKind::RangeInclusive => "...",
+        };
+
+        format!(
+            "{{ () -> {swift_ty} in let val = {expression}; return val.start{operator}val.end }}()",
+            swift_ty = self.swift_range_type(),
+            expression = expression,
+            operator = operator
+        )
+    }
+
+    /// `{ let val = expression; return __private__FfiRangeUsize(start: val.lowerBound, ...) }()`
+    pub(super) fn convert_swift_expression_to_ffi_compatible(&self, expression: &str) -> String {
+        let inclusive = match self.kind {
+            RangeKind::Range => false,
+            RangeKind::RangeInclusive => true,
+        };
+
+        format!(
+            "{{ let val = {expression}; return {ffi_name}(start: val.lowerBound, end: val.upperBound, inclusive: {inclusive}) }}()",
+            expression = expression,
+            ffi_name = self.ffi_name_string(),
+            inclusive = inclusive
+        )
+    }
+}
+
+/// `Range<T>` -> `(RangeKind::Range, T)`
+fn range_kind_and_elem_type(path: &TypePath) -> Option<(RangeKind, &Type)> {
+    let last = path.path.segments.last()?;
+    let kind = if last.ident == "Range" {
+        RangeKind::Range
+    } else if last.ident == "RangeInclusive" {
+        RangeKind::RangeInclusive
+    } else {
+        return None;
+    };
+
+    let args = match &last.arguments {
+        PathArguments::AngleBracketed(args) => &args.args,
+        _ => return None,
+    };
+    let elem = args.iter().find_map(|arg| match arg {
+        GenericArgument::Type(ty) => Some(ty),
+        _ => None,
+    })?;
+
+    Some((kind, elem))
+}
+
+/// If the path is a `Range<T>` or `RangeInclusive<T>` whose element type we do not support,
+/// return the element type.
+pub(crate) fn unsupported_range_elem_type<'a>(
+    path: &'a TypePath,
+    types: &TypeDeclarations,
+) -> Option<&'a Type> {
+    let (_kind, elem) = range_kind_and_elem_type(path)?;
+
+    match BridgedType::new_with_type(elem, types) {
+        Some(bridged_elem) if BridgedRange::supports_elem_type(&bridged_elem) => None,
+        _ => Some(elem),
+    }
+}
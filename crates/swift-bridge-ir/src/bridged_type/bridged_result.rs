@@ -0,0 +1,278 @@
+use crate::bridged_type::{BridgedType, CustomBridgedType, SharedType, StdLibType, TypePosition};
+use crate::parse::{HostLang, TypeDeclarations};
+use crate::SWIFT_BRIDGE_PREFIX;
+use proc_macro2::{Ident, Span, TokenStream};
+use quote::quote;
+use syn::{GenericArgument, Path, PathArguments, Type, TypePath};
+
+/// Result<T, E>
+///
+/// Crosses the FFI boundary as a `#[repr(C)]` enum, which is laid out as a C tagged union.
+///
+/// ```no_run,ignore
+/// #[repr(C)]
+/// pub enum __swift_bridge__ResultSomeStructAndSomeError {
+///     Ok(__swift_bridge__SomeStruct),
+///     Err(*mut super::SomeError),
+/// }
+/// ```
+///
+/// On the Swift side functions that return a `Result` become `throws` functions.
+#[derive(Debug, PartialEq, Clone)]
+pub(crate) struct BridgedResult {
+    pub ok_ty: Box<BridgedType>,
+    pub err_ty: Box<BridgedType>,
+}
+
+impl BridgedResult {
+    /// `Result<T, E>` or `std::result::Result<T, E>`
+    ///
+    /// Returns None if the path is not a Result or if we do not support its `Ok` or `Err` type.
+    pub(super) fn new_with_type_path(path: &TypePath, types: &TypeDeclarations) -> Option<Self> {
+        let last = path.path.segments.last()?;
+        if last.ident != "Result" {
+            return None;
+        }
+
+        let args = match &last.arguments {
+            PathArguments::AngleBracketed(args) => &args.args,
+            _ => return None,
+        };
+        let mut generics = args.iter().filter_map(|arg| match arg {
+            GenericArgument::Type(ty) => Some(ty),
+            _ => None,
+        });
+
+        let ok_ty = match generics.next()? {
+            Type::Tuple(tuple) if tuple.elems.is_empty() => BridgedType::StdLib(StdLibType::Null),
+            ty => BridgedType::new_with_type(ty, types)?,
+        };
+        let err_ty = BridgedType::new_with_type(generics.next()?, types)?;
+
+        if !Self::supports_ok_type(&ok_ty) || !Self::supports_err_type(&err_ty) {
+            return None;
+        }
+
+        Some(BridgedResult {
+            ok_ty: Box::new(ok_ty),
+            err_ty: Box::new(err_ty),
+        })
+    }
+
+    /// The `Ok` payload can be `()`, a primitive, a shared type or an owned opaque Rust type.
+    fn supports_ok_type(ty: &BridgedType) -> bool {
+        match ty {
+            BridgedType::StdLib(stdlib_type) => matches!(
+                stdlib_type,
+                StdLibType::Null
+                    | StdLibType::U8
+                    | StdLibType::I8
+                    | StdLibType::U16
+                    | StdLibType::I16
+                    | StdLibType::U32
+                    | StdLibType::I32
+                    | StdLibType::U64
+                    | StdLibType::I64
+                    | StdLibType::Usize
+                    | StdLibType::Isize
+                    | StdLibType::F32
+                    | StdLibType::F64
+                    | StdLibType::Bool
+            ),
+            _ => Self::supports_err_type(ty),
+        }
+    }
+
+    /// The `Err` payload needs to become a Swift type that conforms to `Error`, so it must be a
+    /// `Box<dyn Error>`, a shared type or an owned opaque Rust type.
+    fn supports_err_type(ty: &BridgedType) -> bool {
+        match ty {
+            BridgedType::StdLib(StdLibType::BoxedError) => true,
+            BridgedType::StdLib(_) => false,
+            BridgedType::Foreign(CustomBridgedType::Shared(_)) => true,
+            BridgedType::Foreign(CustomBridgedType::Opaque(opaque)) => {
+                opaque.host_lang.is_rust() && !opaque.reference
+            }
+        }
+    }
+
+    /// std::result::Result<T, E>
+    pub(super) fn to_rust(&self) -> TokenStream {
+        let ok = self.ok_ty.to_rust();
+        let err = self.err_ty.to_rust();
+
+        quote! { std::result::Result<#ok, #err> }
+    }
+
+    /// ResultSomeStructAndSomeError
+    fn name_without_prefix(&self) -> String {
+        format!(
+            "Result{}And{}",
+            name_segment(&self.ok_ty),
+            name_segment(&self.err_ty)
+        )
+    }
+
+    /// __swift_bridge__$ResultSomeStructAndSomeError
+    pub fn ffi_name_string(&self) -> String {
+        format!("{}${}", SWIFT_BRIDGE_PREFIX, self.name_without_prefix())
+    }
+
+    /// __swift_bridge__$ResultSomeStructAndSomeError$Tag
+    pub fn ffi_tag_name_string(&self) -> String {
+        format!("{}$Tag", self.ffi_name_string())
+    }
+
+    /// __swift_bridge__ResultSomeStructAndSomeError
+    pub fn ffi_name_tokens(&self) -> TokenStream {
+        let name = Ident::new(
+            &format!("{}{}", SWIFT_BRIDGE_PREFIX, self.name_without_prefix()),
+            Span::call_site(),
+        );
+        quote! { #name }
+    }
+
+    /// The `#[repr(C)]` enum that the Result gets converted into before crossing the boundary.
+    pub(crate) fn generate_ffi_repr_tokens(&self, swift_bridge_path: &Path) -> TokenStream {
+        let ffi_name = self.ffi_name_tokens();
+        let err = self.err_ty.to_ffi_compatible_rust_type(swift_bridge_path);
+
+        let ok_variant = if self.ok_ty.is_null() {
+            quote! { Ok }
+        } else {
+            let ok = self.ok_ty.to_ffi_compatible_rust_type(swift_bridge_path);
+            quote! { Ok(#ok) }
+        };
+
+        // The payloads only ever get read on the Swift side, so Rust would otherwise warn that
+        // they are never read.
+        quote! {
+            #[repr(C)]
+            #[doc(hidden)]
+            #[allow(dead_code)]
+            pub enum #ffi_name {
+                #ok_variant,
+                Err(#err)
+            }
+        }
+    }
+
+    /// The C declaration of the tagged union that the `#[repr(C)]` enum is laid out as.
+    pub(crate) fn generate_c_declaration(&self) -> String {
+        let ffi_name = self.ffi_name_string();
+        let ffi_tag_name = self.ffi_tag_name_string();
+
+        let maybe_ok_field = if self.ok_ty.is_null() {
+            "".to_string()
+        } else {
+            format!("{} ok; ", self.ok_ty.to_c())
+        };
+
+        format!(
+            r#"typedef enum {ffi_tag_name} {{ {ffi_name}$ResultOk, {ffi_name}$ResultErr }} {ffi_tag_name};
+union {ffi_name}$Fields {{ {maybe_ok_field}{err} err; }};
+typedef struct {ffi_name} {{ {ffi_tag_name} tag; union {ffi_name}$Fields payload; }} {ffi_name};"#,
+            ffi_name = ffi_name,
+            ffi_tag_name = ffi_tag_name,
+            maybe_ok_field = maybe_ok_field,
+            err = self.err_ty.to_c(),
+        )
+    }
+
+    /// __swift_bridge__ResultSomeStructAndSomeError
+    pub(super) fn to_ffi_compatible_rust_type(&self) -> TokenStream {
+        self.ffi_name_tokens()
+    }
+
+    /// The `Ok` type, since the `Err` gets thrown.
+    ///
+    /// The callback that completes an async function receives the tagged union itself, which we
+    /// then unwrap on the Swift side.
+    pub(super) fn to_swift_type(&self, type_pos: TypePosition) -> String {
+        match type_pos {
+            TypePosition::FnReturn(func_host_lang) if func_host_lang.is_rust() => {
+                self.ok_ty.to_swift_type(type_pos)
+            }
+            TypePosition::SwiftCallsRustAsyncOnCompleteReturnTy => self.ffi_name_string(),
+            _ => todo!(
+                "Result<T, E> is only supported as the return type of extern \"Rust\" functions"
+            ),
+        }
+    }
+
+    pub(super) fn to_c(&self) -> String {
+        format!("struct {}", self.ffi_name_string())
+    }
+
+    pub(super) fn convert_rust_value_to_ffi_value(
+        &self,
+        expression: &TokenStream,
+        swift_bridge_path: &Path,
+    ) -> TokenStream {
+        let ffi_name = self.ffi_name_tokens();
+
+        let convert_ok = if self.ok_ty.is_null() {
+            quote! { Ok(()) => #ffi_name::Ok }
+        } else {
+            let ok = self
+                .ok_ty
+                .convert_rust_value_to_ffi_compatible_value(&quote! { ok }, swift_bridge_path);
+            quote! { Ok(ok) => #ffi_name::Ok(#ok) }
+        };
+        let err = self
+            .err_ty
+            .convert_rust_value_to_ffi_compatible_value(&quote! { err }, swift_bridge_path);
+
+        quote! {
+            match #expression {
+                #convert_ok,
+                Err(err) => #ffi_name::Err(#err)
+            }
+        }
+    }
+
+    /// Unwrap the tagged union into either the `Ok` value or a thrown `Err` value.
+    ///
+    /// `try { () throws -> SomeStruct in let val = ...; if val.tag == ... }()`
+    pub(super) fn convert_ffi_expression_to_swift(&self, expression: &str) -> String {
+        let type_pos = TypePosition::FnReturn(HostLang::Rust);
+
+        let return_ok = if self.ok_ty.is_null() {
+            "return".to_string()
+        } else {
+            format!(
+                "return {}",
+                self.ok_ty
+                    .convert_ffi_value_to_swift_value("val.payload.ok", type_pos)
+            )
+        };
+
+        format!(
+            "try {{ () throws -> {ok_ty} in let val = {expression}; if val.tag == {ffi_name}$ResultOk {{ {return_ok} }} else {{ throw {err} }} }}()",
+            ok_ty = self.ok_ty.to_swift_type(type_pos),
+            expression = expression,
+            ffi_name = self.ffi_name_string(),
+            return_ok = return_ok,
+            err = self
+                .err_ty
+                .convert_ffi_value_to_swift_value("val.payload.err", type_pos),
+        )
+    }
+}
+
+/// The part of the Result's FFI name that identifies the `Ok` or `Err` type.
+///
+/// () -> Void, u8 -> UInt8, SomeStruct -> SomeStruct
+fn name_segment(ty: &BridgedType) -> String {
+    match ty {
+        BridgedType::StdLib(StdLibType::Null) => "Void".to_string(),
+        BridgedType::StdLib(_) => ty.to_swift_type(TypePosition::FnReturn(HostLang::Rust)),
+        BridgedType::Foreign(CustomBridgedType::Shared(SharedType::Struct(shared_struct))) => {
+            shared_struct.name.to_string()
+        }
+        BridgedType::Foreign(CustomBridgedType::Shared(SharedType::Enum(shared_enum))) => {
+            shared_enum.name.to_string()
+        }
+        BridgedType::Foreign(CustomBridgedType::Opaque(opaque)) => opaque.swift_name(),
+    }
+}
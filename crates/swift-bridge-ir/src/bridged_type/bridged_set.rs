@@ -0,0 +1,150 @@
+use crate::bridged_type::bridged_hashmap::{
+    convert_ffi_entry_to_swift, convert_swift_entry_to_ffi, swift_entry_type, swift_ffi_entry_type,
+    BridgedHashMap,
+};
+use crate::bridged_type::BridgedType;
+use crate::parse::TypeDeclarations;
+use proc_macro2::{Span, TokenStream};
+use quote::quote;
+use syn::{GenericArgument, Path, PathArguments, TypePath};
+
+/// `HashSet<T>` or `BTreeSet<T>`
+///
+/// Crosses the FFI boundary as a `swift_bridge::set::FfiSet`, which holds a buffer of FFI
+/// compatible elements.
+///
+/// On the Swift side the set is exposed as a `Set<T>`.
+#[derive(Debug, PartialEq, Clone)]
+pub(crate) struct BridgedSet {
+    pub kind: SetKind,
+    pub elem: Box<BridgedType>,
+}
+
+#[derive(Debug, PartialEq, Copy, Clone)]
+pub(crate) enum SetKind {
+    /// `HashSet<T>`
+    HashSet,
+    /// `BTreeSet<T>`
+    BTreeSet,
+}
+
+impl BridgedSet {
+    /// `HashSet<T>`, `BTreeSet<T>` or their `std::collections::` paths.
+    ///
+    /// Returns None if the path is not a set or if we do not support its element type.
+    pub(super) fn new_with_type_path(path: &TypePath, types: &TypeDeclarations) -> Option<Self> {
+        let last = path.path.segments.last()?;
+        let kind = if last.ident == "HashSet" {
+            SetKind::HashSet
+        } else if last.ident == "BTreeSet" {
+            SetKind::BTreeSet
+        } else {
+            return None;
+        };
+
+        let args = match &last.arguments {
+            PathArguments::AngleBracketed(args) => &args.args,
+            _ => return None,
+        };
+        let elem = args.iter().find_map(|arg| match arg {
+            GenericArgument::Type(ty) => Some(ty),
+            _ => None,
+        })?;
+        let elem = BridgedType::new_with_type(elem, types)?;
+
+        // Sets support the same element types as HashMap keys and values.
+        if !BridgedHashMap::supports_entry_type(&elem) {
+            return None;
+        }
+
+        Some(BridgedSet {
+            kind,
+            elem: Box::new(elem),
+        })
+    }
+
+    /// std::collections::HashSet<T>
+    pub(super) fn to_rust(&self) -> TokenStream {
+        let elem = self.elem.to_rust();
+
+        match self.kind {
+            SetKind::HashSet => quote! { std::collections::HashSet<#elem> },
+            SetKind::BTreeSet => quote! { std::collections::BTreeSet<#elem> },
+        }
+    }
+
+    /// swift_bridge::set::FfiSet<T>
+    pub(super) fn to_ffi_compatible_rust_type(&self, swift_bridge_path: &Path) -> TokenStream {
+        let elem = self.elem.to_ffi_compatible_rust_type(swift_bridge_path);
+
+        quote! { #swift_bridge_path::set::FfiSet<#elem> }
+    }
+
+    /// Set<T>
+    pub(super) fn to_swift_type(&self) -> String {
+        format!("Set<{}>", swift_entry_type(&self.elem))
+    }
+
+    pub(super) fn convert_rust_value_to_ffi_value(
+        &self,
+        expression: &TokenStream,
+        swift_bridge_path: &Path,
+    ) -> TokenStream {
+        let elem = self
+            .elem
+            .convert_rust_value_to_ffi_compatible_value(&quote! { elem }, swift_bridge_path);
+
+        quote! {
+            #swift_bridge_path::set::FfiSet::from_elems(
+                #expression.into_iter().map(|elem| #elem)
+            )
+        }
+    }
+
+    pub(super) fn convert_ffi_value_to_rust_value(
+        &self,
+        value: &TokenStream,
+        span: Span,
+    ) -> TokenStream {
+        let converted_elem = self
+            .elem
+            .convert_ffi_value_to_rust_value(&quote! { elem }, span);
+        let set = self.to_rust();
+
+        quote! {
+            #value
+                .into_elems()
+                .map(|elem| #converted_elem)
+                .collect::<#set>()
+        }
+    }
+
+    /// Move the elements of a Rust set into a Swift Set.
+    pub(super) fn convert_ffi_expression_to_swift(&self, expression: &str) -> String {
+        format!(
+            "{expression}.toSet({{ (elem: {ffi_elem}) in {elem} }})",
+            expression = expression,
+            ffi_elem = swift_ffi_entry_type(&self.elem),
+            elem = convert_ffi_entry_to_swift(&self.elem, "elem"),
+        )
+    }
+
+    /// A Swift Set argument gets lent to Rust using `Set.toFfiSet`, which gives us a
+    /// `{arg}AsFfiSet` that is valid for the duration of the call.
+    /// See `generate_swift.rs`.
+    pub(super) fn convert_swift_expression_to_ffi_compatible(&self, expression: &str) -> String {
+        format!("{expression}AsFfiSet", expression = expression)
+    }
+
+    /// The closure that converts a Swift Set's elements into their FFI representation.
+    ///
+    /// `{ (elem: String) -> UnsafeMutableRawPointer in ... }`
+    pub(crate) fn swift_elems_to_ffi_closure(&self) -> String {
+        format!(
+            "{{ (elem: {elem_ty}) -> {ffi_elem_ty} in {elem} }}",
+            elem_ty = swift_entry_type(&self.elem),
+            ffi_elem_ty = swift_ffi_entry_type(&self.elem),
+            elem = convert_swift_entry_to_ffi(&self.elem, "elem"),
+        )
+    }
+}
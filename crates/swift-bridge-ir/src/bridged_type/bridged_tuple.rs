@@ -0,0 +1,286 @@
+use crate::bridged_type::{BridgedType, CustomBridgedType, SharedType, StdLibType, TypePosition};
+use crate::parse::{HostLang, TypeDeclarations};
+use crate::SWIFT_BRIDGE_PREFIX;
+use proc_macro2::{Ident, Span, TokenStream};
+use quote::{format_ident, quote};
+use syn::{Index, Path, TypeTuple};
+
+/// The largest tuple that we support.
+const MAX_TUPLE_LEN: usize = 4;
+
+/// `(A, B)`
+///
+/// A tuple crosses the FFI boundary as a `#[repr(C)]` struct with one field per element.
+///
+/// ```no_run,ignore
+/// #[repr(C)]
+/// pub struct __swift_bridge__TupleInt32AndString {
+///     _0: i32,
+///     _1: *mut swift_bridge::string::RustString,
+/// }
+/// ```
+///
+/// On the Swift side the struct gets converted to and from a Swift tuple such as
+/// `(Int32, RustString)`.
+#[derive(Debug, PartialEq, Clone)]
+pub(crate) struct BridgedTuple {
+    pub elems: Vec<BridgedType>,
+}
+
+impl BridgedTuple {
+    /// `(A, B)`
+    ///
+    /// Returns None if the tuple has fewer than two or more than `MAX_TUPLE_LEN` elements, or if
+    /// we do not support one of its element types.
+    pub(super) fn new_with_type_tuple(tuple: &TypeTuple, types: &TypeDeclarations) -> Option<Self> {
+        if tuple.elems.len() < 2 || tuple.elems.len() > MAX_TUPLE_LEN {
+            return None;
+        }
+
+        let mut elems = vec![];
+        for elem in tuple.elems.iter() {
+            let elem = BridgedType::new_with_type(elem, types)?;
+            if !Self::supports_elem_type(&elem) {
+                return None;
+            }
+
+            elems.push(elem);
+        }
+
+        Some(BridgedTuple { elems })
+    }
+
+    /// The elements can be primitives, `String`s or shared structs.
+    fn supports_elem_type(ty: &BridgedType) -> bool {
+        match ty {
+            BridgedType::StdLib(stdlib_type) => matches!(
+                stdlib_type,
+                StdLibType::U8
+                    | StdLibType::I8
+                    | StdLibType::U16
+                    | StdLibType::I16
+                    | StdLibType::U32
+                    | StdLibType::I32
+                    | StdLibType::U64
+                    | StdLibType::I64
+                    | StdLibType::Usize
+                    | StdLibType::Isize
+                    | StdLibType::F32
+                    | StdLibType::F64
+                    | StdLibType::Bool
+                    | StdLibType::String
+            ),
+            BridgedType::Foreign(CustomBridgedType::Shared(SharedType::Struct(_))) => true,
+            _ => false,
+        }
+    }
+
+    /// (i32, String)
+    pub(super) fn to_rust(&self) -> TokenStream {
+        let elems = self.elems.iter().map(|elem| elem.to_rust());
+
+        quote! { (#(#elems),*) }
+    }
+
+    /// TupleInt32AndString
+    fn name_without_prefix(&self) -> String {
+        let elems: Vec<String> = self
+            .elems
+            .iter()
+            .map(|elem| match elem {
+                BridgedType::StdLib(StdLibType::String) => "String".to_string(),
+                BridgedType::Foreign(CustomBridgedType::Shared(SharedType::Struct(
+                    shared_struct,
+                ))) => shared_struct.name.to_string(),
+                _ => elem.to_swift_type(TypePosition::FnReturn(HostLang::Rust)),
+            })
+            .collect();
+
+        format!("Tuple{}", elems.join("And"))
+    }
+
+    /// __swift_bridge__$TupleInt32AndString
+    pub fn ffi_name_string(&self) -> String {
+        format!("{}${}", SWIFT_BRIDGE_PREFIX, self.name_without_prefix())
+    }
+
+    /// __swift_bridge__TupleInt32AndString
+    pub fn ffi_name_tokens(&self) -> TokenStream {
+        let name = Ident::new(
+            &format!("{}{}", SWIFT_BRIDGE_PREFIX, self.name_without_prefix()),
+            Span::call_site(),
+        );
+        quote! { #name }
+    }
+
+    /// _0, _1, ...
+    fn field_names(&self) -> impl Iterator<Item = Ident> + '_ {
+        (0..self.elems.len()).map(|idx| format_ident!("_{}", idx))
+    }
+
+    /// The `#[repr(C)]` struct that holds the tuple's elements while they cross the FFI boundary.
+    pub(crate) fn generate_ffi_repr_tokens(&self, swift_bridge_path: &Path) -> TokenStream {
+        let ffi_name = self.ffi_name_tokens();
+        let field_names = self.field_names();
+        let field_tys = self
+            .elems
+            .iter()
+            .map(|elem| elem.to_ffi_compatible_rust_type(swift_bridge_path));
+
+        quote! {
+            #[repr(C)]
+            #[doc(hidden)]
+            pub struct #ffi_name {
+                #(#field_names: #field_tys),*
+            }
+        }
+    }
+
+    /// The C declaration of the struct that holds the tuple's elements.
+    pub(crate) fn generate_c_declaration(&self) -> String {
+        let fields: Vec<String> = self
+            .elems
+            .iter()
+            .enumerate()
+            .map(|(idx, elem)| format!("{} _{};", elem.to_c(), idx))
+            .collect();
+
+        format!(
+            "typedef struct {ffi_name} {{ {fields} }} {ffi_name};",
+            ffi_name = self.ffi_name_string(),
+            fields = fields.join(" ")
+        )
+    }
+
+    pub(super) fn to_ffi_compatible_rust_type(&self) -> TokenStream {
+        self.ffi_name_tokens()
+    }
+
+    /// (Int32, RustString)
+    ///
+    /// The `@_cdecl` functions that Rust calls use the C struct instead.
+    pub(super) fn to_swift_type(&self, type_pos: TypePosition) -> String {
+        match type_pos {
+            TypePosition::FnArg(func_host_lang) | TypePosition::FnReturn(func_host_lang)
+                if func_host_lang.is_swift() =>
+            {
+                self.ffi_name_string()
+            }
+            TypePosition::SharedStructField => {
+                todo!("Tuple fields in shared structs are not yet supported")
+            }
+            _ => {
+                let elems: Vec<String> = self
+                    .elems
+                    .iter()
+                    .map(|elem| elem.to_swift_type(type_pos))
+                    .collect();
+
+                format!("({})", elems.join(", "))
+            }
+        }
+    }
+
+    pub(super) fn to_c(&self) -> String {
+        format!("struct {}", self.ffi_name_string())
+    }
+
+    /// `{ let val = expression; __swift_bridge__TupleInt32AndString { _0: val.0, _1: ... } }`
+    pub(super) fn convert_rust_value_to_ffi_value(
+        &self,
+        expression: &TokenStream,
+        swift_bridge_path: &Path,
+    ) -> TokenStream {
+        let ffi_name = self.ffi_name_tokens();
+        let field_names = self.field_names();
+        let fields = self.elems.iter().enumerate().map(|(idx, elem)| {
+            let idx = Index::from(idx);
+            elem.convert_rust_value_to_ffi_compatible_value(&quote! { val.#idx }, swift_bridge_path)
+        });
+
+        quote! {
+            { let val = #expression; #ffi_name { #(#field_names: #fields),* } }
+        }
+    }
+
+    /// `{ let val = value; (val._0, ...) }`
+    pub(super) fn convert_ffi_value_to_rust_value(
+        &self,
+        value: &TokenStream,
+        span: Span,
+    ) -> TokenStream {
+        let elems = self
+            .elems
+            .iter()
+            .zip(self.field_names())
+            .map(|(elem, field_name)| {
+                elem.convert_ffi_value_to_rust_value(&quote! { val.#field_name }, span)
+            });
+
+        quote! {
+            { let val = #value; (#(#elems),*) }
+        }
+    }
+
+    /// `{ () -> (Int32, RustString) in let val = expression; return (val._0, ...) }()`
+    pub(super) fn convert_ffi_expression_to_swift(
+        &self,
+        expression: &str,
+        type_pos: TypePosition,
+    ) -> String {
+        let elems: Vec<String> = self
+            .elems
+            .iter()
+            .enumerate()
+            .map(|(idx, elem)| {
+                elem.convert_ffi_value_to_swift_value(&format!("val._{}", idx), type_pos)
+            })
+            .collect();
+
+        let swift_ty = match type_pos {
+            TypePosition::FnArg(func_host_lang) | TypePosition::FnReturn(func_host_lang)
+                if func_host_lang.is_swift() =>
+            {
+                self.to_swift_type(TypePosition::FnReturn(HostLang::Rust))
+            }
+            _ => self.to_swift_type(type_pos),
+        };
+
+        format!(
+            "{{ () -> {swift_ty} in let val = {expression}; return ({elems}) }}()",
+            swift_ty = swift_ty,
+            expression = expression,
+            elems = elems.join(", ")
+        )
+    }
+
+    /// `{ let val = expression; return __swift_bridge__$TupleInt32AndString(_0: val.0, ...) }()`
+    pub(super) fn convert_swift_expression_to_ffi_compatible(
+        &self,
+        expression: &str,
+        type_pos: TypePosition,
+    ) -> String {
+        let fields: Vec<String> = self
+            .elems
+            .iter()
+            .enumerate()
+            .map(|(idx, elem)| {
+                format!(
+                    "_{idx}: {}",
+                    elem.convert_swift_expression_to_ffi_compatible(
+                        &format!("val.{}", idx),
+                        type_pos
+                    ),
+                    idx = idx
+                )
+            })
+            .collect();
+
+        format!(
+            "{{ let val = {expression}; return {ffi_name}({fields}) }}()",
+            expression = expression,
+            ffi_name = self.ffi_name_string(),
+            fields = fields.join(", ")
+        )
+    }
+}
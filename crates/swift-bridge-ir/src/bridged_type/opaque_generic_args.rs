@@ -0,0 +1,119 @@
+use crate::bridged_type::{BridgedType, StdLibType, TypePosition};
+use crate::parse::{HostLang, TypeDeclarations};
+use proc_macro2::TokenStream;
+use quote::{quote, ToTokens};
+use syn::{GenericArgument, PathArguments, TypePath};
+
+/// The concrete types that a generic opaque type was instantiated with.
+///
+/// Every instantiation gets its own Swift class and its own set of C symbols.
+///
+/// `MyWrapper<u32>` -> [u32]
+/// `MyWrapper` -> []
+#[derive(Debug, PartialEq, Clone, Default)]
+pub(crate) struct OpaqueGenericArgs {
+    pub args: Vec<BridgedType>,
+}
+
+impl OpaqueGenericArgs {
+    /// `MyWrapper<u32>` -> [u32]
+    ///
+    /// Returns None if the path does not have any generic arguments or if we do not support one of
+    /// its generic arguments.
+    pub(crate) fn new_with_type_path(path: &TypePath, types: &TypeDeclarations) -> Option<Self> {
+        let last = path.path.segments.last()?;
+
+        let args = match &last.arguments {
+            PathArguments::AngleBracketed(args) => &args.args,
+            _ => return None,
+        };
+
+        let mut generic_args = vec![];
+        for arg in args {
+            let ty = match arg {
+                GenericArgument::Type(ty) => BridgedType::new_with_type(ty, types)?,
+                _ => return None,
+            };
+
+            if !Self::supports_arg_type(&ty) {
+                return None;
+            }
+
+            generic_args.push(ty);
+        }
+
+        Some(OpaqueGenericArgs { args: generic_args })
+    }
+
+    /// Whether or not we know how to instantiate a generic opaque type with the given type.
+    ///
+    /// For now we support primitives.
+    fn supports_arg_type(ty: &BridgedType) -> bool {
+        matches!(
+            ty,
+            BridgedType::StdLib(
+                StdLibType::U8
+                    | StdLibType::I8
+                    | StdLibType::U16
+                    | StdLibType::I16
+                    | StdLibType::U32
+                    | StdLibType::I32
+                    | StdLibType::U64
+                    | StdLibType::I64
+                    | StdLibType::Usize
+                    | StdLibType::Isize
+                    | StdLibType::F32
+                    | StdLibType::F64
+                    | StdLibType::Bool
+            )
+        )
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.args.is_empty()
+    }
+
+    /// Generic arguments for use in a type position.
+    ///
+    /// [u32, u64] -> <u32, u64>
+    pub fn to_rust_type_tokens(&self) -> TokenStream {
+        if self.is_empty() {
+            return quote! {};
+        }
+
+        let args = self.args.iter().map(|arg| arg.to_rust());
+        quote! { <#(#args),*> }
+    }
+
+    /// Generic arguments for use in an expression position.
+    ///
+    /// [u32, u64] -> ::<u32, u64>
+    pub fn to_rust_turbofish_tokens(&self) -> TokenStream {
+        if self.is_empty() {
+            return quote! {};
+        }
+
+        let args = self.to_rust_type_tokens();
+        quote! { :: #args }
+    }
+
+    /// Appended to the type's name to get the name of the instantiation's Swift class.
+    ///
+    /// [u32, u64] -> "UInt32UInt64"
+    pub fn swift_name_suffix(&self) -> String {
+        self.args
+            .iter()
+            .map(|arg| arg.to_swift_type(TypePosition::FnArg(HostLang::Rust)))
+            .collect()
+    }
+
+    /// Appended to the type's name to get the name that is used in the instantiation's C symbols.
+    ///
+    /// [u32, u64] -> "$u32$u64"
+    pub fn link_name_suffix(&self) -> String {
+        self.args
+            .iter()
+            .map(|arg| format!("${}", arg.to_rust().to_token_stream()))
+            .collect()
+    }
+}
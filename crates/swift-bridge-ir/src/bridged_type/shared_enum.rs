@@ -1,3 +1,4 @@
+use crate::parse::SwiftVisibility;
 use crate::SWIFT_BRIDGE_PREFIX;
 use proc_macro2::{Ident, TokenStream};
 use quote::quote;
@@ -10,6 +11,23 @@ pub(crate) use self::enum_variant::EnumVariant;
 pub(crate) struct SharedEnum {
     pub name: Ident,
     pub variants: Vec<EnumVariant>,
+    /// Whether or not the `#[swift_bridge(Comparable)]` attribute was present on the enum.
+    ///
+    /// If so, the Swift enum gets an `Int32` raw value and is ordered by its discriminants.
+    pub comparable: bool,
+    /// Whether or not the `#[swift_bridge(Hashable)]` attribute was present on the enum.
+    pub hashable: bool,
+    /// Whether or not the `#[swift_bridge(LocalizedError)]` attribute was present on the enum.
+    pub localized_error: bool,
+    /// Whether or not the `#[swift_bridge(Sendable)]` attribute was present on the enum.
+    pub sendable: bool,
+    /// Whether or not the `#[swift_bridge(transparent)]` attribute was present on the enum.
+    ///
+    /// If so, the enum is passed over FFI as a plain `i32` instead of as a tagged union, and every
+    /// variant has a discriminant.
+    pub transparent: bool,
+    /// The access modifier of the generated Swift enum.
+    pub swift_visibility: SwiftVisibility,
 }
 
 impl SharedEnum {
@@ -28,6 +46,34 @@ impl SharedEnum {
         format!("{}Tag", self.ffi_name_string())
     }
 
+    /// __swift_bridge__$SomeEnumFields
+    pub fn ffi_fields_union_name_string(&self) -> String {
+        format!("{}Fields", self.ffi_name_string())
+    }
+
+    /// __swift_bridge__$SomeEnum$FieldOfSomeVariant
+    pub fn ffi_variant_fields_name_string(&self, variant: &EnumVariant) -> String {
+        format!("{}$FieldOf{}", self.ffi_name_string(), variant.name)
+    }
+
+    /// Whether or not any of the enum's variants have associated data.
+    pub fn has_one_or_more_variants_with_data(&self) -> bool {
+        self.variants.iter().any(|v| !v.fields.is_empty())
+    }
+
+    /// Whether or not any of the enum's variants have an explicit discriminant, such as
+    /// `NotFound = 404`.
+    ///
+    /// If so, the Rust enum is `#[repr(i32)]` and the Swift enum is backed by an `Int32` raw value.
+    pub fn has_explicit_discriminants(&self) -> bool {
+        self.variants.iter().any(|v| v.discriminant.is_some())
+    }
+
+    /// Whether or not the Swift enum has an `Int32` raw value.
+    pub fn has_swift_raw_value(&self) -> bool {
+        self.comparable || self.has_explicit_discriminants()
+    }
+
     /// __swift_bridge__SomeEnum
     pub fn ffi_name_tokens(&self) -> TokenStream {
         let name = Ident::new(
@@ -54,7 +100,11 @@ impl SharedEnum {
 
 impl PartialEq for SharedEnum {
     fn eq(&self, other: &Self) -> bool {
-        self.name.to_string() == other.name.to_string() && self.variants == other.variants
+        self.name.to_string() == other.name.to_string()
+            && self.variants == other.variants
+            && self.hashable == other.hashable
+            && self.sendable == other.sendable
+            && self.transparent == other.transparent
     }
 }
 
@@ -63,6 +113,9 @@ impl Debug for SharedEnum {
         f.debug_struct("SharedEnum")
             .field("name", &self.name.to_string())
             .field("variants", &self.variants)
+            .field("hashable", &self.hashable)
+            .field("sendable", &self.sendable)
+            .field("transparent", &self.transparent)
             .finish()
     }
 }
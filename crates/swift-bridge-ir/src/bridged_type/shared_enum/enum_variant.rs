@@ -5,14 +5,16 @@ use std::fmt::{Debug, Formatter};
 #[derive(Clone)]
 pub(crate) struct EnumVariant {
     pub name: Ident,
-    // Will be used in a future commit.
-    #[allow(unused)]
     pub fields: StructFields,
+    /// The variant's explicit discriminant, such as the `404` in `NotFound = 404`.
+    pub discriminant: Option<i32>,
 }
 
 impl PartialEq for EnumVariant {
     fn eq(&self, other: &Self) -> bool {
         self.name.to_string() == other.name.to_string()
+            && self.fields == other.fields
+            && self.discriminant == other.discriminant
     }
 }
 
@@ -21,6 +23,7 @@ impl Debug for EnumVariant {
         f.debug_struct("EnumVariant")
             .field("name", &self.name.to_string())
             .field("fields", &self.fields)
+            .field("discriminant", &self.discriminant)
             .finish()
     }
 }
@@ -1,5 +1,5 @@
 use crate::bridged_type::{BridgedType, TypePosition};
-use crate::parse::TypeDeclarations;
+use crate::parse::{SwiftVisibility, TypeDeclarations};
 use crate::SWIFT_BRIDGE_PREFIX;
 use proc_macro2::{Ident, TokenStream};
 use quote::quote;
@@ -7,7 +7,7 @@ use std::fmt::{Debug, Formatter};
 use syn::spanned::Spanned;
 use syn::{LitStr, Path};
 
-pub(crate) use self::struct_field::StructFields;
+pub(crate) use self::struct_field::{NormalizedStructField, StructFields};
 
 mod struct_field;
 
@@ -18,6 +18,23 @@ pub(crate) struct SharedStruct {
     pub fields: StructFields,
     pub swift_name: Option<LitStr>,
     pub already_declared: bool,
+    /// Whether or not the `#[swift_bridge(Equatable)]` attribute was present on the struct.
+    pub equatable: bool,
+    /// Whether or not the `#[swift_bridge(Codable)]` attribute was present on the struct.
+    pub codable: bool,
+    /// Whether or not the `#[swift_bridge(Sendable)]` attribute was present on the struct.
+    pub sendable: bool,
+    /// The field that the generated Swift struct uses for its `Identifiable` conformance, set
+    /// using the `#[swift_bridge(Identifiable)]` or `#[swift_bridge(Identifiable = "...")]`
+    /// attribute.
+    pub identifiable: Option<Ident>,
+    /// The access modifier of the generated Swift struct or class.
+    pub swift_visibility: SwiftVisibility,
+    /// How the fields are renamed in the generated Swift struct, set using the
+    /// `#[swift_bridge(rename_all = "...")]` attribute.
+    pub rename_all: Option<StructRenameAll>,
+    /// A doc comment, which we copy over to the generated Swift struct or class.
+    pub doc_comment: Option<String>,
 }
 
 impl SharedStruct {
@@ -28,6 +45,16 @@ impl SharedStruct {
         }
     }
 
+    /// The name of a field in the generated Swift struct.
+    ///
+    /// The Rust struct and its FFI representation always keep the Rust field name.
+    pub(crate) fn swift_field_name(&self, field_name: &str) -> String {
+        match self.rename_all {
+            Some(StructRenameAll::CamelCase) => snake_case_to_camel_case(field_name),
+            None => field_name.to_string(),
+        }
+    }
+
     pub(crate) fn ffi_name_string(&self) -> String {
         let name = self.swift_name_string();
 
@@ -91,7 +118,7 @@ impl SharedStruct {
             })
             .collect();
 
-        let converted_fields = self.wrap_fields(&converted_fields);
+        let converted_fields = self.fields.wrap_fields(&converted_fields);
 
         if self.fields.is_empty() {
             quote! {
@@ -128,7 +155,7 @@ impl SharedStruct {
             })
             .collect();
 
-        let converted_fields = self.wrap_fields(&converted_fields);
+        let converted_fields = self.fields.wrap_fields(&converted_fields);
 
         let ffi_name = self.ffi_name_tokens();
 
@@ -156,9 +183,13 @@ impl SharedStruct {
             .iter()
             .map(|norm_field| {
                 let field_name = norm_field.ffi_field_name();
+                let swift_field_name = self.swift_field_name(&field_name);
                 let ty = BridgedType::new_with_type(&norm_field.ty, types).unwrap();
                 let access_field = ty.convert_swift_expression_to_ffi_compatible(
-                    &format!("val.{field_name}", field_name = field_name),
+                    &format!(
+                        "val.{swift_field_name}",
+                        swift_field_name = swift_field_name
+                    ),
                     TypePosition::SharedStructField,
                 );
 
@@ -204,8 +235,8 @@ impl SharedStruct {
                 );
 
                 format!(
-                    "{field_name}: {access_field}",
-                    field_name = field_name,
+                    "{swift_field_name}: {access_field}",
+                    swift_field_name = self.swift_field_name(&field_name),
                     access_field = access_field
                 )
             })
@@ -223,25 +254,6 @@ impl SharedStruct {
             )
         }
     }
-
-    fn wrap_fields(&self, fields: &[TokenStream]) -> TokenStream {
-        match &self.fields {
-            StructFields::Named(_) => {
-                quote! {
-                    { #(#fields),* }
-                }
-            }
-            StructFields::Unnamed(_) => {
-                quote! {
-                    ( #(#fields),* )
-                }
-            }
-            StructFields::Unit => {
-                debug_assert_eq!(fields.len(), 0);
-                quote! {}
-            }
-        }
-    }
 }
 
 impl PartialEq for SharedStruct {
@@ -252,6 +264,13 @@ impl PartialEq for SharedStruct {
             && self.swift_name.as_ref().map(|l| l.value())
                 == other.swift_name.as_ref().map(|l| l.value())
             && self.already_declared == other.already_declared
+            && self.equatable == other.equatable
+            && self.codable == other.codable
+            && self.sendable == other.sendable
+            && self.identifiable.as_ref().map(|i| i.to_string())
+                == other.identifiable.as_ref().map(|i| i.to_string())
+            && self.swift_visibility == other.swift_visibility
+            && self.rename_all == other.rename_all
     }
 }
 
@@ -263,10 +282,48 @@ impl Debug for SharedStruct {
             .field("fields", &self.fields)
             .field("swift_name", &self.swift_name.as_ref().map(|l| l.value()))
             .field("already_declared", &self.already_declared)
+            .field("equatable", &self.equatable)
+            .field("codable", &self.codable)
+            .field("sendable", &self.sendable)
+            .field(
+                "identifiable",
+                &self.identifiable.as_ref().map(|i| i.to_string()),
+            )
+            .field("swift_visibility", &self.swift_visibility)
+            .field("rename_all", &self.rename_all)
             .finish()
     }
 }
 
+/// How to rename a shared struct's fields in the generated Swift struct.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub(crate) enum StructRenameAll {
+    /// some_field -> someField
+    CamelCase,
+}
+
+/// some_field -> someField
+///
+/// Leading underscores are kept, so `_private_field` becomes `_privateField`.
+pub(crate) fn snake_case_to_camel_case(name: &str) -> String {
+    let words = name.trim_start_matches('_');
+    let mut camel_case = name[..name.len() - words.len()].to_string();
+
+    for (idx, word) in words.split('_').filter(|w| !w.is_empty()).enumerate() {
+        if idx == 0 {
+            camel_case += word;
+        } else {
+            let mut chars = word.chars();
+            if let Some(first) = chars.next() {
+                camel_case.extend(first.to_uppercase());
+                camel_case += chars.as_str();
+            }
+        }
+    }
+
+    camel_case
+}
+
 /// Whether to create a class or a structure when creating the Swift representation of a shared
 /// struct.
 ///
@@ -63,6 +63,28 @@ impl StructFields {
         }
     }
 
+    /// Wrap fields in the braces or parentheses that match this struct's fields.
+    ///
+    /// { a: val.a, b: val.b } or (val.0, val.1)
+    pub fn wrap_fields(&self, fields: &[TokenStream]) -> TokenStream {
+        match self {
+            StructFields::Named(_) => {
+                quote! {
+                    { #(#fields),* }
+                }
+            }
+            StructFields::Unnamed(_) => {
+                quote! {
+                    ( #(#fields),* )
+                }
+            }
+            StructFields::Unit => {
+                debug_assert_eq!(fields.len(), 0);
+                quote! {}
+            }
+        }
+    }
+
     pub fn from_syn_fields(fields: Fields) -> Self {
         match fields {
             Fields::Named(f) => {
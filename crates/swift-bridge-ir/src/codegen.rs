@@ -14,6 +14,8 @@ pub struct SwiftCodeAndCHeader {
     pub swift: String,
     /// The generated C header.
     pub c_header: String,
+    /// The generated Objective-C header, if `CodegenConfig::generate_objc_header` was set.
+    pub objc_header: Option<String>,
 }
 
 /// Configuration for how we will generate our Swift code.
@@ -22,6 +24,30 @@ pub struct CodegenConfig {
     /// This helps us decide whether or not to generate code for parts of the module
     /// that are annotated with `#[cfg(feature = "some-feature")]`
     pub crate_feature_lookup: Box<dyn Fn(&str) -> bool>,
+    /// Whether or not to also generate an Objective-C compatible header.
+    /// This header contains the same declarations as the C header, but annotates pointers that
+    /// can be null as `_Nullable` and assumes that every other pointer is non-null.
+    pub generate_objc_header: bool,
+    /// If set, the generated C header can also be included from C++.
+    /// See [`CppHeaderConfig`].
+    pub cpp_header: Option<CppHeaderConfig>,
+    /// Whether or not to generate Swift that is meant to be compiled with library evolution
+    /// enabled, such as when distributing a binary framework.
+    ///
+    /// The generated `public` declarations then only consist of the Swift wrappers around the
+    /// bridged functions and types. The `init(ptr:)` initializers of the generated classes become
+    /// `internal`, just like the `@_cdecl` functions that Rust calls.
+    pub library_evolution: bool,
+}
+
+/// Configuration for generating a C header that can be included from C++.
+///
+/// The header's declarations get wrapped in `#ifdef __cplusplus` / `extern "C"` guards so that
+/// C++ code links against the un-mangled symbols.
+#[derive(Debug, Clone, Default)]
+pub struct CppHeaderConfig {
+    /// If set, the declarations are placed in this C++ namespace, such as `"my_crate::ffi"`.
+    pub namespace: Option<String>,
 }
 
 #[cfg(test)]
@@ -29,6 +55,9 @@ impl CodegenConfig {
     pub(crate) fn no_features_enabled() -> Self {
         CodegenConfig {
             crate_feature_lookup: Box::new(|_| false),
+            generate_objc_header: false,
+            cpp_header: None,
+            library_evolution: false,
         }
     }
 }
@@ -39,6 +68,11 @@ impl SwiftBridgeModule {
         SwiftCodeAndCHeader {
             swift: self.generate_swift(&config),
             c_header: self.generate_c_header(&config),
+            objc_header: if config.generate_objc_header {
+                Some(self.generate_objc_header(&config))
+            } else {
+                None
+            },
         }
     }
 
@@ -46,16 +80,16 @@ impl SwiftBridgeModule {
     /// in the final binary.
     /// If not, when we won't generate any C or Swift code for it.
     fn module_will_be_compiled(&self, config: &CodegenConfig) -> bool {
-        for cfg_attr in &self.cfg_attrs {
-            match cfg_attr {
-                CfgAttr::Feature(feature_name) => {
-                    if !(config.crate_feature_lookup)(&feature_name.value()) {
-                        return false;
-                    }
-                }
-            }
-        }
+        config.cfg_attrs_are_enabled(&self.cfg_attrs)
+    }
+}
 
-        true
+impl CodegenConfig {
+    /// Whether or not code that is annotated with the given conditional compilation flags will be
+    /// included in the final binary.
+    fn cfg_attrs_are_enabled(&self, cfg_attrs: &[CfgAttr]) -> bool {
+        cfg_attrs.iter().all(|cfg_attr| match cfg_attr {
+            CfgAttr::Feature(feature_name) => (self.crate_feature_lookup)(&feature_name.value()),
+        })
     }
 }
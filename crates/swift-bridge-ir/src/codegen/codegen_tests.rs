@@ -29,18 +29,59 @@ use crate::test_utils::{
 };
 
 mod already_declared_attribute_codegen_tests;
+mod arc_codegen_tests;
+mod array_codegen_tests;
 mod async_function_codegen_tests;
+mod available_attribute_codegen_tests;
+mod boxed_error_codegen_tests;
+mod boxed_str_codegen_tests;
+mod char_codegen_tests;
+mod closure_argument_codegen_tests;
+mod comparable_attribute_codegen_tests;
 mod conditional_compilation_codegen_tests;
+mod conforms_to_attribute_codegen_tests;
+mod copy_attribute_codegen_tests;
+mod cow_str_codegen_tests;
+#[cfg(feature = "chrono")]
+mod date_time_codegen_tests;
+mod deterministic_codegen_tests;
+mod display_attribute_codegen_tests;
+mod doc_comment_codegen_tests;
+mod duration_codegen_tests;
+mod expressible_by_string_literal_attribute_codegen_tests;
 mod extern_rust_function_opaque_rust_type_argument_codegen_tests;
 mod extern_rust_function_opaque_rust_type_return_codegen_tests;
 mod extern_rust_method_swift_class_placement_codegen_tests;
 mod extern_rust_opaque_type_codegen_tests;
 mod function_attribute_codegen_tests;
+mod generic_opaque_rust_type_codegen_tests;
+mod hashmap_codegen_tests;
+mod int128_codegen_tests;
+mod mutex_codegen_tests;
+mod net_codegen_tests;
+mod no_free_attribute_codegen_tests;
+mod non_zero_codegen_tests;
 mod option_codegen_tests;
+mod path_buf_codegen_tests;
+mod pin_codegen_tests;
+mod range_codegen_tests;
+mod ref_mut_primitive_codegen_tests;
+mod result_codegen_tests;
+mod sendable_attribute_codegen_tests;
+mod set_codegen_tests;
 mod shared_enum_codegen_tests;
 mod shared_struct_codegen_tests;
+mod slice_codegen_tests;
 mod string_codegen_tests;
+mod swift_name_attribute_codegen_tests;
+mod swift_protocol_codegen_tests;
+mod swift_visibility_attribute_codegen_tests;
+mod symbol_prefix_codegen_tests;
+mod trait_object_codegen_tests;
+mod tuple_codegen_tests;
+mod unsafe_fn_codegen_tests;
 mod vec_codegen_tests;
+mod vec_deque_codegen_tests;
 
 /// Test code generation for freestanding Swift function that takes an opaque Rust type argument.
 mod extern_swift_freestanding_fn_with_owned_opaque_rust_type_arg {
@@ -179,6 +220,8 @@ struct BridgeModule {
     /// A mock representation of the features that are enabled for the crate that contains the
     /// bridge module.
     pub enabled_crate_features: Vec<&'static str>,
+    /// The `#[swift_bridge::bridge(symbol_prefix = "...")]`, if any.
+    pub symbol_prefix: Option<&'static str>,
 }
 
 impl From<TokenStream> for BridgeModule {
@@ -186,6 +229,7 @@ impl From<TokenStream> for BridgeModule {
         BridgeModule {
             tokens,
             enabled_crate_features: vec![],
+            symbol_prefix: None,
         }
     }
 }
@@ -234,7 +278,10 @@ enum ExpectedCHeader {
 
 impl CodegenTest {
     fn test(self) {
-        let module = parse_ok(self.bridge_module.tokens);
+        let mut module = parse_ok(self.bridge_module.tokens);
+        if let Some(symbol_prefix) = self.bridge_module.symbol_prefix {
+            module.set_symbol_prefix(symbol_prefix.to_string());
+        }
         let generated_tokens = module.to_token_stream();
 
         match self.expected_rust_tokens {
@@ -264,6 +311,9 @@ impl CodegenTest {
         let crate_feature_lookup = Box::new(lookup);
         let codegen_config = CodegenConfig {
             crate_feature_lookup,
+            generate_objc_header: false,
+            cpp_header: None,
+            library_evolution: false,
         };
 
         let swift = module.generate_swift(&codegen_config);
@@ -0,0 +1,171 @@
+use super::{CodegenTest, ExpectedCHeader, ExpectedRustTokens, ExpectedSwiftCode};
+use proc_macro2::TokenStream;
+use quote::quote;
+
+/// Verify that we generate a Swift class that holds an `Arc<Type>` for an opaque Rust type with
+/// the `#[swift_bridge(Arc)]` attribute, along with a function that clones the `Arc`.
+mod extern_rust_arc_type {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Rust" {
+                    #[swift_bridge(Arc)]
+                    type SomeType;
+
+                    fn value(self: &Arc<SomeType>) -> u32;
+
+                    fn new_some_type() -> Arc<SomeType>;
+                    fn take_some_type(arg: Arc<SomeType>);
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::ContainsMany(vec![
+            quote! {
+                #[export_name = "__swift_bridge__$SomeType$_free"]
+                pub extern "C" fn __swift_bridge__SomeType__free (
+                    this: *mut std::sync::Arc<super::SomeType>
+                ) {
+                    let this = unsafe { Box::from_raw(this) };
+                    drop(this);
+                }
+            },
+            quote! {
+                #[export_name = "__swift_bridge__$SomeType$_clone"]
+                pub extern "C" fn __swift_bridge__SomeType__clone (
+                    this: *mut std::sync::Arc<super::SomeType>
+                ) -> *mut std::sync::Arc<super::SomeType> {
+                    Box::into_raw(Box::new(std::sync::Arc::clone(unsafe { &*this })))
+                }
+            },
+            quote! {
+                #[export_name = "__swift_bridge__$SomeType$value"]
+                pub extern "C" fn __swift_bridge__SomeType_value (
+                    this: *const std::sync::Arc<super::SomeType>
+                ) -> u32 {
+                    (unsafe { &*this }).value()
+                }
+            },
+            quote! {
+                #[export_name = "__swift_bridge__$new_some_type"]
+                pub extern "C" fn __swift_bridge__new_some_type (
+                ) -> *mut std::sync::Arc<super::SomeType> {
+                    Box::into_raw(Box::new(super::new_some_type())) as *mut std::sync::Arc<super::SomeType>
+                }
+            },
+            quote! {
+                #[export_name = "__swift_bridge__$take_some_type"]
+                pub extern "C" fn __swift_bridge__take_some_type (
+                    arg: *mut std::sync::Arc<super::SomeType>
+                ) {
+                    super::take_some_type(unsafe { *Box::from_raw(arg) })
+                }
+            },
+        ])
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsManyAfterTrim(vec![
+            r#"
+public func new_some_type() -> SomeType {
+    SomeType(ptr: __swift_bridge__$new_some_type())
+}
+"#,
+            r#"
+public func take_some_type(_ arg: SomeType) {
+    __swift_bridge__$take_some_type({arg.isOwned = false; return arg.ptr;}())
+}
+"#,
+            r#"
+public class SomeTypeRef {
+    var ptr: UnsafeMutableRawPointer
+
+    public init(ptr: UnsafeMutableRawPointer) {
+        self.ptr = ptr
+    }
+}
+extension SomeTypeRef {
+    public func copy() -> SomeType {
+        SomeType(ptr: __swift_bridge__$SomeType$_clone(ptr))
+    }
+}
+"#,
+            r#"
+extension SomeTypeRef {
+    public func value() -> UInt32 {
+        __swift_bridge__$SomeType$value(ptr)
+    }
+}
+"#,
+        ])
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsManyAfterTrim(vec![
+            r#"
+typedef struct SomeType SomeType;
+void __swift_bridge__$SomeType$_free(void* self);
+void* __swift_bridge__$SomeType$_clone(void* self);
+"#,
+            "uint32_t __swift_bridge__$SomeType$value(void* self);",
+            "void* __swift_bridge__$new_some_type(void);",
+            "void __swift_bridge__$take_some_type(void* arg);",
+        ])
+    }
+
+    #[test]
+    fn extern_rust_arc_type() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Verify that we do not generate a clone function or a Swift `copy()` method for opaque Rust
+/// types that are not `Arc`s.
+mod extern_rust_non_arc_type_has_no_copy {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Rust" {
+                    type SomeType;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::DoesNotContain(quote! {
+            __swift_bridge__SomeType__clone
+        })
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::DoesNotContainAfterTrim("public func copy() -> SomeType")
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::DoesNotContainAfterTrim("__swift_bridge__$SomeType$_clone")
+    }
+
+    #[test]
+    fn extern_rust_non_arc_type_has_no_copy() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
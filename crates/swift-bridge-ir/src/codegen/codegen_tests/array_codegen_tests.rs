@@ -0,0 +1,200 @@
+//! See also: crates/swift-integration-tests/src/array.rs
+
+use super::{CodegenTest, ExpectedCHeader, ExpectedRustTokens, ExpectedSwiftCode};
+use proc_macro2::TokenStream;
+use quote::quote;
+
+/// Test code generation for Rust function that returns a fixed size array.
+mod extern_rust_fn_return_array {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Rust" {
+                    fn some_function() -> [u8; 4];
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::ContainsMany(vec![
+            quote! {
+                #[repr(C)]
+                #[doc(hidden)]
+                pub struct __swift_bridge__ArrayUInt8Len4 {
+                    elements: [u8; 4],
+                }
+            },
+            quote! {
+                #[export_name = "__swift_bridge__$some_function"]
+                pub extern "C" fn __swift_bridge__some_function() -> __swift_bridge__ArrayUInt8Len4 {
+                    __swift_bridge__ArrayUInt8Len4 { elements: super::some_function() }
+                }
+            },
+        ])
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+public func some_function() -> (UInt8, UInt8, UInt8, UInt8) {
+    __swift_bridge__$some_function().elements
+}
+"#,
+        )
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsManyAfterTrim(vec![
+            r#"
+typedef struct __swift_bridge__$ArrayUInt8Len4 { uint8_t elements[4]; } __swift_bridge__$ArrayUInt8Len4;
+"#,
+            r#"
+struct __swift_bridge__$ArrayUInt8Len4 __swift_bridge__$some_function(void);
+"#,
+            "#include <stdint.h>",
+        ])
+    }
+
+    #[test]
+    fn extern_rust_fn_return_array() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Test code generation for Rust function that takes a fixed size array argument.
+///
+/// The array's struct only gets defined once even though two functions use it.
+mod extern_rust_fn_array_arg {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Rust" {
+                    fn some_function(arg: [f32; 2]);
+                    fn another_function(arg: [f32; 2]) -> [f32; 2];
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::ContainsMany(vec![
+            quote! {
+                #[repr(C)]
+                #[doc(hidden)]
+                pub struct __swift_bridge__ArrayFloatLen2 {
+                    elements: [f32; 2],
+                }
+
+                #[export_name = "__swift_bridge__$some_function"]
+            },
+            quote! {
+                pub extern "C" fn __swift_bridge__some_function(
+                    arg: __swift_bridge__ArrayFloatLen2
+                ) {
+                    super::some_function(arg.elements)
+                }
+            },
+        ])
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+public func some_function(_ arg: (Float, Float)) {
+    __swift_bridge__$some_function(__swift_bridge__$ArrayFloatLen2(elements: arg))
+}
+"#,
+        )
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsAfterTrim(
+            r#"
+typedef struct __swift_bridge__$ArrayFloatLen2 { float elements[2]; } __swift_bridge__$ArrayFloatLen2;
+void __swift_bridge__$some_function(struct __swift_bridge__$ArrayFloatLen2 arg);
+struct __swift_bridge__$ArrayFloatLen2 __swift_bridge__$another_function(struct __swift_bridge__$ArrayFloatLen2 arg);
+"#,
+        )
+    }
+
+    #[test]
+    fn extern_rust_fn_array_arg() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Test code generation for Swift function that returns a fixed size array.
+mod extern_swift_fn_return_array {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Swift" {
+                    fn some_function() -> [i32; 3];
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::ContainsMany(vec![
+            quote! {
+                pub fn some_function() -> [i32; 3] {
+                    unsafe { __swift_bridge__some_function() }.elements
+                }
+            },
+            quote! {
+                #[link_name = "__swift_bridge__$some_function"]
+                fn __swift_bridge__some_function() -> __swift_bridge__ArrayInt32Len3;
+            },
+        ])
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+@_cdecl("__swift_bridge__$some_function")
+func __swift_bridge__some_function () -> __swift_bridge__$ArrayInt32Len3 {
+    __swift_bridge__$ArrayInt32Len3(elements: some_function())
+}
+"#,
+        )
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsAfterTrim(
+            r#"
+typedef struct __swift_bridge__$ArrayInt32Len3 { int32_t elements[3]; } __swift_bridge__$ArrayInt32Len3;
+"#,
+        )
+    }
+
+    #[test]
+    fn extern_swift_fn_return_array() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
@@ -381,7 +381,7 @@ mod extern_rust_async_method {
              pub extern "C" fn __swift_bridge__SomeType_some_method(
                 callback_wrapper: *mut std::ffi::c_void,
                 callback: extern "C" fn(*mut std::ffi::c_void) -> (),
-                this: *mut super::SomeType
+                this: *const super::SomeType
             ) {
                 let callback_wrapper = swift_bridge::async_support::SwiftCallbackWrapper(callback_wrapper);
                 let fut = (unsafe {&*this}).some_method();
@@ -451,3 +451,351 @@ void __swift_bridge__$SomeType$some_method(void* callback_wrapper, void __swift_
         .test();
     }
 }
+
+/// Verify that we generate the correct code for an extern "Rust" async method that returns an
+/// opaque Rust type.
+mod extern_rust_async_method_returns_opaque_type {
+    use super::*;
+
+    fn bridge_module() -> TokenStream {
+        quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                extern "Rust" {
+                    type SomeType;
+                    async fn load(&self) -> OtherType;
+                }
+
+                extern "Rust" {
+                    type OtherType;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::Contains(quote! {
+             pub extern "C" fn __swift_bridge__SomeType_load(
+                callback_wrapper: *mut std::ffi::c_void,
+                callback: extern "C" fn(*mut std::ffi::c_void, *mut super::OtherType) -> (),
+                this: *const super::SomeType
+            ) {
+                let callback_wrapper = swift_bridge::async_support::SwiftCallbackWrapper(callback_wrapper);
+                let fut = (unsafe {&*this}).load();
+                let task = async move {
+                    let val = Box::into_raw(Box::new(fut.await)) as *mut super::OtherType;
+
+                    let callback_wrapper = callback_wrapper;
+                    let callback_wrapper = callback_wrapper.0;
+
+                    (callback)(callback_wrapper, val)
+                };
+                swift_bridge::async_support::ASYNC_RUNTIME.spawn_task(Box::pin(task))
+            }
+        })
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+extension SomeTypeRef {
+    public func load() async -> OtherType {
+        class CbWrapper {
+            var cb: (Result<OtherType, Never>) -> ()
+
+            public init(cb: @escaping (Result<OtherType, Never>) -> ()) {
+                self.cb = cb
+            }
+        }
+
+        func onComplete(cbWrapperPtr: UnsafeMutableRawPointer?, rustFnRetVal: UnsafeMutableRawPointer?) {
+            let wrapper = Unmanaged<CbWrapper>.fromOpaque(cbWrapperPtr!).takeRetainedValue()
+            wrapper.cb(.success(OtherType(ptr: rustFnRetVal!)))
+        }
+
+        return await withCheckedContinuation({ (continuation: CheckedContinuation<OtherType, Never>) in
+            let callback = { rustFnRetVal in
+                continuation.resume(with: rustFnRetVal)
+            }
+
+            let wrapper = CbWrapper(cb: callback)
+            let wrapperPtr = Unmanaged.passRetained(wrapper).toOpaque()
+
+            __swift_bridge__$SomeType$load(wrapperPtr, onComplete, ptr)
+        })
+    }
+}
+"#,
+        )
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsAfterTrim(
+            r#"
+void __swift_bridge__$SomeType$load(void* callback_wrapper, void __swift_bridge__$SomeType$load$async(void* callback_wrapper, void* ret), void* self);
+    "#,
+        )
+    }
+
+    #[test]
+    fn extern_rust_async_method_returns_opaque_type() {
+        CodegenTest {
+            bridge_module: bridge_module().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Verify that we generate the correct code for extern "Rust" async functions that return a
+/// Result.
+mod extern_rust_async_function_returns_result {
+    use super::*;
+
+    fn bridge_module() -> TokenStream {
+        quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                struct SomeError;
+
+                extern "Rust" {
+                    async fn some_function() -> Result<u8, SomeError>;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::Contains(quote! {
+             pub extern "C" fn __swift_bridge__some_function(
+                callback_wrapper: *mut std::ffi::c_void,
+                callback: extern "C" fn(*mut std::ffi::c_void, __swift_bridge__ResultUInt8AndSomeError) -> (),
+            ) {
+                let callback_wrapper = swift_bridge::async_support::SwiftCallbackWrapper(callback_wrapper);
+                let fut = super::some_function();
+                let task = async move {
+                    let val = match fut.await {
+                        Ok(ok) => __swift_bridge__ResultUInt8AndSomeError::Ok(ok),
+                        Err(err) => __swift_bridge__ResultUInt8AndSomeError::Err(err.into_ffi_repr())
+                    };
+
+                    let callback_wrapper = callback_wrapper;
+                    let callback_wrapper = callback_wrapper.0;
+
+                    (callback)(callback_wrapper, val)
+                };
+                swift_bridge::async_support::ASYNC_RUNTIME.spawn_task(Box::pin(task))
+            }
+        })
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+func some_function() async throws -> UInt8 {
+    class CbWrapper {
+        var cb: (Result<UInt8, Error>) -> ()
+
+        public init(cb: @escaping (Result<UInt8, Error>) -> ()) {
+            self.cb = cb
+        }
+    }
+
+    func onComplete(cbWrapperPtr: UnsafeMutableRawPointer?, rustFnRetVal: __swift_bridge__$ResultUInt8AndSomeError) {
+        let wrapper = Unmanaged<CbWrapper>.fromOpaque(cbWrapperPtr!).takeRetainedValue()
+        wrapper.cb(Result(catching: { try { () throws -> UInt8 in let val = rustFnRetVal; if val.tag == __swift_bridge__$ResultUInt8AndSomeError$ResultOk { return val.payload.ok } else { throw val.payload.err.intoSwiftRepr() } }() }))
+    }
+
+    let continuationGuard = __private__ThrowingContinuationGuard<UInt8>()
+
+    return try await withTaskCancellationHandler(operation: {
+        try await withCheckedThrowingContinuation({ (continuation: CheckedContinuation<UInt8, Error>) in
+            continuationGuard.setContinuation(continuation)
+
+            let callback = { rustFnRetVal in
+                continuationGuard.resume(with: rustFnRetVal)
+            }
+
+            let wrapper = CbWrapper(cb: callback)
+            let wrapperPtr = Unmanaged.passRetained(wrapper).toOpaque()
+
+            __swift_bridge__$some_function(wrapperPtr, onComplete)
+        })
+    }, onCancel: {
+        continuationGuard.cancel()
+    })
+}
+"#,
+        )
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsAfterTrim(
+            r#"
+void __swift_bridge__$some_function(void* callback_wrapper, void __swift_bridge__$some_function$async(void* callback_wrapper, struct __swift_bridge__$ResultUInt8AndSomeError ret));
+    "#,
+        )
+    }
+
+    #[test]
+    fn extern_rust_async_function_returns_result() {
+        CodegenTest {
+            bridge_module: bridge_module().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Verify that we generate the correct code for extern "Rust" async functions that return a
+/// `Result<(), E>`.
+mod extern_rust_async_function_returns_result_null {
+    use super::*;
+
+    fn bridge_module() -> TokenStream {
+        quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                extern "Rust" {
+                    type SomeError;
+
+                    async fn some_function() -> Result<(), SomeError>;
+                }
+            }
+        }
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsManyAfterTrim(vec![
+            "func some_function() async throws {",
+            r#"
+    func onComplete(cbWrapperPtr: UnsafeMutableRawPointer?, rustFnRetVal: __swift_bridge__$ResultVoidAndSomeError) {
+        let wrapper = Unmanaged<CbWrapper>.fromOpaque(cbWrapperPtr!).takeRetainedValue()
+        wrapper.cb(Result(catching: { try { () throws -> () in let val = rustFnRetVal; if val.tag == __swift_bridge__$ResultVoidAndSomeError$ResultOk { return } else { throw SomeError(ptr: val.payload.err) } }() }))
+    }
+"#,
+            "let continuationGuard = __private__ThrowingContinuationGuard<()>()",
+        ])
+    }
+
+    #[test]
+    fn extern_rust_async_function_returns_result_null() {
+        CodegenTest {
+            bridge_module: bridge_module().into(),
+            expected_rust_tokens: ExpectedRustTokens::SkipTest,
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: ExpectedCHeader::SkipTest,
+        }
+        .test();
+    }
+}
+
+/// Verify that we can annotate that an async method should serve as the `next()` function of an
+/// `AsyncSequence` and `AsyncIteratorProtocol` extension.
+mod extern_rust_async_iterator {
+    use super::*;
+
+    fn bridge_module() -> TokenStream {
+        quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                extern "Rust" {
+                    type SomeStream;
+                    type AnotherStream;
+
+                    #[swift_bridge(AsyncIterator)]
+                    async fn next(self: &mut SomeStream) -> Option<u32>;
+
+                    #[swift_bridge(AsyncIterator)]
+                    async fn next_event(self: &mut AnotherStream) -> Option<u32>;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::Contains(quote! {
+            pub extern "C" fn __swift_bridge__SomeStream_next(
+                callback_wrapper: *mut std::ffi::c_void,
+                callback: extern "C" fn(*mut std::ffi::c_void, swift_bridge::option::OptionU32) -> (),
+                this: *mut super::SomeStream
+            ) {
+                let callback_wrapper = swift_bridge::async_support::SwiftCallbackWrapper(callback_wrapper);
+                let fut = (unsafe { &mut *this }).next();
+                let task = async move {
+                    let val = if let Some(val) = fut.await {
+                        swift_bridge::option::OptionU32 { val, is_some: true }
+                    } else {
+                        swift_bridge::option::OptionU32 { val: 123, is_some: false }
+                    };
+
+                    let callback_wrapper = callback_wrapper;
+                    let callback_wrapper = callback_wrapper.0;
+
+                    (callback)(callback_wrapper, val)
+                };
+                swift_bridge::async_support::ASYNC_RUNTIME.spawn_task(Box::pin(task))
+            }
+        })
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsManyAfterTrim(vec![
+            r#"
+extension SomeStreamRefMut: AsyncSequence, AsyncIteratorProtocol {
+    public func makeAsyncIterator() -> SomeStreamRefMut {
+        self
+    }
+}
+extension SomeStreamRefMut {
+    public func next() async -> Optional<UInt32> {
+        class CbWrapper {
+            var cb: (Result<Optional<UInt32>, Never>) -> ()
+
+            public init(cb: @escaping (Result<Optional<UInt32>, Never>) -> ()) {
+                self.cb = cb
+            }
+        }
+
+        func onComplete(cbWrapperPtr: UnsafeMutableRawPointer?, rustFnRetVal: __private__OptionU32) {
+            let wrapper = Unmanaged<CbWrapper>.fromOpaque(cbWrapperPtr!).takeRetainedValue()
+            wrapper.cb(.success({ let val = rustFnRetVal; if val.is_some { return val.val } else { return nil } }()))
+        }
+"#,
+            r#"
+extension AnotherStreamRefMut: AsyncSequence, AsyncIteratorProtocol {
+    public func makeAsyncIterator() -> AnotherStreamRefMut {
+        self
+    }
+
+    public func next() async -> Optional<UInt32> {
+        return await self.next_event()
+    }
+}
+"#,
+        ])
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsAfterTrim(
+            r#"
+void __swift_bridge__$SomeStream$next(void* callback_wrapper, void __swift_bridge__$SomeStream$next$async(void* callback_wrapper, struct __private__OptionU32 ret), void* self);
+    "#,
+        )
+    }
+
+    #[test]
+    fn extern_rust_async_iterator() {
+        CodegenTest {
+            bridge_module: bridge_module().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
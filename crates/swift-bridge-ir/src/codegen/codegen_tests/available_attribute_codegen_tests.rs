@@ -0,0 +1,112 @@
+use super::{CodegenTest, ExpectedCHeader, ExpectedRustTokens, ExpectedSwiftCode};
+use proc_macro2::TokenStream;
+use quote::quote;
+
+/// Verify that the `#[swift_bridge(available = "...")]` attribute generates an `@available`
+/// attribute right before the generated Swift function.
+mod function_available_attribute {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Rust" {
+                    #[swift_bridge(available = "iOS 15.0")]
+                    fn a();
+
+                    /// Some documentation.
+                    #[swift_bridge(available = "iOS 15.0, macOS 12.0")]
+                    async fn b();
+                }
+            }
+        }
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsManyAfterTrim(vec![
+            r#"
+@available(iOS 15.0, *)
+public func a() {
+    __swift_bridge__$a()
+}
+"#,
+            r#"
+/// Some documentation.
+@available(iOS 15.0, macOS 12.0, *)
+public func b() async {
+"#,
+        ])
+    }
+
+    #[test]
+    fn function_available_attribute() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: ExpectedRustTokens::SkipTest,
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: ExpectedCHeader::SkipTest,
+        }
+        .test();
+    }
+}
+
+/// Verify that the `#[swift_bridge(available = "...")]` attribute on an opaque type annotates the
+/// generated classes and their extensions, and that methods can have their own availability.
+mod opaque_type_available_attribute {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Rust" {
+                    #[swift_bridge(available = "macOS 12.0")]
+                    type SomeType;
+
+                    #[swift_bridge(available = "macOS 13.0")]
+                    fn some_method(&self);
+                }
+            }
+        }
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsManyAfterTrim(vec![
+            r#"
+@available(macOS 12.0, *)
+public class SomeType: SomeTypeRefMut {
+"#,
+            r#"
+@available(macOS 12.0, *)
+public class SomeTypeRefMut: SomeTypeRef {
+"#,
+            r#"
+@available(macOS 12.0, *)
+public class SomeTypeRef {
+"#,
+            r#"
+@available(macOS 12.0, *)
+extension SomeTypeRef {
+    @available(macOS 13.0, *)
+    public func some_method() {
+        __swift_bridge__$SomeType$some_method(ptr)
+    }
+}
+"#,
+            r#"
+@available(macOS 12.0, *)
+extension SomeType: Vectorizable {
+"#,
+        ])
+    }
+
+    #[test]
+    fn opaque_type_available_attribute() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: ExpectedRustTokens::SkipTest,
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: ExpectedCHeader::SkipTest,
+        }
+        .test();
+    }
+}
@@ -0,0 +1,78 @@
+use super::{CodegenTest, ExpectedCHeader, ExpectedRustTokens, ExpectedSwiftCode};
+use proc_macro2::TokenStream;
+use quote::quote;
+
+/// Verify that we can return a `Box<str>` from Rust.
+///
+/// The boxed string is passed to Swift as a pointer and length without a capacity. Swift copies it
+/// into a Swift `String` and then frees the `Box<str>`.
+mod extern_rust_fn_return_boxed_str {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Rust" {
+                    type SomeType;
+
+                    fn some_function() -> Box<str>;
+                    fn name(&self) -> Box<str>;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::ContainsMany(vec![
+            quote! {
+                #[export_name = "__swift_bridge__$some_function"]
+                pub extern "C" fn __swift_bridge__some_function() -> swift_bridge::string::FfiBoxedStr {
+                    swift_bridge::string::FfiBoxedStr::from_boxed_str(super::some_function())
+                }
+            },
+            quote! {
+                #[export_name = "__swift_bridge__$SomeType$name"]
+                pub extern "C" fn __swift_bridge__SomeType_name(
+                    this: *const super::SomeType
+                ) -> swift_bridge::string::FfiBoxedStr {
+                    swift_bridge::string::FfiBoxedStr::from_boxed_str((unsafe { &*this }).name())
+                }
+            },
+        ])
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsManyAfterTrim(vec![
+            r#"
+public func some_function() -> String {
+    __swift_bridge__$some_function().intoString()
+}
+"#,
+            r#"
+extension SomeTypeRef {
+    public func name() -> String {
+        __swift_bridge__$SomeType$name(ptr).intoString()
+    }
+}
+"#,
+        ])
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsManyAfterTrim(vec![
+            "struct __private__FfiBoxedStr __swift_bridge__$some_function(void);",
+            "struct __private__FfiBoxedStr __swift_bridge__$SomeType$name(void* self);",
+        ])
+    }
+
+    #[test]
+    fn extern_rust_fn_return_boxed_str() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
@@ -0,0 +1,114 @@
+//! See also: crates/swift-integration-tests/src/char.rs
+
+use super::{CodegenTest, ExpectedCHeader, ExpectedRustTokens, ExpectedSwiftCode};
+use proc_macro2::TokenStream;
+use quote::quote;
+
+/// Test code generation for Rust function that takes and returns a char.
+mod extern_rust_fn_char_round_trip {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Rust" {
+                    fn some_function(arg: char) -> char;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::Contains(quote! {
+            #[export_name = "__swift_bridge__$some_function"]
+            pub extern "C" fn __swift_bridge__some_function(arg: u32) -> u32 {
+                u32::from(
+                    super::some_function(char::from_u32(arg).expect("Invalid Unicode scalar value"))
+                )
+            }
+        })
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+public func some_function(_ arg: Unicode.Scalar) -> Unicode.Scalar {
+    Unicode.Scalar(__swift_bridge__$some_function(arg.value))!
+}
+"#,
+        )
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsManyAfterTrim(vec![
+            r#"
+uint32_t __swift_bridge__$some_function(uint32_t arg);
+"#,
+            "#include <stdint.h>",
+        ])
+    }
+
+    #[test]
+    fn extern_rust_fn_char_round_trip() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Test code generation for Swift function that takes and returns a char.
+mod extern_swift_fn_char_round_trip {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Swift" {
+                    fn some_function(arg: char) -> char;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::ContainsMany(vec![
+            quote! {
+                pub fn some_function(arg: char) -> char {
+                    char::from_u32(
+                        unsafe { __swift_bridge__some_function(u32::from(arg)) }
+                    ).expect("Invalid Unicode scalar value")
+                }
+            },
+            quote! {
+                #[link_name = "__swift_bridge__$some_function"]
+                fn __swift_bridge__some_function(arg: u32) -> u32;
+            },
+        ])
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+@_cdecl("__swift_bridge__$some_function")
+func __swift_bridge__some_function (_ arg: UInt32) -> UInt32 {
+    some_function(arg: Unicode.Scalar(arg)!).value
+}
+"#,
+        )
+    }
+
+    #[test]
+    fn extern_swift_fn_char_round_trip() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: ExpectedCHeader::SkipTest,
+        }
+        .test();
+    }
+}
@@ -0,0 +1,398 @@
+//! See also: crates/swift-integration-tests/src/closure.rs
+
+use super::{CodegenTest, ExpectedCHeader, ExpectedRustTokens, ExpectedSwiftCode};
+use proc_macro2::TokenStream;
+use quote::quote;
+
+/// Test code generation for Rust function that accepts a closure that takes no arguments.
+mod extern_rust_fn_closure_arg_no_params {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Rust" {
+                    fn some_function(callback: Box<dyn Fn()>);
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::ContainsMany(vec![
+            quote! {
+                #[repr(C)]
+                #[doc(hidden)]
+                pub struct __swift_bridge__BoxedFn {
+                    ctx: *mut std::ffi::c_void,
+                    call: extern "C" fn(*mut std::ffi::c_void),
+                    free: extern "C" fn(*mut std::ffi::c_void),
+                }
+
+                impl __swift_bridge__BoxedFn {
+                    #[doc(hidden)]
+                    #[inline(always)]
+                    pub fn into_rust_repr(self) -> impl Fn() {
+                        move | | self.invoke()
+                    }
+
+                    fn invoke(&self) {
+                        (self.call)(self.ctx)
+                    }
+                }
+
+                impl Drop for __swift_bridge__BoxedFn {
+                    fn drop(&mut self) {
+                        (self.free)(self.ctx)
+                    }
+                }
+            },
+            quote! {
+                #[export_name = "__swift_bridge__$some_function"]
+                pub extern "C" fn __swift_bridge__some_function(callback: __swift_bridge__BoxedFn) {
+                    super::some_function(Box::new(callback.into_rust_repr()))
+                }
+            },
+        ])
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+public func some_function(_ callback: @escaping () -> ()) {
+    __swift_bridge__$some_function(__swift_bridge__$BoxedFn(ctx: Unmanaged.passRetained(__private__SwiftClosureBox<() -> ()>(callback)).toOpaque(), call: { ctx in Unmanaged<__private__SwiftClosureBox<() -> ()>>.fromOpaque(ctx!).takeUnretainedValue().closure() }, free: { ctx in Unmanaged<__private__SwiftClosureBox<() -> ()>>.fromOpaque(ctx!).release() }))
+}
+"#,
+        )
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ExactAfterTrim(
+            r#"
+typedef struct __swift_bridge__$BoxedFn { void* ctx; void (*call)(void*); void (*free)(void*); } __swift_bridge__$BoxedFn;
+void __swift_bridge__$some_function(struct __swift_bridge__$BoxedFn callback);
+"#,
+        )
+    }
+
+    #[test]
+    fn extern_rust_fn_closure_arg_no_params() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Test code generation for Rust function that accepts a closure that takes one primitive
+/// argument.
+mod extern_rust_fn_closure_arg_one_param {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Rust" {
+                    fn some_function(callback: Box<dyn Fn(u32)>);
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::ContainsMany(vec![
+            quote! {
+                pub struct __swift_bridge__BoxedFnUInt32 {
+                    ctx: *mut std::ffi::c_void,
+                    call: extern "C" fn(*mut std::ffi::c_void, u32),
+                    free: extern "C" fn(*mut std::ffi::c_void),
+                }
+
+                impl __swift_bridge__BoxedFnUInt32 {
+                    #[doc(hidden)]
+                    #[inline(always)]
+                    pub fn into_rust_repr(self) -> impl Fn(u32) {
+                        move |arg0: u32| self.invoke(arg0)
+                    }
+
+                    fn invoke(&self, arg0: u32) {
+                        (self.call)(self.ctx, arg0)
+                    }
+                }
+            },
+            quote! {
+                pub extern "C" fn __swift_bridge__some_function(callback: __swift_bridge__BoxedFnUInt32) {
+                    super::some_function(Box::new(callback.into_rust_repr()))
+                }
+            },
+        ])
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+public func some_function(_ callback: @escaping (UInt32) -> ()) {
+    __swift_bridge__$some_function(__swift_bridge__$BoxedFnUInt32(ctx: Unmanaged.passRetained(__private__SwiftClosureBox<(UInt32) -> ()>(callback)).toOpaque(), call: { ctx, arg0 in Unmanaged<__private__SwiftClosureBox<(UInt32) -> ()>>.fromOpaque(ctx!).takeUnretainedValue().closure(arg0) }, free: { ctx in Unmanaged<__private__SwiftClosureBox<(UInt32) -> ()>>.fromOpaque(ctx!).release() }))
+}
+"#,
+        )
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ExactAfterTrim(
+            r#"
+#include <stdint.h>
+typedef struct __swift_bridge__$BoxedFnUInt32 { void* ctx; void (*call)(void*, uint32_t); void (*free)(void*); } __swift_bridge__$BoxedFnUInt32;
+void __swift_bridge__$some_function(struct __swift_bridge__$BoxedFnUInt32 callback);
+"#,
+        )
+    }
+
+    #[test]
+    fn extern_rust_fn_closure_arg_one_param() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Test code generation for Rust functions that accept `FnMut` closures that take multiple
+/// primitive arguments.
+/// Both functions use the same closure signature, so we only declare its FFI representation once.
+mod extern_rust_fn_closure_arg_multiple_params {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Rust" {
+                    fn some_function(callback: Box<dyn FnMut(u8, bool)>);
+                    fn another_function(arg: u8, callback: Box<dyn FnMut(u8, bool)>);
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::ContainsMany(vec![
+            quote! {
+                impl __swift_bridge__BoxedFnUInt8AndBool {
+                    #[doc(hidden)]
+                    #[inline(always)]
+                    pub fn into_rust_repr(self) -> impl Fn(u8, bool) {
+                        move |arg0: u8, arg1: bool| self.invoke(arg0, arg1)
+                    }
+
+                    fn invoke(&self, arg0: u8, arg1: bool) {
+                        (self.call)(self.ctx, arg0, arg1)
+                    }
+                }
+            },
+            quote! {
+                pub extern "C" fn __swift_bridge__another_function(
+                    arg: u8,
+                    callback: __swift_bridge__BoxedFnUInt8AndBool
+                ) {
+                    super::another_function(arg, Box::new(callback.into_rust_repr()))
+                }
+            },
+        ])
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+public func another_function(_ arg: UInt8, _ callback: @escaping (UInt8, Bool) -> ()) {
+    __swift_bridge__$another_function(arg, __swift_bridge__$BoxedFnUInt8AndBool(ctx: Unmanaged.passRetained(__private__SwiftClosureBox<(UInt8, Bool) -> ()>(callback)).toOpaque(), call: { ctx, arg0, arg1 in Unmanaged<__private__SwiftClosureBox<(UInt8, Bool) -> ()>>.fromOpaque(ctx!).takeUnretainedValue().closure(arg0, arg1) }, free: { ctx in Unmanaged<__private__SwiftClosureBox<(UInt8, Bool) -> ()>>.fromOpaque(ctx!).release() }))
+}
+"#,
+        )
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ExactAfterTrim(
+            r#"
+#include <stdint.h>
+#include <stdbool.h>
+typedef struct __swift_bridge__$BoxedFnUInt8AndBool { void* ctx; void (*call)(void*, uint8_t, bool); void (*free)(void*); } __swift_bridge__$BoxedFnUInt8AndBool;
+void __swift_bridge__$some_function(struct __swift_bridge__$BoxedFnUInt8AndBool callback);
+void __swift_bridge__$another_function(uint8_t arg, struct __swift_bridge__$BoxedFnUInt8AndBool callback);
+"#,
+        )
+    }
+
+    #[test]
+    fn extern_rust_fn_closure_arg_multiple_params() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Test code generation for a Rust method that borrows a closure that returns a primitive.
+mod extern_rust_method_borrowed_closure_arg_returns_primitive {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Rust" {
+                    type SomeType;
+
+                    fn map_each(&self, f: &dyn Fn(u32) -> u32);
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::ContainsMany(vec![
+            quote! {
+                #[repr(C)]
+                #[doc(hidden)]
+                pub struct __swift_bridge__BoxedFnUInt32ReturningUInt32 {
+                    ctx: *mut std::ffi::c_void,
+                    call: extern "C" fn(*mut std::ffi::c_void, u32) -> u32,
+                    free: extern "C" fn(*mut std::ffi::c_void),
+                }
+
+                impl __swift_bridge__BoxedFnUInt32ReturningUInt32 {
+                    #[doc(hidden)]
+                    #[inline(always)]
+                    pub fn into_rust_repr(self) -> impl Fn(u32) -> u32 {
+                        move |arg0: u32| self.invoke(arg0)
+                    }
+
+                    fn invoke(&self, arg0: u32) -> u32 {
+                        (self.call)(self.ctx, arg0)
+                    }
+                }
+            },
+            quote! {
+                pub extern "C" fn __swift_bridge__SomeType_map_each(
+                    this: *const super::SomeType,
+                    f: __swift_bridge__BoxedFnUInt32ReturningUInt32
+                ) {
+                    (unsafe { &*this }).map_each(&f.into_rust_repr())
+                }
+            },
+        ])
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+    public func map_each(_ f: @escaping (UInt32) -> UInt32) {
+        __swift_bridge__$SomeType$map_each(ptr, __swift_bridge__$BoxedFnUInt32ReturningUInt32(ctx: Unmanaged.passRetained(__private__SwiftClosureBox<(UInt32) -> UInt32>(f)).toOpaque(), call: { ctx, arg0 in Unmanaged<__private__SwiftClosureBox<(UInt32) -> UInt32>>.fromOpaque(ctx!).takeUnretainedValue().closure(arg0) }, free: { ctx in Unmanaged<__private__SwiftClosureBox<(UInt32) -> UInt32>>.fromOpaque(ctx!).release() }))
+    }
+"#,
+        )
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsManyAfterTrim(vec![
+            r#"typedef struct __swift_bridge__$BoxedFnUInt32ReturningUInt32 { void* ctx; uint32_t (*call)(void*, uint32_t); void (*free)(void*); } __swift_bridge__$BoxedFnUInt32ReturningUInt32;"#,
+            r#"void __swift_bridge__$SomeType$map_each(void* self, struct __swift_bridge__$BoxedFnUInt32ReturningUInt32 f);"#,
+        ])
+    }
+
+    #[test]
+    fn extern_rust_method_borrowed_closure_arg_returns_primitive() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Test code generation for Rust function that accepts a closure that returns a String.
+/// The Swift String gets moved into a RustString that Rust takes ownership of.
+mod extern_rust_fn_closure_arg_returns_string {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Rust" {
+                    fn some_function(callback: Box<dyn Fn(u8) -> String>);
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::ContainsMany(vec![
+            quote! {
+                pub struct __swift_bridge__BoxedFnUInt8ReturningString {
+                    ctx: *mut std::ffi::c_void,
+                    call: extern "C" fn(*mut std::ffi::c_void, u8) -> *mut swift_bridge::string::RustString,
+                    free: extern "C" fn(*mut std::ffi::c_void),
+                }
+
+                impl __swift_bridge__BoxedFnUInt8ReturningString {
+                    #[doc(hidden)]
+                    #[inline(always)]
+                    pub fn into_rust_repr(self) -> impl Fn(u8) -> String {
+                        move |arg0: u8| self.invoke(arg0)
+                    }
+
+                    fn invoke(&self, arg0: u8) -> String {
+                        unsafe { Box::from_raw((self.call)(self.ctx, arg0)).0 }
+                    }
+                }
+            },
+            quote! {
+                pub extern "C" fn __swift_bridge__some_function(
+                    callback: __swift_bridge__BoxedFnUInt8ReturningString
+                ) {
+                    super::some_function(Box::new(callback.into_rust_repr()))
+                }
+            },
+        ])
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+public func some_function(_ callback: @escaping (UInt8) -> String) {
+    __swift_bridge__$some_function(__swift_bridge__$BoxedFnUInt8ReturningString(ctx: Unmanaged.passRetained(__private__SwiftClosureBox<(UInt8) -> String>(callback)).toOpaque(), call: { ctx, arg0 in { let rustString = Unmanaged<__private__SwiftClosureBox<(UInt8) -> String>>.fromOpaque(ctx!).takeUnretainedValue().closure(arg0).intoRustString(); rustString.isOwned = false; return rustString.ptr }() }, free: { ctx in Unmanaged<__private__SwiftClosureBox<(UInt8) -> String>>.fromOpaque(ctx!).release() }))
+}
+"#,
+        )
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ExactAfterTrim(
+            r#"
+#include <stdint.h>
+typedef struct __swift_bridge__$BoxedFnUInt8ReturningString { void* ctx; void* (*call)(void*, uint8_t); void (*free)(void*); } __swift_bridge__$BoxedFnUInt8ReturningString;
+void __swift_bridge__$some_function(struct __swift_bridge__$BoxedFnUInt8ReturningString callback);
+"#,
+        )
+    }
+
+    #[test]
+    fn extern_rust_fn_closure_arg_returns_string() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
@@ -0,0 +1,110 @@
+use super::{CodegenTest, ExpectedCHeader, ExpectedRustTokens, ExpectedSwiftCode};
+use proc_macro2::TokenStream;
+use quote::quote;
+
+/// Verify that we use an opaque Rust type's `Ord` implementation to generate a Swift `Comparable`
+/// conformance when it has the `#[swift_bridge(Comparable)]` attribute.
+mod opaque_rust_type_comparable_attribute {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Rust" {
+                    #[swift_bridge(Comparable)]
+                    type SomeType;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::Contains(quote! {
+            #[export_name = "__swift_bridge__$SomeType$_cmp"]
+            pub extern "C" fn __swift_bridge__SomeType__cmp (
+                lhs: *mut super::SomeType,
+                rhs: *mut super::SomeType
+            ) -> i8 {
+                std::cmp::Ord::cmp(unsafe { &*lhs }, unsafe { &*rhs }) as i8
+            }
+        })
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+extension SomeTypeRef: Comparable {
+    public static func < (lhs: SomeTypeRef, rhs: SomeTypeRef) -> Bool {
+        __swift_bridge__$SomeType$_cmp(lhs.ptr, rhs.ptr) < 0
+    }
+
+    public static func == (lhs: SomeTypeRef, rhs: SomeTypeRef) -> Bool {
+        __swift_bridge__$SomeType$_cmp(lhs.ptr, rhs.ptr) == 0
+    }
+}
+"#,
+        )
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsManyAfterTrim(vec![
+            "#include <stdint.h>",
+            r#"
+void __swift_bridge__$SomeType$_free(void* self);
+int8_t __swift_bridge__$SomeType$_cmp(void* lhs, void* rhs);
+"#,
+        ])
+    }
+
+    #[test]
+    fn opaque_rust_type_comparable_attribute() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Verify that we do not generate a `Comparable` conformance for an opaque Rust type without the
+/// `#[swift_bridge(Comparable)]` attribute.
+mod opaque_rust_type_without_comparable_attribute {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Rust" {
+                    type SomeType;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::DoesNotContain(quote! {
+            __swift_bridge__SomeType__cmp
+        })
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::DoesNotContainAfterTrim("Comparable")
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::DoesNotContainAfterTrim("_cmp")
+    }
+
+    #[test]
+    fn opaque_rust_type_without_comparable_attribute() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
@@ -20,6 +20,7 @@ mod cfg_feature_bridge_module_feature_enabled {
         BridgeModule {
             tokens,
             enabled_crate_features: vec!["some-feature"],
+            symbol_prefix: None,
         }
     }
 
@@ -80,6 +81,7 @@ mod cfg_feature_bridge_module_feature_disabled {
         BridgeModule {
             tokens,
             enabled_crate_features: vec![],
+            symbol_prefix: None,
         }
     }
 
@@ -120,3 +122,147 @@ some_function
         .test();
     }
 }
+
+/// Verify that a function with a disabled `#[cfg(feature = "foo")]` gets its Rust shim gated
+/// behind the feature, and doesn't get any Swift or C code, while the functions next to it do.
+mod cfg_feature_function_feature_disabled {
+    use super::*;
+    use crate::codegen::codegen_tests::BridgeModule;
+
+    fn bridge_module() -> BridgeModule {
+        let tokens = quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                extern "Rust" {
+                    #[cfg(feature = "some-feature")]
+                    fn gated_function();
+
+                    fn ungated_function();
+                }
+
+                extern "Swift" {
+                    #[cfg(feature = "some-feature")]
+                    fn gated_swift_function();
+                }
+            }
+        };
+        BridgeModule {
+            tokens,
+            enabled_crate_features: vec![],
+            symbol_prefix: None,
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::ContainsMany(vec![
+            quote! {
+                #[cfg(feature = "some-feature")]
+                #[export_name = "__swift_bridge__$gated_function"]
+                pub extern "C" fn __swift_bridge__gated_function() {
+                    super::gated_function()
+                }
+
+                #[export_name = "__swift_bridge__$ungated_function"]
+                pub extern "C" fn __swift_bridge__ungated_function() {
+                    super::ungated_function()
+                }
+            },
+            quote! {
+                #[cfg(feature = "some-feature")]
+                pub fn gated_swift_function() {
+                    unsafe { __swift_bridge__gated_swift_function() }
+                }
+            },
+            quote! {
+                #[cfg(feature = "some-feature")]
+                #[link_name = "__swift_bridge__$gated_swift_function"]
+                fn __swift_bridge__gated_swift_function();
+            },
+        ])
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ExactAfterTrim(
+            r#"
+public func ungated_function() {
+    __swift_bridge__$ungated_function()
+}
+"#,
+        )
+    }
+
+    const EXPECTED_C_HEADER: ExpectedCHeader = ExpectedCHeader::ExactAfterTrim(
+        r#"
+void __swift_bridge__$ungated_function(void);
+    "#,
+    );
+
+    #[test]
+    fn cfg_feature_function_feature_disabled() {
+        CodegenTest {
+            bridge_module: bridge_module(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: EXPECTED_C_HEADER,
+        }
+        .test();
+    }
+}
+
+/// Verify that a function with an enabled `#[cfg(feature = "foo")]` gets its Swift and C code.
+mod cfg_feature_function_feature_enabled {
+    use super::*;
+    use crate::codegen::codegen_tests::BridgeModule;
+
+    fn bridge_module() -> BridgeModule {
+        let tokens = quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                extern "Rust" {
+                    #[cfg(feature = "some-feature")]
+                    fn gated_function();
+
+                    fn ungated_function();
+                }
+            }
+        };
+        BridgeModule {
+            tokens,
+            enabled_crate_features: vec!["some-feature"],
+            symbol_prefix: None,
+        }
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsManyAfterTrim(vec![
+            r#"
+public func gated_function() {
+    __swift_bridge__$gated_function()
+}
+"#,
+            r#"
+public func ungated_function() {
+    __swift_bridge__$ungated_function()
+}
+"#,
+        ])
+    }
+
+    const EXPECTED_C_HEADER: ExpectedCHeader = ExpectedCHeader::ExactAfterTrim(
+        r#"
+void __swift_bridge__$gated_function(void);
+void __swift_bridge__$ungated_function(void);
+    "#,
+    );
+
+    #[test]
+    fn cfg_feature_function_feature_enabled() {
+        CodegenTest {
+            bridge_module: bridge_module(),
+            expected_rust_tokens: ExpectedRustTokens::SkipTest,
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: EXPECTED_C_HEADER,
+        }
+        .test();
+    }
+}
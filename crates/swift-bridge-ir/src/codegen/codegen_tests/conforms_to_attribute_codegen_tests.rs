@@ -0,0 +1,44 @@
+use super::{CodegenTest, ExpectedCHeader, ExpectedRustTokens, ExpectedSwiftCode};
+use proc_macro2::TokenStream;
+use quote::quote;
+
+/// Verify that an opaque Rust type with the `#[swift_bridge(conforms_to = (...))]` attribute
+/// gets a Swift class that conforms to the listed protocols.
+mod opaque_rust_type_conforms_to_attribute {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Rust" {
+                    #[swift_bridge(conforms_to = Shape)]
+                    type Circle;
+
+                    #[swift_bridge(conforms_to = (Shape, Drawable))]
+                    type Square;
+
+                    fn area(self: &Circle) -> f64;
+                    fn area(self: &Square) -> f64;
+                }
+            }
+        }
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsManyAfterTrim(vec![
+            "public class Circle: CircleRefMut, Shape {",
+            "public class Square: SquareRefMut, Shape, Drawable {",
+        ])
+    }
+
+    #[test]
+    fn opaque_rust_type_conforms_to_attribute() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: ExpectedRustTokens::SkipTest,
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: ExpectedCHeader::SkipTest,
+        }
+        .test();
+    }
+}
@@ -0,0 +1,177 @@
+use super::{CodegenTest, ExpectedCHeader, ExpectedRustTokens, ExpectedSwiftCode};
+use proc_macro2::TokenStream;
+use quote::quote;
+
+/// Verify that we generate a by-value FFI representation for an opaque Rust type with the
+/// `#[swift_bridge(Copy(N))]` attribute, along with compile time checks for its size and that
+/// it implements `Copy`.
+mod opaque_rust_type_copy_attribute {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Rust" {
+                    #[swift_bridge(Copy(16))]
+                    type SomeType;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::ContainsMany(vec![
+            quote! {
+                #[repr(C)]
+                #[doc(hidden)]
+                pub struct __swift_bridge__SomeType([u8; 16]);
+
+                impl __swift_bridge__SomeType {
+                    #[inline(always)]
+                    fn into_rust_repr(self) -> super::SomeType {
+                        unsafe { std::mem::transmute::<__swift_bridge__SomeType, super::SomeType>(self) }
+                    }
+
+                    #[inline(always)]
+                    fn from_rust_repr(repr: super::SomeType) -> __swift_bridge__SomeType {
+                        unsafe { std::mem::transmute::<super::SomeType, __swift_bridge__SomeType>(repr) }
+                    }
+                }
+            },
+            quote! {
+                const _: () = {
+                    let _: [u8; std::mem::size_of::<super::SomeType>()] = [0; 16];
+
+                    fn assert_copy<T: Copy>() {}
+                    fn _assert_copy() {
+                        assert_copy::<super::SomeType>();
+                    }
+                };
+            },
+        ])
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ExactAfterTrim(
+            r#"
+public struct SomeType {
+    fileprivate var bytes: __swift_bridge__$SomeType
+
+    func intoFfiRepr() -> __swift_bridge__$SomeType {
+        bytes
+    }
+}
+extension __swift_bridge__$SomeType {
+    func intoSwiftRepr() -> SomeType {
+        SomeType(bytes: self)
+    }
+}
+"#,
+        )
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ExactAfterTrim(
+            r#"
+#include <stdint.h>
+typedef struct __swift_bridge__$SomeType { uint8_t bytes[16]; } __swift_bridge__$SomeType;
+"#,
+        )
+    }
+
+    #[test]
+    fn opaque_rust_type_copy_attribute() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Verify that a `Copy` opaque Rust type gets passed to and returned from functions and methods
+/// by value instead of behind a pointer.
+mod opaque_rust_type_copy_attribute_passed_by_value {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Rust" {
+                    #[swift_bridge(Copy(16))]
+                    type SomeType;
+
+                    fn some_method(&self, other: SomeType) -> SomeType;
+                    fn some_function(arg: &SomeType) -> SomeType;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::ContainsMany(vec![
+            quote! {
+                #[export_name = "__swift_bridge__$SomeType$some_method"]
+                pub extern "C" fn __swift_bridge__SomeType_some_method(
+                    this: __swift_bridge__SomeType,
+                    other: __swift_bridge__SomeType
+                ) -> __swift_bridge__SomeType {
+                    __swift_bridge__SomeType::from_rust_repr(
+                        this.into_rust_repr().some_method(other.into_rust_repr())
+                    )
+                }
+            },
+            quote! {
+                #[export_name = "__swift_bridge__$some_function"]
+                pub extern "C" fn __swift_bridge__some_function(
+                    arg: __swift_bridge__SomeType
+                ) -> __swift_bridge__SomeType {
+                    __swift_bridge__SomeType::from_rust_repr(
+                        super::some_function(&arg.into_rust_repr())
+                    )
+                }
+            },
+        ])
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsManyAfterTrim(vec![
+            r#"
+public func some_function(_ arg: SomeType) -> SomeType {
+    __swift_bridge__$some_function(arg.intoFfiRepr()).intoSwiftRepr()
+}
+"#,
+            r#"
+extension SomeType {
+    public func some_method(_ other: SomeType) -> SomeType {
+        __swift_bridge__$SomeType$some_method(self.intoFfiRepr(), other.intoFfiRepr()).intoSwiftRepr()
+    }
+}
+"#,
+        ])
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsManyAfterTrim(vec![
+            r#"
+struct __swift_bridge__$SomeType __swift_bridge__$SomeType$some_method(struct __swift_bridge__$SomeType self, struct __swift_bridge__$SomeType other);
+"#,
+            r#"
+struct __swift_bridge__$SomeType __swift_bridge__$some_function(struct __swift_bridge__$SomeType arg);
+"#,
+        ])
+    }
+
+    #[test]
+    fn opaque_rust_type_copy_attribute_passed_by_value() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
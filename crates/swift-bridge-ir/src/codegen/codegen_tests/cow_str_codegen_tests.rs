@@ -0,0 +1,81 @@
+use super::{CodegenTest, ExpectedCHeader, ExpectedRustTokens, ExpectedSwiftCode};
+use proc_macro2::TokenStream;
+use quote::quote;
+
+/// Verify that we can return a `Cow<str>` from Rust.
+///
+/// Whether the `Cow` is borrowed or owned is decided at runtime by `FfiCowStr::from_cow`, so both
+/// arms share the same generated code. Swift always copies the string into a Swift `String`, and
+/// frees it if it was owned.
+mod extern_rust_fn_return_cow_str {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Rust" {
+                    type SomeType;
+
+                    fn borrowed_or_owned(owned: bool) -> Cow<'static, str>;
+                    fn name(&self) -> Cow<str>;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::ContainsMany(vec![
+            quote! {
+                #[export_name = "__swift_bridge__$borrowed_or_owned"]
+                pub extern "C" fn __swift_bridge__borrowed_or_owned(
+                    owned: bool
+                ) -> swift_bridge::string::FfiCowStr {
+                    swift_bridge::string::FfiCowStr::from_cow(super::borrowed_or_owned(owned))
+                }
+            },
+            quote! {
+                #[export_name = "__swift_bridge__$SomeType$name"]
+                pub extern "C" fn __swift_bridge__SomeType_name(
+                    this: *const super::SomeType
+                ) -> swift_bridge::string::FfiCowStr {
+                    swift_bridge::string::FfiCowStr::from_cow((unsafe { &*this }).name())
+                }
+            },
+        ])
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsManyAfterTrim(vec![
+            r#"
+public func borrowed_or_owned(_ owned: Bool) -> String {
+    __swift_bridge__$borrowed_or_owned(owned).intoString()
+}
+"#,
+            r#"
+extension SomeTypeRef {
+    public func name() -> String {
+        __swift_bridge__$SomeType$name(ptr).intoString()
+    }
+}
+"#,
+        ])
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsManyAfterTrim(vec![
+            "struct __private__FfiCowStr __swift_bridge__$borrowed_or_owned(bool owned);",
+            "struct __private__FfiCowStr __swift_bridge__$SomeType$name(void* self);",
+        ])
+    }
+
+    #[test]
+    fn extern_rust_fn_return_cow_str() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
@@ -0,0 +1,76 @@
+//! Only compiled when the `chrono` feature is enabled.
+
+use super::{CodegenTest, ExpectedCHeader, ExpectedRustTokens, ExpectedSwiftCode};
+use proc_macro2::TokenStream;
+use quote::quote;
+
+/// Test code generation for Rust function that takes and returns a `DateTime<Utc>`.
+mod extern_rust_fn_date_time_round_trip {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Rust" {
+                    fn some_function(arg: DateTime<Utc>) -> chrono::DateTime<chrono::Utc>;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::Contains(quote! {
+            #[export_name = "__swift_bridge__$some_function"]
+            pub extern "C" fn __swift_bridge__some_function(
+                arg: swift_bridge::date_time::FfiDateTime
+            ) -> swift_bridge::date_time::FfiDateTime {
+                {
+                    let date_time = super::some_function({
+                        let date_time = arg;
+                        chrono::TimeZone::timestamp_opt(
+                            &chrono::Utc,
+                            date_time.secs,
+                            date_time.nanos,
+                        )
+                        .unwrap()
+                    });
+                    swift_bridge::date_time::FfiDateTime {
+                        secs: date_time.timestamp(),
+                        nanos: date_time.timestamp_subsec_nanos(),
+                    }
+                }
+            }
+        })
+    }
+
+    /// `Date` comes from Foundation, so the generated code must import it.
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+import Foundation
+public func some_function(_ arg: Date) -> Date {
+    __swift_bridge__$some_function(__private__FfiDateTime.fromDate(arg)).intoDate()
+}
+"#,
+        )
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsAfterTrim(
+            r#"
+struct __private__FfiDateTime __swift_bridge__$some_function(struct __private__FfiDateTime arg);
+"#,
+        )
+    }
+
+    #[test]
+    fn extern_rust_fn_date_time_round_trip() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
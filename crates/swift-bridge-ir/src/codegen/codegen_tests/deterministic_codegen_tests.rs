@@ -0,0 +1,123 @@
+//! Verify that identical bridge modules always produce byte-identical code, so that builds are
+//! reproducible, and that the code follows the order that things were declared in.
+
+use crate::codegen::CodegenConfig;
+use crate::test_utils::parse_ok;
+use proc_macro2::TokenStream;
+use quote::{quote, ToTokens};
+
+fn bridge_module_tokens() -> TokenStream {
+    quote! {
+        #[swift_bridge::bridge]
+        mod ffi {
+            #[swift_bridge(swift_repr = "struct")]
+            struct SomeStruct {
+                field: u8,
+            }
+
+            enum SomeEnum {
+                Variant1,
+                Variant2,
+            }
+
+            extern "Rust" {
+                type SomeType;
+                type AnotherType;
+
+                fn some_function(a: String, b: &str, c: Option<String>, d: Option<&str>);
+                fn slice_function() -> &'static [u32];
+                fn another_slice_function() -> &'static [u8];
+                fn struct_function(arg: SomeStruct) -> SomeEnum;
+                fn method(self: &SomeType, a: &str, b: String) -> u8;
+                fn another_method(self: &AnotherType, a: String, b: &str);
+            }
+
+            extern "Swift" {
+                type SomeSwiftType;
+
+                fn swift_function(arg: u8) -> SomeSwiftType;
+            }
+        }
+    }
+}
+
+/// Verify that generating the same module multiple times always produces the same Rust, Swift
+/// and C header code.
+#[test]
+fn identical_modules_generate_identical_code() {
+    let generate = || {
+        let module = parse_ok(bridge_module_tokens());
+        let config = CodegenConfig::no_features_enabled();
+
+        (
+            module.to_token_stream().to_string(),
+            module.generate_swift(&config),
+            module.generate_c_header(&config),
+        )
+    };
+
+    let (first_rust, first_swift, first_c_header) = generate();
+
+    // Each `HashMap` and `HashSet` gets its own random seed, so generating a few times gives any
+    // hash ordering a chance to leak into the output.
+    for _ in 0..10 {
+        let (rust, swift, c_header) = generate();
+
+        assert_eq!(rust, first_rust);
+        assert_eq!(swift, first_swift);
+        assert_eq!(c_header, first_c_header);
+    }
+}
+
+/// Verify that the generated Swift declares the types and functions of interleaved `extern`
+/// blocks in the order that they appear in the source, regardless of which block they are in.
+#[test]
+fn interleaved_extern_blocks_preserve_source_order() {
+    let tokens = quote! {
+        #[swift_bridge::bridge]
+        mod ffi {
+            extern "Rust" {
+                type TypeA;
+                fn first();
+                type TypeB;
+                type TypeC;
+            }
+
+            extern "Swift" {
+                fn second();
+                type TypeD;
+            }
+
+            extern "Rust" {
+                fn third();
+                type TypeE;
+            }
+        }
+    };
+    let module = parse_ok(tokens);
+    let swift = module.generate_swift(&CodegenConfig::no_features_enabled());
+
+    let positions = |declarations: &[&str]| -> Vec<usize> {
+        declarations
+            .iter()
+            .map(|declaration| swift.find(declaration).unwrap())
+            .collect()
+    };
+    let is_sorted = |positions: &[usize]| positions.windows(2).all(|w| w[0] < w[1]);
+
+    let functions = positions(&[
+        "public func first()",
+        "func __swift_bridge__second ()",
+        "public func third()",
+    ]);
+    assert!(is_sorted(&functions));
+
+    let types = positions(&[
+        "public class TypeA:",
+        "public class TypeB:",
+        "public class TypeC:",
+        "func __swift_bridge__TypeD__free",
+        "public class TypeE:",
+    ]);
+    assert!(is_sorted(&types));
+}
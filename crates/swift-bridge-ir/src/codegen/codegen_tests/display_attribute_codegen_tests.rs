@@ -0,0 +1,106 @@
+use super::{CodegenTest, ExpectedCHeader, ExpectedRustTokens, ExpectedSwiftCode};
+use proc_macro2::TokenStream;
+use quote::quote;
+
+/// Verify that we use an opaque Rust type's `Display` implementation to generate a Swift
+/// `CustomStringConvertible` conformance when it has the `#[swift_bridge(Display)]` attribute.
+mod opaque_rust_type_display_attribute {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Rust" {
+                    #[swift_bridge(Display)]
+                    type SomeType;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::Contains(quote! {
+            #[export_name = "__swift_bridge__$SomeType$_to_string"]
+            pub extern "C" fn __swift_bridge__SomeType__to_string (
+                this: *mut super::SomeType
+            ) -> *mut swift_bridge::string::RustString {
+                swift_bridge::string::RustString(
+                    (unsafe { &*this }).to_string()
+                ).box_into_raw()
+            }
+        })
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+extension SomeTypeRef: CustomStringConvertible {
+    public var description: String {
+        RustString(ptr: __swift_bridge__$SomeType$_to_string(ptr)).toString()
+    }
+}
+"#,
+        )
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsAfterTrim(
+            r#"
+void __swift_bridge__$SomeType$_free(void* self);
+void* __swift_bridge__$SomeType$_to_string(void* self);
+"#,
+        )
+    }
+
+    #[test]
+    fn opaque_rust_type_display_attribute() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Verify that we do not generate a `CustomStringConvertible` conformance for an opaque Rust type
+/// without the `#[swift_bridge(Display)]` attribute.
+mod opaque_rust_type_without_display_attribute {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Rust" {
+                    type SomeType;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::DoesNotContain(quote! {
+            __swift_bridge__SomeType__to_string
+        })
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::DoesNotContainAfterTrim("CustomStringConvertible")
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::DoesNotContainAfterTrim("_to_string")
+    }
+
+    #[test]
+    fn opaque_rust_type_without_display_attribute() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
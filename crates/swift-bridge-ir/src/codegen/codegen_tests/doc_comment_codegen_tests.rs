@@ -0,0 +1,136 @@
+use super::{CodegenTest, ExpectedCHeader, ExpectedRustTokens, ExpectedSwiftCode};
+use proc_macro2::TokenStream;
+use quote::quote;
+
+/// Verify that we copy the doc comment on an opaque Rust type over to the generated Swift class.
+mod opaque_rust_type_doc_comment {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Rust" {
+                    /// Some comment
+                    ///
+                    /// Another line
+                    type SomeType;
+                }
+            }
+        }
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+/// Some comment
+///
+/// Another line
+public class SomeType: SomeTypeRefMut {
+"#,
+        )
+    }
+
+    #[test]
+    fn opaque_rust_type_doc_comment() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: ExpectedRustTokens::SkipTest,
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: ExpectedCHeader::SkipTest,
+        }
+        .test();
+    }
+}
+
+/// Verify that we copy the doc comments on extern Rust functions and methods over to the
+/// generated Swift functions.
+mod extern_rust_fn_doc_comment {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Rust" {
+                    type SomeType;
+
+                    /// Makes a SomeType
+                    #[swift_bridge(init)]
+                    fn new() -> SomeType;
+
+                    /// Some method
+                    fn some_method(&self);
+                }
+
+                extern "Rust" {
+                    /// Some function
+                    fn some_function();
+                }
+            }
+        }
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsManyAfterTrim(vec![
+            r#"
+/// Some function
+public func some_function() {
+"#,
+            r#"
+    /// Makes a SomeType
+    public convenience init() {
+"#,
+            r#"
+    /// Some method
+    public func some_method() {
+"#,
+        ])
+    }
+
+    #[test]
+    fn extern_rust_fn_doc_comment() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: ExpectedRustTokens::SkipTest,
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: ExpectedCHeader::SkipTest,
+        }
+        .test();
+    }
+}
+
+/// Verify that we copy the doc comment on a shared struct over to the generated Swift struct.
+mod shared_struct_doc_comment {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                /// Some comment
+                #[swift_bridge(swift_repr = "struct")]
+                struct SomeStruct {
+                    field: u8,
+                }
+            }
+        }
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+/// Some comment
+public struct SomeStruct {
+"#,
+        )
+    }
+
+    #[test]
+    fn shared_struct_doc_comment() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: ExpectedRustTokens::SkipTest,
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: ExpectedCHeader::SkipTest,
+        }
+        .test();
+    }
+}
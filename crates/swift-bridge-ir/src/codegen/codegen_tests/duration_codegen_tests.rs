@@ -0,0 +1,121 @@
+//! See also: crates/swift-integration-tests/src/duration.rs
+
+use super::{CodegenTest, ExpectedCHeader, ExpectedRustTokens, ExpectedSwiftCode};
+use proc_macro2::TokenStream;
+use quote::quote;
+
+/// Test code generation for Rust function that takes and returns a Duration.
+mod extern_rust_fn_duration_round_trip {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Rust" {
+                    fn some_function(arg: Duration) -> std::time::Duration;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::Contains(quote! {
+            #[export_name = "__swift_bridge__$some_function"]
+            pub extern "C" fn __swift_bridge__some_function(
+                arg: swift_bridge::duration::FfiDuration
+            ) -> swift_bridge::duration::FfiDuration {
+                swift_bridge::duration::FfiDuration::from_duration(
+                    super::some_function(arg.into_duration())
+                )
+            }
+        })
+    }
+
+    /// `TimeInterval` comes from Foundation, so the generated code must import it.
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+import Foundation
+public func some_function(_ arg: TimeInterval) -> TimeInterval {
+    __swift_bridge__$some_function(__private__FfiDuration.fromTimeInterval(arg)).intoTimeInterval()
+}
+"#,
+        )
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsAfterTrim(
+            r#"
+struct __private__FfiDuration __swift_bridge__$some_function(struct __private__FfiDuration arg);
+"#,
+        )
+    }
+
+    #[test]
+    fn extern_rust_fn_duration_round_trip() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Test code generation for Swift function that takes and returns a Duration.
+mod extern_swift_fn_duration_round_trip {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Swift" {
+                    fn some_function(arg: Duration) -> Duration;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::ContainsMany(vec![
+            quote! {
+                pub fn some_function(arg: std::time::Duration) -> std::time::Duration {
+                    unsafe {
+                        __swift_bridge__some_function(
+                            swift_bridge::duration::FfiDuration::from_duration(arg)
+                        )
+                    }.into_duration()
+                }
+            },
+            quote! {
+                #[link_name = "__swift_bridge__$some_function"]
+                fn __swift_bridge__some_function(
+                    arg: swift_bridge::duration::FfiDuration
+                ) -> swift_bridge::duration::FfiDuration;
+            },
+        ])
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+@_cdecl("__swift_bridge__$some_function")
+func __swift_bridge__some_function (_ arg: __private__FfiDuration) -> __private__FfiDuration {
+    __private__FfiDuration.fromTimeInterval(some_function(arg: arg.intoTimeInterval()))
+}
+"#,
+        )
+    }
+
+    #[test]
+    fn extern_swift_fn_duration_round_trip() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: ExpectedCHeader::SkipTest,
+        }
+        .test();
+    }
+}
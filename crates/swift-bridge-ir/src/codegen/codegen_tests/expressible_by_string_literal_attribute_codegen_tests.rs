@@ -0,0 +1,105 @@
+use super::{CodegenTest, ExpectedCHeader, ExpectedRustTokens, ExpectedSwiftCode};
+use proc_macro2::TokenStream;
+use quote::quote;
+
+/// Verify that an opaque Rust type with the `#[swift_bridge(ExpressibleByStringLiteral)]` attribute
+/// conforms to `ExpressibleByStringLiteral` by calling its initializer that takes a `String`.
+mod opaque_rust_type_expressible_by_string_literal_attribute {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Rust" {
+                    #[swift_bridge(ExpressibleByStringLiteral)]
+                    type SomeType;
+
+                    #[swift_bridge(init)]
+                    fn new(text: String) -> SomeType;
+                }
+            }
+        }
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+public class SomeType: SomeTypeRefMut, ExpressibleByStringLiteral {
+    var isOwned: Bool = true
+
+    public override init(ptr: UnsafeMutableRawPointer) {
+        super.init(ptr: ptr)
+    }
+
+    public required convenience init(stringLiteral value: String) {
+        self.init(value)
+    }
+
+    public required convenience init(extendedGraphemeClusterLiteral value: String) {
+        self.init(stringLiteral: value)
+    }
+
+    public required convenience init(unicodeScalarLiteral value: String) {
+        self.init(stringLiteral: value)
+    }
+
+    deinit {
+"#,
+        )
+    }
+
+    #[test]
+    fn opaque_rust_type_expressible_by_string_literal_attribute() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: ExpectedRustTokens::SkipTest,
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: ExpectedCHeader::SkipTest,
+        }
+        .test();
+    }
+}
+
+/// Verify that the `ExpressibleByStringLiteral` conformance passes the string literal using the
+/// label of an initializer that takes a `&str`.
+mod opaque_rust_type_expressible_by_string_literal_labeled_str_init {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Rust" {
+                    #[swift_bridge(ExpressibleByStringLiteral)]
+                    type SomeType;
+
+                    #[swift_bridge(init)]
+                    fn new(val: u8) -> SomeType;
+
+                    #[swift_bridge(init)]
+                    fn from_text(#[swift_bridge(label = "text")] text: &str) -> SomeType;
+                }
+            }
+        }
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+    public required convenience init(stringLiteral value: String) {
+        self.init(text: value)
+    }
+"#,
+        )
+    }
+
+    #[test]
+    fn opaque_rust_type_expressible_by_string_literal_labeled_str_init() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: ExpectedRustTokens::SkipTest,
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: ExpectedCHeader::SkipTest,
+        }
+        .test();
+    }
+}
@@ -107,3 +107,456 @@ extension SomeTypeRef {
         .test();
     }
 }
+
+/// Verify that extern "Rust" associated functions that do not take `self` become static methods
+/// on the owned Swift class, and that their C symbols are still namespaced under the type.
+mod extern_rust_static_method_swift_class_placement {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Rust" {
+                    type SomeType;
+
+                    #[swift_bridge(associated_to = SomeType)]
+                    fn a() -> SomeType;
+
+                    #[swift_bridge(associated_to = SomeType)]
+                    fn b(arg: u8) -> u8;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::ContainsMany(vec![
+            quote! {
+                #[export_name = "__swift_bridge__$SomeType$a"]
+                pub extern "C" fn __swift_bridge__SomeType_a() -> *mut super::SomeType {
+                    Box::into_raw(Box::new(super::SomeType::a())) as *mut super::SomeType
+                }
+            },
+            quote! {
+                #[export_name = "__swift_bridge__$SomeType$b"]
+                pub extern "C" fn __swift_bridge__SomeType_b(arg: u8) -> u8 {
+                    super::SomeType::b(arg)
+                }
+            },
+        ])
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+public class SomeType: SomeTypeRefMut {
+    var isOwned: Bool = true
+
+    public override init(ptr: UnsafeMutableRawPointer) {
+        super.init(ptr: ptr)
+    }
+
+    deinit {
+        if isOwned {
+            __swift_bridge__$SomeType$_free(ptr)
+        }
+    }
+}
+extension SomeType {
+    public static func a() -> SomeType {
+        SomeType(ptr: __swift_bridge__$SomeType$a())
+    }
+
+    public static func b(_ arg: UInt8) -> UInt8 {
+        __swift_bridge__$SomeType$b(arg)
+    }
+}
+public class SomeTypeRefMut: SomeTypeRef {
+"#,
+        )
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsAfterTrim(
+            r#"
+void* __swift_bridge__$SomeType$a(void);
+uint8_t __swift_bridge__$SomeType$b(uint8_t arg);
+"#,
+        )
+    }
+
+    #[test]
+    fn extern_rust_static_method_swift_class_placement() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Verify that extern "Rust" associated functions with the `constant` attribute become
+/// `static let` constants on the owned Swift class.
+mod extern_rust_constant_swift_class_placement {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Rust" {
+                    type SomeType;
+
+                    #[swift_bridge(associated_to = SomeType, constant, swift_name = "MAX")]
+                    fn max() -> u32;
+
+                    #[swift_bridge(associated_to = SomeType, constant)]
+                    fn name() -> String;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::ContainsMany(vec![
+            quote! {
+                #[export_name = "__swift_bridge__$SomeType$max"]
+                pub extern "C" fn __swift_bridge__SomeType_max() -> u32 {
+                    super::SomeType::max()
+                }
+            },
+            quote! {
+                #[export_name = "__swift_bridge__$SomeType$name"]
+                pub extern "C" fn __swift_bridge__SomeType_name() -> *mut swift_bridge::string::RustString {
+                    swift_bridge::string::RustString(super::SomeType::name()).box_into_raw()
+                }
+            },
+        ])
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+extension SomeType {
+    public static let MAX: UInt32 = __swift_bridge__$SomeType$max()
+
+    public static let name: String = RustString(ptr: __swift_bridge__$SomeType$name()).toString()
+}
+"#,
+        )
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsAfterTrim(
+            r#"
+uint32_t __swift_bridge__$SomeType$max(void);
+void* __swift_bridge__$SomeType$name(void);
+"#,
+        )
+    }
+
+    #[test]
+    fn extern_rust_constant_swift_class_placement() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Verify that a type can have multiple initializers with the same shape, as long as their
+/// arguments are given different Swift labels, and that each initializer keeps its own symbol.
+mod extern_rust_labeled_initializers_swift_class_placement {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Rust" {
+                    type SomeType;
+
+                    #[swift_bridge(init)]
+                    fn from_name(#[swift_bridge(label = "fromName")] name: String) -> SomeType;
+
+                    #[swift_bridge(init)]
+                    fn from_id(#[swift_bridge(label = "fromId")] id: u64) -> SomeType;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::ContainsMany(vec![
+            quote! {
+                #[export_name = "__swift_bridge__$SomeType$from_name"]
+                pub extern "C" fn __swift_bridge__SomeType_from_name(
+                    name: swift_bridge::string::FfiString
+                ) -> *mut super::SomeType {
+                    Box::into_raw(Box::new(super::SomeType::from_name(name.into_string()))) as *mut super::SomeType
+                }
+            },
+            quote! {
+                #[export_name = "__swift_bridge__$SomeType$from_id"]
+                pub extern "C" fn __swift_bridge__SomeType_from_id(id: u64) -> *mut super::SomeType {
+                    Box::into_raw(Box::new(super::SomeType::from_id(id))) as *mut super::SomeType
+                }
+            },
+        ])
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+extension SomeType {
+    public convenience init<GenericIntoRustString: IntoRustString>(fromName name: GenericIntoRustString) {
+        self.init(ptr: name.toFfiString({ nameAsFfiString in
+            __swift_bridge__$SomeType$from_name(nameAsFfiString)
+        }))
+    }
+
+    public convenience init(fromId id: UInt64) {
+        self.init(ptr: __swift_bridge__$SomeType$from_id(id))
+    }
+}
+"#,
+        )
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsManyAfterTrim(vec![
+            r#"void* __swift_bridge__$SomeType$from_name(struct __private__FfiString name);"#,
+            r#"void* __swift_bridge__$SomeType$from_id(uint64_t id);"#,
+        ])
+    }
+
+    #[test]
+    fn extern_rust_labeled_initializers_swift_class_placement() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Verify that an initializer that returns `Option<SomeType>` becomes a failable `init?` on the
+/// owned Swift class.
+///
+/// Rust returns a null pointer for `None`, so Swift returns `nil` without having anything to free.
+mod extern_rust_failable_initializer_swift_class_placement {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Rust" {
+                    type SomeType;
+
+                    #[swift_bridge(init)]
+                    fn try_new(val: u8) -> Option<SomeType>;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::Contains(quote! {
+            #[export_name = "__swift_bridge__$SomeType$try_new"]
+            pub extern "C" fn __swift_bridge__SomeType_try_new(val: u8) -> *mut super::SomeType {
+                if let Some(val) = super::SomeType::try_new(val) {
+                    Box::into_raw(Box::new(val))
+                } else {
+                    std::ptr::null_mut()
+                }
+            }
+        })
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+extension SomeType {
+    public convenience init?(_ val: UInt8) {
+        guard let ptr = __swift_bridge__$SomeType$try_new(val) else { return nil }
+        self.init(ptr: ptr)
+    }
+}
+"#,
+        )
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsAfterTrim(
+            r#"void* __swift_bridge__$SomeType$try_new(uint8_t val);"#,
+        )
+    }
+
+    #[test]
+    fn extern_rust_failable_initializer_swift_class_placement() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Verify that associated functions that return `Self` become static methods and initializers on
+/// the owned Swift class, and that Swift takes ownership of the returned pointer.
+mod extern_rust_self_returning_associated_function_swift_class_placement {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Rust" {
+                    type SomeType;
+
+                    #[swift_bridge(init)]
+                    fn new() -> Self;
+
+                    fn with_capacity(capacity: usize) -> Self;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::ContainsMany(vec![
+            quote! {
+                #[export_name = "__swift_bridge__$SomeType$new"]
+                pub extern "C" fn __swift_bridge__SomeType_new() -> *mut super::SomeType {
+                    Box::into_raw(Box::new(super::SomeType::new())) as *mut super::SomeType
+                }
+            },
+            quote! {
+                #[export_name = "__swift_bridge__$SomeType$with_capacity"]
+                pub extern "C" fn __swift_bridge__SomeType_with_capacity(
+                    capacity: usize
+                ) -> *mut super::SomeType {
+                    Box::into_raw(Box::new(super::SomeType::with_capacity(capacity))) as *mut super::SomeType
+                }
+            },
+        ])
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+extension SomeType {
+    public convenience init() {
+        self.init(ptr: __swift_bridge__$SomeType$new())
+    }
+}
+extension SomeType {
+    public static func with_capacity(_ capacity: UInt) -> SomeType {
+        SomeType(ptr: __swift_bridge__$SomeType$with_capacity(capacity))
+    }
+}
+"#,
+        )
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsAfterTrim(
+            r#"
+void* __swift_bridge__$SomeType$new(void);
+void* __swift_bridge__$SomeType$with_capacity(uintptr_t capacity);
+"#,
+        )
+    }
+
+    #[test]
+    fn extern_rust_self_returning_associated_function_swift_class_placement() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Verify that a `&mut self` method gets a different Rust shim than a `&self` method.
+///
+/// `&self` methods receive a `*const` pointer and borrow it immutably, while `&mut self` methods
+/// receive a `*mut` pointer and borrow it mutably.
+/// Swift makes sure that a `&mut self` method isn't also passed `self` as another argument.
+mod extern_rust_ref_mut_self_method_shim {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Rust" {
+                    type SomeType;
+
+                    fn read(&self) -> u8;
+                    fn update(&mut self, other: &SomeType);
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::ContainsMany(vec![
+            quote! {
+                #[export_name = "__swift_bridge__$SomeType$read"]
+                pub extern "C" fn __swift_bridge__SomeType_read(
+                    this: *const super::SomeType
+                ) -> u8 {
+                    (unsafe { &*this }).read()
+                }
+            },
+            quote! {
+                #[export_name = "__swift_bridge__$SomeType$update"]
+                pub extern "C" fn __swift_bridge__SomeType_update(
+                    this: *mut super::SomeType,
+                    other: *const super::SomeType
+                ) {
+                    (unsafe { &mut *this }).update(unsafe { &*other })
+                }
+            },
+        ])
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsManyAfterTrim(vec![
+            r#"
+extension SomeTypeRefMut {
+    public func update(_ other: SomeTypeRef) {
+        precondition(other.ptr != ptr, "`update` requires exclusive access to `self`, but `other` is the same value")
+        __swift_bridge__$SomeType$update(ptr, other.ptr)
+    }
+}
+"#,
+            r#"
+extension SomeTypeRef {
+    public func read() -> UInt8 {
+        __swift_bridge__$SomeType$read(ptr)
+    }
+}
+"#,
+        ])
+    }
+
+    #[test]
+    fn extern_rust_ref_mut_self_method_shim() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: ExpectedCHeader::SkipTest,
+        }
+        .test();
+    }
+}
@@ -111,6 +111,59 @@ mod into_return_type_attribute_for_shared_struct {
     }
 }
 
+/// Verify that the `#[swift_bridge(return_into)]` attribute converts the returned value using a
+/// type checked `.into()` call, so that the function's native return type can differ from the
+/// bridged return type.
+mod return_into_attribute {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                extern "Rust" {
+                    type PublicType;
+
+                    #[swift_bridge(return_into)]
+                    fn some_function() -> PublicType;
+
+                    #[swift_bridge(return_into)]
+                    fn another_function() -> u64;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::ContainsMany(vec![
+            quote! {
+                pub extern "C" fn __swift_bridge__some_function() -> *mut super::PublicType {
+                    Box::into_raw(Box::new({
+                        let val: super::PublicType = super::some_function().into();
+                        val
+                    })) as *mut super::PublicType
+                }
+            },
+            quote! {
+                pub extern "C" fn __swift_bridge__another_function() -> u64 {
+                    { let val: u64 = super::another_function().into(); val }
+                }
+            },
+        ])
+    }
+
+    #[test]
+    fn return_into_attribute() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: ExpectedSwiftCode::SkipTest,
+            expected_c_header: ExpectedCHeader::SkipTest,
+        }
+        .test();
+    }
+}
+
 /// Verify that we can use `return_with` to convert a return type.
 mod return_with {
     use super::*;
@@ -219,3 +272,764 @@ extension AnotherTypeRef: Identifiable {}"#,
         .test();
     }
 }
+
+/// Verify that we can annotate that a function should serve as the `next()` function of a
+/// `Sequence` and `IteratorProtocol` extension.
+mod protocol_iterator {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                extern "Rust" {
+                    type SomeIterator;
+                    type AnotherIterator;
+                    type SomeItem;
+
+                    #[swift_bridge(Iterator)]
+                    fn next(self: &mut SomeIterator) -> Option<u32>;
+
+                    #[swift_bridge(Iterator)]
+                    fn next_item(self: &mut AnotherIterator) -> Option<SomeItem>;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::SkipTest
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsManyAfterTrim(vec![
+            r#"
+public class SomeIteratorRefMut: SomeIteratorRef {
+    public override init(ptr: UnsafeMutableRawPointer) {
+        super.init(ptr: ptr)
+    }
+}
+extension SomeIteratorRefMut: Sequence, IteratorProtocol {}
+extension SomeIteratorRefMut {
+    public func next() -> Optional<UInt32> {
+        { let val = __swift_bridge__$SomeIterator$next(ptr); if val.is_some { return val.val } else { return nil } }()
+    }
+}"#,
+            r#"
+public class AnotherIteratorRefMut: AnotherIteratorRef {
+    public override init(ptr: UnsafeMutableRawPointer) {
+        super.init(ptr: ptr)
+    }
+}
+extension AnotherIteratorRefMut: Sequence, IteratorProtocol {
+    public func next() -> Optional<SomeItem> {
+        return self.next_item()
+    }
+}"#,
+        ])
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::SkipTest
+    }
+
+    #[test]
+    fn protocol_iterator() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Verify that we use the `#[swift_bridge(swift_name = "...")]` attribute as the name of the
+/// generated Swift function, while the linked symbol is still derived from the Rust name.
+mod extern_rust_fn_swift_name {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Rust" {
+                    type SomeType;
+
+                    #[swift_bridge(swift_name = "someMethod")]
+                    fn some_method(&self, arg: u8);
+                }
+
+                extern "Rust" {
+                    #[swift_bridge(swift_name = "fetchUser")]
+                    fn fetch_user() -> u32;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::ContainsMany(vec![
+            quote! {
+                #[export_name = "__swift_bridge__$fetch_user"]
+                pub extern "C" fn __swift_bridge__fetch_user() -> u32 {
+                    super::fetch_user()
+                }
+            },
+            quote! {
+                #[export_name = "__swift_bridge__$SomeType$some_method"]
+                pub extern "C" fn __swift_bridge__SomeType_some_method(
+                    this: *const super::SomeType,
+                    arg: u8
+                ) {
+                    (unsafe { &*this }).some_method(arg)
+                }
+            },
+        ])
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsManyAfterTrim(vec![
+            r#"
+public func fetchUser() -> UInt32 {
+    __swift_bridge__$fetch_user()
+}
+"#,
+            r#"
+    public func someMethod(_ arg: UInt8) {
+        __swift_bridge__$SomeType$some_method(ptr, arg)
+    }
+"#,
+        ])
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsManyAfterTrim(vec![
+            "uint32_t __swift_bridge__$fetch_user(void);",
+            "void __swift_bridge__$SomeType$some_method(void* self, uint8_t arg);",
+        ])
+    }
+
+    #[test]
+    fn extern_rust_fn_swift_name() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Verify that we declare the `#[swift_bridge(default = ...)]` values of function arguments on
+/// the generated Swift function, while the Rust function and the C symbol are unchanged.
+mod function_arg_default_value {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Rust" {
+                    fn some_function(
+                        #[swift_bridge(default = 5)] int_arg: i32,
+                        #[swift_bridge(default = 1.5)] float_arg: f64,
+                        #[swift_bridge(default = true)] bool_arg: bool,
+                        no_default_arg: u8,
+                    );
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::Contains(quote! {
+            #[export_name = "__swift_bridge__$some_function"]
+            pub extern "C" fn __swift_bridge__some_function(
+                int_arg: i32,
+                float_arg: f64,
+                bool_arg: bool,
+                no_default_arg: u8
+            ) {
+                super::some_function(int_arg, float_arg, bool_arg, no_default_arg)
+            }
+        })
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+public func some_function(_ int_arg: Int32 = 5, _ float_arg: Double = 1.5, _ bool_arg: Bool = true, _ no_default_arg: UInt8) {
+    __swift_bridge__$some_function(int_arg, float_arg, bool_arg, no_default_arg)
+}
+"#,
+        )
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsAfterTrim(
+            r#"
+void __swift_bridge__$some_function(int32_t int_arg, double float_arg, bool bool_arg, uint8_t no_default_arg);
+"#,
+        )
+    }
+
+    #[test]
+    fn function_arg_default_value() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Verify that we can use a string literal as the default value of a string argument.
+mod function_arg_default_string_value {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Rust" {
+                    type SomeType;
+
+                    fn some_method(&self, #[swift_bridge(default = "hello \"world\"")] arg: &str);
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::Contains(quote! {
+            pub extern "C" fn __swift_bridge__SomeType_some_method(
+                this: *const super::SomeType,
+                arg: swift_bridge::string::RustStr
+            )
+        })
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+    public func some_method<GenericToRustStr: ToRustStr>(_ arg: GenericToRustStr = "hello \"world\"") {
+"#,
+        )
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::SkipTest
+    }
+
+    #[test]
+    fn function_arg_default_string_value() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Verify that we use the `#[swift_bridge(label = "...")]` attribute as the external label of
+/// the generated Swift function's argument, while the internal parameter name and the C symbol
+/// are unchanged.
+mod function_arg_label {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Rust" {
+                    fn move_to(#[swift_bridge(label = "to")] point: u32, speed: u8);
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::Contains(quote! {
+            pub extern "C" fn __swift_bridge__move_to(point: u32, speed: u8) {
+                super::move_to(point, speed)
+            }
+        })
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+public func move_to(to point: UInt32, _ speed: UInt8) {
+    __swift_bridge__$move_to(point, speed)
+}
+"#,
+        )
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsAfterTrim(
+            r#"
+void __swift_bridge__$move_to(uint32_t point, uint8_t speed);
+"#,
+        )
+    }
+
+    #[test]
+    fn function_arg_label() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Verify that we call an extern "Swift" function using the labels from the
+/// `#[swift_bridge(label = "...")]` attribute, and that a label of `_` suppresses the label.
+mod extern_swift_fn_arg_label {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Swift" {
+                    fn move_to(
+                        #[swift_bridge(label = "to")] point: u32,
+                        #[swift_bridge(label = "_")] speed: u8,
+                        duration: u16,
+                    );
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::Contains(quote! {
+            pub fn move_to(point: u32, speed: u8, duration: u16) {
+                unsafe { __swift_bridge__move_to(point, speed, duration) }
+            }
+        })
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+@_cdecl("__swift_bridge__$move_to")
+func __swift_bridge__move_to (_ point: UInt32, _ speed: UInt8, _ duration: UInt16) {
+    move_to(to: point, speed, duration: duration)
+}
+"#,
+        )
+    }
+
+    #[test]
+    fn extern_swift_fn_arg_label() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: ExpectedCHeader::SkipTest,
+        }
+        .test();
+    }
+}
+
+/// Verify that a `#[swift_bridge(get(clone))]` getter clones the field into an owned `String`,
+/// while a `#[swift_bridge(get)]` getter borrows the field or copies it.
+mod function_attribute_get_clone {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Rust" {
+                    type SomeType;
+
+                    #[swift_bridge(get(clone))]
+                    fn name(&self) -> String;
+
+                    #[swift_bridge(get)]
+                    fn nickname(&self) -> &str;
+
+                    #[swift_bridge(get)]
+                    fn age(&self) -> u8;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::ContainsMany(vec![
+            quote! {
+                pub extern "C" fn __swift_bridge__SomeType_name(
+                    this: *const super::SomeType
+                ) -> *mut swift_bridge::string::RustString {
+                    swift_bridge::string::RustString((unsafe { &*this }).name.clone()).box_into_raw()
+                }
+            },
+            quote! {
+                pub extern "C" fn __swift_bridge__SomeType_nickname(
+                    this: *const super::SomeType
+                ) -> swift_bridge::string::RustStr {
+                    swift_bridge::string::RustStr::from_str(&(unsafe { &*this }).nickname)
+                }
+            },
+            quote! {
+                pub extern "C" fn __swift_bridge__SomeType_age(
+                    this: *const super::SomeType
+                ) -> u8 {
+                    (unsafe { &*this }).age
+                }
+            },
+        ])
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+    public func name() -> RustString {
+        RustString(ptr: __swift_bridge__$SomeType$name(ptr))
+    }
+"#,
+        )
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsAfterTrim(
+            r#"
+void* __swift_bridge__$SomeType$name(void* self);
+"#,
+        )
+    }
+
+    #[test]
+    fn function_attribute_get_clone() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Verify that a `#[swift_bridge(get(computed))]` method becomes a Swift computed property that
+/// calls the Rust method, named after the method or its `swift_name`.
+mod function_attribute_get_computed {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Rust" {
+                    type SomeList;
+
+                    #[swift_bridge(get(computed))]
+                    fn is_empty(&self) -> bool;
+
+                    #[swift_bridge(get(computed), swift_name = "count")]
+                    fn len(&self) -> usize;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::ContainsMany(vec![
+            quote! {
+                pub extern "C" fn __swift_bridge__SomeList_is_empty(
+                    this: *const super::SomeList
+                ) -> bool {
+                    (unsafe { &*this }).is_empty()
+                }
+            },
+            quote! {
+                pub extern "C" fn __swift_bridge__SomeList_len(
+                    this: *const super::SomeList
+                ) -> usize {
+                    (unsafe { &*this }).len()
+                }
+            },
+        ])
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+extension SomeListRef {
+    public var isEmpty: Bool {
+        __swift_bridge__$SomeList$is_empty(ptr)
+    }
+
+    public var count: UInt {
+        __swift_bridge__$SomeList$len(ptr)
+    }
+}
+"#,
+        )
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsManyAfterTrim(vec![
+            "bool __swift_bridge__$SomeList$is_empty(void* self);",
+            "uintptr_t __swift_bridge__$SomeList$len(void* self);",
+        ])
+    }
+
+    #[test]
+    fn function_attribute_get_computed() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Verify that methods with the `#[swift_bridge(operator = "...")]` attribute become static
+/// operator functions on the Swift class, where the receiver is the left hand side.
+mod function_attribute_operator {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Rust" {
+                    type Vector3;
+
+                    #[swift_bridge(operator = "+")]
+                    fn add(&self, rhs: &Vector3) -> Vector3;
+
+                    #[swift_bridge(operator = "*")]
+                    fn mul(&self, rhs: f32) -> Vector3;
+
+                    #[swift_bridge(operator = "==")]
+                    fn eq(&self, rhs: &Vector3) -> bool;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::Contains(quote! {
+            #[export_name = "__swift_bridge__$Vector3$add"]
+            pub extern "C" fn __swift_bridge__Vector3_add(
+                this: *const super::Vector3,
+                rhs: *const super::Vector3
+            ) -> *mut super::Vector3 {
+                Box::into_raw(Box::new((unsafe { &*this }).add(unsafe { &*rhs }))) as *mut super::Vector3
+            }
+        })
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+extension Vector3Ref {
+    public static func + (_ lhs: Vector3Ref, _ rhs: Vector3Ref) -> Vector3 {
+        Vector3(ptr: __swift_bridge__$Vector3$add(lhs.ptr, rhs.ptr))
+    }
+
+    public static func * (_ lhs: Vector3Ref, _ rhs: Float) -> Vector3 {
+        Vector3(ptr: __swift_bridge__$Vector3$mul(lhs.ptr, rhs))
+    }
+
+    public static func == (_ lhs: Vector3Ref, _ rhs: Vector3Ref) -> Bool {
+        __swift_bridge__$Vector3$eq(lhs.ptr, rhs.ptr)
+    }
+}
+"#,
+        )
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsAfterTrim(
+            r#"
+void* __swift_bridge__$Vector3$add(void* self, void* rhs);
+"#,
+        )
+    }
+
+    #[test]
+    fn function_attribute_operator() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Verify that methods with the `#[swift_bridge(subscript)]` attribute become read-only Swift
+/// subscripts, while the Rust function and the C symbol are unchanged.
+mod function_attribute_subscript {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Rust" {
+                    type SomeList;
+
+                    #[swift_bridge(subscript)]
+                    fn get(&self, index: usize) -> u32;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::Contains(quote! {
+            #[export_name = "__swift_bridge__$SomeList$get"]
+            pub extern "C" fn __swift_bridge__SomeList_get(
+                this: *const super::SomeList,
+                index: usize
+            ) -> u32 {
+                (unsafe { &*this }).get(index)
+            }
+        })
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+extension SomeListRef {
+    public subscript(_ index: UInt) -> UInt32 {
+        __swift_bridge__$SomeList$get(ptr, index)
+    }
+}
+"#,
+        )
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsAfterTrim(
+            r#"
+uint32_t __swift_bridge__$SomeList$get(void* self, uintptr_t index);
+"#,
+        )
+    }
+
+    #[test]
+    fn function_attribute_subscript() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Verify that an extern "Swift" function with the `#[swift_bridge(extern_swift_name = "...")]`
+/// attribute links to the given symbol, and that we do not generate a Swift function for it.
+mod extern_swift_fn_extern_swift_name {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Swift" {
+                    #[swift_bridge(extern_swift_name = "my_swift_function")]
+                    fn some_function(arg: u8) -> u32;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::ContainsMany(vec![
+            quote! {
+                pub fn some_function(arg: u8) -> u32 {
+                    unsafe { __swift_bridge__some_function(arg) }
+                }
+            },
+            quote! {
+                extern "C" {
+                    #[link_name = "my_swift_function"]
+                    fn __swift_bridge__some_function(arg: u8) -> u32;
+                }
+            },
+        ])
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::DoesNotContainManyAfterTrim(vec![
+            "@_cdecl",
+            "func __swift_bridge__some_function",
+        ])
+    }
+
+    #[test]
+    fn extern_swift_fn_extern_swift_name() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: ExpectedCHeader::ExactAfterTrim(""),
+        }
+        .test();
+    }
+}
+
+/// Verify that we do not generate any code for a function or a type with the
+/// `#[swift_bridge(skip)]` attribute.
+mod function_attribute_skip {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Rust" {
+                    #[swift_bridge(skip)]
+                    type SomeType;
+
+                    #[swift_bridge(skip)]
+                    fn some_function(arg: &SomeType) -> u8;
+                }
+
+                extern "Swift" {
+                    #[swift_bridge(skip)]
+                    fn another_function();
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::Exact(quote! {
+            #[allow(non_snake_case)]
+            mod ffi {}
+        })
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ExactAfterTrim("")
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ExactAfterTrim("")
+    }
+
+    #[test]
+    fn function_attribute_skip() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
@@ -0,0 +1,244 @@
+use super::{CodegenTest, ExpectedCHeader, ExpectedRustTokens, ExpectedSwiftCode};
+use proc_macro2::TokenStream;
+use quote::quote;
+
+/// Verify that we generate a distinct set of FFI functions and a distinct Swift class for every
+/// instantiation of a generic opaque Rust type.
+mod generic_opaque_rust_type_instantiations {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Rust" {
+                    type MyWrapper<T>;
+
+                    fn new_u32_wrapper() -> MyWrapper<u32>;
+                    fn new_bool_wrapper() -> MyWrapper<bool>;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::ContainsMany(vec![
+            quote! {
+                #[export_name = "__swift_bridge__$MyWrapper$u32$_free"]
+                pub extern "C" fn __swift_bridge__MyWrapper_u32__free (
+                    this: *mut super::MyWrapper<u32>
+                ) {
+                    let this = unsafe { Box::from_raw(this) };
+                    drop(this);
+                }
+            },
+            quote! {
+                #[export_name = "__swift_bridge__$MyWrapper$bool$_free"]
+                pub extern "C" fn __swift_bridge__MyWrapper_bool__free (
+                    this: *mut super::MyWrapper<bool>
+                ) {
+                    let this = unsafe { Box::from_raw(this) };
+                    drop(this);
+                }
+            },
+            quote! {
+                #[export_name = "__swift_bridge__$new_u32_wrapper"]
+                pub extern "C" fn __swift_bridge__new_u32_wrapper() -> *mut super::MyWrapper<u32> {
+                    Box::into_raw(Box::new(super::new_u32_wrapper())) as *mut super::MyWrapper<u32>
+                }
+            },
+            quote! {
+                #[export_name = "__swift_bridge__$new_bool_wrapper"]
+                pub extern "C" fn __swift_bridge__new_bool_wrapper() -> *mut super::MyWrapper<bool> {
+                    Box::into_raw(Box::new(super::new_bool_wrapper())) as *mut super::MyWrapper<bool>
+                }
+            },
+        ])
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsManyAfterTrim(vec![
+            r#"
+public func new_u32_wrapper() -> MyWrapperUInt32 {
+    MyWrapperUInt32(ptr: __swift_bridge__$new_u32_wrapper())
+}
+public func new_bool_wrapper() -> MyWrapperBool {
+    MyWrapperBool(ptr: __swift_bridge__$new_bool_wrapper())
+}
+"#,
+            r#"
+public class MyWrapperUInt32: MyWrapperUInt32RefMut {
+    var isOwned: Bool = true
+
+    public override init(ptr: UnsafeMutableRawPointer) {
+        super.init(ptr: ptr)
+    }
+
+    deinit {
+        if isOwned {
+            __swift_bridge__$MyWrapper$u32$_free(ptr)
+        }
+    }
+}
+"#,
+            r#"
+public class MyWrapperBool: MyWrapperBoolRefMut {
+    var isOwned: Bool = true
+
+    public override init(ptr: UnsafeMutableRawPointer) {
+        super.init(ptr: ptr)
+    }
+
+    deinit {
+        if isOwned {
+            __swift_bridge__$MyWrapper$bool$_free(ptr)
+        }
+    }
+}
+"#,
+        ])
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsManyAfterTrim(vec![
+            r#"
+typedef struct MyWrapper$u32 MyWrapper$u32;
+void __swift_bridge__$MyWrapper$u32$_free(void* self);
+"#,
+            r#"
+typedef struct MyWrapper$bool MyWrapper$bool;
+void __swift_bridge__$MyWrapper$bool$_free(void* self);
+"#,
+            r#"
+void* __swift_bridge__$new_u32_wrapper(void);
+void* __swift_bridge__$new_bool_wrapper(void);
+"#,
+        ])
+    }
+
+    #[test]
+    fn generic_opaque_rust_type_instantiations() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Verify that we generate methods for an instantiation of a generic opaque Rust type.
+mod generic_opaque_rust_type_instantiation_method {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Rust" {
+                    type MyWrapper<T>;
+
+                    fn get(self: &MyWrapper<u32>) -> u32;
+                    fn get(self: &MyWrapper<u64>) -> u64;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::ContainsMany(vec![
+            quote! {
+                #[export_name = "__swift_bridge__$MyWrapper$u32$get"]
+                pub extern "C" fn __swift_bridge__MyWrapper_u32_get (
+                    this: *const super::MyWrapper<u32>
+                ) -> u32 {
+                    (unsafe { &*this }).get()
+                }
+            },
+            quote! {
+                #[export_name = "__swift_bridge__$MyWrapper$u64$get"]
+                pub extern "C" fn __swift_bridge__MyWrapper_u64_get (
+                    this: *const super::MyWrapper<u64>
+                ) -> u64 {
+                    (unsafe { &*this }).get()
+                }
+            },
+        ])
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsManyAfterTrim(vec![
+            r#"
+extension MyWrapperUInt32Ref {
+    public func get() -> UInt32 {
+        __swift_bridge__$MyWrapper$u32$get(ptr)
+    }
+}
+"#,
+            r#"
+extension MyWrapperUInt64Ref {
+    public func get() -> UInt64 {
+        __swift_bridge__$MyWrapper$u64$get(ptr)
+    }
+}
+"#,
+        ])
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsManyAfterTrim(vec![
+            "uint32_t __swift_bridge__$MyWrapper$u32$get(void* self);",
+            "uint64_t __swift_bridge__$MyWrapper$u64$get(void* self);",
+        ])
+    }
+
+    #[test]
+    fn generic_opaque_rust_type_instantiation_method() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Verify that we do not generate any code for the generic declaration itself.
+mod generic_opaque_rust_type_declaration {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Rust" {
+                    type MyWrapper<T>;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::DoesNotContain(quote! {
+            __swift_bridge__MyWrapper__free
+        })
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::DoesNotContainAfterTrim("MyWrapper")
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::DoesNotContainAfterTrim("MyWrapper")
+    }
+
+    #[test]
+    fn generic_opaque_rust_type_declaration() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
@@ -0,0 +1,306 @@
+//! See also: crates/swift-integration-tests/src/hashmap.rs
+
+use super::{CodegenTest, ExpectedCHeader, ExpectedRustTokens, ExpectedSwiftCode};
+use proc_macro2::TokenStream;
+use quote::quote;
+
+/// Test code generation for Rust function that returns a HashMap<String, String>.
+mod extern_rust_fn_return_hashmap_of_strings {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Rust" {
+                    fn get_config() -> HashMap<String, String>;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::Contains(quote! {
+            #[export_name = "__swift_bridge__$get_config"]
+            pub extern "C" fn __swift_bridge__get_config() -> swift_bridge::hashmap::FfiHashMap<
+                *mut swift_bridge::string::RustString,
+                *mut swift_bridge::string::RustString
+            > {
+                swift_bridge::hashmap::FfiHashMap::from_entries(
+                    super::get_config().into_iter().map(|(key, value)| (
+                        swift_bridge::string::RustString(key).box_into_raw(),
+                        swift_bridge::string::RustString(value).box_into_raw()
+                    ))
+                )
+            }
+        })
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+public func get_config() -> Dictionary<String, String> {
+    __swift_bridge__$get_config().toDictionary({ (key: UnsafeMutableRawPointer) in RustString(ptr: key).toString() }, { (value: UnsafeMutableRawPointer) in RustString(ptr: value).toString() })
+}
+"#,
+        )
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ExactAfterTrim(
+            r#"
+struct __private__FfiHashMap __swift_bridge__$get_config(void);
+"#,
+        )
+    }
+
+    #[test]
+    fn extern_rust_fn_return_hashmap_of_strings() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Test code generation for Rust function that returns a HashMap where the keys and values are
+/// primitives.
+mod extern_rust_fn_return_hashmap_of_primitives {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Rust" {
+                    fn some_function() -> HashMap<u8, bool>;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::Contains(quote! {
+            pub extern "C" fn __swift_bridge__some_function() -> swift_bridge::hashmap::FfiHashMap<u8, bool> {
+                swift_bridge::hashmap::FfiHashMap::from_entries(
+                    super::some_function().into_iter().map(|(key, value)| (key, value))
+                )
+            }
+        })
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+public func some_function() -> Dictionary<UInt8, Bool> {
+    __swift_bridge__$some_function().toDictionary({ (key: UInt8) in key }, { (value: Bool) in value })
+}
+"#,
+        )
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsAfterTrim(
+            r#"
+struct __private__FfiHashMap __swift_bridge__$some_function(void);
+"#,
+        )
+    }
+
+    #[test]
+    fn extern_rust_fn_return_hashmap_of_primitives() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Test code generation for Rust function that has a HashMap argument.
+mod extern_rust_fn_arg_hashmap {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Rust" {
+                    fn some_function(arg: HashMap<String, u32>);
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::Contains(quote! {
+            pub extern "C" fn __swift_bridge__some_function(
+                arg: swift_bridge::hashmap::FfiHashMap<*mut swift_bridge::string::RustString, u32>
+            ) {
+                super::some_function(
+                    arg
+                        .into_entries()
+                        .map(|(key, value)| (unsafe { Box::from_raw(key).0 }, value))
+                        .collect::<std::collections::HashMap<_, _>>()
+                )
+            }
+        })
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+public func some_function(_ arg: Dictionary<String, UInt32>) {
+    arg.toFfiHashMap({ (key: String) -> UnsafeMutableRawPointer in { let rustString = key.intoRustString(); rustString.isOwned = false; return rustString.ptr }() }, { (value: UInt32) -> UInt32 in value }, { argAsFfiHashMap in
+        __swift_bridge__$some_function(argAsFfiHashMap)
+    })
+}
+"#,
+        )
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsAfterTrim(
+            r#"
+void __swift_bridge__$some_function(struct __private__FfiHashMap arg);
+"#,
+        )
+    }
+
+    #[test]
+    fn extern_rust_fn_arg_hashmap() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Test code generation for Rust function that returns a BTreeMap.
+/// The entries get moved into a Swift array of tuples, which preserves the BTreeMap's sorted
+/// order, instead of into a Dictionary, which does not.
+mod extern_rust_fn_return_btreemap {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Rust" {
+                    fn some_function() -> BTreeMap<String, i32>;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::Contains(quote! {
+            pub extern "C" fn __swift_bridge__some_function() -> swift_bridge::hashmap::FfiHashMap<
+                *mut swift_bridge::string::RustString,
+                i32
+            > {
+                swift_bridge::hashmap::FfiHashMap::from_entries(
+                    super::some_function().into_iter().map(|(key, value)| (
+                        swift_bridge::string::RustString(key).box_into_raw(),
+                        value
+                    ))
+                )
+            }
+        })
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+public func some_function() -> [(String, Int32)] {
+    __swift_bridge__$some_function().toEntries({ (key: UnsafeMutableRawPointer) in RustString(ptr: key).toString() }, { (value: Int32) in value })
+}
+"#,
+        )
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsAfterTrim(
+            r#"
+struct __private__FfiHashMap __swift_bridge__$some_function(void);
+"#,
+        )
+    }
+
+    #[test]
+    fn extern_rust_fn_return_btreemap() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Test code generation for Rust function that has a BTreeMap argument.
+/// Swift lends an array of tuples in its original order, and Rust collects it into a BTreeMap.
+mod extern_rust_fn_arg_btreemap {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Rust" {
+                    fn some_function(arg: BTreeMap<u8, bool>);
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::Contains(quote! {
+            pub extern "C" fn __swift_bridge__some_function(
+                arg: swift_bridge::hashmap::FfiHashMap<u8, bool>
+            ) {
+                super::some_function(
+                    arg
+                        .into_entries()
+                        .map(|(key, value)| (key, value))
+                        .collect::<std::collections::BTreeMap<_, _>>()
+                )
+            }
+        })
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+public func some_function(_ arg: [(UInt8, Bool)]) {
+    arg.toFfiHashMap({ (key: UInt8) -> UInt8 in key }, { (value: Bool) -> Bool in value }, { argAsFfiHashMap in
+        __swift_bridge__$some_function(argAsFfiHashMap)
+    })
+}
+"#,
+        )
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsAfterTrim(
+            r#"
+void __swift_bridge__$some_function(struct __private__FfiHashMap arg);
+"#,
+        )
+    }
+
+    #[test]
+    fn extern_rust_fn_arg_btreemap() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
@@ -0,0 +1,140 @@
+//! See also: crates/swift-integration-tests/src/int128.rs
+
+use super::{CodegenTest, ExpectedCHeader, ExpectedRustTokens, ExpectedSwiftCode};
+use proc_macro2::TokenStream;
+use quote::quote;
+
+/// Test code generation for Rust functions that take and return a u128 and an i128.
+mod extern_rust_fn_int128_round_trip {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Rust" {
+                    fn reflect_u128(arg: u128) -> u128;
+                    fn reflect_i128(arg: i128) -> i128;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::ContainsMany(vec![
+            quote! {
+                #[export_name = "__swift_bridge__$reflect_u128"]
+                pub extern "C" fn __swift_bridge__reflect_u128(
+                    arg: swift_bridge::int128::FfiU128
+                ) -> swift_bridge::int128::FfiU128 {
+                    swift_bridge::int128::FfiU128::from_u128(
+                        super::reflect_u128(arg.into_u128())
+                    )
+                }
+            },
+            quote! {
+                #[export_name = "__swift_bridge__$reflect_i128"]
+                pub extern "C" fn __swift_bridge__reflect_i128(
+                    arg: swift_bridge::int128::FfiI128
+                ) -> swift_bridge::int128::FfiI128 {
+                    swift_bridge::int128::FfiI128::from_i128(
+                        super::reflect_i128(arg.into_i128())
+                    )
+                }
+            },
+        ])
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsManyAfterTrim(vec![
+            r#"
+public func reflect_u128(_ arg: RustU128) -> RustU128 {
+    __swift_bridge__$reflect_u128(arg.intoFfiRepr()).intoSwiftRepr()
+}
+"#,
+            r#"
+public func reflect_i128(_ arg: RustI128) -> RustI128 {
+    __swift_bridge__$reflect_i128(arg.intoFfiRepr()).intoSwiftRepr()
+}
+"#,
+        ])
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsManyAfterTrim(vec![
+            r#"
+struct __private__FfiU128 __swift_bridge__$reflect_u128(struct __private__FfiU128 arg);
+"#,
+            r#"
+struct __private__FfiI128 __swift_bridge__$reflect_i128(struct __private__FfiI128 arg);
+"#,
+        ])
+    }
+
+    #[test]
+    fn extern_rust_fn_int128_round_trip() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Test code generation for a Swift function that takes and returns a u128.
+mod extern_swift_fn_u128_round_trip {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Swift" {
+                    fn some_function(arg: u128) -> u128;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::ContainsMany(vec![
+            quote! {
+                pub fn some_function(arg: u128) -> u128 {
+                    unsafe {
+                        __swift_bridge__some_function(
+                            swift_bridge::int128::FfiU128::from_u128(arg)
+                        )
+                    }.into_u128()
+                }
+            },
+            quote! {
+                #[link_name = "__swift_bridge__$some_function"]
+                fn __swift_bridge__some_function(
+                    arg: swift_bridge::int128::FfiU128
+                ) -> swift_bridge::int128::FfiU128;
+            },
+        ])
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+@_cdecl("__swift_bridge__$some_function")
+func __swift_bridge__some_function (_ arg: __private__FfiU128) -> __private__FfiU128 {
+    some_function(arg: arg.intoSwiftRepr()).intoFfiRepr()
+}
+"#,
+        )
+    }
+
+    #[test]
+    fn extern_swift_fn_u128_round_trip() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: ExpectedCHeader::SkipTest,
+        }
+        .test();
+    }
+}
@@ -0,0 +1,110 @@
+use super::{CodegenTest, ExpectedCHeader, ExpectedRustTokens, ExpectedSwiftCode};
+use proc_macro2::TokenStream;
+use quote::quote;
+
+/// Verify that the methods of an opaque Rust type with the `#[swift_bridge(Mutex)]` attribute
+/// lock the mutex before they get called, and that a poisoned lock gets thrown as an error.
+mod extern_rust_mutex_guarded_method {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Rust" {
+                    #[swift_bridge(Mutex)]
+                    type SomeType;
+
+                    #[swift_bridge(init)]
+                    fn new() -> Mutex<SomeType>;
+
+                    fn value(&self) -> u32;
+                    fn increment(&mut self);
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::ContainsMany(vec![
+            quote! {
+                #[export_name = "__swift_bridge__$SomeType$new"]
+                pub extern "C" fn __swift_bridge__SomeType_new (
+                ) -> *mut std::sync::Mutex<super::SomeType> {
+                    Box::into_raw(Box::new(super::SomeType::new())) as *mut std::sync::Mutex<super::SomeType>
+                }
+            },
+            quote! {
+                #[export_name = "__swift_bridge__$SomeType$value"]
+                pub extern "C" fn __swift_bridge__SomeType_value (
+                    this: *const std::sync::Mutex<super::SomeType>
+                ) -> __swift_bridge__ResultUInt32AndRustError {
+                    match (unsafe { &*this })
+                        .lock()
+                        .map(|this| this.value())
+                        .map_err(|err| Box::<dyn std::error::Error>::from(err.to_string()))
+                    {
+                        Ok(ok) => __swift_bridge__ResultUInt32AndRustError::Ok(ok),
+                        Err(err) => __swift_bridge__ResultUInt32AndRustError::Err(
+                            Box::into_raw(Box::new(swift_bridge::error::RustError(err)))
+                        )
+                    }
+                }
+            },
+            quote! {
+                #[export_name = "__swift_bridge__$SomeType$increment"]
+                pub extern "C" fn __swift_bridge__SomeType_increment (
+                    this: *mut std::sync::Mutex<super::SomeType>
+                ) -> __swift_bridge__ResultVoidAndRustError {
+                    match (unsafe { &*this })
+                        .lock()
+                        .map(|mut this| this.increment())
+                        .map_err(|err| Box::<dyn std::error::Error>::from(err.to_string()))
+                    {
+                        Ok(()) => __swift_bridge__ResultVoidAndRustError::Ok,
+                        Err(err) => __swift_bridge__ResultVoidAndRustError::Err(
+                            Box::into_raw(Box::new(swift_bridge::error::RustError(err)))
+                        )
+                    }
+                }
+            },
+        ])
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsManyAfterTrim(vec![
+            r#"
+extension SomeTypeRefMut {
+    public func increment() throws {
+        try { () throws -> () in let val = __swift_bridge__$SomeType$increment(ptr); if val.tag == __swift_bridge__$ResultVoidAndRustError$ResultOk { return } else { throw RustError(ptr: val.payload.err) } }()
+    }
+}
+"#,
+            r#"
+extension SomeTypeRef {
+    public func value() throws -> UInt32 {
+        try { () throws -> UInt32 in let val = __swift_bridge__$SomeType$value(ptr); if val.tag == __swift_bridge__$ResultUInt32AndRustError$ResultOk { return val.payload.ok } else { throw RustError(ptr: val.payload.err) } }()
+    }
+}
+"#,
+        ])
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsManyAfterTrim(vec![
+            "void* __swift_bridge__$SomeType$new(void);",
+            "struct __swift_bridge__$ResultUInt32AndRustError __swift_bridge__$SomeType$value(void* self);",
+            "struct __swift_bridge__$ResultVoidAndRustError __swift_bridge__$SomeType$increment(void* self);",
+        ])
+    }
+
+    #[test]
+    fn extern_rust_mutex_guarded_method() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
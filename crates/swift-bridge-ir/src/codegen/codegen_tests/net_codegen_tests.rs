@@ -0,0 +1,176 @@
+//! See also: crates/swift-integration-tests/src/net.rs
+
+use super::{CodegenTest, ExpectedCHeader, ExpectedRustTokens, ExpectedSwiftCode};
+use proc_macro2::TokenStream;
+use quote::quote;
+
+/// Test code generation for Rust function that takes and returns an IpAddr.
+mod extern_rust_fn_ip_addr_round_trip {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Rust" {
+                    fn some_function(arg: IpAddr) -> std::net::IpAddr;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::Contains(quote! {
+            #[export_name = "__swift_bridge__$some_function"]
+            pub extern "C" fn __swift_bridge__some_function(
+                arg: swift_bridge::net::FfiIpAddr
+            ) -> swift_bridge::net::FfiIpAddr {
+                swift_bridge::net::FfiIpAddr::from_ip_addr(
+                    super::some_function(arg.into_ip_addr())
+                )
+            }
+        })
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+public func some_function(_ arg: RustIpAddr) -> RustIpAddr {
+    __swift_bridge__$some_function(arg.intoFfiRepr()).intoSwiftRepr()
+}
+"#,
+        )
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsAfterTrim(
+            r#"
+struct __private__FfiIpAddr __swift_bridge__$some_function(struct __private__FfiIpAddr arg);
+"#,
+        )
+    }
+
+    #[test]
+    fn extern_rust_fn_ip_addr_round_trip() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Test code generation for Rust function that takes and returns a SocketAddr.
+mod extern_rust_fn_socket_addr_round_trip {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Rust" {
+                    fn some_function(arg: std::net::SocketAddr) -> SocketAddr;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::Contains(quote! {
+            #[export_name = "__swift_bridge__$some_function"]
+            pub extern "C" fn __swift_bridge__some_function(
+                arg: swift_bridge::net::FfiSocketAddr
+            ) -> swift_bridge::net::FfiSocketAddr {
+                swift_bridge::net::FfiSocketAddr::from_socket_addr(
+                    super::some_function(arg.into_socket_addr())
+                )
+            }
+        })
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+public func some_function(_ arg: RustSocketAddr) -> RustSocketAddr {
+    __swift_bridge__$some_function(arg.intoFfiRepr()).intoSwiftRepr()
+}
+"#,
+        )
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsAfterTrim(
+            r#"
+struct __private__FfiSocketAddr __swift_bridge__$some_function(struct __private__FfiSocketAddr arg);
+"#,
+        )
+    }
+
+    #[test]
+    fn extern_rust_fn_socket_addr_round_trip() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Test code generation for Swift function that takes and returns a SocketAddr.
+mod extern_swift_fn_socket_addr_round_trip {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Swift" {
+                    fn some_function(arg: SocketAddr) -> SocketAddr;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::ContainsMany(vec![
+            quote! {
+                pub fn some_function(arg: std::net::SocketAddr) -> std::net::SocketAddr {
+                    unsafe {
+                        __swift_bridge__some_function(
+                            swift_bridge::net::FfiSocketAddr::from_socket_addr(arg)
+                        )
+                    }.into_socket_addr()
+                }
+            },
+            quote! {
+                #[link_name = "__swift_bridge__$some_function"]
+                fn __swift_bridge__some_function(
+                    arg: swift_bridge::net::FfiSocketAddr
+                ) -> swift_bridge::net::FfiSocketAddr;
+            },
+        ])
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+@_cdecl("__swift_bridge__$some_function")
+func __swift_bridge__some_function (_ arg: __private__FfiSocketAddr) -> __private__FfiSocketAddr {
+    some_function(arg: arg.intoSwiftRepr()).intoFfiRepr()
+}
+"#,
+        )
+    }
+
+    #[test]
+    fn extern_swift_fn_socket_addr_round_trip() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: ExpectedCHeader::SkipTest,
+        }
+        .test();
+    }
+}
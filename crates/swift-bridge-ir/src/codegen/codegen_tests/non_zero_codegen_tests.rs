@@ -0,0 +1,177 @@
+//! See also: crates/swift-integration-tests/src/non_zero.rs
+
+use super::{CodegenTest, ExpectedCHeader, ExpectedRustTokens, ExpectedSwiftCode};
+use proc_macro2::TokenStream;
+use quote::quote;
+
+/// Test code generation for Rust function that takes and returns a NonZeroU32.
+mod extern_rust_fn_non_zero_round_trip {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Rust" {
+                    fn some_function(arg: NonZeroU32) -> std::num::NonZeroU64;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::Contains(quote! {
+            #[export_name = "__swift_bridge__$some_function"]
+            pub extern "C" fn __swift_bridge__some_function(arg: u32) -> u64 {
+                super::some_function(
+                    std::num::NonZeroU32::new(arg).expect("Expected a non-zero integer")
+                ).get()
+            }
+        })
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+public func some_function(_ arg: UInt32) -> UInt64 {
+    __swift_bridge__$some_function(arg)
+}
+"#,
+        )
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsAfterTrim(
+            r#"
+uint64_t __swift_bridge__$some_function(uint32_t arg);
+"#,
+        )
+    }
+
+    #[test]
+    fn extern_rust_fn_non_zero_round_trip() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Test code generation for Rust function that takes and returns an Option<NonZeroU32>.
+/// `None` is represented by `0`, so no `__private__OptionU32` is needed.
+mod extern_rust_fn_option_non_zero_round_trip {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Rust" {
+                    fn some_function(arg: Option<NonZeroU32>) -> Option<NonZeroU32>;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::Contains(quote! {
+            #[export_name = "__swift_bridge__$some_function"]
+            pub extern "C" fn __swift_bridge__some_function(arg: u32) -> u32 {
+                if let Some(val) = super::some_function(std::num::NonZeroU32::new(arg)) {
+                    val.get()
+                } else {
+                    0
+                }
+            }
+        })
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+public func some_function(_ arg: Optional<UInt32>) -> Optional<UInt32> {
+    { let val = __swift_bridge__$some_function({ if let val = arg { return val } else { return 0 } }()); if val != 0 { return val } else { return nil } }()
+}
+"#,
+        )
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsAfterTrim(
+            r#"
+uint32_t __swift_bridge__$some_function(uint32_t arg);
+"#,
+        )
+    }
+
+    #[test]
+    fn extern_rust_fn_option_non_zero_round_trip() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Test code generation for Swift function that takes and returns an Option<NonZeroU32>.
+mod extern_swift_fn_option_non_zero_round_trip {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Swift" {
+                    fn some_function(arg: Option<NonZeroU32>) -> Option<NonZeroU32>;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::ContainsMany(vec![
+            quote! {
+                pub fn some_function(
+                    arg: Option<std::num::NonZeroU32>
+                ) -> Option<std::num::NonZeroU32> {
+                    std::num::NonZeroU32::new(unsafe {
+                        __swift_bridge__some_function(if let Some(val) = arg {
+                            val.get()
+                        } else {
+                            0
+                        })
+                    })
+                }
+            },
+            quote! {
+                #[link_name = "__swift_bridge__$some_function"]
+                fn __swift_bridge__some_function(arg: u32) -> u32;
+            },
+        ])
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+@_cdecl("__swift_bridge__$some_function")
+func __swift_bridge__some_function (_ arg: UInt32) -> UInt32 {
+    { if let val = some_function(arg: { let val = arg; if val != 0 { return val } else { return nil } }()) { return val } else { return 0 } }()
+}
+"#,
+        )
+    }
+
+    #[test]
+    fn extern_swift_fn_option_non_zero_round_trip() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: ExpectedCHeader::SkipTest,
+        }
+        .test();
+    }
+}
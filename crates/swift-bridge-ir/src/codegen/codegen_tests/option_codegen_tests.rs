@@ -226,10 +226,13 @@ mod extern_rust_fn_arg_option_str {
                arg: swift_bridge::string::RustStr
             ) {
                 super::some_function(
-                    if arg.start.is_null() {
-                        None
-                    } else {
-                        Some(arg.to_str())
+                    {
+                        let val = arg;
+                        if val.start.is_null() {
+                            None
+                        } else {
+                            Some(val.to_str())
+                        }
                     }
                 )
             }
@@ -266,6 +269,77 @@ void __swift_bridge__$some_function(struct RustStr arg);
     }
 }
 
+/// Test code generation for Swift function that takes and returns an Option<&str>.
+///
+/// `None` crosses the boundary as a `RustStr` with a null pointer, so the Swift call only gets
+/// evaluated once before we check for null.
+mod extern_swift_fn_option_str {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Swift" {
+                    fn some_function (arg: Option<&str>) -> Option<&str>;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::ContainsMany(vec![
+            quote! {
+                pub fn some_function(arg: Option<&str>) -> Option<&str> {
+                    {
+                        let val = unsafe {
+                            __swift_bridge__some_function(
+                                if let Some(val) = arg {
+                                    swift_bridge::string::RustStr::from_str(val)
+                                } else {
+                                    swift_bridge::string::RustStr { start: std::ptr::null::<u8>(), len: 0}
+                                }
+                            )
+                        };
+                        if val.start.is_null() {
+                            None
+                        } else {
+                            Some(val.to_str())
+                        }
+                    }
+                }
+            },
+            quote! {
+                #[link_name = "__swift_bridge__$some_function"]
+                fn __swift_bridge__some_function(arg: swift_bridge::string::RustStr) -> swift_bridge::string::RustStr;
+            },
+        ])
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+@_cdecl("__swift_bridge__$some_function")
+func __swift_bridge__some_function (_ arg: RustStr) -> RustStr {
+    { if let val = some_function(arg: { let val = arg; if val.start != nil { return val; } else { return nil; } }()) { return val } else { return RustStr(start: nil, len: 0) } }()
+}
+"#,
+        )
+    }
+
+    const EXPECTED_C_HEADER: ExpectedCHeader = ExpectedCHeader::SkipTest;
+
+    #[test]
+    fn extern_swift_fn_option_str() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: EXPECTED_C_HEADER,
+        }
+        .test();
+    }
+}
+
 /// Test code generation for Rust function that returns an Option<OpaqueRustType>
 mod extern_rust_fn_return_option_opaque_rust_type {
     use super::*;
@@ -298,7 +372,7 @@ mod extern_rust_fn_return_option_opaque_rust_type {
         ExpectedSwiftCode::ContainsAfterTrim(
             r#"
 func some_function() -> Optional<SomeType> {
-    { let val = __swift_bridge__$some_function(); if val != nil { return SomeType(ptr: val!) } else { return nil } }()
+    { if let val = __swift_bridge__$some_function() { return SomeType(ptr: val) } else { return nil } }()
 }
 "#,
         )
@@ -324,6 +398,66 @@ void* __swift_bridge__$some_function(void);
     }
 }
 
+/// Test code generation for Rust function that returns an Option<&OpaqueRustType>
+mod extern_rust_fn_return_option_ref_opaque_rust_type {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Rust" {
+                    type SomeType;
+                    fn some_function (&self) -> Option<&SomeType>;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::Contains(quote! {
+            #[export_name = "__swift_bridge__$SomeType$some_function"]
+            pub extern "C" fn __swift_bridge__SomeType_some_function(
+                this: *const super::SomeType
+            ) -> *const super::SomeType {
+                if let Some(val) = (unsafe { &*this }).some_function() {
+                    val as *const super::SomeType
+                } else {
+                    std::ptr::null::<super::SomeType>()
+                }
+            }
+        })
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+    public func some_function() -> Optional<SomeTypeRef> {
+        { if let val = __swift_bridge__$SomeType$some_function(ptr) { return SomeTypeRef(ptr: val) } else { return nil } }()
+    }
+"#,
+        )
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsAfterTrim(
+            r#"
+void* __swift_bridge__$SomeType$some_function(void* self);
+    "#,
+        )
+    }
+
+    #[test]
+    fn extern_rust_fn_return_option_ref_opaque_rust_type() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
 /// Test code generation for Rust function that takes an Option<OpaqueRustType> argument.
 mod extern_rust_fn_with_option_opaque_rust_type_arg {
     use super::*;
@@ -386,6 +520,331 @@ void __swift_bridge__$some_function(void* arg);
     }
 }
 
+/// Test code generation for Rust method that takes an Option<OpaqueRustType> argument.
+///
+/// `None` is passed as a null pointer, and `Some` gives Rust ownership of the boxed value.
+mod extern_rust_method_with_option_opaque_rust_type_arg {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Rust" {
+                    type SomeType;
+                    fn some_method(&self, arg: Option<SomeType>);
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::Contains(quote! {
+            #[export_name = "__swift_bridge__$SomeType$some_method"]
+            pub extern "C" fn __swift_bridge__SomeType_some_method(
+                this: *const super::SomeType,
+                arg: *mut super::SomeType
+            ) {
+                (unsafe { &*this }).some_method(
+                    if arg.is_null() {
+                        None
+                    } else {
+                        Some( unsafe { * Box::from_raw(arg) } )
+                    }
+                )
+            }
+        })
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+extension SomeTypeRef {
+    public func some_method(_ arg: Optional<SomeType>) {
+        __swift_bridge__$SomeType$some_method(ptr, { if let val = arg { val.isOwned = false; return val.ptr } else { return nil } }())
+    }
+}
+"#,
+        )
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsAfterTrim(
+            r#"
+void __swift_bridge__$SomeType$some_method(void* self, void* arg);
+    "#,
+        )
+    }
+
+    #[test]
+    fn extern_rust_method_with_option_opaque_rust_type_arg() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Test code generation for Rust function that takes Option<&OpaqueRustType> and
+/// Option<&mut OpaqueRustType> arguments.
+///
+/// Swift keeps ownership of the borrowed values, so Rust must not free them.
+mod extern_rust_fn_with_option_ref_opaque_rust_type_arg {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Rust" {
+                    type SomeType;
+                    fn some_function(arg: Option<&SomeType>, arg2: Option<&mut SomeType>);
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::Contains(quote! {
+            #[export_name = "__swift_bridge__$some_function"]
+            pub extern "C" fn __swift_bridge__some_function(
+                arg: *const super::SomeType,
+                arg2: *mut super::SomeType
+            ) {
+                super::some_function(
+                    if arg.is_null() {
+                        None
+                    } else {
+                        Some( unsafe { & * arg } )
+                    },
+                    if arg2.is_null() {
+                        None
+                    } else {
+                        Some( unsafe { &mut * arg2 } )
+                    }
+                )
+            }
+        })
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+func some_function(_ arg: Optional<SomeTypeRef>, _ arg2: Optional<SomeTypeRefMut>) {
+    __swift_bridge__$some_function({ if let val = arg { return val.ptr } else { return nil } }(), { if let val = arg2 { return val.ptr } else { return nil } }())
+}
+"#,
+        )
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsAfterTrim(
+            r#"
+void __swift_bridge__$some_function(void* arg, void* arg2);
+    "#,
+        )
+    }
+
+    #[test]
+    fn extern_rust_fn_with_option_ref_opaque_rust_type_arg() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Test code generation for Swift function that takes Option<OpaqueRustType> and
+/// Option<&OpaqueRustType> arguments.
+mod extern_swift_fn_with_option_opaque_rust_type_arg {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Rust" {
+                    type SomeType;
+                }
+
+                extern "Swift" {
+                    fn some_function(arg: Option<SomeType>, arg2: Option<&SomeType>);
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::Contains(quote! {
+            pub fn some_function(arg: Option<super::SomeType>, arg2: Option<&super::SomeType>) {
+                unsafe {
+                    __swift_bridge__some_function(
+                        if let Some(val) = arg {
+                            Box::into_raw(Box::new(val))
+                        } else {
+                            std::ptr::null_mut()
+                        },
+                        if let Some(val) = arg2 {
+                            val as *const super::SomeType
+                        } else {
+                            std::ptr::null::<super::SomeType>()
+                        }
+                    )
+                }
+            }
+        })
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+@_cdecl("__swift_bridge__$some_function")
+func __swift_bridge__some_function (_ arg: UnsafeMutableRawPointer?, _ arg2: UnsafeMutableRawPointer?) {
+    some_function(arg: { if let val = arg { return SomeType(ptr: val) } else { return nil } }(), arg2: { if let val = arg2 { return SomeTypeRef(ptr: val) } else { return nil } }())
+}
+"#,
+        )
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::SkipTest
+    }
+
+    #[test]
+    fn extern_swift_fn_with_option_opaque_rust_type_arg() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Test code generation for Rust function that returns an Option<Vec<T>>.
+///
+/// `None` crosses the boundary as an `FfiVec` with a null buffer, so that it can be told apart
+/// from an empty Vec.
+mod extern_rust_fn_return_option_vec {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Rust" {
+                    fn some_function() -> Option<Vec<u32>>;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::Contains(quote! {
+            pub extern "C" fn __swift_bridge__some_function() -> swift_bridge::vec::FfiVec<u32> {
+                if let Some(val) = super::some_function() {
+                    swift_bridge::vec::FfiVec::from_elems(val.into_iter().map(|elem| elem))
+                } else {
+                    swift_bridge::vec::FfiVec::none()
+                }
+            }
+        })
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+func some_function() -> Optional<[UInt32]> {
+    { let val = __swift_bridge__$some_function(); if val.elems != nil { return val.toArray(of: UInt32.self) } else { return nil } }()
+}
+"#,
+        )
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsAfterTrim(
+            r#"
+struct __private__FfiVec __swift_bridge__$some_function(void);
+"#,
+        )
+    }
+
+    #[test]
+    fn extern_rust_fn_return_option_vec() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Test code generation for Rust function that returns a Vec<Option<T>>.
+mod extern_rust_fn_return_vec_of_option {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Rust" {
+                    fn some_function() -> Vec<Option<u32>>;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::Contains(quote! {
+            pub extern "C" fn __swift_bridge__some_function(
+            ) -> swift_bridge::vec::FfiVec<swift_bridge::option::OptionU32> {
+                swift_bridge::vec::FfiVec::from_elems(
+                    super::some_function().into_iter().map(|elem|
+                        if let Some(val) = elem {
+                            swift_bridge::option::OptionU32 { val, is_some: true }
+                        } else {
+                            swift_bridge::option::OptionU32 { val: 123, is_some: false }
+                        }
+                    )
+                )
+            }
+        })
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+func some_function() -> [Optional<UInt32>] {
+    __swift_bridge__$some_function().toArray({ (elem: __private__OptionU32) in { let val = elem; if val.is_some { return val.val } else { return nil } }() })
+}
+"#,
+        )
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsAfterTrim(
+            r#"
+struct __private__FfiVec __swift_bridge__$some_function(void);
+"#,
+        )
+    }
+
+    #[test]
+    fn extern_rust_fn_return_vec_of_option() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
 /// Test conversion to and from the FFI representation of a struct that contains Option<F> fields.
 mod shared_struct_with_option_field_ffi_repr {
     use super::*;
@@ -0,0 +1,131 @@
+//! See also: crates/swift-integration-tests/src/path_buf.rs
+
+use super::{CodegenTest, ExpectedCHeader, ExpectedRustTokens, ExpectedSwiftCode};
+use proc_macro2::TokenStream;
+use quote::quote;
+
+/// Test code generation for Rust function that takes and returns a PathBuf.
+mod extern_rust_fn_path_buf_round_trip {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Rust" {
+                    fn some_function(arg: PathBuf) -> std::path::PathBuf;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::Contains(quote! {
+            #[export_name = "__swift_bridge__$some_function"]
+            pub extern "C" fn __swift_bridge__some_function(
+                arg: *mut swift_bridge::string::RustString
+            ) -> *mut swift_bridge::string::RustString {
+                swift_bridge::string::RustString(
+                    super::some_function(std::path::PathBuf::from(unsafe { Box::from_raw(arg).0 }))
+                        .into_os_string()
+                        .into_string()
+                        .unwrap_or_else(|path| path.to_string_lossy().into_owned())
+                ).box_into_raw()
+            }
+        })
+    }
+
+    /// `URL` comes from Foundation, so the generated code must import it.
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+import Foundation
+public func some_function(_ arg: URL) -> URL {
+    URL(fileURLWithPath: RustString(ptr: __swift_bridge__$some_function({ let rustString = arg.path.intoRustString(); rustString.isOwned = false; return rustString.ptr }())).toString())
+}
+"#,
+        )
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsAfterTrim(
+            r#"
+void* __swift_bridge__$some_function(void* arg);
+"#,
+        )
+    }
+
+    #[test]
+    fn extern_rust_fn_path_buf_round_trip() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Test code generation for Swift function that takes and returns a PathBuf.
+mod extern_swift_fn_path_buf_round_trip {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Swift" {
+                    fn some_function(arg: PathBuf) -> PathBuf;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::ContainsMany(vec![
+            quote! {
+                pub fn some_function(arg: std::path::PathBuf) -> std::path::PathBuf {
+                    std::path::PathBuf::from(unsafe {
+                        Box::from_raw(unsafe {
+                            __swift_bridge__some_function(
+                                swift_bridge::string::RustString(
+                                    arg
+                                        .into_os_string()
+                                        .into_string()
+                                        .unwrap_or_else(|path| path.to_string_lossy().into_owned())
+                                ).box_into_raw()
+                            )
+                        }).0
+                    })
+                }
+            },
+            quote! {
+                #[link_name = "__swift_bridge__$some_function"]
+                fn __swift_bridge__some_function(
+                    arg: *mut swift_bridge::string::RustString
+                ) -> *mut swift_bridge::string::RustString;
+            },
+        ])
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+@_cdecl("__swift_bridge__$some_function")
+func __swift_bridge__some_function (_ arg: UnsafeMutableRawPointer) -> UnsafeMutableRawPointer {
+    { let rustString = some_function(arg: URL(fileURLWithPath: RustString(ptr: arg).toString())).path.intoRustString(); rustString.isOwned = false; return rustString.ptr }()
+}
+"#,
+        )
+    }
+
+    #[test]
+    fn extern_swift_fn_path_buf_round_trip() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: ExpectedCHeader::SkipTest,
+        }
+        .test();
+    }
+}
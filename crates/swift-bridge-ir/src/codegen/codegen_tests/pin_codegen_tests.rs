@@ -0,0 +1,129 @@
+use super::{CodegenTest, ExpectedCHeader, ExpectedRustTokens, ExpectedSwiftCode};
+use proc_macro2::TokenStream;
+use quote::quote;
+
+/// Verify that we generate a Swift class that holds a `Pin<Box<Type>>` for an opaque Rust type
+/// with the `#[swift_bridge(Pin)]` attribute, and that methods get called through the pin
+/// without ever moving the value out of its box.
+mod extern_rust_pinned_type {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Rust" {
+                    #[swift_bridge(Pin)]
+                    type SomeType;
+
+                    #[swift_bridge(init)]
+                    fn new() -> Pin<Box<SomeType>>;
+
+                    fn value(&self) -> u32;
+                    fn increment(&mut self);
+
+                    fn take_some_type(arg: Pin<Box<SomeType>>);
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::ContainsMany(vec![
+            quote! {
+                #[export_name = "__swift_bridge__$SomeType$_free"]
+                pub extern "C" fn __swift_bridge__SomeType__free (
+                    this: *mut std::pin::Pin<Box<super::SomeType>>
+                ) {
+                    let this = unsafe { Box::from_raw(this) };
+                    drop(this);
+                }
+            },
+            quote! {
+                #[export_name = "__swift_bridge__$SomeType$new"]
+                pub extern "C" fn __swift_bridge__SomeType_new (
+                ) -> *mut std::pin::Pin<Box<super::SomeType>> {
+                    Box::into_raw(Box::new(super::SomeType::new())) as *mut std::pin::Pin<Box<super::SomeType>>
+                }
+            },
+            quote! {
+                #[export_name = "__swift_bridge__$SomeType$value"]
+                pub extern "C" fn __swift_bridge__SomeType_value (
+                    this: *const std::pin::Pin<Box<super::SomeType>>
+                ) -> u32 {
+                    (unsafe { &*this }).value()
+                }
+            },
+            quote! {
+                #[export_name = "__swift_bridge__$SomeType$increment"]
+                pub extern "C" fn __swift_bridge__SomeType_increment (
+                    this: *mut std::pin::Pin<Box<super::SomeType>>
+                ) {
+                    (unsafe { &mut *this }).as_mut().increment()
+                }
+            },
+            quote! {
+                #[export_name = "__swift_bridge__$take_some_type"]
+                pub extern "C" fn __swift_bridge__take_some_type (
+                    arg: *mut std::pin::Pin<Box<super::SomeType>>
+                ) {
+                    super::take_some_type(unsafe { *Box::from_raw(arg) })
+                }
+            },
+        ])
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsManyAfterTrim(vec![
+            r#"
+extension SomeType {
+    public convenience init() {
+        self.init(ptr: __swift_bridge__$SomeType$new())
+    }
+}
+"#,
+            r#"
+extension SomeTypeRefMut {
+    public func increment() {
+        __swift_bridge__$SomeType$increment(ptr)
+    }
+}
+"#,
+            r#"
+extension SomeTypeRef {
+    public func value() -> UInt32 {
+        __swift_bridge__$SomeType$value(ptr)
+    }
+}
+"#,
+            r#"
+public func take_some_type(_ arg: SomeType) {
+    __swift_bridge__$take_some_type({arg.isOwned = false; return arg.ptr;}())
+}
+"#,
+        ])
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsManyAfterTrim(vec![
+            r#"
+typedef struct SomeType SomeType;
+void __swift_bridge__$SomeType$_free(void* self);
+"#,
+            "void* __swift_bridge__$SomeType$new(void);",
+            "uint32_t __swift_bridge__$SomeType$value(void* self);",
+            "void __swift_bridge__$SomeType$increment(void* self);",
+            "void __swift_bridge__$take_some_type(void* arg);",
+        ])
+    }
+
+    #[test]
+    fn extern_rust_pinned_type() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
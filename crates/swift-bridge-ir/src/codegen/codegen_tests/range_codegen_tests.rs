@@ -0,0 +1,168 @@
+//! See also: crates/swift-integration-tests/src/range.rs
+
+use super::{CodegenTest, ExpectedCHeader, ExpectedRustTokens, ExpectedSwiftCode};
+use proc_macro2::TokenStream;
+use quote::quote;
+
+/// Test code generation for Rust function that returns a Range<usize>.
+mod extern_rust_fn_return_range {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Rust" {
+                    fn some_function() -> Range<usize>;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::Contains(quote! {
+            pub extern "C" fn __swift_bridge__some_function() -> swift_bridge::range::FfiRange<usize> {
+                swift_bridge::range::FfiRange::from_range(super::some_function())
+            }
+        })
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+public func some_function() -> Range<UInt> {
+    { () -> Range<UInt> in let val = __swift_bridge__$some_function(); return val.start..<val.end }()
+}
+"#,
+        )
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsAfterTrim(
+            r#"
+struct __private__FfiRangeUsize __swift_bridge__$some_function(void);
+"#,
+        )
+    }
+
+    #[test]
+    fn extern_rust_fn_return_range() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Test code generation for Rust function that takes a RangeInclusive<i32> argument.
+mod extern_rust_fn_arg_range_inclusive {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Rust" {
+                    fn some_function(arg: std::ops::RangeInclusive<i32>);
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::Contains(quote! {
+            pub extern "C" fn __swift_bridge__some_function(
+                arg: swift_bridge::range::FfiRange<i32>
+            ) {
+                super::some_function(arg.into_range_inclusive())
+            }
+        })
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+public func some_function(_ arg: ClosedRange<Int32>) {
+    __swift_bridge__$some_function({ let val = arg; return __private__FfiRangeI32(start: val.lowerBound, end: val.upperBound, inclusive: true) }())
+}
+"#,
+        )
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsAfterTrim(
+            r#"
+void __swift_bridge__$some_function(struct __private__FfiRangeI32 arg);
+"#,
+        )
+    }
+
+    #[test]
+    fn extern_rust_fn_arg_range_inclusive() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Test code generation for Swift function that takes and returns a Range<u8>.
+mod extern_swift_fn_range_round_trip {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Swift" {
+                    fn some_function(arg: Range<u8>) -> Range<u8>;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::ContainsMany(vec![
+            quote! {
+                pub fn some_function(arg: std::ops::Range<u8>) -> std::ops::Range<u8> {
+                    unsafe {
+                        __swift_bridge__some_function(
+                            swift_bridge::range::FfiRange::from_range(arg)
+                        )
+                    }.into_range()
+                }
+            },
+            quote! {
+                #[link_name = "__swift_bridge__$some_function"]
+                fn __swift_bridge__some_function(
+                    arg: swift_bridge::range::FfiRange<u8>
+                ) -> swift_bridge::range::FfiRange<u8>;
+            },
+        ])
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+@_cdecl("__swift_bridge__$some_function")
+func __swift_bridge__some_function (_ arg: __private__FfiRangeU8) -> __private__FfiRangeU8 {
+    { let val = some_function(arg: { () -> Range<UInt8> in let val = arg; return val.start..<val.end }()); return __private__FfiRangeU8(start: val.lowerBound, end: val.upperBound, inclusive: false) }()
+}
+"#,
+        )
+    }
+
+    #[test]
+    fn extern_swift_fn_range_round_trip() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: ExpectedCHeader::SkipTest,
+        }
+        .test();
+    }
+}
@@ -0,0 +1,114 @@
+use super::{CodegenTest, ExpectedCHeader, ExpectedRustTokens, ExpectedSwiftCode};
+use proc_macro2::TokenStream;
+use quote::quote;
+
+/// Verify that we can take `&mut u32` out-parameters in an extern "Rust" function, which Swift
+/// passes as `inout` parameters.
+mod extern_rust_fn_ref_mut_primitive_out_params {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Rust" {
+                    fn divmod(a: u32, b: u32, q: &mut u32, r: &mut u32);
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::Contains(quote! {
+            #[export_name = "__swift_bridge__$divmod"]
+            pub extern "C" fn __swift_bridge__divmod(
+                a: u32,
+                b: u32,
+                q: *mut u32,
+                r: *mut u32
+            ) {
+                super::divmod(a, b, unsafe { &mut * q }, unsafe { &mut * r })
+            }
+        })
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+public func divmod(_ a: UInt32, _ b: UInt32, _ q: inout UInt32, _ r: inout UInt32) {
+    __swift_bridge__$divmod(a, b, &q, &r)
+}
+"#,
+        )
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsAfterTrim(
+            r#"
+void __swift_bridge__$divmod(uint32_t a, uint32_t b, uint32_t* q, uint32_t* r);
+"#,
+        )
+    }
+
+    #[test]
+    fn extern_rust_fn_ref_mut_primitive_out_params() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Verify that we can pass `&mut f64` and `&mut bool` arguments to an extern "Swift" function,
+/// which receives them as `inout` parameters.
+mod extern_swift_fn_ref_mut_primitive_args {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Swift" {
+                    fn fill(value: &mut f64, found: &mut bool);
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::ContainsMany(vec![
+            quote! {
+                pub fn fill(value: &mut f64, found: &mut bool) {
+                    unsafe { __swift_bridge__fill(value as *mut f64, found as *mut bool) }
+                }
+            },
+            quote! {
+                #[link_name = "__swift_bridge__$fill"]
+                fn __swift_bridge__fill(value: *mut f64, found: *mut bool);
+            },
+        ])
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+@_cdecl("__swift_bridge__$fill")
+func __swift_bridge__fill (_ value: UnsafeMutablePointer<Double>, _ found: UnsafeMutablePointer<Bool>) {
+    fill(value: &value.pointee, found: &found.pointee)
+}
+"#,
+        )
+    }
+
+    #[test]
+    fn extern_swift_fn_ref_mut_primitive_args() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: ExpectedCHeader::SkipTest,
+        }
+        .test();
+    }
+}
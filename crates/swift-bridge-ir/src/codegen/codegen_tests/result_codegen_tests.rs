@@ -0,0 +1,384 @@
+//! See also: crates/swift-integration-tests/src/result.rs
+
+use super::{CodegenTest, ExpectedCHeader, ExpectedRustTokens, ExpectedSwiftCode};
+use proc_macro2::TokenStream;
+use quote::quote;
+
+/// Test code generation for Rust function that returns a Result where the Ok and Err types are
+/// both opaque Rust types.
+mod extern_rust_fn_return_result_opaque_ok_opaque_err {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Rust" {
+                    type SomeType;
+                    type SomeError;
+
+                    fn some_function() -> Result<SomeType, SomeError>;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::ContainsMany(vec![
+            quote! {
+                #[repr(C)]
+                #[doc(hidden)]
+                #[allow(dead_code)]
+                pub enum __swift_bridge__ResultSomeTypeAndSomeError {
+                    Ok(*mut super::SomeType),
+                    Err(*mut super::SomeError)
+                }
+            },
+            quote! {
+                #[export_name = "__swift_bridge__$some_function"]
+                pub extern "C" fn __swift_bridge__some_function() -> __swift_bridge__ResultSomeTypeAndSomeError {
+                    match super::some_function() {
+                        Ok(ok) => __swift_bridge__ResultSomeTypeAndSomeError::Ok(
+                            Box::into_raw(Box::new(ok)) as *mut super::SomeType
+                        ),
+                        Err(err) => __swift_bridge__ResultSomeTypeAndSomeError::Err(
+                            Box::into_raw(Box::new(err)) as *mut super::SomeError
+                        )
+                    }
+                }
+            },
+        ])
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+public func some_function() throws -> SomeType {
+    try { () throws -> SomeType in let val = __swift_bridge__$some_function(); if val.tag == __swift_bridge__$ResultSomeTypeAndSomeError$ResultOk { return SomeType(ptr: val.payload.ok) } else { throw SomeError(ptr: val.payload.err) } }()
+}
+"#,
+        )
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsManyAfterTrim(vec![
+            r#"
+typedef enum __swift_bridge__$ResultSomeTypeAndSomeError$Tag { __swift_bridge__$ResultSomeTypeAndSomeError$ResultOk, __swift_bridge__$ResultSomeTypeAndSomeError$ResultErr } __swift_bridge__$ResultSomeTypeAndSomeError$Tag;
+union __swift_bridge__$ResultSomeTypeAndSomeError$Fields { void* ok; void* err; };
+typedef struct __swift_bridge__$ResultSomeTypeAndSomeError { __swift_bridge__$ResultSomeTypeAndSomeError$Tag tag; union __swift_bridge__$ResultSomeTypeAndSomeError$Fields payload; } __swift_bridge__$ResultSomeTypeAndSomeError;
+"#,
+            r#"
+struct __swift_bridge__$ResultSomeTypeAndSomeError __swift_bridge__$some_function(void);
+"#,
+        ])
+    }
+
+    #[test]
+    fn extern_rust_fn_return_result_opaque_ok_opaque_err() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Test code generation for Rust function that returns a Result where the Ok and Err types are
+/// both shared structs.
+mod extern_rust_fn_return_result_shared_struct_ok_shared_struct_err {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                #[swift_bridge(swift_repr = "struct")]
+                struct SomeStruct {
+                    field: u8,
+                }
+
+                #[swift_bridge(swift_repr = "struct")]
+                struct SomeError {
+                    code: u32,
+                }
+
+                extern "Rust" {
+                    fn some_function() -> Result<SomeStruct, SomeError>;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::ContainsMany(vec![
+            quote! {
+                #[repr(C)]
+                #[doc(hidden)]
+                #[allow(dead_code)]
+                pub enum __swift_bridge__ResultSomeStructAndSomeError {
+                    Ok(__swift_bridge__SomeStruct),
+                    Err(__swift_bridge__SomeError)
+                }
+            },
+            quote! {
+                pub extern "C" fn __swift_bridge__some_function() -> __swift_bridge__ResultSomeStructAndSomeError {
+                    match super::some_function() {
+                        Ok(ok) => __swift_bridge__ResultSomeStructAndSomeError::Ok(ok.into_ffi_repr()),
+                        Err(err) => __swift_bridge__ResultSomeStructAndSomeError::Err(err.into_ffi_repr())
+                    }
+                }
+            },
+        ])
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+public func some_function() throws -> SomeStruct {
+    try { () throws -> SomeStruct in let val = __swift_bridge__$some_function(); if val.tag == __swift_bridge__$ResultSomeStructAndSomeError$ResultOk { return val.payload.ok.intoSwiftRepr() } else { throw val.payload.err.intoSwiftRepr() } }()
+}
+"#,
+        )
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsAfterTrim(
+            r#"
+union __swift_bridge__$ResultSomeStructAndSomeError$Fields { struct __swift_bridge__$SomeStruct ok; struct __swift_bridge__$SomeError err; };
+"#,
+        )
+    }
+
+    #[test]
+    fn extern_rust_fn_return_result_shared_struct_ok_shared_struct_err() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Test code generation for Rust function that returns a Result where the Ok type is a primitive.
+mod extern_rust_fn_return_result_primitive_ok {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Rust" {
+                    type SomeError;
+
+                    fn some_function(arg: &str) -> Result<u32, SomeError>;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::ContainsMany(vec![
+            quote! {
+                pub enum __swift_bridge__ResultUInt32AndSomeError {
+                    Ok(u32),
+                    Err(*mut super::SomeError)
+                }
+            },
+            quote! {
+                pub extern "C" fn __swift_bridge__some_function(
+                    arg: swift_bridge::string::RustStr
+                ) -> __swift_bridge__ResultUInt32AndSomeError {
+                    match super::some_function(arg.to_str()) {
+                        Ok(ok) => __swift_bridge__ResultUInt32AndSomeError::Ok(ok),
+                        Err(err) => __swift_bridge__ResultUInt32AndSomeError::Err(
+                            Box::into_raw(Box::new(err)) as *mut super::SomeError
+                        )
+                    }
+                }
+            },
+        ])
+    }
+
+    // We unwrap the Result outside of the `toRustStr` closure since it doesn't rethrow.
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+public func some_function<GenericToRustStr: ToRustStr>(_ arg: GenericToRustStr) throws -> UInt32 {
+    try { () throws -> UInt32 in let val = arg.toRustStr({ argAsRustStr in
+        __swift_bridge__$some_function(argAsRustStr)
+    }); if val.tag == __swift_bridge__$ResultUInt32AndSomeError$ResultOk { return val.payload.ok } else { throw SomeError(ptr: val.payload.err) } }()
+}
+"#,
+        )
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsManyAfterTrim(vec![
+            r#"
+union __swift_bridge__$ResultUInt32AndSomeError$Fields { uint32_t ok; void* err; };
+"#,
+            "#include <stdint.h>",
+        ])
+    }
+
+    #[test]
+    fn extern_rust_fn_return_result_primitive_ok() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Test code generation for Rust function that returns a `Result<(), E>`.
+mod extern_rust_fn_return_result_unit_ok {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Rust" {
+                    type SomeError;
+
+                    fn some_function() -> Result<(), SomeError>;
+                    fn another_function() -> Result<(), SomeError>;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::ContainsMany(vec![
+            quote! {
+                #[repr(C)]
+                #[doc(hidden)]
+                #[allow(dead_code)]
+                pub enum __swift_bridge__ResultVoidAndSomeError {
+                    Ok,
+                    Err(*mut super::SomeError)
+                }
+
+                #[export_name = "__swift_bridge__$some_function"]
+            },
+            quote! {
+                pub extern "C" fn __swift_bridge__some_function() -> __swift_bridge__ResultVoidAndSomeError {
+                    match super::some_function() {
+                        Ok(()) => __swift_bridge__ResultVoidAndSomeError::Ok,
+                        Err(err) => __swift_bridge__ResultVoidAndSomeError::Err(
+                            Box::into_raw(Box::new(err)) as *mut super::SomeError
+                        )
+                    }
+                }
+            },
+        ])
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+public func some_function() throws {
+    try { () throws -> () in let val = __swift_bridge__$some_function(); if val.tag == __swift_bridge__$ResultVoidAndSomeError$ResultOk { return } else { throw SomeError(ptr: val.payload.err) } }()
+}
+"#,
+        )
+    }
+
+    /// The tagged union only gets declared once even though two functions return it.
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsAfterTrim(
+            r#"
+typedef struct __swift_bridge__$ResultVoidAndSomeError { __swift_bridge__$ResultVoidAndSomeError$Tag tag; union __swift_bridge__$ResultVoidAndSomeError$Fields payload; } __swift_bridge__$ResultVoidAndSomeError;
+struct __swift_bridge__$ResultVoidAndSomeError __swift_bridge__$some_function(void);
+struct __swift_bridge__$ResultVoidAndSomeError __swift_bridge__$another_function(void);
+"#,
+        )
+    }
+
+    #[test]
+    fn extern_rust_fn_return_result_unit_ok() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Verify that a method that returns a `Result<(), E>` with a shared type as the error becomes a
+/// throwing Swift method that doesn't return a value, and that the error gets thrown.
+mod extern_rust_method_return_result_unit_ok_shared_err {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                enum SaveError {
+                    Full,
+                    Io(String),
+                }
+
+                extern "Rust" {
+                    type Db;
+
+                    fn save(&mut self) -> Result<(), SaveError>;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::ContainsMany(vec![
+            quote! {
+                pub enum __swift_bridge__ResultVoidAndSaveError {
+                    Ok,
+                    Err(__swift_bridge__SaveError)
+                }
+            },
+            quote! {
+                pub extern "C" fn __swift_bridge__Db_save(
+                    this: *mut super::Db
+                ) -> __swift_bridge__ResultVoidAndSaveError {
+                    match (unsafe { &mut * this }).save() {
+                        Ok(()) => __swift_bridge__ResultVoidAndSaveError::Ok,
+                        Err(err) => __swift_bridge__ResultVoidAndSaveError::Err(err.into_ffi_repr())
+                    }
+                }
+            },
+        ])
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+extension DbRefMut {
+    public func save() throws {
+        try { () throws -> () in let val = __swift_bridge__$Db$save(ptr); if val.tag == __swift_bridge__$ResultVoidAndSaveError$ResultOk { return } else { throw val.payload.err.intoSwiftRepr() } }()
+    }
+}
+"#,
+        )
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsAfterTrim(
+            "struct __swift_bridge__$ResultVoidAndSaveError __swift_bridge__$Db$save(void* self);",
+        )
+    }
+
+    #[test]
+    fn extern_rust_method_return_result_unit_ok_shared_err() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
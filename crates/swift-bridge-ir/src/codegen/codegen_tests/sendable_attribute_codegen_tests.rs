@@ -0,0 +1,149 @@
+use super::{CodegenTest, ExpectedCHeader, ExpectedRustTokens, ExpectedSwiftCode};
+use proc_macro2::TokenStream;
+use quote::quote;
+
+/// Verify that we generate a `Sendable` conformance for a shared struct that has the
+/// `#[swift_bridge(Sendable)]` attribute.
+mod shared_struct_sendable_attribute {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                #[swift_bridge(Sendable, swift_repr = "struct")]
+                struct SomeStruct {
+                    field: u8,
+                }
+            }
+        }
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim("extension SomeStruct: Sendable {}")
+    }
+
+    #[test]
+    fn shared_struct_sendable_attribute() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: ExpectedRustTokens::SkipTest,
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: ExpectedCHeader::SkipTest,
+        }
+        .test();
+    }
+}
+
+/// Verify that we generate a `Sendable` conformance for a shared enum that has the
+/// `#[swift_bridge(Sendable)]` attribute.
+mod shared_enum_sendable_attribute {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                #[swift_bridge(Sendable)]
+                enum SomeEnum {
+                    Variant1,
+                    Variant2(u8),
+                }
+            }
+        }
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim("extension SomeEnum: Sendable {}")
+    }
+
+    #[test]
+    fn shared_enum_sendable_attribute() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: ExpectedRustTokens::SkipTest,
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: ExpectedCHeader::SkipTest,
+        }
+        .test();
+    }
+}
+
+/// Verify that we generate an `@unchecked Sendable` conformance for an opaque Rust type that has
+/// the `#[swift_bridge(Sendable)]` attribute.
+mod opaque_rust_type_sendable_attribute {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Rust" {
+                    #[swift_bridge(Sendable)]
+                    type SomeType;
+                }
+            }
+        }
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+            __swift_bridge__$SomeType$_free(ptr)
+        }
+    }
+}
+extension SomeType: @unchecked Sendable {}
+"#,
+        )
+    }
+
+    #[test]
+    fn opaque_rust_type_sendable_attribute() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: ExpectedRustTokens::SkipTest,
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: ExpectedCHeader::SkipTest,
+        }
+        .test();
+    }
+}
+
+/// Verify that we do not generate `Sendable` conformances for types without the
+/// `#[swift_bridge(Sendable)]` attribute.
+mod no_sendable_attribute {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                #[swift_bridge(swift_repr = "struct")]
+                struct SomeStruct {
+                    field: u8,
+                }
+
+                enum SomeEnum {
+                    Variant1,
+                    Variant2(u8),
+                }
+
+                extern "Rust" {
+                    type SomeType;
+                }
+            }
+        }
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::DoesNotContainAfterTrim("Sendable")
+    }
+
+    #[test]
+    fn no_sendable_attribute() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: ExpectedRustTokens::SkipTest,
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: ExpectedCHeader::SkipTest,
+        }
+        .test();
+    }
+}
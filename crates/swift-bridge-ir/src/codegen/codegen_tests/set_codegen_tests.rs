@@ -0,0 +1,185 @@
+//! See also: crates/swift-integration-tests/src/set.rs
+
+use super::{CodegenTest, ExpectedCHeader, ExpectedRustTokens, ExpectedSwiftCode};
+use proc_macro2::TokenStream;
+use quote::quote;
+
+/// Test code generation for Rust function that returns a HashSet of primitives.
+mod extern_rust_fn_return_hashset_of_primitives {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Rust" {
+                    fn some_function() -> HashSet<u64>;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::Contains(quote! {
+            pub extern "C" fn __swift_bridge__some_function() -> swift_bridge::set::FfiSet<u64> {
+                swift_bridge::set::FfiSet::from_elems(
+                    super::some_function().into_iter().map(|elem| elem)
+                )
+            }
+        })
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+public func some_function() -> Set<UInt64> {
+    __swift_bridge__$some_function().toSet({ (elem: UInt64) in elem })
+}
+"#,
+        )
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsAfterTrim(
+            r#"
+struct __private__FfiSet __swift_bridge__$some_function(void);
+"#,
+        )
+    }
+
+    #[test]
+    fn extern_rust_fn_return_hashset_of_primitives() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Test code generation for Rust function that has a HashSet<String> argument.
+mod extern_rust_fn_arg_hashset_of_strings {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Rust" {
+                    fn some_function(arg: HashSet<String>);
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::Contains(quote! {
+            pub extern "C" fn __swift_bridge__some_function(
+                arg: swift_bridge::set::FfiSet<*mut swift_bridge::string::RustString>
+            ) {
+                super::some_function(
+                    arg
+                        .into_elems()
+                        .map(|elem| unsafe { Box::from_raw(elem).0 })
+                        .collect::<std::collections::HashSet<String> >()
+                )
+            }
+        })
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+public func some_function(_ arg: Set<String>) {
+    arg.toFfiSet({ (elem: String) -> UnsafeMutableRawPointer in { let rustString = elem.intoRustString(); rustString.isOwned = false; return rustString.ptr }() }, { argAsFfiSet in
+        __swift_bridge__$some_function(argAsFfiSet)
+    })
+}
+"#,
+        )
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsAfterTrim(
+            r#"
+void __swift_bridge__$some_function(struct __private__FfiSet arg);
+"#,
+        )
+    }
+
+    #[test]
+    fn extern_rust_fn_arg_hashset_of_strings() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Test code generation for Rust function that takes and returns a BTreeSet.
+mod extern_rust_fn_btreeset {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Rust" {
+                    fn some_function(arg: BTreeSet<u32>) -> BTreeSet<String>;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::Contains(quote! {
+            pub extern "C" fn __swift_bridge__some_function(
+                arg: swift_bridge::set::FfiSet<u32>
+            ) -> swift_bridge::set::FfiSet<*mut swift_bridge::string::RustString> {
+                swift_bridge::set::FfiSet::from_elems(
+                    super::some_function(
+                        arg
+                            .into_elems()
+                            .map(|elem| elem)
+                            .collect::<std::collections::BTreeSet<u32> >()
+                    )
+                    .into_iter()
+                    .map(|elem| swift_bridge::string::RustString(elem).box_into_raw())
+                )
+            }
+        })
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+public func some_function(_ arg: Set<UInt32>) -> Set<String> {
+    return arg.toFfiSet({ (elem: UInt32) -> UInt32 in elem }, { argAsFfiSet in
+        __swift_bridge__$some_function(argAsFfiSet).toSet({ (elem: UnsafeMutableRawPointer) in RustString(ptr: elem).toString() })
+    })
+}
+"#,
+        )
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsAfterTrim(
+            r#"
+struct __private__FfiSet __swift_bridge__$some_function(struct __private__FfiSet arg);
+"#,
+        )
+    }
+
+    #[test]
+    fn extern_rust_fn_btreeset() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
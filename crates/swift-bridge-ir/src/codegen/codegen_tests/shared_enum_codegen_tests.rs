@@ -117,6 +117,141 @@ typedef struct __swift_bridge__$Option$SomeEnum { bool is_some; __swift_bridge__
     }
 }
 
+/// Verify that we generate the correct to_ffi_repr() and to_rust_repr() implementations for an
+/// enum where the variants contain named data, unnamed data and no data.
+mod generates_enum_to_and_from_ffi_conversions_with_data {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                enum SomeEnum {
+                    Named { a: u8, b: String },
+                    Unnamed(i32, bool),
+                    NoData,
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::Contains(quote! {
+            pub enum SomeEnum {
+                Named { a: u8, b: String },
+                Unnamed(i32, bool),
+                NoData
+            }
+
+            #[repr(C)]
+            #[doc(hidden)]
+            pub enum __swift_bridge__SomeEnum {
+                Named { a: u8, b: *mut swift_bridge::string::RustString },
+                Unnamed(i32, bool),
+                NoData
+            }
+
+            impl swift_bridge::SharedEnum for SomeEnum {
+                type FfiRepr = __swift_bridge__SomeEnum;
+            }
+
+            impl SomeEnum {
+                #[doc(hidden)]
+                #[inline(always)]
+                pub fn into_ffi_repr(self) -> __swift_bridge__SomeEnum {
+                    match self {
+                        SomeEnum::Named { a, b } => __swift_bridge__SomeEnum::Named {
+                            a: a,
+                            b: swift_bridge::string::RustString(b).box_into_raw()
+                        },
+                        SomeEnum::Unnamed(_0, _1) => __swift_bridge__SomeEnum::Unnamed(_0, _1),
+                        SomeEnum::NoData => __swift_bridge__SomeEnum::NoData
+                    }
+                }
+            }
+
+            impl __swift_bridge__SomeEnum {
+                #[doc(hidden)]
+                #[inline(always)]
+                pub fn into_rust_repr(self) -> SomeEnum {
+                    match self {
+                        __swift_bridge__SomeEnum::Named { a, b } => SomeEnum::Named {
+                            a: a,
+                            b: unsafe { Box::from_raw(b).0 }
+                        },
+                        __swift_bridge__SomeEnum::Unnamed(_0, _1) => SomeEnum::Unnamed(_0, _1),
+                        __swift_bridge__SomeEnum::NoData => SomeEnum::NoData
+                    }
+                }
+            }
+        })
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+public enum SomeEnum {
+    case Named(a: UInt8, b: RustString)
+    case Unnamed(Int32, Bool)
+    case NoData
+}
+extension SomeEnum {
+    func intoFfiRepr() -> __swift_bridge__$SomeEnum {
+        switch self {
+            case SomeEnum.Named(let a, let b):
+                return __swift_bridge__$SomeEnum(tag: __swift_bridge__$SomeEnum$Named, payload: __swift_bridge__$SomeEnumFields(Named: __swift_bridge__$SomeEnum$FieldOfNamed(a: a, b: { let rustString = b.intoRustString(); rustString.isOwned = false; return rustString.ptr }())))
+            case SomeEnum.Unnamed(let _0, let _1):
+                return __swift_bridge__$SomeEnum(tag: __swift_bridge__$SomeEnum$Unnamed, payload: __swift_bridge__$SomeEnumFields(Unnamed: __swift_bridge__$SomeEnum$FieldOfUnnamed(_0: _0, _1: _1)))
+            case SomeEnum.NoData:
+                return __swift_bridge__$SomeEnum(tag: __swift_bridge__$SomeEnum$NoData, payload: __swift_bridge__$SomeEnumFields())
+        }
+    }
+}
+extension __swift_bridge__$SomeEnum {
+    func intoSwiftRepr() -> SomeEnum {
+        switch self.tag {
+            case __swift_bridge__$SomeEnum$Named:
+                return SomeEnum.Named(a: self.payload.Named.a, b: RustString(ptr: self.payload.Named.b))
+            case __swift_bridge__$SomeEnum$Unnamed:
+                return SomeEnum.Unnamed(self.payload.Unnamed._0, self.payload.Unnamed._1)
+            case __swift_bridge__$SomeEnum$NoData:
+                return SomeEnum.NoData
+            default:
+                fatalError("Unreachable")
+        }
+    }
+}
+"#,
+        )
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ExactAfterTrim(
+            r#"
+#include <stdint.h>
+#include <stdbool.h>
+typedef enum __swift_bridge__$SomeEnumTag { __swift_bridge__$SomeEnum$Named, __swift_bridge__$SomeEnum$Unnamed, __swift_bridge__$SomeEnum$NoData, } __swift_bridge__$SomeEnumTag;
+typedef struct __swift_bridge__$SomeEnum$FieldOfNamed { uint8_t a; void* b; } __swift_bridge__$SomeEnum$FieldOfNamed;
+typedef struct __swift_bridge__$SomeEnum$FieldOfUnnamed { int32_t _0; bool _1; } __swift_bridge__$SomeEnum$FieldOfUnnamed;
+union __swift_bridge__$SomeEnumFields { __swift_bridge__$SomeEnum$FieldOfNamed Named; __swift_bridge__$SomeEnum$FieldOfUnnamed Unnamed; };
+typedef struct __swift_bridge__$SomeEnum { __swift_bridge__$SomeEnumTag tag; union __swift_bridge__$SomeEnumFields payload; } __swift_bridge__$SomeEnum;
+typedef struct __swift_bridge__$Option$SomeEnum { bool is_some; __swift_bridge__$SomeEnum val; } __swift_bridge__$Option$SomeEnum;
+"#,
+        )
+    }
+
+    #[test]
+    fn generates_enum_to_and_from_ffi_conversions_with_data() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
 /// Verify that we generate the correct code for a function that has an enum as an argument and
 /// returns an enum.
 mod using_enum_in_extern_rust_fn {
@@ -296,3 +431,531 @@ struct __swift_bridge__$Option$SomeEnum __swift_bridge__$some_function(struct __
         .test();
     }
 }
+
+/// Verify that we generate a `Hashable` conformance for an enum with the
+/// `#[swift_bridge(Hashable)]` attribute.
+mod enum_hashable_attribute {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                #[swift_bridge(Hashable)]
+                enum MyEnum {
+                    Variant1,
+                    Variant2,
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::Contains(quote! {
+            pub enum MyEnum {
+                Variant1,
+                Variant2
+            }
+        })
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+extension MyEnum: Hashable {}
+"#,
+        )
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::SkipTest
+    }
+
+    #[test]
+    fn enum_hashable_attribute() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Verify that we do not generate a `Hashable` conformance for an enum with the
+/// `#[swift_bridge(Hashable)]` attribute if one of its associated values is not `Hashable`.
+mod enum_hashable_attribute_with_unhashable_data {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                #[swift_bridge(Hashable)]
+                enum MyEnum {
+                    Variant1(u8),
+                    Variant2(String),
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::SkipTest
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::DoesNotContainAfterTrim("Hashable")
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::SkipTest
+    }
+
+    #[test]
+    fn enum_hashable_attribute_with_unhashable_data() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Verify that we generate a `Comparable` conformance for an enum with the
+/// `#[swift_bridge(Comparable)]` attribute that compares the variants' raw values, including the
+/// variants whose discriminants are implicit.
+mod enum_comparable_attribute {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                #[swift_bridge(Comparable)]
+                enum Level {
+                    Low = 1,
+                    Medium,
+                    High = 10,
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::Contains(quote! {
+            #[repr(i32)]
+            pub enum Level {
+                Low = 1,
+                Medium,
+                High = 10
+            }
+        })
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsManyAfterTrim(vec![
+            r#"
+public enum Level: Int32 {
+    case Low = 1
+    case Medium
+    case High = 10
+}
+"#,
+            r#"
+extension Level: Comparable {
+    public static func <(lhs: Level, rhs: Level) -> Bool {
+        lhs.rawValue < rhs.rawValue
+    }
+}
+"#,
+        ])
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::SkipTest
+    }
+
+    #[test]
+    fn enum_comparable_attribute() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Verify that a `Comparable` enum without explicit discriminants gets an `Int32` raw value, so
+/// that its variants are ordered by their implicit discriminants.
+mod enum_comparable_attribute_implicit_discriminants {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                #[swift_bridge(Comparable)]
+                enum Level {
+                    Low,
+                    High,
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::Contains(quote! {
+            pub enum Level {
+                Low,
+                High
+            }
+        })
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsManyAfterTrim(vec![
+            r#"
+public enum Level: Int32 {
+    case Low
+    case High
+}
+"#,
+            r#"
+extension Level: Comparable {
+"#,
+        ])
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::SkipTest
+    }
+
+    #[test]
+    fn enum_comparable_attribute_implicit_discriminants() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Verify that we generate a `LocalizedError` conformance for an enum with the
+/// `#[swift_bridge(LocalizedError)]` attribute, using a variant's associated `String` as its
+/// description and falling back to the variant's name.
+mod enum_localized_error_attribute {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                #[swift_bridge(LocalizedError)]
+                enum MyError {
+                    NotFound,
+                    Io(String),
+                    Parse { line: u32, message: String },
+                    Code(i32),
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::SkipTest
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsManyAfterTrim(vec![
+            "import Foundation",
+            r#"
+extension MyError: LocalizedError {
+    public var errorDescription: String? {
+        switch self {
+        case .NotFound:
+            return "NotFound"
+        case .Io(let message):
+            return message.toString()
+        case .Parse(_, let message):
+            return message.toString()
+        case .Code:
+            return "Code"
+        }
+    }
+}
+"#,
+        ])
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::SkipTest
+    }
+
+    #[test]
+    fn enum_localized_error_attribute() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Verify that we carry over explicit discriminants to the Rust enum's `#[repr(i32)]` values and
+/// to the Swift enum's `Int32` raw values.
+mod enum_explicit_discriminants {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                enum Code {
+                    Ok = 0,
+                    Unknown,
+                    NotFound = 404,
+                    Negative = -1,
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::Contains(quote! {
+            #[repr(i32)]
+            pub enum Code {
+                Ok = 0,
+                Unknown,
+                NotFound = 404,
+                Negative = -1
+            }
+
+            #[repr(C)]
+            #[doc(hidden)]
+            pub enum __swift_bridge__Code {
+                Ok,
+                Unknown,
+                NotFound,
+                Negative
+            }
+        })
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+public enum Code: Int32 {
+    case Ok = 0
+    case Unknown
+    case NotFound = 404
+    case Negative = -1
+}
+"#,
+        )
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::SkipTest
+    }
+
+    #[test]
+    fn enum_explicit_discriminants() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Verify that a transparent enum is passed over FFI as its `i32` discriminant, instead of as a
+/// tagged union, and that Swift converts it using its raw value.
+mod enum_transparent_attribute {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                #[swift_bridge(transparent)]
+                enum Flags {
+                    Read = 1,
+                    Write = 2,
+                    Execute = 4,
+                }
+
+                extern "Rust" {
+                    fn some_function(flags: Flags) -> Flags;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::ContainsMany(vec![
+            quote! {
+                #[repr(i32)]
+                pub enum Flags {
+                    Read = 1,
+                    Write = 2,
+                    Execute = 4
+                }
+
+                #[repr(transparent)]
+                #[doc(hidden)]
+                pub struct __swift_bridge__Flags(i32);
+
+                impl swift_bridge::SharedEnum for Flags {
+                    type FfiRepr = __swift_bridge__Flags;
+                }
+
+                impl Flags {
+                    #[doc(hidden)]
+                    #[inline(always)]
+                    pub fn into_ffi_repr(self) -> __swift_bridge__Flags {
+                        __swift_bridge__Flags(self as i32)
+                    }
+                }
+
+                impl __swift_bridge__Flags {
+                    #[doc(hidden)]
+                    #[inline(always)]
+                    pub fn into_rust_repr(self) -> Flags {
+                        match self.0 {
+                            1 => Flags::Read,
+                            2 => Flags::Write,
+                            4 => Flags::Execute,
+                            _ => unreachable!(),
+                        }
+                    }
+                }
+            },
+            quote! {
+                pub extern "C" fn __swift_bridge__some_function(
+                    flags: __swift_bridge__Flags
+                ) -> __swift_bridge__Flags {
+                    super::some_function(flags.into_rust_repr()).into_ffi_repr()
+                }
+            },
+        ])
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsManyAfterTrim(vec![
+            r#"
+public func some_function(_ flags: Flags) -> Flags {
+    Flags(rawValue: __swift_bridge__$some_function(flags.intoFfiRepr()))!
+}
+"#,
+            r#"
+public enum Flags: Int32 {
+    case Read = 1
+    case Write = 2
+    case Execute = 4
+}
+extension Flags {
+    func intoFfiRepr() -> __swift_bridge__$Flags {
+        self.rawValue
+    }
+}
+extension __swift_bridge__$Option$Flags {
+    @inline(__always)
+    func intoSwiftRepr() -> Optional<Flags> {
+        if self.is_some {
+            return Flags(rawValue: self.val)!
+        } else {
+            return nil
+        }
+    }
+"#,
+        ])
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsManyAfterTrim(vec![
+            r#"
+typedef enum __swift_bridge__$FlagsTag { __swift_bridge__$Flags$Read = 1, __swift_bridge__$Flags$Write = 2, __swift_bridge__$Flags$Execute = 4, } __swift_bridge__$FlagsTag;
+typedef int32_t __swift_bridge__$Flags;
+typedef struct __swift_bridge__$Option$Flags { bool is_some; __swift_bridge__$Flags val; } __swift_bridge__$Option$Flags;
+"#,
+            r#"
+__swift_bridge__$Flags __swift_bridge__$some_function(__swift_bridge__$Flags flags);
+"#,
+        ])
+    }
+
+    #[test]
+    fn enum_transparent_attribute() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Verify that we declare a C enum with the discriminants of a transparent enum's variants,
+/// including the variants whose discriminants are implicit.
+mod enum_transparent_c_enum {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                #[swift_bridge(transparent)]
+                enum Level {
+                    Low,
+                    High = 5,
+                    Higher,
+                }
+            }
+        }
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+public enum Level: Int32 {
+    case Low = 0
+    case High = 5
+    case Higher = 6
+}
+"#,
+        )
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsAfterTrim(
+            r#"
+typedef enum __swift_bridge__$LevelTag { __swift_bridge__$Level$Low = 0, __swift_bridge__$Level$High = 5, __swift_bridge__$Level$Higher = 6, } __swift_bridge__$LevelTag;
+typedef int32_t __swift_bridge__$Level;
+"#,
+        )
+    }
+
+    #[test]
+    fn enum_transparent_c_enum() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: ExpectedRustTokens::SkipTest,
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
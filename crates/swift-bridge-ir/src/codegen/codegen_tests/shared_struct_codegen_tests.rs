@@ -688,3 +688,462 @@ struct __swift_bridge__$Option$SomeStruct __swift_bridge__$some_function(struct
         .test();
     }
 }
+
+/// Verify that we generate an `Equatable` conformance for a struct with the `Equatable` attribute
+/// when all of its fields are `Equatable`, including `Option<T>` fields and other `Equatable`
+/// shared structs.
+mod shared_struct_equatable_attribute {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                #[swift_bridge(swift_repr = "struct", Equatable)]
+                struct InnerStruct {
+                    field: u8,
+                }
+
+                #[swift_bridge(swift_repr = "struct", Equatable)]
+                struct SomeStruct {
+                    field: u8,
+                    optional: Option<u32>,
+                    inner: InnerStruct,
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::SkipTest
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsManyAfterTrim(vec![
+            r#"
+extension InnerStruct: Equatable {
+    public static func == (lhs: InnerStruct, rhs: InnerStruct) -> Bool {
+        return lhs.field == rhs.field
+    }
+}
+"#,
+            r#"
+extension SomeStruct: Equatable {
+    public static func == (lhs: SomeStruct, rhs: SomeStruct) -> Bool {
+        return lhs.field == rhs.field && lhs.optional == rhs.optional && lhs.inner == rhs.inner
+    }
+}
+"#,
+        ])
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::SkipTest
+    }
+
+    #[test]
+    fn shared_struct_equatable_attribute() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Verify that we do not generate an `Equatable` conformance if one of the struct's fields is not
+/// `Equatable`.
+mod shared_struct_equatable_attribute_non_equatable_field {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                #[swift_bridge(swift_repr = "struct")]
+                struct NotEquatable {
+                    field: u8,
+                }
+
+                #[swift_bridge(swift_repr = "struct", Equatable)]
+                struct SomeStruct {
+                    field: u8,
+                    not_equatable: NotEquatable,
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::SkipTest
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::DoesNotContainAfterTrim("extension SomeStruct: Equatable")
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::SkipTest
+    }
+
+    #[test]
+    fn shared_struct_equatable_attribute_non_equatable_field() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Verify that we generate a `Codable` conformance for a struct with the `Codable` attribute,
+/// including when one of its fields is another `Codable` shared struct.
+mod shared_struct_codable_attribute {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                #[swift_bridge(swift_repr = "struct", Codable)]
+                struct InnerStruct {
+                    field: u8,
+                }
+
+                #[swift_bridge(swift_repr = "struct", Codable)]
+                struct SomeStruct {
+                    field: f64,
+                    optional: Option<u32>,
+                    inner: InnerStruct,
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::SkipTest
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsManyAfterTrim(vec![
+            "extension InnerStruct: Codable {}",
+            "extension SomeStruct: Codable {}",
+        ])
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::SkipTest
+    }
+
+    #[test]
+    fn shared_struct_codable_attribute() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Verify that we generate an `Identifiable` conformance for a struct with an `id` field and the
+/// `Identifiable` attribute.
+mod shared_struct_identifiable_attribute {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                #[swift_bridge(swift_repr = "struct", Identifiable)]
+                struct SomeStruct {
+                    id: u64,
+                    field: u8,
+                }
+            }
+        }
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim("extension SomeStruct: Identifiable {}")
+    }
+
+    #[test]
+    fn shared_struct_identifiable_attribute() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: ExpectedRustTokens::SkipTest,
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: ExpectedCHeader::SkipTest,
+        }
+        .test();
+    }
+}
+
+/// Verify that we generate an `Identifiable` conformance that uses a custom id field for a struct
+/// with the `Identifiable = "..."` attribute.
+mod shared_struct_identifiable_attribute_custom_field {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                #[swift_bridge(swift_repr = "struct", Identifiable = "uuid")]
+                struct SomeStruct {
+                    uuid: u64,
+                    field: u8,
+                }
+            }
+        }
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+extension SomeStruct: Identifiable {
+    public var id: UInt64 {
+        return self.uuid
+    }
+}
+"#,
+        )
+    }
+
+    #[test]
+    fn shared_struct_identifiable_attribute_custom_field() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: ExpectedRustTokens::SkipTest,
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: ExpectedCHeader::SkipTest,
+        }
+        .test();
+    }
+}
+
+/// Verify that the `rename_all = "camelCase"` attribute renames the fields of the generated Swift
+/// struct while the Rust struct and its FFI representation keep their snake_case field names.
+mod shared_struct_rename_all_camel_case_attribute {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                #[swift_bridge(swift_repr = "struct", rename_all = "camelCase", Equatable)]
+                struct SomeStruct {
+                    user_id: u32,
+                    is_active: bool,
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::Contains(quote! {
+            #[repr(C)]
+            #[doc(hidden)]
+            pub struct __swift_bridge__SomeStruct {
+                user_id: u32,
+                is_active: bool
+            }
+        })
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsManyAfterTrim(vec![
+            r#"
+public struct SomeStruct {
+    var userId: UInt32
+    var isActive: Bool
+
+    @inline(__always)
+    func intoFfiRepr() -> __swift_bridge__$SomeStruct {
+        { let val = self; return __swift_bridge__$SomeStruct(user_id: val.userId, is_active: val.isActive); }()
+    }
+}
+extension __swift_bridge__$SomeStruct {
+    @inline(__always)
+    func intoSwiftRepr() -> SomeStruct {
+        { let val = self; return SomeStruct(userId: val.user_id, isActive: val.is_active); }()
+    }
+}
+"#,
+            r#"
+extension SomeStruct: Equatable {
+    public static func == (lhs: SomeStruct, rhs: SomeStruct) -> Bool {
+        return lhs.userId == rhs.userId && lhs.isActive == rhs.isActive
+    }
+}
+"#,
+        ])
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsAfterTrim(
+            r#"
+typedef struct __swift_bridge__$SomeStruct { uint32_t user_id; bool is_active; } __swift_bridge__$SomeStruct;
+"#,
+        )
+    }
+
+    #[test]
+    fn shared_struct_rename_all_camel_case_attribute() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Verify that we generate a Swift `final class` with a memberwise initializer for a struct with
+/// `swift_repr = "class"`, while the FFI representation stays a `#[repr(C)]` struct.
+mod shared_struct_swift_repr_class {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                #[swift_bridge(swift_repr = "class")]
+                struct SomeStruct {
+                    x: f64,
+                    y: f64,
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::Contains(quote! {
+            #[repr(C)]
+            #[doc(hidden)]
+            pub struct __swift_bridge__SomeStruct {
+                x: f64,
+                y: f64
+            }
+        })
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+public final class SomeStruct {
+    var x: Double
+    var y: Double
+
+    public init(x: Double, y: Double) {
+        self.x = x
+        self.y = y
+    }
+
+    @inline(__always)
+    func intoFfiRepr() -> __swift_bridge__$SomeStruct {
+        { let val = self; return __swift_bridge__$SomeStruct(x: val.x, y: val.y); }()
+    }
+}
+extension __swift_bridge__$SomeStruct {
+    @inline(__always)
+    func intoSwiftRepr() -> SomeStruct {
+        { let val = self; return SomeStruct(x: val.x, y: val.y); }()
+    }
+}
+"#,
+        )
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsAfterTrim(
+            r#"
+typedef struct __swift_bridge__$SomeStruct { double x; double y; } __swift_bridge__$SomeStruct;
+"#,
+        )
+    }
+
+    #[test]
+    fn shared_struct_swift_repr_class() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Verify that we generate a `#[repr(C)]` FFI representation for a shared tuple struct, and a
+/// Swift struct with positional members and a positional initializer.
+mod shared_tuple_struct {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                #[swift_bridge(swift_repr = "struct")]
+                struct Point(f32, f32);
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::ContainsMany(vec![
+            quote! {
+                pub struct Point(pub f32, pub f32);
+            },
+            quote! {
+                #[repr(C)]
+                #[doc(hidden)]
+                pub struct __swift_bridge__Point(f32, f32);
+            },
+            quote! {
+                pub fn into_ffi_repr(self) -> __swift_bridge__Point {
+                    { let val = self; __swift_bridge__Point(val.0, val.1) }
+                }
+            },
+            quote! {
+                pub fn into_rust_repr(self) -> Point {
+                    { let val = self; Point(val.0, val.1) }
+                }
+            },
+        ])
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsManyAfterTrim(vec![
+            r#"
+public struct Point {
+    var _0: Float
+    var _1: Float
+"#,
+            r#"
+extension Point {
+    public init(_ _0: Float, _ _1: Float) {
+        self.init(_0: _0, _1: _1)
+    }
+}
+"#,
+        ])
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsAfterTrim(
+            r#"
+typedef struct __swift_bridge__$Point { float _0; float _1; } __swift_bridge__$Point;
+"#,
+        )
+    }
+
+    #[test]
+    fn shared_tuple_struct() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
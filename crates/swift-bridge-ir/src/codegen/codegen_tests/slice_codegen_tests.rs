@@ -0,0 +1,306 @@
+//! See also: crates/swift-integration-tests/src/slice.rs
+
+use super::{CodegenTest, ExpectedCHeader, ExpectedRustTokens, ExpectedSwiftCode};
+use proc_macro2::TokenStream;
+use quote::quote;
+
+/// Test code generation for a Rust function that takes a mutable slice that it writes into.
+mod extern_rust_fn_mut_slice_arg {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Rust" {
+                    fn render(into: &mut [u8]);
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::Contains(quote! {
+            #[export_name = "__swift_bridge__$render"]
+            pub extern "C" fn __swift_bridge__render(
+                into: swift_bridge::FfiSlice<u8>
+            ) {
+                super::render(into.as_mut_slice())
+            }
+        })
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+public func render(_ into: UnsafeMutableBufferPointer<UInt8>) {
+    __swift_bridge__$render(into.toFfiSlice())
+}
+"#,
+        )
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsAfterTrim(
+            r#"
+void __swift_bridge__$render(struct __private__FfiSlice into);
+"#,
+        )
+    }
+
+    #[test]
+    fn extern_rust_fn_mut_slice_arg() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Test code generation for a Rust method that returns a slice that borrows from `&self`.
+///
+/// The slice crosses the FFI boundary as a pointer and a length, so ownership of the memory stays
+/// with the Rust value.
+mod extern_rust_method_return_slice_borrowed_from_self {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Rust" {
+                    type SomeType;
+
+                    fn data(&self) -> &[u8];
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::Contains(quote! {
+            #[export_name = "__swift_bridge__$SomeType$data"]
+            pub extern "C" fn __swift_bridge__SomeType_data(
+                this: *const super::SomeType
+            ) -> swift_bridge::FfiSlice<u8> {
+                swift_bridge::FfiSlice::from_slice((unsafe { &*this }).data())
+            }
+        })
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+public class SomeTypeRef {
+    var ptr: UnsafeMutableRawPointer
+
+    public init(ptr: UnsafeMutableRawPointer) {
+        self.ptr = ptr
+    }
+}
+extension SomeTypeRef {
+    public func data() -> UnsafeBufferPointer<UInt8> {
+        let slice = __swift_bridge__$SomeType$data(ptr); return UnsafeBufferPointer(start: slice.start.assumingMemoryBound(to: UInt8.self), count: Int(slice.len));
+    }
+}
+"#,
+        )
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsAfterTrim(
+            r#"
+struct __private__FfiSlice __swift_bridge__$SomeType$data(void* self);
+"#,
+        )
+    }
+
+    #[test]
+    fn extern_rust_method_return_slice_borrowed_from_self() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Test code generation for a Rust function that returns a mutable slice.
+mod extern_rust_fn_return_mut_slice {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Rust" {
+                    type SomeType;
+
+                    fn buffer(&mut self) -> &mut [u8];
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::Contains(quote! {
+            #[export_name = "__swift_bridge__$SomeType$buffer"]
+            pub extern "C" fn __swift_bridge__SomeType_buffer(
+                this: *mut super::SomeType
+            ) -> swift_bridge::FfiSlice<u8> {
+                swift_bridge::FfiSlice::from_mut_slice((unsafe { &mut *this }).buffer())
+            }
+        })
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+    public func buffer() -> UnsafeMutableBufferPointer<UInt8> {
+        let slice = __swift_bridge__$SomeType$buffer(ptr); return UnsafeMutableBufferPointer(start: slice.start.assumingMemoryBound(to: UInt8.self), count: Int(slice.len));
+    }
+"#,
+        )
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsAfterTrim(
+            r#"
+struct __private__FfiSlice __swift_bridge__$SomeType$buffer(void* self);
+"#,
+        )
+    }
+
+    #[test]
+    fn extern_rust_fn_return_mut_slice() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Test code generation for a Rust function that takes a slice of string slices.
+///
+/// Swift passes an array of `RustStr`s that borrow from its Strings, and the pointers are only
+/// valid for the duration of the call.
+mod extern_rust_fn_str_slice_arg {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Rust" {
+                    fn join(parts: &[&str]) -> String;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::Contains(quote! {
+            #[export_name = "__swift_bridge__$join"]
+            pub extern "C" fn __swift_bridge__join(
+                parts: swift_bridge::FfiSlice<swift_bridge::string::RustStr>
+            ) -> *mut swift_bridge::string::RustString {
+                swift_bridge::string::RustString(super::join(&parts.to_str_vec())).box_into_raw()
+            }
+        })
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+public func join(_ parts: [String]) -> RustString {
+    return parts.toRustStrSlice({ partsAsRustStrSlice in
+        RustString(ptr: __swift_bridge__$join(partsAsRustStrSlice))
+    })
+}
+"#,
+        )
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsAfterTrim(
+            r#"
+void* __swift_bridge__$join(struct __private__FfiSlice parts);
+"#,
+        )
+    }
+
+    #[test]
+    fn extern_rust_fn_str_slice_arg() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Test code generation for a Rust function that takes a `&[u8]` that Swift passes as `Data`.
+///
+/// Swift passes a pointer to the `Data`'s bytes and their length from inside of
+/// `withUnsafeBytes`, so the pointer is only valid for the duration of the call.
+mod extern_rust_fn_u8_slice_arg_as_data {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Rust" {
+                    fn parse(#[swift_bridge(as = "Data")] data: &[u8]) -> u32;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::Contains(quote! {
+            #[export_name = "__swift_bridge__$parse"]
+            pub extern "C" fn __swift_bridge__parse(
+                data: swift_bridge::FfiSlice<u8>
+            ) -> u32 {
+                super::parse(data.as_slice())
+            }
+        })
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+public func parse(_ data: Data) -> UInt32 {
+    return data.withUnsafeBytes({ dataAsBytes in
+        __swift_bridge__$parse(dataAsBytes.toFfiSlice())
+    })
+}
+"#,
+        )
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsAfterTrim(
+            r#"
+uint32_t __swift_bridge__$parse(struct __private__FfiSlice data);
+"#,
+        )
+    }
+
+    #[test]
+    fn extern_rust_fn_u8_slice_arg_as_data() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
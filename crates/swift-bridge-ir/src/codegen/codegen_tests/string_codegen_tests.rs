@@ -3,6 +3,9 @@ use proc_macro2::TokenStream;
 use quote::quote;
 
 /// Test code generation for Rust function that takes an owned String argument.
+///
+/// Swift passes the string to Rust as an `FfiString`, which is only valid inside of the
+/// `toFfiString` closure.
 mod extern_rust_fn_with_owned_string_argument {
     use super::*;
 
@@ -20,9 +23,9 @@ mod extern_rust_fn_with_owned_string_argument {
         ExpectedRustTokens::Contains(quote! {
             #[export_name = "__swift_bridge__$some_function"]
             pub extern "C" fn __swift_bridge__some_function(
-                arg: *mut swift_bridge::string::RustString
+                arg: swift_bridge::string::FfiString
             ) {
-                super::some_function(unsafe { Box::from_raw(arg).0 })
+                super::some_function(arg.into_string())
             }
         })
     }
@@ -31,7 +34,9 @@ mod extern_rust_fn_with_owned_string_argument {
         ExpectedSwiftCode::ContainsAfterTrim(
             r#"
 func some_function<GenericIntoRustString: IntoRustString>(_ arg: GenericIntoRustString) {
-    __swift_bridge__$some_function({ let rustString = arg.intoRustString(); rustString.isOwned = false; return rustString.ptr }())
+    arg.toFfiString({ argAsFfiString in
+        __swift_bridge__$some_function(argAsFfiString)
+    })
 }
 "#,
         )
@@ -40,7 +45,7 @@ func some_function<GenericIntoRustString: IntoRustString>(_ arg: GenericIntoRust
     fn expected_c_header() -> ExpectedCHeader {
         ExpectedCHeader::ExactAfterTrim(
             r#"
-void __swift_bridge__$some_function(void* arg);
+void __swift_bridge__$some_function(struct __private__FfiString arg);
     "#,
         )
     }
@@ -0,0 +1,110 @@
+use super::{CodegenTest, ExpectedCHeader, ExpectedRustTokens, ExpectedSwiftCode};
+use proc_macro2::TokenStream;
+use quote::quote;
+
+/// Verify that the `#[swift_bridge(swift_name = "...")]` attribute on an opaque Rust type renames
+/// the generated Swift classes while the symbols that we link against keep the Rust type's name.
+mod opaque_rust_type_swift_name_attribute {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Rust" {
+                    #[swift_bridge(swift_name = "ImageBuffer")]
+                    type ImgBuf;
+
+                    #[swift_bridge(init)]
+                    fn new() -> ImgBuf;
+                    fn width(&self) -> u32;
+                    fn clone_buf(buf: &ImgBuf) -> ImgBuf;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::ContainsMany(vec![
+            quote! {
+                #[export_name = "__swift_bridge__$ImgBuf$_free"]
+                pub extern "C" fn __swift_bridge__ImgBuf__free (
+                    this: *mut super::ImgBuf
+                ) {
+                    let this = unsafe { Box::from_raw(this) };
+                    drop(this);
+                }
+            },
+            quote! {
+                #[export_name = "__swift_bridge__$ImgBuf$new"]
+                pub extern "C" fn __swift_bridge__ImgBuf_new() -> *mut super::ImgBuf {
+                    Box::into_raw(Box::new(super::ImgBuf::new())) as *mut super::ImgBuf
+                }
+            },
+        ])
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsManyAfterTrim(vec![
+            r#"
+public func clone_buf(_ buf: ImageBufferRef) -> ImageBuffer {
+    ImageBuffer(ptr: __swift_bridge__$clone_buf(buf.ptr))
+}
+"#,
+            r#"
+public class ImageBuffer: ImageBufferRefMut {
+    var isOwned: Bool = true
+
+    public override init(ptr: UnsafeMutableRawPointer) {
+        super.init(ptr: ptr)
+    }
+
+    deinit {
+        if isOwned {
+            __swift_bridge__$ImgBuf$_free(ptr)
+        }
+    }
+}
+extension ImageBuffer {
+    public convenience init() {
+        self.init(ptr: __swift_bridge__$ImgBuf$new())
+    }
+}
+"#,
+            r#"
+extension ImageBufferRef {
+    public func width() -> UInt32 {
+        __swift_bridge__$ImgBuf$width(ptr)
+    }
+}
+"#,
+            r#"
+extension ImageBuffer: Vectorizable {
+    public static func vecOfSelfNew() -> UnsafeMutableRawPointer {
+        __swift_bridge__$Vec_ImgBuf$new()
+    }
+"#,
+        ])
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsManyAfterTrim(vec![
+            r#"
+typedef struct ImgBuf ImgBuf;
+void __swift_bridge__$ImgBuf$_free(void* self);
+"#,
+            "void* __swift_bridge__$ImgBuf$new(void);",
+            "uint32_t __swift_bridge__$ImgBuf$width(void* self);",
+        ])
+    }
+
+    #[test]
+    fn opaque_rust_type_swift_name_attribute() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
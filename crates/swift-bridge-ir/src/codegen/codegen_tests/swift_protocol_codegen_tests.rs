@@ -0,0 +1,55 @@
+use super::{CodegenTest, ExpectedCHeader, ExpectedRustTokens, ExpectedSwiftCode};
+use proc_macro2::TokenStream;
+use quote::quote;
+
+/// Verify that the methods of a Swift type that was declared with a protocol get called through
+/// that protocol.
+mod extern_swift_type_protocol_method {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Swift" {
+                    type SomeType: SomeProtocol;
+
+                    fn protocol_method(&self) -> u32;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::Contains(quote! {
+            impl SomeType {
+                pub fn protocol_method(&self) -> u32 {
+                    unsafe {
+                        __swift_bridge__SomeType_protocol_method(swift_bridge::PointerToSwiftType(self.0))
+                    }
+                }
+            }
+        })
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+@_cdecl("__swift_bridge__$SomeType$protocol_method")
+func __swift_bridge__SomeType_protocol_method (_ this: UnsafeMutableRawPointer) -> UInt32 {
+    (Unmanaged<SomeType>.fromOpaque(this).takeUnretainedValue() as SomeProtocol).protocol_method()
+}
+"#,
+        )
+    }
+
+    #[test]
+    fn extern_swift_type_protocol_method() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: ExpectedCHeader::SkipTest,
+        }
+        .test();
+    }
+}
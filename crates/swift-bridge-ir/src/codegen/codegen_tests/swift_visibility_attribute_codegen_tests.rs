@@ -0,0 +1,180 @@
+use super::{CodegenTest, ExpectedCHeader, ExpectedRustTokens, ExpectedSwiftCode};
+use proc_macro2::TokenStream;
+use quote::quote;
+
+/// Verify that we use the `#[swift_bridge(swift_visibility = "...")]` attribute as the access
+/// modifier of generated Swift functions.
+mod function_swift_visibility_attribute {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Rust" {
+                    #[swift_bridge(swift_visibility = "internal")]
+                    fn a();
+                    #[swift_bridge(swift_visibility = "fileprivate")]
+                    fn b();
+                    #[swift_bridge(swift_visibility = "private")]
+                    fn c();
+                    #[swift_bridge(swift_visibility = "public")]
+                    fn d();
+                    fn e();
+                }
+            }
+        }
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsManyAfterTrim(vec![
+            r#"
+internal func a() {
+    __swift_bridge__$a()
+}
+fileprivate func b() {
+    __swift_bridge__$b()
+}
+private func c() {
+    __swift_bridge__$c()
+}
+public func d() {
+    __swift_bridge__$d()
+}
+public func e() {
+    __swift_bridge__$e()
+}
+"#,
+        ])
+    }
+
+    #[test]
+    fn function_swift_visibility_attribute() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: ExpectedRustTokens::SkipTest,
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: ExpectedCHeader::SkipTest,
+        }
+        .test();
+    }
+}
+
+/// Verify that we use the `#[swift_bridge(swift_visibility = "...")]` attribute as the access
+/// modifier of generated Swift methods and initializers.
+mod method_swift_visibility_attribute {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Rust" {
+                    type SomeType;
+
+                    #[swift_bridge(init, swift_visibility = "internal")]
+                    fn new() -> SomeType;
+
+                    #[swift_bridge(swift_visibility = "fileprivate")]
+                    fn some_method(&self);
+
+                    #[swift_bridge(associated_to = SomeType, swift_visibility = "internal")]
+                    fn some_associated_function();
+                }
+            }
+        }
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsManyAfterTrim(vec![
+            "internal convenience init() {",
+            "fileprivate func some_method() {",
+            "internal static func some_associated_function() {",
+        ])
+    }
+
+    #[test]
+    fn method_swift_visibility_attribute() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: ExpectedRustTokens::SkipTest,
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: ExpectedCHeader::SkipTest,
+        }
+        .test();
+    }
+}
+
+/// Verify that we use the `#[swift_bridge(swift_visibility = "...")]` attribute as the access
+/// modifier of the classes that we generate for an opaque Rust type.
+mod opaque_rust_type_swift_visibility_attribute {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Rust" {
+                    #[swift_bridge(swift_visibility = "internal")]
+                    type SomeType;
+                }
+            }
+        }
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsManyAfterTrim(vec![
+            "internal class SomeType: SomeTypeRefMut {",
+            "internal class SomeTypeRefMut: SomeTypeRef {",
+            "internal class SomeTypeRef {",
+        ])
+    }
+
+    #[test]
+    fn opaque_rust_type_swift_visibility_attribute() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: ExpectedRustTokens::SkipTest,
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: ExpectedCHeader::SkipTest,
+        }
+        .test();
+    }
+}
+
+/// Verify that we use the `#[swift_bridge(swift_visibility = "...")]` attribute as the access
+/// modifier of shared structs and enums.
+mod shared_type_swift_visibility_attribute {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                #[swift_bridge(swift_repr = "struct", swift_visibility = "internal")]
+                struct SomeStruct {
+                    field: u8,
+                }
+
+                #[swift_bridge(swift_visibility = "internal")]
+                enum SomeEnum {
+                    Variant,
+                }
+            }
+        }
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsManyAfterTrim(vec![
+            "internal struct SomeStruct {",
+            "internal enum SomeEnum {",
+        ])
+    }
+
+    #[test]
+    fn shared_type_swift_visibility_attribute() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: ExpectedRustTokens::SkipTest,
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: ExpectedCHeader::SkipTest,
+        }
+        .test();
+    }
+}
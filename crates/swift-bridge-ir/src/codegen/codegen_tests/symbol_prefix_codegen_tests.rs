@@ -0,0 +1,110 @@
+use super::{BridgeModule, CodegenTest, ExpectedCHeader, ExpectedRustTokens, ExpectedSwiftCode};
+use crate::test_utils::parse_ok;
+use proc_macro2::TokenStream;
+use quote::quote;
+
+/// Verify that the `symbol_prefix` bridge module attribute gets used for the symbols that Rust
+/// exports and that Swift and the C header link against.
+mod custom_symbol_prefix {
+    use super::*;
+
+    fn bridge_module() -> BridgeModule {
+        BridgeModule {
+            tokens: quote! {
+                mod ffi {
+                    extern "Rust" {
+                        type SomeType;
+
+                        fn some_function();
+                        fn some_method(&self);
+                    }
+                }
+            },
+            enabled_crate_features: vec![],
+            symbol_prefix: Some("__my_crate__"),
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::ContainsMany(vec![
+            quote! {
+                #[export_name = "__my_crate__$SomeType$_free"]
+                pub extern "C" fn __swift_bridge__SomeType__free (
+                    this: *mut super::SomeType
+                ) {
+                    let this = unsafe { Box::from_raw(this) };
+                    drop(this);
+                }
+            },
+            quote! {
+                #[export_name = "__my_crate__$Vec_SomeType$new"]
+            },
+            quote! {
+                #[export_name = "__my_crate__$some_function"]
+                pub extern "C" fn __swift_bridge__some_function() {
+                    super::some_function()
+                }
+            },
+            quote! {
+                #[export_name = "__my_crate__$SomeType$some_method"]
+            },
+        ])
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsManyAfterTrim(vec![
+            "__my_crate__$SomeType$_free(ptr)",
+            "__my_crate__$Vec_SomeType$new()",
+            "__my_crate__$some_function()",
+            "__my_crate__$SomeType$some_method(ptr)",
+        ])
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsManyAfterTrim(vec![
+            "void __my_crate__$SomeType$_free(void* self);",
+            "void* __my_crate__$Vec_SomeType$new(void);",
+            "void __my_crate__$some_function(void);",
+            "void __my_crate__$SomeType$some_method(void* self);",
+        ])
+    }
+
+    #[test]
+    fn custom_symbol_prefix() {
+        CodegenTest {
+            bridge_module: bridge_module(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Verify that two crates that declare the same type, but that use different symbol prefixes,
+/// do not export colliding symbols.
+#[test]
+fn different_symbol_prefixes_do_not_collide() {
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Rust" {
+                    type SomeType;
+                }
+            }
+        }
+    }
+
+    let mut crate_a = parse_ok(bridge_module_tokens());
+    crate_a.set_symbol_prefix("__crate_a__".to_string());
+    let mut crate_b = parse_ok(bridge_module_tokens());
+    crate_b.set_symbol_prefix("__crate_b__".to_string());
+
+    let free_link_name = |module: &crate::SwiftBridgeModule| {
+        let ty = module.types.get("SomeType").unwrap().unwrap_opaque();
+        ty.free_link_name(&module.symbol_prefix)
+    };
+
+    assert_eq!(free_link_name(&crate_a), "__crate_a__$SomeType$_free");
+    assert_eq!(free_link_name(&crate_b), "__crate_b__$SomeType$_free");
+}
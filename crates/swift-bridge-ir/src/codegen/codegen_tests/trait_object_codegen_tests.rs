@@ -0,0 +1,164 @@
+use super::{CodegenTest, ExpectedCHeader, ExpectedRustTokens, ExpectedSwiftCode};
+use proc_macro2::TokenStream;
+use quote::quote;
+
+/// Verify that we generate a Swift class for a `Box<dyn Trait>` whose methods call into the
+/// trait object.
+mod extern_rust_trait_object {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Rust" {
+                    #[swift_bridge(trait_object)]
+                    type Shape;
+
+                    fn area(&self) -> f64;
+                    fn sides(self: &Box<dyn Shape>) -> u8;
+
+                    fn new_square(side: f64) -> Box<dyn Shape>;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::ContainsMany(vec![
+            quote! {
+                #[export_name = "__swift_bridge__$Shape$_free"]
+                pub extern "C" fn __swift_bridge__Shape__free (
+                    this: *mut Box<dyn super::Shape>
+                ) {
+                    let this = unsafe { Box::from_raw(this) };
+                    drop(this);
+                }
+            },
+            quote! {
+                #[export_name = "__swift_bridge__$Shape$area"]
+                pub extern "C" fn __swift_bridge__Shape_area (
+                    this: *const Box<dyn super::Shape>
+                ) -> f64 {
+                    (unsafe { &*this }).area()
+                }
+            },
+            quote! {
+                #[export_name = "__swift_bridge__$Shape$sides"]
+                pub extern "C" fn __swift_bridge__Shape_sides (
+                    this: *const Box<dyn super::Shape>
+                ) -> u8 {
+                    (unsafe { &*this }).sides()
+                }
+            },
+            quote! {
+                #[export_name = "__swift_bridge__$new_square"]
+                pub extern "C" fn __swift_bridge__new_square (
+                    side: f64
+                ) -> *mut Box<dyn super::Shape> {
+                    Box::into_raw(Box::new(super::new_square(side))) as *mut Box<dyn super::Shape>
+                }
+            },
+        ])
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsManyAfterTrim(vec![
+            r#"
+public func new_square(_ side: Double) -> Shape {
+    Shape(ptr: __swift_bridge__$new_square(side))
+}
+"#,
+            r#"
+public class Shape: ShapeRefMut {
+    var isOwned: Bool = true
+
+    public override init(ptr: UnsafeMutableRawPointer) {
+        super.init(ptr: ptr)
+    }
+
+    deinit {
+        if isOwned {
+            __swift_bridge__$Shape$_free(ptr)
+        }
+    }
+}
+"#,
+            r#"
+extension ShapeRef {
+    public func area() -> Double {
+        __swift_bridge__$Shape$area(ptr)
+    }
+
+    public func sides() -> UInt8 {
+        __swift_bridge__$Shape$sides(ptr)
+    }
+}
+"#,
+        ])
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsManyAfterTrim(vec![
+            r#"
+typedef struct Shape Shape;
+void __swift_bridge__$Shape$_free(void* self);
+"#,
+            "double __swift_bridge__$Shape$area(void* self);",
+            "uint8_t __swift_bridge__$Shape$sides(void* self);",
+            "void* __swift_bridge__$new_square(double side);",
+        ])
+    }
+
+    #[test]
+    fn extern_rust_trait_object() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Verify that we do not generate the functions that back a `Vec<Box<dyn Trait>>`, since we do
+/// not support them yet.
+mod extern_rust_trait_object_not_vectorizable {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Rust" {
+                    #[swift_bridge(trait_object)]
+                    type Shape;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::DoesNotContain(quote! {
+            Vec<Box<dyn super::Shape>>
+        })
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::DoesNotContainAfterTrim("extension Shape: Vectorizable")
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::DoesNotContainAfterTrim("__swift_bridge__$Vec_Shape")
+    }
+
+    #[test]
+    fn extern_rust_trait_object_not_vectorizable() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
@@ -0,0 +1,287 @@
+use super::{CodegenTest, ExpectedCHeader, ExpectedRustTokens, ExpectedSwiftCode};
+use proc_macro2::TokenStream;
+use quote::quote;
+
+/// Verify that we can return a tuple from a Rust function.
+mod extern_rust_fn_return_tuple {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Rust" {
+                    fn some_function() -> (i32, String);
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::ContainsMany(vec![
+            quote! {
+                #[repr(C)]
+                #[doc(hidden)]
+                pub struct __swift_bridge__TupleInt32AndString {
+                    _0: i32,
+                    _1: *mut swift_bridge::string::RustString
+                }
+            },
+            quote! {
+                #[export_name = "__swift_bridge__$some_function"]
+                pub extern "C" fn __swift_bridge__some_function() -> __swift_bridge__TupleInt32AndString {
+                    {
+                        let val = super::some_function();
+                        __swift_bridge__TupleInt32AndString {
+                            _0: val.0,
+                            _1: swift_bridge::string::RustString(val.1).box_into_raw()
+                        }
+                    }
+                }
+            },
+        ])
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+public func some_function() -> (Int32, RustString) {
+    { () -> (Int32, RustString) in let val = __swift_bridge__$some_function(); return (val._0, RustString(ptr: val._1)) }()
+}
+"#,
+        )
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsManyAfterTrim(vec![
+            "#include <stdint.h>",
+            r#"
+typedef struct __swift_bridge__$TupleInt32AndString { int32_t _0; void* _1; } __swift_bridge__$TupleInt32AndString;
+struct __swift_bridge__$TupleInt32AndString __swift_bridge__$some_function(void);
+"#,
+        ])
+    }
+
+    #[test]
+    fn extern_rust_fn_return_tuple() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Verify that we can pass a tuple to a Rust function.
+mod extern_rust_fn_tuple_arg {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Rust" {
+                    fn some_function(arg: (u8, bool, String));
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::Contains(quote! {
+            #[export_name = "__swift_bridge__$some_function"]
+            pub extern "C" fn __swift_bridge__some_function(
+                arg: __swift_bridge__TupleUInt8AndBoolAndString
+            ) {
+                super::some_function({
+                    let val = arg;
+                    (val._0, val._1, unsafe { Box::from_raw(val._2).0 })
+                })
+            }
+        })
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+public func some_function<GenericIntoRustString: IntoRustString>(_ arg: (UInt8, Bool, GenericIntoRustString)) {
+    __swift_bridge__$some_function({ let val = arg; return __swift_bridge__$TupleUInt8AndBoolAndString(_0: val.0, _1: val.1, _2: { let rustString = val.2.intoRustString(); rustString.isOwned = false; return rustString.ptr }()) }())
+}
+"#,
+        )
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsManyAfterTrim(vec![
+            "#include <stdbool.h>",
+            "#include <stdint.h>",
+            r#"
+typedef struct __swift_bridge__$TupleUInt8AndBoolAndString { uint8_t _0; bool _1; void* _2; } __swift_bridge__$TupleUInt8AndBoolAndString;
+void __swift_bridge__$some_function(struct __swift_bridge__$TupleUInt8AndBoolAndString arg);
+"#,
+        ])
+    }
+
+    #[test]
+    fn extern_rust_fn_tuple_arg() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Verify that a tuple can hold a shared struct.
+mod extern_rust_fn_tuple_with_shared_struct {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                #[swift_bridge(swift_repr = "struct")]
+                struct SomeStruct {
+                    field: u8,
+                }
+
+                extern "Rust" {
+                    fn some_function(arg: (SomeStruct, u32)) -> (u32, SomeStruct);
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::ContainsMany(vec![
+            quote! {
+                pub struct __swift_bridge__TupleSomeStructAndUInt32 {
+                    _0: __swift_bridge__SomeStruct,
+                    _1: u32
+                }
+            },
+            quote! {
+                pub struct __swift_bridge__TupleUInt32AndSomeStruct {
+                    _0: u32,
+                    _1: __swift_bridge__SomeStruct
+                }
+            },
+            quote! {
+                pub extern "C" fn __swift_bridge__some_function(
+                    arg: __swift_bridge__TupleSomeStructAndUInt32
+                ) -> __swift_bridge__TupleUInt32AndSomeStruct {
+                    {
+                        let val = super::some_function({
+                            let val = arg;
+                            (val._0.into_rust_repr(), val._1)
+                        });
+                        __swift_bridge__TupleUInt32AndSomeStruct {
+                            _0: val.0,
+                            _1: val.1.into_ffi_repr()
+                        }
+                    }
+                }
+            },
+        ])
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+public func some_function(_ arg: (SomeStruct, UInt32)) -> (UInt32, SomeStruct) {
+    { () -> (UInt32, SomeStruct) in let val = __swift_bridge__$some_function({ let val = arg; return __swift_bridge__$TupleSomeStructAndUInt32(_0: val.0.intoFfiRepr(), _1: val.1) }()); return (val._0, val._1.intoSwiftRepr()) }()
+}
+"#,
+        )
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsAfterTrim(
+            r#"
+typedef struct __swift_bridge__$TupleSomeStructAndUInt32 { struct __swift_bridge__$SomeStruct _0; uint32_t _1; } __swift_bridge__$TupleSomeStructAndUInt32;
+typedef struct __swift_bridge__$TupleUInt32AndSomeStruct { uint32_t _0; struct __swift_bridge__$SomeStruct _1; } __swift_bridge__$TupleUInt32AndSomeStruct;
+"#,
+        )
+    }
+
+    #[test]
+    fn extern_rust_fn_tuple_with_shared_struct() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Verify that we can pass tuples to and return tuples from Swift functions.
+mod extern_swift_fn_tuple {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Swift" {
+                    fn some_function(arg: (u8, u16)) -> (u8, u16);
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::ContainsMany(vec![
+            quote! {
+                pub fn some_function(arg: (u8, u16)) -> (u8, u16) {
+                    {
+                        let val = unsafe {
+                            __swift_bridge__some_function({
+                                let val = arg;
+                                __swift_bridge__TupleUInt8AndUInt16 { _0: val.0, _1: val.1 }
+                            })
+                        };
+                        (val._0, val._1)
+                    }
+                }
+            },
+            quote! {
+                #[link_name = "__swift_bridge__$some_function"]
+                fn __swift_bridge__some_function(
+                    arg: __swift_bridge__TupleUInt8AndUInt16
+                ) -> __swift_bridge__TupleUInt8AndUInt16;
+            },
+        ])
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+@_cdecl("__swift_bridge__$some_function")
+func __swift_bridge__some_function (_ arg: __swift_bridge__$TupleUInt8AndUInt16) -> __swift_bridge__$TupleUInt8AndUInt16 {
+    { let val = some_function(arg: { () -> (UInt8, UInt16) in let val = arg; return (val._0, val._1) }()); return __swift_bridge__$TupleUInt8AndUInt16(_0: val.0, _1: val.1) }()
+}
+"#,
+        )
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsAfterTrim(
+            r#"
+typedef struct __swift_bridge__$TupleUInt8AndUInt16 { uint8_t _0; uint16_t _1; } __swift_bridge__$TupleUInt8AndUInt16;
+"#,
+        )
+    }
+
+    #[test]
+    fn extern_swift_fn_tuple() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
@@ -0,0 +1,172 @@
+use super::{CodegenTest, ExpectedCHeader, ExpectedRustTokens, ExpectedSwiftCode};
+use proc_macro2::TokenStream;
+use quote::quote;
+
+/// Verify that we call an `unsafe fn` Rust function inside of an `unsafe` block and that we note
+/// the unsafety in the Swift function's documentation.
+mod extern_rust_unsafe_fn {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Rust" {
+                    unsafe fn some_function(ptr: *const u8, len: usize) -> u8;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::Contains(quote! {
+            #[export_name = "__swift_bridge__$some_function"]
+            pub extern "C" fn __swift_bridge__some_function(ptr: *const u8, len: usize) -> u8 {
+                unsafe { super::some_function(ptr, len) }
+            }
+        })
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+/// - Warning: Calls an `unsafe` Rust function. The caller must uphold its safety requirements.
+public func some_function(_ ptr: UnsafePointer<UInt8>, _ len: UInt) -> UInt8 {
+    __swift_bridge__$some_function(ptr, len)
+}
+"#,
+        )
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsAfterTrim(
+            r#"
+uint8_t __swift_bridge__$some_function(uint8_t const * ptr, uintptr_t len);
+"#,
+        )
+    }
+
+    #[test]
+    fn extern_rust_unsafe_fn() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Verify that we call an `unsafe fn` Rust method inside of an `unsafe` block, and that the
+/// unsafety note comes after the method's own documentation.
+mod extern_rust_unsafe_method {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Rust" {
+                    type SomeType;
+
+                    /// Some documentation.
+                    unsafe fn some_method(&self, arg: u8) -> u8;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::Contains(quote! {
+            #[export_name = "__swift_bridge__$SomeType$some_method"]
+            pub extern "C" fn __swift_bridge__SomeType_some_method(
+                this: *const super::SomeType,
+                arg: u8
+            ) -> u8 {
+                unsafe { (unsafe { &*this }).some_method(arg) }
+            }
+        })
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+extension SomeTypeRef {
+    /// Some documentation.
+    ///
+    /// - Warning: Calls an `unsafe` Rust function. The caller must uphold its safety requirements.
+    public func some_method(_ arg: UInt8) -> UInt8 {
+        __swift_bridge__$SomeType$some_method(ptr, arg)
+    }
+}
+"#,
+        )
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsAfterTrim(
+            r#"
+uint8_t __swift_bridge__$SomeType$some_method(void* self, uint8_t arg);
+"#,
+        )
+    }
+
+    #[test]
+    fn extern_rust_unsafe_method() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Verify that a Swift function declared as an `unsafe fn` is exposed to Rust as an `unsafe fn`.
+mod extern_swift_unsafe_fn {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Swift" {
+                    unsafe fn some_function(arg: u8);
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::Contains(quote! {
+            pub unsafe fn some_function(arg: u8) {
+                unsafe { __swift_bridge__some_function(arg) }
+            }
+        })
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+@_cdecl("__swift_bridge__$some_function")
+func __swift_bridge__some_function (_ arg: UInt8) {
+    some_function(arg: arg)
+}
+"#,
+        )
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ExactAfterTrim("")
+    }
+
+    #[test]
+    fn extern_swift_unsafe_fn() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
@@ -278,3 +278,449 @@ void __swift_bridge__$some_function(void* arg);
         .test();
     }
 }
+
+/// Verify that a `&mut Vec<T>` crosses the FFI boundary as a borrowed `RustVec<T>` handle, so that
+/// pushing to the handle from Swift mutates the Vec that Rust owns.
+mod extern_rust_fn_ref_mut_vec {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Rust" {
+                    type Stack;
+                    fn items_mut(&mut self) -> &mut Vec<u32>;
+                    fn push_all(items: &mut Vec<u32>, new_items: &[u32]);
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::ContainsMany(vec![
+            quote! {
+                pub extern "C" fn __swift_bridge__Stack_items_mut(
+                    this: *mut super::Stack
+                ) -> *mut Vec<u32> {
+                    (unsafe { &mut * this }).items_mut() as *mut Vec<u32>
+                }
+            },
+            quote! {
+                pub extern "C" fn __swift_bridge__push_all(
+                    items: *mut Vec<u32>,
+                    new_items: swift_bridge::FfiSlice<u32>
+                ) {
+                    super::push_all(unsafe { &mut * items }, new_items.as_slice())
+                }
+            },
+        ])
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsManyAfterTrim(vec![
+            r#"
+    public func items_mut() -> RustVec<UInt32> {
+        RustVec(borrowedPtr: __swift_bridge__$Stack$items_mut(ptr))
+    }
+"#,
+            r#"
+public func push_all(_ items: RustVec<UInt32>, _ new_items: UnsafeBufferPointer<UInt32>) {
+    __swift_bridge__$push_all(items.ptr, new_items.toFfiSlice())
+}
+"#,
+        ])
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsManyAfterTrim(vec![
+            r#"void* __swift_bridge__$Stack$items_mut(void* self);"#,
+            r#"void __swift_bridge__$push_all(void* items, struct __private__FfiSlice new_items);"#,
+        ])
+    }
+
+    #[test]
+    fn extern_rust_fn_ref_mut_vec() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Test code generation for Rust function that returns a Vec<T> where T is a shared struct.
+mod extern_rust_fn_return_vec_of_shared_struct {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                struct SomeStruct {
+                    name: String,
+                    age: u8,
+                }
+
+                extern "Rust" {
+                    fn some_function() -> Vec<SomeStruct>;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::Contains(quote! {
+            pub extern "C" fn __swift_bridge__some_function(
+            ) -> swift_bridge::vec::FfiVec<__swift_bridge__SomeStruct> {
+                swift_bridge::vec::FfiVec::from_elems(
+                    super::some_function().into_iter().map(|elem| elem.into_ffi_repr())
+                )
+            }
+        })
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+func some_function() -> [SomeStruct] {
+    __swift_bridge__$some_function().toArray({ (elem: __swift_bridge__$SomeStruct) in elem.intoSwiftRepr() })
+}
+"#,
+        )
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsAfterTrim(
+            r#"
+struct __private__FfiVec __swift_bridge__$some_function(void);
+"#,
+        )
+    }
+
+    #[test]
+    fn extern_rust_fn_return_vec_of_shared_struct() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Verify that Vecs of floats that get moved into Swift Arrays copy their whole buffer into the
+/// Array at once, instead of converting one element at a time.
+mod extern_rust_fn_return_float_vecs_as_arrays {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Rust" {
+                    fn some_function() -> Vec<Vec<f32>>;
+                    fn another_function() -> Option<Vec<f64>>;
+                }
+            }
+        }
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsManyAfterTrim(vec![
+            r#"
+func some_function() -> [[Float]] {
+    __swift_bridge__$some_function().toArray({ (elem: __private__FfiVec) in elem.toArray(of: Float.self) })
+}
+"#,
+            r#"
+func another_function() -> Optional<[Double]> {
+    { let val = __swift_bridge__$another_function(); if val.elems != nil { return val.toArray(of: Double.self) } else { return nil } }()
+}
+"#,
+        ])
+    }
+
+    #[test]
+    fn extern_rust_fn_return_float_vecs_as_arrays() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: ExpectedRustTokens::SkipTest,
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: ExpectedCHeader::SkipTest,
+        }
+        .test();
+    }
+}
+
+/// Test code generation for Rust function that returns a Vec<Vec<T>>.
+/// Every inner Vec gets moved into its own Swift Array.
+mod extern_rust_fn_return_nested_vec {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Rust" {
+                    fn some_function() -> Vec<Vec<i32>>;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::Contains(quote! {
+            pub extern "C" fn __swift_bridge__some_function(
+            ) -> swift_bridge::vec::FfiVec<swift_bridge::vec::FfiVec<i32> > {
+                swift_bridge::vec::FfiVec::from_elems(
+                    super::some_function().into_iter().map(|elem|
+                        swift_bridge::vec::FfiVec::from_elems(elem.into_iter().map(|elem| elem))
+                    )
+                )
+            }
+        })
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+func some_function() -> [[Int32]] {
+    __swift_bridge__$some_function().toArray({ (elem: __private__FfiVec) in elem.toArray(of: Int32.self) })
+}
+"#,
+        )
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsAfterTrim(
+            r#"
+struct __private__FfiVec __swift_bridge__$some_function(void);
+"#,
+        )
+    }
+
+    #[test]
+    fn extern_rust_fn_return_nested_vec() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Verify that a `Vec<u8>` that is returned with `return_as = "Data"` gets wrapped in Swift `Data`
+/// without copying its bytes.
+mod extern_rust_fn_return_vec_u8_as_data {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Rust" {
+                    #[swift_bridge(return_as = "Data")]
+                    fn some_function() -> Vec<u8>;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::Contains(quote! {
+            pub extern "C" fn __swift_bridge__some_function() -> swift_bridge::vec::FfiVec<u8> {
+                swift_bridge::vec::FfiVec::from_elems(super::some_function().into_iter().map(|elem| elem))
+            }
+        })
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsManyAfterTrim(vec![
+            "import Foundation",
+            r#"
+func some_function() -> Data {
+    __swift_bridge__$some_function().toData()
+}
+"#,
+        ])
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsAfterTrim(
+            r#"
+struct __private__FfiVec __swift_bridge__$some_function(void);
+"#,
+        )
+    }
+
+    #[test]
+    fn extern_rust_fn_return_vec_u8_as_data() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Verify that a `Vec<f32>` that is returned with `return_as = "Array"` becomes a Swift `[Float]`
+/// that Swift copies the whole Rust buffer into at once.
+mod extern_rust_fn_return_vec_f32_as_array {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Rust" {
+                    #[swift_bridge(return_as = "Array")]
+                    fn f() -> Vec<f32>;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::Contains(quote! {
+            pub extern "C" fn __swift_bridge__f() -> swift_bridge::vec::FfiVec<f32> {
+                swift_bridge::vec::FfiVec::from_elems(super::f().into_iter().map(|elem| elem))
+            }
+        })
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+func f() -> [Float] {
+    __swift_bridge__$f().toArray(of: Float.self)
+}
+"#,
+        )
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsAfterTrim(
+            r#"
+struct __private__FfiVec __swift_bridge__$f(void);
+"#,
+        )
+    }
+
+    #[test]
+    fn extern_rust_fn_return_vec_f32_as_array() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Verify that a Rust function that takes a `Vec<i32>` accepts a `RustVec<Int32>` by default.
+mod extern_rust_fn_arg_vec_of_primitive {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Rust" {
+                    fn some_function(arg: Vec<i32>);
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::Contains(quote! {
+            pub extern "C" fn __swift_bridge__some_function(arg: *mut Vec<i32>) {
+                super::some_function(unsafe { * Box::from_raw(arg) })
+            }
+        })
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+func some_function(_ arg: RustVec<Int32>) {
+    __swift_bridge__$some_function({ let val = arg; val.isOwned = false; return val.ptr }())
+}
+"#,
+        )
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsAfterTrim(
+            r#"
+void __swift_bridge__$some_function(void* arg);
+"#,
+        )
+    }
+
+    #[test]
+    fn extern_rust_fn_arg_vec_of_primitive() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Verify that a Rust function that takes a `#[swift_bridge(as = "Array")] arg: Vec<i32>` accepts
+/// a Swift `[Int32]`, which Rust copies into a new Vec that the function takes ownership of.
+mod extern_rust_fn_arg_vec_of_primitive_as_array {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Rust" {
+                    fn some_function(#[swift_bridge(as = "Array")] arg: Vec<i32>);
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::Contains(quote! {
+            pub extern "C" fn __swift_bridge__some_function(arg: *mut Vec<i32>) {
+                super::some_function(unsafe { * Box::from_raw(arg) })
+            }
+        })
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+func some_function(_ arg: [Int32]) {
+    __swift_bridge__$some_function(arg.withUnsafeBufferPointer { buf in __swift_bridge__$Vec_i32$from_slice(buf.baseAddress, UInt(buf.count)) })
+}
+"#,
+        )
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsAfterTrim(
+            r#"
+void __swift_bridge__$some_function(void* arg);
+"#,
+        )
+    }
+
+    #[test]
+    fn extern_rust_fn_arg_vec_of_primitive_as_array() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
@@ -1,7 +1,7 @@
 //! Tests can be found in src/codegen/codegen_tests.rs and its submodules.
 
 use crate::bridged_type::{BridgedType, StdLibType, StructFields};
-use crate::codegen::CodegenConfig;
+use crate::codegen::{CodegenConfig, CppHeaderConfig};
 use crate::parse::{SharedTypeDeclaration, TypeDeclaration, TypeDeclarations};
 use crate::parsed_extern_fn::ParsedExternFn;
 use crate::{SwiftBridgeModule, SWIFT_BRIDGE_PREFIX};
@@ -12,7 +12,7 @@ const NOTICE: &'static str = "// File automatically generated by swift-bridge.";
 
 struct Bookkeeping {
     includes: BTreeSet<&'static str>,
-    slice_types: HashSet<String>,
+    slice_types: BTreeSet<String>,
 }
 
 impl SwiftBridgeModule {
@@ -27,17 +27,65 @@ impl SwiftBridgeModule {
     }
 
     pub(crate) fn generate_c_header_inner(&self, config: &CodegenConfig) -> String {
+        let (includes, declarations) =
+            self.generate_header_includes_and_declarations(config, false);
+
+        match &config.cpp_header {
+            Some(cpp_header) if !declarations.is_empty() => format!(
+                "{}{}",
+                includes,
+                wrap_declarations_for_cpp(&declarations, cpp_header)
+            ),
+            _ => format!("{}{}", includes, declarations),
+        }
+    }
+
+    /// Generate the contents of an Objective-C header file based on the contents of this module.
+    ///
+    /// The declarations are the same as the C header's, but they live in an `NS_ASSUME_NONNULL`
+    /// region and pointers that can be null are annotated as `_Nullable`.
+    pub(crate) fn generate_objc_header(&self, config: &CodegenConfig) -> String {
+        format!(
+            r#"{notice}
+{header}"#,
+            notice = NOTICE,
+            header = self.generate_objc_header_inner(config)
+        )
+    }
+
+    pub(crate) fn generate_objc_header_inner(&self, config: &CodegenConfig) -> String {
+        let (includes, declarations) = self.generate_header_includes_and_declarations(config, true);
+
+        format!(
+            r#"#import <Foundation/Foundation.h>
+{includes}
+NS_ASSUME_NONNULL_BEGIN
+
+{declarations}
+NS_ASSUME_NONNULL_END
+"#,
+            includes = includes,
+            declarations = declarations
+        )
+    }
+
+    /// The `#include`s and the declarations of a C or, if `objc` is set, Objective-C header.
+    fn generate_header_includes_and_declarations(
+        &self,
+        config: &CodegenConfig,
+        objc: bool,
+    ) -> (String, String) {
         let mut header = "".to_string();
 
         if !self.module_will_be_compiled(config) {
-            return header;
+            return ("".to_string(), header);
         }
 
         let mut bookkeeping = Bookkeeping {
             includes: BTreeSet::new(),
             // TODO: Delete this.
             //  Don't think we're using it.
-            slice_types: HashSet::new(),
+            slice_types: BTreeSet::new(),
         };
 
         for ty in self.types.types() {
@@ -140,15 +188,91 @@ typedef struct {option_ffi_name} {{ bool is_some; {ffi_name} val; }} {option_ffi
                             variants += &v;
                         }
 
-                        let enum_decl = format!(
-                            r#"typedef enum {ffi_tag_name} {{ {variants}}} {ffi_tag_name};
+                        let enum_decl = if ty_enum.transparent {
+                            bookkeeping.includes.insert("stdint.h");
+
+                            // The size of a C enum is implementation defined, so the value still
+                            // crosses the boundary as an `int32_t`. The enum gives C consumers
+                            // names for the variants' discriminants.
+                            let mut variants = "".to_string();
+                            for variant in ty_enum.variants.iter() {
+                                variants += &format!(
+                                    "{}${} = {}, ",
+                                    ffi_name,
+                                    variant.name,
+                                    variant.discriminant.unwrap()
+                                );
+                            }
+
+                            format!(
+                                r#"typedef enum {ffi_tag_name} {{ {variants}}} {ffi_tag_name};
+typedef int32_t {ffi_name};
+typedef struct {option_ffi_name} {{ bool is_some; {ffi_name} val; }} {option_ffi_name};"#,
+                                ffi_name = ffi_name,
+                                ffi_tag_name = ffi_tag_name,
+                                option_ffi_name = option_ffi_name,
+                                variants = variants,
+                            )
+                        } else if ty_enum.has_one_or_more_variants_with_data() {
+                            let fields_union = ty_enum.ffi_fields_union_name_string();
+
+                            let mut variant_structs = "".to_string();
+                            let mut union_fields = "".to_string();
+
+                            for variant in ty_enum.variants.iter() {
+                                if variant.fields.is_empty() {
+                                    continue;
+                                }
+
+                                let variant_fields_name =
+                                    ty_enum.ffi_variant_fields_name_string(variant);
+
+                                let mut fields = "".to_string();
+                                for norm_field in variant.fields.normalized_fields() {
+                                    let ty =
+                                        BridgedType::new_with_type(&norm_field.ty, &self.types)
+                                            .unwrap();
+                                    if let Some(include) = ty.c_include() {
+                                        bookkeeping.includes.insert(include);
+                                    }
+
+                                    fields +=
+                                        &format!("{} {}; ", ty.to_c(), norm_field.ffi_field_name());
+                                }
+
+                                variant_structs += &format!(
+                                    "typedef struct {name} {{ {fields}}} {name};\n",
+                                    name = variant_fields_name,
+                                    fields = fields
+                                );
+                                union_fields +=
+                                    &format!("{} {}; ", variant_fields_name, variant.name);
+                            }
+
+                            format!(
+                                r#"typedef enum {ffi_tag_name} {{ {variants}}} {ffi_tag_name};
+{variant_structs}union {fields_union} {{ {union_fields}}};
+typedef struct {ffi_name} {{ {ffi_tag_name} tag; union {fields_union} payload; }} {ffi_name};
+typedef struct {option_ffi_name} {{ bool is_some; {ffi_name} val; }} {option_ffi_name};"#,
+                                ffi_name = ffi_name,
+                                ffi_tag_name = ffi_tag_name,
+                                option_ffi_name = option_ffi_name,
+                                variants = variants,
+                                variant_structs = variant_structs,
+                                fields_union = fields_union,
+                                union_fields = union_fields
+                            )
+                        } else {
+                            format!(
+                                r#"typedef enum {ffi_tag_name} {{ {variants}}} {ffi_tag_name};
 typedef struct {ffi_name} {{ {ffi_tag_name} tag; }} {ffi_name};
 typedef struct {option_ffi_name} {{ bool is_some; {ffi_name} val; }} {option_ffi_name};"#,
-                            ffi_name = ffi_name,
-                            ffi_tag_name = ffi_tag_name,
-                            option_ffi_name = option_ffi_name,
-                            variants = variants
-                        );
+                                ffi_name = ffi_name,
+                                ffi_tag_name = ffi_tag_name,
+                                option_ffi_name = option_ffi_name,
+                                variants = variants
+                            )
+                        };
 
                         header += &enum_decl;
                         header += "\n";
@@ -159,35 +283,148 @@ typedef struct {option_ffi_name} {{ bool is_some; {ffi_name} val; }} {option_ffi
                         continue;
                     }
 
-                    if ty.already_declared {
+                    if ty.already_declared || ty.is_generic_declaration() {
+                        continue;
+                    }
+
+                    // A `Copy` type gets passed by value, so Swift needs to know its size.
+                    if let Some(size_bytes) = ty.copy {
+                        bookkeeping.includes.insert("stdint.h");
+                        header += &format!(
+                            "typedef struct {ffi_name} {{ uint8_t bytes[{size_bytes}]; }} {ffi_name};",
+                            ffi_name = ty.copy_ffi_repr_string(),
+                            size_bytes = size_bytes
+                        );
+                        header += "\n";
                         continue;
                     }
 
-                    let ty_name = ty.to_string();
+                    let ty_name = ty.link_name_segment();
 
                     let ty_decl = format!("typedef struct {ty_name} {ty_name};", ty_name = ty_name);
                     let drop_ty = format!(
-                        r#"void __swift_bridge__${ty_name}$_free(void* self);"#,
-                        ty_name = ty_name
+                        "void {}(void* self);",
+                        ty.free_link_name(&self.symbol_prefix)
                     );
-                    let vec_functions = vec_functions(&ty_name);
 
                     header += &ty_decl;
                     header += "\n";
-                    header += &drop_ty;
+                    if !ty.no_free {
+                        header += &drop_ty;
+                        header += "\n";
+                    }
+
+                    if ty.arc {
+                        header += &format!(
+                            "void* {}(void* self);",
+                            ty.clone_link_name(&self.symbol_prefix)
+                        );
+                        header += "\n";
+                    }
+
+                    if ty.comparable {
+                        bookkeeping.includes.insert("stdint.h");
+                        header += &format!(
+                            "int8_t {}(void* lhs, void* rhs);",
+                            ty.cmp_link_name(&self.symbol_prefix)
+                        );
+                        header += "\n";
+                    }
+
+                    if ty.display {
+                        header += &format!(
+                            "void* {}(void* self);",
+                            ty.to_string_link_name(&self.symbol_prefix)
+                        );
+                        header += "\n";
+                    }
+
+                    if ty.is_vectorizable() {
+                        header += &vec_functions(&ty_name, objc, &self.symbol_prefix);
+                        header += "\n";
+                    }
+                }
+            }
+        }
+
+        let mut declared_results = HashSet::new();
+        let mut declared_boxed_fns = HashSet::new();
+        let mut declared_arrays = HashSet::new();
+        let mut declared_tuples = HashSet::new();
+        // Functions whose `#[cfg(...)]` attributes are disabled don't get compiled, so we don't
+        // declare them.
+        let functions: Vec<_> = self
+            .functions
+            .iter()
+            .filter(|function| config.cfg_attrs_are_enabled(&function.cfg_attrs))
+            .collect();
+
+        for function in functions.iter() {
+            // Swift functions that Rust calls use the array and tuple structs in their `@_cdecl`
+            // signatures, so we declare them for both Rust and Swift functions.
+            for array in function.array_types(&self.types) {
+                if declared_arrays.insert(array.ffi_name_string()) {
+                    if let Some(include) = array.elem.c_include() {
+                        bookkeeping.includes.insert(include);
+                    }
+
+                    header += &array.generate_c_declaration();
                     header += "\n";
-                    header += &vec_functions;
+                }
+            }
+
+            for tuple in function.tuple_types(&self.types) {
+                if declared_tuples.insert(tuple.ffi_name_string()) {
+                    for elem in tuple.elems.iter() {
+                        if let Some(include) = elem.c_include() {
+                            bookkeeping.includes.insert(include);
+                        }
+                    }
+
+                    header += &tuple.generate_c_declaration();
+                    header += "\n";
+                }
+            }
+
+            if function.host_lang.is_swift() {
+                continue;
+            }
+
+            if let Some(BridgedType::StdLib(StdLibType::Result(result))) =
+                function.return_ty_built_in(&self.types)
+            {
+                if declared_results.insert(result.ffi_name_string()) {
+                    header += &result.generate_c_declaration();
+                    header += "\n";
+                }
+            }
+
+            for boxed_fn in function.boxed_fn_args(&self.types) {
+                if declared_boxed_fns.insert(boxed_fn.ffi_name_string()) {
+                    for param in boxed_fn.params.iter().chain([boxed_fn.ret.as_ref()]) {
+                        if let Some(include) = param.c_include() {
+                            bookkeeping.includes.insert(include);
+                        }
+                    }
+
+                    header += &boxed_fn.generate_c_declaration();
                     header += "\n";
                 }
             }
         }
 
-        for function in self.functions.iter() {
+        for function in functions.iter() {
             if function.host_lang.is_swift() {
                 continue;
             }
 
-            header += &declare_func(&function, &mut bookkeeping, &self.types);
+            header += &declare_func(
+                &function,
+                &mut bookkeeping,
+                &self.types,
+                objc,
+                &self.symbol_prefix,
+            );
         }
 
         for slice_ty in bookkeeping.slice_types.iter() {
@@ -201,31 +438,37 @@ typedef struct {option_ffi_name} {{ bool is_some; {ffi_name} val; }} {option_ffi
 
         let mut includes = bookkeeping.includes.iter().collect::<Vec<_>>();
         includes.sort();
+        let mut include_lines = "".to_string();
         for include in includes {
-            header = format!(
+            include_lines = format!(
                 r#"#include <{}>
 {}"#,
-                include, header
+                include, include_lines
             );
         }
 
-        header
+        (include_lines, header)
     }
 }
 
-fn vec_functions(ty_name: &str) -> String {
+fn vec_functions(ty_name: &str, objc: bool, symbol_prefix: &str) -> String {
+    // `pop`, `get` and `get_mut` return a null pointer when there is no element.
+    let maybe_null = if objc { "void* _Nullable" } else { "void*" };
+
     format!(
         r#"
-void* __swift_bridge__$Vec_{ty_name}$new(void);
-void __swift_bridge__$Vec_{ty_name}$drop(void* vec_ptr);
-void __swift_bridge__$Vec_{ty_name}$push(void* vec_ptr, void* item_ptr);
-void* __swift_bridge__$Vec_{ty_name}$pop(void* vec_ptr);
-void* __swift_bridge__$Vec_{ty_name}$get(void* vec_ptr, uintptr_t index);
-void* __swift_bridge__$Vec_{ty_name}$get_mut(void* vec_ptr, uintptr_t index);
-uintptr_t __swift_bridge__$Vec_{ty_name}$len(void* vec_ptr);
-void* __swift_bridge__$Vec_{ty_name}$as_ptr(void* vec_ptr);
+void* {symbol_prefix}$Vec_{ty_name}$new(void);
+void {symbol_prefix}$Vec_{ty_name}$drop(void* vec_ptr);
+void {symbol_prefix}$Vec_{ty_name}$push(void* vec_ptr, void* item_ptr);
+{maybe_null} {symbol_prefix}$Vec_{ty_name}$pop(void* vec_ptr);
+{maybe_null} {symbol_prefix}$Vec_{ty_name}$get(void* vec_ptr, uintptr_t index);
+{maybe_null} {symbol_prefix}$Vec_{ty_name}$get_mut(void* vec_ptr, uintptr_t index);
+uintptr_t {symbol_prefix}$Vec_{ty_name}$len(void* vec_ptr);
+void* {symbol_prefix}$Vec_{ty_name}$as_ptr(void* vec_ptr);
 "#,
-        ty_name = ty_name
+        ty_name = ty_name,
+        maybe_null = maybe_null,
+        symbol_prefix = symbol_prefix
     )
 }
 
@@ -233,10 +476,21 @@ fn declare_func(
     func: &ParsedExternFn,
     bookkeeping: &mut Bookkeeping,
     types: &TypeDeclarations,
+    objc: bool,
+    symbol_prefix: &str,
 ) -> String {
-    let ret = func.to_c_header_return(types);
-    let name = func.link_name();
-    let params = func.to_c_header_params(types);
+    let (ret, params) = if objc {
+        (
+            func.to_objc_header_return(types),
+            func.to_objc_header_params(types),
+        )
+    } else {
+        (
+            func.to_c_header_return(types),
+            func.to_c_header_params(types),
+        )
+    };
+    let name = func.link_name(symbol_prefix);
 
     if let ReturnType::Type(_, ty) = &func.func.sig.output {
         if let Some(ty) = BridgedType::new_with_type(&ty, types) {
@@ -257,7 +511,12 @@ fn declare_func(
         let maybe_ret = if maybe_ret == BridgedType::StdLib(StdLibType::Null) {
             "".to_string()
         } else {
-            format!(", {} ret", maybe_ret.to_c())
+            let ret_ty = if objc {
+                maybe_ret.to_objc()
+            } else {
+                maybe_ret.to_c()
+            };
+            format!(", {} ret", ret_ty)
         };
 
         let maybe_params = if func.sig.inputs.is_empty() {
@@ -283,6 +542,36 @@ fn declare_func(
     declaration
 }
 
+/// Wrap the declarations in `extern "C"` guards, and optionally a namespace, so that the header
+/// can be included from C++.
+///
+/// The guards are only active when compiling C++, so the header can still be included from C and
+/// Swift.
+fn wrap_declarations_for_cpp(declarations: &str, cpp_header: &CppHeaderConfig) -> String {
+    let (namespace_begin, namespace_end) = match &cpp_header.namespace {
+        Some(namespace) => (
+            format!("namespace {} {{\n", namespace),
+            format!("}} // namespace {}\n", namespace),
+        ),
+        None => ("".to_string(), "".to_string()),
+    };
+
+    format!(
+        r#"#ifdef __cplusplus
+{namespace_begin}extern "C" {{
+#endif
+
+{declarations}
+#ifdef __cplusplus
+}} // extern "C"
+{namespace_end}#endif
+"#,
+        namespace_begin = namespace_begin,
+        declarations = declarations,
+        namespace_end = namespace_end
+    )
+}
+
 #[cfg(test)]
 mod tests {
     //! More tests can be found in src/codegen/codegen_tests.rs and its submodules.
@@ -424,7 +713,7 @@ typedef struct SomeType SomeType;
 void __swift_bridge__$SomeType$_free(void* self);
 {}
 "#,
-            vec_functions("SomeType")
+            vec_functions("SomeType", false, "__swift_bridge__")
         );
 
         let module = parse_ok(tokens);
@@ -491,7 +780,7 @@ void __swift_bridge__$SomeType$_free(void* self);
 {}
 void __swift_bridge__$SomeType$foo(void* self, uint8_t val);
         "#,
-            vec_functions("SomeType")
+            vec_functions("SomeType", false, "__swift_bridge__")
         );
 
         let module = parse_ok(tokens);
@@ -522,7 +811,7 @@ void __swift_bridge__$SomeType$_free(void* self);
 {}
 void __swift_bridge__$SomeType$foo(void* self, void* val);
         "#,
-            vec_functions("SomeType")
+            vec_functions("SomeType", false, "__swift_bridge__")
         );
 
         let module = parse_ok(tokens);
@@ -554,7 +843,7 @@ void __swift_bridge__$SomeType$_free(void* self);
 {}
 uint8_t __swift_bridge__$SomeType$foo(void* self);
         "#,
-            vec_functions("SomeType")
+            vec_functions("SomeType", false, "__swift_bridge__")
         );
 
         let module = parse_ok(tokens);
@@ -627,4 +916,128 @@ struct __private__PointerToSwiftType __swift_bridge__$some_function(void);
             &expected,
         );
     }
+
+    /// Verify that the Objective-C header wraps the declarations in an `NS_ASSUME_NONNULL`
+    /// region and annotates an `Option` pointer parameter as `_Nullable`.
+    #[test]
+    fn objc_header_annotates_option_param_as_nullable() {
+        let tokens = quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                extern "Rust" {
+                    fn some_function(arg: Option<String>, other: u8);
+                }
+            }
+        };
+        let expected = r#"
+#import <Foundation/Foundation.h>
+#include <stdint.h>
+
+NS_ASSUME_NONNULL_BEGIN
+
+void __swift_bridge__$some_function(void* _Nullable arg, uint8_t other);
+
+NS_ASSUME_NONNULL_END
+        "#;
+
+        let module = parse_ok(tokens);
+        assert_trimmed_generated_equals_trimmed_expected(
+            &module.generate_objc_header_inner(&CodegenConfig::no_features_enabled()),
+            &expected,
+        );
+        assert_trimmed_generated_equals_trimmed_expected(
+            &module.generate_c_header_inner(&CodegenConfig::no_features_enabled()),
+            r#"
+#include <stdint.h>
+void __swift_bridge__$some_function(void* arg, uint8_t other);
+            "#,
+        );
+    }
+
+    /// Verify that when a C++ header is requested we wrap the declarations in `extern "C"`
+    /// guards and the configured namespace, while keeping the opaque type forward declarations.
+    #[test]
+    fn cpp_header_wraps_declarations_in_extern_c_and_namespace() {
+        let tokens = quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                extern "Rust" {
+                    type SomeType;
+                    fn some_function(arg: u8) -> SomeType;
+                }
+            }
+        };
+        let expected = r#"
+#include <stdint.h>
+#ifdef __cplusplus
+namespace my_crate::ffi {
+extern "C" {
+#endif
+
+typedef struct SomeType SomeType;
+void __swift_bridge__$SomeType$_free(void* self);
+
+void* __swift_bridge__$Vec_SomeType$new(void);
+void __swift_bridge__$Vec_SomeType$drop(void* vec_ptr);
+void __swift_bridge__$Vec_SomeType$push(void* vec_ptr, void* item_ptr);
+void* __swift_bridge__$Vec_SomeType$pop(void* vec_ptr);
+void* __swift_bridge__$Vec_SomeType$get(void* vec_ptr, uintptr_t index);
+void* __swift_bridge__$Vec_SomeType$get_mut(void* vec_ptr, uintptr_t index);
+uintptr_t __swift_bridge__$Vec_SomeType$len(void* vec_ptr);
+void* __swift_bridge__$Vec_SomeType$as_ptr(void* vec_ptr);
+
+void* __swift_bridge__$some_function(uint8_t arg);
+
+#ifdef __cplusplus
+} // extern "C"
+} // namespace my_crate::ffi
+#endif
+        "#;
+
+        let module = parse_ok(tokens);
+        let config = CodegenConfig {
+            cpp_header: Some(CppHeaderConfig {
+                namespace: Some("my_crate::ffi".to_string()),
+            }),
+            ..CodegenConfig::no_features_enabled()
+        };
+        assert_trimmed_generated_equals_trimmed_expected(
+            &module.generate_c_header_inner(&config),
+            &expected,
+        );
+    }
+
+    /// Verify that the C++ guards are emitted without a namespace when none was configured.
+    #[test]
+    fn cpp_header_without_namespace() {
+        let tokens = quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                extern "Rust" {
+                    fn some_function();
+                }
+            }
+        };
+        let expected = r#"
+#ifdef __cplusplus
+extern "C" {
+#endif
+
+void __swift_bridge__$some_function(void);
+
+#ifdef __cplusplus
+} // extern "C"
+#endif
+        "#;
+
+        let module = parse_ok(tokens);
+        let config = CodegenConfig {
+            cpp_header: Some(CppHeaderConfig::default()),
+            ..CodegenConfig::no_features_enabled()
+        };
+        assert_trimmed_generated_equals_trimmed_expected(
+            &module.generate_c_header_inner(&config),
+            &expected,
+        );
+    }
 }
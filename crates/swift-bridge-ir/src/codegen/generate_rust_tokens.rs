@@ -6,11 +6,19 @@ use proc_macro2::{Ident, TokenStream};
 use quote::quote;
 use quote::ToTokens;
 
-use crate::bridge_module_attributes::CfgAttr;
+use crate::bridged_type::{BridgedType, StdLibType};
+use crate::codegen::generate_rust_tokens::arc::generate_clone_function;
+use crate::codegen::generate_rust_tokens::comparable::generate_cmp_function;
+use crate::codegen::generate_rust_tokens::copy::generate_copy_type_ffi_repr;
+use crate::codegen::generate_rust_tokens::display::generate_to_string_function;
 use crate::codegen::generate_rust_tokens::vec::generate_vec_of_opaque_rust_type_functions;
 use crate::parse::{HostLang, SharedTypeDeclaration, TypeDeclaration};
-use crate::{SwiftBridgeModule, SWIFT_BRIDGE_PREFIX};
+use crate::SwiftBridgeModule;
 
+mod arc;
+mod comparable;
+mod copy;
+mod display;
 mod shared_enum;
 mod shared_struct;
 mod vec;
@@ -25,20 +33,84 @@ impl ToTokens for SwiftBridgeModule {
 
         let mut shared_struct_definitions = vec![];
         let mut shared_enum_definitions = vec![];
+        let mut result_definitions: Vec<(String, TokenStream)> = vec![];
+        let mut boxed_fn_definitions: Vec<(String, TokenStream)> = vec![];
+        let mut array_definitions: Vec<(String, TokenStream)> = vec![];
+        let mut tuple_definitions: Vec<(String, TokenStream)> = vec![];
         let mut impl_fn_tokens: HashMap<String, Vec<TokenStream>> = HashMap::new();
         let mut freestanding_rust_call_swift_fn_tokens = vec![];
         let mut extern_swift_fn_tokens = vec![];
 
         for func in &self.functions {
+            let cfg_attrs = &func.cfg_attrs;
+
+            for array in func.array_types(&self.types) {
+                let ffi_name = array.ffi_name_string();
+
+                if !array_definitions.iter().any(|(name, _)| name == &ffi_name) {
+                    array_definitions.push((ffi_name, array.generate_ffi_repr_tokens()));
+                }
+            }
+
+            for tuple in func.tuple_types(&self.types) {
+                let ffi_name = tuple.ffi_name_string();
+
+                if !tuple_definitions.iter().any(|(name, _)| name == &ffi_name) {
+                    tuple_definitions.push((
+                        ffi_name,
+                        tuple.generate_ffi_repr_tokens(&self.swift_bridge_path),
+                    ));
+                }
+            }
+
             match func.host_lang {
                 HostLang::Rust => {
-                    extern_rust_fn_tokens.push(
-                        func.to_extern_c_function_tokens(&self.swift_bridge_path, &self.types),
+                    let tokens = func.to_extern_c_function_tokens(
+                        &self.swift_bridge_path,
+                        &self.symbol_prefix,
+                        &self.types,
                     );
+                    extern_rust_fn_tokens.push(quote! {
+                        #(#cfg_attrs)*
+                        #tokens
+                    });
+
+                    if let Some(BridgedType::StdLib(StdLibType::Result(result))) =
+                        func.return_ty_built_in(&self.types)
+                    {
+                        let ffi_name = result.ffi_name_string();
+
+                        // Multiple functions can return the same Result type, but we only want to
+                        // define its FFI representation once.
+                        if !result_definitions.iter().any(|(name, _)| name == &ffi_name) {
+                            result_definitions.push((
+                                ffi_name,
+                                result.generate_ffi_repr_tokens(&self.swift_bridge_path),
+                            ));
+                        }
+                    }
+
+                    for boxed_fn in func.boxed_fn_args(&self.types) {
+                        let ffi_name = boxed_fn.ffi_name_string();
+
+                        if !boxed_fn_definitions
+                            .iter()
+                            .any(|(name, _)| name == &ffi_name)
+                        {
+                            boxed_fn_definitions.push((
+                                ffi_name,
+                                boxed_fn.generate_ffi_repr_tokens(&self.swift_bridge_path),
+                            ));
+                        }
+                    }
                 }
                 HostLang::Swift => {
                     let tokens = func
                         .to_rust_fn_that_calls_a_swift_extern(&self.swift_bridge_path, &self.types);
+                    let tokens = quote! {
+                        #(#cfg_attrs)*
+                        #tokens
+                    };
 
                     if let Some(ty) = func.associated_type.as_ref() {
                         match ty {
@@ -58,9 +130,15 @@ impl ToTokens for SwiftBridgeModule {
                         freestanding_rust_call_swift_fn_tokens.push(tokens);
                     }
 
-                    extern_swift_fn_tokens.push(
-                        func.to_extern_c_function_tokens(&self.swift_bridge_path, &self.types),
+                    let tokens = func.to_extern_c_function_tokens(
+                        &self.swift_bridge_path,
+                        &self.symbol_prefix,
+                        &self.types,
                     );
+                    extern_swift_fn_tokens.push(quote! {
+                        #(#cfg_attrs)*
+                        #tokens
+                    });
                 }
             };
         }
@@ -77,30 +155,61 @@ impl ToTokens for SwiftBridgeModule {
                         shared_enum_definitions.push(definition);
                     }
                 }
+                TypeDeclaration::Opaque(ty) if ty.is_generic_declaration() => {}
                 TypeDeclaration::Opaque(ty) => {
-                    let link_name = format!("{}${}$_free", SWIFT_BRIDGE_PREFIX, ty.to_string(),);
-                    let free_mem_func_name = Ident::new(
-                        &format!("{}{}__free", SWIFT_BRIDGE_PREFIX, ty.to_string()),
-                        ty.span(),
-                    );
-                    let this = &ty.ty;
+                    let link_name = ty.free_link_name(&self.symbol_prefix);
+                    let free_mem_func_name = Ident::new(&ty.free_func_name(), ty.span());
+                    let this = ty.rust_ty_tokens();
                     let ty_name = &ty.ty;
 
                     match ty.host_lang {
+                        HostLang::Rust if ty.copy.is_some() => {
+                            if !ty.already_declared {
+                                extern_rust_fn_tokens
+                                    .push(generate_copy_type_ffi_repr(ty, ty.copy.unwrap()));
+                            }
+                        }
                         HostLang::Rust => {
                             let free = quote! {
                                 #[export_name = #link_name]
-                                pub extern "C" fn #free_mem_func_name (this: *mut super::#this) {
+                                pub extern "C" fn #free_mem_func_name (this: *mut #this) {
                                     let this = unsafe { Box::from_raw(this) };
                                     drop(this);
                                 }
                             };
 
-                            let vec_functions = generate_vec_of_opaque_rust_type_functions(ty_name);
-
                             if !ty.already_declared {
-                                extern_rust_fn_tokens.push(free);
-                                extern_rust_fn_tokens.push(vec_functions);
+                                // A `no_free` type's lifetime is managed outside of the bridge.
+                                if !ty.no_free {
+                                    extern_rust_fn_tokens.push(free);
+                                }
+
+                                if ty.arc {
+                                    extern_rust_fn_tokens
+                                        .push(generate_clone_function(ty, &self.symbol_prefix));
+                                }
+
+                                if ty.comparable {
+                                    extern_rust_fn_tokens
+                                        .push(generate_cmp_function(ty, &self.symbol_prefix));
+                                }
+
+                                if ty.display {
+                                    extern_rust_fn_tokens.push(generate_to_string_function(
+                                        ty,
+                                        &self.swift_bridge_path,
+                                        &self.symbol_prefix,
+                                    ));
+                                }
+
+                                if ty.is_vectorizable() {
+                                    extern_rust_fn_tokens.push(
+                                        generate_vec_of_opaque_rust_type_functions(
+                                            ty_name,
+                                            &self.symbol_prefix,
+                                        ),
+                                    );
+                                }
                             }
                         }
                         HostLang::Swift => {
@@ -119,25 +228,35 @@ impl ToTokens for SwiftBridgeModule {
                                 }
                             };
 
+                            let drop_impl = if ty.no_free {
+                                quote! {}
+                            } else {
+                                quote! {
+                                    impl Drop for #ty_name {
+                                        fn drop (&mut self) {
+                                            unsafe { #free_mem_func_name(self.0) }
+                                        }
+                                    }
+                                }
+                            };
+
                             let struct_tokens = quote! {
                                 #[repr(C)]
                                 pub struct #ty_name(*mut std::ffi::c_void);
 
                                 #impls
 
-                                impl Drop for #ty_name {
-                                    fn drop (&mut self) {
-                                        unsafe { #free_mem_func_name(self.0) }
-                                    }
-                                }
+                                #drop_impl
                             };
                             structs_for_swift_classes.push(struct_tokens);
 
-                            let free = quote! {
-                                #[link_name = #link_name]
-                                fn #free_mem_func_name (this: *mut std::ffi::c_void);
-                            };
-                            extern_swift_fn_tokens.push(free);
+                            if !ty.no_free {
+                                let free = quote! {
+                                    #[link_name = #link_name]
+                                    fn #free_mem_func_name (this: *mut std::ffi::c_void);
+                                };
+                                extern_swift_fn_tokens.push(free);
+                            }
                         }
                     };
                 }
@@ -157,21 +276,35 @@ impl ToTokens for SwiftBridgeModule {
         let mut module_attributes = vec![];
 
         for cfg in &self.cfg_attrs {
-            match cfg {
-                CfgAttr::Feature(feature_name) => {
-                    //
-                    module_attributes.push(quote! {
-                        #[cfg(feature = #feature_name)]
-                    });
-                }
-            };
+            module_attributes.push(quote! { #cfg });
         }
 
+        let result_definitions = result_definitions
+            .into_iter()
+            .map(|(_, definition)| definition);
+        let boxed_fn_definitions = boxed_fn_definitions
+            .into_iter()
+            .map(|(_, definition)| definition);
+        let array_definitions = array_definitions
+            .into_iter()
+            .map(|(_, definition)| definition);
+        let tuple_definitions = tuple_definitions
+            .into_iter()
+            .map(|(_, definition)| definition);
+
         let module_inner = quote! {
             #(#shared_struct_definitions)*
 
             #(#shared_enum_definitions)*
 
+            #(#result_definitions)*
+
+            #(#boxed_fn_definitions)*
+
+            #(#array_definitions)*
+
+            #(#tuple_definitions)*
+
             #(#extern_rust_fn_tokens)*
 
             #(#freestanding_rust_call_swift_fn_tokens)*
@@ -422,7 +555,7 @@ mod tests {
         let expected_func = quote! {
             #[export_name = "__swift_bridge__$some_function"]
             pub extern "C" fn __swift_bridge__some_function () -> *mut super::Foo {
-                Box::into_raw(Box::new(super::some_function().into())) as *mut super::Foo
+                Box::into_raw(Box::new({ let val: super::Foo = super::some_function().into(); val })) as *mut super::Foo
             }
         };
 
@@ -710,7 +843,7 @@ mod tests {
         let expected = quote! {
             #[export_name = "__swift_bridge__$SomeType$message"]
             pub extern "C" fn __swift_bridge__SomeType_message (
-                this: *mut super::SomeType,
+                this: *const super::SomeType,
                 val: u8
             ) {
                 (unsafe { &*this }).message(val)
@@ -830,7 +963,11 @@ mod tests {
         let function = &module.functions[0];
 
         assert_tokens_eq(
-            &function.to_extern_c_function_tokens(&module.swift_bridge_path, &module.types),
+            &function.to_extern_c_function_tokens(
+                &module.swift_bridge_path,
+                &module.symbol_prefix,
+                &module.types,
+            ),
             &expected_fn,
         );
     }
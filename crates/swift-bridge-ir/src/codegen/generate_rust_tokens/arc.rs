@@ -0,0 +1,22 @@
+use crate::parse::OpaqueForeignTypeDeclaration;
+use proc_macro2::{Ident, TokenStream};
+use quote::quote;
+
+/// Generate the function that Swift calls in order to copy an `Arc`-backed opaque Rust type.
+///
+/// The copy shares ownership with the original by incrementing the `Arc`'s reference count.
+pub(super) fn generate_clone_function(
+    ty: &OpaqueForeignTypeDeclaration,
+    symbol_prefix: &str,
+) -> TokenStream {
+    let link_name = ty.clone_link_name(symbol_prefix);
+    let func_name = Ident::new(&ty.clone_func_name(), ty.span());
+    let this = ty.rust_ty_tokens();
+
+    quote! {
+        #[export_name = #link_name]
+        pub extern "C" fn #func_name (this: *mut #this) -> *mut #this {
+            Box::into_raw(Box::new(std::sync::Arc::clone(unsafe { &*this })))
+        }
+    }
+}
@@ -0,0 +1,23 @@
+use crate::parse::OpaqueForeignTypeDeclaration;
+use proc_macro2::{Ident, TokenStream};
+use quote::quote;
+
+/// Generate the function that Swift calls inside of an opaque Rust type's `Comparable`
+/// implementation in order to compare two values using the type's `Ord` implementation.
+///
+/// `std::cmp::Ordering` is `#[repr(i8)]`, so the result is -1, 0 or 1.
+pub(super) fn generate_cmp_function(
+    ty: &OpaqueForeignTypeDeclaration,
+    symbol_prefix: &str,
+) -> TokenStream {
+    let link_name = ty.cmp_link_name(symbol_prefix);
+    let func_name = Ident::new(&ty.cmp_func_name(), ty.span());
+    let this = ty.rust_ty_tokens();
+
+    quote! {
+        #[export_name = #link_name]
+        pub extern "C" fn #func_name (lhs: *mut #this, rhs: *mut #this) -> i8 {
+            std::cmp::Ord::cmp(unsafe { &*lhs }, unsafe { &*rhs }) as i8
+        }
+    }
+}
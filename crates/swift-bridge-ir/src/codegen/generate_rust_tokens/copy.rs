@@ -0,0 +1,43 @@
+use crate::parse::OpaqueForeignTypeDeclaration;
+use proc_macro2::{Literal, TokenStream};
+use quote::quote;
+
+/// Generate the FFI representation of a `#[swift_bridge(Copy(N))]` opaque Rust type.
+///
+/// The type gets passed across the FFI boundary as its bytes instead of being boxed, so we make
+/// sure at compile time that the declared size is correct and that the type is `Copy`.
+pub(super) fn generate_copy_type_ffi_repr(
+    ty: &OpaqueForeignTypeDeclaration,
+    size_bytes: usize,
+) -> TokenStream {
+    let this = ty.rust_ty_tokens();
+    let ffi_repr = ty.copy_ffi_repr_tokens();
+    let size_bytes = Literal::usize_unsuffixed(size_bytes);
+
+    quote! {
+        #[repr(C)]
+        #[doc(hidden)]
+        pub struct #ffi_repr([u8; #size_bytes]);
+
+        impl #ffi_repr {
+            #[inline(always)]
+            fn into_rust_repr(self) -> #this {
+                unsafe { std::mem::transmute::<#ffi_repr, #this>(self) }
+            }
+
+            #[inline(always)]
+            fn from_rust_repr(repr: #this) -> #ffi_repr {
+                unsafe { std::mem::transmute::<#this, #ffi_repr>(repr) }
+            }
+        }
+
+        const _: () = {
+            let _: [u8; std::mem::size_of::<#this>()] = [0; #size_bytes];
+
+            fn assert_copy<T: Copy>() {}
+            fn _assert_copy() {
+                assert_copy::<#this>();
+            }
+        };
+    }
+}
@@ -0,0 +1,28 @@
+use crate::parse::OpaqueForeignTypeDeclaration;
+use proc_macro2::{Ident, TokenStream};
+use quote::quote;
+use syn::Path;
+
+/// Generate the function that Swift calls inside of an opaque Rust type's
+/// `CustomStringConvertible` implementation in order to format the type using its `Display`
+/// implementation.
+pub(super) fn generate_to_string_function(
+    ty: &OpaqueForeignTypeDeclaration,
+    swift_bridge_path: &Path,
+    symbol_prefix: &str,
+) -> TokenStream {
+    let link_name = ty.to_string_link_name(symbol_prefix);
+    let func_name = Ident::new(&ty.to_string_func_name(), ty.span());
+    let this = ty.rust_ty_tokens();
+
+    quote! {
+        #[export_name = #link_name]
+        pub extern "C" fn #func_name (
+            this: *mut #this
+        ) -> *mut #swift_bridge_path::string::RustString {
+            #swift_bridge_path::string::RustString(
+                (unsafe { &*this }).to_string()
+            ).box_into_raw()
+        }
+    }
+}
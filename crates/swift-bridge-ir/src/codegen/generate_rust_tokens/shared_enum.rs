@@ -1,10 +1,11 @@
 //! More tests can be found in
 //! crates/swift-bridge-ir/src/codegen/codegen_tests/shared_enum_codegen_tests.rs
 
-use crate::bridged_type::SharedEnum;
+use crate::bridged_type::{BridgedType, EnumVariant, NormalizedStructField, SharedEnum};
 use crate::{SwiftBridgeModule, SWIFT_BRIDGE_PREFIX};
-use proc_macro2::TokenStream;
+use proc_macro2::{Span, TokenStream};
 use quote::quote;
+use syn::spanned::Spanned;
 use syn::Ident;
 
 impl SwiftBridgeModule {
@@ -26,16 +27,54 @@ impl SwiftBridgeModule {
 
         for variant in shared_enum.variants.iter() {
             let variant_name = &variant.name;
+
+            let fields: Vec<TokenStream> = variant
+                .fields
+                .normalized_fields()
+                .iter()
+                .map(|norm_field| {
+                    let maybe_name_and_colon = norm_field.maybe_name_and_colon();
+                    let ty = &norm_field.ty;
+
+                    quote! {
+                        #maybe_name_and_colon #ty
+                    }
+                })
+                .collect();
+            let fields = variant.fields.wrap_fields(&fields);
+            let discriminant = variant.discriminant.map(|discriminant| {
+                let discriminant = proc_macro2::Literal::i32_unsuffixed(discriminant);
+                quote! { = #discriminant }
+            });
+
             let v = quote! {
-                #variant_name
+                #variant_name #fields #discriminant
             };
             enum_variants.push(v);
         }
 
         for variant in shared_enum.variants.iter() {
             let variant_name = &variant.name;
+
+            let fields: Vec<TokenStream> = variant
+                .fields
+                .normalized_fields()
+                .iter()
+                .map(|norm_field| {
+                    let maybe_name_and_colon = norm_field.maybe_name_and_colon();
+
+                    let ty = BridgedType::new_with_type(&norm_field.ty, &self.types).unwrap();
+                    let ty = ty.to_ffi_compatible_rust_type(swift_bridge_path);
+
+                    quote! {
+                        #maybe_name_and_colon #ty
+                    }
+                })
+                .collect();
+            let fields = variant.fields.wrap_fields(&fields);
+
             let v = quote! {
-                #variant_name
+                #variant_name #fields
             };
             enum_ffi_variants.push(v);
         }
@@ -45,55 +84,121 @@ impl SwiftBridgeModule {
 
         for variant in shared_enum.variants.iter() {
             let variant_name = &variant.name;
+
+            let bindings = variant_field_bindings(variant);
+            let converted_fields = self.convert_variant_fields(variant, |ty, binding| {
+                ty.convert_rust_value_to_ffi_compatible_value(binding, swift_bridge_path)
+            });
+
             let v = quote! {
-                #enum_name :: #variant_name => #enum_ffi_name :: #variant_name
+                #enum_name :: #variant_name #bindings => #enum_ffi_name :: #variant_name #converted_fields
             };
             convert_rust_variants_to_ffi.push(v);
         }
 
         for variant in shared_enum.variants.iter() {
             let variant_name = &variant.name;
+
+            let bindings = variant_field_bindings(variant);
+            let converted_fields = self.convert_variant_fields(variant, |ty, binding| {
+                ty.convert_ffi_value_to_rust_value(binding, binding.span())
+            });
+
             let v = quote! {
-                #enum_ffi_name :: #variant_name => #enum_name :: #variant_name
+                #enum_ffi_name :: #variant_name #bindings => #enum_name :: #variant_name #converted_fields
             };
             convert_ffi_variants_to_rust.push(v);
         }
 
-        let definition = quote! {
-            pub enum #enum_name {
-                #(#enum_variants),*
-            }
-
-            #[repr(C)]
-            #[doc(hidden)]
-            pub enum #enum_ffi_name {
-                #(#enum_ffi_variants),*
-            }
+        let repr = if shared_enum.has_explicit_discriminants() {
+            quote! { #[repr(i32)] }
+        } else {
+            quote! {}
+        };
 
-            impl #swift_bridge_path::SharedEnum for #enum_name {
-                type FfiRepr = #enum_ffi_name;
-            }
+        // A transparent enum is passed over FFI as its `i32` discriminant instead of as a tagged
+        // union.
+        let (ffi_repr, ffi_conversions) = if shared_enum.transparent {
+            let variant_names = shared_enum.variants.iter().map(|v| &v.name);
+            let discriminants = shared_enum
+                .variants
+                .iter()
+                .map(|v| proc_macro2::Literal::i32_unsuffixed(v.discriminant.unwrap_or_default()));
 
-            impl #enum_name {
+            let ffi_repr = quote! {
+                #[repr(transparent)]
                 #[doc(hidden)]
-                #[inline(always)]
-                pub fn into_ffi_repr(self) -> #enum_ffi_name {
-                    match self {
-                        #(#convert_rust_variants_to_ffi),*
+                pub struct #enum_ffi_name(i32);
+            };
+            let ffi_conversions = quote! {
+                impl #enum_name {
+                    #[doc(hidden)]
+                    #[inline(always)]
+                    pub fn into_ffi_repr(self) -> #enum_ffi_name {
+                        #enum_ffi_name(self as i32)
                     }
                 }
-            }
 
-            impl #enum_ffi_name {
+                impl #enum_ffi_name {
+                    #[doc(hidden)]
+                    #[inline(always)]
+                    pub fn into_rust_repr(self) -> #enum_name {
+                        match self.0 {
+                            #(#discriminants => #enum_name :: #variant_names,)*
+                            _ => unreachable!(),
+                        }
+                    }
+                }
+            };
+
+            (ffi_repr, ffi_conversions)
+        } else {
+            let ffi_repr = quote! {
+                #[repr(C)]
                 #[doc(hidden)]
-                #[inline(always)]
-                pub fn into_rust_repr(self) -> #enum_name {
-                    match self {
-                        #(#convert_ffi_variants_to_rust),*
+                pub enum #enum_ffi_name {
+                    #(#enum_ffi_variants),*
+                }
+            };
+            let ffi_conversions = quote! {
+                impl #enum_name {
+                    #[doc(hidden)]
+                    #[inline(always)]
+                    pub fn into_ffi_repr(self) -> #enum_ffi_name {
+                        match self {
+                            #(#convert_rust_variants_to_ffi),*
+                        }
                     }
                 }
+
+                impl #enum_ffi_name {
+                    #[doc(hidden)]
+                    #[inline(always)]
+                    pub fn into_rust_repr(self) -> #enum_name {
+                        match self {
+                            #(#convert_ffi_variants_to_rust),*
+                        }
+                    }
+                }
+            };
+
+            (ffi_repr, ffi_conversions)
+        };
+
+        let definition = quote! {
+            #repr
+            pub enum #enum_name {
+                #(#enum_variants),*
             }
 
+            #ffi_repr
+
+            impl #swift_bridge_path::SharedEnum for #enum_name {
+                type FfiRepr = #enum_ffi_name;
+            }
+
+            #ffi_conversions
+
             #[repr(C)]
             #[doc(hidden)]
             pub struct #option_enum {
@@ -132,4 +237,54 @@ impl SwiftBridgeModule {
 
         Some(definition)
     }
+
+    /// Convert each of a variant's fields, which are bound using the names from
+    /// `variant_field_bindings`.
+    ///
+    /// { field_a: convert(field_a) } or (convert(_0), convert(_1))
+    fn convert_variant_fields(
+        &self,
+        variant: &EnumVariant,
+        convert: impl Fn(&BridgedType, &TokenStream) -> TokenStream,
+    ) -> TokenStream {
+        let converted_fields: Vec<TokenStream> = variant
+            .fields
+            .normalized_fields()
+            .iter()
+            .map(|norm_field| {
+                let maybe_name_and_colon = norm_field.maybe_name_and_colon();
+                let binding = field_binding(norm_field);
+
+                let ty = BridgedType::new_with_type(&norm_field.ty, &self.types).unwrap();
+                let converted_field = convert(&ty, &quote! { #binding });
+
+                quote! {
+                    #maybe_name_and_colon #converted_field
+                }
+            })
+            .collect();
+
+        variant.fields.wrap_fields(&converted_fields)
+    }
+}
+
+/// The pattern that binds each of a variant's fields to a variable.
+///
+/// { field_a, field_b } or (_0, _1)
+fn variant_field_bindings(variant: &EnumVariant) -> TokenStream {
+    let bindings: Vec<TokenStream> = variant
+        .fields
+        .normalized_fields()
+        .iter()
+        .map(|norm_field| {
+            let binding = field_binding(norm_field);
+            quote! { #binding }
+        })
+        .collect();
+
+    variant.fields.wrap_fields(&bindings)
+}
+
+fn field_binding(norm_field: &NormalizedStructField) -> Ident {
+    Ident::new(&norm_field.ffi_field_name(), Span::call_site())
 }
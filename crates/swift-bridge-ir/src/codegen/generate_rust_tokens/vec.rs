@@ -5,11 +5,14 @@ use quote::quote;
 /// Rust type's Vectorizable implementation.
 ///
 /// So inside of `extension MyRustType: Vectorizable {}` on the Swift side.
-pub(super) fn generate_vec_of_opaque_rust_type_functions(ty: &Ident) -> TokenStream {
+pub(super) fn generate_vec_of_opaque_rust_type_functions(
+    ty: &Ident,
+    symbol_prefix: &str,
+) -> TokenStream {
     // examples:
     // "__swift_bridge__$Vec_MyRustType$new"
     // "__swift_bridge__$Vec_MyRustType$drop"
-    let make_export_name = |fn_name| format!("__swift_bridge__$Vec_{}${}", ty, fn_name);
+    let make_export_name = |fn_name| format!("{}$Vec_{}${}", symbol_prefix, ty, fn_name);
     let export_name_new = make_export_name("new");
     let export_name_drop = make_export_name("drop");
     let export_name_len = make_export_name("len");
@@ -168,10 +171,10 @@ mod tests {
         };
 
         assert_tokens_eq(
-            &generate_vec_of_opaque_rust_type_functions(&Ident::new(
-                "ARustType",
-                Span::call_site(),
-            )),
+            &generate_vec_of_opaque_rust_type_functions(
+                &Ident::new("ARustType", Span::call_site()),
+                "__swift_bridge__",
+            ),
             &expected,
         );
     }
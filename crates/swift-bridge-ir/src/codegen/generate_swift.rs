@@ -1,18 +1,21 @@
-use std::collections::{HashMap, HashSet};
+use std::collections::{BTreeSet, HashMap};
 use std::ops::Deref;
 
 use quote::ToTokens;
-use syn::{Path, ReturnType, Type};
+use syn::{FnArg, Path, ReturnType, Type};
 
-use crate::bridged_type::{fn_arg_name, BridgedType, StdLibType, TypePosition};
+use crate::bridged_type::{
+    fn_arg_name, pat_type_pat_is_self, snake_case_to_camel_case, BridgedType, StdLibType,
+    TypePosition,
+};
 use crate::codegen::generate_swift::vec::generate_vectorizable_extension;
 use crate::codegen::CodegenConfig;
 use crate::parse::{
-    HostLang, OpaqueForeignTypeDeclaration, SharedTypeDeclaration, TypeDeclaration,
-    TypeDeclarations,
+    HostLang, OpaqueForeignTypeDeclaration, SharedTypeDeclaration, SwiftAvailability,
+    TypeDeclaration, TypeDeclarations,
 };
-use crate::parsed_extern_fn::ParsedExternFn;
-use crate::{SwiftBridgeModule, SWIFT_BRIDGE_PREFIX};
+use crate::parsed_extern_fn::{Getter, ParsedExternFn};
+use crate::SwiftBridgeModule;
 
 mod vec;
 
@@ -33,6 +36,10 @@ impl SwiftBridgeModule {
         let mut class_protocols: HashMap<String, ClassProtocols> = HashMap::new();
 
         for function in &self.functions {
+            if !config.cfg_attrs_are_enabled(&function.cfg_attrs) {
+                continue;
+            }
+
             if function.host_lang.is_rust() {
                 if let Some(ty) = function.associated_type.as_ref() {
                     match ty {
@@ -42,13 +49,13 @@ impl SwiftBridgeModule {
                         }
                         TypeDeclaration::Opaque(opaque_ty) => {
                             associated_funcs_and_methods
-                                .entry(opaque_ty.to_string())
+                                .entry(opaque_ty.swift_name())
                                 .or_default()
                                 .push(function);
 
                             if function.is_swift_identifiable {
                                 let identifiable_protocol = IdentifiableProtocol {
-                                    func_name: function.func.sig.ident.to_string(),
+                                    func_name: function.swift_name_string(),
                                     return_ty: BridgedType::new_with_return_type(
                                         &function.func.sig.output,
                                         &self.types,
@@ -57,10 +64,42 @@ impl SwiftBridgeModule {
                                     .to_swift_type(TypePosition::FnReturn(opaque_ty.host_lang)),
                                 };
                                 class_protocols
-                                    .entry(opaque_ty.to_string())
+                                    .entry(opaque_ty.swift_name())
                                     .or_default()
                                     .identifiable = Some(identifiable_protocol);
                             }
+
+                            if function.is_swift_iterator {
+                                let iterator_protocol = IteratorProtocol {
+                                    func_name: function.swift_name_string(),
+                                    return_ty: BridgedType::new_with_return_type(
+                                        &function.func.sig.output,
+                                        &self.types,
+                                    )
+                                    .unwrap()
+                                    .to_swift_type(TypePosition::FnReturn(opaque_ty.host_lang)),
+                                };
+                                class_protocols
+                                    .entry(opaque_ty.swift_name())
+                                    .or_default()
+                                    .iterator = Some(iterator_protocol);
+                            }
+
+                            if function.is_swift_async_iterator {
+                                let iterator_protocol = IteratorProtocol {
+                                    func_name: function.swift_name_string(),
+                                    return_ty: BridgedType::new_with_return_type(
+                                        &function.func.sig.output,
+                                        &self.types,
+                                    )
+                                    .unwrap()
+                                    .to_swift_type(TypePosition::FnReturn(opaque_ty.host_lang)),
+                                };
+                                class_protocols
+                                    .entry(opaque_ty.swift_name())
+                                    .or_default()
+                                    .async_iterator = Some(iterator_protocol);
+                            }
                         }
                     };
                     continue;
@@ -68,13 +107,19 @@ impl SwiftBridgeModule {
             }
 
             let func_definition = match function.host_lang {
-                HostLang::Rust => {
-                    gen_func_swift_calls_rust(function, &self.types, &self.swift_bridge_path)
-                }
+                HostLang::Rust => gen_func_swift_calls_rust(
+                    function,
+                    &self.types,
+                    &self.swift_bridge_path,
+                    &self.symbol_prefix,
+                ),
+                // The user already wrote a Swift function with this symbol.
+                HostLang::Swift if function.extern_swift_name.is_some() => continue,
                 HostLang::Swift => gen_function_exposes_swift_to_rust(
                     function,
                     &self.types,
                     &self.swift_bridge_path,
+                    &self.symbol_prefix,
                 ),
             };
 
@@ -96,34 +141,71 @@ impl SwiftBridgeModule {
                         swift += "\n";
                     }
                 }
+                TypeDeclaration::Opaque(ty) if ty.is_generic_declaration() => {}
                 TypeDeclaration::Opaque(ty) => match ty.host_lang {
                     HostLang::Rust => {
-                        let class_protocols = class_protocols.get(&ty.ty.to_string());
+                        let class_protocols = class_protocols.get(&ty.swift_name());
                         let default_cp = ClassProtocols::default();
                         let class_protocols = class_protocols.unwrap_or(&default_cp);
 
-                        swift += &generate_swift_class(
+                        let mut class = generate_swift_class(
                             ty,
                             &associated_funcs_and_methods,
                             class_protocols,
                             &self.types,
                             &self.swift_bridge_path,
+                            &self.symbol_prefix,
+                            config.library_evolution,
                         );
-                        swift += "\n";
+                        class += "\n";
+
+                        if !ty.already_declared && ty.is_vectorizable() {
+                            class += &generate_vectorizable_extension(
+                                &ty,
+                                &ty.swift_name(),
+                                &self.symbol_prefix,
+                            );
+                            class += "\n";
+                        }
 
-                        if !ty.already_declared {
-                            swift += &generate_vectorizable_extension(&ty);
-                            swift += "\n";
+                        if let Some(available) = ty.available.as_ref() {
+                            class = annotate_top_level_declarations(&class, available);
                         }
+
+                        swift += &class;
                     }
                     HostLang::Swift => {
-                        swift += &generate_drop_swift_instance_reference_count(ty);
-                        swift += "\n";
+                        if !ty.no_free {
+                            swift += &generate_drop_swift_instance_reference_count(
+                                ty,
+                                &self.symbol_prefix,
+                            );
+                            swift += "\n";
+                        }
                     }
                 },
             };
         }
 
+        // Swift imports are per file, so we need to import Foundation ourselves in order to use
+        // types such as `TimeInterval` and `URL`, as well as the `LocalizedError` protocol.
+        let uses_localized_error = self.types.types().iter().any(|ty| {
+            matches!(
+                ty,
+                TypeDeclaration::Shared(SharedTypeDeclaration::Enum(shared_enum))
+                    if shared_enum.localized_error
+            )
+        });
+        if uses_localized_error
+            || self
+                .functions
+                .iter()
+                .filter(|function| config.cfg_attrs_are_enabled(&function.cfg_attrs))
+                .any(|function| function.uses_foundation(&self.types))
+        {
+            swift = format!("import Foundation\n{}", swift);
+        }
+
         swift
     }
 }
@@ -132,11 +214,20 @@ impl SwiftBridgeModule {
 struct ClassProtocols {
     // The name of the function to use for the Identifiable protocol implementation.
     identifiable: Option<IdentifiableProtocol>,
+    // The name of the function to use for the IteratorProtocol implementation's `next()`.
+    iterator: Option<IteratorProtocol>,
+    // The name of the async function to use for the AsyncIteratorProtocol implementation's
+    // `next()`.
+    async_iterator: Option<IteratorProtocol>,
 }
 struct IdentifiableProtocol {
     func_name: String,
     return_ty: String,
 }
+struct IteratorProtocol {
+    func_name: String,
+    return_ty: String,
+}
 
 fn generate_swift_class(
     ty: &OpaqueForeignTypeDeclaration,
@@ -144,27 +235,47 @@ fn generate_swift_class(
     class_protocols: &ClassProtocols,
     types: &TypeDeclarations,
     swift_bridge_path: &Path,
+    symbol_prefix: &str,
+    library_evolution: bool,
 ) -> String {
-    let type_name = ty.to_string();
+    let type_name = ty.swift_name();
+
+    // The raw pointer initializers are an implementation detail, so we keep them out of the
+    // public interface of modules that are compiled with library evolution.
+    let ptr_init_visibility = if library_evolution { "" } else { "public " };
 
     let mut initializers = vec![];
+    let mut static_methods = vec![];
 
     let mut owned_self_methods = vec![];
     let mut ref_self_methods = vec![];
     let mut ref_mut_self_methods = vec![];
 
+    let mut string_literal_init_label = None;
+
     if let Some(methods) = associated_funcs_and_methods.get(&type_name) {
         for type_method in methods {
             // TODO: Normalize with freestanding func codegen above
 
-            let func_definition = gen_func_swift_calls_rust(type_method, types, swift_bridge_path);
+            if type_method.is_swift_constant {
+                static_methods.push(gen_swift_constant(type_method, types, symbol_prefix));
+                continue;
+            }
 
-            let is_class_func = type_method.func.sig.inputs.is_empty();
+            let func_definition =
+                gen_func_swift_calls_rust(type_method, types, swift_bridge_path, symbol_prefix);
 
             if type_method.is_swift_initializer {
+                if ty.expressible_by_string_literal && string_literal_init_label.is_none() {
+                    string_literal_init_label = type_method.string_literal_initializer_label(types);
+                }
                 initializers.push(func_definition);
-            } else if is_class_func {
-                ref_self_methods.push(func_definition);
+            } else if !type_method.is_method() {
+                static_methods.push(func_definition);
+            } else if ty.copy.is_some() {
+                // A `Copy` type is a struct that gets passed by value, so there are no `Ref` and
+                // `RefMut` classes to put its methods on.
+                owned_self_methods.push(func_definition);
             } else {
                 if type_method.self_reference().is_some() {
                     if type_method.self_mutability().is_some() {
@@ -179,40 +290,95 @@ fn generate_swift_class(
         }
     }
 
+    if ty.copy.is_some() {
+        return generate_swift_copy_struct(ty, &initializers, &static_methods, &owned_self_methods);
+    }
+
     let class_decl = if ty.already_declared {
         "".to_string()
     } else {
-        let free_func_call = format!("{}${}$_free(ptr)", SWIFT_BRIDGE_PREFIX, type_name);
-
-        format!(
-            r#"public class {type_name}: {type_name}RefMut {{
-    var isOwned: Bool = true
-
-    public override init(ptr: UnsafeMutableRawPointer) {{
-        super.init(ptr: ptr)
-    }}
+        // A `no_free` type's lifetime is managed outside of the bridge, so the class never frees
+        // the value that it points to.
+        let deinit = if ty.no_free {
+            "".to_string()
+        } else {
+            format!(
+                r#"
 
     deinit {{
         if isOwned {{
-            {free_func_call}
+            {}(ptr)
         }}
+    }}"#,
+                ty.free_link_name(symbol_prefix)
+            )
+        };
+
+        // The owned class inherits every method, so it is the one that conforms to the user's
+        // protocols.
+        let mut conforms_to: String = ty
+            .conforms_to
+            .iter()
+            .map(|protocol| format!(", {}", protocol))
+            .collect();
+
+        // A non-final class can only satisfy a protocol's initializers with `required`
+        // initializers, and those have to be declared in the class itself instead of in an
+        // extension.
+        let string_literal_inits = match string_literal_init_label.as_ref() {
+            Some(label) => {
+                conforms_to += ", ExpressibleByStringLiteral";
+                format!(
+                    r#"
+
+    {visibility} required convenience init(stringLiteral value: String) {{
+        self.init({label}value)
     }}
+
+    {visibility} required convenience init(extendedGraphemeClusterLiteral value: String) {{
+        self.init(stringLiteral: value)
+    }}
+
+    {visibility} required convenience init(unicodeScalarLiteral value: String) {{
+        self.init(stringLiteral: value)
+    }}"#,
+                    visibility = ty.swift_visibility.as_swift_keyword(),
+                    label = label,
+                )
+            }
+            None => "".to_string(),
+        };
+
+        format!(
+            r#"{doc_comment}{visibility} class {type_name}: {type_name}RefMut{conforms_to} {{
+    var isOwned: Bool = true
+
+    {ptr_init_visibility}override init(ptr: UnsafeMutableRawPointer) {{
+        super.init(ptr: ptr)
+    }}{string_literal_inits}{deinit}
 }}"#,
+            doc_comment = swift_doc_comment(ty.doc_comment.as_ref(), ""),
+            visibility = ty.swift_visibility.as_swift_keyword(),
             type_name = type_name,
-            free_func_call = free_func_call
+            conforms_to = conforms_to,
+            ptr_init_visibility = ptr_init_visibility,
+            string_literal_inits = string_literal_inits,
+            deinit = deinit
         )
     };
-    let class_ref_mut_decl = if ty.already_declared {
+    let mut class_ref_mut_decl = if ty.already_declared {
         "".to_string()
     } else {
         format!(
             r#"
-public class {type_name}RefMut: {type_name}Ref {{
-    public override init(ptr: UnsafeMutableRawPointer) {{
+{visibility} class {type_name}RefMut: {type_name}Ref {{
+    {ptr_init_visibility}override init(ptr: UnsafeMutableRawPointer) {{
         super.init(ptr: ptr)
     }}
 }}"#,
-            type_name = type_name
+            visibility = ty.swift_visibility.as_swift_keyword(),
+            type_name = type_name,
+            ptr_init_visibility = ptr_init_visibility
         )
     };
     let mut class_ref_decl = if ty.already_declared {
@@ -220,16 +386,72 @@ public class {type_name}RefMut: {type_name}Ref {{
     } else {
         format!(
             r#"
-public class {type_name}Ref {{
+{visibility} class {type_name}Ref {{
     var ptr: UnsafeMutableRawPointer
 
-    public init(ptr: UnsafeMutableRawPointer) {{
+    {ptr_init_visibility}init(ptr: UnsafeMutableRawPointer) {{
         self.ptr = ptr
     }}
 }}"#,
+            visibility = ty.swift_visibility.as_swift_keyword(),
             type_name = type_name,
+            ptr_init_visibility = ptr_init_visibility,
         )
     };
+    // `IteratorProtocol` needs a `next()` method, and Swift gives every `IteratorProtocol` that is
+    // also a `Sequence` a `makeIterator()` that returns itself.
+    if let Some(iterator) = class_protocols.iterator.as_ref() {
+        let next_func = if iterator.func_name == "next" {
+            "".to_string()
+        } else {
+            format!(
+                r#"
+    public func next() -> {iterator_return_ty} {{
+        return self.{iterator_func}()
+    }}
+"#,
+                iterator_func = iterator.func_name,
+                iterator_return_ty = iterator.return_ty
+            )
+        };
+
+        class_ref_mut_decl += &format!(
+            r#"
+extension {type_name}RefMut: Sequence, IteratorProtocol {{{next_func}}}"#,
+            type_name = type_name,
+            next_func = next_func,
+        );
+    }
+    // Unlike `Sequence`, an `AsyncSequence` that is its own iterator does not get a default
+    // `makeAsyncIterator()`.
+    // We only call into Rust when Swift asks for the next element, so a Rust stream never gets
+    // polled ahead of its consumer.
+    if let Some(iterator) = class_protocols.async_iterator.as_ref() {
+        let next_func = if iterator.func_name == "next" {
+            "".to_string()
+        } else {
+            format!(
+                r#"
+
+    public func next() async -> {iterator_return_ty} {{
+        return await self.{iterator_func}()
+    }}"#,
+                iterator_func = iterator.func_name,
+                iterator_return_ty = iterator.return_ty
+            )
+        };
+
+        class_ref_mut_decl += &format!(
+            r#"
+extension {type_name}RefMut: AsyncSequence, AsyncIteratorProtocol {{
+    public func makeAsyncIterator() -> {type_name}RefMut {{
+        self
+    }}{next_func}
+}}"#,
+            type_name = type_name,
+            next_func = next_func,
+        );
+    }
     if let Some(identifiable) = class_protocols.identifiable.as_ref() {
         let identifiable_var = if identifiable.func_name == "id" {
             "".to_string()
@@ -253,6 +475,63 @@ extension {type_name}Ref: Identifiable {{{identifiable_var}}}"#,
         );
     }
 
+    // Swift can't verify that a class which wraps a pointer to Rust memory is thread safe, so the
+    // user is responsible for making sure that the Rust type is `Send + Sync`.
+    let class_decl = if ty.sendable && !ty.already_declared {
+        format!(
+            "{}\nextension {}: @unchecked Sendable {{}}",
+            class_decl, type_name
+        )
+    } else {
+        class_decl
+    };
+
+    // Copying an `Arc`-backed type increments the `Arc`'s reference count, so the copy shares
+    // the same Rust value as the original.
+    if ty.arc && !ty.already_declared {
+        class_ref_decl += &format!(
+            r#"
+extension {type_name}Ref {{
+    public func copy() -> {type_name} {{
+        {type_name}(ptr: {clone_link_name}(ptr))
+    }}
+}}"#,
+            type_name = type_name,
+            clone_link_name = ty.clone_link_name(symbol_prefix),
+        );
+    }
+
+    // `Comparable` refines `Equatable`, so we generate `==` along with `<`.
+    if ty.comparable && !ty.already_declared {
+        class_ref_decl += &format!(
+            r#"
+extension {type_name}Ref: Comparable {{
+    public static func < (lhs: {type_name}Ref, rhs: {type_name}Ref) -> Bool {{
+        {cmp_link_name}(lhs.ptr, rhs.ptr) < 0
+    }}
+
+    public static func == (lhs: {type_name}Ref, rhs: {type_name}Ref) -> Bool {{
+        {cmp_link_name}(lhs.ptr, rhs.ptr) == 0
+    }}
+}}"#,
+            type_name = type_name,
+            cmp_link_name = ty.cmp_link_name(symbol_prefix),
+        );
+    }
+
+    if ty.display && !ty.already_declared {
+        class_ref_decl += &format!(
+            r#"
+extension {type_name}Ref: CustomStringConvertible {{
+    public var description: String {{
+        RustString(ptr: {to_string_link_name}(ptr)).toString()
+    }}
+}}"#,
+            type_name = type_name,
+            to_string_link_name = ty.to_string_link_name(symbol_prefix),
+        );
+    }
+
     let initializers = if initializers.len() == 0 {
         "".to_string()
     } else {
@@ -267,6 +546,20 @@ extension {type_name} {{
         )
     };
 
+    let static_methods = if static_methods.is_empty() {
+        "".to_string()
+    } else {
+        let static_methods: String = static_methods.join("\n\n");
+        format!(
+            r#"
+extension {type_name} {{
+{static_methods}
+}}"#,
+            type_name = type_name,
+            static_methods = static_methods
+        )
+    };
+
     let owned_instance_methods = if owned_self_methods.len() == 0 {
         "".to_string()
     } else {
@@ -311,11 +604,12 @@ extension {type_name}RefMut {{
 
     let class = format!(
         r#"
-{class_decl}{initializers}{owned_instance_methods}{class_ref_decl}{ref_mut_instance_methods}{class_ref_mut_decl}{ref_instance_methods}"#,
+{class_decl}{initializers}{static_methods}{owned_instance_methods}{class_ref_decl}{ref_mut_instance_methods}{class_ref_mut_decl}{ref_instance_methods}"#,
         class_decl = class_decl,
         class_ref_decl = class_ref_mut_decl,
         class_ref_mut_decl = class_ref_decl,
         initializers = initializers,
+        static_methods = static_methods,
         owned_instance_methods = owned_instance_methods,
         ref_mut_instance_methods = ref_mut_instance_methods,
         ref_instance_methods = ref_instance_methods,
@@ -324,6 +618,93 @@ extension {type_name}RefMut {{
     return class;
 }
 
+// Generate a struct for a `#[swift_bridge(Copy(N))]` type, which holds the Rust value's bytes
+// so that it can be passed to and from Rust by value.
+//
+// # Example
+//
+// ```
+// public struct Foo {
+//     fileprivate var bytes: __swift_bridge__$Foo
+//
+//     func intoFfiRepr() -> __swift_bridge__$Foo {
+//         bytes
+//     }
+// }
+// extension __swift_bridge__$Foo {
+//     func intoSwiftRepr() -> Foo {
+//         Foo(bytes: self)
+//     }
+// }
+// ```
+fn generate_swift_copy_struct(
+    ty: &OpaqueForeignTypeDeclaration,
+    initializers: &[String],
+    static_methods: &[String],
+    instance_methods: &[String],
+) -> String {
+    let type_name = ty.swift_name();
+    let ffi_repr = ty.copy_ffi_repr_string();
+
+    let mut struct_decl = if ty.already_declared {
+        "".to_string()
+    } else {
+        let conforms_to: Vec<String> = ty
+            .conforms_to
+            .iter()
+            .map(|protocol| protocol.to_string())
+            .collect();
+        let conforms_to = if conforms_to.is_empty() {
+            "".to_string()
+        } else {
+            format!(": {}", conforms_to.join(", "))
+        };
+
+        format!(
+            r#"
+{doc_comment}{visibility} struct {type_name}{conforms_to} {{
+    fileprivate var bytes: {ffi_repr}
+
+    func intoFfiRepr() -> {ffi_repr} {{
+        bytes
+    }}
+}}
+extension {ffi_repr} {{
+    func intoSwiftRepr() -> {type_name} {{
+        {type_name}(bytes: self)
+    }}
+}}"#,
+            doc_comment = swift_doc_comment(ty.doc_comment.as_ref(), ""),
+            visibility = ty.swift_visibility.as_swift_keyword(),
+            type_name = type_name,
+            conforms_to = conforms_to,
+            ffi_repr = ffi_repr,
+        )
+    };
+
+    // The struct only holds bytes, so it is as thread safe as the Rust type that it copies.
+    if ty.sendable && !ty.already_declared {
+        struct_decl += &format!("\nextension {}: @unchecked Sendable {{}}", type_name);
+    }
+
+    for funcs in [initializers, static_methods, instance_methods] {
+        if funcs.is_empty() {
+            continue;
+        }
+
+        struct_decl += &format!(
+            r#"
+extension {type_name} {{
+{funcs}
+}}"#,
+            type_name = type_name,
+            funcs = funcs.join("\n\n")
+        );
+    }
+
+    struct_decl
+}
+
 // Generate functions to drop the reference count on a Swift class instance.
 //
 // # Example
@@ -334,8 +715,11 @@ extension {type_name}RefMut {{
 //     let _ = Unmanaged<Foo>.fromOpaque(ptr).takeRetainedValue()
 // }
 // ```
-fn generate_drop_swift_instance_reference_count(ty: &OpaqueForeignTypeDeclaration) -> String {
-    let link_name = ty.free_link_name();
+fn generate_drop_swift_instance_reference_count(
+    ty: &OpaqueForeignTypeDeclaration,
+    symbol_prefix: &str,
+) -> String {
+    let link_name = ty.free_link_name(symbol_prefix);
     let fn_name = ty.free_func_name();
 
     format!(
@@ -347,7 +731,47 @@ func {fn_name} (ptr: UnsafeMutableRawPointer) {{
 "##,
         link_name = link_name,
         fn_name = fn_name,
-        ty_name = ty.ty_name_ident()
+        ty_name = ty.swift_name()
+    )
+}
+
+/// Generate a `static let` that gets initialized by calling a Rust function the first time that it
+/// is accessed.
+///
+/// `String`s and `&str`s are exposed as Swift `String`s.
+fn gen_swift_constant(
+    function: &ParsedExternFn,
+    types: &TypeDeclarations,
+    symbol_prefix: &str,
+) -> String {
+    let ty = function.return_ty_built_in(types).unwrap();
+    let call_rust = format!("{}()", function.link_name(symbol_prefix));
+
+    let (swift_ty, value) = match ty {
+        BridgedType::StdLib(StdLibType::String) => (
+            "String".to_string(),
+            format!("RustString(ptr: {}).toString()", call_rust),
+        ),
+        BridgedType::StdLib(StdLibType::Str) => {
+            ("String".to_string(), format!("{}.toString()", call_rust))
+        }
+        _ => (
+            ty.to_swift_type(TypePosition::FnReturn(function.host_lang)),
+            ty.convert_ffi_value_to_swift_value(
+                &call_rust,
+                TypePosition::FnReturn(function.host_lang),
+            ),
+        ),
+    };
+
+    format!(
+        "{doc_comment}{available}    {visibility} static let {name}: {swift_ty} = {value}",
+        doc_comment = swift_doc_comment(function.doc_comment.as_ref(), "    "),
+        available = swift_available_attribute(function.available.as_ref(), "    "),
+        visibility = function.swift_visibility.as_swift_keyword(),
+        name = function.swift_name_string(),
+        swift_ty = swift_ty,
+        value = value
     )
 }
 
@@ -370,9 +794,10 @@ fn gen_func_swift_calls_rust(
     function: &ParsedExternFn,
     types: &TypeDeclarations,
     swift_bridge_path: &Path,
+    symbol_prefix: &str,
 ) -> String {
     let fn_name = function.sig.ident.to_string();
-    let params = function.to_swift_param_names_and_types(false, types);
+    let mut params = function.to_swift_param_names_and_types(false, types);
     let call_args = function.to_swift_call_args(true, false, types, swift_bridge_path);
 
     let call_fn = if function.sig.asyncness.is_some() {
@@ -394,43 +819,90 @@ fn gen_func_swift_calls_rust(
                 todo!()
             }
             TypeDeclaration::Opaque(ty) => {
-                format!("${}", ty.to_string())
+                format!("${}", ty.link_name_segment())
             }
         }
     } else {
         "".to_string()
     };
 
-    let maybe_static_class_func = if function.associated_type.is_some()
+    let maybe_static_func = if function.associated_type.is_some()
         && (!function.is_method() && !function.is_swift_initializer)
     {
-        "class "
+        "static "
     } else {
         ""
     };
 
-    let swift_class_func_name = if function.is_swift_initializer {
-        "public convenience init".to_string()
-    } else {
-        format!("public func {}", fn_name.as_str())
-    };
+    let visibility = function.swift_visibility.as_swift_keyword();
+    let swift_class_func_name =
+        if function.is_swift_initializer && function.associated_type_is_copy() {
+            // A `Copy` type is a Swift struct, and structs don't have convenience initializers.
+            format!("{} init", visibility)
+        } else if function.is_failable_initializer() {
+            format!("{} convenience init?", visibility)
+        } else if function.is_swift_initializer {
+            format!("{} convenience init", visibility)
+        } else if function.is_swift_subscript {
+            // A subscript without a `get` or `set` block is read-only.
+            format!("{} subscript", visibility)
+        } else if let Some(operator) = function.swift_operator.as_ref() {
+            // Operators are static functions, where the receiver becomes the left hand side.
+            if let Some(TypeDeclaration::Opaque(ty)) = function.associated_type.as_ref() {
+                let lhs_ty = if ty.copy.is_some() {
+                    ty.swift_name()
+                } else {
+                    format!("{}Ref", ty.swift_name())
+                };
+                params = format!("_ lhs: {}, {}", lhs_ty, params);
+            }
+
+            format!("{} static func {} ", visibility, operator)
+        } else {
+            format!(
+                "{} {}func {}",
+                visibility,
+                maybe_static_func,
+                function.swift_name_string()
+            )
+        };
 
     let indentation = if function.associated_type.is_some() {
         "    "
     } else {
         ""
     };
+    let mut doc_comment = swift_doc_comment(function.doc_comment.as_ref(), indentation);
+    if function.sig.unsafety.is_some() {
+        if !doc_comment.is_empty() {
+            doc_comment += &format!("{}///\n", indentation);
+        }
+        doc_comment += &format!(
+            "{}/// - Warning: Calls an `unsafe` Rust function. The caller must uphold its safety requirements.\n",
+            indentation
+        );
+    }
+    let available = swift_available_attribute(function.available.as_ref(), indentation);
 
     let call_rust = format!(
         "{prefix}{type_name_segment}${call_fn}",
-        prefix = SWIFT_BRIDGE_PREFIX,
+        prefix = symbol_prefix,
         type_name_segment = type_name_segment,
         call_fn = call_fn
     );
+    let maybe_result = match function.return_ty_built_in(types) {
+        Some(BridgedType::StdLib(StdLibType::Result(result))) => Some(result),
+        _ => None,
+    };
+
     let mut call_rust = if function.sig.asyncness.is_some() {
         call_rust
     } else if function.is_swift_initializer {
         call_rust
+    } else if maybe_result.is_some() {
+        // Results get unwrapped after we've wrapped the call in any argument conversions, so
+        // that we never throw from inside of one of the argument conversion closures.
+        call_rust
     } else if let Some(built_in) = function.return_ty_built_in(types) {
         built_in.convert_ffi_value_to_swift_value(
             &call_rust,
@@ -480,13 +952,14 @@ fn gen_func_swift_calls_rust(
     let returns_null = Some(BridgedType::StdLib(StdLibType::Null))
         == BridgedType::new_with_return_type(&function.func.sig.output, types);
 
-    let maybe_return = if returns_null || function.is_swift_initializer {
+    let maybe_return = if returns_null || function.is_swift_initializer || maybe_result.is_some() {
         ""
     } else {
         "return "
     };
 
-    let mut maybe_generics = HashSet::new();
+    // Ordered so that the generic parameters always get emitted in the same order.
+    let mut maybe_generics = BTreeSet::new();
 
     for arg in function.func.sig.inputs.iter() {
         let bridged_arg = BridgedType::new_with_fn_arg(arg, types);
@@ -503,6 +976,34 @@ fn gen_func_swift_calls_rust(
             maybe_generics.insert(SwiftFuncGenerics::Str);
         }
 
+        // The `Data`'s bytes are only valid inside of the `withUnsafeBytes` closure, so Rust
+        // must not hold on to the slice after the call returns.
+        if function.args_as_data.contains(&arg_name) {
+            call_rust = format!(
+                r#"{maybe_return}{arg}.withUnsafeBytes({{ {arg}AsBytes in
+{indentation}        {call_rust}
+{indentation}    }})"#,
+                maybe_return = maybe_return,
+                indentation = indentation,
+                arg = arg_name,
+                call_rust = call_rust
+            );
+            continue;
+        }
+
+        if function.passes_arg_as_ffi_string(&bridged_arg) {
+            call_rust = format!(
+                r#"{maybe_return}{arg}.toFfiString({{ {arg}AsFfiString in
+{indentation}        {call_rust}
+{indentation}    }})"#,
+                maybe_return = maybe_return,
+                indentation = indentation,
+                arg = arg_name,
+                call_rust = call_rust
+            );
+            continue;
+        }
+
         // TODO: Refactor to make less duplicative
         match bridged_arg {
             BridgedType::StdLib(StdLibType::Str) => {
@@ -529,14 +1030,67 @@ fn gen_func_swift_calls_rust(
                     call_rust = call_rust
                 );
             }
+            BridgedType::StdLib(StdLibType::RefSlice(slice)) if slice.is_str_slice() => {
+                call_rust = format!(
+                    r#"{maybe_return}{arg}.toRustStrSlice({{ {arg}AsRustStrSlice in
+{indentation}        {call_rust}
+{indentation}    }})"#,
+                    maybe_return = maybe_return,
+                    indentation = indentation,
+                    arg = arg_name,
+                    call_rust = call_rust
+                );
+            }
+            BridgedType::StdLib(StdLibType::HashMap(map)) => {
+                call_rust = format!(
+                    r#"{maybe_return}{arg}.toFfiHashMap({entries_to_ffi}, {{ {arg}AsFfiHashMap in
+{indentation}        {call_rust}
+{indentation}    }})"#,
+                    maybe_return = maybe_return,
+                    indentation = indentation,
+                    arg = arg_name,
+                    entries_to_ffi = map.swift_entries_to_ffi_closures(),
+                    call_rust = call_rust
+                );
+            }
+            BridgedType::StdLib(StdLibType::Set(set)) => {
+                call_rust = format!(
+                    r#"{maybe_return}{arg}.toFfiSet({elems_to_ffi}, {{ {arg}AsFfiSet in
+{indentation}        {call_rust}
+{indentation}    }})"#,
+                    maybe_return = maybe_return,
+                    indentation = indentation,
+                    arg = arg_name,
+                    elems_to_ffi = set.swift_elems_to_ffi_closure(),
+                    call_rust = call_rust
+                );
+            }
             _ => {}
         }
     }
 
-    if function.is_swift_initializer {
+    if function.is_failable_initializer() {
+        // Rust returns a null pointer for `None`, so there is nothing for Swift to free.
+        call_rust = format!(
+            "guard let ptr = {call_rust} else {{ return nil }}\n{indentation}    self.init(ptr: ptr)",
+            call_rust = call_rust,
+            indentation = indentation
+        )
+    } else if function.is_swift_initializer && function.associated_type_is_copy() {
+        call_rust = format!("self = {}.intoSwiftRepr()", call_rust)
+    } else if function.is_swift_initializer {
         call_rust = format!("self.init(ptr: {})", call_rust)
     }
 
+    // Async functions unwrap their Result inside of the callback that completes them.
+    if let Some(result) = maybe_result
+        .as_ref()
+        .filter(|_| function.sig.asyncness.is_none())
+    {
+        call_rust = BridgedType::StdLib(StdLibType::Result(result.clone()))
+            .convert_ffi_value_to_swift_value(&call_rust, TypePosition::FnReturn(HostLang::Rust));
+    }
+
     let maybe_return = if function.is_swift_initializer {
         "".to_string()
     } else {
@@ -576,8 +1130,50 @@ fn gen_func_swift_calls_rust(
             )
         };
 
-        let fn_body = format!(
-            r#"class CbWrapper {{
+        let fn_body = if maybe_result.is_some() {
+            // The Rust function's `Err` gets thrown by the continuation. If the Swift task gets
+            // cancelled we throw a `CancellationError` right away and ignore the Rust function's
+            // eventual result.
+            format!(
+                r#"class CbWrapper {{
+    var cb: (Result<{rust_fn_ret_ty}, Error>) -> ()
+
+    public init(cb: @escaping (Result<{rust_fn_ret_ty}, Error>) -> ()) {{
+        self.cb = cb
+    }}
+}}
+
+func onComplete(cbWrapperPtr: UnsafeMutableRawPointer?{maybe_on_complete_sig_ret_val}) {{
+    let wrapper = Unmanaged<CbWrapper>.fromOpaque(cbWrapperPtr!).takeRetainedValue()
+    wrapper.cb(Result(catching: {{ {on_complete_ret_val} }}))
+}}
+
+let continuationGuard = __private__ThrowingContinuationGuard<{rust_fn_ret_ty}>()
+
+return try await withTaskCancellationHandler(operation: {{
+    try await withCheckedThrowingContinuation({{ (continuation: CheckedContinuation<{rust_fn_ret_ty}, Error>) in
+        continuationGuard.setContinuation(continuation)
+
+        let callback = {{ rustFnRetVal in
+            continuationGuard.resume(with: rustFnRetVal)
+        }}
+
+        let wrapper = CbWrapper(cb: callback)
+        let wrapperPtr = Unmanaged.passRetained(wrapper).toOpaque()
+
+        {call_rust}
+    }})
+}}, onCancel: {{
+    continuationGuard.cancel()
+}})"#,
+                rust_fn_ret_ty = rust_fn_ret_ty,
+                maybe_on_complete_sig_ret_val = maybe_on_complete_sig_ret_val,
+                on_complete_ret_val = on_complete_ret_val,
+                call_rust = call_rust,
+            )
+        } else {
+            format!(
+                r#"class CbWrapper {{
     var cb: (Result<{rust_fn_ret_ty}, Never>) -> ()
 
     public init(cb: @escaping (Result<{rust_fn_ret_ty}, Never>) -> ()) {{
@@ -600,11 +1196,12 @@ return await withCheckedContinuation({{ (continuation: CheckedContinuation<{rust
 
     {call_rust}
 }})"#,
-            rust_fn_ret_ty = rust_fn_ret_ty,
-            maybe_on_complete_sig_ret_val = maybe_on_complete_sig_ret_val,
-            on_complete_ret_val = on_complete_ret_val,
-            call_rust = call_rust,
-        );
+                rust_fn_ret_ty = rust_fn_ret_ty,
+                maybe_on_complete_sig_ret_val = maybe_on_complete_sig_ret_val,
+                on_complete_ret_val = on_complete_ret_val,
+                call_rust = call_rust,
+            )
+        };
 
         let mut fn_body_indented = "".to_string();
         for line in fn_body.lines() {
@@ -617,11 +1214,12 @@ return await withCheckedContinuation({{ (continuation: CheckedContinuation<{rust
         let fn_body_indented = fn_body_indented.trim_end();
 
         format!(
-            r#"{indentation}{maybe_static_class_func}{swift_class_func_name}{maybe_generics}({params}) async{maybe_ret} {{
+            r#"{doc_comment}{available}{indentation}{swift_class_func_name}{maybe_generics}({params}) async{maybe_ret} {{
 {fn_body_indented}
 {indentation}}}"#,
+            doc_comment = doc_comment,
+            available = available,
             indentation = indentation,
-            maybe_static_class_func = maybe_static_class_func,
             swift_class_func_name = swift_class_func_name,
             maybe_generics = maybe_generics,
             params = params,
@@ -629,12 +1227,58 @@ return await withCheckedContinuation({{ (continuation: CheckedContinuation<{rust
             fn_body_indented = fn_body_indented,
         )
     } else {
+        let exclusive_access_checks = exclusive_self_access_checks(function, types);
+        let call_rust = if exclusive_access_checks.is_empty() {
+            call_rust
+        } else {
+            // The call is no longer the only expression in the function body, so it can't be
+            // implicitly returned.
+            let call_rust = if returns_null || call_rust.starts_with("return ") {
+                call_rust
+            } else {
+                format!("return {}", call_rust)
+            };
+
+            let mut body = "".to_string();
+            for check in exclusive_access_checks {
+                body += &format!("{}\n{}    ", check, indentation);
+            }
+            body + &call_rust
+        };
+
+        if function.getter == Some(Getter::Computed) {
+            // A computed property without a `get` or `set` block is read-only.
+            let property_name = match function.swift_name_override.as_ref() {
+                Some(swift_name) => swift_name.value(),
+                None => snake_case_to_camel_case(&fn_name),
+            };
+            // We push a parse error for computed getters that don't return a value.
+            let property_ty = function
+                .return_ty_built_in(types)
+                .expect("Computed getters return a value")
+                .to_swift_type(TypePosition::FnReturn(function.host_lang));
+
+            return format!(
+                r#"{doc_comment}{available}{indentation}{visibility} var {property_name}: {property_ty} {{
+{indentation}    {call_rust}
+{indentation}}}"#,
+                doc_comment = doc_comment,
+                available = available,
+                indentation = indentation,
+                visibility = visibility,
+                property_name = property_name,
+                property_ty = property_ty,
+                call_rust = call_rust
+            );
+        }
+
         format!(
-            r#"{indentation}{maybe_static_class_func}{swift_class_func_name}{maybe_generics}({params}){maybe_ret} {{
+            r#"{doc_comment}{available}{indentation}{swift_class_func_name}{maybe_generics}({params}){maybe_ret} {{
 {indentation}    {call_rust}
 {indentation}}}"#,
+            doc_comment = doc_comment,
+            available = available,
             indentation = indentation,
-            maybe_static_class_func = maybe_static_class_func,
             swift_class_func_name = swift_class_func_name,
             maybe_generics = maybe_generics,
             params = params,
@@ -646,18 +1290,109 @@ return await withCheckedContinuation({{ (continuation: CheckedContinuation<{rust
     func_definition
 }
 
+/// A `&mut self` method needs exclusive access to its Rust value, so we make sure that Swift
+/// doesn't also pass that same value in as one of the method's other arguments.
+///
+/// `precondition(other.ptr != ptr, "...")`
+fn exclusive_self_access_checks(
+    function: &ParsedExternFn,
+    types: &TypeDeclarations,
+) -> Vec<String> {
+    let self_ty = match function.associated_type.as_ref() {
+        Some(TypeDeclaration::Opaque(ty))
+            if function.host_lang.is_rust()
+                && function.self_mutability().is_some()
+                && ty.copy.is_none() =>
+        {
+            ty
+        }
+        _ => return vec![],
+    };
+
+    let mut checks = vec![];
+    for arg in function.func.sig.inputs.iter() {
+        let pat_ty = match arg {
+            FnArg::Typed(pat_ty) if !pat_type_pat_is_self(pat_ty) => pat_ty,
+            _ => continue,
+        };
+
+        let is_self_ty = matches!(
+            types.get_with_pat_type(pat_ty),
+            Some(TypeDeclaration::Opaque(arg_ty)) if arg_ty.swift_name() == self_ty.swift_name()
+        );
+        if !is_self_ty {
+            continue;
+        }
+
+        let arg_name = fn_arg_name(arg).unwrap();
+        checks.push(format!(
+            r#"precondition({arg}.ptr != ptr, "`{func}` requires exclusive access to `self`, but `{arg}` is the same value")"#,
+            arg = arg_name,
+            func = function.swift_name_string(),
+        ));
+    }
+
+    checks
+}
+
+/// Turn a parsed doc comment into a Swift documentation comment.
+///
+/// ```no_run,ignore
+/// /// Some comment
+/// /// Another line
+/// ```
+fn swift_doc_comment(doc_comment: Option<&String>, indentation: &str) -> String {
+    let mut swift_doc_comment = "".to_string();
+
+    if let Some(doc_comment) = doc_comment {
+        for line in doc_comment.lines() {
+            swift_doc_comment += &format!("{}///{}\n", indentation, line);
+        }
+    }
+
+    swift_doc_comment
+}
+
+/// `@available(iOS 15.0, *)` on its own line, if the declaration has an
+/// `#[swift_bridge(available = "...")]` attribute.
+fn swift_available_attribute(available: Option<&SwiftAvailability>, indentation: &str) -> String {
+    match available {
+        Some(available) => format!("{}{}\n", indentation, available.to_swift_attribute()),
+        None => "".to_string(),
+    }
+}
+
+/// Add an `@available(...)` attribute to each of the classes and extensions that we generate for
+/// an opaque Rust type.
+///
+/// Swift requires an extension of a type to be at least as restricted as the type itself, so
+/// every top level declaration needs the attribute, not just the class.
+fn annotate_top_level_declarations(swift: &str, available: &SwiftAvailability) -> String {
+    let mut annotated = "".to_string();
+
+    for line in swift.lines() {
+        let is_class = line.split_whitespace().nth(1) == Some("class");
+        if line.starts_with("extension ") || (is_class && !line.starts_with(' ')) {
+            annotated += &available.to_swift_attribute();
+            annotated += "\n";
+        }
+
+        annotated += line;
+        annotated += "\n";
+    }
+
+    annotated
+}
+
 fn gen_function_exposes_swift_to_rust(
     func: &ParsedExternFn,
     types: &TypeDeclarations,
     swift_bridge_path: &Path,
+    symbol_prefix: &str,
 ) -> String {
-    let link_name = func.link_name();
+    let link_name = func.link_name(symbol_prefix);
     let prefixed_fn_name = func.prefixed_fn_name();
-    let fn_name = if let Some(swift_name) = func.swift_name_override.as_ref() {
-        swift_name.value()
-    } else {
-        func.sig.ident.to_string()
-    };
+    let fn_name = func.swift_name_string();
 
     let params = func.to_swift_param_names_and_types(true, types);
     let ret = func.to_swift_return_type(types);
@@ -677,10 +1412,24 @@ fn gen_function_exposes_swift_to_rust(
                     //
                     todo!()
                 }
-                TypeDeclaration::Opaque(associated_type) => associated_type.to_string(),
+                TypeDeclaration::Opaque(associated_type) => associated_type.swift_name(),
             };
 
-            if func.is_method() {
+            let swift_protocol = match associated_type {
+                TypeDeclaration::Opaque(associated_type) => associated_type.swift_protocol.as_ref(),
+                TypeDeclaration::Shared(_) => None,
+            };
+
+            if let (true, Some(protocol)) = (func.is_method(), swift_protocol) {
+                // Calling the method through the protocol makes the Swift compiler check that the
+                // type conforms to it.
+                call_fn = format!(
+                    "(Unmanaged<{ty_name}>.fromOpaque(this).takeUnretainedValue() as {protocol}).{call_fn}",
+                    ty_name = ty_name,
+                    protocol = protocol,
+                    call_fn = call_fn
+                );
+            } else if func.is_method() {
                 call_fn = format!(
                     "Unmanaged<{ty_name}>.fromOpaque(this).takeUnretainedValue().{call_fn}",
                     ty_name = ty_name,
@@ -1131,15 +1880,8 @@ extension FooRef {
         let generated = module.generate_swift(&CodegenConfig::no_features_enabled());
 
         let expected = r#"
-public class FooRef {
-    var ptr: UnsafeMutableRawPointer
-
-    public init(ptr: UnsafeMutableRawPointer) {
-        self.ptr = ptr
-    }
-}
-extension FooRef {
-    class public func bar() {
+extension Foo {
+    public static func bar() {
         __swift_bridge__$Foo$bar()
     }
 }
@@ -1345,4 +2087,64 @@ func __swift_bridge__some_function () {
 
         assert_trimmed_generated_contains_trimmed_expected(&generated, &expected);
     }
+
+    /// Verify that when generating Swift for library evolution the public API only consists of
+    /// the Swift wrappers, and that the `@_cdecl` functions and raw pointer initializers stay
+    /// internal.
+    #[test]
+    fn library_evolution_public_api_has_no_cdecl_functions() {
+        let tokens = quote! {
+            mod foo {
+                extern "Rust" {
+                    type SomeType;
+
+                    #[swift_bridge(init)]
+                    fn new() -> SomeType;
+                    fn some_method(&self) -> u8;
+                }
+
+                extern "Swift" {
+                    type SwiftType;
+
+                    fn swift_function(arg: u8);
+                }
+            }
+        };
+        let module: SwiftBridgeModule = parse_quote!(#tokens);
+        let config = CodegenConfig {
+            library_evolution: true,
+            ..CodegenConfig::no_features_enabled()
+        };
+        let generated = module.generate_swift(&config);
+
+        let lines: Vec<&str> = generated.lines().map(|line| line.trim()).collect();
+        for (idx, line) in lines.iter().enumerate() {
+            if line.starts_with("@_cdecl") {
+                assert!(!lines[idx + 1].starts_with("public"), "{}", lines[idx + 1]);
+            }
+            assert!(!line.contains("public init(ptr:"), "{}", line);
+            assert!(!line.contains("public override init(ptr:"), "{}", line);
+        }
+
+        assert_trimmed_generated_contains_trimmed_expected(
+            &generated,
+            r#"
+public class SomeTypeRef {
+    var ptr: UnsafeMutableRawPointer
+
+    init(ptr: UnsafeMutableRawPointer) {
+        self.ptr = ptr
+    }
+}
+"#,
+        );
+        assert_trimmed_generated_contains_trimmed_expected(
+            &generated,
+            "public convenience init() {",
+        );
+        assert_trimmed_generated_contains_trimmed_expected(
+            &generated,
+            "public func some_method() -> UInt8 {",
+        );
+    }
 }
@@ -1,4 +1,6 @@
-use crate::bridged_type::SharedEnum;
+use crate::bridged_type::{
+    BridgedType, EnumVariant, SharedEnum, StdLibType, StructFields, TypePosition,
+};
 use crate::SwiftBridgeModule;
 
 impl SwiftBridgeModule {
@@ -15,8 +17,13 @@ impl SwiftBridgeModule {
         for variant in shared_enum.variants.iter() {
             let v = format!(
                 r#"
-    case {name}"#,
-                name = variant.name
+    case {name}{fields}{discriminant}"#,
+                name = variant.name,
+                fields = self.swift_variant_fields(variant),
+                discriminant = variant
+                    .discriminant
+                    .map(|discriminant| format!(" = {}", discriminant))
+                    .unwrap_or_default()
             );
             variants += &v;
         }
@@ -25,14 +32,28 @@ impl SwiftBridgeModule {
         }
 
         for variant in shared_enum.variants.iter() {
-            let case = format!(
-                r#"
+            let case = if shared_enum.has_one_or_more_variants_with_data() {
+                format!(
+                    r#"
+            case {enum_name}.{variant_name}{bindings}:
+                return {enum_ffi_name}(tag: {enum_ffi_name}${variant_name}, payload: {payload})"#,
+                    enum_name = enum_name,
+                    enum_ffi_name = enum_ffi_name,
+                    variant_name = variant.name,
+                    bindings = swift_variant_field_bindings(variant),
+                    payload =
+                        self.convert_swift_variant_fields_to_ffi_payload(shared_enum, variant)
+                )
+            } else {
+                format!(
+                    r#"
             case {enum_name}.{variant_name}:
                 return {enum_ffi_name}(tag: {enum_ffi_name}${variant_name})"#,
-                enum_name = enum_name,
-                enum_ffi_name = enum_ffi_name,
-                variant_name = variant.name
-            );
+                    enum_name = enum_name,
+                    enum_ffi_name = enum_ffi_name,
+                    variant_name = variant.name
+                )
+            };
             convert_swift_to_ffi_repr += &case;
         }
         if convert_swift_to_ffi_repr.len() > 0 {
@@ -43,10 +64,11 @@ impl SwiftBridgeModule {
             let case = format!(
                 r#"
             case {enum_ffi_name}${variant_name}:
-                return {enum_name}.{variant_name}"#,
+                return {enum_name}.{variant_name}{fields}"#,
                 enum_name = enum_name,
                 enum_ffi_name = enum_ffi_name,
-                variant_name = variant.name
+                variant_name = variant.name,
+                fields = self.convert_ffi_payload_to_swift_variant_fields(variant)
             );
             convert_ffi_repr_to_swift += &case;
         }
@@ -59,9 +81,20 @@ impl SwiftBridgeModule {
             );
         }
 
-        let swift_enum = format!(
-            r#"public enum {enum_name} {{{variants}}}
-extension {enum_name} {{
+        // A transparent enum is passed over FFI as its raw value.
+        let ffi_repr_conversions = if shared_enum.transparent {
+            format!(
+                r#"extension {enum_name} {{
+    func intoFfiRepr() -> {ffi_repr_name} {{
+        self.rawValue
+    }}
+}}"#,
+                enum_name = enum_name,
+                ffi_repr_name = shared_enum.ffi_name_string(),
+            )
+        } else {
+            format!(
+                r#"extension {enum_name} {{
     func intoFfiRepr() -> {ffi_repr_name} {{
         switch self {{{convert_swift_to_ffi_repr}}}
     }}
@@ -70,12 +103,28 @@ extension {enum_ffi_name} {{
     func intoSwiftRepr() -> {enum_name} {{
         switch self.tag {{{convert_ffi_repr_to_swift}}}
     }}
-}}
+}}"#,
+                enum_name = enum_name,
+                enum_ffi_name = enum_ffi_name,
+                ffi_repr_name = shared_enum.ffi_name_string(),
+                convert_swift_to_ffi_repr = convert_swift_to_ffi_repr,
+                convert_ffi_repr_to_swift = convert_ffi_repr_to_swift
+            )
+        };
+        let option_val_to_swift = if shared_enum.transparent {
+            format!("{}(rawValue: self.val)!", enum_name)
+        } else {
+            "self.val.intoSwiftRepr()".to_string()
+        };
+
+        let swift_enum = format!(
+            r#"{visibility} enum {enum_name}{raw_value_ty} {{{variants}}}
+{ffi_repr_conversions}
 extension {option_ffi_name} {{
     @inline(__always)
     func intoSwiftRepr() -> Optional<{enum_name}> {{
         if self.is_some {{
-            return self.val.intoSwiftRepr()
+            return {option_val_to_swift}
         }} else {{
             return nil
         }}
@@ -90,15 +139,252 @@ extension {option_ffi_name} {{
         }}
     }}
 }}"#,
+            visibility = shared_enum.swift_visibility.as_swift_keyword(),
             enum_name = enum_name,
-            enum_ffi_name = enum_ffi_name,
+            raw_value_ty = if shared_enum.has_swift_raw_value() {
+                ": Int32"
+            } else {
+                ""
+            },
+            ffi_repr_conversions = ffi_repr_conversions,
             option_ffi_name = option_ffi_name,
+            option_val_to_swift = option_val_to_swift,
             ffi_repr_name = shared_enum.ffi_name_string(),
-            variants = variants,
-            convert_swift_to_ffi_repr = convert_swift_to_ffi_repr,
-            convert_ffi_repr_to_swift = convert_ffi_repr_to_swift
+            variants = variants
         );
 
+        let swift_enum = match generate_shared_enum_comparable(shared_enum) {
+            Some(comparable) => format!("{}\n{}", swift_enum, comparable),
+            None => swift_enum,
+        };
+        let swift_enum = match self.generate_shared_enum_hashable(shared_enum) {
+            Some(hashable) => format!("{}\n{}", swift_enum, hashable),
+            None => swift_enum,
+        };
+        let swift_enum = match self.generate_shared_enum_localized_error(shared_enum) {
+            Some(localized_error) => format!("{}\n{}", swift_enum, localized_error),
+            None => swift_enum,
+        };
+        let swift_enum = if shared_enum.sendable {
+            format!("{}\nextension {}: Sendable {{}}", swift_enum, enum_name)
+        } else {
+            swift_enum
+        };
+
         Some(swift_enum)
     }
+
+    /// Generate a `Hashable` conformance for an enum that has the `#[swift_bridge(Hashable)]`
+    /// attribute.
+    ///
+    /// Swift synthesizes `hash(into:)` from the discriminant and the associated values, so we
+    /// only generate the conformance if all of the associated values are themselves `Hashable`.
+    fn generate_shared_enum_hashable(&self, shared_enum: &SharedEnum) -> Option<String> {
+        if !shared_enum.hashable {
+            return None;
+        }
+
+        for variant in shared_enum.variants.iter() {
+            for norm_field in variant.fields.normalized_fields() {
+                let ty = BridgedType::new_with_type(&norm_field.ty, &self.types).unwrap();
+                if !ty.is_swift_hashable() {
+                    return None;
+                }
+            }
+        }
+
+        Some(format!(
+            "extension {}: Hashable {{}}",
+            shared_enum.swift_name_string()
+        ))
+    }
+
+    /// Generate a `LocalizedError` conformance for an enum that has the
+    /// `#[swift_bridge(LocalizedError)]` attribute.
+    ///
+    /// A variant's `errorDescription` is its first associated `String`, or the variant's name if
+    /// it doesn't have one.
+    fn generate_shared_enum_localized_error(&self, shared_enum: &SharedEnum) -> Option<String> {
+        if !shared_enum.localized_error {
+            return None;
+        }
+
+        let mut cases = "".to_string();
+        for variant in shared_enum.variants.iter() {
+            let norm_fields = variant.fields.normalized_fields();
+            let message_idx = norm_fields.iter().position(|norm_field| {
+                BridgedType::new_with_type(&norm_field.ty, &self.types)
+                    == Some(BridgedType::StdLib(StdLibType::String))
+            });
+
+            let case = match message_idx {
+                Some(message_idx) => {
+                    let bindings: Vec<String> = (0..norm_fields.len())
+                        .map(|idx| {
+                            if idx == message_idx {
+                                "let message".to_string()
+                            } else {
+                                "_".to_string()
+                            }
+                        })
+                        .collect();
+
+                    format!(
+                        r#"
+        case .{variant_name}({bindings}):
+            return message.toString()"#,
+                        variant_name = variant.name,
+                        bindings = bindings.join(", ")
+                    )
+                }
+                None => format!(
+                    r#"
+        case .{variant_name}:
+            return "{variant_name}""#,
+                    variant_name = variant.name
+                ),
+            };
+            cases += &case;
+        }
+
+        Some(format!(
+            r#"extension {enum_name}: LocalizedError {{
+    {visibility} var errorDescription: String? {{
+        switch self {{{cases}
+        }}
+    }}
+}}"#,
+            enum_name = shared_enum.swift_name_string(),
+            visibility = shared_enum.swift_visibility.as_swift_keyword(),
+            cases = cases
+        ))
+    }
+
+    /// The associated values of a variant.
+    ///
+    /// (radius: Double) or (UInt8, UInt32)
+    fn swift_variant_fields(&self, variant: &EnumVariant) -> String {
+        let fields: Vec<String> = variant
+            .fields
+            .normalized_fields()
+            .iter()
+            .map(|norm_field| {
+                let ty = BridgedType::new_with_type(&norm_field.ty, &self.types).unwrap();
+                let ty = ty.to_swift_type(TypePosition::SharedStructField);
+
+                match &variant.fields {
+                    StructFields::Named(_) => {
+                        format!("{}: {}", norm_field.ffi_field_name(), ty)
+                    }
+                    _ => ty,
+                }
+            })
+            .collect();
+
+        wrap_in_parens_if_not_empty(fields)
+    }
+
+    /// __swift_bridge__$SomeEnumFields(SomeVariant: __swift_bridge__$SomeEnum$FieldOfSomeVariant(a: a))
+    fn convert_swift_variant_fields_to_ffi_payload(
+        &self,
+        shared_enum: &SharedEnum,
+        variant: &EnumVariant,
+    ) -> String {
+        let fields_union = shared_enum.ffi_fields_union_name_string();
+
+        if variant.fields.is_empty() {
+            return format!("{}()", fields_union);
+        }
+
+        let converted_fields: Vec<String> = variant
+            .fields
+            .normalized_fields()
+            .iter()
+            .map(|norm_field| {
+                let field_name = norm_field.ffi_field_name();
+                let ty = BridgedType::new_with_type(&norm_field.ty, &self.types).unwrap();
+
+                format!(
+                    "{}: {}",
+                    field_name,
+                    ty.convert_swift_expression_to_ffi_compatible(
+                        &field_name,
+                        TypePosition::SharedStructField
+                    )
+                )
+            })
+            .collect();
+
+        format!(
+            "{fields_union}({variant_name}: {variant_fields}({converted_fields}))",
+            fields_union = fields_union,
+            variant_name = variant.name,
+            variant_fields = shared_enum.ffi_variant_fields_name_string(variant),
+            converted_fields = converted_fields.join(", ")
+        )
+    }
+
+    /// (radius: self.payload.Circle.radius) or (self.payload.Tuple._0)
+    fn convert_ffi_payload_to_swift_variant_fields(&self, variant: &EnumVariant) -> String {
+        let fields: Vec<String> = variant
+            .fields
+            .normalized_fields()
+            .iter()
+            .map(|norm_field| {
+                let field_name = norm_field.ffi_field_name();
+                let ty = BridgedType::new_with_type(&norm_field.ty, &self.types).unwrap();
+                let converted = ty.convert_ffi_value_to_swift_value(
+                    &format!("self.payload.{}.{}", variant.name, field_name),
+                    TypePosition::SharedStructField,
+                );
+
+                match &variant.fields {
+                    StructFields::Named(_) => format!("{}: {}", field_name, converted),
+                    _ => converted,
+                }
+            })
+            .collect();
+
+        wrap_in_parens_if_not_empty(fields)
+    }
+}
+
+/// Generate a `Comparable` conformance for an enum that has the `#[swift_bridge(Comparable)]`
+/// attribute.
+///
+/// Variants are ordered by their raw values, which are the same as their Rust discriminants.
+fn generate_shared_enum_comparable(shared_enum: &SharedEnum) -> Option<String> {
+    if !shared_enum.comparable {
+        return None;
+    }
+
+    Some(format!(
+        r#"extension {enum_name}: Comparable {{
+    {visibility} static func <(lhs: {enum_name}, rhs: {enum_name}) -> Bool {{
+        lhs.rawValue < rhs.rawValue
+    }}
+}}"#,
+        enum_name = shared_enum.swift_name_string(),
+        visibility = shared_enum.swift_visibility.as_swift_keyword(),
+    ))
+}
+
+/// (let a, let b)
+fn swift_variant_field_bindings(variant: &EnumVariant) -> String {
+    let bindings: Vec<String> = variant
+        .fields
+        .normalized_fields()
+        .iter()
+        .map(|norm_field| format!("let {}", norm_field.ffi_field_name()))
+        .collect();
+
+    wrap_in_parens_if_not_empty(bindings)
+}
+
+fn wrap_in_parens_if_not_empty(items: Vec<String>) -> String {
+    if items.is_empty() {
+        "".to_string()
+    } else {
+        format!("({})", items.join(", "))
+    }
 }
@@ -1,4 +1,5 @@
 use crate::bridged_type::{BridgedType, SharedStruct, StructFields, StructSwiftRepr, TypePosition};
+use crate::codegen::generate_swift::swift_doc_comment;
 use crate::SwiftBridgeModule;
 
 impl SwiftBridgeModule {
@@ -14,60 +15,63 @@ impl SwiftBridgeModule {
         let struct_name = &shared_struct.swift_name_string();
         let option_ffi_name = shared_struct.ffi_option_name_string();
 
-        match shared_struct.swift_repr {
-            StructSwiftRepr::Class => {
-                todo!()
+        // Swift only synthesizes a memberwise initializer for structs, so we generate one for
+        // classes ourselves.
+        let (type_keyword, initializer) = match shared_struct.swift_repr {
+            StructSwiftRepr::Class => (
+                "final class",
+                self.shared_struct_class_initializer(shared_struct),
+            ),
+            StructSwiftRepr::Structure => ("struct", "".to_string()),
+        };
+
+        let mut fields = match &shared_struct.fields {
+            StructFields::Named(named) => {
+                let mut fields = "".to_string();
+
+                for field in named.iter() {
+                    let bridged_ty = BridgedType::new_with_type(&field.ty, &self.types).unwrap();
+
+                    fields += &format!(
+                        "    var {}: {}\n",
+                        shared_struct.swift_field_name(&field.swift_name_string()),
+                        bridged_ty.to_swift_type(TypePosition::SharedStructField)
+                    );
+                }
+
+                fields
             }
-            StructSwiftRepr::Structure => {
-                let mut fields = match &shared_struct.fields {
-                    StructFields::Named(named) => {
-                        let mut fields = "".to_string();
-
-                        for field in named.iter() {
-                            let bridged_ty =
-                                BridgedType::new_with_type(&field.ty, &self.types).unwrap();
-
-                            fields += &format!(
-                                "    var {}: {}\n",
-                                field.swift_name_string(),
-                                bridged_ty.to_swift_type(TypePosition::SharedStructField)
-                            );
-                        }
-
-                        fields
-                    }
-                    StructFields::Unnamed(unnamed) => {
-                        let mut fields = "".to_string();
-
-                        for field in unnamed.iter() {
-                            let bridged_ty =
-                                BridgedType::new_with_type(&field.ty, &self.types).unwrap();
-
-                            fields += &format!(
-                                "    var {}: {}\n",
-                                field.swift_name_string(),
-                                bridged_ty.to_swift_type(TypePosition::SharedStructField)
-                            );
-                        }
-
-                        fields
-                    }
-                    StructFields::Unit => "".to_string(),
-                };
-
-                if fields.len() > 0 {
-                    fields = format!("\n{}", fields)
+            StructFields::Unnamed(unnamed) => {
+                let mut fields = "".to_string();
+
+                for field in unnamed.iter() {
+                    let bridged_ty = BridgedType::new_with_type(&field.ty, &self.types).unwrap();
+
+                    fields += &format!(
+                        "    var {}: {}\n",
+                        field.swift_name_string(),
+                        bridged_ty.to_swift_type(TypePosition::SharedStructField)
+                    );
                 }
 
-                let convert_swift_to_ffi_repr =
-                    shared_struct.convert_swift_to_ffi_repr("self", &self.types);
-                let convert_ffi_repr_to_swift =
-                    shared_struct.convert_ffi_expression_to_swift("self", &self.types);
+                fields
+            }
+            StructFields::Unit => "".to_string(),
+        };
+
+        if fields.len() > 0 {
+            fields = format!("\n{}", fields)
+        }
 
-                // No need to generate any code. Swift will automatically generate a
-                //  struct from our C header typedef that we generate for this struct.
-                let swift_struct = format!(
-                    r#"public struct {struct_name} {{{fields}
+        let convert_swift_to_ffi_repr =
+            shared_struct.convert_swift_to_ffi_repr("self", &self.types);
+        let convert_ffi_repr_to_swift =
+            shared_struct.convert_ffi_expression_to_swift("self", &self.types);
+
+        // No need to generate any code. Swift will automatically generate a
+        //  struct from our C header typedef that we generate for this struct.
+        let swift_struct = format!(
+            r#"{doc_comment}{visibility} {type_keyword} {struct_name} {{{fields}{initializer}
     @inline(__always)
     func intoFfiRepr() -> {ffi_repr_name} {{
         {convert_swift_to_ffi_repr}
@@ -98,16 +102,206 @@ extension {option_ffi_name} {{
         }}
     }}
 }}"#,
-                    struct_name = struct_name,
-                    fields = fields,
-                    ffi_repr_name = shared_struct.ffi_name_string(),
-                    option_ffi_name = option_ffi_name,
-                    convert_swift_to_ffi_repr = convert_swift_to_ffi_repr,
-                    convert_ffi_repr_to_swift = convert_ffi_repr_to_swift
-                );
-
-                Some(swift_struct)
+            doc_comment = swift_doc_comment(shared_struct.doc_comment.as_ref(), ""),
+            visibility = shared_struct.swift_visibility.as_swift_keyword(),
+            type_keyword = type_keyword,
+            struct_name = struct_name,
+            fields = fields,
+            initializer = initializer,
+            ffi_repr_name = shared_struct.ffi_name_string(),
+            option_ffi_name = option_ffi_name,
+            convert_swift_to_ffi_repr = convert_swift_to_ffi_repr,
+            convert_ffi_repr_to_swift = convert_ffi_repr_to_swift
+        );
+
+        let swift_struct = match self.generate_shared_tuple_struct_initializer(shared_struct) {
+            Some(initializer) => format!("{}\n{}", swift_struct, initializer),
+            None => swift_struct,
+        };
+        let swift_struct = match self.generate_shared_struct_equatable(shared_struct) {
+            Some(equatable) => format!("{}\n{}", swift_struct, equatable),
+            None => swift_struct,
+        };
+        let swift_struct = if shared_struct.codable {
+            format!("{}\nextension {}: Codable {{}}", swift_struct, struct_name)
+        } else {
+            swift_struct
+        };
+        // Swift can't check that a class with mutable properties is safe to share between
+        // threads, so we leave that up to the user.
+        let swift_struct = match (shared_struct.sendable, shared_struct.swift_repr) {
+            (true, StructSwiftRepr::Class) => format!(
+                "{}\nextension {}: @unchecked Sendable {{}}",
+                swift_struct, struct_name
+            ),
+            (true, StructSwiftRepr::Structure) => {
+                format!("{}\nextension {}: Sendable {{}}", swift_struct, struct_name)
             }
+            (false, _) => swift_struct,
+        };
+        let swift_struct = match self.generate_shared_struct_identifiable(shared_struct) {
+            Some(identifiable) => format!("{}\n{}", swift_struct, identifiable),
+            None => swift_struct,
+        };
+
+        Some(swift_struct)
+    }
+
+    /// The memberwise initializer of a shared struct with `swift_repr = "class"`.
+    fn shared_struct_class_initializer(&self, shared_struct: &SharedStruct) -> String {
+        let mut params = vec![];
+        let mut assignments = "".to_string();
+
+        for norm_field in shared_struct.fields.normalized_fields() {
+            let field_name = shared_struct.swift_field_name(&norm_field.ffi_field_name());
+            let ty = BridgedType::new_with_type(&norm_field.ty, &self.types).unwrap();
+
+            params.push(format!(
+                "{}: {}",
+                field_name,
+                ty.to_swift_type(TypePosition::SharedStructField)
+            ));
+            assignments += &format!(
+                "        self.{field_name} = {field_name}\n",
+                field_name = field_name
+            );
+        }
+
+        format!(
+            r#"
+    {visibility} init({params}) {{
+{assignments}    }}
+"#,
+            visibility = shared_struct.swift_visibility.as_swift_keyword(),
+            params = params.join(", "),
+            assignments = assignments
+        )
+    }
+
+    /// Generate a positional initializer for a tuple struct, so that `struct Point(f32, f32)` can
+    /// be created using `Point(1.0, 2.0)`.
+    ///
+    /// The initializer is declared in an extension so that Swift still synthesizes the
+    /// memberwise initializer that our FFI conversions use.
+    fn generate_shared_tuple_struct_initializer(
+        &self,
+        shared_struct: &SharedStruct,
+    ) -> Option<String> {
+        let unnamed = match &shared_struct.fields {
+            StructFields::Unnamed(unnamed) if !unnamed.is_empty() => unnamed,
+            _ => return None,
+        };
+
+        let mut params = vec![];
+        let mut args = vec![];
+        for field in unnamed.iter() {
+            let field_name = field.swift_name_string();
+            let ty = BridgedType::new_with_type(&field.ty, &self.types).unwrap();
+
+            params.push(format!(
+                "_ {}: {}",
+                field_name,
+                ty.to_swift_type(TypePosition::SharedStructField)
+            ));
+            args.push(format!(
+                "{field_name}: {field_name}",
+                field_name = field_name
+            ));
         }
+
+        let maybe_convenience = match shared_struct.swift_repr {
+            StructSwiftRepr::Class => "convenience ",
+            StructSwiftRepr::Structure => "",
+        };
+
+        Some(format!(
+            r#"extension {struct_name} {{
+    {visibility} {maybe_convenience}init({params}) {{
+        self.init({args})
+    }}
+}}"#,
+            struct_name = shared_struct.swift_name_string(),
+            visibility = shared_struct.swift_visibility.as_swift_keyword(),
+            maybe_convenience = maybe_convenience,
+            params = params.join(", "),
+            args = args.join(", ")
+        ))
+    }
+
+    /// Generate an `Identifiable` conformance for a struct that has the
+    /// `#[swift_bridge(Identifiable)]` attribute.
+    ///
+    /// If the id field isn't named `id` we add an `id` property that returns it.
+    fn generate_shared_struct_identifiable(&self, shared_struct: &SharedStruct) -> Option<String> {
+        let id_field = shared_struct.identifiable.as_ref()?;
+        let struct_name = shared_struct.swift_name_string();
+
+        let field = match &shared_struct.fields {
+            StructFields::Named(named) => named.iter().find(|f| f.name == *id_field)?,
+            _ => return None,
+        };
+
+        let field_name = shared_struct.swift_field_name(&field.swift_name_string());
+        if field_name == "id" {
+            return Some(format!(
+                "extension {struct_name}: Identifiable {{}}",
+                struct_name = struct_name
+            ));
+        }
+
+        let id_ty = BridgedType::new_with_type(&field.ty, &self.types)
+            .unwrap()
+            .to_swift_type(TypePosition::SharedStructField);
+
+        Some(format!(
+            r#"extension {struct_name}: Identifiable {{
+    public var id: {id_ty} {{
+        return self.{field_name}
+    }}
+}}"#,
+            struct_name = struct_name,
+            id_ty = id_ty,
+            field_name = field_name
+        ))
+    }
+
+    /// Generate an `Equatable` conformance for a struct that has the
+    /// `#[swift_bridge(Equatable)]` attribute.
+    ///
+    /// We only generate the conformance if all of the struct's fields are themselves `Equatable`.
+    fn generate_shared_struct_equatable(&self, shared_struct: &SharedStruct) -> Option<String> {
+        if !shared_struct.equatable {
+            return None;
+        }
+
+        let mut comparisons = vec![];
+        for norm_field in shared_struct.fields.normalized_fields() {
+            let ty = BridgedType::new_with_type(&norm_field.ty, &self.types).unwrap();
+            if !ty.is_swift_equatable() {
+                return None;
+            }
+
+            let field_name = shared_struct.swift_field_name(&norm_field.ffi_field_name());
+            comparisons.push(format!(
+                "lhs.{field_name} == rhs.{field_name}",
+                field_name = field_name
+            ));
+        }
+
+        let comparison = if comparisons.is_empty() {
+            "true".to_string()
+        } else {
+            comparisons.join(" && ")
+        };
+
+        Some(format!(
+            r#"extension {struct_name}: Equatable {{
+    public static func == (lhs: {struct_name}, rhs: {struct_name}) -> Bool {{
+        return {comparison}
+    }}
+}}"#,
+            struct_name = shared_struct.swift_name_string(),
+            comparison = comparison
+        ))
     }
 }
@@ -1,54 +1,63 @@
 use proc_macro2::Ident;
 
 /// Generate the `extension MyRustType: Vectorizable {}` for the Swift side.
-pub(super) fn generate_vectorizable_extension(ty: &Ident) -> String {
+///
+/// The Swift class is named `swift_name` while the symbols that we link against are named after
+/// the Rust type `ty`.
+pub(super) fn generate_vectorizable_extension(
+    ty: &Ident,
+    swift_name: &str,
+    symbol_prefix: &str,
+) -> String {
     format!(
-        r#"extension {ty}: Vectorizable {{
+        r#"extension {swift_name}: Vectorizable {{
     public static func vecOfSelfNew() -> UnsafeMutableRawPointer {{
-        __swift_bridge__$Vec_{ty}$new()
+        {symbol_prefix}$Vec_{ty}$new()
     }}
 
     public static func vecOfSelfFree(vecPtr: UnsafeMutableRawPointer) {{
-        __swift_bridge__$Vec_{ty}$drop(vecPtr)
+        {symbol_prefix}$Vec_{ty}$drop(vecPtr)
     }}
 
-    public static func vecOfSelfPush(vecPtr: UnsafeMutableRawPointer, value: {ty}) {{
-        __swift_bridge__$Vec_{ty}$push(vecPtr, {{value.isOwned = false; return value.ptr;}}())
+    public static func vecOfSelfPush(vecPtr: UnsafeMutableRawPointer, value: {swift_name}) {{
+        {symbol_prefix}$Vec_{ty}$push(vecPtr, {{value.isOwned = false; return value.ptr;}}())
     }}
 
     public static func vecOfSelfPop(vecPtr: UnsafeMutableRawPointer) -> Optional<Self> {{
-        let pointer = __swift_bridge__$Vec_{ty}$pop(vecPtr)
+        let pointer = {symbol_prefix}$Vec_{ty}$pop(vecPtr)
         if pointer == nil {{
             return nil
         }} else {{
-            return ({ty}(ptr: pointer!) as! Self)
+            return ({swift_name}(ptr: pointer!) as! Self)
         }}
     }}
 
-    public static func vecOfSelfGet(vecPtr: UnsafeMutableRawPointer, index: UInt) -> Optional<{ty}Ref> {{
-        let pointer = __swift_bridge__$Vec_{ty}$get(vecPtr, index)
+    public static func vecOfSelfGet(vecPtr: UnsafeMutableRawPointer, index: UInt) -> Optional<{swift_name}Ref> {{
+        let pointer = {symbol_prefix}$Vec_{ty}$get(vecPtr, index)
         if pointer == nil {{
             return nil
         }} else {{
-            return {ty}Ref(ptr: pointer!)
+            return {swift_name}Ref(ptr: pointer!)
         }}
     }}
 
-    public static func vecOfSelfGetMut(vecPtr: UnsafeMutableRawPointer, index: UInt) -> Optional<{ty}RefMut> {{
-        let pointer = __swift_bridge__$Vec_{ty}$get_mut(vecPtr, index)
+    public static func vecOfSelfGetMut(vecPtr: UnsafeMutableRawPointer, index: UInt) -> Optional<{swift_name}RefMut> {{
+        let pointer = {symbol_prefix}$Vec_{ty}$get_mut(vecPtr, index)
         if pointer == nil {{
             return nil
         }} else {{
-            return {ty}RefMut(ptr: pointer!)
+            return {swift_name}RefMut(ptr: pointer!)
         }}
     }}
 
     public static func vecOfSelfLen(vecPtr: UnsafeMutableRawPointer) -> UInt {{
-        __swift_bridge__$Vec_{ty}$len(vecPtr)
+        {symbol_prefix}$Vec_{ty}$len(vecPtr)
     }}
 }}
 "#,
-        ty = ty.to_string()
+        ty = ty.to_string(),
+        swift_name = swift_name,
+        symbol_prefix = symbol_prefix
     )
 }
 
@@ -110,7 +119,11 @@ extension ARustType: Vectorizable {
 "#;
 
         assert_trimmed_generated_equals_trimmed_expected(
-            &generate_vectorizable_extension(&Ident::new("ARustType", Span::call_site())),
+            &generate_vectorizable_extension(
+                &Ident::new("ARustType", Span::call_site()),
+                "ARustType",
+                "__swift_bridge__",
+            ),
             &expected,
         );
     }
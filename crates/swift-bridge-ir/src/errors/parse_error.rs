@@ -1,7 +1,8 @@
+use crate::parse::HostLang;
 use proc_macro2::Ident;
 use quote::ToTokens;
-use syn::{Error, Receiver};
-use syn::{ForeignItemType, LitStr};
+use syn::{Error, Expr, Receiver};
+use syn::{ForeignItemType, Lit, LitStr};
 use syn::{Token, Type};
 
 pub(crate) enum ParseError {
@@ -22,16 +23,45 @@ pub(crate) enum ParseError {
     AmbiguousSelf {
         self_: Receiver,
     },
+    /// `fn new() -> Self;` in an extern block that declares more than one type, without a
+    /// `#[swift_bridge(associated_to = SomeType)]` attribute.
+    AmbiguousSelfReturnType {
+        fn_ident: Ident,
+    },
     /// fn foo (bar: &Bar);
     /// If Bar wasn't declared using a `type Bar` declaration.
     UndeclaredType {
         ty: Type,
     },
+    /// extern "Rust" { #[swift_bridge(no_free)] type Foo; fn bar(foo: Foo); }
+    /// A `no_free` type can only be passed to Rust by reference, since Rust would otherwise free it.
+    NoFreeTypeTakenByValue {
+        fn_ident: Ident,
+        ty: Ident,
+    },
+    /// extern "Rust" { #[swift_bridge(skip)] type Foo; fn bar(foo: &Foo); }
+    /// A type that we don't generate code for can only be used by other skipped declarations.
+    SkippedTypeUsed {
+        ty: Type,
+        skipped_ty: Ident,
+    },
     /// Declared a type that we already support.
     /// Example: `type u32`
     DeclaredBuiltInType {
         ty: ForeignItemType,
     },
+    /// extern "Rust" { type Foo: SomeProtocol; }
+    /// Only `extern "Swift"` types get called through a Swift protocol.
+    ProtocolBoundOnRustType {
+        ty: Ident,
+        protocol: Ident,
+    },
+    /// #[swift_bridge(ExpressibleByStringLiteral)]
+    /// type Foo;
+    /// The type needs an `#[swift_bridge(init)]` function that takes a single `String` or `&str`.
+    ExpressibleByStringLiteralMissingInit {
+        ty: Ident,
+    },
     /// A bridge module struct with one or more fields must have a
     /// `#\[swift_bridge(swift_repr ="...")\[\]` attribute so that we know whether to create a
     /// `struct` or `class` on the Swift side.
@@ -42,10 +72,60 @@ pub(crate) enum ParseError {
     StructInvalidSwiftRepr {
         swift_repr_attr_value: LitStr,
     },
+    /// Only "camelCase" can be used as rename_all.
+    StructInvalidRenameAll {
+        rename_all_attr_value: LitStr,
+    },
     /// A struct was declared with an unrecognized attribute.
     StructUnrecognizedAttribute {
         attribute: Ident,
     },
+    /// A `#[swift_bridge(Codable)]` struct has a field whose Swift type is not `Codable`.
+    StructFieldNotCodable {
+        struct_ident: Ident,
+        field_ty: Type,
+    },
+    /// An `#[swift_bridge(Identifiable)]` struct does not have the field that it uses as its id.
+    StructIdentifiableFieldMissing {
+        struct_ident: Ident,
+        field: Ident,
+    },
+    /// An `#[swift_bridge(Identifiable)]` struct's id field is not `Hashable` on the Swift side.
+    StructIdentifiableFieldNotHashable {
+        struct_ident: Ident,
+        field_ty: Type,
+    },
+    /// An enum was declared with an unrecognized attribute.
+    EnumUnrecognizedAttribute {
+        attribute: Ident,
+    },
+    /// enum Code { Ok = SOME_CONST }
+    /// Discriminants must be integer literals that fit in an `i32`.
+    EnumDiscriminantNotI32 {
+        discriminant: Expr,
+    },
+    /// enum Code { Ok = 0, Found(u8) = 1 }
+    /// Swift enums with raw values cannot have associated values.
+    EnumDiscriminantWithFields {
+        enum_ident: Ident,
+    },
+    /// #[swift_bridge(transparent)]
+    /// enum Flags { A(u8) }
+    EnumTransparentWithFields {
+        enum_ident: Ident,
+    },
+    /// #[swift_bridge(Comparable)]
+    /// enum Level { Low(u8) }
+    /// Comparable enums are ordered by their raw values, which can't have associated values.
+    EnumComparableWithFields {
+        enum_ident: Ident,
+    },
+    /// enum Code { Ok = 1, Found = 1 }
+    EnumDuplicateDiscriminant {
+        variant: Ident,
+        other_variant: Ident,
+        discriminant: i32,
+    },
     /// There is no reason to use `swift_repr = "class"` on an empty struct.
     /// It's extra overhead with no advantages.
     EmptyStructHasSwiftReprClass {
@@ -53,11 +133,73 @@ pub(crate) enum ParseError {
         swift_repr_attr_value: LitStr,
     },
     FunctionAttribute(FunctionAttributeParseError),
+    /// fn foo (#[swift_bridge(default = 5)] arg: Foo);
+    /// Only integer, float, bool and string arguments can have default values.
+    ArgDefaultUnsupportedType {
+        ty: Type,
+    },
+    /// fn foo (#[swift_bridge(default = "hello")] arg: u8);
+    ArgDefaultMismatchedType {
+        default: Lit,
+        ty: Type,
+    },
+    /// Default argument values get declared on the Swift functions that call into Rust, so they
+    /// can't be used on functions that Rust calls.
+    ArgDefaultOnExternSwiftFunction {
+        default: Lit,
+    },
+    /// fn foo (#[swift_bridge(as = "Vec")] arg: &[u8]);
+    /// Only `as = "Data"` is supported.
+    ArgAsUnsupportedType {
+        ty: LitStr,
+    },
+    /// fn foo (#[swift_bridge(as = "Data")] arg: &str);
+    /// Only `&[u8]` arguments of non-async extern "Rust" functions can be passed as `Data`.
+    ArgAsDataMustBeU8Slice {
+        ty: Type,
+    },
+    /// fn foo (#[swift_bridge(as = "Array")] arg: Vec<String>);
+    /// Only `Vec<T>` arguments of extern "Rust" functions, where `T` is a primitive such as `i32`
+    /// or `bool`, can be passed as an `Array`.
+    ArgAsArrayMustBePrimitiveVec {
+        ty: Type,
+    },
+    /// fn foo () -> [String; 2];
+    /// Fixed size arrays can only hold `Copy` primitives such as `u8` or `f32`.
+    ArrayElementNotCopy {
+        elem: Type,
+    },
+    /// fn foo () -> Range<f32>;
+    /// Ranges can only hold integers such as `usize` or `i32`.
+    RangeElementNotInteger {
+        elem: Type,
+    },
+    /// extern "Rust" { fn foo (arg: Result<u8, Bar>); }
+    /// Some types can be passed from Rust to Swift but not from Swift to Rust, so they can only be
+    /// returned from `extern "Rust"` functions and passed to `extern "Swift"` functions.
+    RustToSwiftOnlyType {
+        ty: Type,
+        host_lang: HostLang,
+    },
+    /// fn foo () -> Option<char>;
+    /// Some types can't be wrapped in an `Option` yet.
+    UnsupportedOptionType {
+        ty: Type,
+    },
 }
 
 /// An error while parsing a function attribute.
 pub(crate) enum FunctionAttributeParseError {
     Identifiable(IdentifiableParseError),
+    Iterator(IteratorParseError),
+    AsyncIterator(IteratorParseError),
+    Constant(ConstantParseError),
+    Getter(GetterParseError),
+    Operator(OperatorParseError),
+    Subscript(SubscriptParseError),
+    ExternSwiftName(ExternSwiftNameParseError),
+    ReturnAs(ReturnAsParseError),
+    Init(InitParseError),
 }
 
 /// An error while parsing a function's `Identifiable` attribute.
@@ -68,6 +210,83 @@ pub(crate) enum IdentifiableParseError {
     MissingReturnType { fn_ident: Ident },
 }
 
+/// An error while parsing a function's `Iterator` or `AsyncIterator` attribute.
+pub(crate) enum IteratorParseError {
+    /// An `Iterator` implementation function must take a single `(&mut self)` argument.
+    MustBeRefMutSelf { fn_ident: Ident },
+    /// An `Iterator` implementation function must return an `Option<T>`.
+    MustReturnOption { fn_ident: Ident },
+    /// An `AsyncIterator` implementation function must be an `async fn`.
+    NotAsync { fn_ident: Ident },
+}
+
+/// An error while parsing a function's `get` or `get(clone)` attribute.
+pub(crate) enum GetterParseError {
+    /// A getter must take a single `(&self)` argument.
+    MustBeRefSelf { fn_ident: Ident },
+    /// A `get(clone)` getter returns an owned clone of the field, so it can't return a reference.
+    CloneReturnsReference { fn_ident: Ident },
+    /// A `get(computed)` getter becomes a Swift computed property, so it must synchronously
+    /// return a value and can't fail.
+    ComputedMustReturnInfallibleValue { fn_ident: Ident },
+}
+
+/// An error while parsing a function's `operator = "..."` attribute.
+pub(crate) enum OperatorParseError {
+    /// Only `+`, `-`, `*`, `/` and `==` are supported.
+    UnsupportedOperator { operator: LitStr },
+    /// An operator function must take `&self` and one other argument.
+    MustBeRefSelfAndOneArg { fn_ident: Ident },
+}
+
+/// An error while parsing a function's `subscript` attribute.
+pub(crate) enum SubscriptParseError {
+    /// A subscript function must take `&self` and at least one other argument.
+    MustBeRefSelfWithArgs { fn_ident: Ident },
+    /// A subscript function must return a value.
+    MissingReturnType { fn_ident: Ident },
+}
+
+/// An error while parsing a function's `extern_swift_name` attribute.
+pub(crate) enum ExternSwiftNameParseError {
+    /// Only `extern "Swift"` functions can link to an existing Swift symbol.
+    NotExternSwift { fn_ident: Ident },
+    /// The name must be a valid C symbol, such as `my_swift_function`.
+    InvalidSymbol { name: LitStr },
+}
+
+/// An error while parsing a function's `return_as` attribute.
+pub(crate) enum ReturnAsParseError {
+    /// Only `return_as = "Data"` and `return_as = "Array"` are supported.
+    UnsupportedType { ty: LitStr },
+    /// `return_as = "Data"` can only be used on an extern "Rust" function that returns a
+    /// `Vec<u8>`.
+    MustReturnVecU8 { fn_ident: Ident },
+    /// `return_as = "Array"` can only be used on an extern "Rust" function that returns a Vec of
+    /// numbers, such as a `Vec<f32>`.
+    MustReturnNumericVec { fn_ident: Ident },
+}
+
+/// An error while parsing a function's `init` attribute.
+pub(crate) enum InitParseError {
+    /// Two initializers of the same type have the same argument labels and types, so Swift
+    /// can't tell them apart.
+    DuplicateSignature {
+        fn_ident: Ident,
+        other_fn_ident: Ident,
+    },
+}
+
+/// An error while parsing a function's `constant` attribute.
+pub(crate) enum ConstantParseError {
+    /// A constant must be associated to a type.
+    MustBeAssociatedToType { fn_ident: Ident },
+    /// A constant's function must not take any arguments.
+    MustNotTakeArguments { fn_ident: Ident },
+    /// A constant must be a primitive, a `String` or a `&str`.
+    UnsupportedType { fn_ident: Ident },
+}
+
 impl Into<syn::Error> for ParseError {
     fn into(self) -> Error {
         match self {
@@ -96,6 +315,15 @@ self: &SomeType
 self: &mut SomeType
 "#,
             ),
+            ParseError::AmbiguousSelfReturnType { fn_ident } => {
+                let message = format!(
+                    r#"Could not infer a type for the `Self` that {} returns. Try specifying the type:
+#[swift_bridge(associated_to = SomeType)]
+"#,
+                    fn_ident
+                );
+                Error::new_spanned(fn_ident, message)
+            }
             ParseError::UndeclaredType { ty } => {
                 let ty_name = ty.to_token_stream().to_string();
                 // "& Bar" -> "Bar"
@@ -108,6 +336,34 @@ self: &mut SomeType
                 );
                 Error::new_spanned(ty, message)
             }
+            ParseError::ProtocolBoundOnRustType { ty, protocol } => {
+                let message = format!(
+                    r#"Type {} is an extern "Rust" type, so it can't be called through the Swift protocol {}"#,
+                    ty, protocol
+                );
+                Error::new_spanned(protocol, message)
+            }
+            ParseError::ExpressibleByStringLiteralMissingInit { ty } => {
+                let message = format!(
+                    r#"ExpressibleByStringLiteral type {} needs a #[swift_bridge(init)] function that takes a single String or &str"#,
+                    ty
+                );
+                Error::new_spanned(ty, message)
+            }
+            ParseError::NoFreeTypeTakenByValue { fn_ident, ty } => {
+                let message = format!(
+                    r#"{} takes ownership of the no_free type {}, which would free it. Take a &{} or &mut {} instead."#,
+                    fn_ident, ty, ty, ty
+                );
+                Error::new_spanned(fn_ident, message)
+            }
+            ParseError::SkippedTypeUsed { ty, skipped_ty } => {
+                let message = format!(
+                    r#"Type {} has the #[swift_bridge(skip)] attribute, so it can only be used by declarations that are also skipped."#,
+                    skipped_ty
+                );
+                Error::new_spanned(ty, message)
+            }
             ParseError::DeclaredBuiltInType { ty } => {
                 let message = format!(
                     r#"Type {} is already supported
@@ -141,6 +397,12 @@ TODO: Link to documntation on how to decide on the swift representation.
                 let message = r#"Invalid value. Must be either "class" or "struct"#;
                 Error::new_spanned(swift_repr_attr_value, message)
             }
+            ParseError::StructInvalidRenameAll {
+                rename_all_attr_value,
+            } => {
+                let message = r#"Invalid value. Must be "camelCase""#;
+                Error::new_spanned(rename_all_attr_value, message)
+            }
             ParseError::EmptyStructHasSwiftReprClass {
                 struct_ident,
                 swift_repr_attr_value,
@@ -162,6 +424,157 @@ struct {struct_name};
                 let message = format!(r#"Did not recognize struct attribute "{}"."#, attribute);
                 Error::new_spanned(attribute, message)
             }
+            ParseError::StructFieldNotCodable {
+                struct_ident,
+                field_ty,
+            } => {
+                let message = format!(
+                    r#"Codable struct {} has a field of type `{}`, which is not Codable on the Swift side.
+Shared structs that are used as fields must also have the `#[swift_bridge(Codable)]` attribute."#,
+                    struct_ident,
+                    field_ty.to_token_stream()
+                );
+                Error::new_spanned(field_ty, message)
+            }
+            ParseError::StructIdentifiableFieldMissing {
+                struct_ident,
+                field,
+            } => {
+                let message = format!(
+                    r#"Identifiable struct {} does not have a field named `{}`.
+Use `#[swift_bridge(Identifiable = "...")]` to use a different field as the id."#,
+                    struct_ident, field
+                );
+                Error::new_spanned(field, message)
+            }
+            ParseError::StructIdentifiableFieldNotHashable {
+                struct_ident,
+                field_ty,
+            } => {
+                let message = format!(
+                    r#"Identifiable struct {} has an id of type `{}`, which is not Hashable on the Swift side."#,
+                    struct_ident,
+                    field_ty.to_token_stream()
+                );
+                Error::new_spanned(field_ty, message)
+            }
+            ParseError::EnumUnrecognizedAttribute { attribute } => {
+                let message = format!(r#"Did not recognize enum attribute "{}"."#, attribute);
+                Error::new_spanned(attribute, message)
+            }
+            ParseError::EnumDiscriminantNotI32 { discriminant } => {
+                let message = format!(
+                    r#"Enum discriminants must be integer literals that fit in an `i32`, but found `{}`."#,
+                    discriminant.to_token_stream()
+                );
+                Error::new_spanned(discriminant, message)
+            }
+            ParseError::EnumDiscriminantWithFields { enum_ident } => {
+                let message = format!(
+                    r#"Enum {} has explicit discriminants, so its variants cannot have fields."#,
+                    enum_ident
+                );
+                Error::new_spanned(enum_ident, message)
+            }
+            ParseError::EnumTransparentWithFields { enum_ident } => {
+                let message = format!(
+                    r#"Enum {} is transparent, so its variants cannot have fields."#,
+                    enum_ident
+                );
+                Error::new_spanned(enum_ident, message)
+            }
+            ParseError::EnumComparableWithFields { enum_ident } => {
+                let message = format!(
+                    r#"Enum {} is Comparable, so its variants cannot have fields."#,
+                    enum_ident
+                );
+                Error::new_spanned(enum_ident, message)
+            }
+            ParseError::EnumDuplicateDiscriminant {
+                variant,
+                other_variant,
+                discriminant,
+            } => {
+                let message = format!(
+                    r#"Variant {} has the discriminant {}, which is already used by variant {}."#,
+                    variant, discriminant, other_variant
+                );
+                Error::new_spanned(variant, message)
+            }
+            ParseError::ArrayElementNotCopy { elem } => {
+                let message = format!(
+                    r#"Fixed size arrays can only hold Copy primitives such as `u8`, `f32` or `bool`, but found `{}`."#,
+                    elem.to_token_stream()
+                );
+                Error::new_spanned(elem, message)
+            }
+            ParseError::RangeElementNotInteger { elem } => {
+                let message = format!(
+                    r#"Ranges can only hold integers such as `usize` or `i32`, but found `{}`."#,
+                    elem.to_token_stream()
+                );
+                Error::new_spanned(elem, message)
+            }
+            ParseError::RustToSwiftOnlyType { ty, host_lang } => {
+                let position = if host_lang.is_rust() {
+                    "a return type"
+                } else {
+                    "an argument"
+                };
+                let message = format!(
+                    r#"`{}` is only supported as {}."#,
+                    ty.to_token_stream(),
+                    position
+                );
+                Error::new_spanned(ty, message)
+            }
+            ParseError::UnsupportedOptionType { ty } => {
+                let message = format!(r#"`{}` is not yet supported."#, ty.to_token_stream());
+                Error::new_spanned(ty, message)
+            }
+            ParseError::ArgDefaultUnsupportedType { ty } => {
+                let message = format!(
+                    r#"Arguments of type {} can't have a default value.
+Only integer, float, bool and string arguments can have default values."#,
+                    ty.to_token_stream()
+                );
+                Error::new_spanned(ty, message)
+            }
+            ParseError::ArgDefaultMismatchedType { default, ty } => {
+                let message = format!(
+                    r#"Default value {} can't be used for an argument of type {}."#,
+                    default.to_token_stream(),
+                    ty.to_token_stream()
+                );
+                Error::new_spanned(default, message)
+            }
+            ParseError::ArgDefaultOnExternSwiftFunction { default } => Error::new_spanned(
+                default,
+                r#"Default argument values can only be used on functions within `extern "Rust"` blocks."#,
+            ),
+            ParseError::ArgAsUnsupportedType { ty } => {
+                let message = format!(
+                    r#"Cannot pass an argument as "{}". The only supported types are "Data" and "Array"."#,
+                    ty.value()
+                );
+                Error::new_spanned(ty, message)
+            }
+            ParseError::ArgAsDataMustBeU8Slice { ty } => {
+                let message = format!(
+                    r#"Arguments of type {} can't use `as = "Data"`.
+Only `&[u8]` arguments of non-async functions within `extern "Rust"` blocks can be passed as `Data`."#,
+                    ty.to_token_stream()
+                );
+                Error::new_spanned(ty, message)
+            }
+            ParseError::ArgAsArrayMustBePrimitiveVec { ty } => {
+                let message = format!(
+                    r#"Arguments of type {} can't use `as = "Array"`.
+Only Vecs of primitives, such as a `Vec<i32>`, that are arguments of functions within `extern "Rust"` blocks can be passed as an `Array`."#,
+                    ty.to_token_stream()
+                );
+                Error::new_spanned(ty, message)
+            }
             ParseError::FunctionAttribute(fn_attrib) => match fn_attrib {
                 FunctionAttributeParseError::Identifiable(identifiable) => match identifiable {
                     IdentifiableParseError::MustBeRefSelf { fn_ident } => {
@@ -179,7 +592,169 @@ struct {struct_name};
                         Error::new_spanned(fn_ident, message)
                     }
                 },
+                FunctionAttributeParseError::Iterator(iterator) => {
+                    iterator.into_syn_error("Iterator")
+                }
+                FunctionAttributeParseError::AsyncIterator(iterator) => {
+                    iterator.into_syn_error("AsyncIterator")
+                }
+                FunctionAttributeParseError::Constant(constant) => match constant {
+                    ConstantParseError::MustBeAssociatedToType { fn_ident } => {
+                        let message = format!(
+                            r#"Constant {} must be associated to a type using `associated_to = SomeType`."#,
+                            fn_ident
+                        );
+                        Error::new_spanned(fn_ident, message)
+                    }
+                    ConstantParseError::MustNotTakeArguments { fn_ident } => {
+                        let message =
+                            format!(r#"Constant function {} must not take arguments."#, fn_ident);
+                        Error::new_spanned(fn_ident, message)
+                    }
+                    ConstantParseError::UnsupportedType { fn_ident } => {
+                        let message = format!(
+                            r#"Constant {} must be a primitive such as `u32` or `bool`, a `String` or a `&str`."#,
+                            fn_ident
+                        );
+                        Error::new_spanned(fn_ident, message)
+                    }
+                },
+                FunctionAttributeParseError::Getter(getter) => match getter {
+                    GetterParseError::MustBeRefSelf { fn_ident } => {
+                        let message = format!(
+                            r#"Getter {} must take `&self` as its only argument."#,
+                            fn_ident
+                        );
+                        Error::new_spanned(fn_ident, message)
+                    }
+                    GetterParseError::CloneReturnsReference { fn_ident } => {
+                        let message = format!(
+                            r#"Getter {} uses `get(clone)` so it must return an owned value such as a `String`. Use `get` to return a reference to the field."#,
+                            fn_ident
+                        );
+                        Error::new_spanned(fn_ident, message)
+                    }
+                    GetterParseError::ComputedMustReturnInfallibleValue { fn_ident } => {
+                        let message = format!(
+                            r#"Getter {} uses `get(computed)` so it must be a non-async method that returns a value. It can't return a `Result` or be a method on a `Mutex` type."#,
+                            fn_ident
+                        );
+                        Error::new_spanned(fn_ident, message)
+                    }
+                },
+                FunctionAttributeParseError::Operator(operator) => match operator {
+                    OperatorParseError::UnsupportedOperator { operator } => {
+                        let message = format!(
+                            r#"Unsupported operator "{}". Supported operators are "+", "-", "*", "/" and "==""#,
+                            operator.value()
+                        );
+                        Error::new_spanned(operator, message)
+                    }
+                    OperatorParseError::MustBeRefSelfAndOneArg { fn_ident } => {
+                        let message = format!(
+                            r#"Operator function {} must take `&self` and one other argument."#,
+                            fn_ident
+                        );
+                        Error::new_spanned(fn_ident, message)
+                    }
+                },
+                FunctionAttributeParseError::Subscript(subscript) => match subscript {
+                    SubscriptParseError::MustBeRefSelfWithArgs { fn_ident } => {
+                        let message = format!(
+                            r#"Subscript function {} must take `&self` and at least one other argument."#,
+                            fn_ident
+                        );
+                        Error::new_spanned(fn_ident, message)
+                    }
+                    SubscriptParseError::MissingReturnType { fn_ident } => {
+                        let message =
+                            format!(r#"Subscript function {} must return a value."#, fn_ident);
+                        Error::new_spanned(fn_ident, message)
+                    }
+                },
+                FunctionAttributeParseError::ExternSwiftName(extern_swift_name) => {
+                    match extern_swift_name {
+                        ExternSwiftNameParseError::NotExternSwift { fn_ident } => {
+                            let message = format!(
+                                r#"Function {} is not an extern "Swift" function, so it cannot use `extern_swift_name`."#,
+                                fn_ident
+                            );
+                            Error::new_spanned(fn_ident, message)
+                        }
+                        ExternSwiftNameParseError::InvalidSymbol { name } => {
+                            let message = format!(
+                                r#""{}" is not a valid symbol name. Symbol names must start with a letter or an underscore, and can only contain letters, digits, underscores and `$`."#,
+                                name.value()
+                            );
+                            Error::new_spanned(name, message)
+                        }
+                    }
+                }
+                FunctionAttributeParseError::ReturnAs(return_as) => match return_as {
+                    ReturnAsParseError::UnsupportedType { ty } => {
+                        let message = format!(
+                            r#"Cannot return a value as "{}". The only supported types are "Data" and "Array"."#,
+                            ty.value()
+                        );
+                        Error::new_spanned(ty, message)
+                    }
+                    ReturnAsParseError::MustReturnVecU8 { fn_ident } => {
+                        let message = format!(
+                            r#"Function {} must be an extern "Rust" function that returns a `Vec<u8>` in order to use `return_as = "Data"`."#,
+                            fn_ident
+                        );
+                        Error::new_spanned(fn_ident, message)
+                    }
+                    ReturnAsParseError::MustReturnNumericVec { fn_ident } => {
+                        let message = format!(
+                            r#"Function {} must be an extern "Rust" function that returns a Vec of numbers, such as a `Vec<f32>`, in order to use `return_as = "Array"`."#,
+                            fn_ident
+                        );
+                        Error::new_spanned(fn_ident, message)
+                    }
+                },
+                FunctionAttributeParseError::Init(init) => match init {
+                    InitParseError::DuplicateSignature {
+                        fn_ident,
+                        other_fn_ident,
+                    } => {
+                        let message = format!(
+                            r#"Initializer {} has the same Swift signature as initializer {}. Use `#[swift_bridge(label = "...")]` to give their arguments different labels."#,
+                            fn_ident, other_fn_ident
+                        );
+                        Error::new_spanned(fn_ident, message)
+                    }
+                },
             },
         }
     }
 }
+
+impl IteratorParseError {
+    /// `attribute` is the name of the attribute that the error is for, such as `Iterator`.
+    fn into_syn_error(self, attribute: &str) -> Error {
+        match self {
+            IteratorParseError::MustBeRefMutSelf { fn_ident } => {
+                let message = format!(
+                    r#"{} function {} must take `&mut self` as its only argument."#,
+                    attribute, fn_ident
+                );
+                Error::new_spanned(fn_ident, message)
+            }
+            IteratorParseError::MustReturnOption { fn_ident } => {
+                let message = format!(
+                    r#"{} function {} must return an `Option<T>`."#,
+                    attribute, fn_ident
+                );
+                Error::new_spanned(fn_ident, message)
+            }
+            IteratorParseError::NotAsync { fn_ident } => {
+                let message = format!(
+                    r#"{} function {} must be an `async fn`."#,
+                    attribute, fn_ident
+                );
+                Error::new_spanned(fn_ident, message)
+            }
+        }
+    }
+}
@@ -15,7 +15,7 @@ use crate::parse::TypeDeclarations;
 use crate::parsed_extern_fn::ParsedExternFn;
 
 pub use self::bridge_macro_attributes::{SwiftBridgeModuleAttr, SwiftBridgeModuleAttrs};
-pub use self::codegen::CodegenConfig;
+pub use self::codegen::{CodegenConfig, CppHeaderConfig};
 
 mod errors;
 mod parse;
@@ -61,6 +61,7 @@ pub struct SwiftBridgeModule {
     types: TypeDeclarations,
     functions: Vec<ParsedExternFn>,
     swift_bridge_path: Path,
+    symbol_prefix: String,
     cfg_attrs: Vec<CfgAttr>,
 }
 
@@ -70,6 +71,15 @@ impl SwiftBridgeModule {
     pub fn set_swift_bridge_path(&mut self, path: Path) {
         self.swift_bridge_path = path;
     }
+
+    /// Set the prefix of the symbols that the generated Rust and Swift code export and link
+    /// against, such as the `__swift_bridge__` in `__swift_bridge__$SomeType$_free`.
+    ///
+    /// Linking two crates that declare the same functions or types into one binary would lead to
+    /// duplicate symbols, so each crate can use its own prefix.
+    pub fn set_symbol_prefix(&mut self, prefix: String) {
+        self.symbol_prefix = prefix;
+    }
 }
 
 #[cfg(test)]
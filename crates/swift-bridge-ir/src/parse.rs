@@ -1,13 +1,13 @@
 use crate::bridge_module_attributes::CfgAttr;
-use crate::bridged_type::BridgedType;
+use crate::bridged_type::{BridgedType, StructFields};
 use crate::errors::{ParseError, ParseErrors};
 use crate::parse::parse_enum::SharedEnumDeclarationParser;
 use crate::parse::parse_extern_mod::ForeignModParser;
 use crate::parse::parse_struct::SharedStructDeclarationParser;
-use crate::SwiftBridgeModule;
+use crate::{SwiftBridgeModule, SWIFT_BRIDGE_PREFIX};
 use quote::{quote, ToTokens};
 use syn::parse::{Parse, ParseStream};
-use syn::{Item, ItemMod};
+use syn::{Attribute, Item, ItemMod, Lit, LitStr, Meta};
 
 mod parse_enum;
 mod parse_extern_mod;
@@ -50,6 +50,139 @@ impl HostLang {
     }
 }
 
+/// The access modifier of a generated Swift declaration.
+///
+/// `#[swift_bridge(swift_visibility = "internal")]`
+#[derive(Debug, Default, PartialEq, Copy, Clone)]
+pub(crate) enum SwiftVisibility {
+    #[default]
+    Public,
+    Internal,
+    FilePrivate,
+    Private,
+}
+
+impl SwiftVisibility {
+    /// `public`, `internal`, `fileprivate` or `private`
+    pub fn as_swift_keyword(&self) -> &'static str {
+        match self {
+            SwiftVisibility::Public => "public",
+            SwiftVisibility::Internal => "internal",
+            SwiftVisibility::FilePrivate => "fileprivate",
+            SwiftVisibility::Private => "private",
+        }
+    }
+}
+
+impl Parse for SwiftVisibility {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let visibility: LitStr = input.parse()?;
+
+        match visibility.value().as_str() {
+            "public" => Ok(SwiftVisibility::Public),
+            "internal" => Ok(SwiftVisibility::Internal),
+            "fileprivate" => Ok(SwiftVisibility::FilePrivate),
+            "private" => Ok(SwiftVisibility::Private),
+            _ => Err(syn::Error::new_spanned(
+                visibility,
+                r#"Invalid Swift visibility. Must be "public", "internal", "fileprivate" or "private"."#,
+            )),
+        }
+    }
+}
+
+impl SwiftVisibility {
+    /// Parse the visibility of a type.
+    ///
+    /// The Swift code that we generate to convert a type to and from its FFI representation is
+    /// `internal`, so a type can't be `fileprivate` or `private`.
+    pub fn parse_type_visibility(input: ParseStream) -> syn::Result<Self> {
+        let visibility: LitStr = input.parse()?;
+
+        match visibility.value().as_str() {
+            "public" => Ok(SwiftVisibility::Public),
+            "internal" => Ok(SwiftVisibility::Internal),
+            _ => Err(syn::Error::new_spanned(
+                visibility,
+                r#"Invalid Swift visibility for a type. Must be "public" or "internal"."#,
+            )),
+        }
+    }
+}
+
+/// The platforms that a generated Swift declaration is available on.
+///
+/// `#[swift_bridge(available = "iOS 15.0, macOS 12.0")]`
+#[derive(Debug, PartialEq, Clone)]
+pub(crate) struct SwiftAvailability {
+    /// `["iOS 15.0", "macOS 12.0"]`
+    pub platforms: Vec<String>,
+}
+
+impl SwiftAvailability {
+    /// `@available(iOS 15.0, macOS 12.0, *)`
+    pub fn to_swift_attribute(&self) -> String {
+        format!("@available({}, *)", self.platforms.join(", "))
+    }
+}
+
+impl Parse for SwiftAvailability {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let available: LitStr = input.parse()?;
+
+        let mut platforms = vec![];
+        for platform in available.value().split(',') {
+            let platform = platform.split_whitespace().collect::<Vec<_>>();
+
+            // `iOS 15.0`
+            if platform.len() != 2 {
+                return Err(syn::Error::new_spanned(
+                    available,
+                    r#"Invalid availability. Must be a comma separated list of platforms and versions, such as "iOS 15.0, macOS 12.0"."#,
+                ));
+            }
+
+            platforms.push(platform.join(" "));
+        }
+
+        Ok(SwiftAvailability { platforms })
+    }
+}
+
+/// Append the text of a `/// doc comment` line to the doc comment that has been parsed so far.
+///
+/// Each line of a doc comment becomes its own `#[doc = "..."]` attribute, so multi-line doc
+/// comments get joined back together with newlines.
+fn push_doc_comment_line(doc_comment: &mut Option<String>, attr: &Attribute) -> syn::Result<()> {
+    let line = match attr.parse_meta()? {
+        Meta::NameValue(name_val) => match name_val.lit {
+            Lit::Str(comment) => comment.value(),
+            lit => {
+                return Err(syn::Error::new_spanned(
+                    lit,
+                    "Doc comments must be string literals.",
+                ))
+            }
+        },
+        meta => {
+            return Err(syn::Error::new_spanned(
+                meta,
+                "Doc comments must be string literals.",
+            ))
+        }
+    };
+
+    match doc_comment {
+        Some(doc_comment) => {
+            doc_comment.push('\n');
+            doc_comment.push_str(&line);
+        }
+        None => *doc_comment = Some(line),
+    };
+
+    Ok(())
+}
+
 impl Parse for SwiftBridgeModuleAndErrors {
     fn parse(input: ParseStream) -> syn::Result<Self> {
         let mut errors = ParseErrors::new();
@@ -60,6 +193,7 @@ impl Parse for SwiftBridgeModuleAndErrors {
             let mut functions = vec![];
             let mut type_declarations = TypeDeclarations::default();
             let mut unresolved_types = vec![];
+            let mut skipped_types = vec![];
             let mut cfg_attrs = vec![];
 
             for attr in item_mod.attrs {
@@ -80,6 +214,7 @@ impl Parse for SwiftBridgeModuleAndErrors {
                             type_declarations: &mut type_declarations,
                             functions: &mut functions,
                             unresolved_types: &mut unresolved_types,
+                            skipped_types: &mut skipped_types,
                         }
                         .parse(foreign_mod)?;
                     }
@@ -121,16 +256,116 @@ impl Parse for SwiftBridgeModuleAndErrors {
                     continue;
                 }
 
+                // "Option < Foo >" -> ["Option", "<", "Foo", ">"]
+                let ty_string = unresolved_type.to_token_stream().to_string();
+                let skipped_ty = skipped_types.iter().find(|skipped_ty| {
+                    ty_string
+                        .split_whitespace()
+                        .any(|segment| *skipped_ty == segment)
+                });
+                if let Some(skipped_ty) = skipped_ty {
+                    errors.push(ParseError::SkippedTypeUsed {
+                        ty: unresolved_type.clone(),
+                        skipped_ty: skipped_ty.clone(),
+                    });
+                    continue;
+                }
+
                 errors.push(ParseError::UndeclaredType {
                     ty: unresolved_type.clone(),
                 });
             }
 
+            // A `Codable` struct's fields must all be `Codable`, including any nested shared
+            // structs, which might be declared after the struct that uses them.
+            for ty in type_declarations.types() {
+                let shared_struct = match ty {
+                    TypeDeclaration::Shared(SharedTypeDeclaration::Struct(shared_struct))
+                        if shared_struct.codable =>
+                    {
+                        shared_struct
+                    }
+                    _ => continue,
+                };
+
+                for field in shared_struct.fields.normalized_fields() {
+                    match BridgedType::new_with_type(&field.ty, &type_declarations) {
+                        Some(field_ty) if !field_ty.is_swift_codable() => {
+                            errors.push(ParseError::StructFieldNotCodable {
+                                struct_ident: shared_struct.name.clone(),
+                                field_ty: field.ty.clone(),
+                            });
+                        }
+                        _ => {}
+                    }
+                }
+            }
+
+            // An `Identifiable` struct's id field must exist and be `Hashable`.
+            for ty in type_declarations.types() {
+                let (shared_struct, id_field) = match ty {
+                    TypeDeclaration::Shared(SharedTypeDeclaration::Struct(shared_struct)) => {
+                        match shared_struct.identifiable.as_ref() {
+                            Some(id_field) => (shared_struct, id_field),
+                            None => continue,
+                        }
+                    }
+                    _ => continue,
+                };
+
+                let field = match &shared_struct.fields {
+                    StructFields::Named(named) => named.iter().find(|f| f.name == *id_field),
+                    _ => None,
+                };
+                let field = match field {
+                    Some(field) => field,
+                    None => {
+                        errors.push(ParseError::StructIdentifiableFieldMissing {
+                            struct_ident: shared_struct.name.clone(),
+                            field: id_field.clone(),
+                        });
+                        continue;
+                    }
+                };
+
+                match BridgedType::new_with_type(&field.ty, &type_declarations) {
+                    Some(field_ty) if !field_ty.is_swift_hashable() => {
+                        errors.push(ParseError::StructIdentifiableFieldNotHashable {
+                            struct_ident: shared_struct.name.clone(),
+                            field_ty: field.ty.clone(),
+                        });
+                    }
+                    _ => {}
+                }
+            }
+
+            // An `ExpressibleByStringLiteral` type needs an initializer that a string literal can be
+            // passed to.
+            for ty in type_declarations.types() {
+                let ty = match ty {
+                    TypeDeclaration::Opaque(ty) if ty.expressible_by_string_literal => ty,
+                    _ => continue,
+                };
+
+                let has_string_init = functions.iter().any(|func| {
+                    matches!(&func.associated_type, Some(TypeDeclaration::Opaque(assoc)) if assoc.ty == ty.ty)
+                        && func
+                            .string_literal_initializer_label(&type_declarations)
+                            .is_some()
+                });
+                if !has_string_init {
+                    errors.push(ParseError::ExpressibleByStringLiteralMissingInit {
+                        ty: ty.ty.clone(),
+                    });
+                }
+            }
+
             let module = SwiftBridgeModule {
                 name: module_name,
                 types: type_declarations,
                 functions,
                 swift_bridge_path: syn::parse2(quote! { swift_bridge }).unwrap(),
+                symbol_prefix: SWIFT_BRIDGE_PREFIX.to_string(),
                 cfg_attrs,
             };
             Ok(SwiftBridgeModuleAndErrors { module, errors })
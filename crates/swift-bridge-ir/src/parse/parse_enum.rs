@@ -1,41 +1,218 @@
 use crate::bridged_type::{EnumVariant, SharedEnum, StructFields};
-use crate::errors::ParseErrors;
-use syn::ItemEnum;
+use crate::errors::{ParseError, ParseErrors};
+use crate::parse::SwiftVisibility;
+use proc_macro2::Ident;
+use std::collections::HashMap;
+use syn::parse::{Parse, ParseStream};
+use syn::{Expr, ExprLit, ExprUnary, ItemEnum, Lit, Token, UnOp};
 
 pub(crate) struct SharedEnumDeclarationParser<'a> {
     pub item_enum: ItemEnum,
-    // Will be used in a future commit..
-    #[allow(unused)]
     pub errors: &'a mut ParseErrors,
 }
 
+enum EnumAttr {
+    Comparable,
+    Hashable,
+    LocalizedError,
+    Sendable,
+    SwiftVisibility(SwiftVisibility),
+    Transparent,
+    UnrecognizedAttribute(Ident),
+}
+
+#[derive(Default)]
+struct EnumAttribs {
+    comparable: bool,
+    hashable: bool,
+    localized_error: bool,
+    sendable: bool,
+    swift_visibility: SwiftVisibility,
+    transparent: bool,
+}
+
+struct ParsedAttribs(Vec<EnumAttr>);
+
+impl Parse for ParsedAttribs {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        if input.is_empty() {
+            return Ok(ParsedAttribs(vec![]));
+        }
+
+        let opts = syn::punctuated::Punctuated::<_, syn::token::Comma>::parse_terminated(input)?;
+
+        Ok(ParsedAttribs(opts.into_iter().collect()))
+    }
+}
+
+impl Parse for EnumAttr {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let key: Ident = input.parse()?;
+
+        let attr = match key.to_string().as_str() {
+            "Comparable" => EnumAttr::Comparable,
+            "Hashable" => EnumAttr::Hashable,
+            "LocalizedError" => EnumAttr::LocalizedError,
+            "Sendable" => EnumAttr::Sendable,
+            "swift_visibility" => {
+                input.parse::<Token![=]>()?;
+                EnumAttr::SwiftVisibility(SwiftVisibility::parse_type_visibility(input)?)
+            }
+            "transparent" => EnumAttr::Transparent,
+            _ => EnumAttr::UnrecognizedAttribute(key),
+        };
+
+        Ok(attr)
+    }
+}
+
 impl<'a> SharedEnumDeclarationParser<'a> {
     pub fn parse(self) -> Result<SharedEnum, syn::Error> {
         let item_enum = self.item_enum;
 
+        let mut attribs = EnumAttribs::default();
+
+        for attr in item_enum.attrs {
+            if attr.path.is_ident("doc") {
+                continue;
+            }
+
+            let sections: ParsedAttribs = attr.parse_args()?;
+
+            for attr in sections.0 {
+                match attr {
+                    EnumAttr::Comparable => {
+                        attribs.comparable = true;
+                    }
+                    EnumAttr::Hashable => {
+                        attribs.hashable = true;
+                    }
+                    EnumAttr::LocalizedError => {
+                        attribs.localized_error = true;
+                    }
+                    EnumAttr::Sendable => {
+                        attribs.sendable = true;
+                    }
+                    EnumAttr::SwiftVisibility(visibility) => {
+                        attribs.swift_visibility = visibility;
+                    }
+                    EnumAttr::Transparent => {
+                        attribs.transparent = true;
+                    }
+                    EnumAttr::UnrecognizedAttribute(attribute) => {
+                        self.errors
+                            .push(ParseError::EnumUnrecognizedAttribute { attribute });
+                    }
+                };
+            }
+        }
+
         let mut variants = vec![];
 
+        // The discriminant that each variant ends up with, whether explicit or implicit, so that
+        // we can catch collisions such as `A = 1, B = 0, C`.
+        let mut discriminants: HashMap<i32, Ident> = HashMap::new();
+        let mut next_discriminant = Some(0);
+
         for v in item_enum.variants {
+            let discriminant = match v.discriminant {
+                Some((_eq, expr)) => match parse_i32_discriminant(&expr) {
+                    Some(discriminant) => Some(discriminant),
+                    None => {
+                        self.errors
+                            .push(ParseError::EnumDiscriminantNotI32 { discriminant: expr });
+                        None
+                    }
+                },
+                None => None,
+            };
+
+            let value = discriminant.or(next_discriminant);
+            if let Some(value) = value {
+                if let Some(other_variant) = discriminants.get(&value) {
+                    self.errors.push(ParseError::EnumDuplicateDiscriminant {
+                        variant: v.ident.clone(),
+                        other_variant: other_variant.clone(),
+                        discriminant: value,
+                    });
+                } else {
+                    discriminants.insert(value, v.ident.clone());
+                }
+                next_discriminant = value.checked_add(1);
+            }
+
+            // A transparent enum is passed over FFI as its discriminant, so we give every variant
+            // one.
+            let discriminant = if attribs.transparent {
+                value
+            } else {
+                discriminant
+            };
+
             let variant = EnumVariant {
                 name: v.ident,
                 fields: StructFields::from_syn_fields(v.fields),
+                discriminant,
             };
             variants.push(variant);
         }
 
+        let has_discriminants = variants.iter().any(|v| v.discriminant.is_some());
+        let has_fields = variants.iter().any(|v| !v.fields.is_empty());
+        if attribs.transparent && has_fields {
+            self.errors.push(ParseError::EnumTransparentWithFields {
+                enum_ident: item_enum.ident.clone(),
+            });
+        } else if attribs.comparable && has_fields {
+            self.errors.push(ParseError::EnumComparableWithFields {
+                enum_ident: item_enum.ident.clone(),
+            });
+        } else if has_discriminants && has_fields {
+            self.errors.push(ParseError::EnumDiscriminantWithFields {
+                enum_ident: item_enum.ident.clone(),
+            });
+        }
+
         let shared_enum = SharedEnum {
             name: item_enum.ident,
             variants,
+            comparable: attribs.comparable,
+            hashable: attribs.hashable,
+            localized_error: attribs.localized_error,
+            sendable: attribs.sendable,
+            transparent: attribs.transparent,
+            swift_visibility: attribs.swift_visibility,
         };
 
         Ok(shared_enum)
     }
 }
 
+/// `404` or `-1`
+fn parse_i32_discriminant(expr: &Expr) -> Option<i32> {
+    match expr {
+        Expr::Lit(ExprLit {
+            lit: Lit::Int(int), ..
+        }) => int.base10_parse().ok(),
+        Expr::Unary(ExprUnary {
+            op: UnOp::Neg(_),
+            expr,
+            ..
+        }) => match expr.as_ref() {
+            Expr::Lit(ExprLit {
+                lit: Lit::Int(int), ..
+            }) => format!("-{}", int.base10_digits()).parse().ok(),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::bridged_type::StructFields;
-    use crate::test_utils::parse_ok;
+    use crate::errors::ParseError;
+    use crate::test_utils::{parse_errors, parse_ok};
     use quote::{quote, ToTokens};
 
     /// Verify that we can parse an enum with no variants.
@@ -105,4 +282,259 @@ mod tests {
             _ => panic!(),
         }
     }
+
+    /// Verify that we can parse a `Hashable` attribute.
+    #[test]
+    fn parses_enum_hashable_attribute() {
+        let tokens = quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                #[swift_bridge(Hashable)]
+                enum SomeEnum {
+                    Variant
+                }
+            }
+        };
+
+        let module = parse_ok(tokens);
+
+        let ty = module.types.types()[0].unwrap_shared_enum();
+        assert!(ty.hashable);
+    }
+
+    /// Verify that we can parse a `Sendable` attribute.
+    #[test]
+    fn parses_enum_sendable_attribute() {
+        let tokens = quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                #[swift_bridge(Sendable)]
+                enum SomeEnum {
+                    Variant
+                }
+            }
+        };
+
+        let module = parse_ok(tokens);
+
+        let ty = module.types.types()[0].unwrap_shared_enum();
+        assert!(ty.sendable);
+    }
+
+    /// Verify that we can parse a `LocalizedError` attribute.
+    #[test]
+    fn parses_enum_localized_error_attribute() {
+        let tokens = quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                #[swift_bridge(LocalizedError)]
+                enum SomeEnum {
+                    Variant
+                }
+            }
+        };
+
+        let module = parse_ok(tokens);
+
+        let ty = module.types.types()[0].unwrap_shared_enum();
+        assert!(ty.localized_error);
+    }
+
+    /// Verify that we can parse explicit discriminants.
+    #[test]
+    fn parses_enum_discriminants() {
+        let tokens = quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                enum Code {
+                    Ok = 0,
+                    Unknown,
+                    NotFound = 404,
+                    Negative = -1,
+                }
+            }
+        };
+
+        let module = parse_ok(tokens);
+
+        let ty = module.types.types()[0].unwrap_shared_enum();
+        let discriminants: Vec<Option<i32>> = ty.variants.iter().map(|v| v.discriminant).collect();
+        assert_eq!(discriminants, vec![Some(0), None, Some(404), Some(-1)]);
+        assert!(ty.has_explicit_discriminants());
+    }
+
+    /// Verify that we return an error if two variants have the same discriminant, including
+    /// implicit discriminants.
+    #[test]
+    fn error_if_duplicate_discriminant() {
+        let tokens = quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                enum Code {
+                    A = 1,
+                    B = 0,
+                    C,
+                }
+            }
+        };
+
+        let errors = parse_errors(tokens);
+        assert_eq!(errors.len(), 1);
+
+        match &errors[0] {
+            ParseError::EnumDuplicateDiscriminant {
+                variant,
+                other_variant,
+                discriminant,
+            } => {
+                assert_eq!(variant.to_string(), "C");
+                assert_eq!(other_variant.to_string(), "A");
+                assert_eq!(*discriminant, 1);
+            }
+            _ => panic!(),
+        }
+    }
+
+    /// Verify that we return an error if a variant with a discriminant has fields.
+    #[test]
+    fn error_if_discriminant_on_enum_with_fields() {
+        let tokens = quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                enum Code {
+                    A = 1,
+                    B(u8),
+                }
+            }
+        };
+
+        let errors = parse_errors(tokens);
+        assert_eq!(errors.len(), 1);
+
+        match &errors[0] {
+            ParseError::EnumDiscriminantWithFields { enum_ident } => {
+                assert_eq!(enum_ident.to_string(), "Code");
+            }
+            _ => panic!(),
+        }
+    }
+
+    /// Verify that we parse the `transparent` attribute, and that every variant of a transparent
+    /// enum gets a discriminant.
+    #[test]
+    fn parses_enum_transparent_attribute() {
+        let tokens = quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                #[swift_bridge(transparent)]
+                enum Flags {
+                    A,
+                    B = 4,
+                    C,
+                }
+            }
+        };
+
+        let module = parse_ok(tokens);
+
+        let ty = module.types.types()[0].unwrap_shared_enum();
+        assert!(ty.transparent);
+        let discriminants: Vec<Option<i32>> = ty.variants.iter().map(|v| v.discriminant).collect();
+        assert_eq!(discriminants, vec![Some(0), Some(4), Some(5)]);
+    }
+
+    /// Verify that we return an error if a variant of a transparent enum has fields.
+    #[test]
+    fn error_if_transparent_enum_has_fields() {
+        let tokens = quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                #[swift_bridge(transparent)]
+                enum Flags {
+                    A,
+                    B(u8),
+                }
+            }
+        };
+
+        let errors = parse_errors(tokens);
+        assert_eq!(errors.len(), 1);
+
+        match &errors[0] {
+            ParseError::EnumTransparentWithFields { enum_ident } => {
+                assert_eq!(enum_ident.to_string(), "Flags");
+            }
+            _ => panic!(),
+        }
+    }
+
+    /// Verify that we parse the `Comparable` attribute.
+    #[test]
+    fn parses_enum_comparable_attribute() {
+        let tokens = quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                #[swift_bridge(Comparable)]
+                enum Level {
+                    Low,
+                    High,
+                }
+            }
+        };
+
+        let module = parse_ok(tokens);
+
+        let ty = module.types.types()[0].unwrap_shared_enum();
+        assert!(ty.comparable);
+        assert!(ty.has_swift_raw_value());
+    }
+
+    /// Verify that we return an error if a variant of a Comparable enum has fields.
+    #[test]
+    fn error_if_comparable_enum_has_fields() {
+        let tokens = quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                #[swift_bridge(Comparable)]
+                enum Level {
+                    Low,
+                    High(u8),
+                }
+            }
+        };
+
+        let errors = parse_errors(tokens);
+        assert_eq!(errors.len(), 1);
+
+        match &errors[0] {
+            ParseError::EnumComparableWithFields { enum_ident } => {
+                assert_eq!(enum_ident.to_string(), "Level");
+            }
+            _ => panic!(),
+        }
+    }
+
+    /// Verify that we return an error if an attribute isn't recognized.
+    #[test]
+    fn error_if_attribute_unrecognized() {
+        let tokens = quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                #[swift_bridge(unrecognized)]
+                enum SomeEnum {
+                    Variant
+                }
+            }
+        };
+
+        let errors = parse_errors(tokens);
+        assert_eq!(errors.len(), 1);
+
+        match &errors[0] {
+            ParseError::EnumUnrecognizedAttribute { attribute } => {
+                assert_eq!(&attribute.to_string(), "unrecognized");
+            }
+            _ => panic!(),
+        };
+    }
 }
@@ -1,5 +1,15 @@
-use crate::bridged_type::{pat_type_pat_is_self, BridgedType};
-use crate::errors::{FunctionAttributeParseError, IdentifiableParseError, ParseError, ParseErrors};
+use crate::bridged_type::{
+    array_has_unsupported_elem_type, pat_type_pat_is_self, unsupported_range_elem_type,
+    BridgedType, OpaqueGenericArgs, StdLibType,
+};
+use crate::errors::{
+    ConstantParseError, ExternSwiftNameParseError, FunctionAttributeParseError, GetterParseError,
+    IdentifiableParseError, InitParseError, IteratorParseError, OperatorParseError, ParseError,
+    ParseErrors, ReturnAsParseError, SubscriptParseError,
+};
+use crate::parse::parse_extern_mod::argument_attributes::{
+    ArgumentAttr, ArgumentAttributes, ParsedArgumentAttributes,
+};
 use crate::parse::parse_extern_mod::function_attributes::FunctionAttributes;
 use crate::parse::parse_extern_mod::generic_opaque_type::GenericOpaqueType;
 use crate::parse::parse_extern_mod::opaque_type_attributes::{
@@ -8,18 +18,27 @@ use crate::parse::parse_extern_mod::opaque_type_attributes::{
 use crate::parse::type_declarations::{
     OpaqueForeignTypeDeclaration, TypeDeclaration, TypeDeclarations,
 };
-use crate::parse::HostLang;
+use crate::parse::{push_doc_comment_line, HostLang, SwiftVisibility};
+use crate::parsed_extern_fn::{option_inner_type, Getter};
 use crate::ParsedExternFn;
+use proc_macro2::{Group, Ident, TokenStream, TokenTree};
 use quote::ToTokens;
-use std::cmp::Ordering;
 use std::collections::HashMap;
 use std::ops::Deref;
-use syn::{FnArg, ForeignItem, ForeignItemFn, ItemForeignMod, Meta, Pat, ReturnType, Type};
+use syn::punctuated::Punctuated;
+use syn::{
+    FnArg, ForeignItem, ForeignItemFn, GenericArgument, ItemForeignMod, Pat, PathArguments,
+    ReturnType, Token, Type,
+};
 
+mod argument_attributes;
 mod function_attributes;
 mod generic_opaque_type;
 mod opaque_type_attributes;
 
+/// The operators that can be used with the `#[swift_bridge(operator = "...")]` attribute.
+const SUPPORTED_OPERATORS: [&str; 5] = ["+", "-", "*", "/", "=="];
+
 pub(super) struct ForeignModParser<'a> {
     pub errors: &'a mut ParseErrors,
     /// All of the type declarations across all of the extern "..." foreign modules in the
@@ -27,6 +46,9 @@ pub(super) struct ForeignModParser<'a> {
     pub type_declarations: &'a mut TypeDeclarations,
     pub functions: &'a mut Vec<ParsedExternFn>,
     pub unresolved_types: &'a mut Vec<Type>,
+    /// The types that were declared with the `#[swift_bridge(skip)]` attribute, which we don't
+    /// generate any code for.
+    pub skipped_types: &'a mut Vec<Ident>,
 }
 
 impl<'a> ForeignModParser<'a> {
@@ -49,13 +71,12 @@ impl<'a> ForeignModParser<'a> {
             }
         };
 
-        foreign_mod.items.sort_by(|a, _b| {
-            if matches!(a, ForeignItem::Type(_)) {
-                Ordering::Less
-            } else {
-                Ordering::Greater
-            }
-        });
+        // Parse the types before the functions that use them. The sort is stable, so the types
+        // and the functions each keep the order that they were declared in.
+        // Generic types such as `type MyWrapper<T>;` get parsed as `ForeignItem::Verbatim`.
+        foreign_mod
+            .items
+            .sort_by_key(|item| !matches!(item, ForeignItem::Type(_) | ForeignItem::Verbatim(_)));
 
         let mut local_type_declarations = HashMap::new();
         for foreign_mod_item in foreign_mod.items {
@@ -82,20 +103,7 @@ impl<'a> ForeignModParser<'a> {
 
                         match attribute_name.as_str() {
                             "doc" => {
-                                let meta = attr.parse_meta()?;
-                                let doc = match meta {
-                                    Meta::NameValue(name_val) => match name_val.lit {
-                                        syn::Lit::Str(comment) => comment.value(),
-                                        _ => {
-                                            todo!("Push parse error that doc attribute is in incorrect format")
-                                        }
-                                    },
-                                    _ => {
-                                        todo!("Push parse error that doc attribute is in incorrect format")
-                                    }
-                                };
-
-                                doc_comment = Some(doc);
+                                push_doc_comment_line(&mut doc_comment, attr)?;
                             }
                             "swift_bridge" => {
                                 let attr: OpaqueTypeAttr = attr.parse_args()?;
@@ -105,44 +113,128 @@ impl<'a> ForeignModParser<'a> {
                         };
                     }
 
+                    if attributes.skip {
+                        self.skipped_types.push(foreign_ty.ident.clone());
+                        continue;
+                    }
+
                     let foreign_type = OpaqueForeignTypeDeclaration {
                         ty: foreign_ty.ident.clone(),
                         host_lang,
                         already_declared: attributes.already_declared,
                         doc_comment,
                         generics: vec![],
+                        generic_args: OpaqueGenericArgs::default(),
+                        trait_object: attributes.trait_object,
+                        arc: attributes.arc,
+                        pin: attributes.pin,
+                        mutex: attributes.mutex,
+                        no_free: attributes.no_free,
+                        copy: attributes.copy,
+                        comparable: attributes.comparable,
+                        display: attributes.display,
+                        expressible_by_string_literal: attributes.expressible_by_string_literal,
+                        sendable: attributes.sendable,
+                        swift_name: attributes.swift_name,
+                        swift_visibility: attributes.swift_visibility,
+                        available: attributes.available,
+                        conforms_to: attributes.conforms_to,
+                        swift_protocol: None,
+                    };
+
+                    // A trait object gets used as `Box<dyn Trait>`, a reference counted type gets
+                    // used as `Arc<Type>`, a pinned type gets used as `Pin<Box<Type>>` and a mutex
+                    // gets used as `Mutex<Type>`, so those are the names that we look them up by.
+                    let ty_name = if attributes.trait_object {
+                        format!("Box < dyn {} >", ty_name)
+                    } else if attributes.arc {
+                        format!("Arc < {} >", ty_name)
+                    } else if attributes.pin {
+                        format!("Pin < Box < {} > >", ty_name)
+                    } else if attributes.mutex {
+                        format!("Mutex < {} >", ty_name)
+                    } else {
+                        ty_name
                     };
+
                     self.type_declarations.insert(
                         ty_name.clone(),
                         TypeDeclaration::Opaque(foreign_type.clone()),
                     );
                     local_type_declarations.insert(ty_name, foreign_type);
                 }
-                ForeignItem::Fn(func) => {
+                ForeignItem::Fn(mut func) => {
                     let mut attributes = FunctionAttributes::default();
+                    let mut doc_comment = None;
+                    let mut cfg_attrs = vec![];
 
                     for attr in func.attrs.iter() {
+                        if attr.path.is_ident("doc") {
+                            push_doc_comment_line(&mut doc_comment, attr)?;
+                            continue;
+                        }
+                        if attr.path.is_ident("cfg") {
+                            cfg_attrs.push(syn::parse2(attr.tokens.clone())?);
+                            continue;
+                        }
+
                         attributes = attr.parse_args()?;
                     }
 
+                    if attributes.skip {
+                        continue;
+                    }
+
+                    let argument_attributes =
+                        self.parse_argument_attributes(&mut func, host_lang)?;
+
+                    // `Self` in a return type refers to the type that the function is associated
+                    // to, so we replace it with that type.
+                    let mut self_return_ty = None;
+                    if let ReturnType::Type(_, return_ty) = &mut func.sig.output {
+                        if contains_self_ty(return_ty.to_token_stream()) {
+                            match self_ty(&func.sig.inputs, &attributes, &local_type_declarations) {
+                                Some(self_ty) => {
+                                    **return_ty = syn::parse2(replace_self_ty(
+                                        return_ty.to_token_stream(),
+                                        &self_ty,
+                                    ))?;
+                                    self_return_ty = Some(self_ty.to_token_stream().to_string());
+                                }
+                                None => {
+                                    self.errors.push(ParseError::AmbiguousSelfReturnType {
+                                        fn_ident: func.sig.ident.clone(),
+                                    });
+                                }
+                            }
+                        }
+                    }
+
                     for arg in func.sig.inputs.iter() {
                         if let FnArg::Typed(pat_ty) = arg {
                             let ty = &pat_ty.ty;
+                            self.declare_generic_opaque_type_instantiations(ty);
                             if BridgedType::new_with_type(&ty, &self.type_declarations).is_none() {
-                                self.unresolved_types.push(ty.deref().clone());
+                                self.push_unresolved_type(ty);
                             }
                         }
                     }
 
                     let return_type = &func.sig.output;
                     if let ReturnType::Type(_, return_ty) = return_type {
+                        self.declare_generic_opaque_type_instantiations(return_ty);
+                        // We've already pushed an error if we couldn't infer the type of `Self`.
                         if BridgedType::new_with_type(return_ty.deref(), &self.type_declarations)
                             .is_none()
+                            && !contains_self_ty(return_ty.to_token_stream())
                         {
-                            self.unresolved_types.push(return_ty.deref().clone());
+                            self.push_unresolved_type(return_ty);
                         }
                     }
 
+                    self.reject_rust_to_swift_only_types(&func, host_lang);
+                    self.reject_unsupported_option_types(&func);
+
                     let first_input = func.sig.inputs.iter().next();
 
                     let associated_type = self.get_associated_type(
@@ -151,6 +243,37 @@ impl<'a> ForeignModParser<'a> {
                         &attributes,
                         &mut local_type_declarations,
                     )?;
+                    // A function such as `fn with_capacity(capacity: usize) -> Self` is associated
+                    // to the type that `Self` refers to.
+                    let associated_type = associated_type.or_else(|| {
+                        self_return_ty.and_then(|ty| self.type_declarations.get(&ty).cloned())
+                    });
+
+                    // Passing a `no_free` type to Rust by value would drop it, so the value would
+                    // get freed even though its lifetime is managed outside of the bridge.
+                    if host_lang.is_rust() {
+                        for arg in func.sig.inputs.iter() {
+                            let owned_ty = match arg {
+                                FnArg::Receiver(receiver) if receiver.reference.is_none() => {
+                                    associated_type.as_ref().and_then(|ty| match ty {
+                                        TypeDeclaration::Opaque(ty) => Some(ty.clone()),
+                                        _ => None,
+                                    })
+                                }
+                                FnArg::Receiver(_) => None,
+                                FnArg::Typed(pat_ty) => {
+                                    self.owned_opaque_type(&pat_ty.ty.to_token_stream().to_string())
+                                }
+                            };
+
+                            if let Some(ty) = owned_ty.filter(|ty| ty.no_free) {
+                                self.errors.push(ParseError::NoFreeTypeTakenByValue {
+                                    fn_ident: func.sig.ident.clone(),
+                                    ty: ty.ty.clone(),
+                                });
+                            }
+                        }
+                    }
 
                     if attributes.is_swift_identifiable {
                         let args = &func.sig.inputs;
@@ -190,18 +313,331 @@ impl<'a> ForeignModParser<'a> {
                         }
                     }
 
-                    self.functions.push(ParsedExternFn {
+                    if attributes.is_swift_iterator || attributes.is_swift_async_iterator {
+                        let args = &func.sig.inputs;
+
+                        let mut is_ref_mut_self_no_args = args.len() == 1;
+                        if is_ref_mut_self_no_args {
+                            is_ref_mut_self_no_args = match args.iter().next().unwrap() {
+                                FnArg::Receiver(receiver) => {
+                                    receiver.reference.is_some() && receiver.mutability.is_some()
+                                }
+                                FnArg::Typed(pat_ty) => {
+                                    pat_type_pat_is_self(pat_ty)
+                                        && pat_ty
+                                            .ty
+                                            .to_token_stream()
+                                            .to_string()
+                                            .starts_with("& mut")
+                                }
+                            };
+                        }
+
+                        let returns_option = match &func.sig.output {
+                            ReturnType::Type(_, ty) => matches!(
+                                BridgedType::new_with_type(ty, self.type_declarations),
+                                Some(BridgedType::StdLib(StdLibType::Option(_)))
+                            ),
+                            ReturnType::Default => false,
+                        };
+
+                        let fn_ident = func.sig.ident.clone();
+                        let mut iterator_errors = vec![];
+                        if !is_ref_mut_self_no_args {
+                            iterator_errors.push(IteratorParseError::MustBeRefMutSelf {
+                                fn_ident: fn_ident.clone(),
+                            });
+                        }
+                        if !returns_option {
+                            iterator_errors.push(IteratorParseError::MustReturnOption {
+                                fn_ident: fn_ident.clone(),
+                            });
+                        }
+                        if attributes.is_swift_async_iterator && func.sig.asyncness.is_none() {
+                            iterator_errors.push(IteratorParseError::NotAsync { fn_ident });
+                        }
+
+                        for error in iterator_errors {
+                            let error = if attributes.is_swift_async_iterator {
+                                FunctionAttributeParseError::AsyncIterator(error)
+                            } else {
+                                FunctionAttributeParseError::Iterator(error)
+                            };
+                            self.errors.push(ParseError::FunctionAttribute(error));
+                        }
+                    }
+
+                    if attributes.is_swift_constant {
+                        let fn_ident = func.sig.ident.clone();
+
+                        if associated_type.is_none() {
+                            self.errors.push(ParseError::FunctionAttribute(
+                                FunctionAttributeParseError::Constant(
+                                    ConstantParseError::MustBeAssociatedToType {
+                                        fn_ident: fn_ident.clone(),
+                                    },
+                                ),
+                            ));
+                        }
+                        if !func.sig.inputs.is_empty() {
+                            self.errors.push(ParseError::FunctionAttribute(
+                                FunctionAttributeParseError::Constant(
+                                    ConstantParseError::MustNotTakeArguments {
+                                        fn_ident: fn_ident.clone(),
+                                    },
+                                ),
+                            ));
+                        }
+
+                        let is_supported_type = match &func.sig.output {
+                            ReturnType::Type(_, ty) => {
+                                BridgedType::new_with_type(ty, self.type_declarations)
+                                    .map(|ty| ty.supports_swift_constant())
+                                    .unwrap_or(false)
+                            }
+                            ReturnType::Default => false,
+                        };
+                        if !is_supported_type {
+                            self.errors.push(ParseError::FunctionAttribute(
+                                FunctionAttributeParseError::Constant(
+                                    ConstantParseError::UnsupportedType { fn_ident },
+                                ),
+                            ));
+                        }
+                    }
+
+                    if let Some(getter) = attributes.getter {
+                        let fn_ident = func.sig.ident.clone();
+                        let args = &func.sig.inputs;
+
+                        let is_ref_self_no_args =
+                            args.len() == 1 && args.iter().next().is_some_and(is_ref_self);
+                        if !is_ref_self_no_args {
+                            self.errors.push(ParseError::FunctionAttribute(
+                                FunctionAttributeParseError::Getter(
+                                    GetterParseError::MustBeRefSelf {
+                                        fn_ident: fn_ident.clone(),
+                                    },
+                                ),
+                            ));
+                        }
+
+                        let returns_reference = matches!(
+                            &func.sig.output,
+                            ReturnType::Type(_, ty) if matches!(ty.deref(), Type::Reference(_))
+                        );
+                        if getter == Getter::Clone && returns_reference {
+                            self.errors.push(ParseError::FunctionAttribute(
+                                FunctionAttributeParseError::Getter(
+                                    GetterParseError::CloneReturnsReference {
+                                        fn_ident: fn_ident.clone(),
+                                    },
+                                ),
+                            ));
+                        }
+
+                        // Methods on `Mutex` types return a `Result` since locking can fail.
+                        let returns_value = matches!(&func.sig.output, ReturnType::Type(_, _));
+                        let returns_result = matches!(
+                            BridgedType::new_with_return_type(
+                                &func.sig.output,
+                                self.type_declarations
+                            ),
+                            Some(BridgedType::StdLib(StdLibType::Result(_)))
+                        );
+                        let is_mutex_method = matches!(
+                            associated_type.as_ref(),
+                            Some(TypeDeclaration::Opaque(ty)) if ty.mutex
+                        );
+                        if getter == Getter::Computed
+                            && (func.sig.asyncness.is_some()
+                                || !returns_value
+                                || returns_result
+                                || is_mutex_method)
+                        {
+                            self.errors.push(ParseError::FunctionAttribute(
+                                FunctionAttributeParseError::Getter(
+                                    GetterParseError::ComputedMustReturnInfallibleValue {
+                                        fn_ident,
+                                    },
+                                ),
+                            ));
+                        }
+                    }
+
+                    if attributes.is_swift_subscript {
+                        let fn_ident = func.sig.ident.clone();
+                        let args = &func.sig.inputs;
+
+                        let is_ref_self_with_args =
+                            args.len() > 1 && args.iter().next().is_some_and(is_ref_self);
+                        if !is_ref_self_with_args {
+                            self.errors.push(ParseError::FunctionAttribute(
+                                FunctionAttributeParseError::Subscript(
+                                    SubscriptParseError::MustBeRefSelfWithArgs {
+                                        fn_ident: fn_ident.clone(),
+                                    },
+                                ),
+                            ));
+                        }
+
+                        if matches!(func.sig.output, ReturnType::Default) {
+                            self.errors.push(ParseError::FunctionAttribute(
+                                FunctionAttributeParseError::Subscript(
+                                    SubscriptParseError::MissingReturnType { fn_ident },
+                                ),
+                            ));
+                        }
+                    }
+
+                    if let Some(name) = &attributes.extern_swift_name {
+                        if !host_lang.is_swift() {
+                            self.errors.push(ParseError::FunctionAttribute(
+                                FunctionAttributeParseError::ExternSwiftName(
+                                    ExternSwiftNameParseError::NotExternSwift {
+                                        fn_ident: func.sig.ident.clone(),
+                                    },
+                                ),
+                            ));
+                        }
+
+                        if !is_valid_symbol_name(&name.value()) {
+                            self.errors.push(ParseError::FunctionAttribute(
+                                FunctionAttributeParseError::ExternSwiftName(
+                                    ExternSwiftNameParseError::InvalidSymbol { name: name.clone() },
+                                ),
+                            ));
+                        }
+                    }
+
+                    let mut return_as_data = false;
+                    let mut return_as_array = false;
+                    if let Some(ty) = &attributes.return_as {
+                        let is_sync_rust_fn = host_lang.is_rust() && func.sig.asyncness.is_none();
+                        let fn_ident = func.sig.ident.clone();
+
+                        match ty.value().as_str() {
+                            "Data" => {
+                                return_as_data = true;
+
+                                let returns_vec_u8 = matches!(
+                                    &func.sig.output,
+                                    ReturnType::Type(_, ty) if ty.to_token_stream().to_string() == "Vec < u8 >"
+                                );
+                                if !is_sync_rust_fn || !returns_vec_u8 {
+                                    self.errors.push(ParseError::FunctionAttribute(
+                                        FunctionAttributeParseError::ReturnAs(
+                                            ReturnAsParseError::MustReturnVecU8 { fn_ident },
+                                        ),
+                                    ));
+                                }
+                            }
+                            "Array" => {
+                                return_as_array = true;
+
+                                let returns_numeric_vec = matches!(
+                                    BridgedType::new_with_return_type(
+                                        &func.sig.output,
+                                        self.type_declarations
+                                    ),
+                                    Some(BridgedType::StdLib(StdLibType::Vec(vec)))
+                                        if vec.has_numeric_elems()
+                                );
+                                if !is_sync_rust_fn || !returns_numeric_vec {
+                                    self.errors.push(ParseError::FunctionAttribute(
+                                        FunctionAttributeParseError::ReturnAs(
+                                            ReturnAsParseError::MustReturnNumericVec { fn_ident },
+                                        ),
+                                    ));
+                                }
+                            }
+                            _ => {
+                                self.errors.push(ParseError::FunctionAttribute(
+                                    FunctionAttributeParseError::ReturnAs(
+                                        ReturnAsParseError::UnsupportedType { ty: ty.clone() },
+                                    ),
+                                ));
+                            }
+                        }
+                    }
+
+                    let mut swift_operator = None;
+                    if let Some(operator) = attributes.operator {
+                        let args = &func.sig.inputs;
+                        let is_ref_self_and_one_arg =
+                            args.len() == 2 && args.iter().next().is_some_and(is_ref_self);
+
+                        if !is_ref_self_and_one_arg {
+                            self.errors.push(ParseError::FunctionAttribute(
+                                FunctionAttributeParseError::Operator(
+                                    OperatorParseError::MustBeRefSelfAndOneArg {
+                                        fn_ident: func.sig.ident.clone(),
+                                    },
+                                ),
+                            ));
+                        }
+
+                        if SUPPORTED_OPERATORS.contains(&operator.value().as_str()) {
+                            swift_operator = Some(operator.value());
+                        } else {
+                            self.errors.push(ParseError::FunctionAttribute(
+                                FunctionAttributeParseError::Operator(
+                                    OperatorParseError::UnsupportedOperator { operator },
+                                ),
+                            ));
+                        }
+                    }
+
+                    let function = ParsedExternFn {
                         func,
                         associated_type,
                         is_swift_initializer: attributes.is_swift_initializer,
                         is_swift_identifiable: attributes.is_swift_identifiable,
+                        is_swift_iterator: attributes.is_swift_iterator,
+                        is_swift_async_iterator: attributes.is_swift_async_iterator,
+                        is_swift_constant: attributes.is_swift_constant,
+                        is_swift_subscript: attributes.is_swift_subscript,
+                        getter: attributes.getter,
+                        swift_operator,
                         host_lang,
                         rust_name_override: attributes.rust_name,
                         swift_name_override: attributes.swift_name,
+                        extern_swift_name: attributes.extern_swift_name,
+                        return_as_data,
+                        return_as_array,
                         into_return_type: attributes.into_return_type,
                         return_with: attributes.return_with,
                         args_into: attributes.args_into,
-                    });
+                        swift_visibility: attributes.swift_visibility,
+                        available: attributes.available,
+                        arg_defaults: argument_attributes.defaults,
+                        arg_labels: argument_attributes.labels,
+                        args_as_data: argument_attributes.as_data,
+                        args_as_array: argument_attributes.as_array,
+                        doc_comment,
+                        cfg_attrs,
+                    };
+
+                    // Each initializer gets its own symbol, but Swift can only tell initializers
+                    // apart by their argument labels and types.
+                    if function.is_swift_initializer {
+                        let signature = function.swift_initializer_signature();
+                        if let Some(other) = self.functions.iter().find(|other| {
+                            other.is_swift_initializer
+                                && other.swift_initializer_signature() == signature
+                        }) {
+                            self.errors.push(ParseError::FunctionAttribute(
+                                FunctionAttributeParseError::Init(
+                                    InitParseError::DuplicateSignature {
+                                        fn_ident: function.func.sig.ident.clone(),
+                                        other_fn_ident: other.func.sig.ident.clone(),
+                                    },
+                                ),
+                            ));
+                        }
+                    }
+
+                    self.functions.push(function);
                 }
                 ForeignItem::Verbatim(foreign_item_verbatim) => {
                     if let Ok(generic_foreign_type) =
@@ -211,6 +647,15 @@ impl<'a> ForeignModParser<'a> {
                         //  types
                         let ty_name = generic_foreign_type.ident.to_string();
 
+                        if let Some(protocol) = generic_foreign_type.swift_protocol.as_ref() {
+                            if host_lang.is_rust() {
+                                self.errors.push(ParseError::ProtocolBoundOnRustType {
+                                    ty: generic_foreign_type.ident.clone(),
+                                    protocol: protocol.clone(),
+                                });
+                            }
+                        }
+
                         let foreign_ty = OpaqueForeignTypeDeclaration {
                             ty: generic_foreign_type.ident,
                             host_lang,
@@ -222,6 +667,22 @@ impl<'a> ForeignModParser<'a> {
                                 .clone()
                                 .into_iter()
                                 .collect(),
+                            generic_args: OpaqueGenericArgs::default(),
+                            trait_object: false,
+                            arc: false,
+                            pin: false,
+                            mutex: false,
+                            no_free: false,
+                            copy: None,
+                            comparable: false,
+                            display: false,
+                            expressible_by_string_literal: false,
+                            sendable: false,
+                            swift_name: None,
+                            swift_visibility: SwiftVisibility::default(),
+                            available: None,
+                            conforms_to: vec![],
+                            swift_protocol: generic_foreign_type.swift_protocol,
                         };
                         self.type_declarations
                             .insert(ty_name.clone(), TypeDeclaration::Opaque(foreign_ty.clone()));
@@ -235,6 +696,277 @@ impl<'a> ForeignModParser<'a> {
         Ok(())
     }
 
+    /// Parse the `#[swift_bridge(default = ...)]`, `#[swift_bridge(label = "...")]` and
+    /// `#[swift_bridge(as = "...")]` attributes on a function's arguments.
+    ///
+    /// We remove the attributes from the function's arguments since they are only meaningful to
+    /// us, and return them keyed by argument name.
+    fn parse_argument_attributes(
+        &mut self,
+        func: &mut ForeignItemFn,
+        host_lang: HostLang,
+    ) -> syn::Result<ParsedArgumentAttributes> {
+        let mut parsed = ParsedArgumentAttributes::default();
+        let is_async = func.sig.asyncness.is_some();
+
+        for arg in func.sig.inputs.iter_mut() {
+            let pat_ty = match arg {
+                FnArg::Typed(pat_ty) => pat_ty,
+                FnArg::Receiver(_) => continue,
+            };
+
+            let mut attributes = ArgumentAttributes::default();
+            let mut other_attrs = vec![];
+            for attr in pat_ty.attrs.drain(..) {
+                if attr.path.is_ident("swift_bridge") {
+                    let attribs = attr
+                        .parse_args_with(Punctuated::<ArgumentAttr, Token![,]>::parse_terminated)?;
+                    for attrib in attribs {
+                        attributes.store_attrib(attrib);
+                    }
+                } else {
+                    other_attrs.push(attr);
+                }
+            }
+            pat_ty.attrs = other_attrs;
+
+            let arg_name = pat_ty.pat.to_token_stream().to_string();
+            if let Some(label) = attributes.label {
+                parsed.labels.insert(arg_name.clone(), label.value());
+            }
+
+            if let Some(as_ty) = attributes.as_ty {
+                let bridged_ty = BridgedType::new_with_type(&pat_ty.ty, self.type_declarations);
+
+                match as_ty.value().as_str() {
+                    "Data" => {
+                        let is_u8_slice = matches!(
+                            bridged_ty,
+                            Some(BridgedType::StdLib(StdLibType::RefSlice(slice))) if slice.is_u8_slice()
+                        );
+                        if !host_lang.is_rust() || is_async || !is_u8_slice {
+                            self.errors.push(ParseError::ArgAsDataMustBeU8Slice {
+                                ty: pat_ty.ty.deref().clone(),
+                            });
+                        } else {
+                            parsed.as_data.insert(arg_name.clone());
+                        }
+                    }
+                    "Array" => {
+                        let is_primitive_vec = matches!(
+                            bridged_ty,
+                            Some(BridgedType::StdLib(StdLibType::Vec(vec))) if vec.can_be_passed_as_swift_array()
+                        );
+                        if !host_lang.is_rust() || !is_primitive_vec {
+                            self.errors.push(ParseError::ArgAsArrayMustBePrimitiveVec {
+                                ty: pat_ty.ty.deref().clone(),
+                            });
+                        } else {
+                            parsed.as_array.insert(arg_name.clone());
+                        }
+                    }
+                    _ => {
+                        self.errors
+                            .push(ParseError::ArgAsUnsupportedType { ty: as_ty });
+                    }
+                }
+            }
+
+            let default = match attributes.default {
+                Some(default) => default,
+                None => continue,
+            };
+
+            if host_lang.is_swift() {
+                self.errors
+                    .push(ParseError::ArgDefaultOnExternSwiftFunction { default });
+                continue;
+            }
+
+            let ty = match BridgedType::new_with_type(&pat_ty.ty, self.type_declarations) {
+                Some(ty) => ty,
+                // We report undeclared types elsewhere.
+                None => continue,
+            };
+            if !ty.supports_swift_default_value() {
+                self.errors.push(ParseError::ArgDefaultUnsupportedType {
+                    ty: pat_ty.ty.deref().clone(),
+                });
+                continue;
+            }
+            if !ty.can_be_initialized_with_literal(&default) {
+                self.errors.push(ParseError::ArgDefaultMismatchedType {
+                    default,
+                    ty: pat_ty.ty.deref().clone(),
+                });
+                continue;
+            }
+
+            parsed.defaults.insert(arg_name, default);
+        }
+
+        Ok(parsed)
+    }
+
+    /// Push an error for each type that Swift would need to pass to Rust, but that can only be
+    /// passed from Rust to Swift.
+    ///
+    /// Swift passes the arguments of `extern "Rust"` functions and the return values of
+    /// `extern "Swift"` functions to Rust.
+    fn reject_rust_to_swift_only_types(&mut self, func: &ForeignItemFn, host_lang: HostLang) {
+        let swift_to_rust_tys: Vec<&Type> = if host_lang.is_rust() {
+            func.sig
+                .inputs
+                .iter()
+                .filter_map(|arg| match arg {
+                    FnArg::Typed(pat_ty) => Some(pat_ty.ty.deref()),
+                    FnArg::Receiver(_) => None,
+                })
+                .collect()
+        } else {
+            match &func.sig.output {
+                ReturnType::Type(_, ty) => vec![ty.deref()],
+                ReturnType::Default => vec![],
+            }
+        };
+
+        for ty in swift_to_rust_tys {
+            // We report undeclared types elsewhere.
+            let is_supported = BridgedType::new_with_type(ty, self.type_declarations)
+                .map(|bridged| bridged.can_be_passed_from_swift_to_rust())
+                .unwrap_or(true);
+            if !is_supported {
+                self.errors.push(ParseError::RustToSwiftOnlyType {
+                    ty: ty.clone(),
+                    host_lang,
+                });
+            }
+        }
+    }
+
+    /// Push an error for each argument or return type that is an `Option` holding a type that
+    /// can't be wrapped in an `Option` yet, such as an `Option<char>`.
+    fn reject_unsupported_option_types(&mut self, func: &ForeignItemFn) {
+        let arg_tys = func.sig.inputs.iter().filter_map(|arg| match arg {
+            FnArg::Typed(pat_ty) => Some(pat_ty.ty.deref()),
+            FnArg::Receiver(_) => None,
+        });
+        let return_ty = match &func.sig.output {
+            ReturnType::Type(_, ty) => Some(ty.deref()),
+            ReturnType::Default => None,
+        };
+
+        for ty in arg_tys.chain(return_ty) {
+            if let Some(BridgedType::StdLib(StdLibType::Option(option))) =
+                BridgedType::new_with_type(ty, self.type_declarations)
+            {
+                if !option.ty.can_be_wrapped_in_option() {
+                    self.errors
+                        .push(ParseError::UnsupportedOptionType { ty: ty.clone() });
+                }
+            }
+        }
+    }
+
+    /// Store a type that we could not resolve so that we can check it again once all of the
+    /// module's types have been declared.
+    ///
+    /// Arrays and ranges that hold an unsupported element type will never resolve, so we push an
+    /// error for them right away.
+    fn push_unresolved_type(&mut self, ty: &Type) {
+        if let Type::Array(array) = ty {
+            if array_has_unsupported_elem_type(array, self.type_declarations) {
+                self.errors.push(ParseError::ArrayElementNotCopy {
+                    elem: array.elem.deref().clone(),
+                });
+                return;
+            }
+        }
+        if let Type::Path(path) = ty {
+            if let Some(elem) = unsupported_range_elem_type(path, self.type_declarations) {
+                self.errors
+                    .push(ParseError::RangeElementNotInteger { elem: elem.clone() });
+                return;
+            }
+        }
+
+        self.unresolved_types.push(ty.clone());
+    }
+
+    /// Declare every instantiation of a generic opaque type within the given type, such as the
+    /// `MyWrapper<u32>` in `Option<MyWrapper<u32>>`, so that they resolve like any other declared
+    /// type.
+    ///
+    /// Instantiations that we do not support, such as `MyWrapper<String>`, stay undeclared.
+    fn declare_generic_opaque_type_instantiations(&mut self, ty: &Type) {
+        let path = match ty {
+            Type::Reference(reference) => {
+                self.declare_generic_opaque_type_instantiations(&reference.elem);
+                return;
+            }
+            Type::Path(path) => path,
+            _ => return,
+        };
+        let last = match path.path.segments.last() {
+            Some(last) => last,
+            None => return,
+        };
+        let args = match &last.arguments {
+            PathArguments::AngleBracketed(args) => &args.args,
+            _ => return,
+        };
+
+        for arg in args.iter() {
+            if let GenericArgument::Type(arg) = arg {
+                self.declare_generic_opaque_type_instantiations(arg);
+            }
+        }
+
+        let ty_name = path.path.to_token_stream().to_string();
+        if self.type_declarations.get(&ty_name).is_some() {
+            return;
+        }
+
+        let generic_ty = match self.type_declarations.get(&last.ident.to_string()) {
+            Some(TypeDeclaration::Opaque(opaque))
+                if opaque.host_lang.is_rust() && opaque.is_generic_declaration() =>
+            {
+                opaque
+            }
+            _ => return,
+        };
+        let generic_args = match OpaqueGenericArgs::new_with_type_path(path, self.type_declarations)
+        {
+            Some(generic_args) if generic_args.args.len() == generic_ty.generics.len() => {
+                generic_args
+            }
+            _ => return,
+        };
+
+        let instantiation = OpaqueForeignTypeDeclaration {
+            generics: vec![],
+            generic_args,
+            ..generic_ty.clone()
+        };
+        self.type_declarations
+            .insert(ty_name, TypeDeclaration::Opaque(instantiation));
+    }
+
+    /// The opaque type that gets passed by value in an argument of type `ty`, such as the
+    /// `SomeType` in `SomeType` or `Option<SomeType>`.
+    fn owned_opaque_type(&self, ty: &str) -> Option<OpaqueForeignTypeDeclaration> {
+        // "Option < SomeType >" -> "SomeType"
+        let ty = ty
+            .strip_prefix("Option < ")
+            .and_then(|ty| ty.strip_suffix(" >"))
+            .unwrap_or(ty);
+
+        match self.type_declarations.get(ty) {
+            Some(TypeDeclaration::Opaque(ty)) => Some(ty.clone()),
+            _ => None,
+        }
+    }
+
     fn get_associated_type(
         &mut self,
         first: Option<&FnArg>,
@@ -307,7 +1039,11 @@ Otherwise we use a more general error that says that your argument is invalid.
                         ReturnType::Default => {
                             todo!("Push error if initializer does not return a type")
                         }
-                        ReturnType::Type(_, ty) => ty.deref().to_token_stream().to_string(),
+                        // A failable initializer returns `Option<SomeType>`.
+                        ReturnType::Type(_, ty) => option_inner_type(ty)
+                            .unwrap_or(ty)
+                            .to_token_stream()
+                            .to_string(),
                     };
 
                     let ty = self.type_declarations.get(&ty_string);
@@ -325,25 +1061,146 @@ Otherwise we use a more general error that says that your argument is invalid.
     }
 }
 
-#[cfg(test)]
-mod tests {
-    use crate::errors::ParseError;
-    use crate::test_utils::{parse_errors, parse_ok};
-    use crate::SwiftBridgeModule;
-    use quote::{quote, ToTokens};
-    use syn::parse_quote;
+/// `&self` or `self: &SomeType`
+fn is_ref_self(arg: &FnArg) -> bool {
+    match arg {
+        FnArg::Receiver(receiver) => receiver.reference.is_some() && receiver.mutability.is_none(),
+        FnArg::Typed(pat_ty) => {
+            let ty = pat_ty.ty.to_token_stream().to_string();
+            pat_type_pat_is_self(pat_ty) && ty.starts_with('&') && !ty.starts_with("& mut")
+        }
+    }
+}
 
-    /// Verify that we can parse a SwiftBridgeModule from an empty module.
-    #[test]
-    fn parse_empty_module() {
-        let tokens = quote! {
-            mod foo { }
-        };
+/// Get the type that `Self` refers to in a function's signature.
+///
+/// This is the type in `#[swift_bridge(associated_to = SomeType)]`, the type of a
+/// `self: &SomeType` argument, or the only type declared in the extern block.
+fn self_ty(
+    inputs: &Punctuated<FnArg, Token![,]>,
+    attributes: &FunctionAttributes,
+    local_type_declarations: &HashMap<String, OpaqueForeignTypeDeclaration>,
+) -> Option<Type> {
+    if let Some(associated_to) = &attributes.associated_to {
+        return syn::parse2(associated_to.to_token_stream()).ok();
+    }
+
+    if let Some(FnArg::Typed(arg)) = inputs.iter().next() {
+        if pat_type_pat_is_self(arg) {
+            return match arg.ty.deref() {
+                Type::Reference(type_ref) => Some(type_ref.elem.deref().clone()),
+                ty => Some(ty.clone()),
+            };
+        }
+    }
+
+    if local_type_declarations.len() == 1 {
+        let ty_name = local_type_declarations.keys().next().unwrap();
+        return syn::parse_str(ty_name).ok();
+    }
+
+    None
+}
+
+/// Whether or not some tokens, such as `Option < Self >`, mention the `Self` type.
+fn contains_self_ty(tokens: TokenStream) -> bool {
+    tokens.into_iter().any(|token| match token {
+        TokenTree::Ident(ident) => ident == "Self",
+        TokenTree::Group(group) => contains_self_ty(group.stream()),
+        _ => false,
+    })
+}
+
+/// Replace every `Self` in some tokens with the type that it refers to.
+fn replace_self_ty(tokens: TokenStream, self_ty: &Type) -> TokenStream {
+    tokens
+        .into_iter()
+        .flat_map(|token| match token {
+            TokenTree::Ident(ident) if ident == "Self" => self_ty.to_token_stream(),
+            TokenTree::Group(group) => {
+                let mut replaced =
+                    Group::new(group.delimiter(), replace_self_ty(group.stream(), self_ty));
+                replaced.set_span(group.span());
+                TokenTree::Group(replaced).into()
+            }
+            token => token.into(),
+        })
+        .collect()
+}
+
+/// Whether or not a name can be used as a linker symbol, such as `my_swift_function` or
+/// `__swift_bridge__$some_function`.
+fn is_valid_symbol_name(name: &str) -> bool {
+    let mut chars = name.chars();
+
+    let starts_with_letter_or_underscore =
+        matches!(chars.next(), Some(first) if first.is_ascii_alphabetic() || first == '_');
+
+    starts_with_letter_or_underscore
+        && chars.all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '$')
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::errors::ParseError;
+    use crate::parse::{SwiftBridgeModuleAndErrors, SwiftVisibility};
+    use crate::test_utils::{parse_errors, parse_ok};
+    use crate::SwiftBridgeModule;
+    use quote::{quote, ToTokens};
+    use syn::parse_quote;
+
+    /// Verify that we can parse a SwiftBridgeModule from an empty module.
+    #[test]
+    fn parse_empty_module() {
+        let tokens = quote! {
+            mod foo { }
+        };
         let module: SwiftBridgeModule = parse_quote!(#tokens);
 
         assert_eq!(module.name.to_string(), "foo");
     }
 
+    /// Verify that types and functions keep their source order across interleaved extern blocks.
+    #[test]
+    fn parse_interleaved_extern_blocks_in_source_order() {
+        let tokens = quote! {
+            mod foo {
+                extern "Rust" {
+                    type TypeA;
+                    fn first();
+                    type TypeB;
+                    type TypeC;
+                }
+
+                extern "Swift" {
+                    fn second();
+                    type TypeD;
+                }
+
+                extern "Rust" {
+                    fn third();
+                    type TypeE;
+                }
+            }
+        };
+        let module = parse_ok(tokens);
+
+        let types: Vec<String> = module
+            .types
+            .types()
+            .iter()
+            .map(|ty| ty.unwrap_opaque().ty.to_string())
+            .collect();
+        assert_eq!(types, vec!["TypeA", "TypeB", "TypeC", "TypeD", "TypeE"]);
+
+        let functions: Vec<String> = module
+            .functions
+            .iter()
+            .map(|f| f.func.sig.ident.to_string())
+            .collect();
+        assert_eq!(functions, vec!["first", "second", "third"]);
+    }
+
     /// Verify that we store an error if no abi name was provided.
     #[test]
     fn error_if_no_abi_name_provided_for_an_extern_block() {
@@ -534,6 +1391,192 @@ mod tests {
         }
     }
 
+    /// Verify that we push an error instead of panicking when a function has a tuple, array or
+    /// slice argument that we do not support, such as a tuple that holds an opaque type or that
+    /// has more than four elements.
+    #[test]
+    fn unsupported_tuple_and_array_argument_types() {
+        let tokens = quote! {
+            mod foo {
+                extern "Rust" {
+                    type Foo;
+
+                    fn a (arg: (u8, Foo));
+                    fn b (arg: [Foo; 2]);
+                }
+
+                extern "Swift" {
+                    type Bar;
+
+                    fn c (arg: (Bar, u8));
+                    fn d (arg: (u8, u16, u32, u64, u8));
+                }
+            }
+        };
+        let errors = parse_errors(tokens);
+        assert_eq!(errors.len(), 4);
+
+        for error in errors.iter() {
+            match error {
+                ParseError::UndeclaredType { .. } | ParseError::ArrayElementNotCopy { .. } => {}
+                _ => panic!(),
+            }
+        }
+    }
+
+    /// Verify that we push an error if a fixed size array holds a type that isn't a `Copy`
+    /// primitive.
+    #[test]
+    fn error_if_array_element_is_not_copy() {
+        let tokens = quote! {
+            mod foo {
+                extern "Rust" {
+                    type Foo;
+
+                    fn a (arg: [String; 2]);
+                    fn b () -> [Foo; 3];
+                    fn c (arg: [u8; 4]) -> [f32; 2];
+                }
+            }
+        };
+        let errors = parse_errors(tokens);
+        assert_eq!(errors.len(), 2);
+
+        for error in errors.iter() {
+            match error {
+                ParseError::ArrayElementNotCopy { .. } => {}
+                _ => panic!(),
+            }
+        }
+    }
+
+    /// Verify that we push an error if Swift would need to pass a type to Rust that can only be
+    /// passed from Rust to Swift.
+    #[test]
+    fn error_if_rust_to_swift_only_type_is_passed_to_rust() {
+        let tokens = quote! {
+            mod foo {
+                extern "Rust" {
+                    type SomeError;
+
+                    fn a (arg: Result<u8, SomeError>);
+                    fn b () -> Result<u8, SomeError>;
+                    fn c (arg: Cow<'static, str>);
+                    fn d () -> Cow<'static, str>;
+                    fn e (arg: Vec<Vec<u8>>);
+                    fn f () -> Vec<Vec<u8>>;
+                    fn k (arg: VecDeque<u8>);
+                    fn l () -> VecDeque<u8>;
+                    fn m (arg: Box<str>);
+                    fn n () -> Box<str>;
+                }
+
+                extern "Swift" {
+                    fn g () -> Cow<'static, str>;
+                    fn h (arg: Cow<'static, str>);
+                    fn i () -> Vec<Vec<u8>>;
+                    fn j (arg: Vec<Vec<u8>>);
+                    fn o () -> VecDeque<u8>;
+                    fn p (arg: VecDeque<u8>);
+                    fn q () -> Box<str>;
+                    fn r (arg: Box<str>);
+                }
+            }
+        };
+        let errors = parse_errors(tokens);
+        assert_eq!(errors.len(), 9);
+
+        let rejected: Vec<(String, bool)> = errors
+            .iter()
+            .map(|error| match error {
+                ParseError::RustToSwiftOnlyType { ty, host_lang } => {
+                    (ty.to_token_stream().to_string(), host_lang.is_rust())
+                }
+                _ => panic!(),
+            })
+            .collect();
+        assert_eq!(
+            rejected,
+            vec![
+                ("Result < u8 , SomeError >".to_string(), true),
+                ("Cow < 'static , str >".to_string(), true),
+                ("Vec < Vec < u8 > >".to_string(), true),
+                ("VecDeque < u8 >".to_string(), true),
+                ("Box < str >".to_string(), true),
+                ("Cow < 'static , str >".to_string(), false),
+                ("Vec < Vec < u8 > >".to_string(), false),
+                ("VecDeque < u8 >".to_string(), false),
+                ("Box < str >".to_string(), false),
+            ]
+        );
+    }
+
+    /// Verify that we push an error if an argument or return type is an `Option` that holds a type
+    /// that can't be wrapped in an `Option` yet.
+    #[test]
+    fn error_if_option_holds_unsupported_type() {
+        let tokens = quote! {
+            mod foo {
+                extern "Rust" {
+                    fn a () -> Option<char>;
+                    fn b (arg: Option<char>);
+                    fn c (arg: char) -> Option<u32>;
+                    fn d () -> Option<Cow<'static, str>>;
+                    fn e (arg: Option<VecDeque<u8>>);
+                    fn f () -> Option<Box<str>>;
+                }
+
+                extern "Swift" {
+                    fn g () -> Option<char>;
+                }
+            }
+        };
+        let errors = parse_errors(tokens);
+        assert_eq!(errors.len(), 6);
+
+        let rejected: Vec<String> = errors
+            .iter()
+            .map(|error| match error {
+                ParseError::UnsupportedOptionType { ty } => ty.to_token_stream().to_string(),
+                _ => panic!(),
+            })
+            .collect();
+        assert_eq!(
+            rejected,
+            vec![
+                "Option < char >",
+                "Option < char >",
+                "Option < Cow < 'static , str > >",
+                "Option < VecDeque < u8 > >",
+                "Option < Box < str > >",
+                "Option < char >",
+            ]
+        );
+    }
+
+    /// Verify that we push an error if a range holds a type that isn't an integer.
+    #[test]
+    fn error_if_range_element_is_not_integer() {
+        let tokens = quote! {
+            mod foo {
+                extern "Rust" {
+                    fn a (arg: Range<f32>);
+                    fn b () -> std::ops::RangeInclusive<String>;
+                    fn c (arg: Range<usize>) -> RangeInclusive<i32>;
+                }
+            }
+        };
+        let errors = parse_errors(tokens);
+        assert_eq!(errors.len(), 2);
+
+        for error in errors.iter() {
+            match error {
+                ParseError::RangeElementNotInteger { .. } => {}
+                _ => panic!(),
+            }
+        }
+    }
+
     /// Verify that a freestanding function can return a declared type.
     #[test]
     fn freestanding_function_return_declared_type() {
@@ -612,6 +1655,84 @@ mod tests {
         }
     }
 
+    /// Verify that a `Self` return type gets replaced with the type that the function is
+    /// associated to.
+    #[test]
+    fn parses_self_return_type() {
+        let tokens = quote! {
+            mod foo {
+                extern "Rust" {
+                    type SomeType;
+
+                    fn a () -> Self;
+                    fn b (&self) -> Option<Self>;
+                }
+
+                extern "Rust" {
+                    type AnotherType;
+                    type ThirdType;
+
+                    #[swift_bridge(associated_to = AnotherType)]
+                    fn c () -> Self;
+                    fn d (self: &ThirdType) -> Self;
+                }
+            }
+        };
+
+        let module = parse_ok(tokens);
+        let functions = &module.functions;
+
+        for (idx, (ty_name, return_ty)) in [
+            ("SomeType", "SomeType"),
+            ("SomeType", "Option < SomeType >"),
+            ("AnotherType", "AnotherType"),
+            ("ThirdType", "ThirdType"),
+        ]
+        .into_iter()
+        .enumerate()
+        {
+            assert_eq!(
+                functions[idx]
+                    .associated_type
+                    .as_ref()
+                    .unwrap()
+                    .unwrap_opaque()
+                    .ty
+                    .to_string(),
+                ty_name
+            );
+            assert_eq!(
+                functions[idx].func.sig.output.to_token_stream().to_string(),
+                format!("-> {}", return_ty)
+            );
+        }
+    }
+
+    /// Verify that we push an error if we can't tell which type a `Self` return type refers to.
+    #[test]
+    fn error_if_self_return_type_is_ambiguous() {
+        let tokens = quote! {
+            mod foo {
+                extern "Rust" {
+                    type SomeType;
+                    type AnotherType;
+
+                    fn a () -> Self;
+                }
+            }
+        };
+
+        let errors = parse_errors(tokens);
+        assert_eq!(errors.len(), 1);
+
+        match &errors[0] {
+            ParseError::AmbiguousSelfReturnType { fn_ident } => {
+                assert_eq!(fn_ident, "a");
+            }
+            _ => panic!(),
+        };
+    }
+
     /// Verify that annotated self methods get parsed.
     #[test]
     fn disambiguate_method() {
@@ -724,56 +1845,602 @@ mod tests {
         );
     }
 
-    /// Verify that we can parse a doc comment from an extern "Rust" opaque type.
+    /// Verify that we can parse the `Display` attribute.
     #[test]
-    fn parse_opaque_rust_type_doc_comment() {
+    fn parse_display_attribute() {
         let tokens = quote! {
             mod foo {
                 extern "Rust" {
-                    /// Some comment
-                    type AnotherType;
+                    #[swift_bridge(Display)]
+                    type SomeType;
                 }
             }
         };
 
         let module = parse_ok(tokens);
 
-        assert_eq!(
+        assert!(
             module
                 .types
-                .get("AnotherType")
+                .get("SomeType")
                 .unwrap()
                 .unwrap_opaque()
-                .doc_comment
-                .as_ref()
-                .unwrap(),
-            " Some comment"
+                .display
         );
     }
 
-    /// Verify that we can parse generic extern "Rust" types
+    /// Verify that we can parse the `ExpressibleByStringLiteral` attribute.
     #[test]
-    fn parse_generic_extern_rust_type() {
+    fn parse_expressible_by_string_literal_attribute() {
         let tokens = quote! {
-            #[swift_bridge:bridge]
             mod foo {
                 extern "Rust" {
-                    type SomeType<A, B>;
+                    #[swift_bridge(ExpressibleByStringLiteral)]
+                    type SomeType;
+
+                    #[swift_bridge(init)]
+                    fn new(text: &str) -> SomeType;
                 }
             }
         };
 
         let module = parse_ok(tokens);
 
-        assert_eq!(
+        assert!(
             module
                 .types
                 .get("SomeType")
                 .unwrap()
                 .unwrap_opaque()
-                .generics
-                .len(),
-            2
+                .expressible_by_string_literal
+        );
+    }
+
+    /// Verify that we push an error if an `ExpressibleByStringLiteral` type does not have an
+    /// initializer that takes a single string.
+    #[test]
+    fn error_if_expressible_by_string_literal_without_string_init() {
+        let tokens = quote! {
+            mod foo {
+                extern "Rust" {
+                    #[swift_bridge(ExpressibleByStringLiteral)]
+                    type SomeType;
+
+                    #[swift_bridge(init)]
+                    fn new(text: String, len: u8) -> SomeType;
+
+                    #[swift_bridge(init)]
+                    fn parse(text: &str) -> Option<SomeType>;
+                }
+            }
+        };
+
+        let errors = parse_errors(tokens);
+
+        assert_eq!(errors.len(), 1);
+        match &errors[0] {
+            ParseError::ExpressibleByStringLiteralMissingInit { ty } => {
+                assert_eq!(ty, "SomeType");
+            }
+            _ => panic!(),
+        }
+    }
+
+    /// Verify that we can parse the `Comparable` attribute.
+    #[test]
+    fn parse_comparable_attribute() {
+        let tokens = quote! {
+            mod foo {
+                extern "Rust" {
+                    #[swift_bridge(Comparable)]
+                    type SomeType;
+                }
+            }
+        };
+
+        let module = parse_ok(tokens);
+
+        assert!(
+            module
+                .types
+                .get("SomeType")
+                .unwrap()
+                .unwrap_opaque()
+                .comparable
         );
     }
+
+    /// Verify that we can parse the Swift protocol that a Swift type's methods get called
+    /// through.
+    #[test]
+    fn parse_swift_type_protocol() {
+        let tokens = quote! {
+            mod foo {
+                extern "Swift" {
+                    type SomeType: SomeProtocol;
+                    fn protocol_method(&self);
+                }
+            }
+        };
+
+        let module = parse_ok(tokens);
+
+        let ty = module.types.get("SomeType").unwrap().unwrap_opaque();
+        assert_eq!(ty.swift_protocol.as_ref().unwrap(), "SomeProtocol");
+        assert!(!ty.is_generic_declaration());
+    }
+
+    /// Verify that we push an error if an extern "Rust" type declares a Swift protocol.
+    #[test]
+    fn error_if_rust_type_has_protocol() {
+        let tokens = quote! {
+            mod foo {
+                extern "Rust" {
+                    type SomeType: SomeProtocol;
+                }
+            }
+        };
+
+        let errors = parse_errors(tokens);
+
+        assert_eq!(errors.len(), 1);
+        match &errors[0] {
+            ParseError::ProtocolBoundOnRustType { ty, protocol } => {
+                assert_eq!(ty, "SomeType");
+                assert_eq!(protocol, "SomeProtocol");
+            }
+            _ => panic!(),
+        }
+    }
+
+    /// Verify that we can parse the `Sendable` attribute.
+    #[test]
+    fn parse_sendable_attribute() {
+        let tokens = quote! {
+            mod foo {
+                extern "Rust" {
+                    #[swift_bridge(Sendable)]
+                    type SomeType;
+                }
+            }
+        };
+
+        let module = parse_ok(tokens);
+
+        assert!(
+            module
+                .types
+                .get("SomeType")
+                .unwrap()
+                .unwrap_opaque()
+                .sendable
+        );
+    }
+
+    /// Verify that we can parse the `swift_name` attribute on an opaque type.
+    #[test]
+    fn parse_opaque_type_swift_name_attribute() {
+        let tokens = quote! {
+            mod foo {
+                extern "Rust" {
+                    #[swift_bridge(swift_name = "ImageBuffer")]
+                    type ImgBuf;
+                }
+            }
+        };
+
+        let module = parse_ok(tokens);
+
+        let ty = module.types.get("ImgBuf").unwrap().unwrap_opaque();
+        assert_eq!(ty.swift_name.as_ref().unwrap().value(), "ImageBuffer");
+        assert_eq!(ty.swift_name(), "ImageBuffer");
+        assert_eq!(ty.link_name_segment(), "ImgBuf");
+    }
+
+    /// Verify that we can parse the `Copy(N)` attribute on an opaque type.
+    #[test]
+    fn parse_opaque_type_copy_attribute() {
+        let tokens = quote! {
+            mod foo {
+                extern "Rust" {
+                    #[swift_bridge(Copy(16))]
+                    type SomeType;
+
+                    type AnotherType;
+                }
+            }
+        };
+
+        let module = parse_ok(tokens);
+
+        assert_eq!(
+            module.types.get("SomeType").unwrap().unwrap_opaque().copy,
+            Some(16)
+        );
+        assert_eq!(
+            module
+                .types
+                .get("AnotherType")
+                .unwrap()
+                .unwrap_opaque()
+                .copy,
+            None
+        );
+    }
+
+    /// Verify that we can parse the `conforms_to` attribute with one or many protocols.
+    #[test]
+    fn parse_conforms_to_attribute() {
+        let tokens = quote! {
+            mod foo {
+                extern "Rust" {
+                    #[swift_bridge(conforms_to = Shape)]
+                    type SomeType;
+
+                    #[swift_bridge(conforms_to = (Shape, Drawable))]
+                    type AnotherType;
+                }
+            }
+        };
+
+        let module = parse_ok(tokens);
+
+        let conforms_to = |ty: &str| -> Vec<String> {
+            module
+                .types
+                .get(ty)
+                .unwrap()
+                .unwrap_opaque()
+                .conforms_to
+                .iter()
+                .map(|protocol| protocol.to_string())
+                .collect()
+        };
+
+        assert_eq!(conforms_to("SomeType"), vec!["Shape"]);
+        assert_eq!(conforms_to("AnotherType"), vec!["Shape", "Drawable"]);
+    }
+
+    /// Verify that we can parse the `swift_visibility` attribute on an opaque type.
+    #[test]
+    fn parse_swift_visibility_attribute() {
+        let tokens = quote! {
+            mod foo {
+                extern "Rust" {
+                    #[swift_bridge(swift_visibility = "internal")]
+                    type SomeType;
+                    type AnotherType;
+                }
+            }
+        };
+
+        let module = parse_ok(tokens);
+
+        let visibility = |ty: &str| {
+            module
+                .types
+                .get(ty)
+                .unwrap()
+                .unwrap_opaque()
+                .swift_visibility
+        };
+        assert_eq!(visibility("SomeType"), SwiftVisibility::Internal);
+        assert_eq!(visibility("AnotherType"), SwiftVisibility::Public);
+    }
+
+    /// Verify that an opaque type can't be `fileprivate` or `private`, since the code that we
+    /// generate for it is `internal`.
+    #[test]
+    fn error_if_opaque_type_swift_visibility_is_private() {
+        for visibility in ["fileprivate", "private", "invalid"] {
+            let tokens = quote! {
+                mod foo {
+                    extern "Rust" {
+                        #[swift_bridge(swift_visibility = #visibility)]
+                        type SomeType;
+                    }
+                }
+            };
+
+            assert!(syn::parse2::<SwiftBridgeModuleAndErrors>(tokens).is_err());
+        }
+    }
+
+    /// Verify that we can parse a doc comment from an extern "Rust" opaque type.
+    #[test]
+    fn parse_opaque_rust_type_doc_comment() {
+        let tokens = quote! {
+            mod foo {
+                extern "Rust" {
+                    /// Some comment
+                    type AnotherType;
+                }
+            }
+        };
+
+        let module = parse_ok(tokens);
+
+        assert_eq!(
+            module
+                .types
+                .get("AnotherType")
+                .unwrap()
+                .unwrap_opaque()
+                .doc_comment
+                .as_ref()
+                .unwrap(),
+            " Some comment"
+        );
+    }
+
+    /// Verify that we can parse generic extern "Rust" types
+    #[test]
+    fn parse_generic_extern_rust_type() {
+        let tokens = quote! {
+            #[swift_bridge:bridge]
+            mod foo {
+                extern "Rust" {
+                    type SomeType<A, B>;
+                }
+            }
+        };
+
+        let module = parse_ok(tokens);
+
+        assert_eq!(
+            module
+                .types
+                .get("SomeType")
+                .unwrap()
+                .unwrap_opaque()
+                .generics
+                .len(),
+            2
+        );
+    }
+
+    /// Verify that we declare a `#[swift_bridge(trait_object)]` type as a `Box<dyn Trait>`.
+    #[test]
+    fn parse_trait_object_type() {
+        let tokens = quote! {
+            #[swift_bridge:bridge]
+            mod foo {
+                extern "Rust" {
+                    #[swift_bridge(trait_object)]
+                    type SomeTrait;
+                }
+            }
+        };
+
+        let module = parse_ok(tokens);
+
+        assert!(module.types.get("SomeTrait").is_none());
+        assert!(
+            module
+                .types
+                .get("Box < dyn SomeTrait >")
+                .unwrap()
+                .unwrap_opaque()
+                .trait_object
+        );
+    }
+
+    /// Verify that we declare a `#[swift_bridge(Arc)]` type as an `Arc<Type>`.
+    #[test]
+    fn parse_arc_type() {
+        let tokens = quote! {
+            #[swift_bridge:bridge]
+            mod foo {
+                extern "Rust" {
+                    #[swift_bridge(Arc)]
+                    type SomeType;
+                }
+            }
+        };
+
+        let module = parse_ok(tokens);
+
+        assert!(module.types.get("SomeType").is_none());
+        assert!(
+            module
+                .types
+                .get("Arc < SomeType >")
+                .unwrap()
+                .unwrap_opaque()
+                .arc
+        );
+    }
+
+    /// Verify that we declare a `#[swift_bridge(Pin)]` type as a `Pin<Box<Type>>`.
+    #[test]
+    fn parse_pin_type() {
+        let tokens = quote! {
+            #[swift_bridge:bridge]
+            mod foo {
+                extern "Rust" {
+                    #[swift_bridge(Pin)]
+                    type SomeType;
+                }
+            }
+        };
+
+        let module = parse_ok(tokens);
+
+        assert!(module.types.get("SomeType").is_none());
+        assert!(
+            module
+                .types
+                .get("Pin < Box < SomeType > >")
+                .unwrap()
+                .unwrap_opaque()
+                .pin
+        );
+    }
+
+    /// Verify that we don't declare types or functions that have the `#[swift_bridge(skip)]`
+    /// attribute.
+    #[test]
+    fn parse_skip_attribute() {
+        let tokens = quote! {
+            #[swift_bridge:bridge]
+            mod foo {
+                extern "Rust" {
+                    #[swift_bridge(skip)]
+                    type SomeType;
+                    type AnotherType;
+
+                    #[swift_bridge(skip)]
+                    fn a(arg: SomeType);
+                    fn b();
+                }
+            }
+        };
+
+        let module = parse_ok(tokens);
+
+        assert!(module.types.get("SomeType").is_none());
+        assert!(module.types.get("AnotherType").is_some());
+
+        assert_eq!(module.functions.len(), 1);
+        assert_eq!(module.functions[0].func.sig.ident, "b");
+    }
+
+    /// Verify that we push an error if a declaration that isn't skipped uses a skipped type.
+    #[test]
+    fn error_if_skipped_type_is_used() {
+        let tokens = quote! {
+            #[swift_bridge:bridge]
+            mod foo {
+                extern "Rust" {
+                    #[swift_bridge(skip)]
+                    type SomeType;
+
+                    fn a(arg: Option<SomeType>);
+                }
+            }
+        };
+
+        let errors = parse_errors(tokens);
+        assert_eq!(errors.len(), 1);
+
+        match &errors[0] {
+            ParseError::SkippedTypeUsed { ty, skipped_ty } => {
+                assert_eq!(ty.to_token_stream().to_string(), "Option < SomeType >");
+                assert_eq!(skipped_ty, "SomeType");
+            }
+            _ => panic!(),
+        };
+    }
+
+    /// Verify that we declare a `#[swift_bridge(Mutex)]` type as a `Mutex<Type>`.
+    #[test]
+    fn parse_mutex_type() {
+        let tokens = quote! {
+            #[swift_bridge:bridge]
+            mod foo {
+                extern "Rust" {
+                    #[swift_bridge(Mutex)]
+                    type SomeType;
+                }
+            }
+        };
+
+        let module = parse_ok(tokens);
+
+        assert!(module.types.get("SomeType").is_none());
+        assert!(
+            module
+                .types
+                .get("Mutex < SomeType >")
+                .unwrap()
+                .unwrap_opaque()
+                .mutex
+        );
+    }
+
+    /// Verify that we parse the `#[swift_bridge(no_free)]` attribute.
+    #[test]
+    fn parse_no_free_type() {
+        let tokens = quote! {
+            #[swift_bridge:bridge]
+            mod foo {
+                extern "Rust" {
+                    #[swift_bridge(no_free)]
+                    type SomeType;
+                }
+            }
+        };
+
+        let module = parse_ok(tokens);
+
+        let ty = module.types.get("SomeType").unwrap().unwrap_opaque();
+        assert!(ty.no_free);
+        assert!(!ty.is_vectorizable());
+    }
+
+    /// Verify that we push an error if a `no_free` type gets passed to Rust by value, since Rust
+    /// would free it.
+    #[test]
+    fn error_if_no_free_type_taken_by_value() {
+        let tokens = quote! {
+            #[swift_bridge:bridge]
+            mod foo {
+                extern "Rust" {
+                    #[swift_bridge(no_free)]
+                    type SomeType;
+
+                    fn a(self);
+                    fn b(arg: SomeType);
+                    fn c(arg: Option<SomeType>);
+                    fn d(&self, arg: &SomeType);
+                }
+            }
+        };
+
+        let errors = parse_errors(tokens);
+        assert_eq!(errors.len(), 3);
+
+        for (error, expected_fn) in errors.iter().zip(["a", "b", "c"]) {
+            match error {
+                ParseError::NoFreeTypeTakenByValue { fn_ident, ty } => {
+                    assert_eq!(fn_ident, expected_fn);
+                    assert_eq!(ty, "SomeType");
+                }
+                _ => panic!(),
+            };
+        }
+    }
+
+    /// Verify that we declare the instantiations of generic extern "Rust" types that our
+    /// functions use.
+    #[test]
+    fn declares_generic_extern_rust_type_instantiations() {
+        let tokens = quote! {
+            #[swift_bridge:bridge]
+            mod foo {
+                extern "Rust" {
+                    type SomeType<A>;
+
+                    fn some_function(arg: &SomeType<u32>) -> Option<SomeType<bool>>;
+                }
+            }
+        };
+
+        let module = parse_ok(tokens);
+
+        let instantiation = module
+            .types
+            .get("SomeType < u32 >")
+            .unwrap()
+            .unwrap_opaque();
+        assert!(!instantiation.is_generic_declaration());
+        assert_eq!(instantiation.swift_name(), "SomeTypeUInt32");
+
+        let instantiation = module
+            .types
+            .get("SomeType < bool >")
+            .unwrap()
+            .unwrap_opaque();
+        assert_eq!(instantiation.swift_name(), "SomeTypeBool");
+    }
 }
@@ -0,0 +1,314 @@
+use proc_macro2::Ident;
+use std::collections::{HashMap, HashSet};
+use syn::ext::IdentExt;
+use syn::parse::{Parse, ParseStream};
+use syn::{Lit, LitStr, Token};
+
+/// Attributes on a function argument.
+///
+/// fn some_function(#[swift_bridge(default = 5)] arg: u8);
+/// fn move_to(#[swift_bridge(label = "to")] point: Point);
+/// fn parse(#[swift_bridge(as = "Data")] bytes: &[u8]);
+/// fn sum(#[swift_bridge(as = "Array")] numbers: Vec<i32>);
+#[derive(Default)]
+pub(super) struct ArgumentAttributes {
+    /// The default value of the argument in the generated Swift function.
+    pub default: Option<Lit>,
+    /// The argument's external label in Swift, where `_` means that the argument has no label.
+    pub label: Option<LitStr>,
+    /// The Swift type that the argument gets passed as, such as `"Data"` for a `&[u8]` or
+    /// `"Array"` for a `Vec<i32>`.
+    pub as_ty: Option<LitStr>,
+}
+
+impl ArgumentAttributes {
+    pub fn store_attrib(&mut self, attrib: ArgumentAttr) {
+        match attrib {
+            ArgumentAttr::Default(default) => self.default = Some(default),
+            ArgumentAttr::Label(label) => self.label = Some(label),
+            ArgumentAttr::As(ty) => self.as_ty = Some(ty),
+        }
+    }
+}
+
+/// The argument attributes of a function, keyed by argument name.
+#[derive(Default)]
+pub(super) struct ParsedArgumentAttributes {
+    /// The default values of the arguments that have a `#[swift_bridge(default = ...)]`.
+    pub defaults: HashMap<String, Lit>,
+    /// The labels of the arguments that have a `#[swift_bridge(label = "...")]`.
+    pub labels: HashMap<String, String>,
+    /// The arguments that Swift passes as `Data`.
+    pub as_data: HashSet<String>,
+    /// The Vec arguments that Swift passes as an Array.
+    pub as_array: HashSet<String>,
+}
+
+pub(super) enum ArgumentAttr {
+    Default(Lit),
+    Label(LitStr),
+    As(LitStr),
+}
+
+impl Parse for ArgumentAttr {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        // `parse_any` so that we can parse the `as` keyword.
+        let key = input.call(Ident::parse_any)?;
+
+        let attrib = match key.to_string().as_str() {
+            "default" => {
+                input.parse::<Token![=]>()?;
+                ArgumentAttr::Default(input.parse()?)
+            }
+            "label" => {
+                input.parse::<Token![=]>()?;
+                ArgumentAttr::Label(input.parse()?)
+            }
+            "as" => {
+                input.parse::<Token![=]>()?;
+                ArgumentAttr::As(input.parse()?)
+            }
+            _ => {
+                return Err(syn::Error::new_spanned(
+                    key,
+                    "Unrecognized function argument attribute.",
+                ))
+            }
+        };
+
+        Ok(attrib)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::errors::ParseError;
+    use crate::test_utils::{parse_errors, parse_ok};
+    use quote::{quote, ToTokens};
+
+    /// Verify that we parse the default value of a function argument.
+    #[test]
+    fn parse_default_argument_value() {
+        let tokens = quote! {
+            mod foo {
+                extern "Rust" {
+                    fn some_function(
+                        #[swift_bridge(default = 5)] arg1: u8,
+                        arg2: bool,
+                        #[swift_bridge(default = "hello")] arg3: &str,
+                    );
+                }
+            }
+        };
+
+        let module = parse_ok(tokens);
+        let func = &module.functions[0];
+
+        assert_eq!(func.arg_defaults.len(), 2);
+        assert_eq!(func.arg_defaults["arg1"].to_token_stream().to_string(), "5");
+        assert_eq!(
+            func.arg_defaults["arg3"].to_token_stream().to_string(),
+            r#""hello""#
+        );
+    }
+
+    /// Verify that we parse the Swift label of a function argument.
+    #[test]
+    fn parse_argument_label() {
+        let tokens = quote! {
+            mod foo {
+                extern "Rust" {
+                    fn some_function(
+                        #[swift_bridge(label = "to")] arg1: u8,
+                        arg2: bool,
+                        #[swift_bridge(label = "_", default = 5)] arg3: u8,
+                    );
+                }
+            }
+        };
+
+        let module = parse_ok(tokens);
+        let func = &module.functions[0];
+
+        assert_eq!(func.arg_labels.len(), 2);
+        assert_eq!(func.arg_labels["arg1"], "to");
+        assert_eq!(func.arg_labels["arg3"], "_");
+        assert_eq!(func.arg_defaults.len(), 1);
+    }
+
+    /// Verify that we push an error if an opaque type argument has a default value.
+    #[test]
+    fn error_if_default_value_for_opaque_type() {
+        let tokens = quote! {
+            mod foo {
+                extern "Rust" {
+                    type SomeType;
+
+                    fn some_function(#[swift_bridge(default = 5)] arg: SomeType);
+                }
+            }
+        };
+
+        let errors = parse_errors(tokens);
+        assert_eq!(errors.len(), 1);
+
+        match &errors[0] {
+            ParseError::ArgDefaultUnsupportedType { ty } => {
+                assert_eq!(ty.to_token_stream().to_string(), "SomeType")
+            }
+            _ => panic!(),
+        }
+    }
+
+    /// Verify that we push an error if the default value's literal does not match the argument's
+    /// type.
+    #[test]
+    fn error_if_default_value_has_mismatched_type() {
+        let tokens = quote! {
+            mod foo {
+                extern "Rust" {
+                    fn some_function(#[swift_bridge(default = "hello")] arg: u8);
+                }
+            }
+        };
+
+        let errors = parse_errors(tokens);
+        assert_eq!(errors.len(), 1);
+
+        match &errors[0] {
+            ParseError::ArgDefaultMismatchedType { default, ty } => {
+                assert_eq!(default.to_token_stream().to_string(), r#""hello""#);
+                assert_eq!(ty.to_token_stream().to_string(), "u8");
+            }
+            _ => panic!(),
+        }
+    }
+
+    /// Verify that we push an error if an extern "Swift" function argument has a default value.
+    #[test]
+    fn error_if_default_value_in_extern_swift() {
+        let tokens = quote! {
+            mod foo {
+                extern "Swift" {
+                    fn some_function(#[swift_bridge(default = 5)] arg: u8);
+                }
+            }
+        };
+
+        let errors = parse_errors(tokens);
+        assert_eq!(errors.len(), 1);
+
+        match &errors[0] {
+            ParseError::ArgDefaultOnExternSwiftFunction { .. } => {}
+            _ => panic!(),
+        }
+    }
+
+    /// Verify that we parse the `as = "Data"` attribute of a `&[u8]` argument.
+    #[test]
+    fn parse_argument_as_data() {
+        let tokens = quote! {
+            mod foo {
+                extern "Rust" {
+                    fn some_function(#[swift_bridge(as = "Data")] arg1: &[u8], arg2: &[u8]);
+                }
+            }
+        };
+
+        let module = parse_ok(tokens);
+        let func = &module.functions[0];
+
+        assert_eq!(func.args_as_data.len(), 1);
+        assert!(func.args_as_data.contains("arg1"));
+    }
+
+    /// Verify that we parse the `as = "Array"` attribute of a `Vec<T>` argument.
+    #[test]
+    fn parse_argument_as_array() {
+        let tokens = quote! {
+            mod foo {
+                extern "Rust" {
+                    fn some_function(#[swift_bridge(as = "Array")] arg1: Vec<i32>, arg2: Vec<i32>);
+                }
+            }
+        };
+
+        let module = parse_ok(tokens);
+        let func = &module.functions[0];
+
+        assert_eq!(func.args_as_array.len(), 1);
+        assert!(func.args_as_array.contains("arg1"));
+        assert!(func.args_as_data.is_empty());
+    }
+
+    /// Verify that we push an error if an argument is passed as a type other than `Data` or
+    /// `Array`.
+    #[test]
+    fn error_if_argument_as_unsupported_type() {
+        let tokens = quote! {
+            mod foo {
+                extern "Rust" {
+                    fn some_function(#[swift_bridge(as = "NSArray")] arg: Vec<u8>);
+                }
+            }
+        };
+
+        let errors = parse_errors(tokens);
+        assert_eq!(errors.len(), 1);
+
+        match &errors[0] {
+            ParseError::ArgAsUnsupportedType { ty } => assert_eq!(ty.value(), "NSArray"),
+            _ => panic!(),
+        }
+    }
+
+    /// Verify that we push an error if an argument that isn't a Vec of primitives is passed as an
+    /// `Array`.
+    #[test]
+    fn error_if_argument_as_array_is_not_primitive_vec() {
+        let tokens = quote! {
+            mod foo {
+                extern "Rust" {
+                    fn a(#[swift_bridge(as = "Array")] arg: Vec<String>);
+                    fn b(#[swift_bridge(as = "Array")] arg: &[u8]);
+                }
+
+                extern "Swift" {
+                    fn c(#[swift_bridge(as = "Array")] arg: Vec<u8>);
+                }
+            }
+        };
+
+        let errors = parse_errors(tokens);
+        assert_eq!(errors.len(), 3);
+
+        for error in errors.iter() {
+            match error {
+                ParseError::ArgAsArrayMustBePrimitiveVec { .. } => {}
+                _ => panic!(),
+            }
+        }
+    }
+
+    /// Verify that we push an error if an argument that isn't a `&[u8]` is passed as `Data`.
+    #[test]
+    fn error_if_argument_as_data_is_not_u8_slice() {
+        let tokens = quote! {
+            mod foo {
+                extern "Rust" {
+                    fn some_function(#[swift_bridge(as = "Data")] arg: &mut [u8]);
+                }
+            }
+        };
+
+        let errors = parse_errors(tokens);
+        assert_eq!(errors.len(), 1);
+
+        match &errors[0] {
+            ParseError::ArgAsDataMustBeU8Slice { ty } => {
+                assert_eq!(ty.to_token_stream().to_string(), "& mut [u8]")
+            }
+            _ => panic!(),
+        }
+    }
+}
@@ -1,4 +1,7 @@
+use crate::parse::{SwiftAvailability, SwiftVisibility};
+use crate::parsed_extern_fn::Getter;
 use proc_macro2::Ident;
+use syn::ext::IdentExt;
 use syn::parse::{Parse, ParseStream};
 use syn::{LitStr, Path, Token};
 
@@ -7,11 +10,22 @@ pub(super) struct FunctionAttributes {
     pub associated_to: Option<Ident>,
     pub is_swift_initializer: bool,
     pub is_swift_identifiable: bool,
+    pub is_swift_iterator: bool,
+    pub is_swift_async_iterator: bool,
+    pub is_swift_constant: bool,
+    pub is_swift_subscript: bool,
+    pub getter: Option<Getter>,
+    pub operator: Option<LitStr>,
     pub rust_name: Option<LitStr>,
     pub swift_name: Option<LitStr>,
+    pub extern_swift_name: Option<LitStr>,
     pub into_return_type: bool,
     pub return_with: Option<Path>,
+    pub return_as: Option<LitStr>,
     pub args_into: Option<Vec<Ident>>,
+    pub swift_visibility: SwiftVisibility,
+    pub available: Option<SwiftAvailability>,
+    pub skip: bool,
 }
 
 impl FunctionAttributes {
@@ -27,16 +41,49 @@ impl FunctionAttributes {
             FunctionAttr::SwiftName(name) => {
                 self.swift_name = Some(name);
             }
+            FunctionAttr::ExternSwiftName(name) => {
+                self.extern_swift_name = Some(name);
+            }
             FunctionAttr::IntoReturnType => {
                 self.into_return_type = true;
             }
             FunctionAttr::ReturnWith(path) => {
                 self.return_with = Some(path);
             }
+            FunctionAttr::ReturnAs(ty) => {
+                self.return_as = Some(ty);
+            }
             FunctionAttr::ArgsInto(args) => self.args_into = Some(args),
             FunctionAttr::Identifiable => {
                 self.is_swift_identifiable = true;
             }
+            FunctionAttr::Iterator => {
+                self.is_swift_iterator = true;
+            }
+            FunctionAttr::AsyncIterator => {
+                self.is_swift_async_iterator = true;
+            }
+            FunctionAttr::Constant => {
+                self.is_swift_constant = true;
+            }
+            FunctionAttr::Subscript => {
+                self.is_swift_subscript = true;
+            }
+            FunctionAttr::Get(getter) => {
+                self.getter = Some(getter);
+            }
+            FunctionAttr::Operator(operator) => {
+                self.operator = Some(operator);
+            }
+            FunctionAttr::SwiftVisibility(visibility) => {
+                self.swift_visibility = visibility;
+            }
+            FunctionAttr::Available(available) => {
+                self.available = Some(available);
+            }
+            FunctionAttr::Skip => {
+                self.skip = true;
+            }
         }
     }
 }
@@ -44,12 +91,23 @@ impl FunctionAttributes {
 pub(super) enum FunctionAttr {
     AssociatedTo(Ident),
     SwiftName(LitStr),
+    ExternSwiftName(LitStr),
     RustName(LitStr),
     Init,
     Identifiable,
+    Iterator,
+    AsyncIterator,
+    Constant,
+    Subscript,
+    Get(Getter),
+    Operator(LitStr),
     IntoReturnType,
     ReturnWith(Path),
+    ReturnAs(LitStr),
     ArgsInto(Vec<Ident>),
+    SwiftVisibility(SwiftVisibility),
+    Available(SwiftAvailability),
+    Skip,
 }
 
 impl Parse for FunctionAttributes {
@@ -69,7 +127,8 @@ impl Parse for FunctionAttributes {
 
 impl Parse for FunctionAttr {
     fn parse(input: ParseStream) -> syn::Result<Self> {
-        let key: Ident = input.parse()?;
+        // `parse_any` so that we can parse the `as` keyword.
+        let key = input.call(Ident::parse_any)?;
 
         let attrib = match key.to_string().as_str() {
             "associated_to" => {
@@ -84,13 +143,52 @@ impl Parse for FunctionAttr {
 
                 FunctionAttr::SwiftName(value)
             }
+            "extern_swift_name" => {
+                input.parse::<Token![=]>()?;
+                let value: LitStr = input.parse()?;
+
+                FunctionAttr::ExternSwiftName(value)
+            }
             "init" => FunctionAttr::Init,
+            "skip" => FunctionAttr::Skip,
             "Identifiable" => FunctionAttr::Identifiable,
-            "into_return_type" => FunctionAttr::IntoReturnType,
+            "Iterator" => FunctionAttr::Iterator,
+            "AsyncIterator" => FunctionAttr::AsyncIterator,
+            "constant" => FunctionAttr::Constant,
+            "subscript" => FunctionAttr::Subscript,
+            "get" => {
+                if input.peek(syn::token::Paren) {
+                    let content;
+                    syn::parenthesized!(content in input);
+
+                    let kind: Ident = content.parse()?;
+                    match kind.to_string().as_str() {
+                        "clone" => FunctionAttr::Get(Getter::Clone),
+                        "computed" => FunctionAttr::Get(Getter::Computed),
+                        _ => {
+                            return Err(syn::Error::new_spanned(
+                                kind,
+                                "Expected `get`, `get(clone)` or `get(computed)`.",
+                            ));
+                        }
+                    }
+                } else {
+                    FunctionAttr::Get(Getter::Borrow)
+                }
+            }
+            "into_return_type" | "return_into" => FunctionAttr::IntoReturnType,
+            "operator" => {
+                input.parse::<Token![=]>()?;
+                FunctionAttr::Operator(input.parse()?)
+            }
             "return_with" => {
                 input.parse::<Token![=]>()?;
                 FunctionAttr::ReturnWith(input.parse()?)
             }
+            "return_as" | "as" => {
+                input.parse::<Token![=]>()?;
+                FunctionAttr::ReturnAs(input.parse()?)
+            }
             "rust_name" => {
                 input.parse::<Token![=]>()?;
                 let value: LitStr = input.parse()?;
@@ -106,6 +204,14 @@ impl Parse for FunctionAttr {
                 let args = syn::punctuated::Punctuated::<_, Token![,]>::parse_terminated(&content)?;
                 FunctionAttr::ArgsInto(args.into_iter().collect())
             }
+            "swift_visibility" => {
+                input.parse::<Token![=]>()?;
+                FunctionAttr::SwiftVisibility(input.parse()?)
+            }
+            "available" => {
+                input.parse::<Token![=]>()?;
+                FunctionAttr::Available(input.parse()?)
+            }
 
             _ => panic!(
                 "TODO: Return spanned error for unrecognized attribute... Like we do for StructAttr"
@@ -118,7 +224,13 @@ impl Parse for FunctionAttr {
 
 #[cfg(test)]
 mod tests {
-    use crate::errors::{FunctionAttributeParseError, IdentifiableParseError, ParseError};
+    use crate::errors::{
+        ConstantParseError, ExternSwiftNameParseError, FunctionAttributeParseError,
+        GetterParseError, IdentifiableParseError, InitParseError, IteratorParseError,
+        OperatorParseError, ParseError, ReturnAsParseError, SubscriptParseError,
+    };
+    use crate::parse::{SwiftBridgeModuleAndErrors, SwiftVisibility};
+    use crate::parsed_extern_fn::Getter;
     use crate::test_utils::{parse_errors, parse_ok};
     use quote::{quote, ToTokens};
 
@@ -141,6 +253,26 @@ mod tests {
         assert!(module.functions[0].into_return_type);
     }
 
+    /// Verify that we can parse the return_into attribute, which is another name for the
+    /// into_return_type attribute that mirrors the args_into attribute.
+    #[test]
+    fn parse_extern_rust_return_into_attribute() {
+        let tokens = quote! {
+            mod foo {
+                extern "Rust" {
+                    type Foo;
+
+                    #[swift_bridge(return_into)]
+                    fn some_function () -> Foo;
+                }
+            }
+        };
+
+        let module = parse_ok(tokens);
+
+        assert!(module.functions[0].into_return_type);
+    }
+
     /// Verify that we can parse the return_with attribute from extern "Rust" blocks.
     #[test]
     fn parse_extern_rust_return_with_attribute() {
@@ -251,6 +383,31 @@ mod tests {
         assert!(func.is_swift_initializer);
     }
 
+    /// Verify that an init function that returns `Option<Self>` is associated to its type and is
+    /// failable.
+    #[test]
+    fn failable_initializer() {
+        let tokens = quote! {
+            mod foo {
+                extern "Rust" {
+                    type Foo;
+
+                    #[swift_bridge(init)]
+                    fn bar () -> Option<Foo>;
+                }
+            }
+        };
+
+        let module = parse_ok(tokens);
+
+        let func = &module.functions[0];
+        assert!(func.is_failable_initializer());
+        assert_eq!(
+            func.associated_type.as_ref().unwrap().unwrap_opaque().ty,
+            "Foo"
+        );
+    }
+
     /// Verify that we push an error if the initialize type is not defined.
     #[test]
     fn error_if_initialized_type_not_defined() {
@@ -274,6 +431,45 @@ mod tests {
         }
     }
 
+    /// Verify that we push an error if two initializers have the same argument labels and
+    /// types, and that giving their arguments different labels avoids the error.
+    #[test]
+    fn error_if_initializers_have_same_swift_signature() {
+        let tokens = quote! {
+            mod foo {
+                extern "Rust" {
+                    type Foo;
+
+                    #[swift_bridge(init)]
+                    fn from_name(name: String) -> Foo;
+                    #[swift_bridge(init)]
+                    fn from_title(title: String) -> Foo;
+
+                    #[swift_bridge(init)]
+                    fn from_id(#[swift_bridge(label = "fromId")] id: u64) -> Foo;
+                    #[swift_bridge(init)]
+                    fn from_index(#[swift_bridge(label = "fromIndex")] index: u64) -> Foo;
+                }
+            }
+        };
+
+        let errors = parse_errors(tokens);
+        assert_eq!(errors.len(), 1);
+
+        match &errors[0] {
+            ParseError::FunctionAttribute(FunctionAttributeParseError::Init(
+                InitParseError::DuplicateSignature {
+                    fn_ident,
+                    other_fn_ident,
+                },
+            )) => {
+                assert_eq!(fn_ident, "from_title");
+                assert_eq!(other_fn_ident, "from_name");
+            }
+            _ => panic!(),
+        }
+    }
+
     /// Verify that we can parse a from attribute for a struct.
     #[test]
     fn parses_extern_rust_args_into_attribute() {
@@ -402,6 +598,657 @@ mod tests {
         assert!(func.is_swift_identifiable);
     }
 
+    /// Verify that we can parse the `Iterator` attribute
+    #[test]
+    fn parses_iterator_attribute() {
+        let tokens = quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                extern "Rust" {
+                    type SomeIterator;
+
+                    #[swift_bridge(Iterator)]
+                    fn next(&mut self) -> Option<u32>;
+                }
+            }
+        };
+
+        let module = parse_ok(tokens);
+
+        let func = &module.functions[0];
+
+        assert!(func.is_swift_iterator);
+    }
+
+    /// Verify that we push a parse error if we put an Iterator attribute on a function that
+    /// does not take `&mut self` or does not return an `Option`.
+    #[test]
+    fn error_if_iterator_attribute_on_invalid_function() {
+        let tokens = quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                extern "Rust" {
+                    type SomeIterator;
+
+                    #[swift_bridge(Iterator)]
+                    fn a(&self) -> Option<u32>;
+                    #[swift_bridge(Iterator)]
+                    fn b(&mut self) -> u32;
+                }
+            }
+        };
+
+        let errors = parse_errors(tokens);
+        assert_eq!(errors.len(), 2);
+
+        match &errors[0] {
+            ParseError::FunctionAttribute(FunctionAttributeParseError::Iterator(
+                IteratorParseError::MustBeRefMutSelf { fn_ident },
+            )) => {
+                assert_eq!(fn_ident, "a");
+            }
+            _ => panic!(),
+        };
+        match &errors[1] {
+            ParseError::FunctionAttribute(FunctionAttributeParseError::Iterator(
+                IteratorParseError::MustReturnOption { fn_ident },
+            )) => {
+                assert_eq!(fn_ident, "b");
+            }
+            _ => panic!(),
+        };
+    }
+
+    /// Verify that we can parse the `AsyncIterator` attribute.
+    #[test]
+    fn parses_async_iterator_attribute() {
+        let tokens = quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                extern "Rust" {
+                    type SomeStream;
+
+                    #[swift_bridge(AsyncIterator)]
+                    async fn next(&mut self) -> Option<u32>;
+                }
+            }
+        };
+
+        let module = parse_ok(tokens);
+
+        let func = &module.functions[0];
+
+        assert!(func.is_swift_async_iterator);
+        assert!(!func.is_swift_iterator);
+    }
+
+    /// Verify that we push a parse error if we put an AsyncIterator attribute on a function that
+    /// is not async.
+    #[test]
+    fn error_if_async_iterator_attribute_on_non_async_function() {
+        let tokens = quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                extern "Rust" {
+                    type SomeStream;
+
+                    #[swift_bridge(AsyncIterator)]
+                    fn next(&mut self) -> Option<u32>;
+                }
+            }
+        };
+
+        let errors = parse_errors(tokens);
+        assert_eq!(errors.len(), 1);
+
+        match &errors[0] {
+            ParseError::FunctionAttribute(FunctionAttributeParseError::AsyncIterator(
+                IteratorParseError::NotAsync { fn_ident },
+            )) => {
+                assert_eq!(fn_ident, "next");
+            }
+            _ => panic!(),
+        };
+    }
+
+    /// Verify that we can parse the `constant` attribute.
+    #[test]
+    fn parses_constant_attribute() {
+        let tokens = quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                extern "Rust" {
+                    type SomeType;
+
+                    #[swift_bridge(associated_to = SomeType, constant)]
+                    fn max() -> u32;
+                }
+            }
+        };
+
+        let module = parse_ok(tokens);
+
+        let func = &module.functions[0];
+
+        assert!(func.is_swift_constant);
+    }
+
+    /// Verify that we push a parse error if we put a constant attribute on a function that is not
+    /// associated to a type, takes arguments or returns an unsupported type.
+    #[test]
+    fn error_if_constant_attribute_on_invalid_function() {
+        let tokens = quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                extern "Rust" {
+                    type SomeType;
+
+                    #[swift_bridge(constant)]
+                    fn a() -> u32;
+                    #[swift_bridge(associated_to = SomeType, constant)]
+                    fn b(arg: u8) -> u32;
+                    #[swift_bridge(associated_to = SomeType, constant)]
+                    fn c() -> Vec<u32>;
+                }
+            }
+        };
+
+        let errors = parse_errors(tokens);
+        assert_eq!(errors.len(), 3);
+
+        match &errors[0] {
+            ParseError::FunctionAttribute(FunctionAttributeParseError::Constant(
+                ConstantParseError::MustBeAssociatedToType { fn_ident },
+            )) => {
+                assert_eq!(fn_ident, "a");
+            }
+            _ => panic!(),
+        };
+        match &errors[1] {
+            ParseError::FunctionAttribute(FunctionAttributeParseError::Constant(
+                ConstantParseError::MustNotTakeArguments { fn_ident },
+            )) => {
+                assert_eq!(fn_ident, "b");
+            }
+            _ => panic!(),
+        };
+        match &errors[2] {
+            ParseError::FunctionAttribute(FunctionAttributeParseError::Constant(
+                ConstantParseError::UnsupportedType { fn_ident },
+            )) => {
+                assert_eq!(fn_ident, "c");
+            }
+            _ => panic!(),
+        };
+    }
+
+    /// Verify that we can parse the `get`, `get(clone)` and `get(computed)` attributes.
+    #[test]
+    fn parses_get_attributes() {
+        let tokens = quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                extern "Rust" {
+                    type SomeType;
+
+                    #[swift_bridge(get)]
+                    fn name(&self) -> &str;
+                    #[swift_bridge(get(clone))]
+                    fn nickname(&self) -> String;
+                    fn age(&self) -> u8;
+                    #[swift_bridge(get(computed))]
+                    fn is_adult(&self) -> bool;
+                }
+            }
+        };
+
+        let module = parse_ok(tokens);
+
+        assert_eq!(module.functions[0].getter, Some(Getter::Borrow));
+        assert_eq!(module.functions[1].getter, Some(Getter::Clone));
+        assert_eq!(module.functions[2].getter, None);
+        assert_eq!(module.functions[3].getter, Some(Getter::Computed));
+    }
+
+    /// Verify that we push a parse error if we put a getter attribute on a function that does not
+    /// take `&self`, or a `get(clone)` attribute on a function that returns a reference.
+    #[test]
+    fn error_if_get_attribute_on_invalid_function() {
+        let tokens = quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                extern "Rust" {
+                    type SomeType;
+
+                    #[swift_bridge(get)]
+                    fn a(&mut self) -> u8;
+                    #[swift_bridge(get(clone))]
+                    fn b(&self) -> &str;
+                }
+            }
+        };
+
+        let errors = parse_errors(tokens);
+        assert_eq!(errors.len(), 2);
+
+        match &errors[0] {
+            ParseError::FunctionAttribute(FunctionAttributeParseError::Getter(
+                GetterParseError::MustBeRefSelf { fn_ident },
+            )) => {
+                assert_eq!(fn_ident, "a");
+            }
+            _ => panic!(),
+        };
+        match &errors[1] {
+            ParseError::FunctionAttribute(FunctionAttributeParseError::Getter(
+                GetterParseError::CloneReturnsReference { fn_ident },
+            )) => {
+                assert_eq!(fn_ident, "b");
+            }
+            _ => panic!(),
+        };
+    }
+
+    /// Verify that we push a parse error if we put a `get(computed)` attribute on a method that is
+    /// async, returns nothing, returns a `Result` or is on a `Mutex` type.
+    #[test]
+    fn error_if_computed_getter_can_not_return_value() {
+        let tokens = quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                extern "Rust" {
+                    type SomeError;
+                }
+
+                extern "Rust" {
+                    type SomeType;
+
+                    #[swift_bridge(get(computed))]
+                    async fn a(&self) -> u8;
+                    #[swift_bridge(get(computed))]
+                    fn b(&self);
+                    #[swift_bridge(get(computed))]
+                    fn c(&self) -> Result<u8, SomeError>;
+                    #[swift_bridge(get(computed))]
+                    fn d(&self) -> u8;
+                }
+
+                extern "Rust" {
+                    #[swift_bridge(Mutex)]
+                    type SomeMutexType;
+
+                    #[swift_bridge(get(computed))]
+                    fn e(&self) -> u8;
+                }
+            }
+        };
+
+        let errors = parse_errors(tokens);
+        assert_eq!(errors.len(), 4);
+
+        let fn_idents: Vec<String> = errors
+            .iter()
+            .map(|error| match error {
+                ParseError::FunctionAttribute(FunctionAttributeParseError::Getter(
+                    GetterParseError::ComputedMustReturnInfallibleValue { fn_ident },
+                )) => fn_ident.to_string(),
+                _ => panic!(),
+            })
+            .collect();
+        assert_eq!(fn_idents, vec!["a", "b", "c", "e"]);
+    }
+
+    /// Verify that we can parse the `operator` attribute.
+    #[test]
+    fn parses_operator_attribute() {
+        let tokens = quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                extern "Rust" {
+                    type Vector3;
+
+                    #[swift_bridge(operator = "-")]
+                    fn sub(&self, rhs: &Vector3) -> Vector3;
+                }
+            }
+        };
+
+        let module = parse_ok(tokens);
+
+        assert_eq!(module.functions[0].swift_operator.as_deref(), Some("-"));
+    }
+
+    /// Verify that we push a parse error if we use an unsupported operator, or put the operator
+    /// attribute on a function that does not take `&self` and one other argument.
+    #[test]
+    fn error_if_invalid_operator_attribute() {
+        let tokens = quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                extern "Rust" {
+                    type Vector3;
+
+                    #[swift_bridge(operator = "%")]
+                    fn rem(&self, rhs: &Vector3) -> Vector3;
+                    #[swift_bridge(operator = "-")]
+                    fn neg(&self) -> Vector3;
+                }
+            }
+        };
+
+        let errors = parse_errors(tokens);
+        assert_eq!(errors.len(), 2);
+
+        match &errors[0] {
+            ParseError::FunctionAttribute(FunctionAttributeParseError::Operator(
+                OperatorParseError::UnsupportedOperator { operator },
+            )) => {
+                assert_eq!(operator.value(), "%");
+            }
+            _ => panic!(),
+        };
+        match &errors[1] {
+            ParseError::FunctionAttribute(FunctionAttributeParseError::Operator(
+                OperatorParseError::MustBeRefSelfAndOneArg { fn_ident },
+            )) => {
+                assert_eq!(fn_ident, "neg");
+            }
+            _ => panic!(),
+        };
+    }
+
+    /// Verify that we can parse the `subscript` attribute.
+    #[test]
+    fn parses_subscript_attribute() {
+        let tokens = quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                extern "Rust" {
+                    type SomeList;
+
+                    #[swift_bridge(subscript)]
+                    fn get(&self, index: usize) -> u32;
+                }
+            }
+        };
+
+        let module = parse_ok(tokens);
+
+        assert!(module.functions[0].is_swift_subscript);
+    }
+
+    /// Verify that we push a parse error if we put the subscript attribute on a function that
+    /// does not take `&self` and at least one other argument, or that does not return a value.
+    #[test]
+    fn error_if_invalid_subscript_attribute() {
+        let tokens = quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                extern "Rust" {
+                    type SomeList;
+
+                    #[swift_bridge(subscript)]
+                    fn a(&self) -> u32;
+                    #[swift_bridge(subscript)]
+                    fn b(&mut self, index: usize) -> u32;
+                    #[swift_bridge(subscript)]
+                    fn c(&self, index: usize);
+                }
+            }
+        };
+
+        let errors = parse_errors(tokens);
+        assert_eq!(errors.len(), 3);
+
+        for (error, expected_fn) in errors.iter().take(2).zip(["a", "b"]) {
+            match error {
+                ParseError::FunctionAttribute(FunctionAttributeParseError::Subscript(
+                    SubscriptParseError::MustBeRefSelfWithArgs { fn_ident },
+                )) => {
+                    assert_eq!(fn_ident, expected_fn);
+                }
+                _ => panic!(),
+            };
+        }
+        match &errors[2] {
+            ParseError::FunctionAttribute(FunctionAttributeParseError::Subscript(
+                SubscriptParseError::MissingReturnType { fn_ident },
+            )) => {
+                assert_eq!(fn_ident, "c");
+            }
+            _ => panic!(),
+        };
+    }
+
+    /// Verify that we can parse the `extern_swift_name` attribute.
+    #[test]
+    fn parses_extern_swift_name_attribute() {
+        let tokens = quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                extern "Swift" {
+                    #[swift_bridge(extern_swift_name = "my_swift_function")]
+                    fn some_function();
+                }
+            }
+        };
+
+        let module = parse_ok(tokens);
+
+        assert_eq!(
+            module.functions[0]
+                .extern_swift_name
+                .as_ref()
+                .unwrap()
+                .value(),
+            "my_swift_function"
+        );
+    }
+
+    /// Verify that we push a parse error if the `extern_swift_name` attribute is not a valid
+    /// symbol, or if it is used on an extern "Rust" function.
+    #[test]
+    fn error_if_invalid_extern_swift_name_attribute() {
+        let tokens = quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                extern "Swift" {
+                    #[swift_bridge(extern_swift_name = "1st_function")]
+                    fn a();
+                    #[swift_bridge(extern_swift_name = "my-function")]
+                    fn b();
+                }
+
+                extern "Rust" {
+                    #[swift_bridge(extern_swift_name = "my_rust_function")]
+                    fn c();
+                }
+            }
+        };
+
+        let errors = parse_errors(tokens);
+        assert_eq!(errors.len(), 3);
+
+        for (error, expected_name) in errors.iter().take(2).zip(["1st_function", "my-function"]) {
+            match error {
+                ParseError::FunctionAttribute(FunctionAttributeParseError::ExternSwiftName(
+                    ExternSwiftNameParseError::InvalidSymbol { name },
+                )) => {
+                    assert_eq!(name.value(), expected_name);
+                }
+                _ => panic!(),
+            };
+        }
+        match &errors[2] {
+            ParseError::FunctionAttribute(FunctionAttributeParseError::ExternSwiftName(
+                ExternSwiftNameParseError::NotExternSwift { fn_ident },
+            )) => {
+                assert_eq!(fn_ident, "c");
+            }
+            _ => panic!(),
+        };
+    }
+
+    /// Verify that we can parse the `return_as` attribute, as well as its `as` shorthand.
+    #[test]
+    fn parses_return_as_attribute() {
+        let tokens = quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                extern "Rust" {
+                    #[swift_bridge(return_as = "Data")]
+                    fn a() -> Vec<u8>;
+                    #[swift_bridge(as = "Data")]
+                    fn b() -> Vec<u8>;
+                    fn c() -> Vec<u8>;
+                    #[swift_bridge(return_as = "Array")]
+                    fn d() -> Vec<f32>;
+                }
+            }
+        };
+
+        let module = parse_ok(tokens);
+
+        assert!(module.functions[0].return_as_data);
+        assert!(module.functions[1].return_as_data);
+        assert!(!module.functions[2].return_as_data);
+        assert!(!module.functions[2].return_as_array);
+        assert!(module.functions[3].return_as_array);
+        assert!(!module.functions[3].return_as_data);
+    }
+
+    /// Verify that we push a parse error if the `return_as` attribute names an unsupported type,
+    /// or if it is used on a function that does not return a `Vec<u8>` or a Vec of numbers.
+    #[test]
+    fn error_if_invalid_return_as_attribute() {
+        let tokens = quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                extern "Rust" {
+                    #[swift_bridge(return_as = "NSData")]
+                    fn a() -> Vec<u8>;
+                    #[swift_bridge(return_as = "Data")]
+                    fn b() -> Vec<u16>;
+                    #[swift_bridge(return_as = "Array")]
+                    fn c() -> Vec<String>;
+                    #[swift_bridge(return_as = "Array")]
+                    async fn d() -> Vec<f64>;
+                }
+            }
+        };
+
+        let errors = parse_errors(tokens);
+        assert_eq!(errors.len(), 4);
+
+        match &errors[0] {
+            ParseError::FunctionAttribute(FunctionAttributeParseError::ReturnAs(
+                ReturnAsParseError::UnsupportedType { ty },
+            )) => {
+                assert_eq!(ty.value(), "NSData");
+            }
+            _ => panic!(),
+        };
+        match &errors[1] {
+            ParseError::FunctionAttribute(FunctionAttributeParseError::ReturnAs(
+                ReturnAsParseError::MustReturnVecU8 { fn_ident },
+            )) => {
+                assert_eq!(fn_ident, "b");
+            }
+            _ => panic!(),
+        };
+        for (error, expected_fn_ident) in errors[2..].iter().zip(["c", "d"]) {
+            match error {
+                ParseError::FunctionAttribute(FunctionAttributeParseError::ReturnAs(
+                    ReturnAsParseError::MustReturnNumericVec { fn_ident },
+                )) => {
+                    assert_eq!(fn_ident, expected_fn_ident);
+                }
+                _ => panic!(),
+            };
+        }
+    }
+
+    /// Verify that we can parse the `swift_visibility` attribute.
+    #[test]
+    fn parses_swift_visibility_attribute() {
+        let tokens = quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                extern "Rust" {
+                    #[swift_bridge(swift_visibility = "fileprivate")]
+                    fn a();
+                    fn b();
+                }
+            }
+        };
+
+        let module = parse_ok(tokens);
+
+        assert_eq!(
+            module.functions[0].swift_visibility,
+            SwiftVisibility::FilePrivate
+        );
+        assert_eq!(
+            module.functions[1].swift_visibility,
+            SwiftVisibility::Public
+        );
+    }
+
+    /// Verify that we return an error for an unknown Swift visibility.
+    #[test]
+    fn error_if_invalid_swift_visibility() {
+        let tokens = quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                extern "Rust" {
+                    #[swift_bridge(swift_visibility = "open")]
+                    fn a();
+                }
+            }
+        };
+
+        assert!(syn::parse2::<SwiftBridgeModuleAndErrors>(tokens).is_err());
+    }
+
+    /// Verify that we can parse the `available` attribute, including multiple platforms.
+    #[test]
+    fn parses_available_attribute() {
+        let tokens = quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                extern "Rust" {
+                    #[swift_bridge(available = "iOS 15.0,  macOS 12.0")]
+                    fn a();
+                    fn b();
+                }
+            }
+        };
+
+        let module = parse_ok(tokens);
+
+        assert_eq!(
+            module.functions[0].available.as_ref().unwrap().platforms,
+            vec!["iOS 15.0", "macOS 12.0"]
+        );
+        assert!(module.functions[1].available.is_none());
+    }
+
+    /// Verify that we return an error if a platform in the `available` attribute has no version.
+    #[test]
+    fn error_if_invalid_available_attribute() {
+        let tokens = quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                extern "Rust" {
+                    #[swift_bridge(available = "iOS")]
+                    fn a();
+                }
+            }
+        };
+
+        assert!(syn::parse2::<SwiftBridgeModuleAndErrors>(tokens).is_err());
+    }
+
     /// Verify that we can parse a function that has multiple swift_bridge attributes.
     #[test]
     fn parses_multiple_function_swift_bridge_attributes() {
@@ -8,6 +8,8 @@ pub(crate) struct GenericOpaqueType {
     pub ident: Ident,
     #[allow(unused)]
     pub generics: Generics,
+    /// The `SomeProtocol` in `type MyType: SomeProtocol;`.
+    pub swift_protocol: Option<Ident>,
     #[allow(unused)]
     pub semicolon: Token![;],
 }
@@ -18,6 +20,12 @@ impl Parse for GenericOpaqueType {
             type_token: input.parse()?,
             ident: input.parse()?,
             generics: input.parse()?,
+            swift_protocol: if input.peek(Token![:]) {
+                input.parse::<Token![:]>()?;
+                Some(input.parse()?)
+            } else {
+                None
+            },
             semicolon: input.parse()?,
         })
     }
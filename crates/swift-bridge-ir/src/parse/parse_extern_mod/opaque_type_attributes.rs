@@ -1,21 +1,69 @@
+use crate::parse::{SwiftAvailability, SwiftVisibility};
 use proc_macro2::Ident;
 use syn::parse::{Parse, ParseStream};
+use syn::punctuated::Punctuated;
+use syn::{LitInt, LitStr, Token};
 
 #[derive(Default)]
 pub(super) struct OpaqueTypeAttributes {
     pub already_declared: bool,
+    pub trait_object: bool,
+    pub arc: bool,
+    pub pin: bool,
+    pub mutex: bool,
+    pub no_free: bool,
+    pub skip: bool,
+    pub copy: Option<usize>,
+    pub comparable: bool,
+    pub display: bool,
+    pub expressible_by_string_literal: bool,
+    pub sendable: bool,
+    pub swift_name: Option<LitStr>,
+    pub swift_visibility: SwiftVisibility,
+    pub available: Option<SwiftAvailability>,
+    pub conforms_to: Vec<Ident>,
 }
 
 impl OpaqueTypeAttributes {
     pub fn store_attrib(&mut self, attrib: OpaqueTypeAttr) {
         match attrib {
             OpaqueTypeAttr::AlreadyDeclared => self.already_declared = true,
+            OpaqueTypeAttr::TraitObject => self.trait_object = true,
+            OpaqueTypeAttr::Arc => self.arc = true,
+            OpaqueTypeAttr::Pin => self.pin = true,
+            OpaqueTypeAttr::Mutex => self.mutex = true,
+            OpaqueTypeAttr::NoFree => self.no_free = true,
+            OpaqueTypeAttr::Skip => self.skip = true,
+            OpaqueTypeAttr::Copy(size_bytes) => self.copy = Some(size_bytes),
+            OpaqueTypeAttr::Comparable => self.comparable = true,
+            OpaqueTypeAttr::Display => self.display = true,
+            OpaqueTypeAttr::ExpressibleByStringLiteral => self.expressible_by_string_literal = true,
+            OpaqueTypeAttr::Sendable => self.sendable = true,
+            OpaqueTypeAttr::SwiftName(name) => self.swift_name = Some(name),
+            OpaqueTypeAttr::SwiftVisibility(visibility) => self.swift_visibility = visibility,
+            OpaqueTypeAttr::Available(available) => self.available = Some(available),
+            OpaqueTypeAttr::ConformsTo(protocols) => self.conforms_to.extend(protocols),
         }
     }
 }
 
 pub(super) enum OpaqueTypeAttr {
     AlreadyDeclared,
+    TraitObject,
+    Arc,
+    Pin,
+    Mutex,
+    NoFree,
+    Skip,
+    Copy(usize),
+    Comparable,
+    Display,
+    ExpressibleByStringLiteral,
+    Sendable,
+    SwiftName(LitStr),
+    SwiftVisibility(SwiftVisibility),
+    Available(SwiftAvailability),
+    ConformsTo(Vec<Ident>),
 }
 
 impl Parse for OpaqueTypeAttr {
@@ -24,6 +72,53 @@ impl Parse for OpaqueTypeAttr {
 
         let attrib = match key.to_string().as_str() {
             "already_declared" => OpaqueTypeAttr::AlreadyDeclared,
+            "trait_object" => OpaqueTypeAttr::TraitObject,
+            "Arc" => OpaqueTypeAttr::Arc,
+            "Pin" => OpaqueTypeAttr::Pin,
+            "Mutex" => OpaqueTypeAttr::Mutex,
+            "no_free" => OpaqueTypeAttr::NoFree,
+            "skip" => OpaqueTypeAttr::Skip,
+            // `Copy(16)`, where the number is the size of the type in bytes.
+            "Copy" => {
+                let content;
+                syn::parenthesized!(content in input);
+
+                let size_bytes: LitInt = content.parse()?;
+                OpaqueTypeAttr::Copy(size_bytes.base10_parse()?)
+            }
+            "Comparable" => OpaqueTypeAttr::Comparable,
+            "Display" => OpaqueTypeAttr::Display,
+            "ExpressibleByStringLiteral" => OpaqueTypeAttr::ExpressibleByStringLiteral,
+            "Sendable" => OpaqueTypeAttr::Sendable,
+            "swift_name" => {
+                input.parse::<Token![=]>()?;
+                OpaqueTypeAttr::SwiftName(input.parse()?)
+            }
+            "swift_visibility" => {
+                input.parse::<Token![=]>()?;
+                OpaqueTypeAttr::SwiftVisibility(SwiftVisibility::parse_type_visibility(input)?)
+            }
+            "available" => {
+                input.parse::<Token![=]>()?;
+                OpaqueTypeAttr::Available(input.parse()?)
+            }
+            // `conforms_to = SomeProtocol` or `conforms_to = (SomeProtocol, AnotherProtocol)`
+            "conforms_to" => {
+                input.parse::<Token![=]>()?;
+
+                let protocols = if input.peek(syn::token::Paren) {
+                    let content;
+                    syn::parenthesized!(content in input);
+
+                    Punctuated::<Ident, Token![,]>::parse_terminated(&content)?
+                        .into_iter()
+                        .collect()
+                } else {
+                    vec![input.parse()?]
+                };
+
+                OpaqueTypeAttr::ConformsTo(protocols)
+            }
             _ => panic!("TODO: Return spanned error"),
         };
 
@@ -1,5 +1,6 @@
-use crate::bridged_type::{SharedStruct, StructFields, StructSwiftRepr};
+use crate::bridged_type::{SharedStruct, StructFields, StructRenameAll, StructSwiftRepr};
 use crate::errors::{ParseError, ParseErrors};
+use crate::parse::{push_doc_comment_line, SwiftVisibility};
 use proc_macro2::{Ident, TokenTree};
 use syn::parse::{Parse, ParseStream};
 use syn::{ItemStruct, LitStr, Token};
@@ -14,10 +15,18 @@ enum StructAttr {
     SwiftName(LitStr),
     Error(StructAttrParseError),
     AlreadyDeclared,
+    Equatable,
+    Codable,
+    Sendable,
+    /// The name of the field to use as the `Identifiable` id.
+    Identifiable(Ident),
+    SwiftVisibility(SwiftVisibility),
+    RenameAll(StructRenameAll),
 }
 
 enum StructAttrParseError {
     InvalidSwiftRepr(LitStr),
+    InvalidRenameAll(LitStr),
     UnrecognizedAttribute(Ident),
 }
 
@@ -26,6 +35,12 @@ struct StructAttribs {
     swift_repr: Option<(StructSwiftRepr, LitStr)>,
     swift_name: Option<LitStr>,
     already_declared: bool,
+    equatable: bool,
+    codable: bool,
+    sendable: bool,
+    identifiable: Option<Ident>,
+    swift_visibility: SwiftVisibility,
+    rename_all: Option<StructRenameAll>,
 }
 
 struct ParsedAttribs(Vec<StructAttr>);
@@ -64,6 +79,32 @@ impl Parse for StructAttr {
                 StructAttr::SwiftName(name)
             }
             "already_declared" => StructAttr::AlreadyDeclared,
+            "Equatable" => StructAttr::Equatable,
+            "Codable" => StructAttr::Codable,
+            "Sendable" => StructAttr::Sendable,
+            "Identifiable" => {
+                if input.peek(Token![=]) {
+                    input.parse::<Token![=]>()?;
+
+                    let field: LitStr = input.parse()?;
+                    StructAttr::Identifiable(Ident::new(&field.value(), field.span()))
+                } else {
+                    StructAttr::Identifiable(Ident::new("id", key.span()))
+                }
+            }
+            "swift_visibility" => {
+                input.parse::<Token![=]>()?;
+                StructAttr::SwiftVisibility(SwiftVisibility::parse_type_visibility(input)?)
+            }
+            "rename_all" => {
+                input.parse::<Token![=]>()?;
+
+                let rename_all: LitStr = input.parse()?;
+                match rename_all.value().as_str() {
+                    "camelCase" => StructAttr::RenameAll(StructRenameAll::CamelCase),
+                    _ => StructAttr::Error(StructAttrParseError::InvalidRenameAll(rename_all)),
+                }
+            }
             _ => {
                 move_input_cursor_to_next_comma(input);
 
@@ -80,8 +121,14 @@ impl<'a> SharedStructDeclarationParser<'a> {
         let item_struct = self.item_struct;
 
         let mut attribs = StructAttribs::default();
+        let mut doc_comment = None;
 
         for attr in item_struct.attrs {
+            if attr.path.is_ident("doc") {
+                push_doc_comment_line(&mut doc_comment, &attr)?;
+                continue;
+            }
+
             let sections: ParsedAttribs = attr.parse_args()?;
 
             for attr in sections.0 {
@@ -99,6 +146,11 @@ impl<'a> SharedStructDeclarationParser<'a> {
                             });
                             attribs.swift_repr = Some((StructSwiftRepr::Structure, val));
                         }
+                        StructAttrParseError::InvalidRenameAll(val) => {
+                            self.errors.push(ParseError::StructInvalidRenameAll {
+                                rename_all_attr_value: val,
+                            });
+                        }
                         StructAttrParseError::UnrecognizedAttribute(attribute) => {
                             self.errors
                                 .push(ParseError::StructUnrecognizedAttribute { attribute });
@@ -107,6 +159,24 @@ impl<'a> SharedStructDeclarationParser<'a> {
                     StructAttr::AlreadyDeclared => {
                         attribs.already_declared = true;
                     }
+                    StructAttr::Equatable => {
+                        attribs.equatable = true;
+                    }
+                    StructAttr::Codable => {
+                        attribs.codable = true;
+                    }
+                    StructAttr::Sendable => {
+                        attribs.sendable = true;
+                    }
+                    StructAttr::Identifiable(field) => {
+                        attribs.identifiable = Some(field);
+                    }
+                    StructAttr::SwiftVisibility(visibility) => {
+                        attribs.swift_visibility = visibility;
+                    }
+                    StructAttr::RenameAll(rename_all) => {
+                        attribs.rename_all = Some(rename_all);
+                    }
                 };
             }
         }
@@ -138,6 +208,13 @@ impl<'a> SharedStructDeclarationParser<'a> {
             fields: StructFields::from_syn_fields(item_struct.fields),
             swift_name: attribs.swift_name,
             already_declared: attribs.already_declared,
+            equatable: attribs.equatable,
+            codable: attribs.codable,
+            sendable: attribs.sendable,
+            identifiable: attribs.identifiable,
+            swift_visibility: attribs.swift_visibility,
+            rename_all: attribs.rename_all,
+            doc_comment,
         };
 
         Ok(shared_struct)
@@ -361,6 +438,222 @@ mod tests {
         assert!(ty.already_declared);
     }
 
+    /// Verify that we can parse an `Equatable` attribute.
+    #[test]
+    fn parses_struct_equatable_attribute() {
+        let tokens = quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                #[swift_bridge(Equatable, swift_repr = "struct")]
+                struct SomeType {
+                    field: u8,
+                }
+            }
+        };
+
+        let module = parse_ok(tokens);
+
+        let ty = module.types.types()[0].unwrap_shared_struct();
+        assert!(ty.equatable);
+    }
+
+    /// Verify that we can parse a `Codable` attribute.
+    #[test]
+    fn parses_struct_codable_attribute() {
+        let tokens = quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                #[swift_bridge(Codable, swift_repr = "struct")]
+                struct SomeType {
+                    field: u8,
+                }
+            }
+        };
+
+        let module = parse_ok(tokens);
+
+        let ty = module.types.types()[0].unwrap_shared_struct();
+        assert!(ty.codable);
+    }
+
+    /// Verify that we can parse a `Sendable` attribute.
+    #[test]
+    fn parses_struct_sendable_attribute() {
+        let tokens = quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                #[swift_bridge(Sendable)]
+                struct SomeType;
+            }
+        };
+
+        let module = parse_ok(tokens);
+
+        let ty = module.types.types()[0].unwrap_shared_struct();
+        assert!(ty.sendable);
+    }
+
+    /// Verify that we can parse an `Identifiable` attribute, with and without a custom id field.
+    #[test]
+    fn parses_struct_identifiable_attribute() {
+        let tokens = quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                #[swift_bridge(swift_repr = "struct", Identifiable)]
+                struct SomeType {
+                    id: u8,
+                }
+
+                #[swift_bridge(swift_repr = "struct", Identifiable = "uuid")]
+                struct AnotherType {
+                    uuid: u64,
+                }
+            }
+        };
+
+        let module = parse_ok(tokens);
+
+        let ty = module.types.types()[0].unwrap_shared_struct();
+        assert_eq!(ty.identifiable.as_ref().unwrap(), "id");
+
+        let ty = module.types.types()[1].unwrap_shared_struct();
+        assert_eq!(ty.identifiable.as_ref().unwrap(), "uuid");
+    }
+
+    /// Verify that we can parse a `rename_all` attribute.
+    #[test]
+    fn parses_struct_rename_all_attribute() {
+        let tokens = quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                #[swift_bridge(swift_repr = "struct", rename_all = "camelCase")]
+                struct SomeType {
+                    some_field: u8,
+                }
+            }
+        };
+
+        let module = parse_ok(tokens);
+
+        let ty = module.types.types()[0].unwrap_shared_struct();
+        assert_eq!(ty.rename_all, Some(StructRenameAll::CamelCase));
+        assert_eq!(ty.swift_field_name("some_field"), "someField");
+    }
+
+    /// Verify that we push an error if the `rename_all` attribute is invalid.
+    #[test]
+    fn error_if_invalid_rename_all() {
+        let tokens = quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                #[swift_bridge(swift_repr = "struct", rename_all = "kebab-case")]
+                struct SomeType {
+                    some_field: u8,
+                }
+            }
+        };
+
+        let errors = parse_errors(tokens);
+        assert_eq!(errors.len(), 1);
+
+        match &errors[0] {
+            ParseError::StructInvalidRenameAll {
+                rename_all_attr_value,
+            } => {
+                assert_eq!(rename_all_attr_value.value(), "kebab-case");
+            }
+            _ => panic!(),
+        };
+    }
+
+    /// Verify that we return an error if an `Identifiable` struct doesn't have its id field, or if
+    /// the id field isn't `Hashable`.
+    #[test]
+    fn error_if_identifiable_struct_has_invalid_id_field() {
+        let tokens = quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                #[swift_bridge(swift_repr = "struct", Identifiable)]
+                struct SomeType {
+                    field: u8,
+                }
+
+                #[swift_bridge(swift_repr = "struct", Identifiable = "uuid")]
+                struct AnotherType {
+                    uuid: *const u8,
+                }
+            }
+        };
+
+        let errors = parse_errors(tokens);
+
+        assert_eq!(errors.len(), 2);
+
+        match &errors[0] {
+            ParseError::StructIdentifiableFieldMissing {
+                struct_ident,
+                field,
+            } => {
+                assert_eq!(struct_ident, "SomeType");
+                assert_eq!(field, "id");
+            }
+            _ => panic!(),
+        };
+        match &errors[1] {
+            ParseError::StructIdentifiableFieldNotHashable {
+                struct_ident,
+                field_ty,
+            } => {
+                assert_eq!(struct_ident, "AnotherType");
+                assert_eq!(field_ty.to_token_stream().to_string(), "* const u8");
+            }
+            _ => panic!(),
+        };
+    }
+
+    /// Verify that we return an error if a `Codable` struct has a field that isn't `Codable`,
+    /// such as a nested shared struct that doesn't have the `Codable` attribute.
+    #[test]
+    fn error_if_codable_struct_has_non_codable_field() {
+        let tokens = quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                #[swift_bridge(Codable, swift_repr = "struct")]
+                struct SomeType {
+                    field: u8,
+                    inner: InnerType,
+                    text: String,
+                }
+
+                #[swift_bridge(swift_repr = "struct")]
+                struct InnerType {
+                    field: u8,
+                }
+            }
+        };
+
+        let errors = parse_errors(tokens);
+
+        assert_eq!(errors.len(), 2);
+
+        match &errors[0] {
+            ParseError::StructFieldNotCodable {
+                struct_ident,
+                field_ty,
+            } => {
+                assert_eq!(struct_ident, "SomeType");
+                assert_eq!(field_ty.to_token_stream().to_string(), "InnerType");
+            }
+            _ => panic!(),
+        };
+        match &errors[1] {
+            ParseError::StructFieldNotCodable { field_ty, .. } => {
+                assert_eq!(field_ty.to_token_stream().to_string(), "String");
+            }
+            _ => panic!(),
+        };
+    }
+
     /// Verify that we return an error if an attribute isn't recognized.
     #[test]
     fn error_if_attribute_unrecognized() {
@@ -1,13 +1,14 @@
 use crate::bridged_type::{
-    BridgedType, CustomBridgedType, OpaqueForeignType, SharedEnum, SharedStruct, SharedType,
+    BridgedType, CustomBridgedType, OpaqueForeignType, OpaqueGenericArgs, SharedEnum, SharedStruct,
+    SharedType,
 };
-use crate::parse::HostLang;
+use crate::parse::{HostLang, SwiftAvailability, SwiftVisibility};
 use crate::SWIFT_BRIDGE_PREFIX;
-use proc_macro2::Ident;
-use quote::ToTokens;
+use proc_macro2::{Ident, TokenStream};
+use quote::{quote, ToTokens};
 use std::collections::HashMap;
 use std::ops::Deref;
-use syn::{GenericParam, PatType, Type, TypePath};
+use syn::{GenericParam, LitStr, PatType, Type, TypePath};
 
 #[derive(Default)]
 pub(crate) struct TypeDeclarations {
@@ -38,6 +39,13 @@ impl TypeDeclaration {
                         fields: shared_struct.fields.clone(),
                         swift_name: shared_struct.swift_name.clone(),
                         already_declared: shared_struct.already_declared,
+                        equatable: shared_struct.equatable,
+                        codable: shared_struct.codable,
+                        sendable: shared_struct.sendable,
+                        identifiable: shared_struct.identifiable.clone(),
+                        swift_visibility: shared_struct.swift_visibility,
+                        rename_all: shared_struct.rename_all,
+                        doc_comment: shared_struct.doc_comment.clone(),
                     },
                 )))
             }
@@ -45,16 +53,17 @@ impl TypeDeclaration {
                 BridgedType::Foreign(CustomBridgedType::Shared(SharedType::Enum(SharedEnum {
                     name: shared_enum.name.clone(),
                     variants: shared_enum.variants.clone(),
+                    comparable: shared_enum.comparable,
+                    hashable: shared_enum.hashable,
+                    localized_error: shared_enum.localized_error,
+                    sendable: shared_enum.sendable,
+                    transparent: shared_enum.transparent,
+                    swift_visibility: shared_enum.swift_visibility,
                 })))
             }
-            TypeDeclaration::Opaque(opaque) => {
-                BridgedType::Foreign(CustomBridgedType::Opaque(OpaqueForeignType {
-                    ty: opaque.ty.clone(),
-                    host_lang: opaque.host_lang,
-                    reference,
-                    mutable,
-                }))
-            }
+            TypeDeclaration::Opaque(opaque) => BridgedType::Foreign(CustomBridgedType::Opaque(
+                opaque.to_opaque_foreign_type(reference, mutable),
+            )),
         }
     }
 }
@@ -67,12 +76,70 @@ pub(crate) struct OpaqueForeignTypeDeclaration {
     /// If it was, we won't generate Swift and C type declarations for this type, since we
     /// will elsewhere.
     pub already_declared: bool,
-    /// A doc comment.
-    // TODO: Use this to generate doc comment for the generated Swift type.
-    #[allow(unused)]
+    /// A doc comment, which we copy over to the generated Swift class.
     pub doc_comment: Option<String>,
-    #[allow(unused)]
+    /// The generic parameters of a generic type declaration such as `type MyWrapper<T>;`.
+    ///
+    /// We don't generate any code for the generic declaration itself. Instead, every concrete
+    /// instantiation such as `MyWrapper<u32>` gets declared as its own type with `generic_args`.
     pub generics: Vec<GenericParam>,
+    /// The concrete types of an instantiation of a generic type, such as the `u32` in
+    /// `MyWrapper<u32>`.
+    pub generic_args: OpaqueGenericArgs,
+    /// Whether or not the `#[swift_bridge(trait_object)]` attribute was present on the type.
+    /// If it was, the type is a `Box<dyn Trait>` where `Trait` is the declared type's name.
+    pub trait_object: bool,
+    /// Whether or not the `#[swift_bridge(Arc)]` attribute was present on the type.
+    /// If it was, the type is an `Arc<Type>` and its Swift class can be cheaply copied by
+    /// incrementing the `Arc`'s reference count.
+    pub arc: bool,
+    /// Whether or not the `#[swift_bridge(Pin)]` attribute was present on the type.
+    /// If it was, the type is a `Pin<Box<Type>>`. The Swift class holds the pinned box, so the
+    /// Rust value never moves for as long as Swift owns it.
+    pub pin: bool,
+    /// Whether or not the `#[swift_bridge(Mutex)]` attribute was present on the type.
+    /// If it was, the type is a `Mutex<Type>` and its methods lock the mutex before they get
+    /// called, so Swift never sees the lock.
+    pub mutex: bool,
+    /// Whether or not the `#[swift_bridge(no_free)]` attribute was present on the type.
+    /// If it was, the type's lifetime is managed outside of the bridge, so we don't generate a
+    /// function to free it and its Swift class never frees the value that it points to.
+    pub no_free: bool,
+    /// The size in bytes of a `Copy` type, set using the `#[swift_bridge(Copy(N))]` attribute.
+    /// A `Copy` type gets passed across the FFI boundary by value instead of being boxed, and
+    /// its Swift representation is a struct instead of a class.
+    pub copy: Option<usize>,
+    /// Whether or not the `#[swift_bridge(Comparable)]` attribute was present on the type.
+    /// If it was, the generated Swift class conforms to `Comparable` using the type's `Ord`
+    /// implementation.
+    pub comparable: bool,
+    /// Whether or not the `#[swift_bridge(Display)]` attribute was present on the type.
+    /// If it was, the generated Swift class conforms to `CustomStringConvertible` using the
+    /// type's `Display` implementation.
+    pub display: bool,
+    /// Whether or not the `#[swift_bridge(ExpressibleByStringLiteral)]` attribute was present on
+    /// the type. If it was, the generated Swift class can be created from a string literal using
+    /// the type's initializer that takes a single `String` or `&str`.
+    pub expressible_by_string_literal: bool,
+    /// Whether or not the `#[swift_bridge(Sendable)]` attribute was present on the type.
+    /// If it was, the generated Swift class is marked as `@unchecked Sendable`.
+    pub sendable: bool,
+    /// The name of the generated Swift class, set using the
+    /// `#[swift_bridge(swift_name = "...")]` attribute. The symbols that we link against are
+    /// still named after the Rust type.
+    pub swift_name: Option<LitStr>,
+    /// The access modifier of the generated Swift classes, set using the
+    /// `#[swift_bridge(swift_visibility = "...")]` attribute.
+    pub swift_visibility: SwiftVisibility,
+    /// The platforms that the generated Swift classes are available on, set using the
+    /// `#[swift_bridge(available = "...")]` attribute.
+    pub available: Option<SwiftAvailability>,
+    /// The user declared Swift protocols that the generated Swift class conforms to, set using
+    /// the `#[swift_bridge(conforms_to = (...))]` attribute.
+    pub conforms_to: Vec<Ident>,
+    /// The Swift protocol that the methods of an `extern "Swift"` type get called through,
+    /// declared using `type MyType: SomeProtocol;`.
+    pub swift_protocol: Option<Ident>,
 }
 
 impl Deref for OpaqueForeignTypeDeclaration {
@@ -85,17 +152,129 @@ impl Deref for OpaqueForeignTypeDeclaration {
 
 impl OpaqueForeignTypeDeclaration {
     // "__swift_bridge__$TypeName$_free"
-    pub fn free_link_name(&self) -> String {
-        format!("{}${}$_free", SWIFT_BRIDGE_PREFIX, self.ty.to_string())
+    pub fn free_link_name(&self, symbol_prefix: &str) -> String {
+        format!("{}${}$_free", symbol_prefix, self.link_name_segment())
     }
 
     // "__swift_bridge__TypeName__free"
     pub fn free_func_name(&self) -> String {
-        format!("{}{}__free", SWIFT_BRIDGE_PREFIX, self.ty.to_string())
+        format!("{}{}__free", SWIFT_BRIDGE_PREFIX, self.ident_segment())
     }
 
-    pub fn ty_name_ident(&self) -> &Ident {
-        &self.ty
+    // "__swift_bridge__$TypeName$_clone"
+    pub fn clone_link_name(&self, symbol_prefix: &str) -> String {
+        format!("{}${}$_clone", symbol_prefix, self.link_name_segment())
+    }
+
+    // "__swift_bridge__TypeName__clone"
+    pub fn clone_func_name(&self) -> String {
+        format!("{}{}__clone", SWIFT_BRIDGE_PREFIX, self.ident_segment())
+    }
+
+    // "__swift_bridge__$TypeName$_cmp"
+    pub fn cmp_link_name(&self, symbol_prefix: &str) -> String {
+        format!("{}${}$_cmp", symbol_prefix, self.link_name_segment())
+    }
+
+    // "__swift_bridge__TypeName__cmp"
+    pub fn cmp_func_name(&self) -> String {
+        format!("{}{}__cmp", SWIFT_BRIDGE_PREFIX, self.ident_segment())
+    }
+
+    // "__swift_bridge__$TypeName$_to_string"
+    pub fn to_string_link_name(&self, symbol_prefix: &str) -> String {
+        format!("{}${}$_to_string", symbol_prefix, self.link_name_segment())
+    }
+
+    // "__swift_bridge__TypeName__to_string"
+    pub fn to_string_func_name(&self) -> String {
+        format!("{}{}__to_string", SWIFT_BRIDGE_PREFIX, self.ident_segment())
+    }
+
+    /// Whether or not this is a generic declaration such as `type MyWrapper<T>;`, as opposed to
+    /// a concrete type that we generate code for.
+    pub fn is_generic_declaration(&self) -> bool {
+        !self.generics.is_empty()
+    }
+
+    /// Whether or not we generate the functions that back a `Vec<T>` of this type.
+    ///
+    /// A `no_free` type is never vectorizable, since dropping the `Vec<T>` would free its values.
+    // TODO: Support Vec<T> of generic opaque type instantiations, trait objects, `Arc`s and
+    //  pinned types and mutexes.
+    pub fn is_vectorizable(&self) -> bool {
+        self.generic_args.is_empty()
+            && !self.trait_object
+            && !self.arc
+            && !self.pin
+            && !self.mutex
+            && !self.no_free
+            && self.copy.is_none()
+    }
+
+    pub fn to_opaque_foreign_type(&self, reference: bool, mutable: bool) -> OpaqueForeignType {
+        OpaqueForeignType {
+            ty: self.ty.clone(),
+            host_lang: self.host_lang,
+            reference,
+            mutable,
+            generic_args: self.generic_args.clone(),
+            trait_object: self.trait_object,
+            arc: self.arc,
+            pin: self.pin,
+            mutex: self.mutex,
+            copy: self.copy,
+            swift_name: self.swift_name.as_ref().map(|name| name.value()),
+        }
+    }
+
+    /// MyType -> __swift_bridge__MyType
+    pub fn copy_ffi_repr_tokens(&self) -> Ident {
+        self.to_opaque_foreign_type(false, false)
+            .copy_ffi_repr_tokens()
+    }
+
+    /// MyType -> __swift_bridge__$MyType
+    pub fn copy_ffi_repr_string(&self) -> String {
+        self.to_opaque_foreign_type(false, false)
+            .copy_ffi_repr_string()
+    }
+
+    /// The name of the generated Swift class.
+    ///
+    /// MyWrapper<u32> -> MyWrapperUInt32
+    pub fn swift_name(&self) -> String {
+        self.to_opaque_foreign_type(false, false).swift_name()
+    }
+
+    /// The type's name within the symbols that we link against.
+    ///
+    /// MyWrapper<u32> -> MyWrapper$u32
+    pub fn link_name_segment(&self) -> String {
+        format!("{}{}", self.ty, self.generic_args.link_name_suffix())
+    }
+
+    /// The type's name within identifiers that we generate.
+    ///
+    /// MyWrapper<u32> -> MyWrapper_u32
+    pub fn ident_segment(&self) -> String {
+        self.link_name_segment().replace('$', "_")
+    }
+
+    /// The Rust type, for use in a type position within the generated module.
+    ///
+    /// MyWrapper<u32> -> super::MyWrapper<u32>
+    pub fn rust_ty_tokens(&self) -> TokenStream {
+        self.to_opaque_foreign_type(false, false).rust_ty_tokens()
+    }
+
+    /// The Rust type relative to the generated module's parent, for use in an expression position.
+    ///
+    /// MyWrapper<u32> -> MyWrapper::<u32>
+    pub fn rust_expr_tokens(&self) -> TokenStream {
+        let ty = &self.ty;
+        let generic_args = self.generic_args.to_rust_turbofish_tokens();
+        quote! { #ty #generic_args }
     }
 }
 
@@ -117,18 +296,23 @@ impl TypeDeclarations {
         self.get(&ty)
     }
 
+    /// Get the declaration for a type such as `Foo`, `&Foo` or `&mut Foo`.
     pub(crate) fn get_with_type(&self, ty: &Type) -> Option<&TypeDeclaration> {
-        let ty = match ty.deref() {
-            Type::Reference(reference) => reference.elem.to_token_stream().to_string(),
-            Type::Path(path) => path.to_token_stream().to_string(),
-            _ => todo!("Handle other cases"),
-        };
-        self.get(&ty)
+        match ty {
+            Type::Reference(reference) => self.get_with_type(&reference.elem),
+            Type::Paren(paren) => self.get_with_type(&paren.elem),
+            Type::Group(group) => self.get_with_type(&group.elem),
+            Type::Path(path) => self.get_with_type_path(path),
+            _ => None,
+        }
     }
 
     pub(crate) fn insert(&mut self, type_name: String, ty: TypeDeclaration) {
-        self.decls.insert(type_name.clone(), ty);
-        self.order.push(type_name);
+        // Re-declaring a type replaces it in place, so that every type only gets generated once
+        // and always in the order that it was first declared.
+        if self.decls.insert(type_name.clone(), ty).is_none() {
+            self.order.push(type_name);
+        }
     }
 
     pub fn types(&self) -> Vec<&TypeDeclaration> {
@@ -1,17 +1,52 @@
-use crate::bridged_type::{pat_type_pat_is_self, BridgedType};
-use crate::parse::{HostLang, SharedTypeDeclaration, TypeDeclaration, TypeDeclarations};
+use crate::bridge_module_attributes::CfgAttr;
+use crate::bridged_type::{
+    pat_type_pat_is_self, BridgedArray, BridgedBoxedFn, BridgedTuple, BridgedType, StdLibType,
+};
+use crate::parse::{
+    HostLang, SharedTypeDeclaration, SwiftAvailability, SwiftVisibility, TypeDeclaration,
+    TypeDeclarations,
+};
 use crate::SWIFT_BRIDGE_PREFIX;
 use proc_macro2::{Ident, TokenStream};
 use quote::{quote, quote_spanned, ToTokens};
+use std::collections::{HashMap, HashSet};
 use std::ops::Deref;
 use syn::spanned::Spanned;
-use syn::{FnArg, ForeignItemFn, Lifetime, Path, ReturnType, Token, Type};
+use syn::{FnArg, ForeignItemFn, Lifetime, Lit, Path, ReturnType, Token, Type};
 
 mod to_extern_c_fn;
 mod to_extern_c_param_names_and_types;
 mod to_rust_impl_call_swift;
 mod to_swift_func;
 
+/// How a getter function reads the field that it gets.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub(crate) enum Getter {
+    /// `#[swift_bridge(get)]`
+    ///
+    /// Borrows the field if the function returns a reference, otherwise copies it.
+    Borrow,
+    /// `#[swift_bridge(get(clone))]`
+    ///
+    /// Clones the field so that Swift gets an owned value, such as a `String` or a `Vec<T>`.
+    Clone,
+    /// `#[swift_bridge(get(computed))]`
+    ///
+    /// Calls the method instead of reading a field, and exposes it to Swift as a computed
+    /// property such as `var isEmpty: Bool`.
+    Computed,
+}
+
+impl Getter {
+    /// Whether or not the getter reads a field of the same name instead of calling the method.
+    pub fn reads_field(&self) -> bool {
+        match self {
+            Getter::Borrow | Getter::Clone => true,
+            Getter::Computed => false,
+        }
+    }
+}
+
 /// A method or associated function associated with a type.
 ///
 /// fn bar (&self);
@@ -45,8 +80,107 @@ pub(crate) struct ParsedExternFn {
     /// Whether or not this function should be used for the associated type's Swift
     /// `Identifiable` protocol implementation.
     pub is_swift_identifiable: bool,
+    /// Whether or not this function should be used as the `next()` function of the associated
+    /// type's Swift `IteratorProtocol` implementation.
+    pub is_swift_iterator: bool,
+    /// Whether or not this function should be used as the async `next()` function of the
+    /// associated type's Swift `AsyncIteratorProtocol` implementation.
+    pub is_swift_async_iterator: bool,
+    /// Whether or not this function's return value should be exposed as a `static let` constant
+    /// on the associated type's Swift class.
+    pub is_swift_constant: bool,
+    /// Set by the `#[swift_bridge(get)]` or `#[swift_bridge(get(clone))]` attribute, in which
+    /// case we read the field with the same name as the function instead of calling a method.
+    ///
+    /// ```no_run,ignore
+    /// // Declaration
+    /// #[swift_bridge(get(clone))]
+    /// fn name(&self) -> String;
+    ///
+    /// // Approximate generated code
+    /// extern "C" fn name(this: *const SomeType) -> *mut RustString {
+    ///     RustString((unsafe { &*this }).name.clone()).box_into_raw()
+    /// }
+    /// ```
+    pub getter: Option<Getter>,
+    /// Set by the `#[swift_bridge(operator = "...")]` attribute, in which case the method gets
+    /// exposed to Swift as a static operator function instead of a named method.
+    ///
+    /// ```no_run,ignore
+    /// // Declaration
+    /// #[swift_bridge(operator = "+")]
+    /// fn add(&self, rhs: &Vector3) -> Vector3;
+    ///
+    /// // Approximate generated Swift code
+    /// extension Vector3Ref {
+    ///     public static func + (_ lhs: Vector3Ref, _ rhs: Vector3Ref) -> Vector3 {
+    ///         Vector3(ptr: __swift_bridge__$Vector3$add(lhs.ptr, rhs.ptr))
+    ///     }
+    /// }
+    /// ```
+    pub swift_operator: Option<String>,
+    /// Set by the `#[swift_bridge(subscript)]` attribute, in which case the method gets exposed
+    /// to Swift as a read-only subscript instead of a named method.
+    ///
+    /// ```no_run,ignore
+    /// // Declaration
+    /// #[swift_bridge(subscript)]
+    /// fn get(&self, index: usize) -> u32;
+    ///
+    /// // Approximate generated Swift code
+    /// extension SomeListRef {
+    ///     public subscript(_ index: UInt) -> UInt32 {
+    ///         __swift_bridge__$SomeList$get(ptr, index)
+    ///     }
+    /// }
+    /// ```
+    pub is_swift_subscript: bool,
     pub rust_name_override: Option<syn::LitStr>,
     pub swift_name_override: Option<syn::LitStr>,
+    /// The symbol of an existing Swift function that an `extern "Swift"` function links to,
+    /// set using the `#[swift_bridge(extern_swift_name = "...")]` attribute.
+    ///
+    /// We don't generate a Swift function for these, since the user has already written one.
+    ///
+    /// ```no_run,ignore
+    /// // Declaration
+    /// #[swift_bridge(extern_swift_name = "my_swift_function")]
+    /// fn some_function(arg: u8);
+    ///
+    /// // User written Swift code
+    /// @_cdecl("my_swift_function")
+    /// func mySwiftFunction(arg: UInt8) {}
+    /// ```
+    pub extern_swift_name: Option<syn::LitStr>,
+    /// Set by the `#[swift_bridge(return_as = "Data")]` attribute, in which case the returned
+    /// `Vec<u8>` gets handed to Swift as a `Data` that takes ownership of the Vec's buffer.
+    ///
+    /// ```no_run,ignore
+    /// // Declaration
+    /// #[swift_bridge(return_as = "Data")]
+    /// fn payload() -> Vec<u8>;
+    ///
+    /// // Approximate generated Swift code
+    /// public func payload() -> Data {
+    ///     __swift_bridge__$payload().toData()
+    /// }
+    /// ```
+    pub return_as_data: bool,
+    /// Set by the `#[swift_bridge(return_as = "Array")]` attribute, in which case the returned
+    /// Vec of numbers gets copied into a Swift Array in one shot instead of being handed to Swift
+    /// as a `RustVec<T>`.
+    ///
+    /// ```no_run,ignore
+    /// // Declaration
+    /// #[swift_bridge(return_as = "Array")]
+    /// fn samples() -> Vec<f32>;
+    ///
+    /// // Approximate generated Swift code
+    /// public func samples() -> [Float] {
+    ///     __swift_bridge__$samples().toArray(of: Float.self)
+    /// }
+    /// ```
+    pub return_as_array: bool,
     /// If true, we call `.into()` on the expression that the function returns before returning it.
     ///
     /// ```no_run,ignore
@@ -55,9 +189,11 @@ pub(crate) struct ParsedExternFn {
     ///
     /// // Approximate generated Code
     /// extern "C" fn some_function() -> SomeType {
-    ///     super::some_function().into()
+    ///     { let val: SomeType = super::some_function().into(); val }
     /// }
     /// ```
+    ///
+    /// Set using either `#[swift_bridge(into_return_type)]` or `#[swift_bridge(return_into)]`.
     pub into_return_type: bool,
     pub return_with: Option<Path>,
     /// Call `.into()` before passing this argument to the function that handles it.
@@ -73,9 +209,103 @@ pub(crate) struct ParsedExternFn {
     /// }
     /// ```
     pub args_into: Option<Vec<Ident>>,
+    /// The access modifier of the generated Swift function, set using the
+    /// `#[swift_bridge(swift_visibility = "...")]` attribute.
+    pub swift_visibility: SwiftVisibility,
+    /// The platforms that the generated Swift function is available on, set using the
+    /// `#[swift_bridge(available = "...")]` attribute.
+    ///
+    /// ```no_run,ignore
+    /// // Declaration
+    /// #[swift_bridge(available = "iOS 15.0, macOS 12.0")]
+    /// fn some_function();
+    ///
+    /// // Approximate generated Swift code
+    /// @available(iOS 15.0, macOS 12.0, *)
+    /// func some_function() {
+    ///     __swift_bridge__$some_function()
+    /// }
+    /// ```
+    pub available: Option<SwiftAvailability>,
+    /// Default values for the function's arguments, keyed by argument name.
+    ///
+    /// ```no_run,ignore
+    /// // Declaration
+    /// fn some_function(#[swift_bridge(default = 5)] some_arg: u8);
+    ///
+    /// // Approximate generated Swift code
+    /// func some_function(_ some_arg: UInt8 = 5) {
+    ///     __swift_bridge__$some_function(some_arg)
+    /// }
+    /// ```
+    pub arg_defaults: HashMap<String, Lit>,
+    /// Swift argument labels for the function's arguments, keyed by argument name.
+    ///
+    /// A label of `_` means that the argument has no label.
+    ///
+    /// ```no_run,ignore
+    /// // Declaration
+    /// fn move_to(#[swift_bridge(label = "to")] point: Point);
+    ///
+    /// // Approximate generated Swift code
+    /// func move_to(to point: Point) {
+    ///     __swift_bridge__$move_to(point.intoFfiRepr())
+    /// }
+    /// ```
+    pub arg_labels: HashMap<String, String>,
+    /// The names of the `&[u8]` arguments that Swift passes as `Data`.
+    ///
+    /// The `Data`'s bytes are only borrowed for the duration of the call.
+    ///
+    /// ```no_run,ignore
+    /// // Declaration
+    /// fn parse(#[swift_bridge(as = "Data")] bytes: &[u8]);
+    ///
+    /// // Approximate generated Swift code
+    /// func parse(_ bytes: Data) {
+    ///     bytes.withUnsafeBytes({ bytesAsBytes in
+    ///         __swift_bridge__$parse(bytesAsBytes.toFfiSlice())
+    ///     })
+    /// }
+    /// ```
+    pub args_as_data: HashSet<String>,
+    /// The names of the Vec arguments that Swift passes as an Array.
+    ///
+    /// Rust copies the Array's elements into a new Vec that the function takes ownership of.
+    ///
+    /// ```no_run,ignore
+    /// // Declaration
+    /// fn sum(#[swift_bridge(as = "Array")] numbers: Vec<i32>) -> i32;
+    ///
+    /// // Approximate generated Swift code
+    /// func sum(_ numbers: [Int32]) -> Int32 {
+    ///     __swift_bridge__$sum(numbers.withUnsafeBufferPointer { buf in
+    ///         __swift_bridge__$Vec_i32$from_slice(buf.baseAddress, UInt(buf.count))
+    ///     })
+    /// }
+    /// ```
+    pub args_as_array: HashSet<String>,
+    /// The function's doc comment, which we copy over to the generated Swift function.
+    pub doc_comment: Option<String>,
+    /// The function's `#[cfg(...)]` attributes.
+    ///
+    /// We put them on the generated Rust code, and only generate the Swift and C code for the
+    /// function when they are enabled.
+    pub cfg_attrs: Vec<CfgAttr>,
 }
 
 impl ParsedExternFn {
+    /// The name of the function on the Swift side.
+    ///
+    /// Uses the `#[swift_bridge(swift_name = "...")]` attribute if present, otherwise the Rust
+    /// function's name.
+    pub fn swift_name_string(&self) -> String {
+        match self.swift_name_override.as_ref() {
+            Some(swift_name) => swift_name.value(),
+            None => self.func.sig.ident.to_string(),
+        }
+    }
+
     pub fn is_method(&self) -> bool {
         self.func.sig.receiver().is_some()
     }
@@ -100,14 +330,55 @@ impl ParsedExternFn {
         }
     }
 
+    /// Whether or not this function's associated type is a `#[swift_bridge(Copy(N))]` type, in
+    /// which case `self` gets passed by value instead of behind a pointer.
+    pub fn associated_type_is_copy(&self) -> bool {
+        matches!(
+            self.associated_type.as_ref(),
+            Some(TypeDeclaration::Opaque(ty)) if ty.copy.is_some()
+        )
+    }
+
+    /// Whether or not this function's associated type is a `#[swift_bridge(Pin)]` type, in which
+    /// case `&mut self` methods get called through `Pin::as_mut` so that the value never moves.
+    pub fn associated_type_is_pinned(&self) -> bool {
+        matches!(
+            self.associated_type.as_ref(),
+            Some(TypeDeclaration::Opaque(ty)) if ty.pin
+        )
+    }
+
+    /// Whether or not this is a synchronous method on a `#[swift_bridge(Mutex)]` type, in which
+    /// case the mutex gets locked before the method is called and a poisoned lock gets thrown as
+    /// an error.
+    pub fn is_mutex_guarded_method(&self) -> bool {
+        self.host_lang.is_rust()
+            && self.is_method()
+            && self.sig.asyncness.is_none()
+            && matches!(
+                self.associated_type.as_ref(),
+                Some(TypeDeclaration::Opaque(ty)) if ty.mutex
+            )
+    }
+
+    /// The kind of pointer that the extern "C" function for a Rust method receives `self` as.
+    ///
+    /// `&self` -> `*const`, since Rust only ever reads through it.
+    /// `&mut self` and `self` -> `*mut`
+    pub fn self_ptr_kind(&self) -> TokenStream {
+        if self.self_reference().is_some() && self.self_mutability().is_none() {
+            quote! { *const }
+        } else {
+            quote! { *mut }
+        }
+    }
+
     pub(crate) fn rust_fn_sig_return_tokens(
         &self,
         swift_bridge_path: &Path,
         types: &TypeDeclarations,
     ) -> TokenStream {
-        let sig = &self.func.sig;
-
-        if let Some(ret) = BridgedType::new_with_return_type(&sig.output, types) {
+        if let Some(ret) = self.return_ty_built_in(types) {
             let ty = ret.to_ffi_compatible_rust_type(swift_bridge_path);
             if ty.to_string() == "()" {
                 quote! {}
@@ -160,6 +431,86 @@ impl ParsedExternFn {
         )
     }
 
+    /// The parts of an initializer that Swift uses to tell it apart from the type's other
+    /// initializers, which are the argument labels and types.
+    ///
+    /// `fn from_name(#[swift_bridge(label = "fromName")] name: String) -> SomeType`
+    /// -> `SomeType(fromName: String)`
+    pub fn swift_initializer_signature(&self) -> String {
+        let ty = match self.associated_type.as_ref() {
+            Some(TypeDeclaration::Opaque(ty)) => ty.swift_name(),
+            _ => "".to_string(),
+        };
+
+        let args: Vec<String> = self
+            .func
+            .sig
+            .inputs
+            .iter()
+            .filter_map(|arg| match arg {
+                FnArg::Typed(pat_ty) => {
+                    let arg_name = pat_ty.pat.to_token_stream().to_string();
+                    let label = self
+                        .arg_labels
+                        .get(&arg_name)
+                        .map(|label| label.as_str())
+                        .unwrap_or("_");
+
+                    Some(format!("{}: {}", label, pat_ty.ty.to_token_stream()))
+                }
+                FnArg::Receiver(_) => None,
+            })
+            .collect();
+
+        format!("{}({})", ty, args.join(", "))
+    }
+
+    /// Whether or not this is an initializer that returns `Option<Self>`, which becomes a Swift
+    /// failable `init?` that returns `nil` when Rust returns `None`.
+    pub fn is_failable_initializer(&self) -> bool {
+        self.is_swift_initializer
+            && match &self.func.sig.output {
+                ReturnType::Type(_, ty) => option_inner_type(ty).is_some(),
+                ReturnType::Default => false,
+            }
+    }
+
+    /// The Swift argument label that a string can be passed to this initializer with, if this is
+    /// a non-failable initializer that takes a single `String` or `&str`.
+    ///
+    /// `fn new(name: String) -> SomeType` -> `Some("")`
+    /// `fn new(#[swift_bridge(label = "name")] name: &str) -> SomeType` -> `Some("name: ")`
+    pub fn string_literal_initializer_label(&self, types: &TypeDeclarations) -> Option<String> {
+        if !self.is_swift_initializer || self.is_failable_initializer() {
+            return None;
+        }
+
+        let mut args = self.func.sig.inputs.iter();
+        let pat_ty = match (args.next(), args.next()) {
+            (Some(FnArg::Typed(pat_ty)), None) => pat_ty,
+            _ => return None,
+        };
+
+        match BridgedType::new_with_type(&pat_ty.ty, types)? {
+            BridgedType::StdLib(StdLibType::String | StdLibType::Str) => {}
+            _ => return None,
+        };
+
+        let arg_name = pat_ty.pat.to_token_stream().to_string();
+        let label = match self.arg_labels.get(&arg_name).map(|label| label.as_str()) {
+            None | Some("_") => "".to_string(),
+            Some(label) => format!("{}: ", label),
+        };
+        Some(label)
+    }
+
+    /// Owned `String` arguments that Swift passes to Rust cross the FFI boundary as a
+    /// `swift_bridge::string::FfiString`, so that Rust can build its `String` from either a
+    /// `RustString` or the Swift `String`'s bytes.
+    pub fn passes_arg_as_ffi_string(&self, arg_ty: &BridgedType) -> bool {
+        self.host_lang.is_rust() && matches!(arg_ty, BridgedType::StdLib(StdLibType::String))
+    }
+
     pub fn args_into_contains_arg(&self, arg: &FnArg) -> bool {
         if self.args_into.is_none() {
             return false;
@@ -217,7 +568,15 @@ impl ParsedExternFn {
                     let mut arg = quote! {#pat};
 
                     if let Some(built_in) = BridgedType::new_with_type(&pat_ty.ty, types) {
-                        if self.host_lang.is_rust() {
+                        if self.passes_arg_as_ffi_string(&built_in) {
+                            arg = quote_spanned! {pat_ty.ty.span()=> #arg.into_string() };
+
+                            if self.args_into_contains_arg(fn_arg) {
+                                arg = quote_spanned! {pat_ty.span()=>
+                                    #arg.into()
+                                };
+                            }
+                        } else if self.host_lang.is_rust() {
                             arg = built_in.convert_ffi_value_to_rust_value(&arg, pat_ty.ty.span());
 
                             if self.args_into_contains_arg(fn_arg) {
@@ -249,19 +608,41 @@ impl ParsedExternFn {
     //  becomes..
     // void* self, uint8_t u8, uint32_t arg2
     pub fn to_c_header_params(&self, types: &TypeDeclarations) -> String {
+        self.header_params(types, false)
+    }
+
+    // Same as `to_c_header_params`, but pointers that can be null are annotated as `_Nullable`.
+    pub fn to_objc_header_params(&self, types: &TypeDeclarations) -> String {
+        self.header_params(types, true)
+    }
+
+    fn header_params(&self, types: &TypeDeclarations, objc: bool) -> String {
+        let self_param = match self.associated_type.as_ref() {
+            Some(TypeDeclaration::Opaque(ty)) if ty.copy.is_some() => {
+                format!("struct {} self", ty.copy_ffi_repr_string())
+            }
+            _ => "void* self".to_string(),
+        };
+
         let mut params = vec![];
         let inputs = &self.func.sig.inputs;
         for arg in inputs {
             match arg {
-                FnArg::Receiver(_receiver) => params.push("void* self".to_string()),
+                FnArg::Receiver(_receiver) => params.push(self_param.clone()),
                 FnArg::Typed(pat_ty) => {
                     let pat = &pat_ty.pat;
 
                     if pat_type_pat_is_self(pat_ty) {
-                        params.push("void* self".to_string());
+                        params.push(self_param.clone());
                     } else {
                         let built_in = BridgedType::new_with_type(&pat_ty.ty, types).unwrap();
-                        let ty = built_in.to_c();
+                        let ty = if self.passes_arg_as_ffi_string(&built_in) {
+                            "struct __private__FfiString".to_string()
+                        } else if objc {
+                            built_in.to_objc()
+                        } else {
+                            built_in.to_c()
+                        };
 
                         let arg_name = pat.to_token_stream().to_string();
                         params.push(format!("{} {}", ty, arg_name));
@@ -278,11 +659,26 @@ impl ParsedExternFn {
     }
 
     pub fn to_c_header_return(&self, types: &TypeDeclarations) -> String {
+        self.header_return(types, false)
+    }
+
+    // Same as `to_c_header_return`, but a pointer that can be null is annotated as `_Nullable`.
+    pub fn to_objc_header_return(&self, types: &TypeDeclarations) -> String {
+        self.header_return(types, true)
+    }
+
+    fn header_return(&self, types: &TypeDeclarations, objc: bool) -> String {
+        let to_header = |ty: BridgedType| if objc { ty.to_objc() } else { ty.to_c() };
+
         match &self.func.sig.output {
+            // A mutex guarded method returns a `Result` even if the method doesn't return anything.
+            ReturnType::Default if self.is_mutex_guarded_method() => {
+                to_header(self.return_ty_built_in(types).unwrap())
+            }
             ReturnType::Default => "void".to_string(),
             ReturnType::Type(_, ty) => {
-                if let Some(ty) = BridgedType::new_with_type(&ty, types) {
-                    ty.to_c()
+                if let Some(ty) = self.return_ty_built_in(types) {
+                    to_header(ty)
                 } else {
                     let ty_string = match ty.deref() {
                         Type::Reference(reference) => reference.elem.to_token_stream().to_string(),
@@ -338,10 +734,99 @@ impl ParsedExternFn {
             None
         }
     }
+
+    /// The `Box<dyn Fn>` arguments that this function accepts.
+    pub(crate) fn boxed_fn_args(&self, types: &TypeDeclarations) -> Vec<BridgedBoxedFn> {
+        let mut boxed_fns = vec![];
+
+        for param in &self.func.sig.inputs {
+            if let FnArg::Typed(pat_ty) = param {
+                if let Some(BridgedType::StdLib(StdLibType::BoxedFn(boxed_fn))) =
+                    BridgedType::new_with_type(&pat_ty.ty, types)
+                {
+                    boxed_fns.push(boxed_fn);
+                }
+            }
+        }
+
+        boxed_fns
+    }
+
+    /// The `[T; N]` arguments and return type of this function.
+    pub(crate) fn array_types(&self, types: &TypeDeclarations) -> Vec<BridgedArray> {
+        let mut arrays = vec![];
+
+        for param in &self.func.sig.inputs {
+            if let FnArg::Typed(pat_ty) = param {
+                if let Some(BridgedType::StdLib(StdLibType::Array(array))) =
+                    BridgedType::new_with_type(&pat_ty.ty, types)
+                {
+                    arrays.push(array);
+                }
+            }
+        }
+
+        if let Some(BridgedType::StdLib(StdLibType::Array(array))) = self.return_ty_built_in(types)
+        {
+            arrays.push(array);
+        }
+
+        arrays
+    }
+
+    /// The `(A, B)` arguments and return type of this function.
+    pub(crate) fn tuple_types(&self, types: &TypeDeclarations) -> Vec<BridgedTuple> {
+        let mut tuples = vec![];
+
+        for param in &self.func.sig.inputs {
+            if let FnArg::Typed(pat_ty) = param {
+                if let Some(BridgedType::StdLib(StdLibType::Tuple(tuple))) =
+                    BridgedType::new_with_type(&pat_ty.ty, types)
+                {
+                    tuples.push(tuple);
+                }
+            }
+        }
+
+        if let Some(BridgedType::StdLib(StdLibType::Tuple(tuple))) = self.return_ty_built_in(types)
+        {
+            tuples.push(tuple);
+        }
+
+        tuples
+    }
+
+    /// Whether or not any of this function's arguments or its return type is bridged to a
+    /// Foundation type, such as a `Duration` that becomes a `TimeInterval`, a `PathBuf` that
+    /// becomes a `URL` or a `Vec<u8>` that becomes `Data`.
+    pub(crate) fn uses_foundation(&self, types: &TypeDeclarations) -> bool {
+        let is_foundation_type = |ty: Option<BridgedType>| {
+            matches!(
+                ty,
+                Some(BridgedType::StdLib(
+                    StdLibType::Duration | StdLibType::DateTime | StdLibType::PathBuf
+                ))
+            ) || matches!(ty, Some(BridgedType::StdLib(StdLibType::Vec(vec))) if vec.as_data)
+        };
+
+        self.func
+            .sig
+            .inputs
+            .iter()
+            .any(|arg| is_foundation_type(BridgedType::new_with_fn_arg(arg, types)))
+            || is_foundation_type(self.return_ty_built_in(types))
+            || !self.args_as_data.is_empty()
+    }
 }
 
 impl ParsedExternFn {
-    pub fn link_name(&self) -> String {
+    /// The symbol that the function is exported or imported under, such as
+    /// `__swift_bridge__$SomeType$some_method`.
+    pub fn link_name(&self, symbol_prefix: &str) -> String {
+        if let Some(extern_swift_name) = &self.extern_swift_name {
+            return extern_swift_name.value();
+        }
+
         let host_type = self
             .associated_type
             .as_ref()
@@ -352,7 +837,7 @@ impl ParsedExternFn {
                         todo!()
                     }
                     TypeDeclaration::Opaque(h) => {
-                        format!("${}", h.to_string())
+                        format!("${}", h.link_name_segment())
                     }
                 }
             })
@@ -360,7 +845,7 @@ impl ParsedExternFn {
 
         format!(
             "{}{}${}",
-            SWIFT_BRIDGE_PREFIX,
+            symbol_prefix,
             host_type,
             self.func.sig.ident.to_string()
         )
@@ -377,7 +862,7 @@ impl ParsedExternFn {
                         todo!()
                     }
                     TypeDeclaration::Opaque(h) => {
-                        format!("{}_", h.to_token_stream().to_string())
+                        format!("{}_", h.ident_segment())
                     }
                 }
             })
@@ -397,6 +882,27 @@ impl ParsedExternFn {
     }
 }
 
+/// Option<SomeType> -> SomeType
+pub(crate) fn option_inner_type(ty: &Type) -> Option<&Type> {
+    let last = match ty {
+        Type::Path(path) => path.path.segments.last()?,
+        _ => return None,
+    };
+    if last.ident != "Option" {
+        return None;
+    }
+
+    match &last.arguments {
+        syn::PathArguments::AngleBracketed(args) if args.args.len() == 1 => {
+            match args.args.first()? {
+                syn::GenericArgument::Type(ty) => Some(ty),
+                _ => None,
+            }
+        }
+        _ => None,
+    }
+}
+
 impl Deref for ParsedExternFn {
     type Target = ForeignItemFn;
 
@@ -476,10 +982,10 @@ mod tests {
         let expected = quote! {
             #[export_name = "__swift_bridge__$some_function"]
             pub extern "C" fn __swift_bridge__some_function (
-                arg1: *mut swift_bridge::string::RustString
+                arg1: swift_bridge::string::FfiString
             ) -> *mut swift_bridge::string::RustString {
                 swift_bridge::string::RustString(super::some_function(
-                    unsafe { Box::from_raw(arg1).0 }
+                    arg1.into_string()
                 )).box_into_raw()
             }
         };
@@ -1,9 +1,10 @@
-use crate::bridged_type::BridgedType;
+use crate::bridged_type::{BridgedResult, BridgedType, BuiltInVec, StdLibType};
 use crate::parse::{HostLang, TypeDeclaration, TypeDeclarations};
-use crate::parsed_extern_fn::ParsedExternFn;
+use crate::parsed_extern_fn::{Getter, ParsedExternFn};
 use proc_macro2::{Ident, TokenStream};
 use quote::quote;
-use syn::Path;
+use std::ops::Deref;
+use syn::{Path, ReturnType, Type};
 
 impl ParsedExternFn {
     /// Generates:
@@ -25,9 +26,10 @@ impl ParsedExternFn {
     pub fn to_extern_c_function_tokens(
         &self,
         swift_bridge_path: &Path,
+        symbol_prefix: &str,
         types: &TypeDeclarations,
     ) -> TokenStream {
-        let link_name = self.link_name();
+        let link_name = self.link_name(symbol_prefix);
 
         let params = self.to_extern_c_param_names_and_types(swift_bridge_path, types);
 
@@ -122,16 +124,26 @@ impl ParsedExternFn {
             }
         };
 
-        let call_args = self.to_call_rust_args(swift_bridge_path, types);
-
-        let call_fn = quote! {
-            #fn_name ( #call_args )
-        };
-
-        let mut call_fn = if self.is_method() {
-            self.call_method_tokens(&call_fn)
+        let mut call_fn = if let Some(getter) = self.getter.filter(Getter::reads_field) {
+            self.get_field_tokens(getter, &fn_name)
         } else {
-            self.call_function_tokens(&call_fn)
+            let call_args = self.to_call_rust_args(swift_bridge_path, types);
+
+            let call_fn = quote! {
+                #fn_name ( #call_args )
+            };
+
+            let call_fn = if self.is_method() {
+                self.call_method_tokens(&call_fn)
+            } else {
+                self.call_function_tokens(&call_fn)
+            };
+
+            if self.sig.unsafety.is_some() {
+                quote! { unsafe { #call_fn } }
+            } else {
+                call_fn
+            }
         };
 
         let return_ty = self.return_ty_built_in(types).unwrap();
@@ -157,7 +169,22 @@ impl ParsedExternFn {
 
     /// Generate tokens for calling a method.
     fn call_method_tokens(&self, call_fn: &TokenStream) -> TokenStream {
-        let this = if let Some(reference) = self.self_reference() {
+        let this = if self.associated_type_is_copy() {
+            quote! {
+                this.into_rust_repr()
+            }
+        } else if self.associated_type_is_pinned()
+            && self.self_reference().is_some()
+            && self.self_mutability().is_some()
+        {
+            // Handing out a `&mut Type` would let the value be moved out from behind the `Pin`,
+            // so mutable methods receive a `Pin<&mut Type>` instead.
+            quote! {
+                (unsafe { &mut *this }).as_mut()
+            }
+        } else if self.is_mutex_guarded_method() {
+            return self.call_mutex_guarded_method_tokens(call_fn);
+        } else if let Some(reference) = self.self_reference() {
             let maybe_ref = reference.0;
             let maybe_mut = self.self_mutability();
 
@@ -175,6 +202,56 @@ impl ParsedExternFn {
         }
     }
 
+    /// Generate tokens for locking a `Mutex<Type>` and then calling a method on the guarded value.
+    ///
+    /// A poisoned lock becomes a `Box<dyn Error>` that gets thrown on the Swift side.
+    fn call_mutex_guarded_method_tokens(&self, call_fn: &TokenStream) -> TokenStream {
+        let guarded = if self.self_reference().is_none() {
+            quote! {
+                unsafe { Box::from_raw(this) }.into_inner().map(|this| this.#call_fn)
+            }
+        } else if self.self_mutability().is_some() {
+            quote! {
+                (unsafe { &*this }).lock().map(|mut this| this.#call_fn)
+            }
+        } else {
+            quote! {
+                (unsafe { &*this }).lock().map(|this| this.#call_fn)
+            }
+        };
+
+        quote! {
+            #guarded.map_err(|err| Box::<dyn std::error::Error>::from(err.to_string()))
+        }
+    }
+
+    /// Generate tokens for reading a field for a `#[swift_bridge(get)]` or
+    /// `#[swift_bridge(get(clone))]` getter.
+    fn get_field_tokens(&self, getter: Getter, field: &TokenStream) -> TokenStream {
+        let this = quote! {
+            (unsafe { & *this })
+        };
+
+        match getter {
+            Getter::Borrow => {
+                let returns_reference = matches!(
+                    &self.func.sig.output,
+                    ReturnType::Type(_, ty) if matches!(ty.deref(), Type::Reference(_))
+                );
+
+                if returns_reference {
+                    quote! { & #this.#field }
+                } else {
+                    quote! { #this.#field }
+                }
+            }
+            Getter::Clone => {
+                quote! { #this.#field.clone() }
+            }
+            Getter::Computed => unreachable!("Computed getters call their method."),
+        }
+    }
+
     /// Generate tokens for calling a freestanding or an associated function.
     fn call_function_tokens(&self, call_fn: &TokenStream) -> TokenStream {
         let maybe_associated_type = self.associated_type.as_ref().map(|ty| {
@@ -184,7 +261,7 @@ impl ParsedExternFn {
                     todo!()
                 }
                 TypeDeclaration::Opaque(ty) => {
-                    let ty = &ty.ty;
+                    let ty = ty.rust_expr_tokens();
                     quote! {#ty::}
                 }
             }
@@ -197,7 +274,29 @@ impl ParsedExternFn {
 
     /// If the functions return type is a BuiltInType, return it.
     pub(crate) fn return_ty_built_in(&self, types: &TypeDeclarations) -> Option<BridgedType> {
-        BridgedType::new_with_return_type(&self.func.sig.output, types)
+        let return_ty = BridgedType::new_with_return_type(&self.func.sig.output, types);
+
+        match return_ty {
+            Some(BridgedType::StdLib(StdLibType::Vec(vec))) if self.return_as_data => {
+                Some(BridgedType::StdLib(StdLibType::Vec(BuiltInVec {
+                    as_data: true,
+                    ..vec
+                })))
+            }
+            Some(BridgedType::StdLib(StdLibType::Vec(vec))) if self.return_as_array => {
+                Some(BridgedType::StdLib(StdLibType::Vec(BuiltInVec {
+                    as_array: true,
+                    ..vec
+                })))
+            }
+            Some(return_ty) if self.is_mutex_guarded_method() => {
+                Some(BridgedType::StdLib(StdLibType::Result(BridgedResult {
+                    ok_ty: Box::new(return_ty),
+                    err_ty: Box::new(BridgedType::StdLib(StdLibType::BoxedError)),
+                })))
+            }
+            return_ty => return_ty,
+        }
     }
 }
 
@@ -273,7 +372,11 @@ mod tests {
         let function = &module.functions[0];
 
         assert_tokens_eq(
-            &function.to_extern_c_function_tokens(&module.swift_bridge_path, &module.types),
+            &function.to_extern_c_function_tokens(
+                &module.swift_bridge_path,
+                &module.symbol_prefix,
+                &module.types,
+            ),
             &expected_fn,
         );
     }
@@ -17,7 +17,11 @@ impl ParsedExternFn {
             TypeDeclaration::Shared(_) => {
                 todo!()
             }
-            TypeDeclaration::Opaque(h) => &h.ty,
+            TypeDeclaration::Opaque(h) if h.copy.is_some() => {
+                let ffi_repr = h.copy_ffi_repr_tokens();
+                quote! { #ffi_repr }
+            }
+            TypeDeclaration::Opaque(h) => h.rust_ty_tokens(),
         });
         let mut params = vec![];
         let inputs = &self.func.sig.inputs;
@@ -26,7 +30,12 @@ impl ParsedExternFn {
                 FnArg::Receiver(_receiver) => match self.host_lang {
                     HostLang::Rust => {
                         let this = &host_type.as_ref().unwrap();
-                        let this = quote! { this: *mut super:: #this };
+                        let this = if self.associated_type_is_copy() {
+                            quote! { this: #this }
+                        } else {
+                            let ptr = self.self_ptr_kind();
+                            quote! { this: #ptr #this }
+                        };
                         params.push(this);
                     }
                     HostLang::Swift => {
@@ -51,7 +60,11 @@ impl ParsedExternFn {
                         }
                     } else if let Some(built_in) = BridgedType::new_with_type(&pat_ty.ty, types) {
                         let pat = &pat_ty.pat;
-                        let ty = built_in.to_ffi_compatible_rust_type(swift_bridge_path);
+                        let ty = if self.passes_arg_as_ffi_string(&built_in) {
+                            quote! { #swift_bridge_path::string::FfiString }
+                        } else {
+                            built_in.to_ffi_compatible_rust_type(swift_bridge_path)
+                        };
                         params.push(quote! { #pat: #ty});
                         continue;
                     } else {
@@ -84,9 +97,18 @@ impl ParsedExternFn {
                                 todo!("Shared enum to type name")
                             }
                             TypeDeclaration::Opaque(opaque) => {
-                                if opaque.host_lang.is_rust() {
+                                if opaque.copy.is_some() {
+                                    let ffi_repr = opaque.copy_ffi_repr_tokens();
+                                    quote! { #ffi_repr }
+                                } else if opaque.host_lang.is_rust() {
+                                    let ty = opaque.rust_ty_tokens();
+                                    let ptr = if pat_ty_is_self {
+                                        self.self_ptr_kind()
+                                    } else {
+                                        quote! { *mut }
+                                    };
                                     quote! {
-                                        *mut super::#bridged_type
+                                        #ptr #ty
                                     }
                                 } else {
                                     quote! {
@@ -121,8 +143,8 @@ impl ParsedExternFn {
                                     if opaque.host_lang.is_swift() {
                                         quote! { *mut std::ffi::c_void }
                                     } else {
-                                        let ty = &opaque.ty;
-                                        quote! { *mut super::#ty }
+                                        let ty = opaque.rust_ty_tokens();
+                                        quote! { *mut #ty }
                                     }
                                 }
                             }
@@ -132,12 +154,12 @@ impl ParsedExternFn {
                                     todo!("Add a test that hits this code path")
                                 }
                                 TypeDeclaration::Opaque(opaque) => {
-                                    let ty = &opaque.ty;
+                                    let ty = opaque.rust_ty_tokens();
 
                                     if opaque.host_lang.is_swift() {
                                         quote! { #ty }
                                     } else {
-                                        quote! { *mut super::#ty }
+                                        quote! { *mut #ty }
                                     }
                                 }
                             }
@@ -191,9 +213,9 @@ mod tests {
         }
     }
 
-    /// Verify that a String parameter gets turned into a *mut RustString
+    /// Verify that an owned String parameter gets turned into an FfiString
     #[test]
-    fn converts_string_param_to_ruststring_pointer() {
+    fn converts_string_param_to_ffi_string() {
         let tokens = quote! {
             #[swift_bridge::bridge]
             mod ffi {
@@ -202,7 +224,7 @@ mod tests {
                 }
             }
         };
-        let expected = quote! { s: *mut swift_bridge::string::RustString };
+        let expected = quote! { s: swift_bridge::string::FfiString };
         assert_params_eq(tokens, &expected);
     }
 
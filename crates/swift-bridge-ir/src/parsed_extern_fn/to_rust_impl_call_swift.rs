@@ -36,6 +36,7 @@ impl ParsedExternFn {
     ) -> TokenStream {
         let sig = &self.func.sig;
         let fn_name = &sig.ident;
+        let maybe_unsafe = &sig.unsafety;
 
         let ret = &sig.output;
 
@@ -70,7 +71,7 @@ impl ParsedExternFn {
         }
 
         quote! {
-            pub fn #fn_name(#params) #ret {
+            pub #maybe_unsafe fn #fn_name(#params) #ret {
                 #inner
             }
         }
@@ -116,12 +117,8 @@ impl ParsedExternFn {
                                         todo!("Add a test that hits this code path")
                                     }
                                     TypeDeclaration::Opaque(opaque) => {
-                                        let ty = &opaque.ty;
-                                        if opaque.host_lang.is_rust() {
-                                            quote! { #pat: super:: #ty}
-                                        } else {
-                                            quote! { #pat: #ty }
-                                        }
+                                        let ty = opaque.rust_ty_tokens();
+                                        quote! { #pat: #ty }
                                     }
                                 }
                             }
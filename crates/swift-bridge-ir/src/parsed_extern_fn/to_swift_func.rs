@@ -1,9 +1,9 @@
-use crate::bridged_type::{pat_type_pat_is_self, BridgedType, TypePosition};
+use crate::bridged_type::{pat_type_pat_is_self, BridgedType, StdLibType, TypePosition};
 use crate::parse::TypeDeclarations;
 use crate::parsed_extern_fn::ParsedExternFn;
 use quote::ToTokens;
 use std::ops::Deref;
-use syn::{FnArg, Path, ReturnType, Type};
+use syn::{FnArg, Lit, Path, ReturnType, Type};
 
 impl ParsedExternFn {
     pub fn to_swift_param_names_and_types(
@@ -14,6 +14,8 @@ impl ParsedExternFn {
         let mut params: Vec<String> = vec![];
 
         for arg in &self.func.sig.inputs {
+            let mut label = "_";
+
             let param = match arg {
                 FnArg::Receiver(_receiver) => {
                     if include_receiver_if_present {
@@ -33,17 +35,40 @@ impl ParsedExternFn {
 
                     let arg_name = pat_ty.pat.to_token_stream().to_string();
 
-                    let ty = if let Some(built_in) = BridgedType::new_with_type(&pat_ty.ty, types) {
-                        built_in.to_swift_type(TypePosition::FnArg(self.host_lang))
+                    // The `@_cdecl` functions that Rust calls never have labels. Labels for
+                    // extern "Swift" functions get used when we call the user's Swift function.
+                    if self.host_lang.is_rust() {
+                        if let Some(arg_label) = self.arg_labels.get(&arg_name) {
+                            label = arg_label.as_str();
+                        }
+                    }
+
+                    let type_pos = TypePosition::FnArg(self.host_lang);
+                    let ty = if self.args_as_data.contains(&arg_name) {
+                        "Data".to_string()
+                    } else if let Some(built_in) = BridgedType::new_with_type(&pat_ty.ty, types) {
+                        match built_in {
+                            BridgedType::StdLib(StdLibType::Vec(vec))
+                                if self.args_as_array.contains(&arg_name) =>
+                            {
+                                vec.to_swift_array_type(type_pos)
+                            }
+                            built_in => built_in.to_swift_type(type_pos),
+                        }
                     } else {
                         todo!("Push to ParsedErrors")
                     };
 
-                    format!("{}: {}", arg_name, ty)
+                    match self.arg_defaults.get(&arg_name) {
+                        Some(default) => {
+                            format!("{}: {} = {}", arg_name, ty, swift_default_value(default))
+                        }
+                        None => format!("{}: {}", arg_name, ty),
+                    }
                 }
             };
 
-            params.push(format!("_ {}", param))
+            params.push(format!("{} {}", label, param))
         }
 
         params.join(", ")
@@ -64,12 +89,28 @@ impl ParsedExternFn {
     ) -> String {
         let mut args = vec![];
         let inputs = &self.func.sig.inputs;
+
+        // Operator functions are static, so the receiver is the left hand side argument.
+        let ref_self_ptr = if self.swift_operator.is_some() {
+            "lhs.ptr"
+        } else {
+            "ptr"
+        };
+        // `Copy` types are passed by value, no matter how `self` is borrowed.
+        let copy_self = if self.swift_operator.is_some() {
+            "lhs.intoFfiRepr()"
+        } else {
+            "self.intoFfiRepr()"
+        };
+
         for arg in inputs {
             match arg {
                 FnArg::Receiver(receiver) => {
                     if include_receiver_if_present {
-                        let arg = if receiver.reference.is_some() {
-                            "ptr"
+                        let arg = if self.associated_type_is_copy() {
+                            copy_self
+                        } else if receiver.reference.is_some() {
+                            ref_self_ptr
                         } else {
                             "{isOwned = false; return ptr;}()"
                         };
@@ -85,8 +126,10 @@ impl ParsedExternFn {
 
                     if pat_type_pat_is_self(pat_ty) {
                         if include_receiver_if_present {
-                            let arg = if is_reference {
-                                "ptr"
+                            let arg = if self.associated_type_is_copy() {
+                                copy_self
+                            } else if is_reference {
+                                ref_self_ptr
                             } else {
                                 "{isOwned = false; return ptr;}()"
                             };
@@ -103,7 +146,18 @@ impl ParsedExternFn {
 
                     let arg =
                         if let Some(bridged_ty) = BridgedType::new_with_type(&pat_ty.ty, types) {
-                            if self.host_lang.is_rust() {
+                            if self.args_as_data.contains(&arg_name) {
+                                format!("{}AsBytes.toFfiSlice()", arg)
+                            } else if self.args_as_array.contains(&arg_name) {
+                                match &bridged_ty {
+                                    BridgedType::StdLib(StdLibType::Vec(vec)) => {
+                                        vec.convert_swift_array_to_ffi_value(&arg)
+                                    }
+                                    _ => unreachable!("Only Vecs can be passed as an Array"),
+                                }
+                            } else if self.passes_arg_as_ffi_string(&bridged_ty) {
+                                format!("{}AsFfiString", arg)
+                            } else if self.host_lang.is_rust() {
                                 bridged_ty.convert_swift_expression_to_ffi_compatible(
                                     &arg,
                                     TypePosition::FnArg(self.host_lang),
@@ -119,7 +173,11 @@ impl ParsedExternFn {
                         };
 
                     let arg = if include_var_name {
-                        format!("{}: {}", arg_name, arg)
+                        match self.arg_labels.get(&arg_name).map(|label| label.as_str()) {
+                            Some("_") => arg,
+                            Some(label) => format!("{}: {}", label, arg),
+                            None => format!("{}: {}", arg_name, arg),
+                        }
                     } else {
                         arg
                     };
@@ -134,9 +192,23 @@ impl ParsedExternFn {
 
     pub fn to_swift_return_type(&self, types: &TypeDeclarations) -> String {
         match &self.func.sig.output {
+            // A mutex guarded method throws if the lock was poisoned, even if it doesn't return
+            // anything.
+            ReturnType::Default if self.is_mutex_guarded_method() => " throws".to_string(),
             ReturnType::Default => "".to_string(),
-            ReturnType::Type(_, ty) => {
-                if let Some(built_in) = BridgedType::new_with_type(&ty, types) {
+            ReturnType::Type(..) => {
+                if let Some(built_in) = self.return_ty_built_in(types) {
+                    if let BridgedType::StdLib(StdLibType::Result(result)) = &built_in {
+                        if result.ok_ty.is_null() {
+                            return " throws".to_string();
+                        }
+
+                        return format!(
+                            " throws -> {}",
+                            built_in.to_swift_type(TypePosition::FnReturn(self.host_lang))
+                        );
+                    }
+
                     format!(
                         " -> {}",
                         built_in.to_swift_type(TypePosition::FnReturn(self.host_lang,))
@@ -149,6 +221,20 @@ impl ParsedExternFn {
     }
 }
 
+/// The Swift literal for an argument's default value.
+///
+/// `5u8` -> `5`, `"hello"` -> `"hello"`
+fn swift_default_value(default: &Lit) -> String {
+    match default {
+        Lit::Int(int) => int.base10_digits().to_string(),
+        Lit::Float(float) => float.base10_digits().to_string(),
+        Lit::Bool(bool) => bool.value.to_string(),
+        // Rust's escape sequences for strings are also valid in Swift.
+        Lit::Str(string) => format!("{:?}", string.value()),
+        _ => unreachable!("Unsupported default values are rejected when parsing."),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::parse::SwiftBridgeModuleAndErrors;
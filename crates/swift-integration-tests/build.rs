@@ -5,23 +5,59 @@ fn main() {
     let out_dir = PathBuf::from(out_dir);
 
     let bridges = vec![
+        "src/array.rs",
         "src/async_function.rs",
         "src/expose_opaque_rust_type.rs",
         "src/import_opaque_swift_class.rs",
         "src/bool.rs",
+        "src/boxed_error.rs",
+        "src/char.rs",
+        "src/closure.rs",
+        "src/duration.rs",
+        "src/generic_opaque_rust_type.rs",
+        "src/hashmap.rs",
+        "src/int128.rs",
+        "src/net.rs",
+        "src/non_zero.rs",
         "src/option.rs",
+        "src/path_buf.rs",
         "src/pointer.rs",
+        "src/range.rs",
+        "src/result.rs",
+        "src/set.rs",
         "src/string.rs",
         "src/vec.rs",
         "src/slice.rs",
+        "src/tuple.rs",
         "src/shared_types/shared_struct.rs",
         "src/shared_types/shared_enum.rs",
         "src/rust_function_uses_opaque_swift_type.rs",
         "src/swift_function_uses_opaque_rust_type.rs",
         "src/conditional_compilation.rs",
         "src/opaque_type_attributes/already_declared.rs",
+        "src/opaque_type_attributes/arc.rs",
+        "src/opaque_type_attributes/comparable.rs",
+        "src/opaque_type_attributes/conforms_to.rs",
+        "src/opaque_type_attributes/copy.rs",
+        "src/opaque_type_attributes/display.rs",
+        "src/opaque_type_attributes/expressible_by_string_literal.rs",
+        "src/opaque_type_attributes/mutex.rs",
+        "src/opaque_type_attributes/pin.rs",
+        "src/opaque_type_attributes/sendable.rs",
+        "src/opaque_type_attributes/trait_object.rs",
+        "src/function_attributes/constant.rs",
+        "src/function_attributes/get.rs",
         "src/function_attributes/identifiable.rs",
+        "src/function_attributes/iterator.rs",
+        "src/function_attributes/operator.rs",
+        "src/function_attributes/subscript.rs",
+        "src/function_attributes/swift_name.rs",
+        "src/function_attributes/swift_visibility.rs",
         "src/struct_attributes/already_declared.rs",
+        "src/struct_attributes/codable.rs",
+        "src/struct_attributes/equatable.rs",
+        "src/struct_attributes/identifiable.rs",
+        "src/struct_attributes/rename_all.rs",
         "src/struct_attributes/swift_name.rs",
     ];
     for path in &bridges {
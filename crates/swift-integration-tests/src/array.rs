@@ -0,0 +1,28 @@
+//! See also: crates/swift-bridge-ir/src/codegen/codegen_tests/array_codegen_tests.rs
+
+#[swift_bridge::bridge]
+mod ffi {
+    extern "Rust" {
+        fn rust_reflect_u8_array(arg: [u8; 4]) -> [u8; 4];
+        fn rust_reverse_f32_array(arg: [f32; 2]) -> [f32; 2];
+
+        fn run_array_test();
+    }
+
+    extern "Swift" {
+        #[swift_bridge(swift_name = "swiftReflectI32Array")]
+        fn swift_reflect_i32_array(arg: [i32; 3]) -> [i32; 3];
+    }
+}
+
+fn run_array_test() {
+    assert_eq!(ffi::swift_reflect_i32_array([1, 2, 3]), [1, 2, 3]);
+}
+
+fn rust_reflect_u8_array(arg: [u8; 4]) -> [u8; 4] {
+    arg
+}
+
+fn rust_reverse_f32_array(arg: [f32; 2]) -> [f32; 2] {
+    [arg[1], arg[0]]
+}
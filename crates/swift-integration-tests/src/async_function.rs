@@ -2,11 +2,20 @@
 mod ffi {
     struct AsyncRustFnReturnStruct;
 
+    #[swift_bridge(swift_repr = "struct")]
+    struct AsyncResultError {
+        code: u32,
+    }
+
     extern "Rust" {
         async fn rust_async_return_null();
         async fn rust_async_reflect_u8(arg: u8) -> u8;
         async fn rust_async_return_struct() -> AsyncRustFnReturnStruct;
 
+        async fn rust_async_func_returns_result_u8(succeed: bool) -> Result<u8, AsyncResultError>;
+        async fn rust_async_func_returns_result_null(succeed: bool)
+            -> Result<(), AsyncResultError>;
+        async fn rust_async_func_never_completes() -> Result<u8, AsyncResultError>;
     }
 
     extern "Rust" {
@@ -15,6 +24,22 @@ mod ffi {
         #[swift_bridg(init)]
         fn new() -> TestRustAsyncSelf;
         async fn reflect_u16(&self, arg: u16) -> u16;
+        async fn load_value(&self, value: u16) -> AsyncRustLoadedValue;
+    }
+
+    extern "Rust" {
+        type AsyncRustLoadedValue;
+
+        fn value(&self) -> u16;
+    }
+
+    extern "Rust" {
+        type AsyncRustCounter;
+
+        #[swift_bridge(init)]
+        fn new(limit: u32) -> AsyncRustCounter;
+        #[swift_bridge(AsyncIterator)]
+        async fn next(&mut self) -> Option<u32>;
     }
 }
 
@@ -28,6 +53,27 @@ async fn rust_async_return_struct() -> ffi::AsyncRustFnReturnStruct {
     ffi::AsyncRustFnReturnStruct
 }
 
+async fn rust_async_func_returns_result_u8(succeed: bool) -> Result<u8, ffi::AsyncResultError> {
+    if succeed {
+        Ok(123)
+    } else {
+        Err(ffi::AsyncResultError { code: 456 })
+    }
+}
+
+async fn rust_async_func_returns_result_null(succeed: bool) -> Result<(), ffi::AsyncResultError> {
+    if succeed {
+        Ok(())
+    } else {
+        Err(ffi::AsyncResultError { code: 789 })
+    }
+}
+
+/// Used to verify that cancelling the Swift task throws a `CancellationError`.
+async fn rust_async_func_never_completes() -> Result<u8, ffi::AsyncResultError> {
+    std::future::pending().await
+}
+
 pub struct TestRustAsyncSelf;
 
 impl TestRustAsyncSelf {
@@ -38,4 +84,38 @@ impl TestRustAsyncSelf {
     async fn reflect_u16(&self, arg: u16) -> u16 {
         arg
     }
+
+    async fn load_value(&self, value: u16) -> AsyncRustLoadedValue {
+        AsyncRustLoadedValue(value)
+    }
+}
+
+/// Returned from an async method to verify that Swift takes ownership of the opaque type.
+pub struct AsyncRustLoadedValue(u16);
+
+impl AsyncRustLoadedValue {
+    fn value(&self) -> u16 {
+        self.0
+    }
+}
+
+/// Used to verify that an async `next()` method gets exposed to Swift as an `AsyncSequence`.
+pub struct AsyncRustCounter {
+    count: u32,
+    limit: u32,
+}
+
+impl AsyncRustCounter {
+    fn new(limit: u32) -> Self {
+        AsyncRustCounter { count: 0, limit }
+    }
+
+    async fn next(&mut self) -> Option<u32> {
+        if self.count == self.limit {
+            return None;
+        }
+
+        self.count += 1;
+        Some(self.count)
+    }
 }
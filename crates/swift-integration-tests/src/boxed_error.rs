@@ -0,0 +1,22 @@
+//! See also: crates/swift-bridge-ir/src/codegen/codegen_tests/boxed_error_codegen_tests.rs
+
+#[swift_bridge::bridge]
+mod ffi {
+    extern "Rust" {
+        fn rust_func_returns_boxed_error() -> Box<dyn std::error::Error>;
+
+        fn rust_func_returns_option_boxed_error(fail: bool) -> Option<Box<dyn std::error::Error>>;
+    }
+}
+
+fn rust_func_returns_boxed_error() -> Box<dyn std::error::Error> {
+    "something went wrong".into()
+}
+
+fn rust_func_returns_option_boxed_error(fail: bool) -> Option<Box<dyn std::error::Error>> {
+    if fail {
+        Some(rust_func_returns_boxed_error())
+    } else {
+        None
+    }
+}
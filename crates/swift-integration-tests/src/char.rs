@@ -0,0 +1,25 @@
+//! See also: crates/swift-bridge-ir/src/codegen/codegen_tests/char_codegen_tests.rs
+
+#[swift_bridge::bridge]
+mod ffi {
+    extern "Rust" {
+        fn rust_reflect_char(arg: char) -> char;
+
+        fn run_char_test();
+    }
+
+    extern "Swift" {
+        #[swift_bridge(swift_name = "swiftReflectChar")]
+        fn swift_reflect_char(arg: char) -> char;
+    }
+}
+
+fn run_char_test() {
+    for c in ['a', 'é', '🦀', '\u{D7FF}', '\u{E000}', char::MAX] {
+        assert_eq!(ffi::swift_reflect_char(c), c);
+    }
+}
+
+fn rust_reflect_char(arg: char) -> char {
+    arg
+}
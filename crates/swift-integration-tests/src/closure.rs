@@ -0,0 +1,33 @@
+//! See also: crates/swift-bridge-ir/src/codegen/codegen_tests/closure_argument_codegen_tests.rs
+
+#[swift_bridge::bridge]
+mod ffi {
+    extern "Rust" {
+        fn rust_fn_calls_closure_no_args(callback: Box<dyn Fn()>);
+        fn rust_fn_calls_closure_one_arg(callback: Box<dyn Fn(u32)>);
+        fn rust_fn_calls_fn_mut_closure_multiple_args(callback: Box<dyn FnMut(u8, bool)>);
+        fn rust_fn_sums_borrowed_closure_results(callback: &dyn Fn(u32) -> u32) -> u32;
+        fn rust_fn_calls_closure_returning_string(callback: Box<dyn Fn(u8) -> String>) -> String;
+    }
+}
+
+fn rust_fn_calls_closure_no_args(callback: Box<dyn Fn()>) {
+    callback()
+}
+
+fn rust_fn_calls_closure_one_arg(callback: Box<dyn Fn(u32)>) {
+    callback(123)
+}
+
+fn rust_fn_calls_fn_mut_closure_multiple_args(mut callback: Box<dyn FnMut(u8, bool)>) {
+    callback(1, true);
+    callback(2, false);
+}
+
+fn rust_fn_sums_borrowed_closure_results(callback: &dyn Fn(u32) -> u32) -> u32 {
+    (1..=3).map(callback).sum()
+}
+
+fn rust_fn_calls_closure_returning_string(callback: Box<dyn Fn(u8) -> String>) -> String {
+    format!("{} {}", callback(1), callback(2))
+}
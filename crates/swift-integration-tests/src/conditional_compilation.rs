@@ -17,7 +17,26 @@ mod not_enabled {
     }
 }
 
+#[swift_bridge::bridge]
+mod per_function {
+    extern "Rust" {
+        // This function will be exposed since this "this_is_enabled" feature is on by default.
+        #[cfg(feature = "this_is_enabled")]
+        fn conditionally_exposed_fn_in_ungated_module() -> u8;
+
+        // This function isn't actually defined at `super::undefined_gated_fn`, but it doesn't
+        // matter since its shim won't be compiled.
+        #[cfg(feature = "this_is_not_enabled")]
+        fn undefined_gated_fn();
+    }
+}
+
 #[cfg(feature = "this_is_enabled")]
 fn conditionally_exposed_fn() -> u8 {
     123
 }
+
+#[cfg(feature = "this_is_enabled")]
+fn conditionally_exposed_fn_in_ungated_module() -> u8 {
+    45
+}
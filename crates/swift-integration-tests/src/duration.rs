@@ -0,0 +1,33 @@
+//! See also: crates/swift-bridge-ir/src/codegen/codegen_tests/duration_codegen_tests.rs
+
+use std::time::Duration;
+
+#[swift_bridge::bridge]
+mod ffi {
+    extern "Rust" {
+        fn rust_reflect_duration(arg: Duration) -> Duration;
+        fn rust_duration_from_parts(secs: u64, nanos: u32) -> Duration;
+
+        fn run_duration_test();
+    }
+
+    extern "Swift" {
+        #[swift_bridge(swift_name = "swiftReflectDuration")]
+        fn swift_reflect_duration(arg: Duration) -> Duration;
+    }
+}
+
+fn run_duration_test() {
+    assert_eq!(
+        ffi::swift_reflect_duration(Duration::from_millis(1500)),
+        Duration::from_millis(1500)
+    );
+}
+
+fn rust_reflect_duration(arg: Duration) -> Duration {
+    arg
+}
+
+fn rust_duration_from_parts(secs: u64, nanos: u32) -> Duration {
+    Duration::new(secs, nanos)
+}
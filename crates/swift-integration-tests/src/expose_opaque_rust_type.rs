@@ -1,11 +1,16 @@
 #[swift_bridge::bridge]
 mod ffi {
     extern "Rust" {
+        /// A stack of bytes that lives in Rust.
         type ARustStack;
 
         #[swift_bridge(init)]
         fn new() -> ARustStack;
 
+        /// Create a stack that already holds the given byte.
+        fn with_val(val: u8) -> Self;
+
+        /// Push a byte onto the top of the stack.
         fn push(&mut self, val: u8);
         fn pop(self: &mut ARustStack);
 
@@ -46,6 +51,10 @@ impl ARustStack {
         ARustStack { stack: vec![] }
     }
 
+    fn with_val(val: u8) -> Self {
+        ARustStack { stack: vec![val] }
+    }
+
     fn push(&mut self, val: u8) {
         self.stack.push(val);
     }
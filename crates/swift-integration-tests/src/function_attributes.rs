@@ -1,5 +1,12 @@
 mod args_into;
+mod constant;
+mod get;
 mod identifiable;
 mod into_return_type;
+mod iterator;
+mod operator;
 mod return_with;
 mod rust_name;
+mod subscript;
+mod swift_name;
+mod swift_visibility;
@@ -0,0 +1,50 @@
+//! Verify that associated functions with the `constant` attribute become `static let` constants
+//! on the Swift class.
+//!
+//! See also: crates/swift-bridge-ir/src/codegen/codegen_tests/extern_rust_method_swift_class_placement_codegen_tests.rs
+
+#[swift_bridge::bridge]
+mod ffi {
+    extern "Rust" {
+        type ConstantsHolder;
+
+        #[swift_bridge(associated_to = ConstantsHolder, constant, swift_name = "MAX")]
+        fn max() -> u32;
+
+        #[swift_bridge(associated_to = ConstantsHolder, constant)]
+        fn ratio() -> f64;
+
+        #[swift_bridge(associated_to = ConstantsHolder, constant)]
+        fn enabled() -> bool;
+
+        #[swift_bridge(associated_to = ConstantsHolder, constant)]
+        fn name() -> String;
+
+        #[swift_bridge(associated_to = ConstantsHolder, constant)]
+        fn greeting() -> &'static str;
+    }
+}
+
+pub struct ConstantsHolder;
+
+impl ConstantsHolder {
+    fn max() -> u32 {
+        123
+    }
+
+    fn ratio() -> f64 {
+        0.5
+    }
+
+    fn enabled() -> bool {
+        true
+    }
+
+    fn name() -> String {
+        "constants holder".to_string()
+    }
+
+    fn greeting() -> &'static str {
+        "hello"
+    }
+}
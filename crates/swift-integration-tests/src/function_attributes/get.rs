@@ -0,0 +1,51 @@
+//! Verify that the `get` and `get(clone)` attributes generate getters that read fields, and that
+//! the `get(computed)` attribute generates a computed property that calls a method.
+//!
+//! See also: crates/swift-bridge-ir/src/codegen/codegen_tests/function_attribute_codegen_tests.rs
+
+#[swift_bridge::bridge]
+mod ffi {
+    extern "Rust" {
+        type GetterHolder;
+
+        #[swift_bridge(init)]
+        fn new() -> GetterHolder;
+
+        #[swift_bridge(get)]
+        fn number(&self) -> u32;
+
+        #[swift_bridge(get)]
+        fn borrowed_text(&self) -> &str;
+
+        #[swift_bridge(get(clone))]
+        fn cloned_text(&self) -> String;
+
+        #[swift_bridge(get(clone))]
+        fn cloned_vec(&self) -> Vec<u8>;
+
+        #[swift_bridge(get(computed))]
+        fn is_empty(&self) -> bool;
+    }
+}
+
+pub struct GetterHolder {
+    number: u32,
+    borrowed_text: String,
+    cloned_text: String,
+    cloned_vec: Vec<u8>,
+}
+
+impl GetterHolder {
+    fn new() -> Self {
+        GetterHolder {
+            number: 123,
+            borrowed_text: "borrowed".to_string(),
+            cloned_text: "cloned".to_string(),
+            cloned_vec: vec![1, 2, 3],
+        }
+    }
+
+    fn is_empty(&self) -> bool {
+        self.cloned_vec.is_empty()
+    }
+}
@@ -24,6 +24,10 @@ mod ffi {
         // shared struct.
         #[swift_bridge(into_return_type)]
         fn get_already_declared_struct() -> AlreadyDeclaredStruct;
+
+        // Verify that our code compiles when we use the `return_into` spelling on a primitive.
+        #[swift_bridge(return_into)]
+        fn get_widened_u64() -> u64;
     }
 }
 #[swift_bridge::bridge]
@@ -47,6 +51,10 @@ fn get_already_declared_struct() -> SomeType {
     SomeType
 }
 
+fn get_widened_u64() -> u32 {
+    123
+}
+
 impl Into<SomeType> for AnotherType {
     fn into(self) -> SomeType {
         SomeType
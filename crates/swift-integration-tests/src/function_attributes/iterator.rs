@@ -0,0 +1,60 @@
+#[swift_bridge::bridge]
+mod ffi {
+    extern "Rust" {
+        type IteratorOfPrimitives;
+
+        #[swift_bridge(init)]
+        fn new(count: u32) -> IteratorOfPrimitives;
+
+        #[swift_bridge(Iterator)]
+        fn next(&mut self) -> Option<u32>;
+    }
+
+    extern "Rust" {
+        type IteratorItem;
+
+        fn value(&self) -> u32;
+    }
+
+    extern "Rust" {
+        type IteratorOfOpaqueRustTypes;
+
+        #[swift_bridge(init)]
+        fn new(count: u32) -> IteratorOfOpaqueRustTypes;
+
+        #[swift_bridge(Iterator)]
+        fn next_item(&mut self) -> Option<IteratorItem>;
+    }
+}
+
+pub struct IteratorOfPrimitives(std::ops::Range<u32>);
+
+impl IteratorOfPrimitives {
+    fn new(count: u32) -> Self {
+        IteratorOfPrimitives(0..count)
+    }
+
+    fn next(&mut self) -> Option<u32> {
+        self.0.next()
+    }
+}
+
+pub struct IteratorOfOpaqueRustTypes(std::ops::Range<u32>);
+
+impl IteratorOfOpaqueRustTypes {
+    fn new(count: u32) -> Self {
+        IteratorOfOpaqueRustTypes(0..count)
+    }
+
+    fn next_item(&mut self) -> Option<IteratorItem> {
+        self.0.next().map(IteratorItem)
+    }
+}
+
+pub struct IteratorItem(u32);
+
+impl IteratorItem {
+    fn value(&self) -> u32 {
+        self.0
+    }
+}
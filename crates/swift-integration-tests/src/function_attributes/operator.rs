@@ -0,0 +1,67 @@
+//! Verify that methods with the `operator` attribute become Swift operators.
+//!
+//! See also: crates/swift-bridge-ir/src/codegen/codegen_tests/function_attribute_codegen_tests.rs
+
+#[swift_bridge::bridge]
+mod ffi {
+    extern "Rust" {
+        type OperatorVector;
+
+        #[swift_bridge(init)]
+        fn new(x: f32, y: f32) -> OperatorVector;
+
+        fn x(&self) -> f32;
+        fn y(&self) -> f32;
+
+        #[swift_bridge(operator = "+")]
+        fn add(&self, rhs: &OperatorVector) -> OperatorVector;
+
+        #[swift_bridge(operator = "-")]
+        fn sub(&self, rhs: &OperatorVector) -> OperatorVector;
+
+        #[swift_bridge(operator = "*")]
+        fn mul(&self, rhs: f32) -> OperatorVector;
+
+        #[swift_bridge(operator = "/")]
+        fn div(&self, rhs: f32) -> OperatorVector;
+
+        #[swift_bridge(operator = "==")]
+        fn eq(&self, rhs: &OperatorVector) -> bool;
+    }
+}
+
+#[derive(PartialEq)]
+pub struct OperatorVector {
+    x: f32,
+    y: f32,
+}
+
+impl OperatorVector {
+    fn new(x: f32, y: f32) -> Self {
+        OperatorVector { x, y }
+    }
+
+    fn x(&self) -> f32 {
+        self.x
+    }
+
+    fn y(&self) -> f32 {
+        self.y
+    }
+
+    fn add(&self, rhs: &OperatorVector) -> OperatorVector {
+        OperatorVector::new(self.x + rhs.x, self.y + rhs.y)
+    }
+
+    fn sub(&self, rhs: &OperatorVector) -> OperatorVector {
+        OperatorVector::new(self.x - rhs.x, self.y - rhs.y)
+    }
+
+    fn mul(&self, rhs: f32) -> OperatorVector {
+        OperatorVector::new(self.x * rhs, self.y * rhs)
+    }
+
+    fn div(&self, rhs: f32) -> OperatorVector {
+        OperatorVector::new(self.x / rhs, self.y / rhs)
+    }
+}
@@ -0,0 +1,37 @@
+//! Verify that methods with the `subscript` attribute become Swift subscripts.
+//!
+//! See also: crates/swift-bridge-ir/src/codegen/codegen_tests/function_attribute_codegen_tests.rs
+
+#[swift_bridge::bridge]
+mod ffi {
+    extern "Rust" {
+        type SubscriptGrid;
+
+        #[swift_bridge(init)]
+        fn new(columns: usize) -> SubscriptGrid;
+
+        #[swift_bridge(subscript)]
+        fn get(&self, index: usize) -> u32;
+
+        #[swift_bridge(subscript)]
+        fn cell(&self, row: usize, column: usize) -> u32;
+    }
+}
+
+pub struct SubscriptGrid {
+    columns: usize,
+}
+
+impl SubscriptGrid {
+    fn new(columns: usize) -> Self {
+        SubscriptGrid { columns }
+    }
+
+    fn get(&self, index: usize) -> u32 {
+        index as u32 * 10
+    }
+
+    fn cell(&self, row: usize, column: usize) -> u32 {
+        self.get(row * self.columns + column)
+    }
+}
@@ -0,0 +1,31 @@
+#[swift_bridge::bridge]
+mod ffi {
+    extern "Rust" {
+        type SwiftNameOpaqueType;
+
+        #[swift_bridge(init)]
+        fn new() -> SwiftNameOpaqueType;
+
+        #[swift_bridge(swift_name = "rustFnWithSwiftName")]
+        fn rust_fn_with_swift_name() -> u8;
+
+        #[swift_bridge(swift_name = "methodWithSwiftName")]
+        fn method_with_swift_name(&self, arg: u8) -> u8;
+    }
+}
+
+fn rust_fn_with_swift_name() -> u8 {
+    10
+}
+
+pub struct SwiftNameOpaqueType;
+
+impl SwiftNameOpaqueType {
+    fn new() -> Self {
+        SwiftNameOpaqueType
+    }
+
+    fn method_with_swift_name(&self, arg: u8) -> u8 {
+        arg + 1
+    }
+}
@@ -0,0 +1,39 @@
+#[swift_bridge::bridge]
+mod ffi {
+    extern "Rust" {
+        #[swift_bridge(swift_visibility = "internal")]
+        type SwiftVisibilityOpaqueType;
+
+        #[swift_bridge(init)]
+        fn new() -> SwiftVisibilityOpaqueType;
+
+        #[swift_bridge(swift_visibility = "fileprivate")]
+        fn rust_fn_with_fileprivate_visibility() -> u8;
+
+        #[swift_bridge(swift_visibility = "internal")]
+        fn rust_fn_with_internal_visibility() -> u8;
+
+        #[swift_bridge(swift_visibility = "internal")]
+        fn method_with_internal_visibility(&self, arg: u8) -> u8;
+    }
+}
+
+fn rust_fn_with_fileprivate_visibility() -> u8 {
+    5
+}
+
+fn rust_fn_with_internal_visibility() -> u8 {
+    10
+}
+
+pub struct SwiftVisibilityOpaqueType;
+
+impl SwiftVisibilityOpaqueType {
+    fn new() -> Self {
+        SwiftVisibilityOpaqueType
+    }
+
+    fn method_with_internal_visibility(&self, arg: u8) -> u8 {
+        arg + 1
+    }
+}
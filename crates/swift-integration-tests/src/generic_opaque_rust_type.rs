@@ -0,0 +1,30 @@
+//! See also: crates/swift-bridge-ir/src/codegen/codegen_tests/generic_opaque_rust_type_codegen_tests.rs
+
+#[swift_bridge::bridge]
+mod ffi {
+    extern "Rust" {
+        type GenericWrapper<T>;
+
+        fn new_generic_wrapper_u32(val: u32) -> GenericWrapper<u32>;
+        fn new_generic_wrapper_bool(val: bool) -> GenericWrapper<bool>;
+
+        fn get(self: &GenericWrapper<u32>) -> u32;
+        fn get(self: &GenericWrapper<bool>) -> bool;
+    }
+}
+
+pub struct GenericWrapper<T>(T);
+
+impl<T: Copy> GenericWrapper<T> {
+    fn get(&self) -> T {
+        self.0
+    }
+}
+
+fn new_generic_wrapper_u32(val: u32) -> GenericWrapper<u32> {
+    GenericWrapper(val)
+}
+
+fn new_generic_wrapper_bool(val: bool) -> GenericWrapper<bool> {
+    GenericWrapper(val)
+}
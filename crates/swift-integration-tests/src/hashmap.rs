@@ -0,0 +1,42 @@
+use std::collections::{BTreeMap, HashMap};
+
+#[swift_bridge::bridge]
+mod ffi {
+    extern "Rust" {
+        fn rust_reflect_hashmap_string_string(
+            arg: HashMap<String, String>,
+        ) -> HashMap<String, String>;
+
+        fn rust_reflect_hashmap_u32_f64(arg: HashMap<u32, f64>) -> HashMap<u32, f64>;
+
+        fn rust_return_empty_hashmap() -> HashMap<String, u8>;
+
+        fn rust_reflect_btreemap_string_i32(arg: BTreeMap<String, i32>) -> BTreeMap<String, i32>;
+
+        fn rust_return_btreemap_in_sorted_order() -> BTreeMap<u8, String>;
+    }
+}
+
+fn rust_reflect_hashmap_string_string(arg: HashMap<String, String>) -> HashMap<String, String> {
+    arg
+}
+
+fn rust_reflect_hashmap_u32_f64(arg: HashMap<u32, f64>) -> HashMap<u32, f64> {
+    arg
+}
+
+fn rust_return_empty_hashmap() -> HashMap<String, u8> {
+    HashMap::new()
+}
+
+fn rust_reflect_btreemap_string_i32(arg: BTreeMap<String, i32>) -> BTreeMap<String, i32> {
+    arg
+}
+
+fn rust_return_btreemap_in_sorted_order() -> BTreeMap<u8, String> {
+    let mut map = BTreeMap::new();
+    map.insert(3, "three".to_string());
+    map.insert(1, "one".to_string());
+    map.insert(2, "two".to_string());
+    map
+}
@@ -14,13 +14,22 @@ mod ffi {
         fn as_slice(&self) -> &[u8];
     }
 
+    extern "Swift" {
+        type ASwiftCounter: SwiftCountable;
+
+        #[swift_bridge(init)]
+        fn new(start: u32) -> ASwiftCounter;
+
+        fn count(&self) -> u32;
+    }
+
     extern "Rust" {
         fn run_opaque_swift_class_tests();
     }
 }
 
 fn run_opaque_swift_class_tests() {
-    use ffi::ASwiftStack;
+    use ffi::{ASwiftCounter, ASwiftStack};
     use std::ptr::slice_from_raw_parts;
 
     let mut stack = ASwiftStack::new();
@@ -40,4 +49,7 @@ fn run_opaque_swift_class_tests() {
 
     stack.pop();
     assert_eq!(stack.len(), 1);
+
+    let counter = ASwiftCounter::new(3);
+    assert_eq!(counter.count(), 3);
 }
@@ -0,0 +1,45 @@
+//! See also: crates/swift-bridge-ir/src/codegen/codegen_tests/int128_codegen_tests.rs
+
+#[swift_bridge::bridge]
+mod ffi {
+    extern "Rust" {
+        fn rust_reflect_u128(arg: u128) -> u128;
+        fn rust_reflect_i128(arg: i128) -> i128;
+        fn rust_u128_max() -> u128;
+        fn rust_i128_min() -> i128;
+
+        fn run_int128_test();
+    }
+
+    extern "Swift" {
+        #[swift_bridge(swift_name = "swiftReflectU128")]
+        fn swift_reflect_u128(arg: u128) -> u128;
+        #[swift_bridge(swift_name = "swiftReflectI128")]
+        fn swift_reflect_i128(arg: i128) -> i128;
+    }
+}
+
+fn run_int128_test() {
+    for val in [0, 1, u64::MAX as u128, u64::MAX as u128 + 1, u128::MAX] {
+        assert_eq!(ffi::swift_reflect_u128(val), val);
+    }
+    for val in [0, -1, i64::MIN as i128 - 1, i128::MIN, i128::MAX] {
+        assert_eq!(ffi::swift_reflect_i128(val), val);
+    }
+}
+
+fn rust_reflect_u128(arg: u128) -> u128 {
+    arg
+}
+
+fn rust_reflect_i128(arg: i128) -> i128 {
+    arg
+}
+
+fn rust_u128_max() -> u128 {
+    u128::MAX
+}
+
+fn rust_i128_min() -> i128 {
+    i128::MIN
+}
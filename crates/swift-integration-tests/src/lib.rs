@@ -1,16 +1,31 @@
 mod expose_opaque_rust_type;
 mod import_opaque_swift_class;
 
+mod array;
 mod async_function;
 mod bool;
+mod boxed_error;
+mod char;
+mod closure;
 mod conditional_compilation;
+mod duration;
+mod generic_opaque_rust_type;
+mod hashmap;
+mod int128;
+mod net;
+mod non_zero;
 mod option;
+mod path_buf;
 mod pointer;
+mod range;
+mod result;
 mod rust_function_uses_opaque_swift_type;
+mod set;
 mod shared_types;
 mod slice;
 mod string;
 mod swift_function_uses_opaque_rust_type;
+mod tuple;
 mod vec;
 
 mod function_attributes;
@@ -0,0 +1,49 @@
+//! See also: crates/swift-bridge-ir/src/codegen/codegen_tests/net_codegen_tests.rs
+
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr};
+
+#[swift_bridge::bridge]
+mod ffi {
+    extern "Rust" {
+        fn rust_reflect_ip_addr(arg: IpAddr) -> IpAddr;
+        fn rust_reflect_socket_addr(arg: SocketAddr) -> SocketAddr;
+        fn rust_localhost_v4() -> IpAddr;
+        fn rust_localhost_v6_socket_addr(port: u16) -> SocketAddr;
+
+        fn run_net_test();
+    }
+
+    extern "Swift" {
+        #[swift_bridge(swift_name = "swiftReflectIpAddr")]
+        fn swift_reflect_ip_addr(arg: IpAddr) -> IpAddr;
+        #[swift_bridge(swift_name = "swiftReflectSocketAddr")]
+        fn swift_reflect_socket_addr(arg: SocketAddr) -> SocketAddr;
+    }
+}
+
+fn run_net_test() {
+    let v4 = IpAddr::V4(Ipv4Addr::new(192, 168, 0, 1));
+    assert_eq!(ffi::swift_reflect_ip_addr(v4), v4);
+
+    let v6 = IpAddr::V6(Ipv6Addr::new(0x2001, 0xdb8, 0, 0, 0, 0, 0, 1));
+    assert_eq!(ffi::swift_reflect_ip_addr(v6), v6);
+
+    let socket_addr = SocketAddr::new(v6, 8080);
+    assert_eq!(ffi::swift_reflect_socket_addr(socket_addr), socket_addr);
+}
+
+fn rust_reflect_ip_addr(arg: IpAddr) -> IpAddr {
+    arg
+}
+
+fn rust_reflect_socket_addr(arg: SocketAddr) -> SocketAddr {
+    arg
+}
+
+fn rust_localhost_v4() -> IpAddr {
+    IpAddr::V4(Ipv4Addr::LOCALHOST)
+}
+
+fn rust_localhost_v6_socket_addr(port: u16) -> SocketAddr {
+    SocketAddr::new(IpAddr::V6(Ipv6Addr::LOCALHOST), port)
+}
@@ -0,0 +1,24 @@
+//! See also: crates/swift-bridge-ir/src/codegen/codegen_tests/non_zero_codegen_tests.rs
+
+use std::num::{NonZeroI64, NonZeroU32};
+
+#[swift_bridge::bridge]
+mod ffi {
+    extern "Rust" {
+        fn rust_reflect_non_zero_u32(arg: NonZeroU32) -> NonZeroU32;
+        fn rust_reflect_non_zero_i64(arg: NonZeroI64) -> NonZeroI64;
+        fn rust_reflect_option_non_zero_u32(arg: Option<NonZeroU32>) -> Option<NonZeroU32>;
+    }
+}
+
+fn rust_reflect_non_zero_u32(arg: NonZeroU32) -> NonZeroU32 {
+    arg
+}
+
+fn rust_reflect_non_zero_i64(arg: NonZeroI64) -> NonZeroI64 {
+    arg
+}
+
+fn rust_reflect_option_non_zero_u32(arg: Option<NonZeroU32>) -> Option<NonZeroU32> {
+    arg
+}
@@ -1 +1,11 @@
 mod already_declared;
+mod arc;
+mod comparable;
+mod conforms_to;
+mod copy;
+mod display;
+mod expressible_by_string_literal;
+mod mutex;
+mod pin;
+mod sendable;
+mod trait_object;
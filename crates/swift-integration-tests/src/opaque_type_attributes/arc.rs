@@ -0,0 +1,45 @@
+//! Verify that an `Arc`-backed opaque Rust type can be copied in Swift, with the copy sharing
+//! ownership of the same Rust value.
+//!
+//! See also: crates/swift-bridge-ir/src/codegen/codegen_tests/arc_codegen_tests.rs
+
+use std::sync::atomic::{AtomicU32, Ordering};
+use std::sync::Arc;
+
+#[swift_bridge::bridge]
+mod ffi {
+    extern "Rust" {
+        #[swift_bridge(Arc)]
+        type ArcCounter;
+
+        fn increment(&self) -> u32;
+
+        fn new_arc_counter() -> Arc<ArcCounter>;
+        fn arc_counter_strong_count(counter: &Arc<ArcCounter>) -> usize;
+        fn arc_counter_value(counter: Arc<ArcCounter>) -> u32;
+    }
+}
+
+pub struct ArcCounter {
+    count: AtomicU32,
+}
+
+impl ArcCounter {
+    fn increment(&self) -> u32 {
+        self.count.fetch_add(1, Ordering::SeqCst) + 1
+    }
+}
+
+fn new_arc_counter() -> Arc<ArcCounter> {
+    Arc::new(ArcCounter {
+        count: AtomicU32::new(0),
+    })
+}
+
+fn arc_counter_strong_count(counter: &Arc<ArcCounter>) -> usize {
+    Arc::strong_count(counter)
+}
+
+fn arc_counter_value(counter: Arc<ArcCounter>) -> u32 {
+    counter.count.load(Ordering::SeqCst)
+}
@@ -0,0 +1,24 @@
+//! Verify that an opaque Rust type's `Ord` implementation becomes its Swift `Comparable`
+//! conformance.
+//!
+//! See also: crates/swift-bridge-ir/src/codegen/codegen_tests/comparable_attribute_codegen_tests.rs
+
+#[swift_bridge::bridge]
+mod ffi {
+    extern "Rust" {
+        #[swift_bridge(Comparable)]
+        type ComparableVersion;
+
+        fn new_comparable_version(major: u32, minor: u32) -> ComparableVersion;
+    }
+}
+
+#[derive(PartialEq, Eq, PartialOrd, Ord)]
+pub struct ComparableVersion {
+    major: u32,
+    minor: u32,
+}
+
+fn new_comparable_version(major: u32, minor: u32) -> ComparableVersion {
+    ComparableVersion { major, minor }
+}
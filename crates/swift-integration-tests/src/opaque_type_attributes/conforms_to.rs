@@ -0,0 +1,54 @@
+//! Verify that opaque Rust types can conform to protocols that are declared in Swift.
+//!
+//! See also: crates/swift-bridge-ir/src/codegen/codegen_tests/conforms_to_attribute_codegen_tests.rs
+//! The protocols are declared in SwiftRustIntegrationTestRunner/OpaqueTypeAttributes.swift
+
+#[swift_bridge::bridge]
+mod ffi {
+    extern "Rust" {
+        #[swift_bridge(conforms_to = ConformsToShape)]
+        type ConformsToCircle;
+
+        #[swift_bridge(conforms_to = (ConformsToShape, ConformsToNamed))]
+        type ConformsToSquare;
+
+        fn new_conforms_to_circle(radius: f64) -> ConformsToCircle;
+        fn new_conforms_to_square(side: f64) -> ConformsToSquare;
+
+        fn area(self: &ConformsToCircle) -> f64;
+        fn area(self: &ConformsToSquare) -> f64;
+        fn name(self: &ConformsToSquare) -> String;
+    }
+}
+
+pub struct ConformsToCircle {
+    radius: f64,
+}
+
+pub struct ConformsToSquare {
+    side: f64,
+}
+
+fn new_conforms_to_circle(radius: f64) -> ConformsToCircle {
+    ConformsToCircle { radius }
+}
+
+fn new_conforms_to_square(side: f64) -> ConformsToSquare {
+    ConformsToSquare { side }
+}
+
+impl ConformsToCircle {
+    fn area(&self) -> f64 {
+        std::f64::consts::PI * self.radius * self.radius
+    }
+}
+
+impl ConformsToSquare {
+    fn area(&self) -> f64 {
+        self.side * self.side
+    }
+
+    fn name(&self) -> String {
+        "square".to_string()
+    }
+}
@@ -0,0 +1,51 @@
+//! Verify that a `Copy` opaque Rust type gets passed between Swift and Rust by value.
+//!
+//! See also: crates/swift-bridge-ir/src/codegen/codegen_tests/copy_attribute_codegen_tests.rs
+
+#[swift_bridge::bridge]
+mod ffi {
+    extern "Rust" {
+        #[swift_bridge(Copy(16))]
+        type CopyPoint;
+
+        #[swift_bridge(init)]
+        fn new(x: f64, y: f64) -> CopyPoint;
+
+        fn x(&self) -> f64;
+        fn y(&self) -> f64;
+        fn translated(self, dx: f64, dy: f64) -> CopyPoint;
+
+        fn copy_point_distance(a: &CopyPoint, b: CopyPoint) -> f64;
+    }
+}
+
+#[derive(Copy, Clone)]
+pub struct CopyPoint {
+    x: f64,
+    y: f64,
+}
+
+impl CopyPoint {
+    fn new(x: f64, y: f64) -> Self {
+        CopyPoint { x, y }
+    }
+
+    fn x(&self) -> f64 {
+        self.x
+    }
+
+    fn y(&self) -> f64 {
+        self.y
+    }
+
+    fn translated(self, dx: f64, dy: f64) -> CopyPoint {
+        CopyPoint {
+            x: self.x + dx,
+            y: self.y + dy,
+        }
+    }
+}
+
+fn copy_point_distance(a: &CopyPoint, b: CopyPoint) -> f64 {
+    ((a.x - b.x).powi(2) + (a.y - b.y).powi(2)).sqrt()
+}
@@ -0,0 +1,29 @@
+//! Verify that an opaque Rust type's `Display` implementation becomes its Swift `description`.
+//!
+//! See also: crates/swift-bridge-ir/src/codegen/codegen_tests/display_attribute_codegen_tests.rs
+
+use std::fmt::{Display, Formatter};
+
+#[swift_bridge::bridge]
+mod ffi {
+    extern "Rust" {
+        #[swift_bridge(Display)]
+        type DisplayMoney;
+
+        fn new_display_money(cents: u64) -> DisplayMoney;
+    }
+}
+
+pub struct DisplayMoney {
+    cents: u64,
+}
+
+impl Display for DisplayMoney {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "${}.{:02}", self.cents / 100, self.cents % 100)
+    }
+}
+
+fn new_display_money(cents: u64) -> DisplayMoney {
+    DisplayMoney { cents }
+}
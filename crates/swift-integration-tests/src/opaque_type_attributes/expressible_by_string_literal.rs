@@ -0,0 +1,29 @@
+//! Verify that an opaque Rust type can be created from a Swift string literal.
+//!
+//! See also:
+//! crates/swift-bridge-ir/src/codegen/codegen_tests/expressible_by_string_literal_attribute_codegen_tests.rs
+
+#[swift_bridge::bridge]
+mod ffi {
+    extern "Rust" {
+        #[swift_bridge(ExpressibleByStringLiteral)]
+        type LiteralUsername;
+
+        #[swift_bridge(init)]
+        fn new(name: String) -> LiteralUsername;
+
+        fn name(&self) -> &str;
+    }
+}
+
+pub struct LiteralUsername(String);
+
+impl LiteralUsername {
+    fn new(name: String) -> Self {
+        LiteralUsername(name)
+    }
+
+    fn name(&self) -> &str {
+        &self.0
+    }
+}
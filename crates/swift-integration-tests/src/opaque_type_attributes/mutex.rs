@@ -0,0 +1,52 @@
+//! Verify that the methods of a mutex guarded opaque Rust type lock the mutex, and that a
+//! poisoned lock gets thrown as an error.
+//!
+//! See also: crates/swift-bridge-ir/src/codegen/codegen_tests/mutex_codegen_tests.rs
+
+use std::sync::Mutex;
+
+#[swift_bridge::bridge]
+mod ffi {
+    extern "Rust" {
+        #[swift_bridge(Mutex)]
+        type MutexCounter;
+
+        #[swift_bridge(init)]
+        fn new() -> Mutex<MutexCounter>;
+
+        fn increment(&mut self);
+        fn count(&self) -> u32;
+
+        fn poison_mutex_counter(counter: &Mutex<MutexCounter>);
+    }
+}
+
+pub struct MutexCounter {
+    count: u32,
+}
+
+impl MutexCounter {
+    fn new() -> Mutex<MutexCounter> {
+        Mutex::new(MutexCounter { count: 0 })
+    }
+
+    fn increment(&mut self) {
+        self.count += 1;
+    }
+
+    fn count(&self) -> u32 {
+        self.count
+    }
+}
+
+/// Poison the mutex by panicking on another thread while it holds the lock.
+fn poison_mutex_counter(counter: &Mutex<MutexCounter>) {
+    std::thread::scope(|scope| {
+        let _ = scope
+            .spawn(|| {
+                let _guard = counter.lock();
+                panic!("Poisoning the mutex");
+            })
+            .join();
+    });
+}
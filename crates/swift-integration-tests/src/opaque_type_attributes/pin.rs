@@ -0,0 +1,58 @@
+//! Verify that a pinned opaque Rust type can be created and mutated from Swift without the
+//! Rust value ever moving.
+//!
+//! See also: crates/swift-bridge-ir/src/codegen/codegen_tests/pin_codegen_tests.rs
+
+use std::marker::PhantomPinned;
+use std::pin::Pin;
+
+#[swift_bridge::bridge]
+mod ffi {
+    extern "Rust" {
+        #[swift_bridge(Pin)]
+        type PinnedCounter;
+
+        #[swift_bridge(init)]
+        fn new() -> Pin<Box<PinnedCounter>>;
+
+        fn increment(&mut self);
+        fn count(&self) -> u32;
+        fn has_not_moved(&self) -> bool;
+    }
+}
+
+/// A self-referential type that stores its own address, so it can tell whether it was moved.
+pub struct PinnedCounter {
+    count: u32,
+    address: *const PinnedCounter,
+    _pinned: PhantomPinned,
+}
+
+impl PinnedCounter {
+    fn new() -> Pin<Box<PinnedCounter>> {
+        let mut counter = Box::pin(PinnedCounter {
+            count: 0,
+            address: std::ptr::null(),
+            _pinned: PhantomPinned,
+        });
+
+        let address: *const PinnedCounter = &*counter;
+        // SAFETY: We only write to a field, we never move the counter.
+        unsafe { counter.as_mut().get_unchecked_mut() }.address = address;
+
+        counter
+    }
+
+    fn increment(self: Pin<&mut Self>) {
+        // SAFETY: `count` is not structurally pinned.
+        unsafe { self.get_unchecked_mut() }.count += 1;
+    }
+
+    fn count(&self) -> u32 {
+        self.count
+    }
+
+    fn has_not_moved(&self) -> bool {
+        std::ptr::eq(self, self.address)
+    }
+}
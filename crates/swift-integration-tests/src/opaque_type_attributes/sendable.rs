@@ -0,0 +1,32 @@
+//! Verify that an opaque Rust type with the `Sendable` attribute can be shared between Swift tasks.
+//!
+//! See also: crates/swift-bridge-ir/src/codegen/codegen_tests/sendable_attribute_codegen_tests.rs
+
+use std::sync::atomic::{AtomicU32, Ordering};
+
+#[swift_bridge::bridge]
+mod ffi {
+    extern "Rust" {
+        #[swift_bridge(Sendable)]
+        type SendableCounter;
+
+        fn new_sendable_counter() -> SendableCounter;
+        fn increment(&self) -> u32;
+    }
+}
+
+pub struct SendableCounter {
+    count: AtomicU32,
+}
+
+impl SendableCounter {
+    fn increment(&self) -> u32 {
+        self.count.fetch_add(1, Ordering::SeqCst) + 1
+    }
+}
+
+fn new_sendable_counter() -> SendableCounter {
+    SendableCounter {
+        count: AtomicU32::new(0),
+    }
+}
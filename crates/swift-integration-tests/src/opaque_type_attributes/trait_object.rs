@@ -0,0 +1,59 @@
+//! Verify that we can pass `Box<dyn Trait>` trait objects to Swift and call the trait's methods.
+//!
+//! See also: crates/swift-bridge-ir/src/codegen/codegen_tests/trait_object_codegen_tests.rs
+
+#[swift_bridge::bridge]
+mod ffi {
+    extern "Rust" {
+        #[swift_bridge(trait_object)]
+        type TraitObjectShape;
+
+        fn area(&self) -> f64;
+        fn sides(&self) -> u8;
+
+        fn new_trait_object_square(side: f64) -> Box<dyn TraitObjectShape>;
+        fn new_trait_object_triangle(base: f64, height: f64) -> Box<dyn TraitObjectShape>;
+    }
+}
+
+pub trait TraitObjectShape {
+    fn area(&self) -> f64;
+    fn sides(&self) -> u8;
+}
+
+struct Square {
+    side: f64,
+}
+
+impl TraitObjectShape for Square {
+    fn area(&self) -> f64 {
+        self.side * self.side
+    }
+
+    fn sides(&self) -> u8 {
+        4
+    }
+}
+
+struct Triangle {
+    base: f64,
+    height: f64,
+}
+
+impl TraitObjectShape for Triangle {
+    fn area(&self) -> f64 {
+        self.base * self.height / 2.
+    }
+
+    fn sides(&self) -> u8 {
+        3
+    }
+}
+
+fn new_trait_object_square(side: f64) -> Box<dyn TraitObjectShape> {
+    Box::new(Square { side })
+}
+
+fn new_trait_object_triangle(base: f64, height: f64) -> Box<dyn TraitObjectShape> {
+    Box::new(Triangle { base, height })
+}
@@ -78,6 +78,9 @@ mod ffi {
         #[swift_bridge(init)]
         fn new(field: u8) -> OptTestOpaqueRustType;
         fn field(&self) -> u8;
+        fn self_if_field_is_even(&self) -> Option<&OptTestOpaqueRustType>;
+        fn field_plus(&self, other: Option<&OptTestOpaqueRustType>) -> u8;
+        fn take_field(&mut self, other: Option<OptTestOpaqueRustType>);
     }
 
     extern "Swift" {
@@ -104,6 +107,24 @@ impl OptTestOpaqueRustType {
     fn field(&self) -> u8 {
         self.field
     }
+
+    fn self_if_field_is_even(&self) -> Option<&OptTestOpaqueRustType> {
+        if self.field % 2 == 0 {
+            Some(self)
+        } else {
+            None
+        }
+    }
+
+    fn field_plus(&self, other: Option<&OptTestOpaqueRustType>) -> u8 {
+        self.field + other.map(|other| other.field).unwrap_or(0)
+    }
+
+    fn take_field(&mut self, other: Option<OptTestOpaqueRustType>) {
+        if let Some(other) = other {
+            self.field = other.field;
+        }
+    }
 }
 
 use self::reflect_primitives::*;
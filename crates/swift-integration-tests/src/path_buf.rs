@@ -0,0 +1,33 @@
+//! See also: crates/swift-bridge-ir/src/codegen/codegen_tests/path_buf_codegen_tests.rs
+
+use std::path::PathBuf;
+
+#[swift_bridge::bridge]
+mod ffi {
+    extern "Rust" {
+        fn rust_reflect_path_buf(arg: PathBuf) -> PathBuf;
+        fn rust_join_path(dir: PathBuf, file_name: &str) -> PathBuf;
+
+        fn run_path_buf_test();
+    }
+
+    extern "Swift" {
+        #[swift_bridge(swift_name = "swiftReflectPathBuf")]
+        fn swift_reflect_path_buf(arg: PathBuf) -> PathBuf;
+    }
+}
+
+fn run_path_buf_test() {
+    assert_eq!(
+        ffi::swift_reflect_path_buf(PathBuf::from("/tmp/some file.txt")),
+        PathBuf::from("/tmp/some file.txt")
+    );
+}
+
+fn rust_reflect_path_buf(arg: PathBuf) -> PathBuf {
+    arg
+}
+
+fn rust_join_path(dir: PathBuf, file_name: &str) -> PathBuf {
+    dir.join(file_name)
+}
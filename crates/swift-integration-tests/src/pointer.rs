@@ -12,6 +12,20 @@ mod ffi {
 
         fn rust_run_opaque_pointer_tests();
         fn rust_run_u8_pointer_tests();
+
+        unsafe fn rust_sum_u8_buffer(ptr: *const u8, len: usize) -> u32;
+    }
+
+    // Out-parameters.
+    extern "Rust" {
+        fn rust_divmod(a: u32, b: u32, quotient: &mut u32, remainder: &mut u32);
+
+        fn rust_run_out_param_tests();
+    }
+
+    // Out-parameters.
+    extern "Swift" {
+        fn swift_divmod(a: u32, b: u32, quotient: &mut u32, remainder: &mut u32);
     }
 
     // Opaque pointers.
@@ -71,3 +85,27 @@ fn rust_echo_const_u8(ptr: *const u8) -> *const u8 {
 fn rust_echo_mut_u8(ptr: *mut u8) -> *mut u8 {
     ptr
 }
+
+/// # Safety
+///
+/// `ptr` must point to `len` initialized bytes.
+unsafe fn rust_sum_u8_buffer(ptr: *const u8, len: usize) -> u32 {
+    let bytes = unsafe { std::slice::from_raw_parts(ptr, len) };
+    bytes.iter().map(|byte| *byte as u32).sum()
+}
+
+fn rust_divmod(a: u32, b: u32, quotient: &mut u32, remainder: &mut u32) {
+    *quotient = a / b;
+    *remainder = a % b;
+}
+
+/// Verify that Swift can write to the `&mut` primitives that we pass it.
+fn rust_run_out_param_tests() {
+    let mut quotient = 0;
+    let mut remainder = 0;
+
+    ffi::swift_divmod(17, 5, &mut quotient, &mut remainder);
+
+    assert_eq!(quotient, 3);
+    assert_eq!(remainder, 2);
+}
@@ -0,0 +1,24 @@
+use std::ops::{Range, RangeInclusive};
+
+#[swift_bridge::bridge]
+mod ffi {
+    extern "Rust" {
+        fn rust_reflect_range_usize(arg: Range<usize>) -> Range<usize>;
+
+        fn rust_reflect_range_inclusive_i32(arg: RangeInclusive<i32>) -> RangeInclusive<i32>;
+
+        fn rust_range_len(arg: Range<u64>) -> u64;
+    }
+}
+
+fn rust_reflect_range_usize(arg: Range<usize>) -> Range<usize> {
+    arg
+}
+
+fn rust_reflect_range_inclusive_i32(arg: RangeInclusive<i32>) -> RangeInclusive<i32> {
+    arg
+}
+
+fn rust_range_len(arg: Range<u64>) -> u64 {
+    arg.end - arg.start
+}
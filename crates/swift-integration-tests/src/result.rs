@@ -0,0 +1,103 @@
+#[swift_bridge::bridge]
+mod ffi {
+    #[swift_bridge(swift_repr = "struct")]
+    struct ResultTestSharedStruct {
+        field: u8,
+    }
+
+    #[swift_bridge(swift_repr = "struct")]
+    struct ResultTestSharedError {
+        code: u32,
+    }
+
+    extern "Rust" {
+        type ResultTestOpaqueRustType;
+        type ResultTestOpaqueRustError;
+
+        fn val(self: &ResultTestOpaqueRustType) -> u32;
+
+        fn code(self: &ResultTestOpaqueRustError) -> u32;
+
+        fn rust_func_returns_result_opaque_rust(
+            succeed: bool,
+        ) -> Result<ResultTestOpaqueRustType, ResultTestOpaqueRustError>;
+
+        fn rust_func_returns_result_shared_struct(
+            succeed: bool,
+        ) -> Result<ResultTestSharedStruct, ResultTestSharedError>;
+
+        fn rust_func_returns_result_u32(succeed: bool) -> Result<u32, ResultTestOpaqueRustError>;
+
+        fn rust_func_returns_result_null(succeed: bool) -> Result<(), ResultTestOpaqueRustError>;
+
+        fn rust_func_returns_result_null_shared_error(
+            succeed: bool,
+        ) -> Result<(), ResultTestSharedError>;
+    }
+}
+
+pub struct ResultTestOpaqueRustType {
+    val: u32,
+}
+
+impl ResultTestOpaqueRustType {
+    fn val(&self) -> u32 {
+        self.val
+    }
+}
+
+pub struct ResultTestOpaqueRustError {
+    code: u32,
+}
+
+impl ResultTestOpaqueRustError {
+    fn code(&self) -> u32 {
+        self.code
+    }
+}
+
+fn rust_func_returns_result_opaque_rust(
+    succeed: bool,
+) -> Result<ResultTestOpaqueRustType, ResultTestOpaqueRustError> {
+    if succeed {
+        Ok(ResultTestOpaqueRustType { val: 123 })
+    } else {
+        Err(ResultTestOpaqueRustError { code: 456 })
+    }
+}
+
+fn rust_func_returns_result_shared_struct(
+    succeed: bool,
+) -> Result<ffi::ResultTestSharedStruct, ffi::ResultTestSharedError> {
+    if succeed {
+        Ok(ffi::ResultTestSharedStruct { field: 123 })
+    } else {
+        Err(ffi::ResultTestSharedError { code: 456 })
+    }
+}
+
+fn rust_func_returns_result_u32(succeed: bool) -> Result<u32, ResultTestOpaqueRustError> {
+    if succeed {
+        Ok(123)
+    } else {
+        Err(ResultTestOpaqueRustError { code: 456 })
+    }
+}
+
+fn rust_func_returns_result_null(succeed: bool) -> Result<(), ResultTestOpaqueRustError> {
+    if succeed {
+        Ok(())
+    } else {
+        Err(ResultTestOpaqueRustError { code: 456 })
+    }
+}
+
+fn rust_func_returns_result_null_shared_error(
+    succeed: bool,
+) -> Result<(), ffi::ResultTestSharedError> {
+    if succeed {
+        Ok(())
+    } else {
+        Err(ffi::ResultTestSharedError { code: 789 })
+    }
+}
@@ -0,0 +1,30 @@
+use std::collections::{BTreeSet, HashSet};
+
+#[swift_bridge::bridge]
+mod ffi {
+    extern "Rust" {
+        fn rust_reflect_hashset_u64(arg: HashSet<u64>) -> HashSet<u64>;
+
+        fn rust_reflect_hashset_string(arg: HashSet<String>) -> HashSet<String>;
+
+        fn rust_reflect_btreeset_i32(arg: BTreeSet<i32>) -> BTreeSet<i32>;
+
+        fn rust_return_empty_hashset() -> HashSet<u8>;
+    }
+}
+
+fn rust_reflect_hashset_u64(arg: HashSet<u64>) -> HashSet<u64> {
+    arg
+}
+
+fn rust_reflect_hashset_string(arg: HashSet<String>) -> HashSet<String> {
+    arg
+}
+
+fn rust_reflect_btreeset_i32(arg: BTreeSet<i32>) -> BTreeSet<i32> {
+    arg
+}
+
+fn rust_return_empty_hashset() -> HashSet<u8> {
+    HashSet::new()
+}
@@ -1,15 +1,76 @@
 #[swift_bridge::bridge]
 mod ffi {
+    #[swift_bridge(Hashable)]
     enum EnumWithNoData {
         Variant1,
         Variant2,
     }
 
+    #[swift_bridge(Hashable)]
+    enum EnumWithData {
+        Named { a: u8, b: f64 },
+        Unnamed(u16, bool),
+        NoData,
+    }
+
+    enum EnumWithDiscriminants {
+        Ok = 0,
+        NotFound = 404,
+        Gone,
+        Negative = -1,
+    }
+
+    #[swift_bridge(transparent)]
+    enum TransparentPermission {
+        Read = 1,
+        Write = 2,
+        Execute = 4,
+    }
+
+    #[swift_bridge(Comparable)]
+    enum ComparablePriority {
+        Low = 10,
+        Medium,
+        High = 5,
+    }
+
+    #[swift_bridge(LocalizedError)]
+    enum DownloadError {
+        NotFound,
+        Network(String),
+    }
+
     extern "Rust" {
+        fn reflect_enum_with_discriminants(arg: EnumWithDiscriminants) -> EnumWithDiscriminants;
         fn reflect_enum_with_no_data(arg: EnumWithNoData) -> EnumWithNoData;
+        fn reflect_enum_with_data(arg: EnumWithData) -> EnumWithData;
+        fn reflect_download_error(arg: DownloadError) -> DownloadError;
+        fn reflect_transparent_permission(arg: TransparentPermission) -> TransparentPermission;
+        fn transparent_permission_as_i32(arg: TransparentPermission) -> i32;
     }
 }
 
 fn reflect_enum_with_no_data(arg: ffi::EnumWithNoData) -> ffi::EnumWithNoData {
     arg
 }
+
+fn reflect_enum_with_data(arg: ffi::EnumWithData) -> ffi::EnumWithData {
+    arg
+}
+
+fn reflect_enum_with_discriminants(arg: ffi::EnumWithDiscriminants) -> ffi::EnumWithDiscriminants {
+    assert_eq!(ffi::EnumWithDiscriminants::Gone as i32, 405);
+    arg
+}
+
+fn reflect_download_error(arg: ffi::DownloadError) -> ffi::DownloadError {
+    arg
+}
+
+fn reflect_transparent_permission(arg: ffi::TransparentPermission) -> ffi::TransparentPermission {
+    arg
+}
+
+fn transparent_permission_as_i32(arg: ffi::TransparentPermission) -> i32 {
+    arg as i32
+}
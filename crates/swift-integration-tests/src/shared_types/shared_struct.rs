@@ -17,6 +17,11 @@ mod ffi {
     #[swift_bridge(swift_repr = "struct")]
     struct StructReprStructTupleStruct(u8, u32);
 
+    #[swift_bridge(swift_repr = "class")]
+    struct StructReprClass {
+        named_field: u8,
+    }
+
     extern "Rust" {
         fn test_rust_calls_swift();
 
@@ -29,6 +34,8 @@ mod ffi {
         fn swift_calls_rust_tuple_struct(
             arg: StructReprStructTupleStruct,
         ) -> StructReprStructTupleStruct;
+
+        fn swift_calls_rust_struct_repr_class(arg: StructReprClass) -> StructReprClass;
     }
 
     extern "Swift" {
@@ -61,6 +68,10 @@ fn swift_calls_rust_tuple_struct(
     arg
 }
 
+fn swift_calls_rust_struct_repr_class(arg: ffi::StructReprClass) -> ffi::StructReprClass {
+    arg
+}
+
 #[deny(unused)]
 mod tests {
     use super::ffi;
@@ -1,3 +1,31 @@
+//! See also: crates/swift-bridge-ir/src/codegen/codegen_tests/slice_codegen_tests.rs
+
+#[swift_bridge::bridge]
+mod ffi {
+    extern "Rust" {
+        fn rust_fill_mut_slice(buffer: &mut [u8], value: u8);
+        fn rust_sum_slice(buffer: &[u8]) -> u32;
+        fn rust_sum_data(#[swift_bridge(as = "Data")] data: &[u8]) -> u32;
+        fn rust_join_str_slice(parts: &[&str], separator: &str) -> String;
+    }
+}
+
+fn rust_fill_mut_slice(buffer: &mut [u8], value: u8) {
+    buffer.fill(value);
+}
+
+fn rust_sum_slice(buffer: &[u8]) -> u32 {
+    buffer.iter().map(|val| *val as u32).sum()
+}
+
+fn rust_sum_data(data: &[u8]) -> u32 {
+    rust_sum_slice(data)
+}
+
+fn rust_join_str_slice(parts: &[&str], separator: &str) -> String {
+    parts.join(separator)
+}
+
 // TODO:
 // - Define struct SliceTestOpaqueRustType
 // - Add a method to create Vec<SliceTestOpaqueRustType>
@@ -5,6 +33,5 @@
 //   - Add test to verify that we can iterate over the slice
 //   - Add test to verify that that we can index into the slice
 //   - Add test to verify that we can get the length of the slice
-// - Create SliceTests.swift
-//   - Add Swift test verifying that we can use an Array<SliceTestOpaqueRustType> as a slice
-//   - Add Swift test verifying that we can use a RustVec<SliceTestOpaqueRustType> as a slice
+// - Add Swift test verifying that we can use an Array<SliceTestOpaqueRustType> as a slice
+// - Add Swift test verifying that we can use a RustVec<SliceTestOpaqueRustType> as a slice
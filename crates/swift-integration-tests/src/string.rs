@@ -1,9 +1,26 @@
+use std::borrow::Cow;
+
 #[swift_bridge::bridge]
 mod ffi {
     extern "Rust" {
         fn run_string_tests();
 
         fn create_string(str: &str) -> String;
+
+        fn rust_reflect_string(string: String) -> String;
+
+        fn rust_cow_str(owned: bool) -> Cow<'static, str>;
+
+        fn rust_boxed_str() -> Box<str>;
+    }
+
+    extern "Rust" {
+        type CowStrHolder;
+
+        #[swift_bridge(init)]
+        fn new(name: String) -> CowStrHolder;
+
+        fn name(&self) -> Cow<str>;
     }
 
     extern "Swift" {
@@ -21,3 +38,33 @@ fn run_string_tests() {
 fn create_string(str: &str) -> String {
     str.to_string()
 }
+
+fn rust_reflect_string(string: String) -> String {
+    string
+}
+
+fn rust_cow_str(owned: bool) -> Cow<'static, str> {
+    if owned {
+        Cow::Owned("owned".to_string())
+    } else {
+        Cow::Borrowed("borrowed")
+    }
+}
+
+fn rust_boxed_str() -> Box<str> {
+    "boxed".into()
+}
+
+pub struct CowStrHolder {
+    name: String,
+}
+
+impl CowStrHolder {
+    fn new(name: String) -> Self {
+        CowStrHolder { name }
+    }
+
+    fn name(&self) -> Cow<'_, str> {
+        Cow::Borrowed(&self.name)
+    }
+}
@@ -1,2 +1,6 @@
 mod already_declared;
+mod codable;
+mod equatable;
+mod identifiable;
+mod rename_all;
 mod swift_name;
@@ -0,0 +1,27 @@
+/// We declare a few shared structs that use the `Codable` attribute so that we can encode and
+/// decode them from Swift.
+///
+/// Related: crates/swift-bridge-ir/src/codegen/codegen_tests/shared_struct_codegen_tests.rs
+///   - shared_struct_codable_attribute
+#[swift_bridge::bridge]
+mod ffi {
+    #[swift_bridge(swift_repr = "struct", Codable)]
+    struct CodableInnerStruct {
+        field: u8,
+    }
+
+    #[swift_bridge(swift_repr = "struct", Codable)]
+    struct CodableStruct {
+        field: u32,
+        optional: Option<u16>,
+        inner: CodableInnerStruct,
+    }
+
+    extern "Rust" {
+        fn reflect_codable_struct(arg: CodableStruct) -> CodableStruct;
+    }
+}
+
+fn reflect_codable_struct(arg: ffi::CodableStruct) -> ffi::CodableStruct {
+    arg
+}
@@ -0,0 +1,27 @@
+/// We declare a few shared structs that use the `Equatable` attribute so that we can compare them
+/// from Swift.
+///
+/// Related: crates/swift-bridge-ir/src/codegen/codegen_tests/shared_struct_codegen_tests.rs
+///   - shared_struct_equatable_attribute
+#[swift_bridge::bridge]
+mod ffi {
+    #[swift_bridge(swift_repr = "struct", Equatable)]
+    struct EquatableInnerStruct {
+        field: u8,
+    }
+
+    #[swift_bridge(swift_repr = "struct", Equatable)]
+    struct EquatableStruct {
+        field: u32,
+        optional: Option<u16>,
+        inner: EquatableInnerStruct,
+    }
+
+    extern "Rust" {
+        fn reflect_equatable_struct(arg: EquatableStruct) -> EquatableStruct;
+    }
+}
+
+fn reflect_equatable_struct(arg: ffi::EquatableStruct) -> ffi::EquatableStruct {
+    arg
+}
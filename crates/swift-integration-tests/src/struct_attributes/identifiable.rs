@@ -0,0 +1,28 @@
+/// We declare a few shared structs that use the `Identifiable` attribute so that we can use them
+/// as `Identifiable` values from Swift.
+///
+/// Related: crates/swift-bridge-ir/src/codegen/codegen_tests/shared_struct_codegen_tests.rs
+///   - shared_struct_identifiable_attribute
+///   - shared_struct_identifiable_attribute_custom_field
+#[swift_bridge::bridge]
+mod ffi {
+    #[swift_bridge(swift_repr = "struct", Identifiable)]
+    struct IdentifiableStruct {
+        id: u32,
+        field: u8,
+    }
+
+    #[swift_bridge(swift_repr = "struct", Identifiable = "uuid")]
+    struct IdentifiableStructCustomField {
+        uuid: u64,
+        field: u8,
+    }
+
+    extern "Rust" {
+        fn reflect_identifiable_struct(arg: IdentifiableStruct) -> IdentifiableStruct;
+    }
+}
+
+fn reflect_identifiable_struct(arg: ffi::IdentifiableStruct) -> ffi::IdentifiableStruct {
+    arg
+}
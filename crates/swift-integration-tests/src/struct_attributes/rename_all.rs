@@ -0,0 +1,24 @@
+/// We declare a shared struct that uses the `rename_all` attribute so that we can use its
+/// camelCase fields from Swift.
+///
+/// Related: crates/swift-bridge-ir/src/codegen/codegen_tests/shared_struct_codegen_tests.rs
+///   - shared_struct_rename_all_camel_case_attribute
+#[swift_bridge::bridge]
+mod ffi {
+    #[swift_bridge(swift_repr = "struct", rename_all = "camelCase")]
+    struct RenameAllStruct {
+        user_id: u32,
+        is_active: bool,
+    }
+
+    extern "Rust" {
+        fn reflect_rename_all_struct(arg: RenameAllStruct) -> RenameAllStruct;
+    }
+}
+
+fn reflect_rename_all_struct(arg: ffi::RenameAllStruct) -> ffi::RenameAllStruct {
+    ffi::RenameAllStruct {
+        user_id: arg.user_id + 1,
+        is_active: !arg.is_active,
+    }
+}
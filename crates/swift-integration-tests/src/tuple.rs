@@ -0,0 +1,38 @@
+//! See also: crates/swift-bridge-ir/src/codegen/codegen_tests/tuple_codegen_tests.rs
+
+#[swift_bridge::bridge]
+mod ffi {
+    #[swift_bridge(swift_repr = "struct")]
+    struct TupleTestStruct {
+        field: u8,
+    }
+
+    extern "Rust" {
+        fn rust_reflect_tuple_primitives(arg: (u8, i32, bool)) -> (u8, i32, bool);
+        fn rust_tuple_with_string(arg: (i32, String)) -> (i32, String);
+        fn rust_swap_tuple_with_struct(arg: (TupleTestStruct, u64)) -> (u64, TupleTestStruct);
+
+        fn run_tuple_test();
+    }
+
+    extern "Swift" {
+        #[swift_bridge(swift_name = "swiftSwapTuple")]
+        fn swift_swap_tuple(arg: (u16, f64)) -> (f64, u16);
+    }
+}
+
+fn run_tuple_test() {
+    assert_eq!(ffi::swift_swap_tuple((5, 1.5)), (1.5, 5));
+}
+
+fn rust_reflect_tuple_primitives(arg: (u8, i32, bool)) -> (u8, i32, bool) {
+    arg
+}
+
+fn rust_tuple_with_string(arg: (i32, String)) -> (i32, String) {
+    (arg.0 + 1, arg.1.to_uppercase())
+}
+
+fn rust_swap_tuple_with_struct(arg: (ffi::TupleTestStruct, u64)) -> (u64, ffi::TupleTestStruct) {
+    (arg.1, arg.0)
+}
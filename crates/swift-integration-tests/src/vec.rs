@@ -1,3 +1,6 @@
+use std::collections::VecDeque;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
 #[swift_bridge::bridge]
 mod ffi {
     extern "Rust" {
@@ -9,11 +12,71 @@ mod ffi {
         fn text(&self) -> &str;
     }
 
+    extern "Rust" {
+        type DropCounterInsideVecT;
+
+        #[swift_bridge(init)]
+        fn new() -> DropCounterInsideVecT;
+
+        fn drop_counter_inside_vec_t_drop_count() -> usize;
+    }
+
     extern "Rust" {
         fn rust_reflect_vec_opaque_rust_type(
             arg: Vec<ARustTypeInsideVecT>,
         ) -> Vec<ARustTypeInsideVecT>;
     }
+
+    #[swift_bridge(swift_repr = "struct")]
+    struct SharedStructInsideVecT {
+        text: String,
+        number: u32,
+    }
+
+    extern "Rust" {
+        fn rust_return_vec_of_shared_struct(count: u32) -> Vec<SharedStructInsideVecT>;
+    }
+
+    extern "Rust" {
+        fn rust_return_jagged_vec(rows: u32) -> Vec<Vec<f64>>;
+    }
+
+    extern "Rust" {
+        fn rust_return_option_vec(len: Option<u32>) -> Option<Vec<u32>>;
+        fn rust_return_vec_of_option(len: u32) -> Vec<Option<u32>>;
+    }
+
+    extern "Rust" {
+        fn rust_return_wrapped_vec_deque() -> VecDeque<u32>;
+        fn rust_return_empty_vec_deque() -> VecDeque<u32>;
+    }
+
+    extern "Rust" {
+        fn rust_sum_vec(#[swift_bridge(as = "Array")] numbers: Vec<i32>) -> i32;
+        fn rust_count_true(#[swift_bridge(as = "Array")] flags: Vec<bool>) -> usize;
+    }
+
+    extern "Rust" {
+        #[swift_bridge(return_as = "Data")]
+        fn rust_return_vec_u8_as_data(len: u8) -> Vec<u8>;
+
+        #[swift_bridge(return_as = "Array")]
+        fn rust_return_vec_f32_as_array(len: u8) -> Vec<f32>;
+    }
+
+    extern "Rust" {
+        type RustVecOwner;
+
+        #[swift_bridge(init)]
+        fn new() -> RustVecOwner;
+
+        fn numbers_mut(&mut self) -> &mut Vec<u32>;
+        fn numbers_sum(&self) -> u32;
+    }
+
+    extern "Rust" {
+        fn rust_push_all(vec: &mut Vec<u32>, items: &[u32]);
+    }
 }
 
 pub struct ARustTypeInsideVecT {
@@ -32,6 +95,110 @@ impl ARustTypeInsideVecT {
     }
 }
 
+/// Used to verify that dropping a Swift `RustVec` frees the elements that are still inside of it.
+pub struct DropCounterInsideVecT;
+
+static DROP_COUNTER_INSIDE_VEC_T_DROP_COUNT: AtomicUsize = AtomicUsize::new(0);
+
+impl DropCounterInsideVecT {
+    fn new() -> Self {
+        DropCounterInsideVecT
+    }
+}
+
+impl Drop for DropCounterInsideVecT {
+    fn drop(&mut self) {
+        DROP_COUNTER_INSIDE_VEC_T_DROP_COUNT.fetch_add(1, Ordering::SeqCst);
+    }
+}
+
+fn drop_counter_inside_vec_t_drop_count() -> usize {
+    DROP_COUNTER_INSIDE_VEC_T_DROP_COUNT.load(Ordering::SeqCst)
+}
+
 fn rust_reflect_vec_opaque_rust_type(arg: Vec<ARustTypeInsideVecT>) -> Vec<ARustTypeInsideVecT> {
     arg
 }
+
+fn rust_return_vec_of_shared_struct(count: u32) -> Vec<ffi::SharedStructInsideVecT> {
+    (0..count)
+        .map(|number| ffi::SharedStructInsideVecT {
+            text: format!("number {}", number),
+            number,
+        })
+        .collect()
+}
+
+/// Row `n` holds the numbers `0` to `n - 1`, so the first row is always empty.
+fn rust_return_jagged_vec(rows: u32) -> Vec<Vec<f64>> {
+    (0..rows)
+        .map(|row| (0..row).map(|col| col as f64).collect())
+        .collect()
+}
+
+fn rust_return_option_vec(len: Option<u32>) -> Option<Vec<u32>> {
+    len.map(|len| (0..len).collect())
+}
+
+/// Every odd index is `None`.
+fn rust_return_vec_of_option(len: u32) -> Vec<Option<u32>> {
+    (0..len)
+        .map(|idx| if idx % 2 == 0 { Some(idx) } else { None })
+        .collect()
+}
+
+/// Returns `[0, 1, 2, 3, 4]`, with its back wrapped around to the start of the deque's ring
+/// buffer.
+fn rust_return_wrapped_vec_deque() -> VecDeque<u32> {
+    let mut deque = VecDeque::with_capacity(5);
+    deque.extend([10, 11, 0, 1, 2]);
+    deque.pop_front();
+    deque.pop_front();
+    deque.push_back(3);
+    deque.push_back(4);
+
+    deque
+}
+
+fn rust_return_empty_vec_deque() -> VecDeque<u32> {
+    VecDeque::new()
+}
+
+fn rust_sum_vec(numbers: Vec<i32>) -> i32 {
+    numbers.into_iter().sum()
+}
+
+fn rust_count_true(flags: Vec<bool>) -> usize {
+    flags.into_iter().filter(|flag| *flag).count()
+}
+
+fn rust_return_vec_u8_as_data(len: u8) -> Vec<u8> {
+    (0..len).collect()
+}
+
+fn rust_return_vec_f32_as_array(len: u8) -> Vec<f32> {
+    (0..len).map(|val| val as f32 / 2.).collect()
+}
+
+/// Owns a Vec that Swift mutates through a borrowed `RustVec` handle.
+pub struct RustVecOwner {
+    numbers: Vec<u32>,
+}
+
+impl RustVecOwner {
+    fn new() -> Self {
+        RustVecOwner { numbers: vec![] }
+    }
+
+    fn numbers_mut(&mut self) -> &mut Vec<u32> {
+        &mut self.numbers
+    }
+
+    fn numbers_sum(&self) -> u32 {
+        self.numbers.iter().sum()
+    }
+}
+
+fn rust_push_all(vec: &mut Vec<u32>, items: &[u32]) {
+    vec.extend_from_slice(items);
+}
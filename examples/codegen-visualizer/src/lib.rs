@@ -157,6 +157,9 @@ fn generate_code(bridge_module_source: &str) -> Result<GeneratedCode, String> {
         // TODO: Add an way in the visualizer UI to set whether or not a feature is enabled and then
         //  look up those features here.
         crate_feature_lookup: Box::new(|_feature_name| false),
+        generate_objc_header: false,
+        cpp_header: None,
+        library_evolution: false,
     };
     let generated = module.generate_swift_code_and_c_header(config);
 
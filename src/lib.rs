@@ -6,7 +6,9 @@ pub use swift_bridge_macro::bridge;
 
 mod std_bridge;
 
-pub use self::std_bridge::{option, string};
+pub use self::std_bridge::{
+    date_time, duration, error, hashmap, int128, net, option, range, set, string, vec,
+};
 
 #[doc(hidden)]
 #[cfg(feature = "async")]
@@ -37,10 +39,40 @@ impl<T> FfiSlice<T> {
         }
     }
 
+    /// Create an FfiSlice from a mutable slice.
+    pub fn from_mut_slice(slice: &mut [T]) -> Self {
+        FfiSlice {
+            start: slice.as_mut_ptr(),
+            len: slice.len(),
+        }
+    }
+
     /// Get a reference to the slice that this FfiSlice points to.
     pub fn as_slice(&self) -> &'static [T] {
+        // Swift represents an empty buffer, such as the bytes of an empty `Data`, using a null
+        // base address, which isn't a valid slice pointer.
+        if self.start.is_null() {
+            assert_eq!(self.len, 0, "Null slice pointer with a non-zero length.");
+            return &[];
+        }
+
         unsafe { std::slice::from_raw_parts(self.start, self.len) }
     }
+
+    /// Get a mutable reference to the slice that this FfiSlice points to.
+    ///
+    /// The side that created the FfiSlice must not read or write to the slice's memory until we are
+    /// done using the slice.
+    pub fn as_mut_slice(&self) -> &'static mut [T] {
+        // Swift represents an empty buffer using a null base address, which isn't a valid
+        // slice pointer.
+        if self.start.is_null() {
+            assert_eq!(self.len, 0, "Null slice pointer with a non-zero length.");
+            return &mut [];
+        }
+
+        unsafe { std::slice::from_raw_parts_mut(self.start as *mut T, self.len) }
+    }
 }
 
 // The code generation automatically implements this for all shared structs.
@@ -1,5 +1,14 @@
 #![allow(missing_docs)]
 
+pub mod date_time;
+pub mod duration;
+pub mod error;
+pub mod hashmap;
+pub mod int128;
+pub mod net;
 pub mod option;
+pub mod range;
 mod rust_vec;
+pub mod set;
 pub mod string;
+pub mod vec;
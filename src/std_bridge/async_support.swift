@@ -0,0 +1,53 @@
+/// Resumes the continuation of an async Rust function that returns a `Result` exactly once.
+///
+/// If the Swift task gets cancelled before the Rust function completes we immediately throw a
+/// `CancellationError`. The Rust function keeps running until it completes, at which point its
+/// result gets dropped.
+public class __private__ThrowingContinuationGuard<T> {
+    private let lock = NSLock()
+    private var continuation: CheckedContinuation<T, Error>?
+    private var isCancelled = false
+
+    public init() {}
+
+    public func setContinuation(_ continuation: CheckedContinuation<T, Error>) {
+        lock.lock()
+        if isCancelled {
+            lock.unlock()
+            continuation.resume(throwing: CancellationError())
+            return
+        }
+        self.continuation = continuation
+        lock.unlock()
+    }
+
+    public func resume(with result: Result<T, Error>) {
+        guard let continuation = takeContinuation() else {
+            return
+        }
+
+        switch result {
+        case .success(let val):
+            continuation.resume(returning: val)
+        case .failure(let error):
+            continuation.resume(throwing: error)
+        }
+    }
+
+    public func cancel() {
+        lock.lock()
+        isCancelled = true
+        lock.unlock()
+
+        takeContinuation()?.resume(throwing: CancellationError())
+    }
+
+    private func takeContinuation() -> CheckedContinuation<T, Error>? {
+        lock.lock()
+        defer { lock.unlock() }
+
+        let continuation = self.continuation
+        self.continuation = nil
+        return continuation
+    }
+}
@@ -0,0 +1,11 @@
+/// Holds a Swift closure that was passed to Rust as a `Box<dyn Fn>`.
+///
+/// Closures are not classes, so we box them in order to be able to retain them and pass them to
+/// Rust as a pointer. Rust releases the box when it drops the `Box<dyn Fn>`.
+public class __private__SwiftClosureBox<T> {
+    let closure: T
+
+    init(_ closure: T) {
+        self.closure = closure
+    }
+}
@@ -0,0 +1,12 @@
+/// The FFI representation of a `chrono::DateTime<Utc>`.
+///
+/// `secs` are the whole seconds since the Unix epoch, rounded towards negative infinity, so
+/// `nanos` is always the non-negative number of nanoseconds after `secs`. This holds for dates
+/// before 1970 as well, where half a second before the epoch is `secs: -1, nanos: 500_000_000`.
+/// Swift converts to and from a `Date`.
+#[repr(C)]
+#[doc(hidden)]
+pub struct FfiDateTime {
+    pub secs: i64,
+    pub nanos: u32,
+}
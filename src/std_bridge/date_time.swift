@@ -0,0 +1,21 @@
+extension __private__FfiDateTime {
+    func intoDate() -> Date {
+        Date(timeIntervalSince1970: TimeInterval(self.secs) + TimeInterval(self.nanos) / 1_000_000_000)
+    }
+
+    /// The seconds get rounded towards negative infinity so that the nanoseconds are never
+    /// negative, even for dates before 1970. Sub-nanosecond precision gets rounded to the nearest
+    /// nanosecond.
+    static func fromDate(_ date: Date) -> __private__FfiDateTime {
+        let interval = date.timeIntervalSince1970
+
+        var secs = Int64(interval.rounded(.down))
+        var nanos = UInt32(((interval - TimeInterval(secs)) * 1_000_000_000).rounded())
+        if nanos >= 1_000_000_000 {
+            secs += 1
+            nanos -= 1_000_000_000
+        }
+
+        return __private__FfiDateTime(secs: secs, nanos: nanos)
+    }
+}
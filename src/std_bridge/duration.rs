@@ -0,0 +1,31 @@
+use std::time::Duration;
+
+/// The FFI representation of a `std::time::Duration`.
+///
+/// We pass the whole seconds and the nanoseconds separately instead of as a single `f64` so that
+/// Rust never loses precision, even for very large durations. Swift converts to and from a
+/// `TimeInterval`.
+#[repr(C)]
+#[doc(hidden)]
+pub struct FfiDuration {
+    pub secs: u64,
+    pub nanos: u64,
+}
+
+impl FfiDuration {
+    /// Create an FfiDuration from a Duration.
+    pub fn from_duration(duration: Duration) -> Self {
+        FfiDuration {
+            secs: duration.as_secs(),
+            nanos: duration.subsec_nanos() as u64,
+        }
+    }
+
+    /// Convert back into a Duration.
+    ///
+    /// Swift should never give us more than a second's worth of nanoseconds, but if it does we
+    /// carry them over into the seconds, saturating instead of overflowing.
+    pub fn into_duration(self) -> Duration {
+        Duration::from_secs(self.secs).saturating_add(Duration::from_nanos(self.nanos))
+    }
+}
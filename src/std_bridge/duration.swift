@@ -0,0 +1,26 @@
+extension __private__FfiDuration {
+    func intoTimeInterval() -> TimeInterval {
+        TimeInterval(self.secs) + TimeInterval(self.nanos) / 1_000_000_000
+    }
+
+    /// Negative and NaN intervals become a zero duration and intervals that are too large to fit
+    /// into a `Duration` saturate at the largest duration. Sub-nanosecond precision gets rounded
+    /// to the nearest nanosecond.
+    static func fromTimeInterval(_ interval: TimeInterval) -> __private__FfiDuration {
+        if interval.isNaN || interval <= 0 {
+            return __private__FfiDuration(secs: 0, nanos: 0)
+        }
+        if interval >= TimeInterval(UInt64.max) {
+            return __private__FfiDuration(secs: UInt64.max, nanos: 999_999_999)
+        }
+
+        var secs = UInt64(interval.rounded(.towardZero))
+        var nanos = UInt64(((interval - TimeInterval(secs)) * 1_000_000_000).rounded())
+        if nanos >= 1_000_000_000 {
+            secs += 1
+            nanos -= 1_000_000_000
+        }
+
+        return __private__FfiDuration(secs: secs, nanos: nanos)
+    }
+}
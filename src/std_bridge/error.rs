@@ -0,0 +1,27 @@
+// The generated extern functions dereference the `RustError` pointers that Swift passes to them.
+#![allow(clippy::not_unsafe_ptr_arg_deref)]
+
+pub use self::ffi::*;
+
+#[swift_bridge_macro::bridge(swift_bridge_path = crate)]
+mod ffi {
+    extern "Rust" {
+        type RustError;
+
+        fn message(&self) -> String;
+    }
+}
+
+/// A `Box<dyn std::error::Error>` that was handed to Swift.
+///
+/// Swift sees this as a `RustError` class that conforms to `Error` and
+/// `CustomStringConvertible`.
+#[doc(hidden)]
+pub struct RustError(pub Box<dyn std::error::Error>);
+
+impl RustError {
+    /// The error's `Display` representation.
+    fn message(&self) -> String {
+        self.0.to_string()
+    }
+}
@@ -0,0 +1,8 @@
+extension RustError: Error {}
+
+extension RustError: CustomStringConvertible {
+    /// The Rust error's `Display` representation.
+    public var description: String {
+        self.message().toString()
+    }
+}
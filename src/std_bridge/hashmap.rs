@@ -0,0 +1,59 @@
+use std::any::Any;
+
+/// The FFI representation of a `HashMap<K, V>` or a `BTreeMap<K, V>`.
+///
+/// Holds two parallel buffers, one for the keys and one for the values, where `K` and `V` are
+/// the FFI compatible representations of the map's key and value types.
+/// The entries keep the order that they were created in, so a `BTreeMap`'s entries stay sorted.
+///
+/// When Rust passes a map to Swift the buffers are owned by Rust and get freed when Swift calls
+/// `__swift_bridge__$FfiHashMap$_free`.
+/// When Swift passes a map to Rust the buffers are owned by Swift and are only valid for the
+/// duration of the call.
+#[repr(C)]
+#[doc(hidden)]
+pub struct FfiHashMap<K, V> {
+    pub keys: *const K,
+    pub values: *const V,
+    pub len: usize,
+    /// The Rust allocation that owns the buffers. Null if the buffers are owned by Swift.
+    owner: *mut Box<dyn Any>,
+}
+
+impl<K: 'static, V: 'static> FfiHashMap<K, V> {
+    /// Create an FfiHashMap that owns the buffers that hold its keys and values.
+    pub fn from_entries(entries: impl Iterator<Item = (K, V)>) -> Self {
+        let (keys, values): (Vec<K>, Vec<V>) = entries.unzip();
+
+        let keys_ptr = keys.as_ptr();
+        let values_ptr = values.as_ptr();
+        let len = keys.len();
+
+        let owner: Box<dyn Any> = Box::new((keys, values));
+
+        FfiHashMap {
+            keys: keys_ptr,
+            values: values_ptr,
+            len,
+            owner: Box::into_raw(Box::new(owner)),
+        }
+    }
+
+    /// Move the keys and values out of buffers that are owned by Swift.
+    pub fn into_entries(self) -> impl Iterator<Item = (K, V)> {
+        (0..self.len).map(move |idx| unsafe {
+            (
+                std::ptr::read(self.keys.add(idx)),
+                std::ptr::read(self.values.add(idx)),
+            )
+        })
+    }
+}
+
+#[export_name = "__swift_bridge__$FfiHashMap$_free"]
+#[doc(hidden)]
+pub extern "C" fn _free(map: FfiHashMap<std::ffi::c_void, std::ffi::c_void>) {
+    if !map.owner.is_null() {
+        drop(unsafe { Box::from_raw(map.owner) });
+    }
+}
@@ -0,0 +1,110 @@
+extension __private__FfiHashMap {
+    /// Move the entries of a map that was created by Rust into a Swift Dictionary and then free
+    /// the Rust buffers that held them.
+    func toDictionary<FfiKey, FfiValue, Key: Hashable, Value>(
+        _ intoKey: (FfiKey) -> Key,
+        _ intoValue: (FfiValue) -> Value
+    ) -> Dictionary<Key, Value> {
+        var dictionary = Dictionary<Key, Value>(minimumCapacity: Int(self.len))
+
+        if self.len > 0 {
+            let keys = self.keys.assumingMemoryBound(to: FfiKey.self)
+            let values = self.values.assumingMemoryBound(to: FfiValue.self)
+
+            for idx in 0..<Int(self.len) {
+                dictionary[intoKey(keys[idx])] = intoValue(values[idx])
+            }
+        }
+
+        __swift_bridge__$FfiHashMap$_free(self)
+
+        return dictionary
+    }
+
+    /// Move the entries of a map that was created by Rust into a Swift array of tuples and then
+    /// free the Rust buffers that held them.
+    ///
+    /// Unlike a Dictionary, the array keeps the entries in the order that Rust iterated them in,
+    /// which for a `BTreeMap` is sorted by key.
+    func toEntries<FfiKey, FfiValue, Key, Value>(
+        _ intoKey: (FfiKey) -> Key,
+        _ intoValue: (FfiValue) -> Value
+    ) -> [(Key, Value)] {
+        var entries: [(Key, Value)] = []
+        entries.reserveCapacity(Int(self.len))
+
+        if self.len > 0 {
+            let keys = self.keys.assumingMemoryBound(to: FfiKey.self)
+            let values = self.values.assumingMemoryBound(to: FfiValue.self)
+
+            for idx in 0..<Int(self.len) {
+                entries.append((intoKey(keys[idx]), intoValue(values[idx])))
+            }
+        }
+
+        __swift_bridge__$FfiHashMap$_free(self)
+
+        return entries
+    }
+}
+
+extension Dictionary {
+    /// Lend the Dictionary's entries to Rust for the duration of the callback.
+    func toFfiHashMap<FfiKey, FfiValue, T>(
+        _ keyToFfi: (Key) -> FfiKey,
+        _ valueToFfi: (Value) -> FfiValue,
+        _ withFfiHashMap: (__private__FfiHashMap) -> T
+    ) -> T {
+        var keys: [FfiKey] = []
+        var values: [FfiValue] = []
+        keys.reserveCapacity(self.count)
+        values.reserveCapacity(self.count)
+
+        for (key, value) in self {
+            keys.append(keyToFfi(key))
+            values.append(valueToFfi(value))
+        }
+
+        return keys.withUnsafeBufferPointer({ keysPtr in
+            values.withUnsafeBufferPointer({ valuesPtr in
+                withFfiHashMap(__private__FfiHashMap(
+                    keys: UnsafeMutableRawPointer(mutating: keysPtr.baseAddress),
+                    values: UnsafeMutableRawPointer(mutating: valuesPtr.baseAddress),
+                    len: UInt(self.count),
+                    owner: nil
+                ))
+            })
+        })
+    }
+}
+
+extension Array {
+    /// Lend an array of key-value tuples to Rust for the duration of the callback, keeping the
+    /// entries in the order that they appear in the array.
+    func toFfiHashMap<Key, Value, FfiKey, FfiValue, T>(
+        _ keyToFfi: (Key) -> FfiKey,
+        _ valueToFfi: (Value) -> FfiValue,
+        _ withFfiHashMap: (__private__FfiHashMap) -> T
+    ) -> T where Element == (Key, Value) {
+        var keys: [FfiKey] = []
+        var values: [FfiValue] = []
+        keys.reserveCapacity(self.count)
+        values.reserveCapacity(self.count)
+
+        for (key, value) in self {
+            keys.append(keyToFfi(key))
+            values.append(valueToFfi(value))
+        }
+
+        return keys.withUnsafeBufferPointer({ keysPtr in
+            values.withUnsafeBufferPointer({ valuesPtr in
+                withFfiHashMap(__private__FfiHashMap(
+                    keys: UnsafeMutableRawPointer(mutating: keysPtr.baseAddress),
+                    values: UnsafeMutableRawPointer(mutating: valuesPtr.baseAddress),
+                    len: UInt(self.count),
+                    owner: nil
+                ))
+            })
+        })
+    }
+}
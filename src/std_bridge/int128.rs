@@ -0,0 +1,53 @@
+/// The FFI representation of a `u128`.
+///
+/// Neither C nor Swift have a portable 128 bit integer, so we pass the high and low 64 bits as
+/// separate fields. We split the value using shifts instead of reinterpreting its bytes, so the
+/// `high` and `low` words mean the same thing on both little and big endian platforms.
+#[repr(C)]
+#[doc(hidden)]
+pub struct FfiU128 {
+    pub high: u64,
+    pub low: u64,
+}
+
+impl FfiU128 {
+    /// Split a u128 into its high and low words.
+    pub fn from_u128(val: u128) -> Self {
+        FfiU128 {
+            high: (val >> 64) as u64,
+            low: val as u64,
+        }
+    }
+
+    /// Join the high and low words back into a u128.
+    pub fn into_u128(self) -> u128 {
+        ((self.high as u128) << 64) | self.low as u128
+    }
+}
+
+/// The FFI representation of an `i128`.
+///
+/// The `high` word holds the sign, so a negative number has a negative `high` word.
+///
+/// See [`FfiU128`].
+#[repr(C)]
+#[doc(hidden)]
+pub struct FfiI128 {
+    pub high: i64,
+    pub low: u64,
+}
+
+impl FfiI128 {
+    /// Split an i128 into its high and low words.
+    pub fn from_i128(val: i128) -> Self {
+        FfiI128 {
+            high: (val >> 64) as i64,
+            low: val as u64,
+        }
+    }
+
+    /// Join the high and low words back into an i128.
+    pub fn into_i128(self) -> i128 {
+        ((self.high as i128) << 64) | self.low as i128
+    }
+}
@@ -0,0 +1,61 @@
+/// A Rust `u128`, split into its high and low 64 bits.
+public struct RustU128: Equatable, Hashable, Comparable, Codable {
+    public var high: UInt64
+    public var low: UInt64
+
+    public init(high: UInt64, low: UInt64) {
+        self.high = high
+        self.low = low
+    }
+
+    public init(_ value: UInt64) {
+        self.init(high: 0, low: value)
+    }
+
+    public static func < (lhs: RustU128, rhs: RustU128) -> Bool {
+        (lhs.high, lhs.low) < (rhs.high, rhs.low)
+    }
+}
+
+/// A Rust `i128`, split into its high and low 64 bits.
+///
+/// The `high` word holds the sign, so a negative number has a negative `high` word.
+public struct RustI128: Equatable, Hashable, Comparable, Codable {
+    public var high: Int64
+    public var low: UInt64
+
+    public init(high: Int64, low: UInt64) {
+        self.high = high
+        self.low = low
+    }
+
+    public init(_ value: Int64) {
+        self.init(high: value < 0 ? -1 : 0, low: UInt64(bitPattern: value))
+    }
+
+    public static func < (lhs: RustI128, rhs: RustI128) -> Bool {
+        (lhs.high, lhs.low) < (rhs.high, rhs.low)
+    }
+}
+
+extension RustU128 {
+    func intoFfiRepr() -> __private__FfiU128 {
+        __private__FfiU128(high: self.high, low: self.low)
+    }
+}
+extension __private__FfiU128 {
+    func intoSwiftRepr() -> RustU128 {
+        RustU128(high: self.high, low: self.low)
+    }
+}
+
+extension RustI128 {
+    func intoFfiRepr() -> __private__FfiI128 {
+        __private__FfiI128(high: self.high, low: self.low)
+    }
+}
+extension __private__FfiI128 {
+    func intoSwiftRepr() -> RustI128 {
+        RustI128(high: self.high, low: self.low)
+    }
+}
@@ -0,0 +1,72 @@
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr};
+
+/// The FFI representation of a `std::net::IpAddr`.
+///
+/// The octets are always in network byte order. An IPv4 address only uses the first four of
+/// them and leaves the rest zeroed.
+#[repr(C)]
+#[doc(hidden)]
+pub struct FfiIpAddr {
+    pub is_v6: bool,
+    pub octets: [u8; 16],
+}
+
+impl FfiIpAddr {
+    /// Create an FfiIpAddr from an IpAddr.
+    pub fn from_ip_addr(ip: IpAddr) -> Self {
+        let mut octets = [0; 16];
+
+        match ip {
+            IpAddr::V4(v4) => {
+                octets[..4].copy_from_slice(&v4.octets());
+                FfiIpAddr {
+                    is_v6: false,
+                    octets,
+                }
+            }
+            IpAddr::V6(v6) => {
+                octets.copy_from_slice(&v6.octets());
+                FfiIpAddr {
+                    is_v6: true,
+                    octets,
+                }
+            }
+        }
+    }
+
+    /// Convert back into an IpAddr.
+    pub fn into_ip_addr(self) -> IpAddr {
+        if self.is_v6 {
+            IpAddr::V6(Ipv6Addr::from(self.octets))
+        } else {
+            let [a, b, c, d, ..] = self.octets;
+            IpAddr::V4(Ipv4Addr::new(a, b, c, d))
+        }
+    }
+}
+
+/// The FFI representation of a `std::net::SocketAddr`.
+///
+/// An IPv6 socket address's flow info and scope id are not bridged, so they are zero after a
+/// round trip through Swift.
+#[repr(C)]
+#[doc(hidden)]
+pub struct FfiSocketAddr {
+    pub ip: FfiIpAddr,
+    pub port: u16,
+}
+
+impl FfiSocketAddr {
+    /// Create an FfiSocketAddr from a SocketAddr.
+    pub fn from_socket_addr(addr: SocketAddr) -> Self {
+        FfiSocketAddr {
+            ip: FfiIpAddr::from_ip_addr(addr.ip()),
+            port: addr.port(),
+        }
+    }
+
+    /// Convert back into a SocketAddr.
+    pub fn into_socket_addr(self) -> SocketAddr {
+        SocketAddr::new(self.ip.into_ip_addr(), self.port)
+    }
+}
@@ -0,0 +1,70 @@
+/// A Rust `std::net::IpAddr`.
+///
+/// The octets are in network byte order, so an IPv4 address has 4 of them and an IPv6 address
+/// has 16.
+public enum RustIpAddr: Equatable, Hashable, Codable {
+    case v4([UInt8])
+    case v6([UInt8])
+
+    public var octets: [UInt8] {
+        switch self {
+        case .v4(let octets):
+            return octets
+        case .v6(let octets):
+            return octets
+        }
+    }
+}
+
+/// A Rust `std::net::SocketAddr`.
+public struct RustSocketAddr: Equatable, Hashable, Codable {
+    public var ip: RustIpAddr
+    public var port: UInt16
+
+    public init(ip: RustIpAddr, port: UInt16) {
+        self.ip = ip
+        self.port = port
+    }
+}
+
+extension RustIpAddr {
+    func intoFfiRepr() -> __private__FfiIpAddr {
+        var ffi = __private__FfiIpAddr()
+        let octets: [UInt8]
+        switch self {
+        case .v4(let v4):
+            precondition(v4.count == 4, "An IPv4 address must have 4 octets")
+            ffi.is_v6 = false
+            octets = v4
+        case .v6(let v6):
+            precondition(v6.count == 16, "An IPv6 address must have 16 octets")
+            ffi.is_v6 = true
+            octets = v6
+        }
+        withUnsafeMutableBytes(of: &ffi.octets) { buffer in
+            buffer.copyBytes(from: octets)
+        }
+        return ffi
+    }
+}
+extension __private__FfiIpAddr {
+    func intoSwiftRepr() -> RustIpAddr {
+        let octets = withUnsafeBytes(of: self.octets) { Array($0) }
+        if self.is_v6 {
+            return .v6(octets)
+        } else {
+            return .v4(Array(octets.prefix(4)))
+        }
+    }
+}
+
+extension RustSocketAddr {
+    func intoFfiRepr() -> __private__FfiSocketAddr {
+        __private__FfiSocketAddr(ip: self.ip.intoFfiRepr(), port: self.port)
+    }
+}
+extension __private__FfiSocketAddr {
+    func intoSwiftRepr() -> RustSocketAddr {
+        RustSocketAddr(ip: self.ip.intoSwiftRepr(), port: self.port)
+    }
+}
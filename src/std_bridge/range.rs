@@ -0,0 +1,65 @@
+use std::ops::{Range, RangeInclusive};
+
+/// The FFI representation of a `Range<T>` or a `RangeInclusive<T>`, where `T` is an integer.
+///
+/// Swift's `Range` and `ClosedRange` trap if their lower bound is greater than their upper bound,
+/// so we check that `start <= end` before a range crosses the FFI boundary.
+#[repr(C)]
+#[doc(hidden)]
+pub struct FfiRange<T> {
+    pub start: T,
+    pub end: T,
+    /// Whether or not `end` is included in the range.
+    pub inclusive: bool,
+}
+
+impl<T: PartialOrd + Copy> FfiRange<T> {
+    /// Create an FfiRange from a `Range<T>`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the range's start is greater than its end.
+    pub fn from_range(range: Range<T>) -> Self {
+        assert!(
+            range.start <= range.end,
+            "Cannot bridge a Range whose start is greater than its end."
+        );
+
+        FfiRange {
+            start: range.start,
+            end: range.end,
+            inclusive: false,
+        }
+    }
+
+    /// Create an FfiRange from a `RangeInclusive<T>`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the range is empty, since Swift's `ClosedRange` can't be empty. This includes
+    /// ranges whose start is greater than their end as well as exhausted ranges.
+    pub fn from_range_inclusive(range: RangeInclusive<T>) -> Self {
+        assert!(
+            !range.is_empty(),
+            "Cannot bridge an empty RangeInclusive, since a Swift ClosedRange can't be empty."
+        );
+
+        FfiRange {
+            start: *range.start(),
+            end: *range.end(),
+            inclusive: true,
+        }
+    }
+
+    /// Convert back into a `Range<T>`.
+    pub fn into_range(self) -> Range<T> {
+        assert!(!self.inclusive && self.start <= self.end);
+        self.start..self.end
+    }
+
+    /// Convert back into a `RangeInclusive<T>`.
+    pub fn into_range_inclusive(self) -> RangeInclusive<T> {
+        assert!(self.inclusive && self.start <= self.end);
+        self.start..=self.end
+    }
+}
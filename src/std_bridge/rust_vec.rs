@@ -27,6 +27,20 @@ mod macro_ {
                     Box::into_raw(Box::new(Vec::new()))
                 }
 
+                /// Copy the elements of a Swift Array into a new Vec.
+                ///
+                /// An empty Swift Array does not have a buffer, so `elems` can be null.
+                #[export_name = concat!("__swift_bridge__$Vec_", stringify!($ty), "$from_slice")]
+                #[doc(hidden)]
+                pub extern "C" fn _from_slice(elems: *const $ty, len: usize) -> *mut Vec<$ty> {
+                    let vec = if elems.is_null() {
+                        Vec::new()
+                    } else {
+                        unsafe { std::slice::from_raw_parts(elems, len) }.to_vec()
+                    };
+                    Box::into_raw(Box::new(vec))
+                }
+
                 #[export_name = concat!("__swift_bridge__$Vec_", stringify!($ty), "$_free")]
                 #[doc(hidden)]
                 pub extern "C" fn _drop(vec: *mut Vec<$ty>) {
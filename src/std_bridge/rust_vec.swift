@@ -14,6 +14,14 @@ public class RustVec<T: Vectorizable> {
         isOwned = true
     }
 
+    /// A handle to a Vec that is still owned by Rust, such as a `&mut Vec<T>` argument.
+    /// Pushing to or popping from the handle mutates the Rust Vec, and the Vec is not freed when
+    /// the handle is deinitialized.
+    init(borrowedPtr: UnsafeMutableRawPointer) {
+        self.ptr = borrowedPtr
+        self.isOwned = false
+    }
+
     func push (value: T) {
         T.vecOfSelfPush(vecPtr: ptr, value: value)
     }
@@ -89,6 +97,18 @@ extension UnsafeBufferPointer {
     }
 }
 
+extension UnsafeMutableBufferPointer {
+    func toFfiSlice () -> __private__FfiSlice {
+        __private__FfiSlice(start: self.baseAddress, len: UInt(self.count))
+    }
+}
+
+extension UnsafeRawBufferPointer {
+    func toFfiSlice () -> __private__FfiSlice {
+        __private__FfiSlice(start: UnsafeMutableRawPointer(mutating: self.baseAddress), len: UInt(self.count))
+    }
+}
+
 extension Array {
     /// Get an UnsafeBufferPointer to the array's content's first byte with the array's length.
     ///
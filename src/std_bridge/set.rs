@@ -0,0 +1,50 @@
+use std::any::Any;
+
+/// The FFI representation of a `HashSet<T>` or a `BTreeSet<T>`.
+///
+/// Holds a buffer of the set's elements, where `T` is the FFI compatible representation of the
+/// set's element type.
+///
+/// When Rust passes a set to Swift the buffer is owned by Rust and gets freed when Swift calls
+/// `__swift_bridge__$FfiSet$_free`.
+/// When Swift passes a set to Rust the buffer is owned by Swift and is only valid for the
+/// duration of the call.
+#[repr(C)]
+#[doc(hidden)]
+pub struct FfiSet<T> {
+    pub elems: *const T,
+    pub len: usize,
+    /// The Rust allocation that owns the buffer. Null if the buffer is owned by Swift.
+    owner: *mut Box<dyn Any>,
+}
+
+impl<T: 'static> FfiSet<T> {
+    /// Create an FfiSet that owns the buffer that holds its elements.
+    pub fn from_elems(elems: impl Iterator<Item = T>) -> Self {
+        let elems: Vec<T> = elems.collect();
+
+        let elems_ptr = elems.as_ptr();
+        let len = elems.len();
+
+        let owner: Box<dyn Any> = Box::new(elems);
+
+        FfiSet {
+            elems: elems_ptr,
+            len,
+            owner: Box::into_raw(Box::new(owner)),
+        }
+    }
+
+    /// Move the elements out of a buffer that is owned by Swift.
+    pub fn into_elems(self) -> impl Iterator<Item = T> {
+        (0..self.len).map(move |idx| unsafe { std::ptr::read(self.elems.add(idx)) })
+    }
+}
+
+#[export_name = "__swift_bridge__$FfiSet$_free"]
+#[doc(hidden)]
+pub extern "C" fn _free(set: FfiSet<std::ffi::c_void>) {
+    if !set.owner.is_null() {
+        drop(unsafe { Box::from_raw(set.owner) });
+    }
+}
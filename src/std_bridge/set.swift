@@ -0,0 +1,42 @@
+extension __private__FfiSet {
+    /// Move the elements of a set that was created by Rust into a Swift Set and then free the
+    /// Rust buffer that held them.
+    func toSet<FfiElem, Elem: Hashable>(_ intoElem: (FfiElem) -> Elem) -> Set<Elem> {
+        var set = Set<Elem>(minimumCapacity: Int(self.len))
+
+        if self.len > 0 {
+            let elems = self.elems.assumingMemoryBound(to: FfiElem.self)
+
+            for idx in 0..<Int(self.len) {
+                set.insert(intoElem(elems[idx]))
+            }
+        }
+
+        __swift_bridge__$FfiSet$_free(self)
+
+        return set
+    }
+}
+
+extension Set {
+    /// Lend the Set's elements to Rust for the duration of the callback.
+    func toFfiSet<FfiElem, T>(
+        _ elemToFfi: (Element) -> FfiElem,
+        _ withFfiSet: (__private__FfiSet) -> T
+    ) -> T {
+        var elems: [FfiElem] = []
+        elems.reserveCapacity(self.count)
+
+        for elem in self {
+            elems.append(elemToFfi(elem))
+        }
+
+        return elems.withUnsafeBufferPointer({ elemsPtr in
+            withFfiSet(__private__FfiSet(
+                elems: UnsafeMutableRawPointer(mutating: elemsPtr.baseAddress),
+                len: UInt(self.count),
+                owner: nil
+            ))
+        })
+    }
+}
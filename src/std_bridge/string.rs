@@ -1,5 +1,8 @@
 pub use self::ffi::*;
 
+use std::borrow::Cow;
+use std::mem::ManuallyDrop;
+
 #[swift_bridge_macro::bridge(swift_bridge_path = crate)]
 mod ffi {
     extern "Rust" {
@@ -32,6 +35,135 @@ pub struct RustStr {
     pub len: usize,
 }
 
+/// An owned string that Swift passes to Rust.
+///
+/// A `RustString` is handed back to Rust without copying it.
+///
+/// By default Swift lends Rust a Swift `String`'s UTF-8 buffer for the duration of the call, and
+/// Rust copies the bytes into a new `String`.
+///
+/// With the `unchecked-zero-copy-strings` feature enabled, Swift instead copies the bytes into a
+/// buffer that it allocates using `malloc`, and Rust takes ownership of that buffer using
+/// `String::from_raw_parts`. This is only sound if Rust's global allocator frees memory using the
+/// same `free` that pairs with the `malloc` that Swift used, such as `std::alloc::System` on Apple
+/// platforms. Do not enable the feature if your program uses a different `#[global_allocator]`.
+#[doc(hidden)]
+#[repr(C)]
+pub struct FfiString {
+    /// Null if the string is empty or if Swift passed a `RustString`.
+    pub ptr: *mut u8,
+    pub len: usize,
+    /// The capacity of a buffer that Swift allocated using `malloc`.
+    /// Zero if Swift only lent Rust the bytes for the duration of the call.
+    pub cap: usize,
+    /// Null unless Swift passed a `RustString`.
+    pub rust_string: *mut RustString,
+}
+
+impl FfiString {
+    /// Take ownership of the string that Swift passed to Rust.
+    pub fn into_string(self) -> String {
+        if !self.rust_string.is_null() {
+            return unsafe { Box::from_raw(self.rust_string).0 };
+        }
+
+        if self.ptr.is_null() {
+            return String::new();
+        }
+
+        #[cfg(feature = "unchecked-zero-copy-strings")]
+        if self.cap != 0 {
+            // SAFETY: Swift allocated `cap` bytes using `malloc` and initialized the first `len`
+            // of them with valid UTF-8. Enabling the `unchecked-zero-copy-strings` feature
+            // asserts that Rust's global allocator can free memory from `malloc`.
+            return unsafe { String::from_raw_parts(self.ptr, self.len, self.cap) };
+        }
+
+        RustStr {
+            start: self.ptr,
+            len: self.len,
+        }
+        .to_string()
+    }
+}
+
+/// A `Cow<str>` that Rust returns to Swift.
+///
+/// A borrowed string is passed as a pointer and length without transferring ownership, so Swift
+/// copies it into a Swift `String` before the borrow ends.
+///
+/// An owned string's buffer is transferred to Swift, which copies it into a Swift `String` and
+/// then frees it by calling `__swift_bridge__$FfiCowStr$_free`.
+#[doc(hidden)]
+#[repr(C)]
+pub struct FfiCowStr {
+    pub start: *mut u8,
+    pub len: usize,
+    /// The capacity of an owned string's buffer. Zero if the string is borrowed.
+    pub cap: usize,
+    /// Whether or not Swift is responsible for freeing the string.
+    pub is_owned: bool,
+}
+
+impl FfiCowStr {
+    pub fn from_cow(cow: Cow<str>) -> Self {
+        match cow {
+            Cow::Borrowed(str) => FfiCowStr {
+                start: str.as_ptr() as *mut u8,
+                len: str.len(),
+                cap: 0,
+                is_owned: false,
+            },
+            Cow::Owned(string) => {
+                let mut string = ManuallyDrop::new(string);
+
+                FfiCowStr {
+                    start: string.as_mut_ptr(),
+                    len: string.len(),
+                    cap: string.capacity(),
+                    is_owned: true,
+                }
+            }
+        }
+    }
+}
+
+#[export_name = "__swift_bridge__$FfiCowStr$_free"]
+#[doc(hidden)]
+pub extern "C" fn _free_cow_str(cow: FfiCowStr) {
+    if cow.is_owned {
+        drop(unsafe { String::from_raw_parts(cow.start, cow.len, cow.cap) });
+    }
+}
+
+/// A `Box<str>` that Rust returns to Swift.
+///
+/// The boxed string's buffer is transferred to Swift, which copies it into a Swift `String` and
+/// then frees it by calling `__swift_bridge__$FfiBoxedStr$_free`. Unlike a `String`, a `Box<str>`
+/// has no spare capacity, so only the pointer and length are passed.
+#[doc(hidden)]
+#[repr(C)]
+pub struct FfiBoxedStr {
+    pub start: *mut u8,
+    pub len: usize,
+}
+
+impl FfiBoxedStr {
+    pub fn from_boxed_str(boxed: Box<str>) -> Self {
+        let len = boxed.len();
+        let start = Box::into_raw(boxed) as *mut u8;
+
+        FfiBoxedStr { start, len }
+    }
+}
+
+#[export_name = "__swift_bridge__$FfiBoxedStr$_free"]
+#[doc(hidden)]
+pub extern "C" fn _free_boxed_str(boxed: FfiBoxedStr) {
+    let slice = std::ptr::slice_from_raw_parts_mut(boxed.start, boxed.len);
+    drop(unsafe { Box::from_raw(slice as *mut str) });
+}
+
 impl RustString {
     fn new() -> Self {
         RustString("".to_string())
@@ -83,3 +215,27 @@ impl RustStr {
         }
     }
 }
+
+impl crate::FfiSlice<RustStr> {
+    /// Borrow the `RustStr`s that Swift passed in for a `&[&str]` argument.
+    ///
+    /// The strings are only valid until the Rust function that they were passed to returns.
+    pub fn to_str_vec<'a>(&self) -> Vec<&'a str> {
+        // Swift represents an empty buffer using a null base address, which isn't a valid
+        // slice pointer.
+        if self.start.is_null() {
+            return vec![];
+        }
+
+        self.as_slice()
+            .iter()
+            .map(|str| {
+                RustStr {
+                    start: str.start,
+                    len: str.len,
+                }
+                .to_str()
+            })
+            .collect()
+    }
+}
@@ -34,8 +34,54 @@ extension RustStr: Equatable {
     }
 }
 
+extension __private__FfiCowStr {
+    /// Copy the string into a Swift `String`, freeing it if Rust transferred ownership of it.
+    func intoString() -> String {
+        let string = RustStr(start: self.start, len: self.len).toString()
+
+        if self.is_owned {
+            __swift_bridge__$FfiCowStr$_free(self)
+        }
+
+        return string
+    }
+}
+
+extension __private__FfiBoxedStr {
+    /// Copy the string into a Swift `String` and then free the Rust `Box<str>`.
+    func intoString() -> String {
+        let string = RustStr(start: self.start, len: self.len).toString()
+        __swift_bridge__$FfiBoxedStr$_free(self)
+
+        return string
+    }
+}
+
 public protocol IntoRustString {
     func intoRustString() -> RustString;
+
+    /// Call the closure with an `FfiString` that Rust turns into an owned `String`.
+    /// The `FfiString` is only valid until the closure returns.
+    /// See `FfiString` in src/std_bridge/string.rs
+    func toFfiString<T>(_ withFfiString: (__private__FfiString) -> T) -> T;
+}
+
+extension IntoRustString {
+    public func toFfiString<T>(_ withFfiString: (__private__FfiString) -> T) -> T {
+        self.intoRustString().toFfiString(withFfiString)
+    }
+}
+
+/// Copy the bytes into a buffer allocated using `malloc`, which the Rust side will take ownership of.
+func mallocFfiString(_ bytes: UnsafeBufferPointer<UInt8>) -> __private__FfiString {
+    if bytes.count == 0 {
+        return __private__FfiString(ptr: nil, len: 0, cap: 0, rust_string: nil)
+    }
+
+    let ptr = malloc(bytes.count)!.assumingMemoryBound(to: UInt8.self)
+    ptr.initialize(from: bytes.baseAddress!, count: bytes.count)
+
+    return __private__FfiString(ptr: ptr, len: UInt(bytes.count), cap: UInt(bytes.count), rust_string: nil)
 }
 
 public protocol ToRustStr {
@@ -44,20 +90,32 @@ public protocol ToRustStr {
 
 extension String: IntoRustString {
     public func intoRustString() -> RustString {
-        // TODO: When passing an owned Swift std String to Rust we've being wasteful here in that
-        //  we're creating a RustString (which involves Boxing a Rust std::string::String)
-        //  only to unbox it back into a String once it gets to the Rust side.
-        //
-        //  A better approach would be to pass a RustStr to the Rust side and then have Rust
-        //  call `.to_string()` on the RustStr.
         RustString(self)
     }
+
+    public func toFfiString<T>(_ withFfiString: (__private__FfiString) -> T) -> T {
+        var string = self
+        return string.withUTF8 { bytes in
+            if __swift_bridge__uncheckedZeroCopyStrings {
+                return withFfiString(mallocFfiString(bytes))
+            }
+
+            // Rust copies the bytes into a new `String` before the closure returns.
+            let ptr = UnsafeMutablePointer(mutating: bytes.baseAddress)
+            return withFfiString(__private__FfiString(ptr: ptr, len: UInt(bytes.count), cap: 0, rust_string: nil))
+        }
+    }
 }
 
 extension RustString: IntoRustString {
     public func intoRustString() -> RustString {
         self
     }
+
+    public func toFfiString<T>(_ withFfiString: (__private__FfiString) -> T) -> T {
+        self.isOwned = false
+        return withFfiString(__private__FfiString(ptr: nil, len: 0, cap: 0, rust_string: self.ptr))
+    }
 }
 
 /// If the String is Some:
@@ -90,6 +148,40 @@ extension String: ToRustStr {
     }
 }
 
+extension Array where Element == String {
+    /// Safely get scoped pointers to the Strings and then call the callback with a slice of
+    /// RustStrs that use those pointers.
+    ///
+    /// Used for `&[&str]` arguments.
+    func toRustStrSlice<T> (_ withUnsafeRustStrSlice: (__private__FfiSlice) -> T) -> T {
+        // We copy every String into one buffer so that all of the pointers are valid for the
+        // duration of the callback.
+        // Each String gets followed by a null byte so that a pointer to an empty String is
+        // still a valid non-null pointer.
+        var bytes: [UInt8] = []
+        for string in self {
+            bytes.append(contentsOf: string.utf8)
+            bytes.append(0)
+        }
+
+        return bytes.withUnsafeBufferPointer({ bytesPtr in
+            var start = UnsafeMutablePointer(mutating: bytesPtr.baseAddress)
+            var rustStrs: [RustStr] = []
+            rustStrs.reserveCapacity(self.count)
+
+            for string in self {
+                let len = string.utf8.count
+                rustStrs.append(RustStr(start: start, len: UInt(len)))
+                start = start?.advanced(by: len + 1)
+            }
+
+            return rustStrs.withUnsafeBufferPointer({ rustStrsPtr in
+                withUnsafeRustStrSlice(rustStrsPtr.toFfiSlice())
+            })
+        })
+    }
+}
+
 extension RustStr: ToRustStr {
     public func toRustStr<T> (_ withUnsafeRustStr: (RustStr) -> T) -> T {
         return withUnsafeRustStr(self)
@@ -0,0 +1,55 @@
+use std::any::Any;
+
+/// The FFI representation of a `Vec<T>` that gets moved into a Swift `Array`.
+///
+/// Holds a buffer of the Vec's elements, where `T` is the FFI compatible representation of the
+/// Vec's element type.
+///
+/// The buffer is owned by Rust and gets freed when Swift calls `__swift_bridge__$FfiVec$_free`
+/// after moving the elements out of it.
+#[repr(C)]
+#[doc(hidden)]
+pub struct FfiVec<T> {
+    pub elems: *const T,
+    pub len: usize,
+    /// The Rust allocation that owns the buffer.
+    owner: *mut Box<dyn Any>,
+}
+
+impl<T: 'static> FfiVec<T> {
+    /// Create an FfiVec that owns the buffer that holds its elements.
+    pub fn from_elems(elems: impl Iterator<Item = T>) -> Self {
+        let elems: Vec<T> = elems.collect();
+
+        let elems_ptr = elems.as_ptr();
+        let len = elems.len();
+
+        let owner: Box<dyn Any> = Box::new(elems);
+
+        FfiVec {
+            elems: elems_ptr,
+            len,
+            owner: Box::into_raw(Box::new(owner)),
+        }
+    }
+
+    /// The FfiVec that an `Option<Vec<T>>` uses to represent `None`.
+    ///
+    /// An empty Vec always has a non-null buffer, so a null buffer can't be confused with an
+    /// empty Vec.
+    pub fn none() -> Self {
+        FfiVec {
+            elems: std::ptr::null(),
+            len: 0,
+            owner: std::ptr::null_mut(),
+        }
+    }
+}
+
+#[export_name = "__swift_bridge__$FfiVec$_free"]
+#[doc(hidden)]
+pub extern "C" fn _free(vec: FfiVec<std::ffi::c_void>) {
+    if !vec.owner.is_null() {
+        drop(unsafe { Box::from_raw(vec.owner) });
+    }
+}
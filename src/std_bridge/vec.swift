@@ -0,0 +1,55 @@
+extension __private__FfiVec {
+    /// Move the elements of a Vec that was created by Rust into a Swift Array and then free the
+    /// Rust buffer that held them.
+    func toArray<FfiElem, Elem>(_ intoElem: (FfiElem) -> Elem) -> [Elem] {
+        var array: [Elem] = []
+        array.reserveCapacity(Int(self.len))
+
+        if self.len > 0 {
+            let elems = self.elems.assumingMemoryBound(to: FfiElem.self)
+
+            for idx in 0..<Int(self.len) {
+                array.append(intoElem(elems[idx]))
+            }
+        }
+
+        __swift_bridge__$FfiVec$_free(self)
+
+        return array
+    }
+
+    /// Copy the numbers of a Vec that was created by Rust into a Swift Array in one shot and then
+    /// free the Rust buffer that held them.
+    ///
+    /// Only used for element types that have the same layout in Rust and Swift, such as `f32` and
+    /// `Float`.
+    func toArray<Elem>(of _: Elem.Type) -> [Elem] {
+        var array: [Elem] = []
+
+        if self.len > 0 {
+            let elems = self.elems.assumingMemoryBound(to: Elem.self)
+            array = Array(UnsafeBufferPointer(start: elems, count: Int(self.len)))
+        }
+
+        __swift_bridge__$FfiVec$_free(self)
+
+        return array
+    }
+
+    /// Wrap the bytes of a `Vec<u8>` that was created by Rust in `Data` without copying them.
+    ///
+    /// The Rust buffer gets freed when the `Data` is deallocated.
+    func toData() -> Data {
+        if self.len == 0 {
+            __swift_bridge__$FfiVec$_free(self)
+            return Data()
+        }
+
+        let vec = self
+        return Data(
+            bytesNoCopy: self.elems,
+            count: Int(self.len),
+            deallocator: .custom({ _, _ in __swift_bridge__$FfiVec$_free(vec) })
+        )
+    }
+}